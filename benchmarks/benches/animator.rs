@@ -0,0 +1,47 @@
+#[macro_use]
+extern crate criterion;
+
+use std::time::Duration;
+
+use bevy::{ecs::system::RunSystemOnce, prelude::*};
+use bevy_tweening::{lens::*, *};
+use criterion::{BatchSize, Criterion};
+
+/// Create a world with `count` animators all completing on the next tick.
+fn make_world(count: usize) -> World {
+    let mut world = World::new();
+    world.init_resource::<Events<TweenCompleted>>();
+    world.init_resource::<Events<AnimatorStateChanged>>();
+    let mut time = Time::<()>::default();
+    time.advance_by(Duration::from_secs(1));
+    world.insert_resource(time);
+    world.spawn_batch((0..count).map(|index| {
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+        .with_completed_event(index as u64);
+        (Transform::default(), Animator::new(tween))
+    }));
+    world
+}
+
+fn mass_completion(c: &mut Criterion) {
+    c.bench_function("MassCompletion3k", |b| {
+        b.iter_batched(
+            || make_world(3000),
+            |mut world| {
+                world.run_system_once(component_animator_system::<Transform>);
+                world
+            },
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+criterion_group!(benches, mass_completion);
+criterion_main!(benches);
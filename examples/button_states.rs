@@ -0,0 +1,135 @@
+use bevy::prelude::*;
+use bevy_tweening::{lens::*, *};
+use std::time::Duration;
+
+const BUTTON_COLOR: Color = Color::rgba(162. / 255., 226. / 255., 95. / 255., 1.);
+const TEXT_COLOR: Color = Color::rgba(83. / 255., 163. / 255., 130. / 255., 1.);
+
+/// The button in this example has three animation states, driven by a
+/// `TweenStateMachine`:
+/// - `idle`, at rest;
+/// - `hovered`, slightly enlarged while the cursor is over the button;
+/// - `pressed`, a quick squash played on click, which automatically returns
+///   to `hovered` once completed.
+///
+/// Moving the cursor in and out of the button quickly reverses the hover
+/// animation where it is, while clicking in the middle of it crossfades into
+/// the squash, so the button never jumps.
+fn main() {
+    App::default()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "ButtonStates".to_string(),
+                resolution: (800., 400.).into(),
+                present_mode: bevy::window::PresentMode::Fifo, // vsync
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_systems(Update, bevy::window::close_on_esc)
+        .add_plugins(TweeningPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, (interaction, print_transitions))
+        .run();
+}
+
+/// Prototype of a state, scaling the button from `start` to `end`.
+fn scale(start: f32, end: f32, millis: u64) -> TweenPrototype<Tween<Transform>> {
+    TweenPrototype::new(move || {
+        Tween::new(
+            EaseFunction::QuadraticOut,
+            Duration::from_millis(millis),
+            TransformScaleLens {
+                start: Vec3::splat(start),
+                end: Vec3::splat(end),
+            },
+        )
+    })
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+
+    let font = asset_server.load("fonts/FiraMono-Regular.ttf");
+
+    let states = TweenStateMachine::<Transform>::new("idle")
+        .with_state("idle", scale(1., 1., 200))
+        .with_state("hovered", scale(1., 1.1, 200))
+        .with_state("pressed", scale(1.1, 0.9, 120))
+        .with_return("pressed", "hovered")
+        .with_crossfade(Duration::from_millis(80));
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|container| {
+            container
+                .spawn((
+                    ButtonBundle {
+                        style: Style {
+                            min_width: Val::Px(300.),
+                            min_height: Val::Px(80.),
+                            padding: UiRect::all(Val::Px(8.)),
+                            align_items: AlignItems::Center,
+                            justify_content: JustifyContent::Center,
+                            ..default()
+                        },
+                        background_color: BackgroundColor(BUTTON_COLOR),
+                        ..default()
+                    },
+                    Name::new("button"),
+                    states,
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle {
+                        text: Text::from_section(
+                            "Click me",
+                            TextStyle {
+                                font,
+                                font_size: 48.0,
+                                color: TEXT_COLOR,
+                            },
+                        )
+                        .with_justify(JustifyText::Center),
+                        ..default()
+                    });
+                });
+        });
+}
+
+fn interaction(
+    mut query: Query<(&Interaction, &mut TweenStateMachine<Transform>), Changed<Interaction>>,
+) {
+    for (interaction, mut states) in &mut query {
+        match *interaction {
+            Interaction::Pressed => states.transition_to("pressed"),
+            Interaction::Hovered => {
+                // Releasing the button lets the squash complete, then return
+                // to the hovered state by itself
+                if states.current() != Some("pressed") {
+                    states.transition_to("hovered");
+                }
+            }
+            Interaction::None => states.transition_to("idle"),
+        }
+    }
+}
+
+fn print_transitions(mut reader: EventReader<TweenStateTransition>) {
+    for event in reader.read() {
+        println!(
+            "{:?}: {} -> {}",
+            event.entity,
+            event.exited.as_deref().unwrap_or("<none>"),
+            event.entered
+        );
+    }
+}
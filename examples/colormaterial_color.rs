@@ -17,7 +17,7 @@ fn main() {
             ..default()
         }))
         .add_systems(Update, bevy::window::close_on_esc)
-        .add_plugins(TweeningPlugin)
+        .add_plugins(TweeningPlugin::default())
         .add_systems(Startup, setup)
         .run();
 }
@@ -80,10 +80,7 @@ fn setup(
         let tween = Tween::new(
             *ease_function,
             Duration::from_secs(1),
-            ColorMaterialColorLens {
-                start: Color::RED,
-                end: Color::BLUE,
-            },
+            ColorMaterialColorLens::new(Color::RED, Color::BLUE),
         )
         .with_repeat_count(RepeatCount::Infinite)
         .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
@@ -0,0 +1,117 @@
+//! Headless check of the determinism of the animations.
+//!
+//! This drives a nested animation with a fixed list of deltas, without any
+//! window or renderer, and prints a hash of the animated values after each
+//! step. Running this example on several platforms, for example a Linux CI, a
+//! Windows machine, and a wasm build, must print the same final hash, which is
+//! also checked by the `determinism` integration test. A lockstep simulation
+//! can use the same approach to check its own animations.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_tweening::{lens::*, *};
+
+/// Quantization step of the hashed values, absorbing the differences in the
+/// last bits of the transcendental functions used by the easing functions.
+const EPSILON: f32 = 1e-4;
+
+/// Deltas of the steps, in microseconds.
+const DELTAS_US: [u64; 16] = [
+    16_667, 33_333, 1, 0, 7_919, 250_001, 16_666, 999_983, 3, 104_729, 16_667, 1_299_709, 49_999,
+    0, 611_953, 2_750_159,
+];
+
+fn main() {
+    let mut app = App::new();
+    app.add_plugins(
+        TweeningPlugin::default()
+            .without_defaults()
+            .register::<Transform>(),
+    )
+    .init_resource::<Time>();
+    let entity = app
+        .world
+        .spawn((
+            Transform::default(),
+            Animator::new(Sequence::new((0..3).map(ping_pong))),
+        ))
+        .id();
+
+    let mut hash = Fnv(0xcbf2_9ce4_8422_2325);
+    for (step, delta) in DELTAS_US.into_iter().enumerate() {
+        app.world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_micros(delta));
+        app.update();
+        let transform = app.world.get::<Transform>(entity).unwrap();
+        for value in transform
+            .translation
+            .to_array()
+            .into_iter()
+            .chain(transform.scale.to_array())
+            .chain(transform.rotation.to_array())
+        {
+            hash.write(value);
+        }
+        println!(
+            "step {step:2}: +{delta:>9}us  position={} hash={:#018x}",
+            transform.translation, hash.0
+        );
+    }
+    println!("final hash: {:#018x}", hash.0);
+}
+
+/// Sequence of tracks animating the position, scale, and rotation of an entity
+/// back and forth.
+fn ping_pong(index: u32) -> Tracks<Transform> {
+    let duration = |millis| Duration::from_millis(millis + 37 * u64::from(index));
+    let position = Tween::new(
+        EaseFunction::QuadraticInOut,
+        duration(300),
+        TransformPositionLens {
+            start: Vec3::ZERO,
+            end: Vec3::new(3., -2., 1.) * (index + 1) as f32,
+        },
+    )
+    .with_repeat_count(3)
+    .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
+    let scale = Tween::new(
+        EaseFunction::SineInOut,
+        duration(450),
+        TransformScaleLens {
+            start: Vec3::ONE,
+            end: Vec3::splat(0.25),
+        },
+    )
+    .with_repeat_count(2)
+    .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
+    let rotation = Tween::new(
+        EaseFunction::BackOut,
+        duration(700),
+        TransformRotationLens {
+            start: Quat::IDENTITY,
+            end: Quat::from_rotation_y(2.),
+        },
+    )
+    .with_repeat_count(2)
+    .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
+    Tracks::new([
+        Sequence::from_single(position),
+        Sequence::from_single(scale),
+        Sequence::from_single(rotation),
+    ])
+}
+
+/// 64-bit FNV-1a hash, stable across platforms and Rust versions.
+struct Fnv(u64);
+
+impl Fnv {
+    fn write(&mut self, value: f32) {
+        let quantized = (value / EPSILON).round() as i64;
+        for byte in quantized.to_le_bytes() {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
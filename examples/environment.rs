@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+use bevy_tweening::{lens::*, *};
+use std::time::Duration;
+
+fn main() {
+    App::default()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "AmbientLightLens + FogLens".to_string(),
+                resolution: (1200., 600.).into(),
+                present_mode: bevy::window::PresentMode::Fifo, // vsync
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_systems(Update, bevy::window::close_on_esc)
+        // DirectionalLight is not animated by default; register it with the plugin
+        .add_plugins(TweeningPlugin::default().register::<DirectionalLight>())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+/// Custom lens animating the illuminance of a directional light.
+struct DirectionalLightIlluminanceLens {
+    start: f32,
+    end: f32,
+}
+
+impl Lens<DirectionalLight> for DirectionalLightIlluminanceLens {
+    fn lerp(&mut self, target: &mut DirectionalLight, ratio: f32) {
+        target.illuminance = self.start + (self.end - self.start) * ratio;
+    }
+}
+
+/// Build a tween on the shared day/night timeline.
+///
+/// All the animators of the scene use the same duration, easing, and repeat
+/// parameters, and are all ticked in [`AnimationSystem::AnimationUpdate`], so
+/// they stay in lockstep for the entire transition.
+fn timeline<T>(lens: impl Lens<T> + Send + Sync + 'static) -> Tween<T> {
+    Tween::new(EaseFunction::SineInOut, Duration::from_secs(10), lens)
+        .with_repeat_count(RepeatCount::Infinite)
+        .with_repeat_strategy(RepeatStrategy::MirroredRepeat)
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let day_sky = Color::rgb(0.6, 0.75, 0.9);
+    let night_sky = Color::rgb(0.02, 0.02, 0.08);
+
+    // Ambient light, animated as a resource. The animator can live on any entity.
+    commands.insert_resource(AmbientLight {
+        color: Color::WHITE,
+        brightness: 400.,
+    });
+    commands.spawn(ResourceAnimator::new(timeline(AmbientLightLens {
+        start_color: Color::WHITE,
+        end_color: Color::rgb(0.3, 0.35, 0.6),
+        start_brightness: 400.,
+        end_brightness: 40.,
+    })));
+
+    // Camera with distance fog
+    commands.spawn((
+        Camera3dBundle {
+            transform: Transform::from_xyz(-12., 6., 12.).looking_at(Vec3::ZERO, Vec3::Y),
+            ..default()
+        },
+        FogSettings {
+            color: day_sky,
+            ..default()
+        },
+        Animator::new(timeline(FogLens {
+            start_color: day_sky,
+            end_color: night_sky,
+            start_falloff: FogFalloff::Linear {
+                start: 20.,
+                end: 60.,
+            },
+            end_falloff: FogFalloff::Linear {
+                start: 5.,
+                end: 25.,
+            },
+        })),
+    ));
+
+    // Sun
+    commands.spawn((
+        DirectionalLightBundle {
+            directional_light: DirectionalLight {
+                illuminance: 10_000.,
+                ..default()
+            },
+            transform: Transform::from_xyz(4., 8., 2.).looking_at(Vec3::ZERO, Vec3::Y),
+            ..default()
+        },
+        Animator::new(timeline(DirectionalLightIlluminanceLens {
+            start: 10_000.,
+            end: 50.,
+        })),
+    ));
+
+    // Scene
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(Plane3d::default().mesh().size(100., 100.)),
+        material: materials.add(Color::rgb(0.3, 0.5, 0.3)),
+        ..default()
+    });
+    let cube = meshes.add(Cuboid::new(1., 2., 1.));
+    let material = materials.add(Color::rgb(0.8, 0.7, 0.6));
+    for i in 0..10 {
+        for j in 0..10 {
+            commands.spawn(PbrBundle {
+                mesh: cube.clone(),
+                material: material.clone(),
+                transform: Transform::from_xyz(i as f32 * 4. - 18., 1., j as f32 * -4.),
+                ..default()
+            });
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+use bevy_tweening::{recipes::*, *};
+use std::time::Duration;
+
+const CELL_SIZE: f32 = 64.;
+const GRID_SIZE: IVec2 = IVec2::new(9, 7);
+
+fn main() {
+    App::default()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "GridMove".to_string(),
+                resolution: (800., 600.).into(),
+                present_mode: bevy::window::PresentMode::Fifo, // vsync
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_systems(Update, bevy::window::close_on_esc)
+        .add_plugins(TweeningPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, (move_player, highlight_cell))
+        .run();
+}
+
+#[derive(Component)]
+struct Player;
+
+#[derive(Component)]
+struct Cell(IVec2);
+
+type PlayerQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static GridPosition,
+        Option<&'static Animator<Transform>>,
+    ),
+    With<Player>,
+>;
+
+fn setup(mut commands: Commands) {
+    let mut camera = Camera2dBundle::default();
+    camera.transform.translation.x = (GRID_SIZE.x - 1) as f32 * CELL_SIZE / 2.;
+    camera.transform.translation.y = (GRID_SIZE.y - 1) as f32 * CELL_SIZE / 2.;
+    commands.spawn(camera);
+
+    for y in 0..GRID_SIZE.y {
+        for x in 0..GRID_SIZE.x {
+            commands.spawn((
+                SpriteBundle {
+                    transform: Transform::from_xyz(x as f32 * CELL_SIZE, y as f32 * CELL_SIZE, 0.),
+                    sprite: Sprite {
+                        color: Color::DARK_GRAY,
+                        custom_size: Some(Vec2::splat(CELL_SIZE - 4.)),
+                        ..default()
+                    },
+                    ..default()
+                },
+                Cell(IVec2::new(x, y)),
+            ));
+        }
+    }
+
+    commands.spawn((
+        SpriteBundle {
+            transform: Transform::from_xyz(0., 0., 1.),
+            sprite: Sprite {
+                color: Color::ORANGE,
+                custom_size: Some(Vec2::splat(CELL_SIZE / 2.)),
+                ..default()
+            },
+            ..default()
+        },
+        GridPosition(IVec2::ZERO),
+        Player,
+    ));
+}
+
+/// Start a move to an adjacent cell with the arrow keys, once the previous
+/// move completed.
+fn move_player(mut commands: Commands, keyboard: Res<ButtonInput<KeyCode>>, query: PlayerQuery) {
+    let grid_move = GridMove::new(CELL_SIZE, Duration::from_millis(250));
+    let Ok((entity, position, animator)) = query.get_single() else {
+        return;
+    };
+    if animator.is_some_and(|animator| animator.time_remaining() != Some(Duration::ZERO)) {
+        return;
+    }
+    let direction = [
+        (KeyCode::ArrowUp, IVec2::Y),
+        (KeyCode::ArrowDown, IVec2::NEG_Y),
+        (KeyCode::ArrowLeft, IVec2::NEG_X),
+        (KeyCode::ArrowRight, IVec2::X),
+    ]
+    .into_iter()
+    .find_map(|(key, direction)| keyboard.pressed(key).then_some(direction));
+    let Some(direction) = direction else {
+        return;
+    };
+    let to = position.0 + direction;
+    if to.cmplt(IVec2::ZERO).any() || to.cmpge(GRID_SIZE).any() {
+        return;
+    }
+    commands
+        .entity(entity)
+        .insert(grid_move.step(position.0, to));
+}
+
+/// Highlight the cell the player occupies for gameplay purposes, which commits
+/// to the next cell halfway through each move.
+fn highlight_cell(
+    player: Query<&GridPosition, (With<Player>, Changed<GridPosition>)>,
+    mut cells: Query<(&Cell, &mut Sprite)>,
+) {
+    let Ok(position) = player.get_single() else {
+        return;
+    };
+    for (cell, mut sprite) in &mut cells {
+        sprite.color = if cell.0 == position.0 {
+            Color::DARK_GREEN
+        } else {
+            Color::DARK_GRAY
+        };
+    }
+}
@@ -34,7 +34,7 @@ fn main() {
         .add_systems(Update, bevy::window::close_on_esc)
         .add_systems(Update, interaction)
         .add_systems(Update, enable_interaction_after_initial_animation)
-        .add_plugins(TweeningPlugin)
+        .add_plugins(TweeningPlugin::default())
         .add_plugins(WorldInspectorPlugin::new())
         .add_systems(Startup, setup)
         .run();
@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+use bevy_tweening::{transitions::*, *};
+use std::time::Duration;
+
+/// User data of the event raised once the screen is covered.
+const SWAP_LEVEL: u64 = 1;
+
+fn main() {
+    App::default()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "ScreenFade".to_string(),
+                resolution: (800., 600.).into(),
+                present_mode: bevy::window::PresentMode::Fifo, // vsync
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_systems(Update, bevy::window::close_on_esc)
+        .add_plugins(TweeningPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, (start_fade, swap_level))
+        .run();
+}
+
+/// The sprite standing for the current level, and the index of that level.
+#[derive(Component)]
+struct Level(usize);
+
+const LEVELS: [(Color, Vec2); 3] = [
+    (Color::ORANGE, Vec2::new(-200., 0.)),
+    (Color::TEAL, Vec2::new(0., 100.)),
+    (Color::PINK, Vec2::new(200., -100.)),
+];
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+
+    let (color, position) = LEVELS[0];
+    commands.spawn((
+        SpriteBundle {
+            transform: Transform::from_translation(position.extend(0.)),
+            sprite: Sprite {
+                color,
+                custom_size: Some(Vec2::splat(160.)),
+                ..default()
+            },
+            ..default()
+        },
+        Level(0),
+    ));
+}
+
+/// Press the space bar to fade out, swap the level, and fade back in.
+fn start_fade(mut commands: Commands, keyboard_input: Res<ButtonInput<KeyCode>>) {
+    if keyboard_input.just_pressed(KeyCode::Space) {
+        ScreenFade::out(Duration::from_millis(400))
+            .then_run(SWAP_LEVEL)
+            .then_in(Duration::from_millis(400))
+            .start(&mut commands);
+    }
+}
+
+/// Swap the level while the screen is fully covered.
+fn swap_level(
+    mut reader: EventReader<TweenCompleted>,
+    mut query: Query<(&mut Level, &mut Sprite, &mut Transform)>,
+) {
+    for ev in reader.read() {
+        if ev.user_data != SWAP_LEVEL {
+            continue;
+        }
+        let (mut level, mut sprite, mut transform) = query.single_mut();
+        level.0 = (level.0 + 1) % LEVELS.len();
+        let (color, position) = LEVELS[level.0];
+        sprite.color = color;
+        transform.translation = position.extend(0.);
+    }
+}
@@ -16,7 +16,7 @@ fn main() {
             ..default()
         }))
         .add_systems(Update, bevy::window::close_on_esc)
-        .add_plugins(TweeningPlugin)
+        .add_plugins(TweeningPlugin::default())
         .add_systems(Startup, setup)
         .add_systems(Update, update_text)
         .run();
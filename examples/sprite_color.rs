@@ -13,7 +13,7 @@ fn main() {
             ..default()
         }))
         .add_systems(Update, bevy::window::close_on_esc)
-        .add_plugins(TweeningPlugin)
+        .add_plugins(TweeningPlugin::default())
         .add_systems(Startup, setup)
         .run();
 }
@@ -64,10 +64,7 @@ fn setup(mut commands: Commands) {
         let tween = Tween::new(
             *ease_function,
             std::time::Duration::from_secs(1),
-            SpriteColorLens {
-                start: Color::RED,
-                end: Color::BLUE,
-            },
+            SpriteColorLens::new(Color::RED, Color::BLUE),
         )
         .with_repeat_count(RepeatCount::Infinite)
         .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
@@ -16,7 +16,7 @@ fn main() {
             ..default()
         }))
         .add_systems(Update, bevy::window::close_on_esc)
-        .add_plugins(TweeningPlugin)
+        .add_plugins(TweeningPlugin::default())
         .add_systems(Startup, setup)
         .run();
 }
@@ -71,11 +71,7 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
         let tween = Tween::new(
             *ease_function,
             std::time::Duration::from_secs(1),
-            TextColorLens {
-                start: Color::RED,
-                end: Color::BLUE,
-                section: 0,
-            },
+            TextColorLens::new(Color::RED, Color::BLUE, 0),
         )
         .with_repeat_count(RepeatCount::Infinite)
         .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
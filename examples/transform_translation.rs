@@ -16,7 +16,7 @@ fn main() {
         }))
         .init_resource::<Options>()
         .add_systems(Update, bevy::window::close_on_esc)
-        .add_plugins(TweeningPlugin)
+        .add_plugins(TweeningPlugin::default())
         .add_plugins(ResourceInspectorPlugin::<Options>::default())
         .add_systems(Startup, setup)
         .add_systems(Update, update_animation_speed)
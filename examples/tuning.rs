@@ -0,0 +1,158 @@
+use std::{path::PathBuf, time::Duration, time::SystemTime};
+
+use bevy::prelude::*;
+use bevy_tweening::{lens::*, *};
+
+/// File polled for changes, relative to the crate root.
+const TUNING_FILE: &str = "assets/tuning.ron";
+
+fn main() {
+    App::default()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "Tuning".to_string(),
+                resolution: (800., 400.).into(),
+                present_mode: bevy::window::PresentMode::Fifo, // vsync
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_systems(Update, bevy::window::close_on_esc)
+        .add_plugins(TweeningPlugin::default())
+        .init_resource::<TweenTuning>()
+        .insert_resource(TuningFile {
+            path: PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(TUNING_FILE),
+            modified: None,
+            timer: Timer::from_seconds(0.5, TimerMode::Repeating),
+        })
+        .add_systems(Startup, setup)
+        .add_systems(Update, reload_tuning)
+        .run();
+}
+
+/// Tuning file, and the time it was last modified at when reloaded.
+#[derive(Resource)]
+struct TuningFile {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    timer: Timer,
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+
+    // Sliding sprite, tuned by the "slide" entry
+    let slide = Tween::new(
+        EaseFunction::QuadraticInOut,
+        Duration::from_secs(1),
+        TransformPositionLens {
+            start: Vec3::new(-300., 80., 0.),
+            end: Vec3::new(300., 80., 0.),
+        },
+    )
+    .with_repeat_count(RepeatCount::Infinite)
+    .with_repeat_strategy(RepeatStrategy::MirroredRepeat)
+    .with_tuning_key("slide");
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::RED,
+                custom_size: Some(Vec2::splat(60.)),
+                ..default()
+            },
+            ..default()
+        },
+        Animator::new(slide),
+    ));
+
+    // Pulsing sprite, tuned by the "pulse" entry
+    let pulse = Tween::new(
+        EaseFunction::SineInOut,
+        Duration::from_secs(1),
+        TransformScaleLens {
+            start: Vec3::ONE,
+            end: Vec3::splat(1.5),
+        },
+    )
+    .with_repeat_count(RepeatCount::Infinite)
+    .with_repeat_strategy(RepeatStrategy::MirroredRepeat)
+    .with_tuning_key("pulse");
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::BLUE,
+                custom_size: Some(Vec2::splat(60.)),
+                ..default()
+            },
+            transform: Transform::from_xyz(0., -80., 0.),
+            ..default()
+        },
+        Animator::new(pulse),
+    ));
+}
+
+/// Poll the tuning file, and reload the [`TweenTuning`] each time it's saved.
+fn reload_tuning(time: Res<Time>, mut file: ResMut<TuningFile>, mut tuning: ResMut<TweenTuning>) {
+    if !file.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+    let Ok(modified) = std::fs::metadata(&file.path).and_then(|meta| meta.modified()) else {
+        return;
+    };
+    if file.modified == Some(modified) {
+        return;
+    }
+    file.modified = Some(modified);
+
+    match std::fs::read_to_string(&file.path)
+        .map_err(|err| err.to_string())
+        .and_then(|content| parse_tuning(&content))
+    {
+        Ok(new_tuning) => {
+            info!("Reloaded {}", file.path.display());
+            // Replacing the content marks the resource as changed, which
+            // retunes all the keyed tweens.
+            *tuning = new_tuning;
+        }
+        Err(err) => warn!("Failed to load {}: {}", file.path.display(), err),
+    }
+}
+
+/// Parse a map of tuning keys to `(duration: <seconds>, ease: "<name>")`
+/// entries, where both fields are optional.
+fn parse_tuning(content: &str) -> Result<TweenTuning, String> {
+    let value: ron::Value = ron::from_str(content).map_err(|err| err.to_string())?;
+    let ron::Value::Map(entries) = value else {
+        return Err("expected a map of tuning entries".to_string());
+    };
+
+    let mut tuning = TweenTuning::new();
+    for (key, fields) in entries.iter() {
+        let ron::Value::String(key) = key else {
+            return Err(format!("invalid key {key:?}"));
+        };
+        let ron::Value::Map(fields) = fields else {
+            return Err(format!("invalid entry for '{key}'"));
+        };
+
+        let mut entry = TweenTuningEntry::default();
+        for (field, value) in fields.iter() {
+            match (field, value) {
+                (ron::Value::String(field), ron::Value::Number(secs)) if field == "duration" => {
+                    entry.duration = Some(Duration::from_secs_f64(secs.into_f64().max(0.)));
+                }
+                (ron::Value::String(field), ron::Value::String(name)) if field == "ease" => {
+                    entry.ease = Some(
+                        EaseMethod::from_name(name)
+                            .ok_or_else(|| format!("unknown easing '{name}' for '{key}'"))?,
+                    );
+                }
+                _ => return Err(format!("invalid field {field:?} for '{key}'")),
+            }
+        }
+        tuning.insert(key.clone(), entry);
+    }
+    Ok(tuning)
+}
@@ -0,0 +1,66 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+use bevy_tweening::{lens::*, *};
+use std::time::Duration;
+
+/// Size of the window in normal mode.
+const NORMAL_SIZE: Vec2 = Vec2::new(800., 600.);
+
+/// Size of the window in picture-in-picture mode.
+const PIP_SIZE: Vec2 = Vec2::new(320., 180.);
+
+fn main() {
+    App::default()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "WindowResize".to_string(),
+                resolution: NORMAL_SIZE.into(),
+                present_mode: bevy::window::PresentMode::Fifo, // vsync
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_systems(Update, bevy::window::close_on_esc)
+        .add_plugins(TweeningPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, toggle_pip)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle {
+        camera: Camera {
+            clear_color: ClearColorConfig::Custom(Color::TEAL),
+            ..default()
+        },
+        ..default()
+    });
+}
+
+/// Press the space bar to smoothly resize the window into picture-in-picture
+/// mode, and back.
+fn toggle_pip(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    window: Query<(Entity, &Window), With<PrimaryWindow>>,
+    mut pip: Local<bool>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::Space) {
+        return;
+    }
+    let Ok((entity, window)) = window.get_single() else {
+        return;
+    };
+    *pip = !*pip;
+    let end = if *pip { PIP_SIZE } else { NORMAL_SIZE };
+
+    // Start from the current size, to toggle again in the middle of a resize
+    let tween = Tween::new(
+        EaseFunction::QuadraticInOut,
+        Duration::from_secs(1),
+        WindowResolutionLens {
+            start: Vec2::new(window.resolution.width(), window.resolution.height()),
+            end,
+        },
+    );
+    commands.entity(entity).insert(Animator::new(tween));
+}
@@ -2,9 +2,13 @@
 echo Run all examples
 REM Default
 cargo r --example menu --no-default-features --features="bevy_ui bevy_text bevy/bevy_winit"
+cargo r --example button_states --no-default-features --features="bevy_ui bevy_text bevy/bevy_winit"
 cargo r --example transform_translation --no-default-features --features="bevy_sprite bevy/bevy_winit"
 cargo r --example transform_rotation --no-default-features --features="bevy_sprite bevy/bevy_winit"
 cargo r --example sequence --no-default-features --features="bevy_sprite bevy_text bevy/bevy_winit"
+cargo r --example determinism --no-default-features
+REM bevy_window
+cargo r --example window_resize --no-default-features --features="bevy_window bevy_sprite bevy/bevy_winit"
 REM bevy_sprite
 cargo r --example sprite_color --no-default-features --features="bevy_sprite bevy/bevy_winit"
 REM bevy_ui
@@ -0,0 +1,267 @@
+//! Side effects applied some time after an animator completed.
+
+use std::time::Duration;
+
+use bevy::{ecs::system::EntityCommands, prelude::*};
+
+use crate::plugin::CompletionTracking;
+
+/// Command applied to the entity of an animator by a [`CompletedAction`].
+type InsertFn = Box<dyn Fn(&mut EntityCommands) + Send + Sync>;
+
+/// Effect of a [`CompletedAction`].
+enum CompletedEffect {
+    /// Despawn the entity and its descendants.
+    Despawn,
+    /// Insert a bundle produced by a factory.
+    Insert(InsertFn),
+}
+
+/// Side effect applied to the entity of an animator once its tweenable has
+/// been completed for some time.
+pub(crate) struct CompletedAction {
+    delay: Duration,
+    effect: CompletedEffect,
+    /// Completion time of the tweenable the action was last applied for.
+    applied_for: Option<Duration>,
+}
+
+impl CompletedAction {
+    pub(crate) fn despawn(delay: Duration) -> Self {
+        Self {
+            delay,
+            effect: CompletedEffect::Despawn,
+            applied_for: None,
+        }
+    }
+
+    pub(crate) fn insert<B: Bundle>(
+        delay: Duration,
+        factory: impl Fn() -> B + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            delay,
+            effect: CompletedEffect::Insert(Box::new(move |commands| {
+                commands.try_insert(factory());
+            })),
+            applied_for: None,
+        }
+    }
+
+    /// Apply the action to `entity` if the tweenable completed at
+    /// `completed_at` has been completed for at least the delay of the action
+    /// at time `now`, unless already done for that completion.
+    pub(crate) fn apply(
+        &mut self,
+        completed_at: Duration,
+        now: Duration,
+        commands: &mut Commands,
+        entity: Entity,
+    ) {
+        if self.applied_for == Some(completed_at) || now.saturating_sub(completed_at) < self.delay {
+            return;
+        }
+        self.applied_for = Some(completed_at);
+        let Some(mut entity) = commands.get_entity(entity) else {
+            return;
+        };
+        match &self.effect {
+            CompletedEffect::Despawn => entity.despawn_recursive(),
+            CompletedEffect::Insert(insert) => insert(&mut entity),
+        }
+    }
+}
+
+/// System applying the [`CompletedAction`]s of the animators of type `A`
+/// which are due.
+///
+/// Actions are only applied while the animator remains completed, so
+/// replacing its tweenable, stopping it, or removing it before the delay
+/// elapsed aborts them.
+pub(crate) fn completed_actions_system<A: CompletionTracking>(
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut A)>,
+    mut commands: Commands,
+) {
+    let now = time.elapsed();
+    for (entity, mut animator) in &mut query {
+        if animator.has_completed_actions() {
+            animator
+                .bypass_change_detection()
+                .apply_completed_actions(now, &mut commands, entity);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        lens::TransformPositionLens, AnimationSystem, Animator, EaseMethod, Tween, TweenCompleted,
+        TweeningPlugin,
+    };
+
+    #[derive(Component)]
+    struct Collider;
+
+    fn make_tween() -> Tween<Transform> {
+        Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+    }
+
+    fn make_animator() -> Animator<Transform> {
+        Animator::new(make_tween())
+    }
+
+    fn make_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register::<Transform>(),
+        )
+        .init_resource::<Time>();
+        app
+    }
+
+    fn tick(app: &mut App, millis: u64) {
+        app.world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(millis));
+        app.update();
+    }
+
+    #[test]
+    fn despawn_after() {
+        let mut app = make_app();
+        let entity = app
+            .world
+            .spawn((
+                Transform::default(),
+                make_animator().with_completed_despawn_after(Duration::from_millis(500)),
+            ))
+            .with_children(|parent| {
+                parent.spawn(Transform::default());
+            })
+            .id();
+        let child = app.world.get::<Children>(entity).unwrap()[0];
+
+        tick(&mut app, 1000);
+        assert!(app.world.get_entity(entity).is_some());
+        tick(&mut app, 400);
+        assert!(app.world.get_entity(entity).is_some());
+        tick(&mut app, 100);
+        assert!(app.world.get_entity(entity).is_none());
+        assert!(app.world.get_entity(child).is_none());
+    }
+
+    #[test]
+    fn insert_after() {
+        let mut app = make_app();
+        let entity = app
+            .world
+            .spawn((
+                Transform::default(),
+                make_animator()
+                    .with_completed_insert_after(Duration::ZERO, || Collider)
+                    .with_completed_insert_after(Duration::from_millis(200), || {
+                        Name::new("settled")
+                    }),
+            ))
+            .id();
+
+        tick(&mut app, 500);
+        assert!(app.world.get::<Collider>(entity).is_none());
+
+        // A zero delay applies on the frame the tweenable completes
+        tick(&mut app, 500);
+        assert!(app.world.get::<Collider>(entity).is_some());
+        assert!(app.world.get::<Name>(entity).is_none());
+        tick(&mut app, 200);
+        assert!(app.world.get::<Name>(entity).is_some());
+
+        // Actions are applied once per completion...
+        app.world.entity_mut(entity).remove::<(Collider, Name)>();
+        tick(&mut app, 500);
+        assert!(app.world.get::<Collider>(entity).is_none());
+
+        // ...and again when the tweenable is replayed
+        app.world
+            .get_mut::<Animator<Transform>>(entity)
+            .unwrap()
+            .tweenable_mut()
+            .rewind();
+        tick(&mut app, 0);
+        assert!(app.world.get::<Collider>(entity).is_none());
+        tick(&mut app, 1000);
+        assert!(app.world.get::<Collider>(entity).is_some());
+    }
+
+    #[test]
+    fn cancelled_before_delay() {
+        let mut app = make_app();
+        let spawn = |app: &mut App| {
+            app.world
+                .spawn((
+                    Transform::default(),
+                    make_animator().with_completed_despawn_after(Duration::from_millis(500)),
+                ))
+                .id()
+        };
+        let stopped = spawn(&mut app);
+        let replaced = spawn(&mut app);
+        let removed = spawn(&mut app);
+        tick(&mut app, 1000);
+
+        app.world
+            .get_mut::<Animator<Transform>>(stopped)
+            .unwrap()
+            .stop();
+        app.world
+            .get_mut::<Animator<Transform>>(replaced)
+            .unwrap()
+            .set_tweenable(make_tween());
+        app.world
+            .entity_mut(removed)
+            .remove::<Animator<Transform>>();
+        tick(&mut app, 600);
+        for entity in [stopped, replaced, removed] {
+            assert!(app.world.get_entity(entity).is_some());
+        }
+    }
+
+    #[test]
+    fn insert_after_despawned() {
+        let mut app = make_app();
+        // Gameplay code despawning the entity on the frame its tweenable
+        // completes, with commands applied in the same batch as the insertion
+        app.add_systems(
+            Update,
+            (|mut commands: Commands, mut reader: EventReader<TweenCompleted>| {
+                for ev in reader.read() {
+                    commands.entity(ev.entity).despawn();
+                }
+            })
+            .after(AnimationSystem::AnimationUpdate)
+            .before_ignore_deferred(completed_actions_system::<Animator<Transform>>),
+        );
+        let entity = app
+            .world
+            .spawn((
+                Transform::default(),
+                Animator::new(make_tween().with_completed_event(0))
+                    .with_completed_insert_after(Duration::ZERO, || Collider),
+            ))
+            .id();
+
+        tick(&mut app, 1000);
+        assert!(app.world.get_entity(entity).is_none());
+        tick(&mut app, 1000);
+    }
+}
@@ -0,0 +1,447 @@
+//! Conversion of Bevy [`AnimationClip`]s into tweenables.
+//!
+//! This module is only available with the `bevy_animation` feature.
+
+use std::time::Duration;
+
+use bevy::{
+    animation::{AnimationClip, EntityPath, Interpolation, Keyframes, VariableCurve},
+    prelude::*,
+};
+
+use crate::{lens::Lens, BoxedTweenable, EaseMethod, Sequence, Tracks, Tween};
+
+/// Error returned by [`from_animation_clip()`] when a clip can't be converted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnimationClipError {
+    /// The clip has no curve for the target path.
+    PathNotFound(EntityPath),
+    /// The clip has curves for the target path, but none of them animates a
+    /// [`Transform`].
+    NoTransformCurve,
+    /// The curve at this index in the curves of the target path is invalid.
+    /// Its timestamps are empty, not finite, or not sorted, or its number of
+    /// keyframes doesn't match its number of timestamps.
+    InvalidCurve(usize),
+}
+
+impl std::fmt::Display for AnimationClipError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PathNotFound(path) => write!(f, "no curve for target path {:?}", path.parts),
+            Self::NoTransformCurve => write!(f, "no transform curve for target path"),
+            Self::InvalidCurve(index) => write!(f, "invalid curve #{index}"),
+        }
+    }
+}
+
+impl std::error::Error for AnimationClipError {}
+
+/// Convert the [`Transform`] curves of an [`AnimationClip`] for the entity at
+/// `target_path` into a tweenable.
+///
+/// Each translation, rotation, and scale curve becomes a [`Sequence`] with a
+/// [`Tween`] per pair of consecutive keyframes, and the curves are combined
+/// into [`Tracks`], returned as the single child of a [`Sequence`] so more
+/// tweenables can be chained after it. The segments reproduce the
+/// interpolation of the curve:
+/// - [`Interpolation::Step`] holds the value of a keyframe until the next one;
+/// - [`Interpolation::Linear`] interpolates linearly, with a spherical
+///   interpolation along the shortest path for rotations;
+/// - [`Interpolation::CubicSpline`] follows the cubic Hermite spline of the
+///   keyframes and their tangents.
+///
+/// A curve whose first keyframe isn't at the start of the clip holds the value
+/// of that keyframe until then, and a curve with a single keyframe applies it
+/// instantly. Morph weight curves can't be applied to a [`Transform`], and are
+/// skipped with a warning.
+///
+/// Unlike the [`AnimationPlayer`], the tweenable always applies the value of
+/// the last keyframe of each curve once it reaches it, and plays the clip
+/// once.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::{animation::{AnimationClip, EntityPath}, prelude::*};
+/// # use bevy_tweening::*;
+/// fn clip_animator(clip: &AnimationClip) -> Option<Animator<Transform>> {
+///     let path = EntityPath {
+///         parts: vec![Name::new("root"), Name::new("arm")],
+///     };
+///     let sequence = from_animation_clip(clip, &path).ok()?;
+///     Some(Animator::new(sequence))
+/// }
+/// ```
+///
+/// [`AnimationPlayer`]: bevy::animation::AnimationPlayer
+pub fn from_animation_clip(
+    clip: &AnimationClip,
+    target_path: &EntityPath,
+) -> Result<Sequence<Transform>, AnimationClipError> {
+    let curves = clip
+        .get_curves_by_path(target_path)
+        .ok_or_else(|| AnimationClipError::PathNotFound(target_path.clone()))?;
+    let mut tracks = vec![];
+    for (index, curve) in curves.iter().enumerate() {
+        let Some(channel) = Channel::of(&curve.keyframes) else {
+            warn!(
+                "Skipping morph weights curve #{index} of {:?}, which can't animate a Transform.",
+                target_path.parts
+            );
+            continue;
+        };
+        let segments =
+            curve_segments(curve, channel).ok_or(AnimationClipError::InvalidCurve(index))?;
+        tracks.push(Sequence::new(segments));
+    }
+    let tracks = Tracks::try_new(tracks).map_err(|_| AnimationClipError::NoTransformCurve)?;
+    Ok(Sequence::from_single(tracks))
+}
+
+/// Build the tweens of each segment of a curve, or return `None` if the curve
+/// is invalid.
+fn curve_segments(
+    curve: &VariableCurve,
+    channel: Channel,
+) -> Option<Vec<BoxedTweenable<Transform>>> {
+    let timestamps = &curve.keyframe_timestamps;
+    let valid_timestamps = !timestamps.is_empty()
+        && timestamps.iter().all(|t| t.is_finite() && *t >= 0.)
+        && timestamps.windows(2).all(|t| t[0] <= t[1]);
+    let keyframes = channel.keyframes(&curve.keyframes);
+    let stride = match curve.interpolation {
+        Interpolation::CubicSpline => 3,
+        Interpolation::Step | Interpolation::Linear => 1,
+    };
+    if !valid_timestamps || keyframes.len() != timestamps.len() * stride {
+        return None;
+    }
+    // Value of a keyframe, and for cubic splines its in and out tangents
+    let value = |index: usize| match curve.interpolation {
+        Interpolation::CubicSpline => keyframes[index * 3 + 1],
+        Interpolation::Step | Interpolation::Linear => keyframes[index],
+    };
+    let hold = |duration: Duration, value: Vec4| -> BoxedTweenable<Transform> {
+        let lens = SegmentLens {
+            channel,
+            start: value,
+            end: value,
+            shape: SegmentShape::Linear,
+        };
+        Box::new(Tween::new(EaseMethod::Linear, duration, lens))
+    };
+
+    let times: Vec<_> = timestamps
+        .iter()
+        .map(|t| Duration::from_secs_f32(*t))
+        .collect();
+    let mut segments = vec![];
+    if times.len() == 1 {
+        segments.push(hold(Duration::ZERO, value(0)));
+    } else if !times[0].is_zero() {
+        segments.push(hold(times[0], value(0)));
+    }
+    for index in 0..times.len() - 1 {
+        let duration = times[index + 1] - times[index];
+        let shape = match curve.interpolation {
+            Interpolation::Step => SegmentShape::Step,
+            Interpolation::Linear => SegmentShape::Linear,
+            Interpolation::CubicSpline => {
+                // The tangents are per second, while the spline is evaluated
+                // over the normalized segment
+                let secs = timestamps[index + 1] - timestamps[index];
+                SegmentShape::Cubic {
+                    out_tangent: keyframes[index * 3 + 2] * secs,
+                    in_tangent: keyframes[(index + 1) * 3] * secs,
+                }
+            }
+        };
+        let lens = SegmentLens {
+            channel,
+            start: value(index),
+            end: value(index + 1),
+            shape,
+        };
+        segments.push(Box::new(Tween::new(EaseMethod::Linear, duration, lens)));
+    }
+    Some(segments)
+}
+
+/// Component of a [`Transform`] animated by a curve.
+#[derive(Debug, Clone, Copy)]
+enum Channel {
+    Translation,
+    Rotation,
+    Scale,
+}
+
+impl Channel {
+    fn of(keyframes: &Keyframes) -> Option<Self> {
+        match keyframes {
+            Keyframes::Translation(_) => Some(Self::Translation),
+            Keyframes::Rotation(_) => Some(Self::Rotation),
+            Keyframes::Scale(_) => Some(Self::Scale),
+            Keyframes::Weights(_) => None,
+        }
+    }
+
+    /// Keyframes of the channel, with rotations as their `(x, y, z, w)`
+    /// components and vectors extended with a zero `w`.
+    fn keyframes(self, keyframes: &Keyframes) -> Vec<Vec4> {
+        match keyframes {
+            Keyframes::Translation(values) | Keyframes::Scale(values) => {
+                values.iter().map(|v| v.extend(0.)).collect()
+            }
+            Keyframes::Rotation(values) => values.iter().map(|q| Vec4::from(*q)).collect(),
+            Keyframes::Weights(_) => vec![],
+        }
+    }
+}
+
+/// Interpolation between the two keyframes of a segment.
+#[derive(Debug, Clone, Copy)]
+enum SegmentShape {
+    Step,
+    Linear,
+    /// Cubic Hermite spline, with tangents scaled by the segment duration.
+    Cubic {
+        out_tangent: Vec4,
+        in_tangent: Vec4,
+    },
+}
+
+/// Lens animating a [`Transform`] channel between two keyframes of a curve.
+#[derive(Debug, Clone, Copy)]
+struct SegmentLens {
+    channel: Channel,
+    start: Vec4,
+    end: Vec4,
+    shape: SegmentShape,
+}
+
+impl Lens<Transform> for SegmentLens {
+    fn lerp(&mut self, target: &mut Transform, ratio: f32) {
+        let value = match self.shape {
+            // Reach the next keyframe at the end of the segment, so the last
+            // keyframe of the curve is applied once completed
+            SegmentShape::Step if ratio >= 1. => self.end,
+            SegmentShape::Step => self.start,
+            SegmentShape::Linear => {
+                if let Channel::Rotation = self.channel {
+                    let start = Quat::from_vec4(self.start).normalize();
+                    let mut end = Quat::from_vec4(self.end).normalize();
+                    if start.dot(end) < 0. {
+                        end = -end;
+                    }
+                    target.rotation = start.slerp(end, ratio);
+                    return;
+                }
+                self.start.lerp(self.end, ratio)
+            }
+            SegmentShape::Cubic {
+                out_tangent,
+                in_tangent,
+            } => {
+                let t2 = ratio * ratio;
+                let t3 = t2 * ratio;
+                self.start * (2. * t3 - 3. * t2 + 1.)
+                    + out_tangent * (t3 - 2. * t2 + ratio)
+                    + self.end * (-2. * t3 + 3. * t2)
+                    + in_tangent * (t3 - t2)
+            }
+        };
+        match self.channel {
+            Channel::Translation => target.translation = value.truncate(),
+            Channel::Rotation => target.rotation = Quat::from_vec4(value).normalize(),
+            Channel::Scale => target.scale = value.truncate(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{
+        animation::{animation_player, AnimationPlayer},
+        ecs::system::RunSystemOnce,
+    };
+
+    use super::*;
+    use crate::{tweenable::ComponentTarget, Tweenable};
+
+    fn path() -> EntityPath {
+        EntityPath {
+            parts: vec![Name::new("root")],
+        }
+    }
+
+    fn make_clip() -> AnimationClip {
+        let mut clip = AnimationClip::default();
+        clip.add_curve_to_path(
+            path(),
+            VariableCurve {
+                keyframe_timestamps: vec![0.25, 0.5, 1.5],
+                keyframes: Keyframes::Translation(vec![
+                    Vec3::ZERO,
+                    Vec3::X,
+                    Vec3::new(3., 2., -1.),
+                ]),
+                interpolation: Interpolation::Linear,
+            },
+        );
+        let quarter = Quat::from_rotation_y(std::f32::consts::FRAC_PI_2);
+        let half = Quat::from_rotation_y(std::f32::consts::PI);
+        clip.add_curve_to_path(
+            path(),
+            VariableCurve {
+                keyframe_timestamps: vec![0., 1., 2.],
+                keyframes: Keyframes::Rotation(vec![
+                    Quat::IDENTITY,
+                    Quat::IDENTITY,
+                    Quat::from_xyzw(0., 0.5, 0., 0.),
+                    Quat::from_xyzw(0., -0.3, 0., 0.1),
+                    quarter,
+                    Quat::from_xyzw(0., 0.2, 0., 0.2),
+                    Quat::from_xyzw(0., 0.1, 0., 0.),
+                    half,
+                    Quat::IDENTITY,
+                ]),
+                interpolation: Interpolation::CubicSpline,
+            },
+        );
+        clip.add_curve_to_path(
+            path(),
+            VariableCurve {
+                keyframe_timestamps: vec![0., 0.8, 1.2],
+                keyframes: Keyframes::Scale(vec![Vec3::ONE, Vec3::splat(2.), Vec3::splat(0.5)]),
+                interpolation: Interpolation::Step,
+            },
+        );
+        clip.add_curve_to_path(
+            path(),
+            VariableCurve {
+                keyframe_timestamps: vec![0., 1.],
+                keyframes: Keyframes::Weights(vec![0., 1.]),
+                interpolation: Interpolation::Linear,
+            },
+        );
+        clip
+    }
+
+    /// Evaluate the clip at `secs` with the animation player of Bevy.
+    fn evaluate_clip(clip: &AnimationClip, secs: f32) -> Transform {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let mut clips = Assets::<AnimationClip>::default();
+        let handle = clips.add(clip.clone());
+        world.insert_resource(clips);
+        let mut player = AnimationPlayer::default();
+        player.play(handle).seek_to(secs);
+        let entity = world
+            .spawn((Name::new("root"), Transform::default(), player))
+            .id();
+        world.run_system_once(animation_player);
+        *world.get::<Transform>(entity).unwrap()
+    }
+
+    fn evaluate_tweenable(tweenable: &mut dyn Tweenable<Transform>, secs: f32) -> Transform {
+        let mut world = World::new();
+        let entity = world.spawn(Transform::default()).id();
+        tweenable.set_elapsed(Duration::from_secs_f32(secs));
+        let transform = world.get_mut::<Transform>(entity).unwrap();
+        let mut target = ComponentTarget::new(transform);
+        tweenable.tick(Duration::ZERO, &mut target, entity, &mut vec![]);
+        *world.get::<Transform>(entity).unwrap()
+    }
+
+    #[test]
+    fn animation_clip_matches_player() {
+        let clip = make_clip();
+        let mut sequence = from_animation_clip(&clip, &path()).unwrap();
+        assert_eq!(sequence.duration(), Duration::from_secs(2));
+
+        // Sample where all the curves are active, as the player doesn't apply
+        // a curve outside of its keyframes
+        for secs in [0.3, 0.45, 0.5, 0.7, 0.8, 0.9, 1.1, 1.15] {
+            let expected = evaluate_clip(&clip, secs);
+            let actual = evaluate_tweenable(&mut sequence, secs);
+            assert!(
+                actual.translation.abs_diff_eq(expected.translation, 1e-4),
+                "translation at {secs}: {actual:?} != {expected:?}"
+            );
+            assert!(
+                actual.rotation.abs_diff_eq(expected.rotation, 1e-4),
+                "rotation at {secs}: {actual:?} != {expected:?}"
+            );
+            assert!(
+                actual.scale.abs_diff_eq(expected.scale, 1e-4),
+                "scale at {secs}: {actual:?} != {expected:?}"
+            );
+        }
+
+        // The first keyframe is held until it starts, and the last ones are
+        // applied at the end
+        let start = evaluate_tweenable(&mut sequence, 0.1);
+        assert_eq!(start.translation, Vec3::ZERO);
+        let end = evaluate_tweenable(&mut sequence, 2.);
+        assert_eq!(end.translation, Vec3::new(3., 2., -1.));
+        assert!(end
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_y(std::f32::consts::PI), 1e-5));
+        assert_eq!(end.scale, Vec3::splat(0.5));
+    }
+
+    #[test]
+    fn animation_clip_errors() {
+        let clip = make_clip();
+        let other = EntityPath {
+            parts: vec![Name::new("other")],
+        };
+        assert_eq!(
+            from_animation_clip(&clip, &other).unwrap_err(),
+            AnimationClipError::PathNotFound(other)
+        );
+
+        let mut clip = AnimationClip::default();
+        clip.add_curve_to_path(
+            path(),
+            VariableCurve {
+                keyframe_timestamps: vec![0., 1.],
+                keyframes: Keyframes::Weights(vec![0., 1.]),
+                interpolation: Interpolation::Linear,
+            },
+        );
+        assert_eq!(
+            from_animation_clip(&clip, &path()).unwrap_err(),
+            AnimationClipError::NoTransformCurve
+        );
+
+        clip.add_curve_to_path(
+            path(),
+            VariableCurve {
+                keyframe_timestamps: vec![1., 0.],
+                keyframes: Keyframes::Scale(vec![Vec3::ONE, Vec3::ZERO]),
+                interpolation: Interpolation::Linear,
+            },
+        );
+        assert_eq!(
+            from_animation_clip(&clip, &path()).unwrap_err(),
+            AnimationClipError::InvalidCurve(1)
+        );
+
+        // A single keyframe is applied instantly
+        let mut clip = AnimationClip::default();
+        clip.add_curve_to_path(
+            path(),
+            VariableCurve {
+                keyframe_timestamps: vec![0.],
+                keyframes: Keyframes::Scale(vec![Vec3::splat(3.)]),
+                interpolation: Interpolation::Linear,
+            },
+        );
+        let mut sequence = from_animation_clip(&clip, &path()).unwrap();
+        assert_eq!(sequence.duration(), Duration::ZERO);
+        let transform = evaluate_tweenable(&mut sequence, 0.);
+        assert_eq!(transform.scale, Vec3::splat(3.));
+    }
+}
@@ -0,0 +1,355 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{
+    tweenable::{BoxedLens, CompletedCallback},
+    CompletedBehavior, EaseMethod, Lens, RepeatCount, RepeatSpec, RepeatStrategy, Tween,
+    TweeningDirection,
+};
+
+/// Error returned by [`TweenBuilder::build()`] when the tween configuration is
+/// invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TweenBuildError {
+    /// No lens was provided with [`TweenBuilder::with_lens()`].
+    MissingLens,
+    /// No duration was provided with [`TweenBuilder::with_duration()`].
+    MissingDuration,
+    /// The duration of a single iteration is zero.
+    ZeroDuration,
+    /// The repeat count is zero, so the tween would never play.
+    ZeroRepeatCount,
+    /// A [`RepeatStrategy`] was set, but the tween doesn't repeat, so the
+    /// strategy would never be used.
+    RepeatStrategyWithoutRepeat,
+    /// A [`CompletedBehavior`] other than the default was set, but the tween
+    /// repeats infinitely, so never completes.
+    CompletedBehaviorWithInfiniteRepeat,
+}
+
+impl std::fmt::Display for TweenBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingLens => write!(f, "the tween has no lens"),
+            Self::MissingDuration => write!(f, "the tween has no duration"),
+            Self::ZeroDuration => write!(f, "the tween duration is zero"),
+            Self::ZeroRepeatCount => {
+                write!(f, "the tween repeat count is zero, so it would never play")
+            }
+            Self::RepeatStrategyWithoutRepeat => write!(
+                f,
+                "the tween has a repeat strategy but is not repeating; set a repeat count greater than one"
+            ),
+            Self::CompletedBehaviorWithInfiniteRepeat => write!(
+                f,
+                "the tween has a completed behavior but repeats infinitely, so never completes"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TweenBuildError {}
+
+/// Builder for a [`Tween`], validating its configuration.
+///
+/// Unlike [`Tween::new()`] and the `with_*()` methods of [`Tween`], which
+/// accept any combination of options, the builder checks for incompatible or
+/// meaningless combinations when calling [`build()`], and reports them as a
+/// [`TweenBuildError`]. This is the recommended way to construct a tween from
+/// data files or scripting layers, where invalid values are expected and need
+/// to be reported instead of producing an unexpected animation.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_tweening::{lens::*, *};
+/// # use bevy::prelude::*;
+/// # use std::time::Duration;
+/// let tween: Tween<Transform> = Tween::builder()
+///     .with_ease(EaseFunction::QuadraticInOut)
+///     .with_duration(Duration::from_secs(1))
+///     .with_lens(TransformPositionLens {
+///         start: Vec3::ZERO,
+///         end: Vec3::new(3.5, 0., 0.),
+///     })
+///     .with_repeat_count(3)
+///     .with_repeat_strategy(RepeatStrategy::MirroredRepeat)
+///     .build()
+///     .unwrap();
+///
+/// let err = Tween::<Transform>::builder()
+///     .with_duration(Duration::from_secs(1))
+///     .build()
+///     .err().unwrap();
+/// assert_eq!(err, TweenBuildError::MissingLens);
+/// ```
+///
+/// [`build()`]: TweenBuilder::build
+pub struct TweenBuilder<T> {
+    ease_method: EaseMethod,
+    duration: Option<Duration>,
+    lens: Option<BoxedLens<T>>,
+    repeat_count: RepeatCount,
+    repeat_strategy: Option<RepeatStrategy>,
+    direction: TweeningDirection,
+    completed_behavior: CompletedBehavior,
+    event_data: Option<u64>,
+    on_completed: Option<Box<CompletedCallback<Tween<T>>>>,
+}
+
+impl<T> Default for TweenBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> TweenBuilder<T> {
+    /// Create a new builder with default options.
+    ///
+    /// The lens and the duration have no default, and must be set before
+    /// calling [`build()`].
+    ///
+    /// [`build()`]: TweenBuilder::build
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            ease_method: EaseMethod::default(),
+            duration: None,
+            lens: None,
+            repeat_count: RepeatCount::default(),
+            repeat_strategy: None,
+            direction: TweeningDirection::default(),
+            completed_behavior: CompletedBehavior::default(),
+            event_data: None,
+            on_completed: None,
+        }
+    }
+
+    /// Set the easing method. Defaults to [`EaseMethod::Linear`].
+    #[must_use]
+    pub fn with_ease(mut self, ease_method: impl Into<EaseMethod>) -> Self {
+        self.ease_method = ease_method.into();
+        self
+    }
+
+    /// Set the duration of a single iteration of the animation.
+    #[must_use]
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Set the lens animating the target.
+    #[must_use]
+    pub fn with_lens<L>(mut self, lens: L) -> Self
+    where
+        L: Lens<T> + Send + Sync + 'static,
+    {
+        self.lens = Some(Box::new(lens));
+        self
+    }
+
+    /// Set the number of times to repeat the animation.
+    ///
+    /// See [`Tween::with_repeat_count()`].
+    #[must_use]
+    pub fn with_repeat_count(mut self, count: impl Into<RepeatCount>) -> Self {
+        self.repeat_count = count.into();
+        self
+    }
+
+    /// Choose how the animation behaves upon a repetition.
+    ///
+    /// See [`Tween::with_repeat_strategy()`].
+    #[must_use]
+    pub fn with_repeat_strategy(mut self, strategy: RepeatStrategy) -> Self {
+        self.repeat_strategy = Some(strategy);
+        self
+    }
+
+    /// Set both the repeat count and the repeat strategy of the animation.
+    ///
+    /// See [`Tween::with_repeat()`].
+    #[must_use]
+    pub fn with_repeat(mut self, repeat: impl Into<RepeatSpec>) -> Self {
+        let repeat = repeat.into();
+        self.repeat_count = repeat.count;
+        self.repeat_strategy = Some(repeat.strategy);
+        self
+    }
+
+    /// Set the playback direction of the tween.
+    ///
+    /// See [`Tween::with_direction()`].
+    #[must_use]
+    pub fn with_direction(mut self, direction: TweeningDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Set what the tween leaves its target at once it completes.
+    ///
+    /// See [`Tween::with_completed_behavior()`].
+    #[must_use]
+    pub fn with_completed_behavior(mut self, behavior: CompletedBehavior) -> Self {
+        self.completed_behavior = behavior;
+        self
+    }
+
+    /// Enable raising a completed event.
+    ///
+    /// See [`Tween::with_completed_event()`].
+    #[must_use]
+    pub fn with_completed_event(mut self, user_data: u64) -> Self {
+        self.event_data = Some(user_data);
+        self
+    }
+
+    /// Set a callback invoked when the animation completes.
+    ///
+    /// See [`Tween::with_completed()`].
+    #[must_use]
+    pub fn with_completed<C>(mut self, callback: C) -> Self
+    where
+        C: Fn(Entity, &Tween<T>) + Send + Sync + 'static,
+    {
+        self.on_completed = Some(Box::new(callback));
+        self
+    }
+
+    /// Validate the configuration and build the tween.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TweenBuildError`] describing the first invalid option
+    /// found, if any.
+    pub fn build(self) -> Result<Tween<T>, TweenBuildError> {
+        let lens = self.lens.ok_or(TweenBuildError::MissingLens)?;
+        let duration = self.duration.ok_or(TweenBuildError::MissingDuration)?;
+        if duration.is_zero() {
+            return Err(TweenBuildError::ZeroDuration);
+        }
+        let repeats = match self.repeat_count {
+            RepeatCount::Finite(0) => return Err(TweenBuildError::ZeroRepeatCount),
+            RepeatCount::For(total) if total.is_zero() => {
+                return Err(TweenBuildError::ZeroRepeatCount)
+            }
+            RepeatCount::Finite(count) => count > 1,
+            RepeatCount::For(total) => total > duration,
+            RepeatCount::Infinite => true,
+        };
+        if self.repeat_strategy.is_some() && !repeats {
+            return Err(TweenBuildError::RepeatStrategyWithoutRepeat);
+        }
+        if self.completed_behavior != CompletedBehavior::HoldEnd
+            && self.repeat_count == RepeatCount::Infinite
+        {
+            return Err(TweenBuildError::CompletedBehaviorWithInfiniteRepeat);
+        }
+
+        let mut tween = Tween::from_boxed_lens(self.ease_method, duration, lens)
+            .with_repeat_count(self.repeat_count)
+            .with_repeat_strategy(self.repeat_strategy.unwrap_or_default())
+            .with_direction(self.direction)
+            .with_completed_behavior(self.completed_behavior);
+        if let Some(user_data) = self.event_data {
+            tween.set_completed_event(user_data);
+        }
+        if let Some(callback) = self.on_completed {
+            tween.set_completed_boxed(callback);
+        }
+        Ok(tween)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lens::TransformPositionLens, TotalDuration, Tweenable};
+
+    fn make_builder() -> TweenBuilder<Transform> {
+        Tween::builder()
+            .with_duration(Duration::from_secs(1))
+            .with_lens(TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            })
+    }
+
+    #[test]
+    fn build() {
+        let tween = make_builder()
+            .with_repeat_count(3)
+            .with_repeat_strategy(RepeatStrategy::MirroredRepeat)
+            .with_direction(TweeningDirection::Backward)
+            .build()
+            .unwrap();
+        assert_eq!(tween.duration(), Duration::from_secs(1));
+        assert_eq!(
+            tween.total_duration(),
+            TotalDuration::Finite(Duration::from_secs(3))
+        );
+        assert_eq!(tween.direction(), TweeningDirection::Backward);
+
+        let tween = make_builder()
+            .with_repeat_count(RepeatCount::Infinite)
+            .with_repeat_strategy(RepeatStrategy::Repeat)
+            .build()
+            .unwrap();
+        assert_eq!(tween.total_duration(), TotalDuration::Infinite);
+    }
+
+    #[test]
+    fn build_errors() {
+        let err = Tween::<Transform>::builder()
+            .with_duration(Duration::from_secs(1))
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(err, TweenBuildError::MissingLens);
+
+        let err = Tween::<Transform>::builder()
+            .with_lens(TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            })
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(err, TweenBuildError::MissingDuration);
+
+        let err = make_builder()
+            .with_duration(Duration::ZERO)
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(err, TweenBuildError::ZeroDuration);
+
+        let err = make_builder().with_repeat_count(0).build().err().unwrap();
+        assert_eq!(err, TweenBuildError::ZeroRepeatCount);
+
+        let err = make_builder()
+            .with_repeat_count(Duration::ZERO)
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(err, TweenBuildError::ZeroRepeatCount);
+
+        let err = make_builder()
+            .with_repeat_strategy(RepeatStrategy::MirroredRepeat)
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(err, TweenBuildError::RepeatStrategyWithoutRepeat);
+        assert!(!err.to_string().is_empty());
+
+        let err = make_builder()
+            .with_repeat_count(RepeatCount::Infinite)
+            .with_completed_behavior(CompletedBehavior::SnapToStart)
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(err, TweenBuildError::CompletedBehaviorWithInfiniteRepeat);
+    }
+}
@@ -0,0 +1,487 @@
+use std::{
+    any::Any,
+    collections::VecDeque,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use bevy::{ecs::system::Command, log::warn, prelude::*};
+
+use crate::{
+    Animator, BoxedTweenable, Targetable, TotalDuration, TweenCancelled, TweenCompleted, TweenId,
+    TweenOverrides, TweenState, TweenTuning, TweenValidationError, Tweenable, TweenableState,
+    TweeningDirection,
+};
+
+/// Bit set in the [`TweenCompleted::user_data`] of the events raised by the
+/// steps of an [`AnimationChain`].
+pub const CHAIN_USER_DATA_BIT: u64 = 1 << 63;
+
+/// Generate the user data identifying the completion of a chain step.
+fn next_user_data() -> u64 {
+    static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+    CHAIN_USER_DATA_BIT | NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Tweenable of a chain step, raising a [`TweenCompleted`] event with the
+/// user data of the step when the wrapped tweenable completes.
+struct ChainStepTweenable<T> {
+    inner: BoxedTweenable<T>,
+    /// Identifier of the wrapped tweenable, or a new one if it has none.
+    id: TweenId,
+    user_data: u64,
+    notified: bool,
+}
+
+impl<T> Tweenable<T> for ChainStepTweenable<T> {
+    fn id(&self) -> Option<TweenId> {
+        Some(self.id)
+    }
+
+    fn duration(&self) -> Duration {
+        self.inner.duration()
+    }
+
+    fn total_duration(&self) -> TotalDuration {
+        self.inner.total_duration()
+    }
+
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        self.inner.set_elapsed(elapsed);
+        self.notified = false;
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.inner.elapsed()
+    }
+
+    fn state(&self) -> TweenState {
+        self.inner.state()
+    }
+
+    fn tick(
+        &mut self,
+        delta: Duration,
+        target: &mut dyn Targetable<T>,
+        entity: Entity,
+        events: &mut Vec<TweenCompleted>,
+    ) -> TweenState {
+        let state = self.inner.tick(delta, target, entity, events);
+        if state == TweenState::Completed && !self.notified {
+            self.notified = true;
+            events.push(TweenCompleted {
+                entity,
+                tween_id: self.id,
+                user_data: self.user_data,
+                played: self.inner.elapsed(),
+                remaining_repeats: Some(0),
+                is_elapsed_trigger: false,
+            });
+        }
+        state
+    }
+
+    fn sample(&self, elapsed: Duration, target: &mut T) -> bool {
+        self.inner.sample(elapsed, target)
+    }
+
+    fn direction(&self) -> Option<TweeningDirection> {
+        self.inner.direction()
+    }
+
+    fn set_direction(&mut self, direction: TweeningDirection) -> bool {
+        self.inner.set_direction(direction)
+    }
+
+    fn apply_tuning(&mut self, tuning: &TweenTuning) {
+        self.inner.apply_tuning(tuning);
+    }
+
+    fn apply_overrides(&mut self, overrides: &TweenOverrides) {
+        self.inner.apply_overrides(overrides);
+    }
+
+    fn boundary_value(&self) -> Option<Box<dyn Any + Send + Sync>> {
+        self.inner.boundary_value()
+    }
+
+    fn current_value(&self) -> Option<Box<dyn Any + Send + Sync>> {
+        self.inner.current_value()
+    }
+
+    fn blend_start(&mut self, from: Box<dyn Any + Send + Sync>, window: Duration) -> bool {
+        self.inner.blend_start(from, window)
+    }
+
+    fn collect_cancelled(&self, entity: Entity, cancelled: &mut Vec<TweenCancelled>) {
+        self.inner.collect_cancelled(entity, cancelled);
+    }
+
+    fn take_token_cancelled(&mut self, cancelled: &mut Vec<TweenCancelled>) {
+        self.inner.take_token_cancelled(cancelled);
+    }
+
+    fn rewind(&mut self) {
+        self.inner.rewind();
+        self.notified = false;
+    }
+
+    fn times_completed(&self) -> u32 {
+        self.inner.times_completed()
+    }
+
+    fn validate(&self) -> Result<(), TweenValidationError> {
+        self.inner.validate()
+    }
+
+    fn save_state(&self) -> TweenableState {
+        self.inner.save_state()
+    }
+
+    fn restore_state(&mut self, state: &TweenableState) -> bool {
+        let restored = self.inner.restore_state(state);
+        self.notified = match self.inner.total_duration() {
+            TotalDuration::Finite(total_duration) => self.inner.elapsed() >= total_duration,
+            TotalDuration::Infinite => false,
+        };
+        restored
+    }
+}
+
+type InsertStep = Box<dyn FnOnce(&mut World, Entity, u64) + Send + Sync>;
+
+/// A step of an [`AnimationChain`] not started yet.
+struct ChainStep {
+    entity: Entity,
+    insert: InsertStep,
+    remove: fn(&mut World, Entity),
+}
+
+/// The step of an [`AnimationChain`] currently playing.
+#[derive(Debug, Clone, Copy)]
+struct PlayingStep {
+    entity: Entity,
+    user_data: u64,
+    remove: fn(&mut World, Entity),
+}
+
+fn remove_step<T: Component>(world: &mut World, entity: Entity) {
+    if let Some(mut entity) = world.get_entity_mut(entity) {
+        entity.remove::<Animator<T>>();
+    }
+}
+
+/// A sequence of animations played one after the other on different
+/// entities.
+///
+/// Each step of the chain inserts an [`Animator`] on its entity, once the
+/// animator of the previous step completed. This replaces the boilerplate of
+/// a system reading the [`TweenCompleted`] events of an animation to insert
+/// the next one.
+///
+/// Spawning the chain creates a controller entity holding an
+/// [`ActiveAnimationChain`], and inserts the animator of the first step. The
+/// [`animation_chain_system`] then starts each following step on the frame
+/// the previous one completes. The controller entity is despawned once the
+/// last step completed. Cancel the chain with the [`CancelAnimationChain`]
+/// command.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::*;
+/// # fn open_door() -> Tween<Transform> { unimplemented!() }
+/// # fn extend_bridge() -> Tween<Transform> { unimplemented!() }
+/// fn open_path(mut commands: Commands, door: Entity, bridge: Entity) {
+///     let chain = AnimationChain::new()
+///         .step(door, open_door())
+///         .step(bridge, extend_bridge())
+///         .spawn(&mut commands);
+///     // Later, stop the chain where it is
+///     commands.add(CancelAnimationChain(chain));
+/// }
+/// ```
+#[derive(Default)]
+pub struct AnimationChain {
+    steps: VecDeque<ChainStep>,
+}
+
+impl AnimationChain {
+    /// Create a new empty chain.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a step animating the component `T` of `entity`.
+    ///
+    /// When the step starts, an [`Animator<T>`] playing `tweenable` is
+    /// inserted on `entity`, replacing any existing animator of the same type.
+    /// The next step starts once `tweenable` completes, so it should have a
+    /// finite duration. The animator is left on the entity after it
+    /// completed.
+    ///
+    /// The completion of the step raises a [`TweenCompleted`] event with an
+    /// internally generated user data, which has the [`CHAIN_USER_DATA_BIT`]
+    /// set, in addition of any event raised by `tweenable` itself.
+    #[must_use]
+    pub fn step<T: Component>(
+        mut self,
+        entity: Entity,
+        tweenable: impl Tweenable<T> + 'static,
+    ) -> Self {
+        let insert = move |world: &mut World, entity, user_data| {
+            let tweenable = ChainStepTweenable {
+                id: tweenable.id().unwrap_or_else(TweenId::allocate),
+                inner: Box::new(tweenable),
+                user_data,
+                notified: false,
+            };
+            world.entity_mut(entity).insert(Animator::new(tweenable));
+        };
+        self.steps.push_back(ChainStep {
+            entity,
+            insert: Box::new(insert),
+            remove: remove_step::<T>,
+        });
+        self
+    }
+
+    /// Spawn the controller entity of the chain, and start its first step.
+    ///
+    /// The controller entity is returned, to cancel the chain with
+    /// [`CancelAnimationChain`]. The first step starts when the commands are
+    /// applied. A step whose entity doesn't exist anymore when it starts logs
+    /// a warning and is skipped.
+    pub fn spawn(self, commands: &mut Commands) -> Entity {
+        let controller = commands
+            .spawn(ActiveAnimationChain {
+                steps: self.steps,
+                playing: None,
+            })
+            .id();
+        commands.add(move |world: &mut World| ActiveAnimationChain::advance(world, controller));
+        controller
+    }
+}
+
+/// Component of the controller entity of a spawned [`AnimationChain`].
+#[derive(Component)]
+pub struct ActiveAnimationChain {
+    steps: VecDeque<ChainStep>,
+    playing: Option<PlayingStep>,
+}
+
+impl ActiveAnimationChain {
+    /// The entity of the step currently playing, if any.
+    pub fn playing_entity(&self) -> Option<Entity> {
+        self.playing.map(|step| step.entity)
+    }
+
+    /// The number of steps not started yet.
+    pub fn remaining_steps(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Start the next step of the chain owned by `controller`, or despawn the
+    /// controller if the chain completed.
+    fn advance(world: &mut World, controller: Entity) {
+        loop {
+            let Some(mut chain) = world.get_mut::<ActiveAnimationChain>(controller) else {
+                return;
+            };
+            let Some(step) = chain.steps.pop_front() else {
+                world.despawn(controller);
+                return;
+            };
+            if world.get_entity(step.entity).is_none() {
+                warn!(
+                    "Skipped animation chain step: entity {:?} doesn't exist.",
+                    step.entity
+                );
+                continue;
+            }
+            let user_data = next_user_data();
+            (step.insert)(world, step.entity, user_data);
+            world
+                .get_mut::<ActiveAnimationChain>(controller)
+                .unwrap()
+                .playing = Some(PlayingStep {
+                entity: step.entity,
+                user_data,
+                remove: step.remove,
+            });
+            return;
+        }
+    }
+}
+
+/// Command cancelling an [`AnimationChain`], given its controller entity.
+///
+/// The animator of the step currently playing is removed, leaving its target
+/// in its current state, and the following steps never start. The animators
+/// of the steps already completed are left untouched. The controller entity
+/// is despawned. Chains already completed or cancelled are skipped.
+#[derive(Debug, Clone, Copy)]
+pub struct CancelAnimationChain(pub Entity);
+
+impl Command for CancelAnimationChain {
+    fn apply(self, world: &mut World) {
+        let Some(mut controller) = world.get_entity_mut(self.0) else {
+            return;
+        };
+        let Some(chain) = controller.take::<ActiveAnimationChain>() else {
+            return;
+        };
+        controller.despawn();
+        if let Some(step) = chain.playing {
+            (step.remove)(world, step.entity);
+        }
+    }
+}
+
+/// System advancing the [`AnimationChain`]s.
+///
+/// This system reads the [`TweenCompleted`] events, and starts the next step
+/// of each chain whose playing step completed. It's added by the
+/// [`TweeningPlugin`] after the animator systems, so a step starts on the
+/// frame the previous one completed, and ticks from the next frame.
+///
+/// [`TweeningPlugin`]: crate::TweeningPlugin
+pub fn animation_chain_system(
+    mut commands: Commands,
+    mut reader: EventReader<TweenCompleted>,
+    query: Query<(Entity, &ActiveAnimationChain)>,
+) {
+    let completed: Vec<u64> = reader
+        .read()
+        .filter(|ev| !ev.is_elapsed_trigger && ev.user_data & CHAIN_USER_DATA_BIT != 0)
+        .map(|ev| ev.user_data)
+        .collect();
+    if completed.is_empty() {
+        return;
+    }
+    for (controller, chain) in query.iter() {
+        if chain
+            .playing
+            .is_some_and(|step| completed.contains(&step.user_data))
+        {
+            commands.add(move |world: &mut World| ActiveAnimationChain::advance(world, controller));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::CommandQueue;
+
+    use super::*;
+    use crate::{lens::TransformPositionLens, EaseMethod, Tween, TweeningPlugin};
+
+    fn tween(start: Vec3, end: Vec3) -> Tween<Transform> {
+        Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens { start, end },
+        )
+    }
+
+    fn make_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register::<Transform>(),
+        )
+        .init_resource::<Time>();
+        app
+    }
+
+    fn run_commands(app: &mut App, f: impl FnOnce(&mut Commands) -> Entity) -> Entity {
+        let mut queue = CommandQueue::default();
+        let entity = f(&mut Commands::new(&mut queue, &app.world));
+        queue.apply(&mut app.world);
+        entity
+    }
+
+    fn tick(app: &mut App, duration: Duration) {
+        app.world.resource_mut::<Time>().advance_by(duration);
+        app.update();
+    }
+
+    fn position(app: &App, entity: Entity) -> Vec3 {
+        app.world.get::<Transform>(entity).unwrap().translation
+    }
+
+    #[test]
+    fn chain_order() {
+        let mut app = make_app();
+        let door = app.world.spawn(Transform::default()).id();
+        let bridge = app.world.spawn(Transform::default()).id();
+
+        let controller = run_commands(&mut app, |commands| {
+            AnimationChain::new()
+                .step(door, tween(Vec3::ZERO, Vec3::Y))
+                .step(bridge, tween(Vec3::ZERO, Vec3::X))
+                .step(door, tween(Vec3::Y, Vec3::Z))
+                .spawn(commands)
+        });
+        let chain = app.world.get::<ActiveAnimationChain>(controller).unwrap();
+        assert_eq!(chain.playing_entity(), Some(door));
+        assert_eq!(chain.remaining_steps(), 2);
+
+        // Step 1: only the door moves
+        tick(&mut app, Duration::from_millis(500));
+        assert!(position(&app, door).abs_diff_eq(Vec3::Y * 0.5, 1e-5));
+        assert!(app.world.get::<Animator<Transform>>(bridge).is_none());
+        tick(&mut app, Duration::from_millis(500));
+        assert_eq!(position(&app, door), Vec3::Y);
+        let chain = app.world.get::<ActiveAnimationChain>(controller).unwrap();
+        assert_eq!(chain.playing_entity(), Some(bridge));
+
+        // Step 2: only the bridge moves
+        tick(&mut app, Duration::from_millis(500));
+        assert_eq!(position(&app, door), Vec3::Y);
+        assert!(position(&app, bridge).abs_diff_eq(Vec3::X * 0.5, 1e-5));
+        tick(&mut app, Duration::from_millis(500));
+        assert_eq!(position(&app, bridge), Vec3::X);
+
+        // Step 3: the door moves again
+        tick(&mut app, Duration::from_millis(500));
+        assert_eq!(position(&app, bridge), Vec3::X);
+        assert!(position(&app, door).abs_diff_eq(Vec3::new(0., 0.5, 0.5), 1e-5));
+        tick(&mut app, Duration::from_millis(500));
+        assert_eq!(position(&app, door), Vec3::Z);
+
+        // The chain completed
+        assert!(app.world.get_entity(controller).is_none());
+    }
+
+    #[test]
+    fn chain_cancel() {
+        let mut app = make_app();
+        let door = app.world.spawn(Transform::default()).id();
+        let bridge = app.world.spawn(Transform::default()).id();
+
+        let controller = run_commands(&mut app, |commands| {
+            AnimationChain::new()
+                .step(door, tween(Vec3::ZERO, Vec3::Y))
+                .step(bridge, tween(Vec3::ZERO, Vec3::X))
+                .spawn(commands)
+        });
+        tick(&mut app, Duration::from_secs(1));
+        tick(&mut app, Duration::from_millis(500));
+
+        run_commands(&mut app, |commands| {
+            commands.add(CancelAnimationChain(controller));
+            controller
+        });
+        assert!(app.world.get_entity(controller).is_none());
+        assert!(app.world.get::<Animator<Transform>>(bridge).is_none());
+        // The animator of the completed step is left untouched
+        assert!(app.world.get::<Animator<Transform>>(door).is_some());
+
+        tick(&mut app, Duration::from_secs(1));
+        assert!(position(&app, bridge).abs_diff_eq(Vec3::X * 0.5, 1e-5));
+    }
+}
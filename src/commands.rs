@@ -0,0 +1,291 @@
+use std::time::Duration;
+
+use bevy::{
+    ecs::system::{EntityCommand, EntityCommands},
+    log::warn,
+    prelude::*,
+};
+
+use crate::{
+    tweenable::ComponentTarget, Animator, TotalDuration, TweenCancelled, TweenCompleted, Tweenable,
+};
+
+/// Behavior when inserting an [`Animator`] on an entity which already has one
+/// animating the same component type.
+///
+/// Inserting a component with [`EntityCommands::insert()`] always replaces the
+/// existing animator, which silently drops its pending completion events and
+/// callbacks, without raising a [`TweenCancelled`] event, and leaves the
+/// target at an intermediate value. This is intended
+/// when retriggering an animation, but may also hide two systems fighting over
+/// the same target. Use [`AnimatorCommandsExt::animate_with_policy()`] to make
+/// that choice explicit.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnimatorOverwritePolicy {
+    /// Replace the existing animator, like [`EntityCommands::insert()`], but
+    /// raise a [`TweenCancelled`] event for each of its pending completion
+    /// events.
+    #[default]
+    Replace,
+    /// Fast-forward the existing animator to its end, applying its end state
+    /// to the target and raising its completion events and callbacks, then
+    /// replace it.
+    ///
+    /// An existing animator with an infinite duration has no end, and is
+    /// replaced and cancelled like with [`AnimatorOverwritePolicy::Replace`].
+    ReplaceAndComplete,
+    /// Keep the existing animator if its animation didn't complete yet, and
+    /// log a warning; otherwise replace it.
+    Reject,
+}
+
+/// Extension trait for [`EntityCommands`] to insert an [`Animator`].
+pub trait AnimatorCommandsExt {
+    /// Insert an [`Animator`] playing `tweenable`, resolving any existing
+    /// animator of the same component type according to `policy`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use std::time::Duration;
+    /// # fn jump() -> Tween<Transform> { unimplemented!() }
+    /// fn on_jump(mut commands: Commands, query: Query<Entity, With<Transform>>) {
+    ///     for entity in &query {
+    ///         // Land any jump in progress before starting the new one
+    ///         commands
+    ///             .entity(entity)
+    ///             .animate_with_policy(jump(), AnimatorOverwritePolicy::ReplaceAndComplete);
+    ///     }
+    /// }
+    /// ```
+    fn animate_with_policy<T: Component>(
+        &mut self,
+        tweenable: impl Tweenable<T> + 'static,
+        policy: AnimatorOverwritePolicy,
+    ) -> &mut Self;
+}
+
+impl AnimatorCommandsExt for EntityCommands<'_> {
+    fn animate_with_policy<T: Component>(
+        &mut self,
+        tweenable: impl Tweenable<T> + 'static,
+        policy: AnimatorOverwritePolicy,
+    ) -> &mut Self {
+        self.add(InsertAnimator {
+            animator: Animator::new(tweenable),
+            policy,
+        })
+    }
+}
+
+/// Command inserting an animator with an [`AnimatorOverwritePolicy`].
+struct InsertAnimator<T: Component> {
+    animator: Animator<T>,
+    policy: AnimatorOverwritePolicy,
+}
+
+impl<T: Component> EntityCommand for InsertAnimator<T> {
+    fn apply(self, id: Entity, world: &mut World) {
+        if resolve_overwrite::<T>(world, id, self.policy) {
+            world.entity_mut(id).insert(self.animator);
+        }
+    }
+}
+
+/// Resolve the existing [`Animator<T>`] of the entity `id`, if any, before
+/// inserting a new one, according to `policy`.
+///
+/// Returns `true` if a new animator can be inserted, or `false` if the entity
+/// doesn't exist or the existing animator is kept.
+pub(crate) fn resolve_overwrite<T: Component>(
+    world: &mut World,
+    id: Entity,
+    policy: AnimatorOverwritePolicy,
+) -> bool {
+    let Some(mut entity) = world.get_entity_mut(id) else {
+        return false;
+    };
+    match policy {
+        AnimatorOverwritePolicy::Replace => {
+            if let Some(mut animator) = entity.get_mut::<Animator<T>>() {
+                let cancelled = cancel(&mut animator, id);
+                send_cancelled(world, cancelled);
+            }
+            true
+        }
+        AnimatorOverwritePolicy::ReplaceAndComplete => {
+            // Complete the animator in place, so it's not reported as removed
+            let mut query = world.query::<(&mut Animator<T>, Option<&mut T>)>();
+            let Ok((mut animator, target)) = query.get_mut(world, id) else {
+                return true;
+            };
+            let mut completed = Vec::new();
+            let mut cancelled = Vec::new();
+            if let (TotalDuration::Finite(total_duration), Some(target)) =
+                (animator.tweenable().total_duration(), target)
+            {
+                let remaining = total_duration.saturating_sub(animator.tweenable().elapsed());
+                let mut target = ComponentTarget::new(target);
+                animator
+                    .tweenable_mut()
+                    .tick(remaining, &mut target, id, &mut completed);
+            } else {
+                cancelled = cancel(&mut animator, id);
+            }
+            if let Some(mut events) = world.get_resource_mut::<Events<TweenCompleted>>() {
+                events.send_batch(completed);
+            }
+            send_cancelled(world, cancelled);
+            true
+        }
+        AnimatorOverwritePolicy::Reject => {
+            let running = entity
+                .get::<Animator<T>>()
+                .is_some_and(|animator| animator.time_remaining() != Some(Duration::ZERO));
+            if running {
+                warn!(
+                    "Rejected animator for {} on entity {id:?}: another one is running.",
+                    std::any::type_name::<T>()
+                );
+            }
+            !running
+        }
+    }
+}
+
+/// Cancel the tweenable of an animator about to be overwritten, returning
+/// the [`TweenCancelled`] events to raise.
+///
+/// Overwriting a component doesn't report it as removed, so the animator
+/// systems can't raise those events themselves.
+fn cancel<T: Component>(animator: &mut Animator<T>, id: Entity) -> Vec<TweenCancelled> {
+    animator.cancel_tweenable();
+    animator.take_cancelled(id).collect()
+}
+
+fn send_cancelled(world: &mut World, cancelled: Vec<TweenCancelled>) {
+    if cancelled.is_empty() {
+        return;
+    }
+    if let Some(mut events) = world.get_resource_mut::<Events<TweenCancelled>>() {
+        events.send_batch(cancelled);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::CommandQueue;
+
+    use super::*;
+    use crate::{lens::TransformPositionLens, EaseMethod, Tween};
+
+    fn tween(end: Vec3) -> Tween<Transform> {
+        Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end,
+            },
+        )
+    }
+
+    /// Tick the existing animator halfway, then insert a new one with the
+    /// given policy.
+    fn overwrite(policy: AnimatorOverwritePolicy) -> (World, Entity) {
+        let mut world = World::new();
+        world.init_resource::<Events<TweenCompleted>>();
+        world.init_resource::<Events<TweenCancelled>>();
+        let entity = world
+            .spawn((
+                Transform::default(),
+                Animator::new(tween(Vec3::X).with_completed_event(1)),
+            ))
+            .id();
+        world.resource_scope(|world, mut events: Mut<Events<TweenCompleted>>| {
+            let mut entity_mut = world.entity_mut(entity);
+            let mut animator = entity_mut.take::<Animator<Transform>>().unwrap();
+            let target = entity_mut.get_mut::<Transform>().unwrap();
+            let mut completed = Vec::new();
+            animator.tweenable_mut().tick(
+                Duration::from_millis(500),
+                &mut ComponentTarget::new(target),
+                entity,
+                &mut completed,
+            );
+            entity_mut.insert(animator);
+            events.send_batch(completed);
+        });
+
+        let mut queue = CommandQueue::default();
+        Commands::new(&mut queue, &world)
+            .entity(entity)
+            .animate_with_policy(tween(Vec3::Y).with_completed_event(2), policy);
+        queue.apply(&mut world);
+        (world, entity)
+    }
+
+    fn events(world: &mut World) -> Vec<u64> {
+        world
+            .resource_mut::<Events<TweenCompleted>>()
+            .drain()
+            .map(|event| event.user_data)
+            .collect()
+    }
+
+    fn cancelled(world: &mut World) -> Vec<(u64, f32)> {
+        world
+            .resource_mut::<Events<TweenCancelled>>()
+            .drain()
+            .map(|event| (event.user_data, event.progress_at_cancel))
+            .collect()
+    }
+
+    fn end(world: &World, entity: Entity) -> Vec3 {
+        let animator = world.get::<Animator<Transform>>(entity).unwrap();
+        let mut transform = Transform::default();
+        assert!(animator
+            .tweenable()
+            .sample(Duration::from_secs(1), &mut transform));
+        transform.translation
+    }
+
+    #[test]
+    fn overwrite_replace() {
+        let (mut world, entity) = overwrite(AnimatorOverwritePolicy::Replace);
+        assert!(events(&mut world).is_empty());
+        assert_eq!(cancelled(&mut world), vec![(1, 0.5)]);
+        let transform = world.get::<Transform>(entity).unwrap();
+        assert!(transform.translation.abs_diff_eq(Vec3::X * 0.5, 1e-5));
+        assert_eq!(end(&world, entity), Vec3::Y);
+    }
+
+    #[test]
+    fn overwrite_replace_and_complete() {
+        let (mut world, entity) = overwrite(AnimatorOverwritePolicy::ReplaceAndComplete);
+        assert_eq!(events(&mut world), vec![1]);
+        assert!(cancelled(&mut world).is_empty());
+        let transform = world.get::<Transform>(entity).unwrap();
+        assert_eq!(transform.translation, Vec3::X);
+        assert_eq!(end(&world, entity), Vec3::Y);
+    }
+
+    #[test]
+    fn overwrite_reject() {
+        let (mut world, entity) = overwrite(AnimatorOverwritePolicy::Reject);
+        assert!(events(&mut world).is_empty());
+        assert_eq!(end(&world, entity), Vec3::X);
+
+        // A completed animator can be replaced
+        let mut queue = CommandQueue::default();
+        let mut animator = world.get_mut::<Animator<Transform>>(entity).unwrap();
+        animator.tweenable_mut().set_elapsed(Duration::from_secs(1));
+        Commands::new(&mut queue, &world)
+            .entity(entity)
+            .animate_with_policy(tween(Vec3::Z), AnimatorOverwritePolicy::Reject);
+        queue.apply(&mut world);
+        assert_eq!(end(&world, entity), Vec3::Z);
+    }
+}
@@ -0,0 +1,193 @@
+//! Run conditions on the state of the animators.
+
+use bevy::prelude::*;
+
+use crate::Animator;
+
+/// Run condition which is `true` if any [`Animator<T>`] is active.
+///
+/// An animator is active until its tweenable completed, as recorded by the
+/// animator system in [`Animator::completed_at()`], so this only checks a
+/// cached value and is cheap to evaluate every frame. Paused animators, and
+/// stopped ones, count as active as long as their tweenable didn't complete,
+/// as they're expected to resume; animators with a looping tweenable never
+/// complete, so are always active.
+///
+/// An animator inserted this frame is active, even before it first ticks.
+/// The condition reflects the state of the animators as of the last tick of
+/// the animator systems, so systems gated on it should run after
+/// [`AnimationSystem::AnimationUpdate`] to observe a tweenable completing in
+/// the same frame.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::*;
+/// fn show_skip_button() {}
+///
+/// App::new().add_systems(
+///     Update,
+///     show_skip_button
+///         .run_if(any_animator_active::<Transform>())
+///         .after(AnimationSystem::AnimationUpdate),
+/// );
+/// ```
+///
+/// [`AnimationSystem::AnimationUpdate`]: crate::AnimationSystem::AnimationUpdate
+pub fn any_animator_active<T: Component>() -> impl FnMut(Query<&Animator<T>>) -> bool + Clone {
+    move |query: Query<&Animator<T>>| query.iter().any(is_active)
+}
+
+/// Run condition which is `true` if any [`Animator<T>`] on an entity with the
+/// marker component `M` is active.
+///
+/// See [`any_animator_active()`] for the definition of an active animator.
+pub fn any_animator_active_with<T: Component, M: Component>(
+) -> impl FnMut(Query<&Animator<T>, With<M>>) -> bool + Clone {
+    move |query: Query<&Animator<T>, With<M>>| query.iter().any(is_active)
+}
+
+/// Run condition which is `true` if all the [`Animator<T>`] completed.
+///
+/// This is the opposite of [`any_animator_active()`], so in particular a
+/// paused animator prevents the condition from being `true` until it resumes
+/// and completes. The condition is also `true` if there's no animator at
+/// all, including before they're spawned.
+pub fn all_animators_completed<T: Component>() -> impl FnMut(Query<&Animator<T>>) -> bool + Clone {
+    move |query: Query<&Animator<T>>| !query.iter().any(is_active)
+}
+
+/// Run condition which is `true` if all the [`Animator<T>`] on an entity with
+/// the marker component `M` completed.
+///
+/// See [`all_animators_completed()`] for details.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::*;
+/// #[derive(Component)]
+/// struct IntroAnimation;
+///
+/// fn enable_input() {}
+///
+/// App::new().add_systems(
+///     Update,
+///     enable_input
+///         .run_if(all_animators_completed_with::<Transform, IntroAnimation>())
+///         .after(AnimationSystem::AnimationUpdate),
+/// );
+/// ```
+pub fn all_animators_completed_with<T: Component, M: Component>(
+) -> impl FnMut(Query<&Animator<T>, With<M>>) -> bool + Clone {
+    move |query: Query<&Animator<T>, With<M>>| !query.iter().any(is_active)
+}
+
+fn is_active<T: Component>(animator: &Animator<T>) -> bool {
+    animator.completed_at().is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::{
+        lens::TransformPositionLens, AnimationSystem, AnimatorState, EaseMethod, RepeatCount,
+        Tween, TweeningPlugin,
+    };
+
+    #[derive(Component)]
+    struct Intro;
+
+    #[derive(Default, Resource)]
+    struct Runs {
+        completed: u32,
+        active: u32,
+    }
+
+    fn make_tween(secs: u64) -> Tween<Transform> {
+        Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(secs),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+    }
+
+    #[test]
+    fn gate_on_intro_completed() {
+        let mut app = App::new();
+        app.add_plugins(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register::<Transform>(),
+        )
+        .init_resource::<Time>()
+        .init_resource::<Runs>()
+        .add_systems(
+            Update,
+            (
+                (|mut runs: ResMut<Runs>| runs.completed += 1)
+                    .run_if(all_animators_completed_with::<Transform, Intro>()),
+                (|mut runs: ResMut<Runs>| runs.active += 1)
+                    .run_if(any_animator_active_with::<Transform, Intro>()),
+            )
+                .after(AnimationSystem::AnimationUpdate),
+        );
+        app.world
+            .spawn((Transform::default(), Animator::new(make_tween(1)), Intro));
+        let last = app
+            .world
+            .spawn((Transform::default(), Animator::new(make_tween(2)), Intro))
+            .id();
+        // Not an intro animation, and never completes
+        app.world.spawn((
+            Transform::default(),
+            Animator::new(make_tween(1).with_repeat_count(RepeatCount::Infinite)),
+        ));
+
+        let tick = |app: &mut App, millis| {
+            app.world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(millis));
+            app.update();
+            let runs = app.world.resource::<Runs>();
+            (runs.completed, runs.active)
+        };
+
+        assert_eq!(tick(&mut app, 0), (0, 1));
+        // First intro animation completed, not the second one
+        assert_eq!(tick(&mut app, 1000), (0, 2));
+
+        // Paused animators count as active
+        app.world
+            .get_mut::<Animator<Transform>>(last)
+            .unwrap()
+            .state = AnimatorState::Paused;
+        assert_eq!(tick(&mut app, 1500), (0, 3));
+        app.world
+            .get_mut::<Animator<Transform>>(last)
+            .unwrap()
+            .state = AnimatorState::Playing;
+        assert_eq!(tick(&mut app, 500), (0, 4));
+
+        // Last intro animation completed
+        assert_eq!(tick(&mut app, 500), (1, 4));
+        assert_eq!(tick(&mut app, 500), (2, 4));
+
+        // Ungated conditions include the looping animator
+        assert!(app
+            .world
+            .run_system_once(any_animator_active::<Transform>()));
+        assert!(!app
+            .world
+            .run_system_once(all_animators_completed::<Transform>()));
+    }
+}
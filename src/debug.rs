@@ -0,0 +1,216 @@
+//! Debug visualization of the tweens animating a [`Transform`].
+//!
+//! This module is only available with the `debug_gizmos` feature.
+
+use bevy::prelude::*;
+
+use crate::{Animator, TotalDuration, Tweenable};
+
+/// Maximum number of samples used to draw the path of a [`DebugTweenPath`].
+pub const MAX_DEBUG_PATH_SAMPLES: usize = 256;
+
+/// Component to draw the path of the [`Animator<Transform>`] on the same
+/// entity with gizmos.
+///
+/// The [`debug_tween_path_system`] previews the whole animation timeline with
+/// [`Tweenable::sample()`], without modifying the live animation, and draws:
+/// - a polyline through the sampled positions, if the animation moves the
+///   entity;
+/// - an arc traced by the tip of one of the local axes of the entity, if the
+///   animation rotates it;
+/// - a sphere at the current position of the entity.
+///
+/// The path is drawn in the space of the parent of the entity, so moves with
+/// the parent like the entity does. Nothing is drawn if the tweenable or one
+/// of its lenses doesn't support sampling, for example a custom lens which
+/// doesn't implement [`Lens::sample()`].
+///
+/// [`Lens::sample()`]: crate::Lens::sample
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct DebugTweenPath {
+    /// Number of samples of the path, capped to [`MAX_DEBUG_PATH_SAMPLES`].
+    pub samples: usize,
+    /// Color of the path.
+    pub color: Color,
+    /// Length of the axis whose tip traces the arc of a rotation.
+    pub arc_radius: f32,
+    /// Radius of the sphere marking the current position.
+    pub marker_radius: f32,
+}
+
+impl Default for DebugTweenPath {
+    fn default() -> Self {
+        Self {
+            samples: 64,
+            color: Color::YELLOW,
+            arc_radius: 1.,
+            marker_radius: 0.1,
+        }
+    }
+}
+
+/// Sample the transforms along the whole timeline of a tweenable.
+///
+/// For an infinite tweenable, only the first iteration is sampled. Returns
+/// `None` if the tweenable doesn't support sampling.
+pub(crate) fn sample_path(
+    tweenable: &dyn Tweenable<Transform>,
+    current: &Transform,
+    samples: usize,
+) -> Option<Vec<Transform>> {
+    let samples = samples.clamp(2, MAX_DEBUG_PATH_SAMPLES);
+    let timeline = match tweenable.total_duration() {
+        TotalDuration::Finite(total_duration) => total_duration,
+        TotalDuration::Infinite => tweenable.duration(),
+    };
+    (0..samples)
+        .map(|index| {
+            let elapsed = timeline.mul_f64(index as f64 / (samples - 1) as f64);
+            let mut transform = *current;
+            tweenable
+                .sample(elapsed, &mut transform)
+                .then_some(transform)
+        })
+        .collect()
+}
+
+/// Length of a polyline.
+fn path_length(points: &[Vec3]) -> f32 {
+    points.windows(2).map(|w| w[0].distance(w[1])).sum()
+}
+
+/// Draw the path of the animators with a [`DebugTweenPath`].
+///
+/// This system is not added by the [`TweeningPlugin`]; add it to the app, for
+/// example after [`AnimationSystem::AnimationUpdate`] to draw the marker at
+/// the position of the current frame. It requires the Bevy gizmos, which are
+/// part of the `DefaultPlugins`.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{debug::*, *};
+/// App::new()
+///     .add_plugins((DefaultPlugins, TweeningPlugin::default()))
+///     .add_systems(
+///         Update,
+///         debug_tween_path_system.after(AnimationSystem::AnimationUpdate),
+///     )
+///     .run();
+/// ```
+///
+/// [`TweeningPlugin`]: crate::TweeningPlugin
+/// [`AnimationSystem::AnimationUpdate`]: crate::AnimationSystem::AnimationUpdate
+pub fn debug_tween_path_system(
+    mut gizmos: Gizmos,
+    query: Query<(
+        &Transform,
+        &Animator<Transform>,
+        &DebugTweenPath,
+        Option<&Parent>,
+    )>,
+    parents: Query<&GlobalTransform>,
+) {
+    for (transform, animator, debug, parent) in &query {
+        let Some(path) = sample_path(animator.tweenable(), transform, debug.samples) else {
+            continue;
+        };
+        let parent = parent
+            .and_then(|parent| parents.get(parent.get()).ok())
+            .copied()
+            .unwrap_or_default();
+
+        let positions: Vec<Vec3> = path
+            .iter()
+            .map(|t| parent.transform_point(t.translation))
+            .collect();
+        if path_length(&positions) > f32::EPSILON {
+            gizmos.linestrip(positions, debug.color);
+        }
+
+        // Trace the tip of the local axis which moves the most, to show a
+        // rotation around any axis.
+        let arc = [Vec3::X, Vec3::Y, Vec3::Z]
+            .into_iter()
+            .map(|axis| {
+                path.iter()
+                    .map(|t| {
+                        parent.transform_point(t.translation + t.rotation * axis * debug.arc_radius)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .max_by(|a, b| path_length(a).total_cmp(&path_length(b)))
+            .unwrap();
+        if path_length(&arc) > f32::EPSILON {
+            gizmos.linestrip(arc, debug.color);
+        }
+
+        gizmos.sphere(
+            parent.transform_point(transform.translation),
+            Quat::IDENTITY,
+            debug.marker_radius,
+            debug.color,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{lens::*, EaseMethod, Tween};
+
+    #[test]
+    fn sample_sequence_path() {
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::X,
+            },
+        )
+        .then(Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformRotateZLens {
+                start: 0.,
+                end: std::f32::consts::PI,
+            },
+        ));
+        let current = Transform::from_scale(Vec3::splat(2.));
+
+        let path = sample_path(&tween, &current, 5).unwrap();
+        assert_eq!(path.len(), 5);
+        for (transform, x) in path.iter().zip([0., 0.5, 1., 1., 1.]) {
+            assert!((transform.translation.x - x).abs() < 1e-5);
+            // Fields not animated are kept
+            assert_eq!(transform.scale, Vec3::splat(2.));
+        }
+        assert_eq!(path[2].rotation, Quat::IDENTITY);
+        assert!(path[3]
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2), 1e-5));
+
+        // The live tween is not modified
+        assert_eq!(tween.elapsed(), Duration::ZERO);
+
+        // The number of samples is bounded
+        let path = sample_path(&tween, &current, usize::MAX).unwrap();
+        assert_eq!(path.len(), MAX_DEBUG_PATH_SAMPLES);
+    }
+
+    #[test]
+    fn sample_unsupported() {
+        struct CustomLens;
+
+        impl Lens<Transform> for CustomLens {
+            fn lerp(&mut self, target: &mut Transform, ratio: f32) {
+                target.translation.x = ratio;
+            }
+        }
+
+        let tween = Tween::new(EaseMethod::Linear, Duration::from_secs(1), CustomLens);
+        assert!(sample_path(&tween, &Transform::IDENTITY, 8).is_none());
+    }
+}
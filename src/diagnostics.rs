@@ -0,0 +1,122 @@
+//! Diagnostics measuring the animator systems, per animated target type.
+//!
+//! This module is only available with the `diagnostics` feature.
+
+use std::{any::type_name, marker::PhantomData};
+
+use bevy::{
+    diagnostic::{Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore},
+    ecs::system::SystemParam,
+    prelude::*,
+    utils::Instant,
+};
+
+/// Paths of the diagnostics of the animator system of a target type.
+///
+/// Each animator system registered with the [`TweeningPlugin`] publishes two
+/// diagnostics on each run, identified by the type of the animator it ticks:
+/// - `bevy_tweening/<animator>/entity_count`, the number of animators the
+///   system ticked, paused ones included;
+/// - `bevy_tweening/<animator>/tick_time`, the time spent ticking them, in
+///   milliseconds.
+///
+/// where `<animator>` is the [`type_name()`] of the animator component, for
+/// example `bevy_tweening::Animator<bevy_transform::components::transform::Transform>`.
+/// The diagnostics can be read from the [`DiagnosticsStore`], or logged with
+/// Bevy's [`LogDiagnosticsPlugin`].
+///
+/// ```
+/// # use bevy::{diagnostic::DiagnosticsStore, prelude::*};
+/// # use bevy_tweening::{diagnostics::AnimatorDiagnosticPaths, *};
+/// fn report(store: Res<DiagnosticsStore>) {
+///     let paths = AnimatorDiagnosticPaths::of::<Animator<Transform>>();
+///     if let Some(tick_time) = store.get(&paths.tick_time).and_then(|d| d.smoothed()) {
+///         info!("Transform animators: {tick_time:.3} ms");
+///     }
+/// }
+/// ```
+///
+/// [`TweeningPlugin`]: crate::TweeningPlugin
+/// [`LogDiagnosticsPlugin`]: bevy::diagnostic::LogDiagnosticsPlugin
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnimatorDiagnosticPaths {
+    /// Path of the number of animators ticked.
+    pub entity_count: DiagnosticPath,
+    /// Path of the time spent ticking the animators, in milliseconds.
+    pub tick_time: DiagnosticPath,
+}
+
+impl AnimatorDiagnosticPaths {
+    /// Get the diagnostic paths of the animator component `A`.
+    #[must_use]
+    pub fn of<A: Component>() -> Self {
+        Self::from_type_name(type_name::<A>())
+    }
+
+    /// Get the diagnostic paths of the animator component type named
+    /// `type_name`.
+    pub(crate) fn from_type_name(type_name: &str) -> Self {
+        Self {
+            entity_count: DiagnosticPath::new(format!("bevy_tweening/{type_name}/entity_count")),
+            tick_time: DiagnosticPath::new(format!("bevy_tweening/{type_name}/tick_time")),
+        }
+    }
+
+    /// Register the diagnostics into the [`DiagnosticsStore`] of `app`.
+    pub(crate) fn register(&self, app: &mut App) {
+        use bevy::diagnostic::RegisterDiagnostic;
+        app.register_diagnostic(Diagnostic::new(self.entity_count.clone()))
+            .register_diagnostic(Diagnostic::new(self.tick_time.clone()).with_suffix("ms"));
+    }
+}
+
+/// Paths of the diagnostics of the animator component `A`, cached in the
+/// [`Local`] state of its animator system.
+struct CachedPaths<A> {
+    paths: AnimatorDiagnosticPaths,
+    marker: PhantomData<fn() -> A>,
+}
+
+impl<A: Component> Default for CachedPaths<A> {
+    fn default() -> Self {
+        Self {
+            paths: AnimatorDiagnosticPaths::of::<A>(),
+            marker: PhantomData,
+        }
+    }
+}
+
+/// System parameter publishing the diagnostics of the animator system of the
+/// animator component `A`.
+///
+/// This is an implementation detail of the animator systems, which only
+/// appears in their signature.
+#[derive(SystemParam)]
+pub struct AnimatorSystemDiagnostics<'w, 's, A: Component> {
+    cached: Local<'s, CachedPaths<A>>,
+    store: Option<ResMut<'w, DiagnosticsStore>>,
+}
+
+impl<'w, 's, A: Component> AnimatorSystemDiagnostics<'w, 's, A> {
+    /// Publish the measurements of a run of the system started at `start`,
+    /// which ticked `entity_count` animators.
+    ///
+    /// Nothing is published if the store doesn't exist, or if the
+    /// diagnostics are not registered or are disabled.
+    pub(crate) fn publish(&mut self, start: Instant, entity_count: usize) {
+        let Some(store) = self.store.as_mut() else {
+            return;
+        };
+        let time = Instant::now();
+        let tick_time = time.duration_since(start).as_secs_f64() * 1000.;
+        let paths = &self.cached.paths;
+        for (path, value) in [
+            (&paths.entity_count, entity_count as f64),
+            (&paths.tick_time, tick_time),
+        ] {
+            if let Some(diagnostic) = store.get_mut(path).filter(|d| d.is_enabled) {
+                diagnostic.add_measurement(DiagnosticMeasurement { time, value });
+            }
+        }
+    }
+}
@@ -0,0 +1,311 @@
+//! Easing functions with parameters.
+
+use std::f32::consts::TAU;
+
+use bevy::log::warn;
+
+/// Easing function with parameters.
+///
+/// This is created by the parameterized constructors of [`EaseMethod`], like
+/// [`EaseMethod::elastic_out()`] or [`EaseMethod::bounce_out()`], which
+/// validate the parameters. All the functions return exactly `0.` at `0.` and
+/// `1.` at `1.`, whatever their parameters.
+///
+/// [`EaseMethod`]: crate::EaseMethod
+/// [`EaseMethod::elastic_out()`]: crate::EaseMethod::elastic_out
+/// [`EaseMethod::bounce_out()`]: crate::EaseMethod::bounce_out
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParametricEase {
+    /// Elastic oscillation growing toward the end.
+    ElasticIn {
+        /// Amplitude of the oscillation, at least `1.`.
+        amplitude: f32,
+        /// Period of the oscillation, as a fraction of the whole easing.
+        period: f32,
+    },
+    /// Elastic oscillation decaying from the start.
+    ElasticOut {
+        /// Amplitude of the oscillation, at least `1.`.
+        amplitude: f32,
+        /// Period of the oscillation, as a fraction of the whole easing.
+        period: f32,
+    },
+    /// Elastic oscillation growing toward the middle, then decaying.
+    ElasticInOut {
+        /// Amplitude of the oscillation, at least `1.`.
+        amplitude: f32,
+        /// Period of the oscillation, as a fraction of each half of the
+        /// easing.
+        period: f32,
+    },
+    /// Bounces growing toward the end.
+    BounceIn {
+        /// Number of bounces after the first impact.
+        bounces: u32,
+        /// Fraction of the velocity kept by each bounce, in \[0:1\].
+        restitution: f32,
+    },
+    /// Bounces decaying from the first impact.
+    BounceOut {
+        /// Number of bounces after the first impact.
+        bounces: u32,
+        /// Fraction of the velocity kept by each bounce, in \[0:1\].
+        restitution: f32,
+    },
+    /// Bounces growing toward the middle, then decaying.
+    BounceInOut {
+        /// Number of bounces after the first impact, in each half.
+        bounces: u32,
+        /// Fraction of the velocity kept by each bounce, in \[0:1\].
+        restitution: f32,
+    },
+}
+
+/// Maximum number of bounces of the bounce easings.
+pub(crate) const MAX_BOUNCES: u32 = 16;
+
+impl ParametricEase {
+    /// Sample the easing function at `x`, clamped to \[0:1\].
+    pub(crate) fn sample(self, x: f32) -> f32 {
+        if x <= 0. {
+            return 0.;
+        }
+        if x >= 1. {
+            return 1.;
+        }
+        match self {
+            Self::ElasticIn { amplitude, period } => elastic_in(x, amplitude, period),
+            Self::ElasticOut { amplitude, period } => elastic_out(x, amplitude, period),
+            Self::ElasticInOut { amplitude, period } => {
+                if x < 0.5 {
+                    0.5 * elastic_in(2. * x, amplitude, period)
+                } else {
+                    0.5 * elastic_out(2. * x - 1., amplitude, period) + 0.5
+                }
+            }
+            Self::BounceIn {
+                bounces,
+                restitution,
+            } => 1. - bounce_out(1. - x, bounces, restitution),
+            Self::BounceOut {
+                bounces,
+                restitution,
+            } => bounce_out(x, bounces, restitution),
+            Self::BounceInOut {
+                bounces,
+                restitution,
+            } => {
+                if x < 0.5 {
+                    0.5 - 0.5 * bounce_out(1. - 2. * x, bounces, restitution)
+                } else {
+                    0.5 * bounce_out(2. * x - 1., bounces, restitution) + 0.5
+                }
+            }
+        }
+    }
+}
+
+/// Clamp a parameter of an easing function to its supported range, warning
+/// if it's out of range. Non-finite values are replaced by `default`.
+pub(crate) fn clamp_param(name: &str, value: f32, min: f32, max: f32, default: f32) -> f32 {
+    if !value.is_finite() {
+        warn!("Invalid easing parameter {name}={value}, using {default} instead.");
+        return default;
+    }
+    let clamped = value.clamp(min, max);
+    if clamped != value {
+        warn!("Easing parameter {name}={value} out of range [{min}:{max}], clamped to {clamped}.");
+    }
+    clamped
+}
+
+/// Phase shift of the elastic oscillation, so its envelope ends exactly on the
+/// end value.
+fn elastic_shift(amplitude: f32, period: f32) -> f32 {
+    period / TAU * (1. / amplitude).asin()
+}
+
+fn elastic_in(x: f32, amplitude: f32, period: f32) -> f32 {
+    let shift = elastic_shift(amplitude, period);
+    let t = x - 1.;
+    -(amplitude * 2_f32.powf(10. * t) * ((t - shift) * TAU / period).sin())
+}
+
+fn elastic_out(x: f32, amplitude: f32, period: f32) -> f32 {
+    let shift = elastic_shift(amplitude, period);
+    amplitude * 2_f32.powf(-10. * x) * ((x - shift) * TAU / period).sin() + 1.
+}
+
+/// Ball dropped from a height of 1, bouncing `bounces` times with each bounce
+/// keeping `restitution` of the impact velocity. The time is scaled so the
+/// last bounce lands exactly at `x = 1`.
+fn bounce_out(x: f32, bounces: u32, restitution: f32) -> f32 {
+    // Duration of the fall, with each bounce lasting twice the fall scaled by
+    // the velocity left at that bounce.
+    let mut total = 1.;
+    let mut velocity = 1.;
+    for _ in 0..bounces {
+        velocity *= restitution;
+        total += 2. * velocity;
+    }
+    let fall = 1. / total;
+    if x < fall {
+        let t = x / fall;
+        return t * t;
+    }
+
+    let mut start = fall;
+    let mut velocity = 1.;
+    for _ in 0..bounces {
+        velocity *= restitution;
+        let half_width = fall * velocity;
+        if x < start + 2. * half_width {
+            let t = (x - start - half_width) / half_width;
+            return 1. - velocity * velocity * (1. - t * t);
+        }
+        start += 2. * half_width;
+    }
+    1.
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use super::*;
+    use crate::{EaseFunction, EaseMethod};
+
+    /// Reference elastic easings, with a period of 4/13.
+    fn elastic_in_ref(p: f32) -> f32 {
+        (13. * FRAC_PI_2 * p).sin() * 2_f32.powf(10. * (p - 1.))
+    }
+
+    fn elastic_out_ref(p: f32) -> f32 {
+        (-13. * FRAC_PI_2 * (p + 1.)).sin() * 2_f32.powf(-10. * p) + 1.
+    }
+
+    /// Reference bounce easing with 3 bounces and a restitution of 0.5.
+    fn bounce_out_ref(p: f32) -> f32 {
+        const N: f32 = 7.5625;
+        if p < 1. / 2.75 {
+            N * p * p
+        } else if p < 2. / 2.75 {
+            let p = p - 1.5 / 2.75;
+            N * p * p + 0.75
+        } else if p < 2.5 / 2.75 {
+            let p = p - 2.25 / 2.75;
+            N * p * p + 0.9375
+        } else {
+            let p = p - 2.625 / 2.75;
+            N * p * p + 0.984_375
+        }
+    }
+
+    fn samples() -> impl Iterator<Item = f32> {
+        (0..=200).map(|i| i as f32 / 200.)
+    }
+
+    #[test]
+    fn default_parameters() {
+        let elastic_in = EaseMethod::elastic_in(1., 4. / 13.);
+        let elastic_out = EaseMethod::elastic_out(1., 4. / 13.);
+        for x in samples() {
+            assert!(
+                (elastic_in.sample(x) - elastic_in_ref(x)).abs() < 1e-5,
+                "{x}"
+            );
+            assert!(
+                (elastic_out.sample(x) - elastic_out_ref(x)).abs() < 1e-5,
+                "{x}"
+            );
+        }
+        let ease = EaseMethod::elastic_in_out(1., 4. / 13.);
+        for x in samples() {
+            let expected = if x < 0.5 {
+                0.5 * elastic_in_ref(2. * x)
+            } else {
+                0.5 * elastic_out_ref(2. * x - 1.) + 0.5
+            };
+            assert!((ease.sample(x) - expected).abs() < 1e-5, "{x}");
+        }
+
+        let bounce_out = EaseMethod::bounce_out(3, 0.5);
+        let bounce_in = EaseMethod::bounce_in(3, 0.5);
+        for x in samples() {
+            assert!(
+                (bounce_out.sample(x) - bounce_out_ref(x)).abs() < 1e-5,
+                "{x}"
+            );
+            assert!((bounce_in.sample(x) - (1. - bounce_out_ref(1. - x))).abs() < 1e-5);
+        }
+
+        // The built-in bounce matches on the first impact, then approximates
+        // the bounces with slightly higher ones.
+        let builtin = EaseMethod::EaseFunction(EaseFunction::BounceOut);
+        for x in samples() {
+            let diff = (bounce_out.sample(x) - builtin.sample(x)).abs();
+            if x < 4. / 11. {
+                assert!(diff < 1e-5, "{x}");
+            } else {
+                assert!(diff < 0.051, "{x}");
+            }
+        }
+    }
+
+    #[test]
+    fn exact_endpoints() {
+        for amplitude in [1., 1.5, 4.] {
+            for period in [0.05, 0.3, 1., 2.] {
+                for ease in [
+                    EaseMethod::elastic_in(amplitude, period),
+                    EaseMethod::elastic_out(amplitude, period),
+                    EaseMethod::elastic_in_out(amplitude, period),
+                ] {
+                    assert_eq!(ease.sample(0.), 0.);
+                    assert_eq!(ease.sample(1.), 1.);
+                    assert!(ease.sample(0.5).is_finite());
+                }
+            }
+        }
+        for bounces in [0, 1, 3, MAX_BOUNCES] {
+            for restitution in [0., 0.3, 0.5, 1.] {
+                for ease in [
+                    EaseMethod::bounce_in(bounces, restitution),
+                    EaseMethod::bounce_out(bounces, restitution),
+                    EaseMethod::bounce_in_out(bounces, restitution),
+                ] {
+                    assert_eq!(ease.sample(0.), 0.);
+                    assert_eq!(ease.sample(1.), 1.);
+                    assert_eq!(ease.sample(0.5), ease.sample(0.5).clamp(0., 1.));
+                }
+            }
+        }
+        let ease = EaseMethod::bounce_out(0, 0.5);
+        assert_eq!(ease.sample(0.5), 0.25);
+    }
+
+    #[test]
+    fn clamped_parameters() {
+        let EaseMethod::Parametric(ease) = EaseMethod::elastic_out(0.5, f32::NAN) else {
+            panic!("expected a parametric easing");
+        };
+        assert_eq!(
+            ease,
+            ParametricEase::ElasticOut {
+                amplitude: 1.,
+                period: 4. / 13.
+            }
+        );
+        let EaseMethod::Parametric(ease) = EaseMethod::bounce_in_out(100, 1.5) else {
+            panic!("expected a parametric easing");
+        };
+        assert_eq!(
+            ease,
+            ParametricEase::BounceInOut {
+                bounces: MAX_BOUNCES,
+                restitution: 1.
+            }
+        );
+        assert!(EaseMethod::bounce_out(3, 0.5).name().is_none());
+    }
+}
@@ -0,0 +1,238 @@
+//! Preview of the curve of an [`EaseMethod`], rendered into an [`Image`].
+//!
+//! This module is only available with the `ease_preview` feature. The image is
+//! produced on the CPU, and can be added to the [`Assets<Image>`] to be
+//! displayed in a UI node or a sprite, or its pixels written to disk.
+//!
+//! [`Assets<Image>`]: bevy::asset::Assets
+
+use bevy::{
+    prelude::*,
+    render::{
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+    },
+};
+
+use crate::EaseMethod;
+
+/// Color of the background of the plot.
+const BACKGROUND: [u8; 4] = [24, 24, 24, 255];
+/// Color of the grid lines, every quarter of the input and output range.
+const GRID: [u8; 4] = [48, 48, 48, 255];
+/// Color of the lines marking the output values 0 and 1.
+const BOUNDS: [u8; 4] = [110, 110, 110, 255];
+/// Color of the curve.
+const CURVE: [u8; 4] = [255, 196, 0, 255];
+
+/// Render the curve of `ease` into a new `width` x `height` image.
+///
+/// The ease is sampled once per column, with the input ratio going from `0.`
+/// in the leftmost column to `1.` in the rightmost one, and consecutive samples
+/// are joined with vertical segments, so steep or discontinuous curves stay
+/// connected. The curve is drawn over a faint grid dividing the input and the
+/// output ranges in quarters.
+///
+/// The vertical range spans \[0:1\], extended to the minimum and maximum
+/// sampled values for eases overshooting that range, like
+/// [`EaseFunction::BackOut`]. The rows of the output values `0.` and `1.` are
+/// marked with a brighter line. Non-finite samples of a custom function are
+/// skipped.
+///
+/// The image is in the [`TextureFormat::Rgba8UnormSrgb`] format, and kept in
+/// the main world so its pixels can be read back. Both dimensions are clamped
+/// to at least one pixel.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{ease_preview, *};
+/// fn add_preview(mut images: ResMut<Assets<Image>>) -> Handle<Image> {
+///     let ease = EaseMethod::from(EaseFunction::ElasticOut);
+///     let image = ease_preview::render(&ease, 256, 128);
+///     assert_eq!(image.width(), 256);
+///     images.add(image)
+/// }
+/// ```
+///
+/// [`EaseFunction::BackOut`]: crate::EaseFunction::BackOut
+pub fn render(ease: &EaseMethod, width: u32, height: u32) -> Image {
+    let width = width.max(1);
+    let height = height.max(1);
+    let samples: Vec<f32> = (0..width)
+        .map(|column| {
+            let ratio = if width > 1 {
+                column as f32 / (width - 1) as f32
+            } else {
+                0.
+            };
+            ease.sample(ratio)
+        })
+        .collect();
+    let (min, max) = samples
+        .iter()
+        .filter(|value| value.is_finite())
+        .fold((0_f32, 1_f32), |(min, max), &value| {
+            (min.min(value), max.max(value))
+        });
+
+    let mut plot = Plot {
+        width,
+        height,
+        min,
+        max,
+        data: BACKGROUND.repeat((width * height) as usize),
+    };
+    plot.draw_grid();
+    let mut previous = None;
+    for (column, value) in samples.into_iter().enumerate() {
+        if !value.is_finite() {
+            previous = None;
+            continue;
+        }
+        let row = plot.row(value);
+        let (top, bottom) = match previous {
+            Some(previous) if previous < row => (previous + 1, row),
+            Some(previous) if previous > row => (row, previous - 1),
+            _ => (row, row),
+        };
+        for row in top..=bottom {
+            plot.set(column as u32, row, CURVE);
+        }
+        previous = Some(row);
+    }
+
+    Image::new(
+        Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        plot.data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    )
+}
+
+/// Pixels of a plot, with the output range it maps to its rows.
+struct Plot {
+    width: u32,
+    height: u32,
+    min: f32,
+    max: f32,
+    data: Vec<u8>,
+}
+
+impl Plot {
+    /// Row of an output value, from the top of the image.
+    fn row(&self, value: f32) -> u32 {
+        let fraction = (self.max - value) / (self.max - self.min);
+        let row = (fraction * (self.height - 1) as f32).round();
+        (row as u32).min(self.height - 1)
+    }
+
+    fn set(&mut self, column: u32, row: u32, color: [u8; 4]) {
+        let index = ((row * self.width + column) * 4) as usize;
+        self.data[index..index + 4].copy_from_slice(&color);
+    }
+
+    /// Draw the grid, with the lines of the output values 0 and 1 on top.
+    fn draw_grid(&mut self) {
+        for quarter in 0..=4 {
+            let fraction = quarter as f32 / 4.;
+            let column = (fraction * (self.width - 1) as f32).round() as u32;
+            for row in 0..self.height {
+                self.set(column, row, GRID);
+            }
+        }
+        for quarter in [1, 2, 3, 0, 4] {
+            let row = self.row(quarter as f32 / 4.);
+            let color = if quarter % 4 == 0 { BOUNDS } else { GRID };
+            for column in 0..self.width {
+                self.set(column, row, color);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EaseFunction;
+
+    fn pixel(image: &Image, column: u32, row: u32) -> [u8; 4] {
+        let index = ((row * image.width() + column) * 4) as usize;
+        image.data[index..index + 4].try_into().unwrap()
+    }
+
+    #[test]
+    fn render_linear() {
+        let image = render(&EaseMethod::Linear, 64, 64);
+        assert_eq!(image.width(), 64);
+        assert_eq!(image.height(), 64);
+        assert_eq!(image.data.len(), 64 * 64 * 4);
+
+        // The diagonal from the bottom left to the top right corner
+        for column in 0..64 {
+            assert_eq!(pixel(&image, column, 63 - column), CURVE);
+            let curve_pixels = (0..64)
+                .filter(|&row| pixel(&image, column, row) == CURVE)
+                .count();
+            assert_eq!(curve_pixels, 1);
+        }
+
+        // The 0 and 1 lines are at the bottom and top edges
+        assert!((1..63).all(|column| pixel(&image, column, 0) == BOUNDS));
+        assert_eq!(pixel(&image, 10, 63), BOUNDS);
+        assert_eq!(pixel(&image, 10, 10), BACKGROUND);
+    }
+
+    #[test]
+    fn render_overshoot() {
+        let ease = EaseMethod::from(EaseFunction::BackInOut);
+        let image = render(&ease, 100, 50);
+        assert_eq!(image.width(), 100);
+        assert_eq!(image.height(), 50);
+
+        // The range is extended on both sides, so the 0 and 1 lines move
+        // inwards, and the curve reaches the top and bottom edges
+        let bounds: Vec<_> = (0..50)
+            .filter(|&row| {
+                let count = (0..100)
+                    .filter(|&column| pixel(&image, column, row) == BOUNDS)
+                    .count();
+                count > 80
+            })
+            .collect();
+        assert_eq!(bounds.len(), 2);
+        assert!(bounds[0] > 0 && bounds[1] < 49, "{bounds:?}");
+        assert!((0..100).any(|column| pixel(&image, column, 0) == CURVE));
+        assert!((0..100).any(|column| pixel(&image, column, 49) == CURVE));
+
+        // Each column has a curve pixel, without any gap
+        for column in 0..100 {
+            assert!((0..50).any(|row| pixel(&image, column, row) == CURVE));
+        }
+    }
+
+    #[test]
+    fn render_discrete() {
+        let image = render(&EaseMethod::Discrete(0.5), 11, 11);
+        // The jump is drawn as a vertical segment
+        let count = |column| {
+            (0..11)
+                .filter(|&row| pixel(&image, column, row) == CURVE)
+                .count()
+        };
+        assert_eq!(count(5), 1);
+        assert_eq!(count(6), 10);
+        assert_eq!(pixel(&image, 5, 10), CURVE);
+        assert_eq!(pixel(&image, 6, 0), CURVE);
+
+        let image = render(&EaseMethod::Linear, 0, 0);
+        assert_eq!(image.width(), 1);
+        assert_eq!(image.height(), 1);
+    }
+}
@@ -0,0 +1,366 @@
+use std::{
+    borrow::Cow,
+    collections::VecDeque,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use bevy::{ecs::system::Command, log::warn, prelude::*, utils::HashMap};
+
+use crate::{Animator, TotalDuration, Tweenable};
+
+/// Identifier of a group of animators, controlled as a unit.
+///
+/// A group is created by [`HierarchyAnimation::apply()`], and can be torn down
+/// with the [`CancelAnimationGroup`] and [`CompleteAnimationGroup`] commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AnimationGroupId(u64);
+
+impl AnimationGroupId {
+    fn new() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// An animator belonging to a group.
+#[derive(Debug, Clone, Copy)]
+struct GroupMember {
+    entity: Entity,
+    cancel: fn(&mut World, Entity),
+    complete: fn(&mut World, Entity),
+}
+
+impl GroupMember {
+    fn new<T: Component>(entity: Entity) -> Self {
+        Self {
+            entity,
+            cancel: cancel_member::<T>,
+            complete: complete_member::<T>,
+        }
+    }
+}
+
+fn cancel_member<T: Component>(world: &mut World, entity: Entity) {
+    if let Some(mut entity) = world.get_entity_mut(entity) {
+        entity.remove::<Animator<T>>();
+    }
+}
+
+fn complete_member<T: Component>(world: &mut World, entity: Entity) {
+    if let Some(mut animator) = world.get_mut::<Animator<T>>(entity) {
+        if let TotalDuration::Finite(total_duration) = animator.tweenable().total_duration() {
+            animator.tweenable_mut().set_elapsed(total_duration);
+        }
+    }
+}
+
+/// Registry of the animators of each [`AnimationGroupId`].
+///
+/// This resource is added by the [`TweeningPlugin`], or on first use by a
+/// [`HierarchyAnimation`]. A group is forgotten once cancelled or completed.
+///
+/// [`TweeningPlugin`]: crate::TweeningPlugin
+#[derive(Debug, Default, Resource)]
+pub struct AnimationGroups {
+    groups: HashMap<AnimationGroupId, Vec<GroupMember>>,
+}
+
+impl AnimationGroups {
+    /// Is the group still registered?
+    pub fn contains(&self, group: AnimationGroupId) -> bool {
+        self.groups.contains_key(&group)
+    }
+
+    /// The entities holding an animator of the group, in the order the
+    /// animators were inserted. An entity holding several animators of the
+    /// group is listed once per animator.
+    pub fn entities(&self, group: AnimationGroupId) -> impl Iterator<Item = Entity> + '_ {
+        self.groups
+            .get(&group)
+            .into_iter()
+            .flatten()
+            .map(|member| member.entity)
+    }
+
+    fn take(world: &mut World, group: AnimationGroupId) -> Vec<GroupMember> {
+        world
+            .get_resource_mut::<Self>()
+            .and_then(|mut groups| groups.groups.remove(&group))
+            .unwrap_or_default()
+    }
+}
+
+/// Command removing all the animators of a group.
+///
+/// The targets are left in their current state. Animators already removed or
+/// whose entity was despawned are skipped.
+#[derive(Debug, Clone, Copy)]
+pub struct CancelAnimationGroup(pub AnimationGroupId);
+
+impl Command for CancelAnimationGroup {
+    fn apply(self, world: &mut World) {
+        for member in AnimationGroups::take(world, self.0) {
+            (member.cancel)(world, member.entity);
+        }
+    }
+}
+
+/// Command seeking all the animators of a group to their end.
+///
+/// The end state is applied to the targets on the next tick of the animators.
+/// Animators with an infinite duration are left untouched, as they have no
+/// end. Animators already removed or whose entity was despawned are skipped.
+#[derive(Debug, Clone, Copy)]
+pub struct CompleteAnimationGroup(pub AnimationGroupId);
+
+impl Command for CompleteAnimationGroup {
+    fn apply(self, world: &mut World) {
+        for member in AnimationGroups::take(world, self.0) {
+            (member.complete)(world, member.entity);
+        }
+    }
+}
+
+type InsertAnimator = Box<dyn FnOnce(&mut World, Entity) -> GroupMember + Send + Sync>;
+
+/// An entry of a [`HierarchyAnimation`].
+struct PlanEntry {
+    /// Name of the descendant to animate, or `None` for the root.
+    name: Option<Cow<'static, str>>,
+    insert: InsertAnimator,
+}
+
+/// A declarative plan of animators to insert on an entity hierarchy.
+///
+/// Each entry of the plan targets either the root entity of the hierarchy, or
+/// one of its descendants, identified by its [`Name`]. Applying the plan
+/// resolves the descendants and inserts all the animators at once, and groups
+/// them under a single [`AnimationGroupId`], so the whole animation can be
+/// cancelled or completed as a unit.
+///
+/// Coordinate the animators with a leading [`Delay`]. All animators start
+/// ticking on the same frame.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # fn slide_in() -> Tween<Transform> { unimplemented!() }
+/// # fn fade_in() -> Tween<Transform> { unimplemented!() }
+/// fn spawn_popup(mut commands: Commands) {
+///     let root = commands.spawn(SpatialBundle::default()).id();
+///     // [...] spawn the "Icon" and "Text" descendants
+///     let group = HierarchyAnimation::new()
+///         .at_root(slide_in())
+///         .at_named(
+///             "Icon",
+///             Delay::millis(200).then(fade_in()),
+///         )
+///         .at_named(
+///             "Text",
+///             Delay::millis(400).then(fade_in()),
+///         )
+///         .apply(&mut commands, root);
+///     // Later, tear down the whole animation
+///     commands.add(CancelAnimationGroup(group));
+/// }
+/// ```
+///
+/// [`Delay`]: crate::Delay
+#[derive(Default)]
+pub struct HierarchyAnimation {
+    entries: Vec<PlanEntry>,
+}
+
+impl HierarchyAnimation {
+    /// Create a new empty plan.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Animate the root entity of the hierarchy.
+    #[must_use]
+    pub fn at_root<T: Component>(self, tweenable: impl Tweenable<T> + 'static) -> Self {
+        self.push(None, tweenable)
+    }
+
+    /// Animate the descendant of the root entity with the given [`Name`].
+    ///
+    /// If several descendants have that name, the one closest to the root is
+    /// animated.
+    #[must_use]
+    pub fn at_named<T: Component>(
+        self,
+        name: impl Into<Cow<'static, str>>,
+        tweenable: impl Tweenable<T> + 'static,
+    ) -> Self {
+        self.push(Some(name.into()), tweenable)
+    }
+
+    fn push<T: Component>(
+        mut self,
+        name: Option<Cow<'static, str>>,
+        tweenable: impl Tweenable<T> + 'static,
+    ) -> Self {
+        let insert = move |world: &mut World, entity| {
+            world.entity_mut(entity).insert(Animator::new(tweenable));
+            GroupMember::new::<T>(entity)
+        };
+        self.entries.push(PlanEntry {
+            name,
+            insert: Box::new(insert),
+        });
+        self
+    }
+
+    /// Apply the plan to the hierarchy under `root`, and return the group of
+    /// the inserted animators.
+    ///
+    /// The animators are inserted when the commands are applied, replacing any
+    /// existing animator of the same type on each entity. An entry whose named
+    /// descendant can't be found logs a warning and is skipped, without
+    /// affecting the rest of the plan.
+    pub fn apply(self, commands: &mut Commands, root: Entity) -> AnimationGroupId {
+        let group = AnimationGroupId::new();
+        commands.add(move |world: &mut World| self.insert(world, root, group));
+        group
+    }
+
+    fn insert(self, world: &mut World, root: Entity, group: AnimationGroupId) {
+        if world.get_entity(root).is_none() {
+            warn!("Cannot apply hierarchy animation: root entity {root:?} doesn't exist.");
+            return;
+        }
+        let mut members = Vec::with_capacity(self.entries.len());
+        for entry in self.entries {
+            let entity = match &entry.name {
+                None => root,
+                Some(name) => {
+                    let Some(entity) = find_named(world, root, name) else {
+                        warn!("Cannot animate descendant \"{name}\" of entity {root:?}: no such descendant.");
+                        continue;
+                    };
+                    entity
+                }
+            };
+            members.push((entry.insert)(world, entity));
+        }
+        world
+            .get_resource_or_insert_with(AnimationGroups::default)
+            .groups
+            .insert(group, members);
+    }
+}
+
+/// Find the descendant of `root` with the given name closest to the root.
+fn find_named(world: &World, root: Entity, name: &str) -> Option<Entity> {
+    let mut queue = VecDeque::from([root]);
+    while let Some(entity) = queue.pop_front() {
+        let Some(children) = world.get::<Children>(entity) else {
+            continue;
+        };
+        for &child in children {
+            if world.get::<Name>(child).is_some_and(|n| n.as_str() == name) {
+                return Some(child);
+            }
+            queue.push_back(child);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::ecs::system::CommandQueue;
+
+    use super::*;
+    use crate::{lens::TransformPositionLens, Delay, EaseMethod, Tween};
+
+    fn tween(end: Vec3) -> Tween<Transform> {
+        Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end,
+            },
+        )
+    }
+
+    fn apply(world: &mut World, plan: HierarchyAnimation, root: Entity) -> AnimationGroupId {
+        let mut queue = CommandQueue::default();
+        let group = plan.apply(&mut Commands::new(&mut queue, world), root);
+        queue.apply(world);
+        group
+    }
+
+    fn end(world: &World, entity: Entity) -> Option<Vec3> {
+        let animator = world.get::<Animator<Transform>>(entity)?;
+        let mut transform = Transform::default();
+        let tweenable = animator.tweenable();
+        assert!(tweenable.sample(Duration::from_secs(10), &mut transform));
+        Some(transform.translation)
+    }
+
+    #[test]
+    fn hierarchy_animation() {
+        let mut world = World::new();
+        let text = world.spawn((Transform::default(), Name::new("Text"))).id();
+        let icon = world.spawn((Transform::default(), Name::new("Icon"))).id();
+        let panel = world.spawn((Transform::default(), Name::new("Panel"))).id();
+        let root = world.spawn(Transform::default()).id();
+        world.entity_mut(root).push_children(&[panel]);
+        world.entity_mut(panel).push_children(&[icon]);
+        world.entity_mut(icon).push_children(&[text]);
+
+        let plan = HierarchyAnimation::new()
+            .at_root(tween(Vec3::X))
+            .at_named(
+                "Icon",
+                Delay::new(Duration::from_millis(200)).then(tween(Vec3::Y)),
+            )
+            .at_named("Missing", tween(Vec3::NEG_X))
+            .at_named(
+                "Text",
+                Delay::new(Duration::from_millis(400)).then(tween(Vec3::Z)),
+            );
+        let group = apply(&mut world, plan, root);
+
+        // All animators land on the right entities, skipping the missing one
+        assert_eq!(end(&world, root), Some(Vec3::X));
+        assert_eq!(end(&world, panel), None);
+        assert_eq!(end(&world, icon), Some(Vec3::Y));
+        assert_eq!(end(&world, text), Some(Vec3::Z));
+        let groups = world.resource::<AnimationGroups>();
+        assert!(groups.contains(group));
+        assert_eq!(
+            groups.entities(group).collect::<Vec<_>>(),
+            vec![root, icon, text]
+        );
+
+        // Complete the group
+        let mut queue = CommandQueue::default();
+        Commands::new(&mut queue, &world).add(CompleteAnimationGroup(group));
+        queue.apply(&mut world);
+        assert!(!world.resource::<AnimationGroups>().contains(group));
+        let animator = world.get::<Animator<Transform>>(text).unwrap();
+        assert_eq!(animator.fraction_remaining(), Some(0.));
+
+        // Cancel another group
+        let group = apply(
+            &mut world,
+            HierarchyAnimation::new().at_named("Panel", tween(Vec3::ONE)),
+            root,
+        );
+        assert!(world.get::<Animator<Transform>>(panel).is_some());
+        let mut queue = CommandQueue::default();
+        Commands::new(&mut queue, &world).add(CancelAnimationGroup(group));
+        queue.apply(&mut world);
+        assert!(world.get::<Animator<Transform>>(panel).is_none());
+        assert!(world.get::<Animator<Transform>>(root).is_some());
+        assert!(!world.resource::<AnimationGroups>().contains(group));
+    }
+}
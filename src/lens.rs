@@ -31,80 +31,1334 @@
 //! - [`TransformRotateZLens`]
 //! - [`TransformRotateAxisLens`]
 //!
+//! # Feature-gated lenses
+//!
+//! Only the [`Transform`] lenses and the generic lenses like [`ValueLens`] or
+//! [`RectLens`] are always available. Lenses for other Bevy
+//! components and assets are split by the Bevy crate they depend on, and are
+//! only compiled when the matching cargo feature is enabled:
+//!
+//! | Feature       | Lenses                                                      |
+//! |---------------|-------------------------------------------------------------|
+//! | `bevy_sprite` | `SpriteColorLens`, `SpriteColorPrecomputedLens`,            |
+//! |               | `SpriteCustomSizeLens`, `SpriteRectLens`,                   |
+//! |               | `ColorMaterialColorLens`                                    |
+//! | `bevy_ui`     | `UiPositionLens`, `UiBackgroundColorLens`, `UiZIndexLens`   |
+//! | `bevy_text`   | `TextColorLens`                                             |
+//! | `bevy_window` | `WindowResolutionLens`, `WindowPositionLens`                |
+//! | `bevy_pbr`    | `StandardMaterialBaseColorLens`, `AmbientLightLens`,        |
+//! |               | `FogLens`, `ExposureLens`,                                  |
+//! |               | `DirectionalLightShadowDepthBiasLens`,                      |
+//! |               | `DirectionalLightShadowNormalBiasLens`                      |
+//!
+//! Building with `--no-default-features` leaves only the math-based core of
+//! the crate, which doesn't depend on any Bevy rendering crate.
+//!
+//! # Color spaces
+//!
+//! The color lenses interpolate in the sRGB color space by default. Each of
+//! them can instead interpolate in another [`ColorSpace`], selected with
+//! `with_space()`, or be constructed with `oklab()` from colors authored in
+//! the [`Oklaba`] color space. See [`ColorSpace`] for details on hue
+//! interpolation and endpoint precision.
+//!
+//! # Clamping
+//!
+//! Some easing functions like [`EaseFunction::BackOut`] or
+//! [`EaseFunction::ElasticIn`] overshoot, and produce a `ratio` outside of
+//! \[0:1\]. This is desirable for spatial quantities, where the overshoot is
+//! the point of the easing, but produces invalid values for bounded
+//! quantities like an alpha channel or a light intensity.
+//!
+//! For that reason, the built-in lenses animating a color, a brightness, or a
+//! fog falloff clamp the ratio to \[0:1\] before interpolating:
+//! - `ColorMaterialColorLens`, `SpriteColorLens`, `SpriteColorPrecomputedLens`
+//! - `UiBackgroundColorLens`, `UiOpacityLens`
+//! - `TextColorLens`
+//! - `StandardMaterialBaseColorLens`, `AmbientLightLens`, `FogLens`
+//!
+//! All other built-in lenses, like the [`Transform`] lenses or
+//! `UiPositionLens`, are left unclamped and follow the easing overshoot. To
+//! clamp any other lens, including a custom one, wrap it into a [`Clamped`]
+//! adapter.
+//!
+//! [`EaseFunction::BackOut`]: crate::EaseFunction::BackOut
+//! [`EaseFunction::ElasticIn`]: crate::EaseFunction::ElasticIn
 //! [`rotation`]: https://docs.rs/bevy/0.12.0/bevy/transform/components/struct.Transform.html#structfield.rotation
 //! [`Transform`]: https://docs.rs/bevy/0.12.0/bevy/transform/components/struct.Transform.html
 //! [`Quat::slerp()`]: https://docs.rs/bevy/0.12.0/bevy/math/struct.Quat.html#method.slerp
 
-use bevy::prelude::*;
+use std::{
+    any::Any,
+    ops::{Add, Mul, Sub},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use bevy::prelude::*;
+
+use crate::TweeningDirection;
+
+/// Implement [`RangedLens`] for a lens with `start` and `end` fields of a
+/// [`Copy`] type.
+macro_rules! impl_ranged_lens {
+    ($lens:ty, $value:ty) => {
+        impl $crate::lens::RangedLens for $lens {
+            type Value = $value;
+
+            fn start(&self) -> $value {
+                self.start
+            }
+
+            fn set_start(&mut self, start: $value) {
+                self.start = start;
+            }
+
+            fn end(&self) -> $value {
+                self.end
+            }
+
+            fn set_end(&mut self, end: $value) {
+                self.end = end;
+            }
+        }
+    };
+}
+
+/// Implement [`Lens::is_valid()`] for a lens, checking that the given fields
+/// have no NaN component.
+macro_rules! lens_is_valid {
+    ($($field:ident),+) => {
+        fn is_valid(&self) -> bool {
+            $($crate::lens::is_nan_free(&self.$field))&&+
+        }
+    };
+}
+
+/// Check that a value has no NaN component.
+///
+/// Floating-point NaN values are not equal to themselves, so neither is any
+/// value containing one and comparing its fields with `==`, like the math and
+/// color types of Bevy.
+#[allow(clippy::eq_op)]
+pub(crate) fn is_nan_free<V: PartialEq>(value: &V) -> bool {
+    value == value
+}
+
+/// Implement [`Lens::scale_range()`] for a [`RangedLens`] whose value
+/// supports arithmetic, with [`scale_ranged()`].
+macro_rules! lens_scale_range {
+    () => {
+        fn scale_range(&mut self, factor: f32) -> bool {
+            $crate::lens::scale_ranged(self, factor);
+            true
+        }
+    };
+}
+
+/// Implement [`Lens::boundary_value()`], [`Lens::value_at()`], and
+/// [`Lens::lerp_from()`] for a [`RangedLens`] whose value supports arithmetic,
+/// with [`lerp_ranged_from()`].
+macro_rules! lens_blend {
+    ($target:ty) => {
+        fn value_at(&self, ratio: f32) -> Option<Box<dyn std::any::Any + Send + Sync>> {
+            Some(Box::new(self.start + (self.end - self.start) * ratio))
+        }
+
+        fn boundary_value(
+            &self,
+            direction: $crate::TweeningDirection,
+        ) -> Option<Box<dyn std::any::Any + Send + Sync>> {
+            Some(Box::new(match direction {
+                $crate::TweeningDirection::Forward => self.end,
+                $crate::TweeningDirection::Backward => self.start,
+            }))
+        }
+
+        fn lerp_from(
+            &mut self,
+            target: &mut $target,
+            ratio: f32,
+            from: &dyn std::any::Any,
+            weight: f32,
+        ) -> bool {
+            $crate::lens::lerp_ranged_from(self, target, ratio, from, weight)
+        }
+    };
+}
+
+/// Interpolate a ranged lens from a start value blended between `from` and
+/// the start value of the lens with `weight`.
+///
+/// Returns `false` if `from` is not a value of the lens.
+pub(crate) fn lerp_ranged_from<T, L, V>(
+    lens: &mut L,
+    target: &mut T,
+    ratio: f32,
+    from: &dyn Any,
+    weight: f32,
+) -> bool
+where
+    L: Lens<T> + RangedLens<Value = V> + ?Sized,
+    V: Copy + Add<Output = V> + Sub<Output = V> + Mul<f32, Output = V> + 'static,
+{
+    let Some(&from) = from.downcast_ref::<V>() else {
+        return false;
+    };
+    let start = lens.start();
+    lens.set_start(from + (start - from) * weight);
+    lens.lerp(target, ratio);
+    lens.set_start(start);
+    true
+}
+
+/// Move the end value of a ranged lens around its start value, scaling the
+/// range of the lens by `factor`.
+pub(crate) fn scale_ranged<L, V>(lens: &mut L, factor: f32)
+where
+    L: RangedLens<Value = V> + ?Sized,
+    V: Copy + Add<Output = V> + Sub<Output = V> + Mul<f32, Output = V>,
+{
+    let start = lens.start();
+    lens.set_end(start + (lens.end() - start) * factor);
+}
+
+#[cfg(any(
+    feature = "bevy_sprite",
+    feature = "bevy_ui",
+    feature = "bevy_text",
+    feature = "bevy_pbr"
+))]
+#[macro_use]
+mod color;
+#[cfg(feature = "bevy_pbr")]
+mod pbr;
+#[cfg(feature = "bevy_sprite")]
+mod sprite;
+#[cfg(feature = "bevy_text")]
+mod text;
+#[cfg(feature = "bevy_ui")]
+mod ui;
+#[cfg(feature = "bevy_window")]
+mod window;
+
+#[cfg(any(
+    feature = "bevy_sprite",
+    feature = "bevy_ui",
+    feature = "bevy_text",
+    feature = "bevy_pbr"
+))]
+pub use color::{ColorSpace, Oklaba};
+#[cfg(feature = "bevy_pbr")]
+pub use pbr::*;
+#[cfg(feature = "bevy_sprite")]
+pub use sprite::*;
+#[cfg(feature = "bevy_text")]
+pub use text::*;
+#[cfg(feature = "bevy_ui")]
+pub use ui::*;
+#[cfg(feature = "bevy_window")]
+pub use window::*;
+
+/// Context of the target of a [`Lens`], for lenses depending on more than the
+/// value they animate.
+///
+/// The context is passed to [`Lens::set_context()`] each time a [`Tween`]
+/// ticks. The animator system of [`Transform`] fills the [`parent`] field;
+/// all other targets get a default context.
+///
+/// [`Tween`]: crate::Tween
+/// [`Transform`]: https://docs.rs/bevy/0.12.0/bevy/transform/components/struct.Transform.html
+/// [`parent`]: LensContext::parent
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct LensContext {
+    /// The [`GlobalTransform`] of the parent of the animated entity, if the
+    /// entity has a parent.
+    ///
+    /// This is the value computed by the transform propagation of the
+    /// previous frame, since the animator systems run before it.
+    ///
+    /// [`GlobalTransform`]: https://docs.rs/bevy/0.12.0/bevy/transform/components/struct.GlobalTransform.html
+    pub parent: Option<GlobalTransform>,
+}
+
+/// A lens over a subset of a component.
+///
+/// The lens takes a `target` component or asset from a query, as a mutable
+/// reference, and animates (tweens) a subset of the fields of the
+/// component/asset based on the linear ratio `ratio` in \[0:1\], already
+/// sampled from the easing curve.
+///
+/// # Example
+///
+/// Implement `Lens` for a custom type:
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::*;
+/// struct MyLens {
+///   start: f32,
+///   end: f32,
+/// }
+///
+/// #[derive(Component)]
+/// struct MyStruct(f32);
+///
+/// impl Lens<MyStruct> for MyLens {
+///   fn lerp(&mut self, target: &mut MyStruct, ratio: f32) {
+///     target.0 = self.start + (self.end - self.start) * ratio;
+///   }
+/// }
+/// ```
+pub trait Lens<T> {
+    /// Perform a linear interpolation (lerp) over the subset of fields of a
+    /// component or asset the lens focuses on, based on the linear ratio
+    /// `ratio`. The `target` component or asset is mutated in place. The
+    /// implementation decides which fields are interpolated, and performs
+    /// the animation in-place, overwriting the target.
+    fn lerp(&mut self, target: &mut T, ratio: f32);
+
+    /// Apply the lens to `target` at the given `ratio`, like [`lerp()`], but
+    /// without modifying the lens itself.
+    ///
+    /// This allows previewing an animation with [`Tweenable::sample()`],
+    /// without changing the state of the live lens. Returns `false` if the
+    /// lens doesn't support sampling, in which case `target` is left
+    /// untouched. The default implementation doesn't support sampling; the
+    /// [`Transform`] lenses of this crate do.
+    ///
+    /// [`lerp()`]: Lens::lerp
+    /// [`Tweenable::sample()`]: crate::Tweenable::sample
+    /// [`Transform`]: https://docs.rs/bevy/0.12.0/bevy/transform/components/struct.Transform.html
+    fn sample(&self, target: &mut T, ratio: f32) -> bool {
+        let _ = (target, ratio);
+        false
+    }
+
+    /// Perform a linear interpolation like [`lerp()`], knowing the current
+    /// playback direction of the [`Tween`] owning the lens.
+    ///
+    /// This is invoked by the default implementation of [`lerp_ex()`], the
+    /// method a [`Tween`] invokes to animate its target. The default
+    /// implementation ignores the direction and forwards to [`lerp()`]; only
+    /// lenses behaving differently on the forward and
+    /// backward passes of a [`RepeatStrategy::MirroredRepeat`] tween, like
+    /// [`DirectionalLens`], need to override it. Lens adapters wrapping
+    /// another lens should forward it to that lens.
+    ///
+    /// [`lerp()`]: Lens::lerp
+    /// [`lerp_ex()`]: Lens::lerp_ex
+    /// [`Tween`]: crate::Tween
+    /// [`RepeatStrategy::MirroredRepeat`]: crate::RepeatStrategy::MirroredRepeat
+    fn lerp_directed(&mut self, target: &mut T, ratio: f32, direction: TweeningDirection) {
+        let _ = direction;
+        self.lerp(target, ratio);
+    }
+
+    /// Perform a linear interpolation like [`lerp_directed()`], knowing both
+    /// the eased ratio and the raw linear ratio it was eased from.
+    ///
+    /// This is the method a [`Tween`] invokes to animate its target. The
+    /// `eased` ratio is the one passed to [`lerp()`], while `raw` is the
+    /// progress of the tween before applying its easing function, so that
+    /// `eased` is the easing function sampled at `raw`. Like `eased`, `raw`
+    /// goes from `1.0` down to `0.0` on a backward pass. This allows a lens
+    /// to follow the elapsed time separately from the eased position, for
+    /// example to place trailing ghosts along the portion of the path
+    /// already traversed.
+    ///
+    /// The default implementation ignores `raw` and forwards to
+    /// [`lerp_directed()`], so most lenses only implement [`lerp()`]. Lens
+    /// adapters wrapping another lens should forward it to that lens.
+    ///
+    /// [`lerp()`]: Lens::lerp
+    /// [`lerp_directed()`]: Lens::lerp_directed
+    /// [`Tween`]: crate::Tween
+    fn lerp_ex(&mut self, target: &mut T, eased: f32, raw: f32, direction: TweeningDirection) {
+        let _ = raw;
+        self.lerp_directed(target, eased, direction);
+    }
+
+    /// Sample the lens like [`sample()`], knowing the playback direction of
+    /// the [`Tween`] owning the lens at the sampled time.
+    ///
+    /// See [`lerp_directed()`] for details.
+    ///
+    /// [`sample()`]: Lens::sample
+    /// [`lerp_directed()`]: Lens::lerp_directed
+    /// [`Tween`]: crate::Tween
+    fn sample_directed(&self, target: &mut T, ratio: f32, direction: TweeningDirection) -> bool {
+        let _ = direction;
+        self.sample(target, ratio)
+    }
+
+    /// Check whether the parameters of the lens are valid.
+    ///
+    /// This is used by [`Tweenable::validate()`] to check a tweenable before
+    /// it plays. The default implementation accepts any lens. The built-in
+    /// lenses reject start and end values with a NaN component, which would
+    /// poison the animated target. Lens adapters wrapping another lens should
+    /// forward it to that lens.
+    ///
+    /// [`Tweenable::validate()`]: crate::Tweenable::validate
+    fn is_valid(&self) -> bool {
+        true
+    }
+
+    /// Receive the [`LensContext`] of the target, right before the lens is
+    /// applied.
+    ///
+    /// A [`Tween`] invokes this on each tick, before animating its target.
+    /// The default implementation ignores the context; only lenses depending
+    /// on more than the value they animate, like [`WorldPositionLens`], need
+    /// to override it. Lens adapters wrapping another lens should forward it
+    /// to that lens.
+    ///
+    /// [`Tween`]: crate::Tween
+    fn set_context(&mut self, context: &LensContext) {
+        let _ = context;
+    }
+
+    /// Scale the range of the lens by `factor`, moving its end value around
+    /// its start value.
+    ///
+    /// This is used by [`TweenOverrides::scale_lens_range()`] to vary the
+    /// amplitude of the animations instantiated from a [`TweenPrototype`].
+    /// Returns `false` if the lens doesn't support scaling, in which case
+    /// it's left unchanged. The default implementation doesn't support
+    /// scaling. The [`Transform`] lenses, the color lenses, and [`ValueLens`]
+    /// support it; the color lenses don't extrapolate, so a factor above
+    /// `1.0` leaves their end color unchanged. Lens adapters wrapping another
+    /// lens should forward it to that lens.
+    ///
+    /// [`TweenOverrides::scale_lens_range()`]: crate::TweenOverrides::scale_lens_range
+    /// [`TweenPrototype`]: crate::TweenPrototype
+    /// [`Transform`]: https://docs.rs/bevy/0.12.0/bevy/transform/components/struct.Transform.html
+    fn scale_range(&mut self, factor: f32) -> bool {
+        let _ = factor;
+        false
+    }
+
+    /// Get the value the lens reaches at the end of a pass in `direction`,
+    /// to blend it into the start of another lens with [`lerp_from()`].
+    ///
+    /// This is used by a [`Sequence`] with a [boundary blend] to smooth the
+    /// transition between two consecutive children. The value is type-erased,
+    /// and only meaningful to a lens animating a value of the same type.
+    /// Returns `None` if the lens doesn't support blending, which is the
+    /// default. The [`Transform`] lenses and the color lenses support it.
+    ///
+    /// [`lerp_from()`]: Lens::lerp_from
+    /// [`Sequence`]: crate::Sequence
+    /// [boundary blend]: crate::Sequence::with_boundary_blend
+    /// [`Transform`]: https://docs.rs/bevy/0.12.0/bevy/transform/components/struct.Transform.html
+    fn boundary_value(&self, direction: TweeningDirection) -> Option<Box<dyn Any + Send + Sync>> {
+        let _ = direction;
+        None
+    }
+
+    /// Get the value the lens produces for `ratio`, to blend it into the
+    /// start of another lens with [`lerp_from()`].
+    ///
+    /// This is like [`boundary_value()`] for any point of the pass, and is
+    /// used to blend from an animation interrupted before it completed, like
+    /// the transitions of a [`TweenStateMachine`]. The value is type-erased,
+    /// and only meaningful to a lens animating a value of the same type.
+    /// Returns `None` if the lens doesn't support blending, which is the
+    /// default. The lenses supporting [`boundary_value()`] support it too.
+    ///
+    /// [`lerp_from()`]: Lens::lerp_from
+    /// [`boundary_value()`]: Lens::boundary_value
+    /// [`TweenStateMachine`]: crate::TweenStateMachine
+    fn value_at(&self, ratio: f32) -> Option<Box<dyn Any + Send + Sync>> {
+        let _ = ratio;
+        None
+    }
+
+    /// Perform a linear interpolation like [`lerp()`], from a start value
+    /// blended between `from` and the start value of the lens.
+    ///
+    /// The blended start value is `from` for a `weight` of `0.0`, and the
+    /// start value of the lens for a `weight` of `1.0`, where this is
+    /// equivalent to [`lerp()`]. The `from` value was returned by
+    /// [`boundary_value()`], possibly by another lens. Returns `false` if the
+    /// lens doesn't support blending, or if `from` is not a value of the type
+    /// the lens animates, in which case `target` is left untouched. The
+    /// default implementation doesn't support blending.
+    ///
+    /// [`lerp()`]: Lens::lerp
+    /// [`boundary_value()`]: Lens::boundary_value
+    fn lerp_from(&mut self, target: &mut T, ratio: f32, from: &dyn Any, weight: f32) -> bool {
+        let _ = (target, ratio, from, weight);
+        false
+    }
+}
+
+/// A lens adapter clamping the ratio passed to another lens.
+///
+/// The ratio is clamped to \[`min_factor`:`max_factor`\] before being
+/// forwarded to the wrapped lens. This prevents overshooting easing functions
+/// from producing out-of-range values for a bounded quantity. By default the
+/// ratio is clamped to \[0:1\].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// // Allow a 10% overshoot at most
+/// let lens = Clamped::new(TransformScaleLens {
+///     start: Vec3::ONE,
+///     end: Vec3::splat(2.),
+/// })
+/// .with_range(0., 1.1);
+/// let tween = Tween::new(
+///     EaseFunction::ElasticOut,
+///     1.,
+///     lens,
+/// );
+/// ```
+///
+/// [`min_factor`]: Clamped::min_factor
+/// [`max_factor`]: Clamped::max_factor
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Clamped<L> {
+    /// The wrapped lens.
+    pub lens: L,
+    /// Minimum ratio forwarded to the wrapped lens.
+    pub min_factor: f32,
+    /// Maximum ratio forwarded to the wrapped lens.
+    pub max_factor: f32,
+}
+
+impl<L> Clamped<L> {
+    /// Wrap a lens, clamping its ratio to \[0:1\].
+    pub fn new(lens: L) -> Self {
+        Self {
+            lens,
+            min_factor: 0.,
+            max_factor: 1.,
+        }
+    }
+
+    /// Change the range the ratio is clamped to.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_factor` is greater than `max_factor`, or if either
+    /// value is NaN.
+    #[must_use]
+    pub fn with_range(mut self, min_factor: f32, max_factor: f32) -> Self {
+        assert!(min_factor <= max_factor);
+        self.min_factor = min_factor;
+        self.max_factor = max_factor;
+        self
+    }
+}
+
+impl<T, L: Lens<T>> Lens<T> for Clamped<L> {
+    fn lerp(&mut self, target: &mut T, ratio: f32) {
+        let ratio = ratio.clamp(self.min_factor, self.max_factor);
+        self.lens.lerp(target, ratio);
+    }
+
+    fn lerp_from(&mut self, target: &mut T, ratio: f32, from: &dyn Any, weight: f32) -> bool {
+        let ratio = ratio.clamp(self.min_factor, self.max_factor);
+        self.lens.lerp_from(target, ratio, from, weight)
+    }
+
+    fn sample(&self, target: &mut T, ratio: f32) -> bool {
+        let ratio = ratio.clamp(self.min_factor, self.max_factor);
+        self.lens.sample(target, ratio)
+    }
+
+    fn lerp_directed(&mut self, target: &mut T, ratio: f32, direction: TweeningDirection) {
+        let ratio = ratio.clamp(self.min_factor, self.max_factor);
+        self.lens.lerp_directed(target, ratio, direction);
+    }
+
+    fn lerp_ex(&mut self, target: &mut T, eased: f32, raw: f32, direction: TweeningDirection) {
+        let eased = eased.clamp(self.min_factor, self.max_factor);
+        self.lens.lerp_ex(target, eased, raw, direction);
+    }
+
+    fn sample_directed(&self, target: &mut T, ratio: f32, direction: TweeningDirection) -> bool {
+        let ratio = ratio.clamp(self.min_factor, self.max_factor);
+        self.lens.sample_directed(target, ratio, direction)
+    }
+
+    fn is_valid(&self) -> bool {
+        self.lens.is_valid()
+    }
+
+    fn set_context(&mut self, context: &LensContext) {
+        self.lens.set_context(context);
+    }
+
+    fn scale_range(&mut self, factor: f32) -> bool {
+        self.lens.scale_range(factor)
+    }
+
+    fn boundary_value(&self, direction: TweeningDirection) -> Option<Box<dyn Any + Send + Sync>> {
+        self.lens.boundary_value(direction)
+    }
+}
+
+/// A lens adapter applying a different lens on the forward and backward
+/// passes of a tween.
+///
+/// The `forward` lens is applied while the [`Tween`] owning the adapter plays
+/// forward, and the `backward` lens while it plays backward. Combined with
+/// [`RepeatStrategy::MirroredRepeat`], this allows styling the two passes of
+/// a ping-pong animation differently, without alternating two tweens. Both
+/// lenses receive the same ratio, so the `backward` lens is expected to
+/// describe the same motion from its start to its end; the ratio decreases
+/// while playing backward.
+///
+/// When used directly through [`Lens::lerp()`] or [`Lens::sample()`], without
+/// any direction, the adapter applies the `forward` lens.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// // Bright while scanning right, dim while coming back
+/// let lens = DirectionalLens::new(
+///     TransformScaleLens {
+///         start: Vec3::ONE,
+///         end: Vec3::splat(2.),
+///     },
+///     TransformScaleLens {
+///         start: Vec3::ONE,
+///         end: Vec3::splat(1.2),
+///     },
+/// );
+/// let tween = Tween::new(EaseFunction::QuadraticInOut, 1., lens)
+///     .with_repeat_count(RepeatCount::Infinite)
+///     .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
+/// ```
+///
+/// [`Tween`]: crate::Tween
+/// [`RepeatStrategy::MirroredRepeat`]: crate::RepeatStrategy::MirroredRepeat
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DirectionalLens<LF, LB> {
+    /// Lens applied while the tween plays forward.
+    pub forward: LF,
+    /// Lens applied while the tween plays backward.
+    pub backward: LB,
+}
+
+impl<LF, LB> DirectionalLens<LF, LB> {
+    /// Create an adapter from the lenses of the forward and backward passes.
+    pub fn new(forward: LF, backward: LB) -> Self {
+        Self { forward, backward }
+    }
+}
+
+impl<T, LF: Lens<T>, LB: Lens<T>> Lens<T> for DirectionalLens<LF, LB> {
+    fn lerp(&mut self, target: &mut T, ratio: f32) {
+        self.forward.lerp(target, ratio);
+    }
+
+    fn sample(&self, target: &mut T, ratio: f32) -> bool {
+        self.forward.sample(target, ratio)
+    }
+
+    fn lerp_directed(&mut self, target: &mut T, ratio: f32, direction: TweeningDirection) {
+        match direction {
+            TweeningDirection::Forward => self.forward.lerp_directed(target, ratio, direction),
+            TweeningDirection::Backward => self.backward.lerp_directed(target, ratio, direction),
+        }
+    }
+
+    fn lerp_ex(&mut self, target: &mut T, eased: f32, raw: f32, direction: TweeningDirection) {
+        match direction {
+            TweeningDirection::Forward => self.forward.lerp_ex(target, eased, raw, direction),
+            TweeningDirection::Backward => self.backward.lerp_ex(target, eased, raw, direction),
+        }
+    }
+
+    fn sample_directed(&self, target: &mut T, ratio: f32, direction: TweeningDirection) -> bool {
+        match direction {
+            TweeningDirection::Forward => self.forward.sample_directed(target, ratio, direction),
+            TweeningDirection::Backward => self.backward.sample_directed(target, ratio, direction),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.forward.is_valid() && self.backward.is_valid()
+    }
+
+    fn set_context(&mut self, context: &LensContext) {
+        self.forward.set_context(context);
+        self.backward.set_context(context);
+    }
+
+    fn scale_range(&mut self, factor: f32) -> bool {
+        // Scale both lenses, even if one doesn't support it
+        let forward = self.forward.scale_range(factor);
+        self.backward.scale_range(factor) && forward
+    }
+}
+
+/// A lens adapter computing a secondary value from the same ratio as another
+/// lens.
+///
+/// The wrapped `lens` animates the target as usual. Right after, the `derive`
+/// function receives the updated target and the same ratio, and its result is
+/// stored in the [`DerivedValue`] handle of the adapter. This allows driving a
+/// secondary quantity, which can't be animated by a lens on the same target,
+/// from a single tween, like the volume of the engine sound of a vehicle
+/// flying past the camera. A system then reads the handle to apply the value,
+/// which is always in sync with the primary animation.
+///
+/// Sampling the adapter with [`Lens::sample()`] previews the wrapped lens
+/// only, and leaves the handle untouched.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::{f32::consts::PI, time::Duration};
+/// #[derive(Component)]
+/// struct EngineVolume(DerivedValue<f32>);
+///
+/// fn fly_past(mut commands: Commands) {
+///     // Volume follows a bell curve, loudest halfway
+///     let lens = DerivedLens::new(
+///         TransformPositionLens {
+///             start: Vec3::new(-100., 20., 0.),
+///             end: Vec3::new(100., 20., 0.),
+///         },
+///         |_transform: &Transform, ratio: f32| (ratio * PI).sin(),
+///     );
+///     let volume = EngineVolume(lens.value());
+///     let tween = Tween::new(EaseMethod::Linear, 8., lens);
+///     commands.spawn((SpatialBundle::default(), Animator::new(tween), volume));
+/// }
+///
+/// fn apply_volume(query: Query<&EngineVolume>) {
+///     for volume in &query {
+///         if let Some(volume) = volume.0.get() {
+///             // [...] set the volume of the audio sink
+///         }
+///     }
+/// }
+/// ```
+pub struct DerivedLens<L, F, V> {
+    /// The wrapped lens, animating the target.
+    pub lens: L,
+    derive: F,
+    value: DerivedValue<V>,
+}
+
+impl<L, F, V> DerivedLens<L, F, V> {
+    /// Wrap a lens, deriving a secondary value with the `derive` function
+    /// each time the lens is applied.
+    pub fn new(lens: L, derive: F) -> Self {
+        Self {
+            lens,
+            derive,
+            value: DerivedValue(Arc::new(Mutex::new(None))),
+        }
+    }
+
+    /// Get a handle to the derived value.
+    pub fn value(&self) -> DerivedValue<V> {
+        self.value.clone()
+    }
+}
+
+impl<T, L: Lens<T>, F: Fn(&T, f32) -> V, V> Lens<T> for DerivedLens<L, F, V> {
+    fn lerp(&mut self, target: &mut T, ratio: f32) {
+        self.lens.lerp(target, ratio);
+        self.value.set((self.derive)(target, ratio));
+    }
+
+    fn sample(&self, target: &mut T, ratio: f32) -> bool {
+        self.lens.sample(target, ratio)
+    }
+
+    fn lerp_directed(&mut self, target: &mut T, ratio: f32, direction: TweeningDirection) {
+        self.lens.lerp_directed(target, ratio, direction);
+        self.value.set((self.derive)(target, ratio));
+    }
+
+    fn lerp_ex(&mut self, target: &mut T, eased: f32, raw: f32, direction: TweeningDirection) {
+        self.lens.lerp_ex(target, eased, raw, direction);
+        self.value.set((self.derive)(target, eased));
+    }
+
+    fn sample_directed(&self, target: &mut T, ratio: f32, direction: TweeningDirection) -> bool {
+        self.lens.sample_directed(target, ratio, direction)
+    }
+
+    fn is_valid(&self) -> bool {
+        self.lens.is_valid()
+    }
+
+    fn set_context(&mut self, context: &LensContext) {
+        self.lens.set_context(context);
+    }
+
+    fn scale_range(&mut self, factor: f32) -> bool {
+        self.lens.scale_range(factor)
+    }
+
+    fn boundary_value(&self, direction: TweeningDirection) -> Option<Box<dyn Any + Send + Sync>> {
+        self.lens.boundary_value(direction)
+    }
+
+    fn lerp_from(&mut self, target: &mut T, ratio: f32, from: &dyn Any, weight: f32) -> bool {
+        let supported = self.lens.lerp_from(target, ratio, from, weight);
+        if supported {
+            self.value.set((self.derive)(target, ratio));
+        }
+        supported
+    }
+}
+
+/// Handle to the value computed by a [`DerivedLens`].
+///
+/// The handle is cheap to clone, and can be stored anywhere, for example in a
+/// component of the animated entity.
+#[derive(Debug)]
+pub struct DerivedValue<V>(Arc<Mutex<Option<V>>>);
+
+impl<V> Clone for DerivedValue<V> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<V> DerivedValue<V> {
+    fn set(&self, value: V) {
+        *self.0.lock().unwrap() = Some(value);
+    }
+
+    /// The last value derived by the lens, or `None` if the lens was never
+    /// applied.
+    pub fn get(&self) -> Option<V>
+    where
+        V: Clone,
+    {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+/// A lens adapter running a function on the target after another lens.
+///
+/// The wrapped `lens` animates the target as usual. Right after, the
+/// `post_process` function receives the updated target, to update any field
+/// derived from the animated ones in the same tick. This avoids a separate
+/// system recomputing that field each frame after the animator systems, and
+/// keeps the target consistent whenever the lens is applied or sampled.
+///
+/// A typical use is the depth sorting of 2.5D and isometric games, where the Z
+/// coordinate of a sprite must follow its Y coordinate as it moves, so that
+/// the sprites lower on screen are drawn in front of the ones above them.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// /// Sprites lower on screen are drawn in front.
+/// fn depth(y: f32) -> f32 {
+///     -y * 0.001
+/// }
+///
+/// fn walk(mut commands: Commands, entity: Entity) {
+///     let lens = PostProcessLens::new(
+///         TransformPositionLens {
+///             start: Vec3::new(0., 100., 0.),
+///             end: Vec3::new(40., -20., 0.),
+///         },
+///         |transform: &mut Transform| transform.translation.z = depth(transform.translation.y),
+///     );
+///     let tween = Tween::new(EaseFunction::QuadraticInOut, 1., lens);
+///     commands.entity(entity).insert(Animator::new(tween));
+/// }
+/// ```
+pub struct PostProcessLens<L, F> {
+    /// The wrapped lens, animating the target.
+    pub lens: L,
+    post_process: F,
+}
+
+impl<L, F> PostProcessLens<L, F> {
+    /// Wrap a lens, running the `post_process` function on the target each
+    /// time the lens is applied.
+    pub fn new(lens: L, post_process: F) -> Self {
+        Self { lens, post_process }
+    }
+}
+
+impl<T, L: Lens<T>, F: Fn(&mut T)> Lens<T> for PostProcessLens<L, F> {
+    fn lerp(&mut self, target: &mut T, ratio: f32) {
+        self.lens.lerp(target, ratio);
+        (self.post_process)(target);
+    }
+
+    fn sample(&self, target: &mut T, ratio: f32) -> bool {
+        let supported = self.lens.sample(target, ratio);
+        if supported {
+            (self.post_process)(target);
+        }
+        supported
+    }
+
+    fn lerp_directed(&mut self, target: &mut T, ratio: f32, direction: TweeningDirection) {
+        self.lens.lerp_directed(target, ratio, direction);
+        (self.post_process)(target);
+    }
+
+    fn lerp_ex(&mut self, target: &mut T, eased: f32, raw: f32, direction: TweeningDirection) {
+        self.lens.lerp_ex(target, eased, raw, direction);
+        (self.post_process)(target);
+    }
+
+    fn sample_directed(&self, target: &mut T, ratio: f32, direction: TweeningDirection) -> bool {
+        let supported = self.lens.sample_directed(target, ratio, direction);
+        if supported {
+            (self.post_process)(target);
+        }
+        supported
+    }
+
+    fn is_valid(&self) -> bool {
+        self.lens.is_valid()
+    }
+
+    fn set_context(&mut self, context: &LensContext) {
+        self.lens.set_context(context);
+    }
+
+    fn scale_range(&mut self, factor: f32) -> bool {
+        self.lens.scale_range(factor)
+    }
+
+    fn boundary_value(&self, direction: TweeningDirection) -> Option<Box<dyn Any + Send + Sync>> {
+        self.lens.boundary_value(direction)
+    }
+
+    fn lerp_from(&mut self, target: &mut T, ratio: f32, from: &dyn Any, weight: f32) -> bool {
+        let supported = self.lens.lerp_from(target, ratio, from, weight);
+        if supported {
+            (self.post_process)(target);
+        }
+        supported
+    }
+}
+
+impl<L: RangedLens, F> RangedLens for PostProcessLens<L, F> {
+    type Value = L::Value;
+
+    fn start(&self) -> L::Value {
+        self.lens.start()
+    }
+
+    fn set_start(&mut self, start: L::Value) {
+        self.lens.set_start(start);
+    }
+
+    fn end(&self) -> L::Value {
+        self.lens.end()
+    }
+
+    fn set_end(&mut self, end: L::Value) {
+        self.lens.set_end(end);
+    }
+}
+
+/// A lens interpolating between a start and an end value.
+///
+/// This gives access to the endpoints of a lens, including once it's boxed
+/// inside a [`Tween`], where it can be reached with [`Tween::map_lens()`] to
+/// retarget a running animation. All the built-in lenses implement this
+/// trait. Lenses animating several fields at once, like `AmbientLightLens`,
+/// use a tuple of those fields as their [`Value`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// let mut tween = Tween::new(
+///     EaseFunction::QuadraticInOut,
+///     1.,
+///     TransformPositionLens {
+///         start: Vec3::ZERO,
+///         end: Vec3::X,
+///     },
+/// );
+/// // Move the destination of the tween
+/// let found = tween.map_lens(|lens: &mut TransformPositionLens| lens.set_end(Vec3::Y));
+/// assert!(found);
+/// ```
+///
+/// [`Tween`]: crate::Tween
+/// [`Tween::map_lens()`]: crate::Tween::map_lens
+/// [`Value`]: RangedLens::Value
+pub trait RangedLens {
+    /// Type of the start and end values.
+    type Value;
+
+    /// Get the start value of the lens.
+    fn start(&self) -> Self::Value;
+
+    /// Replace the start value of the lens.
+    fn set_start(&mut self, start: Self::Value);
+
+    /// Get the end value of the lens.
+    fn end(&self) -> Self::Value;
+
+    /// Replace the end value of the lens.
+    fn set_end(&mut self, end: Self::Value);
+}
+
+impl<L: RangedLens> RangedLens for Clamped<L> {
+    type Value = L::Value;
+
+    fn start(&self) -> L::Value {
+        self.lens.start()
+    }
+
+    fn set_start(&mut self, start: L::Value) {
+        self.lens.set_start(start);
+    }
+
+    fn end(&self) -> L::Value {
+        self.lens.end()
+    }
+
+    fn set_end(&mut self, end: L::Value) {
+        self.lens.set_end(end);
+    }
+}
+
+/// A lens moving its target along a path of known length.
+///
+/// This gives the length of the path the lens moves its target along, in the
+/// units of the animated value, for example world units for a translation.
+/// A tween created with [`Tween::new_with_speed()`] derives its duration from
+/// it, so that the same speed produces the same motion whatever the distance.
+///
+/// Straight-line lenses like [`TransformPositionLens`] return the distance
+/// between their start and end values. Lenses following a curved path, like a
+/// spline, return the arc length of the curve, possibly approximated.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// // Move at 200 units per second
+/// let tween = Tween::new_with_speed(
+///     EaseFunction::QuadraticInOut,
+///     200.,
+///     TransformPositionLens {
+///         start: Vec3::ZERO,
+///         end: Vec3::new(300., 400., 0.),
+///     },
+/// );
+/// assert_eq!(tween.duration().as_secs_f32(), 2.5);
+/// ```
+///
+/// [`Tween::new_with_speed()`]: crate::Tween::new_with_speed
+pub trait PathLens {
+    /// Get the length of the path from the start to the end of the lens.
+    ///
+    /// The length is never negative.
+    fn arc_length(&self) -> f32;
+}
+
+impl<L: PathLens> PathLens for Clamped<L> {
+    fn arc_length(&self) -> f32 {
+        self.lens.arc_length()
+    }
+}
+
+/// A lens linearly interpolating a bare value.
+///
+/// This is mostly useful as the inner lens of an [`OptionLens`], or to
+/// animate a single field from a custom lens. It supports any type which can
+/// be linearly interpolated with arithmetic operators, like `f32`, [`Vec2`],
+/// or [`Vec3`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ValueLens<V> {
+    /// Start value.
+    pub start: V,
+    /// End value.
+    pub end: V,
+}
+
+impl<V> Lens<V> for ValueLens<V>
+where
+    V: Copy + Add<Output = V> + Sub<Output = V> + Mul<f32, Output = V>,
+{
+    fn lerp(&mut self, target: &mut V, ratio: f32) {
+        *target = self.start + (self.end - self.start) * ratio;
+    }
+
+    lens_scale_range!();
+}
+
+impl<V: Copy> RangedLens for ValueLens<V> {
+    type Value = V;
+
+    fn start(&self) -> V {
+        self.start
+    }
+
+    fn set_start(&mut self, start: V) {
+        self.start = start;
+    }
+
+    fn end(&self) -> V {
+        self.end
+    }
+
+    fn set_end(&mut self, end: V) {
+        self.end = end;
+    }
+}
+
+impl PathLens for ValueLens<f32> {
+    fn arc_length(&self) -> f32 {
+        (self.end - self.start).abs()
+    }
+}
+
+impl PathLens for ValueLens<Vec2> {
+    fn arc_length(&self) -> f32 {
+        self.start.distance(self.end)
+    }
+}
+
+impl PathLens for ValueLens<Vec3> {
+    fn arc_length(&self) -> f32 {
+        self.start.distance(self.end)
+    }
+}
+
+/// Policy of an [`OptionLens`] when the animated value is `None`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum MissingValuePolicy<V> {
+    /// Write `Some(value)` into the target before interpolating, so the
+    /// animation always runs. The inner lens then overwrites the value.
+    Initialize(V),
+    /// Leave the target untouched while it's `None`, and animate it once
+    /// something else set it to `Some`.
+    SkipWhileNone,
+    /// Leave the target untouched while it's `None`. The first time the
+    /// target is `Some`, replace the start value of the inner lens with the
+    /// current value, so the animation starts from it instead of jumping.
+    TakeCurrentAsStart,
+}
+
+/// A lens adapter animating an optional value.
+///
+/// The wrapped lens animates the value itself, and the [`MissingValuePolicy`]
+/// decides what happens when the target is `None`. Lenses animating an
+/// optional field, like the built-in `SpriteCustomSizeLens`, use this adapter
+/// so they all handle a missing value the same way. Custom lenses animating an
+/// optional field can forward to it too.
+///
+/// The start value taken by [`MissingValuePolicy::TakeCurrentAsStart`] is
+/// captured only once, the first time the target is `Some`. Rewinding the
+/// tween or starting a new iteration of a repeating tween doesn't capture it
+/// again, so the animation replays from that first value, not from the
+/// current one. Clone the lens before its first use to reuse it with a
+/// different target.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::lens::*;
+/// #[derive(Component)]
+/// struct Glow {
+///     radius: Option<f32>,
+/// }
+///
+/// struct GlowRadiusLens(OptionLens<ValueLens<f32>, f32>);
+///
+/// impl Lens<Glow> for GlowRadiusLens {
+///     fn lerp(&mut self, target: &mut Glow, ratio: f32) {
+///         self.0.lerp(&mut target.radius, ratio);
+///     }
+/// }
+///
+/// let mut lens = GlowRadiusLens(OptionLens::new(
+///     ValueLens { start: 0., end: 8. },
+///     MissingValuePolicy::Initialize(0.),
+/// ));
+/// let mut glow = Glow { radius: None };
+/// lens.lerp(&mut glow, 0.5);
+/// assert_eq!(glow.radius, Some(4.));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct OptionLens<L, V> {
+    /// The wrapped lens, animating the value when present.
+    pub lens: L,
+    /// Behavior when the target is `None`.
+    pub policy: MissingValuePolicy<V>,
+    captured: bool,
+}
+
+impl<L, V> OptionLens<L, V> {
+    /// Wrap a lens animating a value into a lens animating an optional value.
+    pub fn new(lens: L, policy: MissingValuePolicy<V>) -> Self {
+        Self {
+            lens,
+            policy,
+            captured: false,
+        }
+    }
+}
+
+impl<L: RangedLens<Value = V>, V: Clone> OptionLens<L, V> {
+    /// Get the value to animate according to the policy, if any.
+    fn value_mut<'a>(&mut self, target: &'a mut Option<V>) -> Option<&'a mut V> {
+        match &self.policy {
+            MissingValuePolicy::Initialize(value) => {
+                Some(target.get_or_insert_with(|| value.clone()))
+            }
+            MissingValuePolicy::SkipWhileNone => target.as_mut(),
+            MissingValuePolicy::TakeCurrentAsStart => {
+                let value = target.as_mut()?;
+                if !self.captured {
+                    self.lens.set_start(value.clone());
+                    self.captured = true;
+                }
+                Some(value)
+            }
+        }
+    }
+}
+
+impl<V: Clone, L: Lens<V> + RangedLens<Value = V>> Lens<Option<V>> for OptionLens<L, V> {
+    fn lerp(&mut self, target: &mut Option<V>, ratio: f32) {
+        if let Some(value) = self.value_mut(target) {
+            self.lens.lerp(value, ratio);
+        }
+    }
 
-/// A lens over a subset of a component.
+    fn lerp_directed(&mut self, target: &mut Option<V>, ratio: f32, direction: TweeningDirection) {
+        if let Some(value) = self.value_mut(target) {
+            self.lens.lerp_directed(value, ratio, direction);
+        }
+    }
+
+    fn lerp_ex(
+        &mut self,
+        target: &mut Option<V>,
+        eased: f32,
+        raw: f32,
+        direction: TweeningDirection,
+    ) {
+        if let Some(value) = self.value_mut(target) {
+            self.lens.lerp_ex(value, eased, raw, direction);
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.lens.is_valid()
+    }
+
+    fn set_context(&mut self, context: &LensContext) {
+        self.lens.set_context(context);
+    }
+
+    fn scale_range(&mut self, factor: f32) -> bool {
+        self.lens.scale_range(factor)
+    }
+}
+
+impl<L: RangedLens, V> RangedLens for OptionLens<L, V> {
+    type Value = L::Value;
+
+    fn start(&self) -> L::Value {
+        self.lens.start()
+    }
+
+    fn set_start(&mut self, start: L::Value) {
+        self.lens.set_start(start);
+    }
+
+    fn end(&self) -> L::Value {
+        self.lens.end()
+    }
+
+    fn set_end(&mut self, end: L::Value) {
+        self.lens.set_end(end);
+    }
+}
+
+impl<L: PathLens, V> PathLens for OptionLens<L, V> {
+    fn arc_length(&self) -> f32 {
+        self.lens.arc_length()
+    }
+}
+
+/// A lens interpolating a [`Rect`].
 ///
-/// The lens takes a `target` component or asset from a query, as a mutable
-/// reference, and animates (tweens) a subset of the fields of the
-/// component/asset based on the linear ratio `ratio` in \[0:1\], already
-/// sampled from the easing curve.
+/// The `min` and `max` corners of the rectangle are each interpolated
+/// component-wise, so a rectangle can grow from a degenerate one with a zero
+/// width or height, like for a left-to-right reveal. The ratio is not clamped,
+/// so an easing overshooting \[0:1\] overshoots the rectangle too; if the
+/// overshoot crosses the corners, they're swapped so that the rectangle always
+/// has `min <= max`.
 ///
-/// # Example
+/// This lens animates a bare [`Rect`]. It's used by the `SpriteRectLens` to
+/// animate the optional source rectangle of a `Sprite`, and can be forwarded
+/// to by a custom lens animating a component holding a [`Rect`].
 ///
-/// Implement `Lens` for a custom type:
+/// # Example
 ///
-/// ```rust
+/// ```
 /// # use bevy::prelude::*;
-/// # use bevy_tweening::*;
-/// struct MyLens {
-///   start: f32,
-///   end: f32,
-/// }
-///
+/// # use bevy_tweening::lens::*;
 /// #[derive(Component)]
-/// struct MyStruct(f32);
+/// struct ClipRect(Rect);
 ///
-/// impl Lens<MyStruct> for MyLens {
-///   fn lerp(&mut self, target: &mut MyStruct, ratio: f32) {
-///     target.0 = self.start + (self.end - self.start) * ratio;
-///   }
+/// struct ClipRectLens(RectLens);
+///
+/// impl Lens<ClipRect> for ClipRectLens {
+///     fn lerp(&mut self, target: &mut ClipRect, ratio: f32) {
+///         self.0.lerp(&mut target.0, ratio);
+///     }
 /// }
 /// ```
-pub trait Lens<T> {
-    /// Perform a linear interpolation (lerp) over the subset of fields of a
-    /// component or asset the lens focuses on, based on the linear ratio
-    /// `ratio`. The `target` component or asset is mutated in place. The
-    /// implementation decides which fields are interpolated, and performs
-    /// the animation in-place, overwriting the target.
-    fn lerp(&mut self, target: &mut T, ratio: f32);
-}
-
-/// A lens to manipulate the [`color`] field of a section of a [`Text`]
-/// component.
-///
-/// [`color`]: https://docs.rs/bevy/0.12.0/bevy/text/struct.TextStyle.html#structfield.color
-/// [`Text`]: https://docs.rs/bevy/0.12.0/bevy/text/struct.Text.html
-#[cfg(feature = "bevy_text")]
 #[derive(Debug, Copy, Clone, PartialEq)]
-pub struct TextColorLens {
-    /// Start color.
-    pub start: Color,
-    /// End color.
-    pub end: Color,
-    /// Index of the text section in the [`Text`] component.
-    pub section: usize,
+pub struct RectLens {
+    /// Start value of the rectangle.
+    pub start: Rect,
+    /// End value of the rectangle.
+    pub end: Rect,
 }
 
-#[cfg(feature = "bevy_text")]
-impl Lens<Text> for TextColorLens {
-    fn lerp(&mut self, target: &mut Text, ratio: f32) {
-        use crate::ColorLerper as _;
-
-        // Note: Add<f32> for Color affects alpha, but not Mul<f32>. So use Vec4 for
-        // consistency.
-        let value = self.start.lerp(&self.end, ratio);
+impl Lens<Rect> for RectLens {
+    fn lerp(&mut self, target: &mut Rect, ratio: f32) {
+        let min = self.start.min.lerp(self.end.min, ratio);
+        let max = self.start.max.lerp(self.end.max, ratio);
+        *target = Rect::from_corners(min, max);
+    }
 
-        if let Some(section) = target.sections.get_mut(self.section) {
-            section.style.color = value;
-        }
+    fn sample(&self, target: &mut Rect, ratio: f32) -> bool {
+        let mut lens = *self;
+        lens.lerp(target, ratio);
+        true
     }
+
+    lens_is_valid!(start, end);
 }
 
+impl_ranged_lens!(RectLens, Rect);
+
 /// A lens to manipulate the [`translation`] field of a [`Transform`] component.
 ///
 /// [`translation`]: https://docs.rs/bevy/0.12.0/bevy/transform/components/struct.Transform.html#structfield.translation
@@ -122,6 +1376,106 @@ impl Lens<Transform> for TransformPositionLens {
         let value = self.start + (self.end - self.start) * ratio;
         target.translation = value;
     }
+
+    fn sample(&self, target: &mut Transform, ratio: f32) -> bool {
+        let mut lens = *self;
+        lens.lerp(target, ratio);
+        true
+    }
+
+    lens_is_valid!(start, end);
+    lens_scale_range!();
+    lens_blend!(Transform);
+}
+
+impl_ranged_lens!(TransformPositionLens, Vec3);
+
+impl PathLens for TransformPositionLens {
+    fn arc_length(&self) -> f32 {
+        self.start.distance(self.end)
+    }
+}
+
+/// A lens to manipulate the [`translation`] field of a [`Transform`]
+/// component, with endpoints in world space.
+///
+/// The lens interpolates a world position from `start` to `end`, then
+/// converts it into the local space of the parent of the animated entity
+/// with the [`GlobalTransform`] of that parent, so the entity moves along
+/// the same world path whatever its parent. An entity without a parent
+/// behaves exactly like with a [`TransformPositionLens`].
+///
+/// The parent is received from the [`LensContext`] passed by the animator
+/// system of [`Transform`], so this lens only applies the conversion when
+/// animated by an [`Animator`]. The [`GlobalTransform`] of the parent is the
+/// one of the previous frame; a parent moving during the frame makes the
+/// child lag one frame behind the world path.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// fn land(mut commands: Commands, ship: Entity) {
+///     // Lands on the pad at the world origin, wherever the ship is parented
+///     let lens = WorldPositionLens::new(Vec3::new(0., 50., 0.), Vec3::ZERO);
+///     let tween = Tween::new(EaseFunction::QuadraticOut, Duration::from_secs(3), lens);
+///     commands.entity(ship).insert(Animator::new(tween));
+/// }
+/// ```
+///
+/// [`translation`]: https://docs.rs/bevy/0.12.0/bevy/transform/components/struct.Transform.html#structfield.translation
+/// [`Transform`]: https://docs.rs/bevy/0.12.0/bevy/transform/components/struct.Transform.html
+/// [`GlobalTransform`]: https://docs.rs/bevy/0.12.0/bevy/transform/components/struct.GlobalTransform.html
+/// [`Animator`]: crate::Animator
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WorldPositionLens {
+    /// Start value of the translation, in world space.
+    pub start: Vec3,
+    /// End value of the translation, in world space.
+    pub end: Vec3,
+    parent: Option<GlobalTransform>,
+}
+
+impl WorldPositionLens {
+    /// Create a lens moving between the given world positions.
+    #[must_use]
+    pub fn new(start: Vec3, end: Vec3) -> Self {
+        Self {
+            start,
+            end,
+            parent: None,
+        }
+    }
+}
+
+impl Lens<Transform> for WorldPositionLens {
+    fn lerp(&mut self, target: &mut Transform, ratio: f32) {
+        let value = self.start + (self.end - self.start) * ratio;
+        target.translation = match &self.parent {
+            Some(parent) => parent.affine().inverse().transform_point3(value),
+            None => value,
+        };
+    }
+
+    fn sample(&self, target: &mut Transform, ratio: f32) -> bool {
+        let mut lens = *self;
+        lens.lerp(target, ratio);
+        true
+    }
+
+    fn set_context(&mut self, context: &LensContext) {
+        self.parent = context.parent;
+    }
+
+    lens_is_valid!(start, end);
+}
+
+impl PathLens for WorldPositionLens {
+    fn arc_length(&self) -> f32 {
+        self.start.distance(self.end)
+    }
 }
 
 /// A lens to manipulate the [`rotation`] field of a [`Transform`] component.
@@ -153,8 +1507,45 @@ impl Lens<Transform> for TransformRotationLens {
     fn lerp(&mut self, target: &mut Transform, ratio: f32) {
         target.rotation = self.start.slerp(self.end, ratio);
     }
+
+    fn sample(&self, target: &mut Transform, ratio: f32) -> bool {
+        let mut lens = *self;
+        lens.lerp(target, ratio);
+        true
+    }
+
+    lens_is_valid!(start, end);
+
+    fn scale_range(&mut self, factor: f32) -> bool {
+        self.end = self.start.slerp(self.end, factor);
+        true
+    }
+
+    fn boundary_value(&self, direction: TweeningDirection) -> Option<Box<dyn Any + Send + Sync>> {
+        Some(Box::new(match direction {
+            TweeningDirection::Forward => self.end,
+            TweeningDirection::Backward => self.start,
+        }))
+    }
+
+    fn lerp_from(
+        &mut self,
+        target: &mut Transform,
+        ratio: f32,
+        from: &dyn Any,
+        weight: f32,
+    ) -> bool {
+        let Some(from) = from.downcast_ref::<Quat>() else {
+            return false;
+        };
+        let start = from.slerp(self.start, weight);
+        target.rotation = start.slerp(self.end, ratio);
+        true
+    }
 }
 
+impl_ranged_lens!(TransformRotationLens, Quat);
+
 /// A lens to rotate a [`Transform`] component around its local X axis.
 ///
 /// This lens interpolates the rotation angle of a [`Transform`] component from
@@ -180,8 +1571,20 @@ impl Lens<Transform> for TransformRotateXLens {
         let angle = (self.end - self.start).mul_add(ratio, self.start);
         target.rotation = Quat::from_rotation_x(angle);
     }
+
+    fn sample(&self, target: &mut Transform, ratio: f32) -> bool {
+        let mut lens = *self;
+        lens.lerp(target, ratio);
+        true
+    }
+
+    lens_is_valid!(start, end);
+    lens_scale_range!();
+    lens_blend!(Transform);
 }
 
+impl_ranged_lens!(TransformRotateXLens, f32);
+
 /// A lens to rotate a [`Transform`] component around its local Y axis.
 ///
 /// This lens interpolates the rotation angle of a [`Transform`] component from
@@ -207,8 +1610,20 @@ impl Lens<Transform> for TransformRotateYLens {
         let angle = (self.end - self.start).mul_add(ratio, self.start);
         target.rotation = Quat::from_rotation_y(angle);
     }
+
+    fn sample(&self, target: &mut Transform, ratio: f32) -> bool {
+        let mut lens = *self;
+        lens.lerp(target, ratio);
+        true
+    }
+
+    lens_is_valid!(start, end);
+    lens_scale_range!();
+    lens_blend!(Transform);
 }
 
+impl_ranged_lens!(TransformRotateYLens, f32);
+
 /// A lens to rotate a [`Transform`] component around its local Z axis.
 ///
 /// This lens interpolates the rotation angle of a [`Transform`] component from
@@ -234,8 +1649,20 @@ impl Lens<Transform> for TransformRotateZLens {
         let angle = (self.end - self.start).mul_add(ratio, self.start);
         target.rotation = Quat::from_rotation_z(angle);
     }
+
+    fn sample(&self, target: &mut Transform, ratio: f32) -> bool {
+        let mut lens = *self;
+        lens.lerp(target, ratio);
+        true
+    }
+
+    lens_is_valid!(start, end);
+    lens_scale_range!();
+    lens_blend!(Transform);
 }
 
+impl_ranged_lens!(TransformRotateZLens, f32);
+
 /// A lens to rotate a [`Transform`] component around a given fixed axis.
 ///
 /// This lens interpolates the rotation angle of a [`Transform`] component from
@@ -267,8 +1694,20 @@ impl Lens<Transform> for TransformRotateAxisLens {
         let angle = (self.end - self.start).mul_add(ratio, self.start);
         target.rotation = Quat::from_axis_angle(self.axis, angle);
     }
+
+    fn sample(&self, target: &mut Transform, ratio: f32) -> bool {
+        let mut lens = *self;
+        lens.lerp(target, ratio);
+        true
+    }
+
+    lens_is_valid!(axis, start, end);
+    lens_scale_range!();
+    lens_blend!(Transform);
 }
 
+impl_ranged_lens!(TransformRotateAxisLens, f32);
+
 /// A lens to manipulate the [`scale`] field of a [`Transform`] component.
 ///
 /// [`scale`]: https://docs.rs/bevy/0.12.0/bevy/transform/components/struct.Transform.html#structfield.scale
@@ -286,109 +1725,139 @@ impl Lens<Transform> for TransformScaleLens {
         let value = self.start + (self.end - self.start) * ratio;
         target.scale = value;
     }
+
+    fn sample(&self, target: &mut Transform, ratio: f32) -> bool {
+        let mut lens = *self;
+        lens.lerp(target, ratio);
+        true
+    }
+
+    lens_is_valid!(start, end);
+    lens_scale_range!();
+    lens_blend!(Transform);
 }
 
-/// A lens to manipulate the [`position`] field of a UI [`Style`] component.
+impl_ranged_lens!(TransformScaleLens, Vec3);
+
+/// A lens to manipulate the [`SyncFactor`] shared by the lenses of a
+/// [`SyncedAnimator`].
 ///
-/// [`position`]: https://docs.rs/bevy/0.12.0/bevy/ui/struct.Style.html#structfield.position
-/// [`Style`]: https://docs.rs/bevy/0.12.0/bevy/ui/struct.Style.html
-#[cfg(feature = "bevy_ui")]
+/// A `start` of `0.` and an `end` of `1.` forward the eased ratio unchanged
+/// to both lenses of the animator. Other values allow, for example, a
+/// [`Sequence`] animating the factor back and forth.
+///
+/// [`SyncFactor`]: crate::SyncFactor
+/// [`SyncedAnimator`]: crate::SyncedAnimator
+/// [`Sequence`]: crate::Sequence
 #[derive(Debug, Copy, Clone, PartialEq)]
-pub struct UiPositionLens {
-    /// Start position.
-    pub start: UiRect,
-    /// End position.
-    pub end: UiRect,
+pub struct SyncFactorLens {
+    /// Start value of the factor.
+    pub start: f32,
+    /// End value of the factor.
+    pub end: f32,
 }
 
-#[cfg(feature = "bevy_ui")]
-fn lerp_val(start: &Val, end: &Val, ratio: f32) -> Val {
-    match (start, end) {
-        (Val::Percent(start), Val::Percent(end)) => {
-            Val::Percent((end - start).mul_add(ratio, *start))
-        }
-        (Val::Px(start), Val::Px(end)) => Val::Px((end - start).mul_add(ratio, *start)),
-        _ => *start,
+impl Lens<crate::SyncFactor> for SyncFactorLens {
+    fn lerp(&mut self, target: &mut crate::SyncFactor, ratio: f32) {
+        target.0 = (self.end - self.start).mul_add(ratio, self.start);
     }
-}
 
-#[cfg(feature = "bevy_ui")]
-impl Lens<Style> for UiPositionLens {
-    fn lerp(&mut self, target: &mut Style, ratio: f32) {
-        target.left = lerp_val(&self.start.left, &self.end.left, ratio);
-        target.right = lerp_val(&self.start.right, &self.end.right, ratio);
-        target.top = lerp_val(&self.start.top, &self.end.top, ratio);
-        target.bottom = lerp_val(&self.start.bottom, &self.end.bottom, ratio);
-    }
+    lens_is_valid!(start, end);
+    lens_scale_range!();
+    lens_blend!(crate::SyncFactor);
 }
 
-/// Gamer
-#[cfg(feature = "bevy_ui")]
-#[derive(Debug, Copy, Clone, PartialEq)]
-pub struct UiBackgroundColorLens {
-    /// Start position.
-    pub start: Color,
-    /// End position.
-    pub end: Color,
+impl_ranged_lens!(SyncFactorLens, f32);
+
+/// Linearly interpolate between two [`Duration`] values.
+///
+/// Unlike converting the durations to `f32` seconds, this interpolates with
+/// integer nanoseconds, and only the difference between `start` and `end` is
+/// scaled by `ratio`. This means the result is exact for a `ratio` of `0.` or
+/// `1.`, and rounded to the nearest nanosecond otherwise, even for long
+/// durations which differ by a small amount. This makes it suitable to write
+/// custom lenses animating [`Duration`] or [`Timer`] fields.
+///
+/// The `ratio` is not clamped, so overshooting easing functions produce values
+/// outside of `start..=end`. As a [`Duration`] cannot be negative, the result
+/// saturates at [`Duration::ZERO`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_tweening::lens::lerp_duration;
+/// # use std::time::Duration;
+/// let start = Duration::from_secs(10);
+/// let end = Duration::from_millis(10_001);
+/// assert_eq!(lerp_duration(start, end, 0.5), Duration::from_micros(10_000_500));
+/// ```
+///
+/// [`Timer`]: https://docs.rs/bevy/0.13.0/bevy/time/struct.Timer.html
+pub fn lerp_duration(start: Duration, end: Duration, ratio: f32) -> Duration {
+    let start = start.as_nanos() as i128;
+    let delta = end.as_nanos() as i128 - start;
+    let offset = (delta as f64 * ratio as f64).round() as i128;
+    let nanos = (start + offset).max(0) as u128;
+    let secs = u64::try_from(nanos / 1_000_000_000).unwrap_or(u64::MAX);
+    Duration::new(secs, (nanos % 1_000_000_000) as u32)
 }
 
-#[cfg(feature = "bevy_ui")]
-impl Lens<BackgroundColor> for UiBackgroundColorLens {
-    fn lerp(&mut self, target: &mut BackgroundColor, ratio: f32) {
-        use crate::ColorLerper as _;
+/// A [`Duration`] value animated with a [`DurationLens`].
+///
+/// This wrapper component allows animating a standalone duration, like a
+/// cooldown, with an [`Animator`]. To animate a [`Duration`] field of another
+/// component, write a custom lens calling [`lerp_duration()`] instead.
+///
+/// [`Animator`]: crate::Animator
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Component)]
+pub struct AnimatedDuration(pub Duration);
+
+impl std::ops::Deref for AnimatedDuration {
+    type Target = Duration;
+
+    fn deref(&self) -> &Duration {
+        &self.0
+    }
+}
 
-        let value = self.start.lerp(&self.end, ratio);
-        target.0 = value;
+impl std::ops::DerefMut for AnimatedDuration {
+    fn deref_mut(&mut self) -> &mut Duration {
+        &mut self.0
     }
 }
 
-/// A lens to manipulate the [`color`] field of a [`ColorMaterial`] asset.
+/// A lens to manipulate a [`Duration`].
 ///
-/// [`color`]: https://docs.rs/bevy/0.12.0/bevy/sprite/struct.ColorMaterial.html#structfield.color
-/// [`ColorMaterial`]: https://docs.rs/bevy/0.12.0/bevy/sprite/struct.ColorMaterial.html
-#[cfg(feature = "bevy_sprite")]
-#[derive(Debug, Copy, Clone, PartialEq)]
-pub struct ColorMaterialColorLens {
-    /// Start color.
-    pub start: Color,
-    /// End color.
-    pub end: Color,
+/// The lens interpolates with [`lerp_duration()`], so doesn't lose precision
+/// on long durations. It can animate an [`AnimatedDuration`] component, the
+/// duration of a [`Timer`], or a bare [`Duration`] from a custom lens.
+///
+/// [`Timer`]: https://docs.rs/bevy/0.13.0/bevy/time/struct.Timer.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DurationLens {
+    /// Start duration.
+    pub start: Duration,
+    /// End duration.
+    pub end: Duration,
 }
 
-#[cfg(feature = "bevy_sprite")]
-impl Lens<ColorMaterial> for ColorMaterialColorLens {
-    fn lerp(&mut self, target: &mut ColorMaterial, ratio: f32) {
-        use crate::ColorLerper as _;
+impl_ranged_lens!(DurationLens, Duration);
 
-        // Note: Add<f32> for Color affects alpha, but not Mul<f32>. So use Vec4 for
-        // consistency.
-        let value = self.start.lerp(&self.end, ratio);
-        target.color = value;
+impl Lens<Duration> for DurationLens {
+    fn lerp(&mut self, target: &mut Duration, ratio: f32) {
+        *target = lerp_duration(self.start, self.end, ratio);
     }
 }
 
-/// A lens to manipulate the [`color`] field of a [`Sprite`] asset.
-///
-/// [`color`]: https://docs.rs/bevy/0.12.0/bevy/sprite/struct.Sprite.html#structfield.color
-/// [`Sprite`]: https://docs.rs/bevy/0.12.0/bevy/sprite/struct.Sprite.html
-#[cfg(feature = "bevy_sprite")]
-#[derive(Debug, Copy, Clone, PartialEq)]
-pub struct SpriteColorLens {
-    /// Start color.
-    pub start: Color,
-    /// End color.
-    pub end: Color,
+impl Lens<AnimatedDuration> for DurationLens {
+    fn lerp(&mut self, target: &mut AnimatedDuration, ratio: f32) {
+        target.0 = lerp_duration(self.start, self.end, ratio);
+    }
 }
 
-#[cfg(feature = "bevy_sprite")]
-impl Lens<Sprite> for SpriteColorLens {
-    fn lerp(&mut self, target: &mut Sprite, ratio: f32) {
-        use crate::ColorLerper as _;
-
-        // Note: Add<f32> for Color affects alpha, but not Mul<f32>. So use Vec4 for
-        // consistency.
-        let value = self.start.lerp(&self.end, ratio);
-        target.color = value;
+impl Lens<Timer> for DurationLens {
+    fn lerp(&mut self, target: &mut Timer, ratio: f32) {
+        target.set_duration(lerp_duration(self.start, self.end, ratio));
     }
 }
 
@@ -397,44 +1866,10 @@ mod tests {
     use std::f32::consts::TAU;
 
     use super::*;
-
-    #[cfg(feature = "bevy_text")]
-    #[test]
-    fn text_color() {
-        let mut lens = TextColorLens {
-            start: Color::RED,
-            end: Color::BLUE,
-            section: 0,
-        };
-        let mut text = Text::from_section("", default());
-
-        lens.lerp(&mut text, 0.);
-        assert_eq!(text.sections[0].style.color, Color::RED);
-
-        lens.lerp(&mut text, 1.);
-        assert_eq!(text.sections[0].style.color, Color::BLUE);
-
-        lens.lerp(&mut text, 0.3);
-        assert_eq!(text.sections[0].style.color, Color::rgba(0.7, 0., 0.3, 1.0));
-
-        let mut lens_section1 = TextColorLens {
-            start: Color::RED,
-            end: Color::BLUE,
-            section: 1,
-        };
-
-        lens_section1.lerp(&mut text, 1.);
-        // Should not have changed because the lens targets section 1
-        assert_eq!(text.sections[0].style.color, Color::rgba(0.7, 0., 0.3, 1.0));
-
-        text.sections.push(TextSection {
-            value: "".to_string(),
-            style: Default::default(),
-        });
-
-        lens_section1.lerp(&mut text, 0.3);
-        assert_eq!(text.sections[1].style.color, Color::rgba(0.7, 0., 0.3, 1.0));
-    }
+    use crate::{
+        tweenable::ComponentTarget, EaseFunction, EaseMethod, RepeatCount, RepeatStrategy, Tween,
+        Tweenable,
+    };
 
     #[test]
     fn transform_position() {
@@ -622,6 +2057,90 @@ mod tests {
         assert!(transform.scale.abs_diff_eq(Vec3::ONE, 1e-5));
     }
 
+    #[test]
+    fn clamped() {
+        let mut lens = Clamped::new(TransformPositionLens {
+            start: Vec3::ZERO,
+            end: Vec3::new(1., 2., -4.),
+        });
+        let mut transform = Transform::default();
+
+        lens.lerp(&mut transform, 1.3);
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(1., 2., -4.), 1e-5));
+
+        lens.lerp(&mut transform, -0.2);
+        assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
+
+        lens.lerp(&mut transform, 0.3);
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(0.3, 0.6, -1.2), 1e-5));
+
+        let mut lens = lens.with_range(-0.5, 1.5);
+        lens.lerp(&mut transform, 1.3);
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(1.3, 2.6, -5.2), 1e-5));
+
+        lens.lerp(&mut transform, 2.);
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(1.5, 3., -6.), 1e-5));
+    }
+
+    #[test]
+    fn duration() {
+        let start = Duration::from_secs(10);
+        let end = Duration::from_millis(10_001);
+        let mut lens = DurationLens { start, end };
+        let mut value = AnimatedDuration::default();
+
+        lens.lerp(&mut value, 0.);
+        assert_eq!(*value, start);
+        lens.lerp(&mut value, 1.);
+        assert_eq!(*value, end);
+        lens.lerp(&mut value, 0.25);
+        assert_eq!(*value, Duration::from_micros(10_000_250));
+
+        // Overshoot saturates at zero
+        let mut lens = DurationLens {
+            start: Duration::from_millis(100),
+            end: Duration::ZERO,
+        };
+        lens.lerp(&mut value, 1.5);
+        assert_eq!(*value, Duration::ZERO);
+
+        let mut timer = Timer::from_seconds(1., TimerMode::Once);
+        lens.lerp(&mut timer, 0.5);
+        assert_eq!(timer.duration(), Duration::from_millis(50));
+    }
+
+    #[test]
+    fn duration_no_drift() {
+        // Tween between two durations differing by exactly 1ms over 10s, and
+        // check each value is exactly the expected one.
+        let start = Duration::from_secs(10);
+        let end = Duration::from_millis(10_001);
+        let mut tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(10),
+            DurationLens { start, end },
+        );
+        let mut world = World::new();
+        let entity = world.spawn(AnimatedDuration(start)).id();
+        let mut events = Vec::new();
+        for i in 1..=1000_u64 {
+            let value = world.get_mut::<AnimatedDuration>(entity).unwrap();
+            let mut target = ComponentTarget::new(value);
+            tween.tick(Duration::from_millis(10), &mut target, entity, &mut events);
+            let value = world.get::<AnimatedDuration>(entity).unwrap();
+            assert_eq!(value.0, start + Duration::from_micros(i));
+        }
+        assert_eq!(world.get::<AnimatedDuration>(entity).unwrap().0, end);
+    }
+
     #[test]
     fn transform_scale() {
         let mut lens = TransformScaleLens {
@@ -646,85 +2165,192 @@ mod tests {
         assert!(transform.scale.abs_diff_eq(Vec3::new(0.3, 0.6, -1.2), 1e-5));
     }
 
-    #[cfg(feature = "bevy_ui")]
     #[test]
-    fn ui_position() {
-        let mut lens = UiPositionLens {
-            start: UiRect {
-                left: Val::Px(0.),
-                top: Val::Px(0.),
-                right: Val::Auto,
-                bottom: Val::Percent(25.),
-            },
-            end: UiRect {
-                left: Val::Px(1.),
-                top: Val::Px(5.),
-                right: Val::Auto,
-                bottom: Val::Percent(45.),
+    fn ranged() {
+        let mut lens = TransformRotateZLens { start: 0., end: 1. };
+        assert_eq!(lens.start(), 0.);
+        assert_eq!(lens.end(), 1.);
+        lens.set_start(2.);
+        lens.set_end(3.);
+        assert_eq!(lens, TransformRotateZLens { start: 2., end: 3. });
+
+        // Adapters forward to the wrapped lens
+        let mut lens = Clamped::new(TransformScaleLens {
+            start: Vec3::ONE,
+            end: Vec3::ZERO,
+        });
+        lens.set_end(Vec3::splat(2.));
+        assert_eq!(lens.lens.end, Vec3::splat(2.));
+        assert_eq!(lens.end(), Vec3::splat(2.));
+        let mut lens = OptionLens::new(
+            ValueLens { start: 0., end: 1. },
+            MissingValuePolicy::<f32>::SkipWhileNone,
+        );
+        lens.set_start(0.5);
+        assert_eq!(lens.lens.start, 0.5);
+    }
+
+    #[test]
+    fn ranged_tween() {
+        let mut tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
             },
+        );
+        assert!(tween.lens::<TransformScaleLens>().is_none());
+        assert!(!tween.map_lens(|lens: &mut TransformScaleLens| lens.set_end(Vec3::X)));
+        assert_eq!(
+            tween.lens::<TransformPositionLens>().map(RangedLens::end),
+            Some(Vec3::ONE)
+        );
+
+        let mut world = World::new();
+        let entity = world.spawn(Transform::default()).id();
+        let mut tick = |tween: &mut Tween<Transform>| {
+            let transform = world.get_mut::<Transform>(entity).unwrap();
+            let mut target = ComponentTarget::new(transform);
+            tween.tick(
+                Duration::from_millis(500),
+                &mut target,
+                entity,
+                &mut Vec::new(),
+            );
+            world.get::<Transform>(entity).unwrap().translation
         };
-        let mut style = Style::default();
+        assert!(tick(&mut tween).abs_diff_eq(Vec3::splat(0.5), 1e-5));
 
-        lens.lerp(&mut style, 0.);
-        assert_eq!(style.left, Val::Px(0.));
-        assert_eq!(style.top, Val::Px(0.));
-        assert_eq!(style.right, Val::Auto);
-        assert_eq!(style.bottom, Val::Percent(25.));
+        // Retarget the running tween
+        assert!(tween.map_lens(|lens: &mut TransformPositionLens| lens.set_end(Vec3::splat(3.))));
+        assert!(tick(&mut tween).abs_diff_eq(Vec3::splat(3.), 1e-5));
+    }
 
-        lens.lerp(&mut style, 1.);
-        assert_eq!(style.left, Val::Px(1.));
-        assert_eq!(style.top, Val::Px(5.));
-        assert_eq!(style.right, Val::Auto);
-        assert_eq!(style.bottom, Val::Percent(45.));
+    #[test]
+    fn post_process() {
+        let mut lens = PostProcessLens::new(
+            TransformPositionLens {
+                start: Vec3::new(0., 100., 0.),
+                end: Vec3::new(40., -20., 0.),
+            },
+            |transform: &mut Transform| transform.translation.z = -transform.translation.y,
+        );
+        let mut transform = Transform::default();
 
-        lens.lerp(&mut style, 0.3);
-        assert_eq!(style.left, Val::Px(0.3));
-        assert_eq!(style.top, Val::Px(1.5));
-        assert_eq!(style.right, Val::Auto);
-        assert_eq!(style.bottom, Val::Percent(31.));
+        lens.lerp(&mut transform, 0.);
+        assert_eq!(transform.translation, Vec3::new(0., 100., -100.));
+        lens.lerp(&mut transform, 0.5);
+        assert_eq!(transform.translation, Vec3::new(20., 40., -40.));
+        lens.lerp_directed(&mut transform, 1., TweeningDirection::Backward);
+        assert_eq!(transform.translation, Vec3::new(40., -20., 20.));
+
+        let mut sampled = Transform::default();
+        assert!(lens.sample(&mut sampled, 0.25));
+        assert_eq!(sampled.translation, Vec3::new(10., 70., -70.));
+
+        // Forwards the endpoints of the wrapped lens
+        lens.set_end(Vec3::ZERO);
+        lens.lerp(&mut transform, 1.);
+        assert_eq!(transform.translation, Vec3::ZERO);
     }
 
-    #[cfg(feature = "bevy_sprite")]
     #[test]
-    fn colormaterial_color() {
-        let mut lens = ColorMaterialColorLens {
-            start: Color::RED,
-            end: Color::BLUE,
-        };
-        let mut mat = ColorMaterial {
-            color: Color::WHITE,
-            texture: None,
-        };
+    fn directional() {
+        let lens = DirectionalLens::new(
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::X,
+            },
+            Clamped::new(TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::Y,
+            }),
+        );
 
-        lens.lerp(&mut mat, 0.);
-        assert_eq!(mat.color, Color::RED);
+        // Without direction, the forward lens applies
+        let mut transform = Transform::default();
+        assert!(lens.sample(&mut transform, 0.25));
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(0.25, 0., 0.), 1e-5));
+
+        let mut tween = Tween::new(EaseMethod::Linear, Duration::from_secs(1), lens)
+            .with_repeat_count(RepeatCount::Infinite)
+            .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
+
+        let mut world = World::new();
+        let entity = world.spawn(Transform::default()).id();
+        let mut tick = |tween: &mut Tween<Transform>, ms| {
+            let transform = world.get_mut::<Transform>(entity).unwrap();
+            let mut target = ComponentTarget::new(transform);
+            tween.tick(
+                Duration::from_millis(ms),
+                &mut target,
+                entity,
+                &mut Vec::new(),
+            );
+            world.get::<Transform>(entity).unwrap().translation
+        };
 
-        lens.lerp(&mut mat, 1.);
-        assert_eq!(mat.color, Color::BLUE);
+        // Same ratio of 0.25 on the forward pass, then the backward one
+        let forward = tick(&mut tween, 250);
+        assert!(forward.abs_diff_eq(Vec3::new(0.25, 0., 0.), 1e-5));
+        let apex = tick(&mut tween, 1000);
+        assert!(apex.abs_diff_eq(Vec3::Y, 1e-5));
+        let backward = tick(&mut tween, 500);
+        assert!(backward.abs_diff_eq(Vec3::new(0., 0.25, 0.), 1e-5));
+        assert_ne!(forward, backward);
 
-        lens.lerp(&mut mat, 0.3);
-        assert_eq!(mat.color, Color::rgba(0.7, 0., 0.3, 1.0));
+        // Sampling picks the lens of the pass at the sampled time
+        let mut transform = Transform::default();
+        assert!(tween.sample(Duration::from_millis(2250), &mut transform));
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(0.25, 0., 0.), 1e-5));
+        assert!(tween.sample(Duration::from_millis(3750), &mut transform));
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(0., 0.25, 0.), 1e-5));
     }
 
-    #[cfg(feature = "bevy_sprite")]
     #[test]
-    fn sprite_color() {
-        let mut lens = SpriteColorLens {
-            start: Color::RED,
-            end: Color::BLUE,
-        };
-        let mut sprite = Sprite {
-            color: Color::WHITE,
-            ..default()
-        };
-
-        lens.lerp(&mut sprite, 0.);
-        assert_eq!(sprite.color, Color::RED);
-
-        lens.lerp(&mut sprite, 1.);
-        assert_eq!(sprite.color, Color::BLUE);
+    fn derived() {
+        let lens = DerivedLens::new(
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::X,
+            },
+            |transform: &Transform, ratio: f32| (transform.translation.x, ratio),
+        );
+        let value = lens.value();
+        assert_eq!(value.get(), None);
 
-        lens.lerp(&mut sprite, 0.3);
-        assert_eq!(sprite.color, Color::rgba(0.7, 0., 0.3, 1.0));
+        // Sampling doesn't derive anything
+        let mut transform = Transform::default();
+        assert!(lens.sample(&mut transform, 0.5));
+        assert_eq!(value.get(), None);
+
+        let mut tween = Tween::new(EaseFunction::QuadraticInOut, Duration::from_secs(1), lens);
+        let mut world = World::new();
+        let entity = world.spawn(Transform::default()).id();
+        for _ in 0..6 {
+            let transform = world.get_mut::<Transform>(entity).unwrap();
+            let mut target = ComponentTarget::new(transform);
+            tween.tick(
+                Duration::from_millis(200),
+                &mut target,
+                entity,
+                &mut Vec::new(),
+            );
+
+            // Derived every tick, from the updated target and the same eased
+            // ratio the primary lens used
+            let translation = world.get::<Transform>(entity).unwrap().translation;
+            let (x, ratio) = value.get().unwrap();
+            assert_eq!(x, translation.x);
+            assert_eq!(ratio, translation.x);
+        }
+        assert_eq!(value.get(), Some((1., 1.)));
     }
 }
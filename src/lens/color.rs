@@ -0,0 +1,398 @@
+//! Color spaces used by the color lenses.
+
+use bevy::prelude::*;
+
+/// Implement the constructors of a color lens with `start`, `end`, and `space`
+/// fields.
+///
+/// `TextColorLens` also takes a section index, so writes its own constructors.
+#[cfg(any(feature = "bevy_ui", feature = "bevy_sprite", feature = "bevy_pbr"))]
+macro_rules! color_lens_impl {
+    () => {
+        /// Create a new lens interpolating between two colors in the default
+        /// [`ColorSpace::Srgba`] color space.
+        pub fn new(start: Color, end: Color) -> Self {
+            Self {
+                start,
+                end,
+                space: ColorSpace::default(),
+            }
+        }
+
+        /// Create a new lens interpolating between two colors authored in the
+        /// [Oklab] color space, natively in that space.
+        ///
+        /// [Oklab]: https://bottosson.github.io/posts/oklab/
+        pub fn oklab(start: Oklaba, end: Oklaba) -> Self {
+            Self {
+                start: start.into(),
+                end: end.into(),
+                space: ColorSpace::Oklaba,
+            }
+        }
+
+        /// Set the color space the lens interpolates in.
+        #[must_use]
+        pub fn with_space(mut self, space: ColorSpace) -> Self {
+            self.space = space;
+            self
+        }
+    };
+}
+
+/// Implement [`Lens::scale_range()`], [`Lens::boundary_value()`],
+/// [`Lens::value_at()`], and [`Lens::lerp_from()`] for a color lens animating
+/// a `$target`, in the color space of the lens.
+///
+/// [`Lens::scale_range()`]: crate::Lens::scale_range
+/// [`Lens::boundary_value()`]: crate::Lens::boundary_value
+/// [`Lens::value_at()`]: crate::Lens::value_at
+/// [`Lens::lerp_from()`]: crate::Lens::lerp_from
+macro_rules! color_lens_blend {
+    ($target:ty) => {
+        fn scale_range(&mut self, factor: f32) -> bool {
+            self.end = self.space.lerp(&self.start, &self.end, factor);
+            true
+        }
+
+        fn value_at(&self, ratio: f32) -> Option<Box<dyn std::any::Any + Send + Sync>> {
+            Some(Box::new(self.space.lerp(&self.start, &self.end, ratio)))
+        }
+
+        fn boundary_value(
+            &self,
+            direction: $crate::TweeningDirection,
+        ) -> Option<Box<dyn std::any::Any + Send + Sync>> {
+            Some(Box::new(match direction {
+                $crate::TweeningDirection::Forward => self.end,
+                $crate::TweeningDirection::Backward => self.start,
+            }))
+        }
+
+        fn lerp_from(
+            &mut self,
+            target: &mut $target,
+            ratio: f32,
+            from: &dyn std::any::Any,
+            weight: f32,
+        ) -> bool {
+            let Some(from) = from.downcast_ref::<Color>() else {
+                return false;
+            };
+            let start = self.start;
+            self.start = self.space.lerp(from, &start, weight);
+            self.lerp(target, ratio);
+            self.start = start;
+            true
+        }
+    };
+}
+
+/// Color space in which a color lens interpolates.
+///
+/// The color lenses store their `start` and `end` colors as a [`Color`], which
+/// can be authored in any of the color spaces Bevy supports. The color space
+/// of the lens determines in which space the intermediate colors are
+/// computed, independently of the space the endpoints were authored in. The
+/// result is written in that same space, with the exception of
+/// [`ColorSpace::Oklaba`], which is not natively supported by Bevy and writes
+/// a linear RGBA color.
+///
+/// Whatever the color space, a ratio of exactly `0.` or `1.` writes the
+/// `start` or `end` color as stored in the lens, without any conversion, so
+/// the endpoint colors are reproduced exactly. The ratio is clamped to
+/// \[0:1\], as a color channel cannot overshoot.
+///
+/// # Hue interpolation
+///
+/// The [`Hsla`] and [`Lcha`] color spaces interpolate the hue along the
+/// shortest arc of the hue circle. When the two hues are exactly 180° apart,
+/// both arcs have the same length, and the hue is interpolated in the
+/// direction of increasing hue. If one of the colors has no hue, because its
+/// saturation or chroma is zero, the hue of the other color is used for both.
+///
+/// [`Hsla`]: ColorSpace::Hsla
+/// [`Lcha`]: ColorSpace::Lcha
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorSpace {
+    /// Interpolate the gamma-encoded sRGB channels. This is the default, and
+    /// writes a [`Color::Rgba`].
+    #[default]
+    Srgba,
+    /// Interpolate the linear RGB channels. This writes a
+    /// [`Color::RgbaLinear`].
+    LinearRgba,
+    /// Interpolate hue, saturation, and lightness. This writes a
+    /// [`Color::Hsla`].
+    Hsla,
+    /// Interpolate lightness, chroma, and hue. This writes a
+    /// [`Color::Lcha`].
+    Lcha,
+    /// Interpolate in the perceptually uniform [Oklab] color space. This
+    /// writes a [`Color::RgbaLinear`].
+    ///
+    /// [Oklab]: https://bottosson.github.io/posts/oklab/
+    Oklaba,
+}
+
+impl ColorSpace {
+    /// Interpolate between two colors in this color space.
+    pub(crate) fn lerp(self, start: &Color, end: &Color, ratio: f32) -> Color {
+        if ratio <= 0. {
+            return *start;
+        }
+        if ratio >= 1. {
+            return *end;
+        }
+        match self {
+            Self::Srgba => {
+                let [r0, g0, b0, a0] = start.as_rgba_f32();
+                let [r1, g1, b1, a1] = end.as_rgba_f32();
+                Color::rgba(
+                    lerp(r0, r1, ratio),
+                    lerp(g0, g1, ratio),
+                    lerp(b0, b1, ratio),
+                    lerp(a0, a1, ratio),
+                )
+            }
+            Self::LinearRgba => {
+                let [r0, g0, b0, a0] = start.as_linear_rgba_f32();
+                let [r1, g1, b1, a1] = end.as_linear_rgba_f32();
+                Color::rgba_linear(
+                    lerp(r0, r1, ratio),
+                    lerp(g0, g1, ratio),
+                    lerp(b0, b1, ratio),
+                    lerp(a0, a1, ratio),
+                )
+            }
+            Self::Hsla => {
+                let [h0, s0, l0, a0] = start.as_hsla_f32();
+                let [h1, s1, l1, a1] = end.as_hsla_f32();
+                let (h0, h1) = resolve_hues(h0, s0, h1, s1);
+                Color::hsla(
+                    lerp_hue(h0, h1, ratio),
+                    lerp(s0, s1, ratio),
+                    lerp(l0, l1, ratio),
+                    lerp(a0, a1, ratio),
+                )
+            }
+            Self::Lcha => {
+                let [l0, c0, h0, a0] = start.as_lcha_f32();
+                let [l1, c1, h1, a1] = end.as_lcha_f32();
+                let (h0, h1) = resolve_hues(h0, c0, h1, c1);
+                Color::lcha(
+                    lerp(l0, l1, ratio),
+                    lerp(c0, c1, ratio),
+                    lerp_hue(h0, h1, ratio),
+                    lerp(a0, a1, ratio),
+                )
+            }
+            Self::Oklaba => {
+                let start = Oklaba::from(*start);
+                let end = Oklaba::from(*end);
+                Oklaba {
+                    lightness: lerp(start.lightness, end.lightness, ratio),
+                    a: lerp(start.a, end.a, ratio),
+                    b: lerp(start.b, end.b, ratio),
+                    alpha: lerp(start.alpha, end.alpha, ratio),
+                }
+                .into()
+            }
+        }
+    }
+}
+
+/// A color in the [Oklab] color space, with an alpha channel.
+///
+/// Bevy doesn't support this color space natively, so this type is used to
+/// author the colors of a color lens interpolating in [`ColorSpace::Oklaba`].
+/// It converts to and from a [`Color`] through linear RGB.
+///
+/// [Oklab]: https://bottosson.github.io/posts/oklab/
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Oklaba {
+    /// Perceived lightness, in \[0:1\].
+    pub lightness: f32,
+    /// Green-red axis.
+    pub a: f32,
+    /// Blue-yellow axis.
+    pub b: f32,
+    /// Alpha channel, in \[0:1\].
+    pub alpha: f32,
+}
+
+impl Oklaba {
+    /// Create a new color from its components.
+    pub const fn new(lightness: f32, a: f32, b: f32, alpha: f32) -> Self {
+        Self {
+            lightness,
+            a,
+            b,
+            alpha,
+        }
+    }
+}
+
+impl From<Color> for Oklaba {
+    fn from(color: Color) -> Self {
+        let [r, g, b, alpha] = color.as_linear_rgba_f32();
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+        Self {
+            lightness: 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            a: 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            b: 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+            alpha,
+        }
+    }
+}
+
+impl From<Oklaba> for Color {
+    fn from(color: Oklaba) -> Self {
+        let Oklaba {
+            lightness,
+            a,
+            b,
+            alpha,
+        } = color;
+        let l = lightness + 0.396_337_78 * a + 0.215_803_76 * b;
+        let m = lightness - 0.105_561_346 * a - 0.063_854_17 * b;
+        let s = lightness - 0.089_484_18 * a - 1.291_485_5 * b;
+        let (l, m, s) = (l * l * l, m * m * m, s * s * s);
+        Color::rgba_linear(
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+            alpha,
+        )
+    }
+}
+
+fn lerp(start: f32, end: f32, ratio: f32) -> f32 {
+    (end - start).mul_add(ratio, start)
+}
+
+/// Replace the hue of an achromatic color with the hue of the other color.
+fn resolve_hues(h0: f32, c0: f32, h1: f32, c1: f32) -> (f32, f32) {
+    if c0 == 0. {
+        (h1, h1)
+    } else if c1 == 0. {
+        (h0, h0)
+    } else {
+        (h0, h1)
+    }
+}
+
+/// Interpolate a hue in degrees along the shortest arc, going in the direction
+/// of increasing hue when both arcs have the same length.
+fn lerp_hue(start: f32, end: f32, ratio: f32) -> f32 {
+    let mut delta = (end - start).rem_euclid(360.);
+    if delta > 180. {
+        delta -= 360.;
+    }
+    delta.mul_add(ratio, start).rem_euclid(360.)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::abs_diff_eq;
+
+    fn assert_color_eq(a: Color, b: Color) {
+        let a = a.as_linear_rgba_f32();
+        let b = b.as_linear_rgba_f32();
+        for i in 0..4 {
+            assert!(abs_diff_eq(a[i], b[i], 1e-4), "{a:?} != {b:?}");
+        }
+    }
+
+    #[test]
+    fn endpoints_exact() {
+        let start = Color::hsla(30., 0.5, 0.5, 1.);
+        let end = Color::lcha(0.7, 0.3, 200., 0.5);
+        for space in [
+            ColorSpace::Srgba,
+            ColorSpace::LinearRgba,
+            ColorSpace::Hsla,
+            ColorSpace::Lcha,
+            ColorSpace::Oklaba,
+        ] {
+            assert_eq!(space.lerp(&start, &end, 0.), start);
+            assert_eq!(space.lerp(&start, &end, 1.), end);
+            // Overshoot is clamped
+            assert_eq!(space.lerp(&start, &end, -0.5), start);
+            assert_eq!(space.lerp(&start, &end, 1.5), end);
+        }
+    }
+
+    #[test]
+    fn spaces() {
+        let start = Color::RED;
+        let end = Color::BLUE;
+
+        let c = ColorSpace::Srgba.lerp(&start, &end, 0.5);
+        assert_eq!(c, Color::rgba(0.5, 0., 0.5, 1.));
+
+        let c = ColorSpace::LinearRgba.lerp(&start, &end, 0.5);
+        assert_eq!(c, Color::rgba_linear(0.5, 0., 0.5, 1.));
+
+        // Red is 0°, blue is 240°, so the shortest arc goes through 300°
+        let c = ColorSpace::Hsla.lerp(&start, &end, 0.5);
+        let Color::Hsla { hue, .. } = c else {
+            panic!("expected Hsla, got {c:?}");
+        };
+        assert!(abs_diff_eq(hue, 300., 1e-3));
+
+        let c = ColorSpace::Lcha.lerp(&start, &end, 0.5);
+        assert!(matches!(c, Color::Lcha { .. }));
+
+        let c = ColorSpace::Oklaba.lerp(&start, &end, 0.5);
+        assert!(matches!(c, Color::RgbaLinear { .. }));
+        let c = Oklaba::from(c);
+        let (s, e) = (Oklaba::from(start), Oklaba::from(end));
+        assert!(abs_diff_eq(
+            c.lightness,
+            (s.lightness + e.lightness) / 2.,
+            1e-4
+        ));
+        assert!(abs_diff_eq(c.a, (s.a + e.a) / 2., 1e-4));
+        assert!(abs_diff_eq(c.b, (s.b + e.b) / 2., 1e-4));
+    }
+
+    #[test]
+    fn hue_shortest_arc() {
+        assert!(abs_diff_eq(lerp_hue(350., 10., 0.5), 0., 1e-3));
+        assert!(abs_diff_eq(lerp_hue(10., 350., 0.5), 0., 1e-3));
+        assert!(abs_diff_eq(lerp_hue(10., 350., 0.25), 5., 1e-3));
+        // Tie-break: exactly 180° apart goes in the direction of increasing hue
+        assert!(abs_diff_eq(lerp_hue(0., 180., 0.5), 90., 1e-3));
+        assert!(abs_diff_eq(lerp_hue(180., 0., 0.5), 270., 1e-3));
+        assert!(abs_diff_eq(lerp_hue(90., 270., 0.5), 180., 1e-3));
+
+        // Achromatic colors take the hue of the other color
+        let c = ColorSpace::Hsla.lerp(&Color::WHITE, &Color::hsl(120., 1., 0.5), 0.5);
+        let Color::Hsla { hue, .. } = c else {
+            panic!("expected Hsla, got {c:?}");
+        };
+        assert!(abs_diff_eq(hue, 120., 1e-3));
+    }
+
+    #[test]
+    fn oklab_round_trip() {
+        for color in [
+            Color::RED,
+            Color::GREEN,
+            Color::BLUE,
+            Color::WHITE,
+            Color::BLACK,
+            Color::rgba(0.2, 0.7, 0.4, 0.3),
+        ] {
+            assert_color_eq(Color::from(Oklaba::from(color)), color);
+        }
+        let white = Oklaba::from(Color::WHITE);
+        assert!(abs_diff_eq(white.lightness, 1., 1e-4));
+        assert!(abs_diff_eq(white.a, 0., 1e-4));
+        assert!(abs_diff_eq(white.b, 0., 1e-4));
+    }
+}
@@ -0,0 +1,439 @@
+//! Lenses for the `bevy_pbr` components and assets.
+
+use bevy::{prelude::*, render::camera::Exposure};
+
+use super::{ColorSpace, Lens, Oklaba, RangedLens};
+
+/// A lens to manipulate the [`base_color`] field of a [`StandardMaterial`]
+/// asset.
+///
+/// [`base_color`]: https://docs.rs/bevy/0.13.0/bevy/pbr/struct.StandardMaterial.html#structfield.base_color
+/// [`StandardMaterial`]: https://docs.rs/bevy/0.13.0/bevy/pbr/struct.StandardMaterial.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StandardMaterialBaseColorLens {
+    /// Start color.
+    pub start: Color,
+    /// End color.
+    pub end: Color,
+    /// Color space the colors are interpolated in.
+    pub space: ColorSpace,
+}
+
+impl StandardMaterialBaseColorLens {
+    color_lens_impl!();
+}
+
+impl Lens<StandardMaterial> for StandardMaterialBaseColorLens {
+    fn lerp(&mut self, target: &mut StandardMaterial, ratio: f32) {
+        let value = self.space.lerp(&self.start, &self.end, ratio);
+        target.base_color = value;
+    }
+
+    lens_is_valid!(start, end);
+    color_lens_blend!(StandardMaterial);
+}
+
+impl_ranged_lens!(StandardMaterialBaseColorLens, Color);
+
+/// A lens to manipulate the [`AmbientLight`] resource.
+///
+/// This lens interpolates both the [`color`] and the [`brightness`] of the
+/// ambient light. It's used with a [`ResourceAnimator`].
+///
+/// [`AmbientLight`]: https://docs.rs/bevy/0.13.0/bevy/pbr/struct.AmbientLight.html
+/// [`color`]: https://docs.rs/bevy/0.13.0/bevy/pbr/struct.AmbientLight.html#structfield.color
+/// [`brightness`]: https://docs.rs/bevy/0.13.0/bevy/pbr/struct.AmbientLight.html#structfield.brightness
+/// [`ResourceAnimator`]: crate::ResourceAnimator
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AmbientLightLens {
+    /// Start color.
+    pub start_color: Color,
+    /// End color.
+    pub end_color: Color,
+    /// Start brightness.
+    pub start_brightness: f32,
+    /// End brightness.
+    pub end_brightness: f32,
+}
+
+impl Lens<AmbientLight> for AmbientLightLens {
+    fn lerp(&mut self, target: &mut AmbientLight, ratio: f32) {
+        let ratio = ratio.clamp(0., 1.);
+
+        target.color = ColorSpace::Srgba.lerp(&self.start_color, &self.end_color, ratio);
+        target.brightness = self.start_brightness.lerp(self.end_brightness, ratio);
+    }
+
+    lens_is_valid!(start_color, end_color, start_brightness, end_brightness);
+}
+
+/// The value of the lens is a `(color, brightness)` tuple.
+impl RangedLens for AmbientLightLens {
+    type Value = (Color, f32);
+
+    fn start(&self) -> (Color, f32) {
+        (self.start_color, self.start_brightness)
+    }
+
+    fn set_start(&mut self, (color, brightness): (Color, f32)) {
+        self.start_color = color;
+        self.start_brightness = brightness;
+    }
+
+    fn end(&self) -> (Color, f32) {
+        (self.end_color, self.end_brightness)
+    }
+
+    fn set_end(&mut self, (color, brightness): (Color, f32)) {
+        self.end_color = color;
+        self.end_brightness = brightness;
+    }
+}
+
+/// A lens to manipulate the [`FogSettings`] component of a camera.
+///
+/// This lens interpolates the [`color`] and the [`falloff`] of the fog. The
+/// parameters of the falloff are interpolated when `start_falloff` and
+/// `end_falloff` use the same [`FogFalloff`] mode. Different modes cannot be
+/// interpolated, so in that case the falloff switches from `start_falloff` to
+/// `end_falloff` at mid-progress.
+///
+/// [`FogSettings`]: https://docs.rs/bevy/0.13.0/bevy/pbr/struct.FogSettings.html
+/// [`color`]: https://docs.rs/bevy/0.13.0/bevy/pbr/struct.FogSettings.html#structfield.color
+/// [`falloff`]: https://docs.rs/bevy/0.13.0/bevy/pbr/struct.FogSettings.html#structfield.falloff
+/// [`FogFalloff`]: https://docs.rs/bevy/0.13.0/bevy/pbr/enum.FogFalloff.html
+#[derive(Debug, Clone)]
+pub struct FogLens {
+    /// Start color.
+    pub start_color: Color,
+    /// End color.
+    pub end_color: Color,
+    /// Start falloff.
+    pub start_falloff: FogFalloff,
+    /// End falloff.
+    pub end_falloff: FogFalloff,
+}
+
+impl Lens<FogSettings> for FogLens {
+    fn lerp(&mut self, target: &mut FogSettings, ratio: f32) {
+        let ratio = ratio.clamp(0., 1.);
+
+        target.color = ColorSpace::Srgba.lerp(&self.start_color, &self.end_color, ratio);
+        target.falloff = match (&self.start_falloff, &self.end_falloff) {
+            (
+                FogFalloff::Linear { start: s0, end: e0 },
+                FogFalloff::Linear { start: s1, end: e1 },
+            ) => FogFalloff::Linear {
+                start: s0.lerp(*s1, ratio),
+                end: e0.lerp(*e1, ratio),
+            },
+            (FogFalloff::Exponential { density: d0 }, FogFalloff::Exponential { density: d1 }) => {
+                FogFalloff::Exponential {
+                    density: d0.lerp(*d1, ratio),
+                }
+            }
+            (
+                FogFalloff::ExponentialSquared { density: d0 },
+                FogFalloff::ExponentialSquared { density: d1 },
+            ) => FogFalloff::ExponentialSquared {
+                density: d0.lerp(*d1, ratio),
+            },
+            (
+                FogFalloff::Atmospheric {
+                    extinction: e0,
+                    inscattering: i0,
+                },
+                FogFalloff::Atmospheric {
+                    extinction: e1,
+                    inscattering: i1,
+                },
+            ) => FogFalloff::Atmospheric {
+                extinction: e0.lerp(*e1, ratio),
+                inscattering: i0.lerp(*i1, ratio),
+            },
+            _ => {
+                if ratio < 0.5 {
+                    self.start_falloff.clone()
+                } else {
+                    self.end_falloff.clone()
+                }
+            }
+        };
+    }
+
+    lens_is_valid!(start_color, end_color);
+}
+
+/// The value of the lens is a `(color, falloff)` tuple.
+impl RangedLens for FogLens {
+    type Value = (Color, FogFalloff);
+
+    fn start(&self) -> (Color, FogFalloff) {
+        (self.start_color, self.start_falloff.clone())
+    }
+
+    fn set_start(&mut self, (color, falloff): (Color, FogFalloff)) {
+        self.start_color = color;
+        self.start_falloff = falloff;
+    }
+
+    fn end(&self) -> (Color, FogFalloff) {
+        (self.end_color, self.end_falloff.clone())
+    }
+
+    fn set_end(&mut self, (color, falloff): (Color, FogFalloff)) {
+        self.end_color = color;
+        self.end_falloff = falloff;
+    }
+}
+
+/// Interpolate between two scalars, or return `None` if the result is not
+/// finite.
+///
+/// Overshooting easings are allowed, but a NaN or infinite value is never
+/// written to a rendering parameter.
+fn lerp_finite(start: f32, end: f32, ratio: f32) -> Option<f32> {
+    let value = start.lerp(end, ratio);
+    value.is_finite().then_some(value)
+}
+
+/// A lens to manipulate the [`Exposure`] component of a camera.
+///
+/// This lens interpolates the [`ev100`] exposure value, for example to adapt
+/// the exposure of a camera during a time-of-day transition. The ratio is not
+/// clamped, but a non-finite exposure value is never written to the camera;
+/// the component is left untouched instead.
+///
+/// [`Exposure`]: https://docs.rs/bevy/0.13.0/bevy/render/camera/struct.Exposure.html
+/// [`ev100`]: https://docs.rs/bevy/0.13.0/bevy/render/camera/struct.Exposure.html#structfield.ev100
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ExposureLens {
+    /// Start exposure value, in EV100.
+    pub start_ev100: f32,
+    /// End exposure value, in EV100.
+    pub end_ev100: f32,
+}
+
+impl Lens<Exposure> for ExposureLens {
+    fn lerp(&mut self, target: &mut Exposure, ratio: f32) {
+        if let Some(ev100) = lerp_finite(self.start_ev100, self.end_ev100, ratio) {
+            target.ev100 = ev100;
+        }
+    }
+
+    lens_is_valid!(start_ev100, end_ev100);
+}
+
+impl RangedLens for ExposureLens {
+    type Value = f32;
+
+    fn start(&self) -> f32 {
+        self.start_ev100
+    }
+
+    fn set_start(&mut self, start: f32) {
+        self.start_ev100 = start;
+    }
+
+    fn end(&self) -> f32 {
+        self.end_ev100
+    }
+
+    fn set_end(&mut self, end: f32) {
+        self.end_ev100 = end;
+    }
+}
+
+/// A lens to manipulate the [`shadow_depth_bias`] field of a
+/// [`DirectionalLight`] component.
+///
+/// The ratio is not clamped, and the bias can become negative, but a
+/// non-finite bias is never written to the light; the component is left
+/// untouched instead.
+///
+/// [`shadow_depth_bias`]: https://docs.rs/bevy/0.13.0/bevy/pbr/struct.DirectionalLight.html#structfield.shadow_depth_bias
+/// [`DirectionalLight`]: https://docs.rs/bevy/0.13.0/bevy/pbr/struct.DirectionalLight.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DirectionalLightShadowDepthBiasLens {
+    /// Start depth bias.
+    pub start: f32,
+    /// End depth bias.
+    pub end: f32,
+}
+
+impl Lens<DirectionalLight> for DirectionalLightShadowDepthBiasLens {
+    fn lerp(&mut self, target: &mut DirectionalLight, ratio: f32) {
+        if let Some(bias) = lerp_finite(self.start, self.end, ratio) {
+            target.shadow_depth_bias = bias;
+        }
+    }
+
+    lens_is_valid!(start, end);
+    lens_scale_range!();
+    lens_blend!(DirectionalLight);
+}
+
+impl_ranged_lens!(DirectionalLightShadowDepthBiasLens, f32);
+
+/// A lens to manipulate the [`shadow_normal_bias`] field of a
+/// [`DirectionalLight`] component.
+///
+/// The ratio is not clamped, and the bias can become negative, but a
+/// non-finite bias is never written to the light; the component is left
+/// untouched instead.
+///
+/// [`shadow_normal_bias`]: https://docs.rs/bevy/0.13.0/bevy/pbr/struct.DirectionalLight.html#structfield.shadow_normal_bias
+/// [`DirectionalLight`]: https://docs.rs/bevy/0.13.0/bevy/pbr/struct.DirectionalLight.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DirectionalLightShadowNormalBiasLens {
+    /// Start normal bias.
+    pub start: f32,
+    /// End normal bias.
+    pub end: f32,
+}
+
+impl Lens<DirectionalLight> for DirectionalLightShadowNormalBiasLens {
+    fn lerp(&mut self, target: &mut DirectionalLight, ratio: f32) {
+        if let Some(bias) = lerp_finite(self.start, self.end, ratio) {
+            target.shadow_normal_bias = bias;
+        }
+    }
+
+    lens_is_valid!(start, end);
+    lens_scale_range!();
+    lens_blend!(DirectionalLight);
+}
+
+impl_ranged_lens!(DirectionalLightShadowNormalBiasLens, f32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn standard_material_base_color() {
+        let mut lens = StandardMaterialBaseColorLens::new(Color::RED, Color::BLUE);
+        let mut mat = StandardMaterial {
+            base_color: Color::WHITE,
+            ..default()
+        };
+
+        lens.lerp(&mut mat, 0.);
+        assert_eq!(mat.base_color, Color::RED);
+
+        lens.lerp(&mut mat, 1.);
+        assert_eq!(mat.base_color, Color::BLUE);
+
+        lens.lerp(&mut mat, 0.3);
+        assert_eq!(mat.base_color, Color::rgba(0.7, 0., 0.3, 1.0));
+    }
+
+    #[test]
+    fn ambient_light() {
+        let mut lens = AmbientLightLens {
+            start_color: Color::RED,
+            end_color: Color::BLUE,
+            start_brightness: 100.,
+            end_brightness: 500.,
+        };
+        let mut light = AmbientLight::default();
+
+        lens.lerp(&mut light, 0.);
+        assert_eq!(light.color, Color::RED);
+        assert_approx_eq!(light.brightness, 100.);
+
+        lens.lerp(&mut light, 1.);
+        assert_eq!(light.color, Color::BLUE);
+        assert_approx_eq!(light.brightness, 500.);
+
+        lens.lerp(&mut light, 0.5);
+        assert_eq!(light.color, Color::rgba(0.5, 0., 0.5, 1.0));
+        assert_approx_eq!(light.brightness, 300.);
+    }
+
+    #[test]
+    fn fog() {
+        let mut lens = FogLens {
+            start_color: Color::WHITE,
+            end_color: Color::BLACK,
+            start_falloff: FogFalloff::Linear {
+                start: 10.,
+                end: 50.,
+            },
+            end_falloff: FogFalloff::Linear {
+                start: 20.,
+                end: 150.,
+            },
+        };
+        let mut fog = FogSettings::default();
+
+        lens.lerp(&mut fog, 0.5);
+        assert_eq!(fog.color, Color::rgba(0.5, 0.5, 0.5, 1.0));
+        let FogFalloff::Linear { start, end } = fog.falloff else {
+            panic!("unexpected falloff {:?}", fog.falloff);
+        };
+        assert_approx_eq!(start, 15.);
+        assert_approx_eq!(end, 100.);
+
+        lens.start_falloff = FogFalloff::Exponential { density: 0.1 };
+        lens.end_falloff = FogFalloff::Exponential { density: 0.3 };
+        lens.lerp(&mut fog, 0.5);
+        let FogFalloff::Exponential { density } = fog.falloff else {
+            panic!("unexpected falloff {:?}", fog.falloff);
+        };
+        assert_approx_eq!(density, 0.2);
+
+        // Different modes switch at mid-progress
+        lens.end_falloff = FogFalloff::ExponentialSquared { density: 0.3 };
+        lens.lerp(&mut fog, 0.49);
+        assert!(matches!(fog.falloff, FogFalloff::Exponential { .. }));
+        lens.lerp(&mut fog, 0.5);
+        assert!(matches!(fog.falloff, FogFalloff::ExponentialSquared { .. }));
+    }
+
+    #[test]
+    fn exposure() {
+        let mut lens = ExposureLens {
+            start_ev100: Exposure::EV100_SUNLIGHT,
+            end_ev100: Exposure::EV100_INDOOR,
+        };
+        let mut exposure = Exposure::default();
+
+        lens.lerp(&mut exposure, 0.);
+        assert_approx_eq!(exposure.ev100, 15.);
+
+        lens.lerp(&mut exposure, 0.25);
+        assert_approx_eq!(exposure.ev100, 13.);
+
+        // Overshoot is allowed, but not a non-finite value
+        lens.lerp(&mut exposure, 1.5);
+        assert_approx_eq!(exposure.ev100, 3.);
+        lens.lerp(&mut exposure, f32::NAN);
+        assert_approx_eq!(exposure.ev100, 3.);
+    }
+
+    #[test]
+    fn directional_light_shadow_bias() {
+        let mut depth_lens = DirectionalLightShadowDepthBiasLens {
+            start: 0.02,
+            end: -0.02,
+        };
+        let mut normal_lens = DirectionalLightShadowNormalBiasLens {
+            start: 1.8,
+            end: 0.6,
+        };
+        let mut light = DirectionalLight::default();
+
+        depth_lens.lerp(&mut light, 0.75);
+        normal_lens.lerp(&mut light, 0.75);
+        assert_approx_eq!(light.shadow_depth_bias, -0.01);
+        assert_approx_eq!(light.shadow_normal_bias, 0.9);
+
+        depth_lens.end = f32::INFINITY;
+        depth_lens.lerp(&mut light, 0.);
+        assert_approx_eq!(light.shadow_depth_bias, -0.01);
+        depth_lens.lerp(&mut light, 0.5);
+        assert_approx_eq!(light.shadow_depth_bias, -0.01);
+    }
+}
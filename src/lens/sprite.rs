@@ -0,0 +1,520 @@
+//! Lenses for the `bevy_sprite` components and assets.
+
+use bevy::prelude::*;
+
+use super::{
+    ColorSpace, Lens, MissingValuePolicy, Oklaba, OptionLens, RangedLens, RectLens, ValueLens,
+};
+
+/// A lens to manipulate the [`color`] field of a [`ColorMaterial`] asset.
+///
+/// [`color`]: https://docs.rs/bevy/0.12.0/bevy/sprite/struct.ColorMaterial.html#structfield.color
+/// [`ColorMaterial`]: https://docs.rs/bevy/0.12.0/bevy/sprite/struct.ColorMaterial.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ColorMaterialColorLens {
+    /// Start color.
+    pub start: Color,
+    /// End color.
+    pub end: Color,
+    /// Color space the colors are interpolated in.
+    pub space: ColorSpace,
+}
+
+impl ColorMaterialColorLens {
+    color_lens_impl!();
+}
+
+impl Lens<ColorMaterial> for ColorMaterialColorLens {
+    fn lerp(&mut self, target: &mut ColorMaterial, ratio: f32) {
+        let value = self.space.lerp(&self.start, &self.end, ratio);
+        target.color = value;
+    }
+
+    lens_is_valid!(start, end);
+    color_lens_blend!(ColorMaterial);
+}
+
+impl_ranged_lens!(ColorMaterialColorLens, Color);
+
+/// A lens to manipulate the [`color`] field of a [`Sprite`] asset.
+///
+/// [`color`]: https://docs.rs/bevy/0.12.0/bevy/sprite/struct.Sprite.html#structfield.color
+/// [`Sprite`]: https://docs.rs/bevy/0.12.0/bevy/sprite/struct.Sprite.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SpriteColorLens {
+    /// Start color.
+    pub start: Color,
+    /// End color.
+    pub end: Color,
+    /// Color space the colors are interpolated in.
+    pub space: ColorSpace,
+}
+
+impl SpriteColorLens {
+    color_lens_impl!();
+
+    /// Convert this lens into a [`SpriteColorPrecomputedLens`], trading the
+    /// color space of the lens for a cheaper interpolation in linear RGB.
+    ///
+    /// The [`space`] of this lens is ignored.
+    ///
+    /// [`space`]: Self::space
+    #[must_use]
+    pub fn precomputed(self) -> SpriteColorPrecomputedLens {
+        SpriteColorPrecomputedLens::new(self.start, self.end)
+    }
+}
+
+impl Lens<Sprite> for SpriteColorLens {
+    fn lerp(&mut self, target: &mut Sprite, ratio: f32) {
+        let value = self.space.lerp(&self.start, &self.end, ratio);
+        target.color = value;
+    }
+
+    lens_is_valid!(start, end);
+    color_lens_blend!(Sprite);
+}
+
+impl_ranged_lens!(SpriteColorLens, Color);
+
+/// A cheaper variant of [`SpriteColorLens`], for animating the color of a
+/// large number of sprites.
+///
+/// The lens converts its endpoints to linear RGBA once, when they're set, and
+/// interpolates those linear components directly, writing a
+/// [`Color::RgbaLinear`] without any further color conversion. This always
+/// interpolates in [`ColorSpace::LinearRgba`], and produces the same
+/// intermediate colors as a [`SpriteColorLens`] in that color space.
+///
+/// Like the other color lenses, the ratio is clamped to \[0:1\], and a ratio
+/// of exactly `0.` or `1.` writes the `start` or `end` color as authored.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::lens::*;
+/// let lens = SpriteColorLens::new(Color::WHITE, Color::NONE).precomputed();
+/// assert_eq!(lens.start(), Color::WHITE);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SpriteColorPrecomputedLens {
+    start: Color,
+    end: Color,
+    start_linear: Vec4,
+    end_linear: Vec4,
+}
+
+impl SpriteColorPrecomputedLens {
+    /// Create a new lens interpolating between two colors in linear RGB.
+    pub fn new(start: Color, end: Color) -> Self {
+        Self {
+            start,
+            end,
+            start_linear: Vec4::from_array(start.as_linear_rgba_f32()),
+            end_linear: Vec4::from_array(end.as_linear_rgba_f32()),
+        }
+    }
+
+    fn sample(&self, ratio: f32) -> Color {
+        if ratio <= 0. {
+            return self.start;
+        }
+        if ratio >= 1. {
+            return self.end;
+        }
+        let [r, g, b, a] = self.start_linear.lerp(self.end_linear, ratio).to_array();
+        Color::rgba_linear(r, g, b, a)
+    }
+}
+
+impl Lens<Sprite> for SpriteColorPrecomputedLens {
+    fn lerp(&mut self, target: &mut Sprite, ratio: f32) {
+        target.color = self.sample(ratio);
+    }
+
+    lens_is_valid!(start, end);
+
+    fn scale_range(&mut self, factor: f32) -> bool {
+        self.set_end(self.sample(factor));
+        true
+    }
+
+    fn value_at(&self, ratio: f32) -> Option<Box<dyn std::any::Any + Send + Sync>> {
+        Some(Box::new(self.sample(ratio)))
+    }
+
+    fn boundary_value(
+        &self,
+        direction: crate::TweeningDirection,
+    ) -> Option<Box<dyn std::any::Any + Send + Sync>> {
+        Some(Box::new(match direction {
+            crate::TweeningDirection::Forward => self.end,
+            crate::TweeningDirection::Backward => self.start,
+        }))
+    }
+
+    fn lerp_from(
+        &mut self,
+        target: &mut Sprite,
+        ratio: f32,
+        from: &dyn std::any::Any,
+        weight: f32,
+    ) -> bool {
+        let Some(from) = from.downcast_ref::<Color>() else {
+            return false;
+        };
+        let saved = *self;
+        self.set_start(Self::new(*from, self.start).sample(weight));
+        self.lerp(target, ratio);
+        *self = saved;
+        true
+    }
+}
+
+impl RangedLens for SpriteColorPrecomputedLens {
+    type Value = Color;
+
+    fn start(&self) -> Color {
+        self.start
+    }
+
+    fn set_start(&mut self, start: Color) {
+        *self = Self::new(start, self.end);
+    }
+
+    fn end(&self) -> Color {
+        self.end
+    }
+
+    fn set_end(&mut self, end: Color) {
+        *self = Self::new(self.start, end);
+    }
+}
+
+/// A lens to manipulate the [`custom_size`] field of a [`Sprite`] component.
+///
+/// The field is optional, and is `None` by default. The lens uses an
+/// [`OptionLens`] to handle that case according to its
+/// [`MissingValuePolicy`].
+///
+/// [`custom_size`]: https://docs.rs/bevy/0.13.0/bevy/sprite/struct.Sprite.html#structfield.custom_size
+/// [`Sprite`]: https://docs.rs/bevy/0.13.0/bevy/sprite/struct.Sprite.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SpriteCustomSizeLens(pub OptionLens<ValueLens<Vec2>, Vec2>);
+
+impl SpriteCustomSizeLens {
+    /// Create a lens animating the custom size from `start` to `end`.
+    pub fn new(start: Vec2, end: Vec2, policy: MissingValuePolicy<Vec2>) -> Self {
+        Self(OptionLens::new(ValueLens { start, end }, policy))
+    }
+}
+
+impl Lens<Sprite> for SpriteCustomSizeLens {
+    fn lerp(&mut self, target: &mut Sprite, ratio: f32) {
+        self.0.lerp(&mut target.custom_size, ratio);
+    }
+
+    fn is_valid(&self) -> bool {
+        self.0.is_valid()
+    }
+
+    fn scale_range(&mut self, factor: f32) -> bool {
+        self.0.scale_range(factor)
+    }
+}
+
+impl RangedLens for SpriteCustomSizeLens {
+    type Value = Vec2;
+
+    fn start(&self) -> Vec2 {
+        self.0.start()
+    }
+
+    fn set_start(&mut self, start: Vec2) {
+        self.0.set_start(start);
+    }
+
+    fn end(&self) -> Vec2 {
+        self.0.end()
+    }
+
+    fn set_end(&mut self, end: Vec2) {
+        self.0.set_end(end);
+    }
+}
+
+/// A lens to manipulate the [`rect`] field of a [`Sprite`] component.
+///
+/// The source rectangle of the sprite is interpolated with a [`RectLens`],
+/// for example to reveal a sprite by growing its rectangle from a zero width,
+/// or to wipe it. Like for [`RectLens`], an overshooting easing never produces
+/// a rectangle with `min > max`. The field is optional, and is `None` by
+/// default. The lens uses an [`OptionLens`] to handle that case according to
+/// its [`MissingValuePolicy`].
+///
+/// [`rect`]: https://docs.rs/bevy/0.13.0/bevy/sprite/struct.Sprite.html#structfield.rect
+/// [`Sprite`]: https://docs.rs/bevy/0.13.0/bevy/sprite/struct.Sprite.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SpriteRectLens(pub OptionLens<RectLens, Rect>);
+
+impl SpriteRectLens {
+    /// Create a lens animating the source rectangle from `start` to `end`.
+    pub fn new(start: Rect, end: Rect, policy: MissingValuePolicy<Rect>) -> Self {
+        Self(OptionLens::new(RectLens { start, end }, policy))
+    }
+}
+
+impl Lens<Sprite> for SpriteRectLens {
+    fn lerp(&mut self, target: &mut Sprite, ratio: f32) {
+        self.0.lerp(&mut target.rect, ratio);
+    }
+
+    fn is_valid(&self) -> bool {
+        self.0.is_valid()
+    }
+}
+
+impl RangedLens for SpriteRectLens {
+    type Value = Rect;
+
+    fn start(&self) -> Rect {
+        self.0.start()
+    }
+
+    fn set_start(&mut self, start: Rect) {
+        self.0.set_start(start);
+    }
+
+    fn end(&self) -> Rect {
+        self.0.end()
+    }
+
+    fn set_end(&mut self, end: Rect) {
+        self.0.set_end(end);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lens::TransformPositionLens, EaseFunction, EaseMethod};
+
+    #[test]
+    fn colormaterial_color() {
+        let mut lens = ColorMaterialColorLens::new(Color::RED, Color::BLUE);
+        let mut mat = ColorMaterial {
+            color: Color::WHITE,
+            texture: None,
+        };
+
+        lens.lerp(&mut mat, 0.);
+        assert_eq!(mat.color, Color::RED);
+
+        lens.lerp(&mut mat, 1.);
+        assert_eq!(mat.color, Color::BLUE);
+
+        lens.lerp(&mut mat, 0.3);
+        assert_eq!(mat.color, Color::rgba(0.7, 0., 0.3, 1.0));
+    }
+
+    #[test]
+    fn sprite_color() {
+        let mut lens = SpriteColorLens::new(Color::RED, Color::BLUE);
+        let mut sprite = Sprite {
+            color: Color::WHITE,
+            ..default()
+        };
+
+        lens.lerp(&mut sprite, 0.);
+        assert_eq!(sprite.color, Color::RED);
+
+        lens.lerp(&mut sprite, 1.);
+        assert_eq!(sprite.color, Color::BLUE);
+
+        lens.lerp(&mut sprite, 0.3);
+        assert_eq!(sprite.color, Color::rgba(0.7, 0., 0.3, 1.0));
+    }
+
+    #[test]
+    fn sprite_color_oklab() {
+        let start = Oklaba::new(0.6, 0.2, 0.1, 1.);
+        let end = Oklaba::new(0.8, -0.1, 0.05, 0.5);
+        let mut lens = SpriteColorLens::oklab(start, end);
+        assert_eq!(lens.space, ColorSpace::Oklaba);
+        let mut sprite = Sprite::default();
+
+        // Endpoints are written exactly as authored
+        lens.lerp(&mut sprite, 0.);
+        assert_eq!(sprite.color, Color::from(start));
+        lens.lerp(&mut sprite, 1.);
+        assert_eq!(sprite.color, Color::from(end));
+
+        lens.lerp(&mut sprite, 0.5);
+        let mid = Oklaba::from(sprite.color);
+        assert!((mid.lightness - 0.7).abs() < 1e-4);
+        assert!((mid.a - 0.05).abs() < 1e-4);
+        assert!((mid.b - 0.075).abs() < 1e-4);
+        assert!((mid.alpha - 0.75).abs() < 1e-4);
+
+        let mut lens = SpriteColorLens::new(Color::RED, Color::BLUE).with_space(ColorSpace::Hsla);
+        lens.lerp(&mut sprite, 0.5);
+        assert!(matches!(sprite.color, Color::Hsla { .. }));
+    }
+
+    #[test]
+    fn sprite_color_precomputed() {
+        let start = Color::rgba(0.9, 0.2, 0.4, 1.);
+        let end = Color::hsla(200., 0.5, 0.3, 0.25);
+        let mut lens = SpriteColorLens::new(start, end).precomputed();
+        let mut reference = SpriteColorLens::new(start, end).with_space(ColorSpace::LinearRgba);
+        let mut sprite = Sprite::default();
+        let mut expected = Sprite::default();
+
+        // Endpoints are written exactly as authored
+        lens.lerp(&mut sprite, 0.);
+        assert_eq!(sprite.color, start);
+        lens.lerp(&mut sprite, 1.);
+        assert_eq!(sprite.color, end);
+        lens.lerp(&mut sprite, -0.5);
+        assert_eq!(sprite.color, start);
+        lens.lerp(&mut sprite, 1.5);
+        assert_eq!(sprite.color, end);
+
+        // Intermediate colors match the linear RGB color space
+        for ratio in [0.1, 0.3, 0.5, 0.9] {
+            lens.lerp(&mut sprite, ratio);
+            reference.lerp(&mut expected, ratio);
+            assert!(matches!(sprite.color, Color::RgbaLinear { .. }));
+            let actual = Vec4::from_array(sprite.color.as_linear_rgba_f32());
+            let expected = Vec4::from_array(expected.color.as_linear_rgba_f32());
+            assert!(actual.abs_diff_eq(expected, 1e-6), "{actual} != {expected}");
+        }
+
+        // Changing an endpoint updates the precomputed values
+        lens.set_end(Color::BLACK);
+        lens.lerp(&mut sprite, 0.5);
+        let [r, g, b, a] = start.as_linear_rgba_f32();
+        let actual = Vec4::from_array(sprite.color.as_linear_rgba_f32());
+        assert!(actual.abs_diff_eq(Vec4::new(r, g, b, a + 1.) * 0.5, 1e-6));
+        lens.lerp(&mut sprite, 1.);
+        assert_eq!(sprite.color, Color::BLACK);
+    }
+
+    #[test]
+    fn sprite_color_clamped() {
+        let ease = EaseMethod::from(EaseFunction::BackOut);
+        let mut color_lens = SpriteColorLens::new(Color::rgba(1., 1., 1., 0.), Color::WHITE);
+        let mut position_lens = TransformPositionLens {
+            start: Vec3::ZERO,
+            end: Vec3::X,
+        };
+        let mut sprite = Sprite::default();
+        let mut transform = Transform::default();
+        let mut max_x = 0_f32;
+        for i in 0..=100 {
+            let ratio = ease.sample(i as f32 / 100.);
+            color_lens.lerp(&mut sprite, ratio);
+            let alpha = sprite.color.a();
+            assert!((0. ..=1.).contains(&alpha), "alpha={alpha}");
+
+            position_lens.lerp(&mut transform, ratio);
+            max_x = max_x.max(transform.translation.x);
+        }
+        // The same ease still overshoots on a spatial lens.
+        assert!(max_x > 1.05, "max_x={max_x}");
+    }
+
+    #[test]
+    fn sprite_custom_size_initialize() {
+        let mut lens = SpriteCustomSizeLens::new(
+            Vec2::ZERO,
+            Vec2::new(4., 8.),
+            MissingValuePolicy::Initialize(Vec2::ONE),
+        );
+        let mut sprite = Sprite::default();
+        assert_eq!(sprite.custom_size, None);
+
+        lens.lerp(&mut sprite, 0.25);
+        assert_eq!(sprite.custom_size, Some(Vec2::new(1., 2.)));
+
+        // A size removed during the animation is created again.
+        sprite.custom_size = None;
+        lens.lerp(&mut sprite, 1.);
+        assert_eq!(sprite.custom_size, Some(Vec2::new(4., 8.)));
+    }
+
+    #[test]
+    fn sprite_custom_size_skip_while_none() {
+        let mut lens = SpriteCustomSizeLens::new(
+            Vec2::ZERO,
+            Vec2::new(4., 8.),
+            MissingValuePolicy::SkipWhileNone,
+        );
+        let mut sprite = Sprite::default();
+
+        lens.lerp(&mut sprite, 0.25);
+        assert_eq!(sprite.custom_size, None);
+
+        sprite.custom_size = Some(Vec2::splat(100.));
+        lens.lerp(&mut sprite, 0.5);
+        assert_eq!(sprite.custom_size, Some(Vec2::new(2., 4.)));
+    }
+
+    #[test]
+    fn sprite_custom_size_take_current() {
+        let mut lens = SpriteCustomSizeLens::new(
+            Vec2::ZERO,
+            Vec2::new(4., 8.),
+            MissingValuePolicy::TakeCurrentAsStart,
+        );
+        let mut sprite = Sprite::default();
+
+        lens.lerp(&mut sprite, 0.25);
+        assert_eq!(sprite.custom_size, None);
+
+        // The first value seen becomes the start value.
+        sprite.custom_size = Some(Vec2::new(2., 4.));
+        lens.lerp(&mut sprite, 0.5);
+        assert_eq!(sprite.custom_size, Some(Vec2::new(3., 6.)));
+        assert_eq!(lens.0.lens.start, Vec2::new(2., 4.));
+
+        // It's captured only once.
+        lens.lerp(&mut sprite, 0.);
+        assert_eq!(sprite.custom_size, Some(Vec2::new(2., 4.)));
+        lens.lerp(&mut sprite, 1.);
+        assert_eq!(sprite.custom_size, Some(Vec2::new(4., 8.)));
+    }
+
+    #[test]
+    fn sprite_rect_reveal() {
+        let mut lens = SpriteRectLens::new(
+            Rect::new(0., 0., 0., 32.),
+            Rect::new(0., 0., 64., 32.),
+            MissingValuePolicy::SkipWhileNone,
+        );
+        let mut sprite = Sprite {
+            rect: Some(Rect::new(0., 0., 64., 32.)),
+            ..default()
+        };
+
+        // Left-to-right reveal, from a zero width
+        lens.lerp(&mut sprite, 0.);
+        assert_eq!(sprite.rect, Some(Rect::new(0., 0., 0., 32.)));
+        assert_eq!(sprite.rect.unwrap().width(), 0.);
+        lens.lerp(&mut sprite, 0.5);
+        assert_eq!(sprite.rect, Some(Rect::new(0., 0., 32., 32.)));
+        lens.lerp(&mut sprite, 1.);
+        assert_eq!(sprite.rect, Some(Rect::new(0., 0., 64., 32.)));
+
+        // An undershoot swaps the corners instead of inverting the rectangle
+        lens.lerp(&mut sprite, -0.25);
+        let rect = sprite.rect.unwrap();
+        assert_eq!(rect.min, Vec2::new(-16., 0.));
+        assert_eq!(rect.max, Vec2::new(0., 32.));
+
+        sprite.rect = None;
+        lens.lerp(&mut sprite, 0.5);
+        assert_eq!(sprite.rect, None);
+    }
+}
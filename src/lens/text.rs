@@ -0,0 +1,105 @@
+//! Lenses for the `bevy_text` components.
+
+use bevy::prelude::*;
+
+use super::{ColorSpace, Lens, Oklaba};
+
+/// A lens to manipulate the [`color`] field of a section of a [`Text`]
+/// component.
+///
+/// [`color`]: https://docs.rs/bevy/0.12.0/bevy/text/struct.TextStyle.html#structfield.color
+/// [`Text`]: https://docs.rs/bevy/0.12.0/bevy/text/struct.Text.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TextColorLens {
+    /// Start color.
+    pub start: Color,
+    /// End color.
+    pub end: Color,
+    /// Color space the colors are interpolated in.
+    pub space: ColorSpace,
+    /// Index of the text section in the [`Text`] component.
+    pub section: usize,
+}
+
+impl TextColorLens {
+    /// Create a new lens interpolating between two colors in the default
+    /// [`ColorSpace::Srgba`] color space, for the given text section.
+    pub fn new(start: Color, end: Color, section: usize) -> Self {
+        Self {
+            start,
+            end,
+            space: ColorSpace::default(),
+            section,
+        }
+    }
+
+    /// Create a new lens interpolating between two colors authored in the
+    /// [Oklab] color space, natively in that space, for the given text
+    /// section.
+    ///
+    /// [Oklab]: https://bottosson.github.io/posts/oklab/
+    pub fn oklab(start: Oklaba, end: Oklaba, section: usize) -> Self {
+        Self {
+            start: start.into(),
+            end: end.into(),
+            space: ColorSpace::Oklaba,
+            section,
+        }
+    }
+
+    /// Set the color space the lens interpolates in.
+    #[must_use]
+    pub fn with_space(mut self, space: ColorSpace) -> Self {
+        self.space = space;
+        self
+    }
+}
+
+impl Lens<Text> for TextColorLens {
+    fn lerp(&mut self, target: &mut Text, ratio: f32) {
+        let value = self.space.lerp(&self.start, &self.end, ratio);
+
+        if let Some(section) = target.sections.get_mut(self.section) {
+            section.style.color = value;
+        }
+    }
+
+    lens_is_valid!(start, end);
+    color_lens_blend!(Text);
+}
+
+impl_ranged_lens!(TextColorLens, Color);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_color() {
+        let mut lens = TextColorLens::new(Color::RED, Color::BLUE, 0);
+        let mut text = Text::from_section("", default());
+
+        lens.lerp(&mut text, 0.);
+        assert_eq!(text.sections[0].style.color, Color::RED);
+
+        lens.lerp(&mut text, 1.);
+        assert_eq!(text.sections[0].style.color, Color::BLUE);
+
+        lens.lerp(&mut text, 0.3);
+        assert_eq!(text.sections[0].style.color, Color::rgba(0.7, 0., 0.3, 1.0));
+
+        let mut lens_section1 = TextColorLens::new(Color::RED, Color::BLUE, 1);
+
+        lens_section1.lerp(&mut text, 1.);
+        // Should not have changed because the lens targets section 1
+        assert_eq!(text.sections[0].style.color, Color::rgba(0.7, 0., 0.3, 1.0));
+
+        text.sections.push(TextSection {
+            value: "".to_string(),
+            style: Default::default(),
+        });
+
+        lens_section1.lerp(&mut text, 0.3);
+        assert_eq!(text.sections[1].style.color, Color::rgba(0.7, 0., 0.3, 1.0));
+    }
+}
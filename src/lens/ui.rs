@@ -0,0 +1,236 @@
+//! Lenses for the `bevy_ui` components.
+
+use bevy::prelude::*;
+
+use super::{ColorSpace, Lens, Oklaba};
+use crate::UiOpacity;
+
+/// A lens to manipulate the [`position`] field of a UI [`Style`] component.
+///
+/// [`position`]: https://docs.rs/bevy/0.12.0/bevy/ui/struct.Style.html#structfield.position
+/// [`Style`]: https://docs.rs/bevy/0.12.0/bevy/ui/struct.Style.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct UiPositionLens {
+    /// Start position.
+    pub start: UiRect,
+    /// End position.
+    pub end: UiRect,
+}
+
+fn lerp_val(start: &Val, end: &Val, ratio: f32) -> Val {
+    match (start, end) {
+        (Val::Percent(start), Val::Percent(end)) => {
+            Val::Percent((end - start).mul_add(ratio, *start))
+        }
+        (Val::Px(start), Val::Px(end)) => Val::Px((end - start).mul_add(ratio, *start)),
+        _ => *start,
+    }
+}
+
+impl Lens<Style> for UiPositionLens {
+    fn lerp(&mut self, target: &mut Style, ratio: f32) {
+        target.left = lerp_val(&self.start.left, &self.end.left, ratio);
+        target.right = lerp_val(&self.start.right, &self.end.right, ratio);
+        target.top = lerp_val(&self.start.top, &self.end.top, ratio);
+        target.bottom = lerp_val(&self.start.bottom, &self.end.bottom, ratio);
+    }
+
+    lens_is_valid!(start, end);
+}
+
+impl_ranged_lens!(UiPositionLens, UiRect);
+
+/// A lens to manipulate the [`BackgroundColor`] component of a UI node.
+///
+/// [`BackgroundColor`]: https://docs.rs/bevy/0.13.0/bevy/ui/struct.BackgroundColor.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct UiBackgroundColorLens {
+    /// Start color.
+    pub start: Color,
+    /// End color.
+    pub end: Color,
+    /// Color space the colors are interpolated in.
+    pub space: ColorSpace,
+}
+
+impl UiBackgroundColorLens {
+    color_lens_impl!();
+}
+
+impl Lens<BackgroundColor> for UiBackgroundColorLens {
+    fn lerp(&mut self, target: &mut BackgroundColor, ratio: f32) {
+        let value = self.space.lerp(&self.start, &self.end, ratio);
+        target.0 = value;
+    }
+
+    lens_is_valid!(start, end);
+    color_lens_blend!(BackgroundColor);
+}
+
+impl_ranged_lens!(UiBackgroundColorLens, Color);
+
+/// A lens to manipulate the [`UiOpacity`] of a UI subtree.
+///
+/// The ratio is clamped to \[0:1\], and the `end` value is written exactly
+/// once the ratio reaches `1.`, so a fade in to an opacity of `1.` restores
+/// the original colors of the subtree.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct UiOpacityLens {
+    /// Start opacity.
+    pub start: f32,
+    /// End opacity.
+    pub end: f32,
+}
+
+impl Lens<UiOpacity> for UiOpacityLens {
+    fn lerp(&mut self, target: &mut UiOpacity, ratio: f32) {
+        let ratio = ratio.clamp(0., 1.);
+        target.0 = if ratio == 1. {
+            self.end
+        } else {
+            (self.end - self.start).mul_add(ratio, self.start)
+        };
+    }
+
+    lens_is_valid!(start, end);
+    lens_scale_range!();
+    lens_blend!(UiOpacity);
+}
+
+impl_ranged_lens!(UiOpacityLens, f32);
+
+/// A lens to switch the [`ZIndex`] of a UI node at a given progress.
+///
+/// The z-index is a discrete value, so it cannot be interpolated. Instead, this
+/// lens writes the `start` value while the lens ratio is strictly below
+/// `threshold`, and the `end` value otherwise. A threshold of `0.` switches to
+/// `end` immediately, while a threshold of `1.` keeps `start` until the
+/// animation completes.
+///
+/// This is typically used in a card game UI to raise a card above its siblings
+/// while it's being animated. See [`CardRaise`] for a ready-made helper.
+///
+/// [`ZIndex`]: https://docs.rs/bevy/0.13.0/bevy/ui/enum.ZIndex.html
+/// [`CardRaise`]: crate::recipes::CardRaise
+#[derive(Debug, Copy, Clone)]
+pub struct UiZIndexLens {
+    /// Z-index written while the ratio is below the threshold.
+    pub start: ZIndex,
+    /// Z-index written once the ratio reached the threshold.
+    pub end: ZIndex,
+    /// Ratio at which the z-index switches from `start` to `end`.
+    pub threshold: f32,
+}
+
+impl Lens<ZIndex> for UiZIndexLens {
+    fn lerp(&mut self, target: &mut ZIndex, ratio: f32) {
+        *target = if ratio < self.threshold {
+            self.start
+        } else {
+            self.end
+        };
+    }
+}
+
+impl_ranged_lens!(UiZIndexLens, ZIndex);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lens::RangedLens;
+
+    #[test]
+    fn ui_position() {
+        let mut lens = UiPositionLens {
+            start: UiRect {
+                left: Val::Px(0.),
+                top: Val::Px(0.),
+                right: Val::Auto,
+                bottom: Val::Percent(25.),
+            },
+            end: UiRect {
+                left: Val::Px(1.),
+                top: Val::Px(5.),
+                right: Val::Auto,
+                bottom: Val::Percent(45.),
+            },
+        };
+        let mut style = Style::default();
+
+        lens.lerp(&mut style, 0.);
+        assert_eq!(style.left, Val::Px(0.));
+        assert_eq!(style.top, Val::Px(0.));
+        assert_eq!(style.right, Val::Auto);
+        assert_eq!(style.bottom, Val::Percent(25.));
+
+        lens.lerp(&mut style, 1.);
+        assert_eq!(style.left, Val::Px(1.));
+        assert_eq!(style.top, Val::Px(5.));
+        assert_eq!(style.right, Val::Auto);
+        assert_eq!(style.bottom, Val::Percent(45.));
+
+        lens.lerp(&mut style, 0.3);
+        assert_eq!(style.left, Val::Px(0.3));
+        assert_eq!(style.top, Val::Px(1.5));
+        assert_eq!(style.right, Val::Auto);
+        assert_eq!(style.bottom, Val::Percent(31.));
+    }
+
+    #[test]
+    fn ui_z_index() {
+        let mut lens = UiZIndexLens {
+            start: ZIndex::Local(0),
+            end: ZIndex::Global(10),
+            threshold: 0.5,
+        };
+        let mut z_index = ZIndex::default();
+
+        lens.lerp(&mut z_index, 0.);
+        assert!(matches!(z_index, ZIndex::Local(0)));
+
+        lens.lerp(&mut z_index, 0.499);
+        assert!(matches!(z_index, ZIndex::Local(0)));
+
+        lens.lerp(&mut z_index, 0.5);
+        assert!(matches!(z_index, ZIndex::Global(10)));
+
+        lens.lerp(&mut z_index, 1.);
+        assert!(matches!(z_index, ZIndex::Global(10)));
+
+        lens.lerp(&mut z_index, 0.2);
+        assert!(matches!(z_index, ZIndex::Local(0)));
+    }
+
+    #[test]
+    fn ui_ranged() {
+        let mut lens = UiPositionLens {
+            start: UiRect::all(Val::Px(0.)),
+            end: UiRect::all(Val::Px(10.)),
+        };
+        assert_eq!(lens.start(), UiRect::all(Val::Px(0.)));
+        assert_eq!(lens.end(), UiRect::all(Val::Px(10.)));
+        lens.set_end(UiRect::all(Val::Px(20.)));
+        let mut style = Style::default();
+        lens.lerp(&mut style, 0.5);
+        assert_eq!(style.left, Val::Px(10.));
+
+        let mut lens = UiBackgroundColorLens::new(Color::RED, Color::BLUE);
+        assert_eq!(lens.start(), Color::RED);
+        lens.set_start(Color::GREEN);
+        assert_eq!(lens.start(), Color::GREEN);
+        let mut color = BackgroundColor::default();
+        lens.lerp(&mut color, 0.);
+        assert_eq!(color.0, Color::GREEN);
+
+        let mut lens = UiZIndexLens {
+            start: ZIndex::Local(0),
+            end: ZIndex::Local(5),
+            threshold: 0.5,
+        };
+        lens.set_end(ZIndex::Global(3));
+        assert!(matches!(lens.end(), ZIndex::Global(3)));
+        let mut z_index = ZIndex::default();
+        lens.lerp(&mut z_index, 1.);
+        assert!(matches!(z_index, ZIndex::Global(3)));
+    }
+}
@@ -0,0 +1,128 @@
+//! Lenses for the `bevy_window` components.
+
+use bevy::{
+    prelude::*,
+    window::{Window, WindowPosition},
+};
+
+use super::Lens;
+
+/// A lens to manipulate the logical [`resolution`] of a [`Window`].
+///
+/// This resizes the window, for example to smoothly shrink it into a
+/// picture-in-picture mode. The size is clamped to at least one logical pixel
+/// in each direction, so an easing overshooting below zero doesn't produce an
+/// invalid size. The window is only written when its size actually changes.
+///
+/// Platforms where the application doesn't control the window size, like
+/// the web or mobile, ignore the resize.
+///
+/// [`resolution`]: https://docs.rs/bevy/0.13.0/bevy/window/struct.Window.html#structfield.resolution
+/// [`Window`]: https://docs.rs/bevy/0.13.0/bevy/window/struct.Window.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WindowResolutionLens {
+    /// Start size, in logical pixels.
+    pub start: Vec2,
+    /// End size, in logical pixels.
+    pub end: Vec2,
+}
+
+impl Lens<Window> for WindowResolutionLens {
+    fn lerp(&mut self, target: &mut Window, ratio: f32) {
+        let size = self.start.lerp(self.end, ratio).max(Vec2::ONE);
+        if target.resolution.width() != size.x || target.resolution.height() != size.y {
+            target.resolution.set(size.x, size.y);
+        }
+    }
+
+    lens_is_valid!(start, end);
+    lens_scale_range!();
+    lens_blend!(Window);
+}
+
+impl_ranged_lens!(WindowResolutionLens, Vec2);
+
+/// A lens to manipulate the [`position`] of a [`Window`] on screen.
+///
+/// Window positions are integers, in physical pixels. The interpolated
+/// position is rounded to the nearest pixel, and the window is only written
+/// when the rounded position changes, so a slow move doesn't request a new
+/// position from the platform every frame while the window stays on the same
+/// pixel. Once animated, the position is always [`WindowPosition::At`], even
+/// if the window was centered before.
+///
+/// Platforms where the application doesn't control the window position, like
+/// the web, mobile, or Wayland, ignore the move.
+///
+/// [`position`]: https://docs.rs/bevy/0.13.0/bevy/window/struct.Window.html#structfield.position
+/// [`Window`]: https://docs.rs/bevy/0.13.0/bevy/window/struct.Window.html
+/// [`WindowPosition::At`]: https://docs.rs/bevy/0.13.0/bevy/window/enum.WindowPosition.html#variant.At
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct WindowPositionLens {
+    /// Start position, in physical pixels.
+    pub start: IVec2,
+    /// End position, in physical pixels.
+    pub end: IVec2,
+}
+
+impl Lens<Window> for WindowPositionLens {
+    fn lerp(&mut self, target: &mut Window, ratio: f32) {
+        let start = self.start.as_vec2();
+        let position = start.lerp(self.end.as_vec2(), ratio).round().as_ivec2();
+        if target.position != WindowPosition::At(position) {
+            target.position = WindowPosition::At(position);
+        }
+    }
+}
+
+impl_ranged_lens!(WindowPositionLens, IVec2);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_resolution() {
+        let mut lens = WindowResolutionLens {
+            start: Vec2::new(800., 600.),
+            end: Vec2::new(400., 200.),
+        };
+        let mut window = Window::default();
+
+        lens.lerp(&mut window, 0.);
+        assert_eq!(window.resolution.width(), 800.);
+        assert_eq!(window.resolution.height(), 600.);
+
+        lens.lerp(&mut window, 0.25);
+        assert_eq!(window.resolution.width(), 700.);
+        assert_eq!(window.resolution.height(), 500.);
+
+        lens.lerp(&mut window, 1.);
+        assert_eq!(window.resolution.width(), 400.);
+        assert_eq!(window.resolution.height(), 200.);
+
+        // Overshooting below zero keeps a valid size
+        lens.lerp(&mut window, 3.);
+        assert_eq!(window.resolution.width(), 1.);
+        assert_eq!(window.resolution.height(), 1.);
+    }
+
+    #[test]
+    fn window_position() {
+        let mut lens = WindowPositionLens {
+            start: IVec2::new(0, 100),
+            end: IVec2::new(10, -100),
+        };
+        let mut window = Window::default();
+        assert_eq!(window.position, WindowPosition::Automatic);
+
+        lens.lerp(&mut window, 0.);
+        assert_eq!(window.position, WindowPosition::At(IVec2::new(0, 100)));
+
+        lens.lerp(&mut window, 0.26);
+        assert_eq!(window.position, WindowPosition::At(IVec2::new(3, 48)));
+
+        lens.lerp(&mut window, 1.);
+        assert_eq!(window.position, WindowPosition::At(IVec2::new(10, -100)));
+    }
+}
@@ -28,7 +28,7 @@
 //!
 //! App::default()
 //!     .add_plugins(DefaultPlugins)
-//!     .add_plugins(TweeningPlugin)
+//!     .add_plugins(TweeningPlugin::default())
 //!     .run();
 //! ```
 //!
@@ -37,15 +37,14 @@
 //! ```
 //! # use bevy::prelude::*;
 //! # use bevy_tweening::{lens::*, *};
-//! # use std::time::Duration;
 //! # fn system(mut commands: Commands) {
 //! # let size = 16.;
 //! // Create a single animation (tween) to move an entity.
 //! let tween = Tween::new(
 //!     // Use a quadratic easing on both endpoints.
 //!     EaseFunction::QuadraticInOut,
-//!     // Animation time.
-//!     Duration::from_secs(1),
+//!     // Animation time, in seconds.
+//!     1.,
 //!     // The lens gives access to the Transform component of the Entity,
 //!     // for the Animator to animate it. It also contains the start and
 //!     // end values respectively associated with the progress ratios 0. and 1.
@@ -84,6 +83,15 @@
 //!   component to any Entity. Animating assets also requires the `bevy_asset`
 //!   feature (enabled by default).
 //!
+//! - To animate a component `C` and an asset `A` from a single timeline, the
+//!   [`synced_animator_system::<C, A>`] system must run each frame, in addition
+//!   of adding a [`SyncedAnimator<C, A>`] component to the Entity holding `C`
+//!   and the [`Handle<A>`] of the asset.
+//!
+//! - To ensure a resource `R` is animated, the
+//!   [`resource_animator_system::<R>`] system must run each frame, in addition
+//!   of adding a [`ResourceAnimator<R>`] component to any Entity.
+//!
 //! By default, 🍃 Bevy Tweening adopts a minimalist approach, and the
 //! [`TweeningPlugin`] will only add systems to animate components and assets
 //! for which a [`Lens`] is provided by 🍃 Bevy Tweening itself. This means that
@@ -96,10 +104,28 @@
 //! | [`Sprite`]             | Only if `bevy_sprite` feature |
 //! | [`ColorMaterial`]      | Only if `bevy_sprite` feature |
 //! | [`Style`]              | Only if `bevy_ui` feature     |
+//! | [`ZIndex`]             | Only if `bevy_ui` feature     |
+//! | [`UiOpacity`]          | Only if `bevy_ui` feature     |
 //! | [`Text`]               | Only if `bevy_text` feature   |
+//! | [`Window`]             | Only if `bevy_window` feature |
+//! | [`StandardMaterial`]   | Only if `bevy_pbr` feature    |
+//! | [`AmbientLight`]       | Only if `bevy_pbr` feature    |
+//! | [`FogSettings`]        | Only if `bevy_pbr` feature    |
+//! | [`Exposure`]           | Only if `bevy_pbr` feature    |
+//! | [`DirectionalLight`]   | Only if `bevy_pbr` feature    |
 //! | All other components   | No                            |
 //!
-//! To add a system for a component `C`, use:
+//! To add a system for a component `C`, register it with the plugin:
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! # use bevy_tweening::*;
+//! # let mut app = App::default();
+//! # #[derive(Component)] struct C;
+//! app.add_plugins(TweeningPlugin::default().register::<C>());
+//! ```
+//!
+//! or add the system manually:
 //!
 //! ```
 //! # use bevy::prelude::*;
@@ -112,7 +138,8 @@
 //! ```
 //!
 //! Similarly for an asset `A`, use the `asset_animator_system`. This is only
-//! available with the `bevy_asset` feature.
+//! available with the `bevy_asset` feature. For a resource `R`, use the
+//! `resource_animator_system`.
 //!
 //! # Tweenables
 //!
@@ -124,7 +151,12 @@
 //! - [`Sequence`] - A series of tweenables executing in series, one after the
 //!   other.
 //! - [`Tracks`] - A collection of tweenables executing in parallel.
-//! - [`Delay`] - A time delay. This doesn't animate anything.
+//! - [`Delay`] - A time delay, which can be extended or cut while pending
+//!   with a [`DelayHandle`]. This doesn't animate anything.
+//! - [`Eased`] - An additional easing applied on top of another tweenable.
+//! - [`Slice`] - A sub-interval of another tweenable.
+//! - [`WaitFor`] - A wait for an external signal, released with a
+//!   [`WaitHandle`]. This doesn't animate anything.
 //!
 //! ## Chaining animations
 //!
@@ -134,11 +166,10 @@
 //! ```
 //! # use bevy::prelude::*;
 //! # use bevy_tweening::{lens::*, *};
-//! # use std::time::Duration;
 //! let tween1 = Tween::new(
 //!     // [...]
 //! #    EaseFunction::BounceOut,
-//! #    Duration::from_secs(2),
+//! #    2.,
 //! #    TransformScaleLens {
 //! #        start: Vec3::ZERO,
 //! #        end: Vec3::ONE,
@@ -147,7 +178,7 @@
 //! let tween2 = Tween::new(
 //!     // [...]
 //! #    EaseFunction::QuadraticInOut,
-//! #    Duration::from_secs(1),
+//! #    1.,
 //! #    TransformPositionLens {
 //! #        start: Vec3::ZERO,
 //! #        end: Vec3::new(3.5, 0., 0.),
@@ -157,6 +188,31 @@
 //! let seq = tween1.then(tween2);
 //! ```
 //!
+//! ## Building tweens from data
+//!
+//! When the options of a tween come from data files or a scripting layer,
+//! prefer building it with [`Tween::builder()`], which validates the
+//! combination of options and returns a [`TweenBuildError`] instead of
+//! silently producing an unexpected animation:
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! # use bevy_tweening::{lens::*, *};
+//! # use std::time::Duration;
+//! let tween: Result<Tween<Transform>, TweenBuildError> = Tween::builder()
+//!     .with_ease(EaseFunction::QuadraticInOut)
+//!     .with_duration(Duration::from_secs(1))
+//!     .with_lens(TransformScaleLens {
+//!         start: Vec3::ZERO,
+//!         end: Vec3::ONE,
+//!     })
+//!     .build();
+//! ```
+//!
+//! The lens itself can also be constructed from a name and a string of
+//! parameters with the [`LensRegistry`] resource inserted by the plugin, and
+//! the easing method from its name with [`EaseMethod::from_name()`].
+//!
 //! # Animators and lenses
 //!
 //! Bevy components and assets are animated with tweening _animator_ components,
@@ -195,6 +251,7 @@
 //! lens can also be created by implementing the trait, allowing to animate
 //! virtually any field of any Bevy component or asset.
 //!
+//! [`synced_animator_system::<C, A>`]: synced_animator_system
 //! [`Transform::translation`]: https://docs.rs/bevy/0.12.0/bevy/transform/components/struct.Transform.html#structfield.translation
 //! [`Entity`]: https://docs.rs/bevy/0.12.0/bevy/ecs/entity/struct.Entity.html
 //! [`Query`]: https://docs.rs/bevy/0.12.0/bevy/ecs/system/struct.Query.html
@@ -202,32 +259,151 @@
 //! [`Sprite`]: https://docs.rs/bevy/0.12.0/bevy/sprite/struct.Sprite.html
 //! [`Style`]: https://docs.rs/bevy/0.12.0/bevy/ui/struct.Style.html
 //! [`Text`]: https://docs.rs/bevy/0.12.0/bevy/text/struct.Text.html
+//! [`StandardMaterial`]: https://docs.rs/bevy/0.13.0/bevy/pbr/struct.StandardMaterial.html
+//! [`AmbientLight`]: https://docs.rs/bevy/0.13.0/bevy/pbr/struct.AmbientLight.html
+//! [`FogSettings`]: https://docs.rs/bevy/0.13.0/bevy/pbr/struct.FogSettings.html
+//! [`Exposure`]: https://docs.rs/bevy/0.13.0/bevy/render/camera/struct.Exposure.html
+//! [`DirectionalLight`]: https://docs.rs/bevy/0.13.0/bevy/pbr/struct.DirectionalLight.html
+//! [`ZIndex`]: https://docs.rs/bevy/0.13.0/bevy/ui/enum.ZIndex.html
+//! [`Window`]: https://docs.rs/bevy/0.13.0/bevy/window/struct.Window.html
 //! [`Transform`]: https://docs.rs/bevy/0.12.0/bevy/transform/components/struct.Transform.html
 
 use std::time::Duration;
 
+use actions::CompletedAction;
 use bevy::prelude::*;
 use interpolation::Ease as IEase;
 pub use interpolation::{EaseFunction, Lerp};
 
-pub use lens::Lens;
+#[cfg(feature = "bevy_animation")]
+pub use animation_clip::{from_animation_clip, AnimationClipError};
+pub use builder::{TweenBuildError, TweenBuilder};
+pub use chain::{
+    animation_chain_system, ActiveAnimationChain, AnimationChain, CancelAnimationChain,
+    CHAIN_USER_DATA_BIT,
+};
+pub use commands::{AnimatorCommandsExt, AnimatorOverwritePolicy};
+pub use conditions::{
+    all_animators_completed, all_animators_completed_with, any_animator_active,
+    any_animator_active_with,
+};
+pub use ease::ParametricEase;
+pub use group::{
+    AnimationGroupId, AnimationGroups, CancelAnimationGroup, CompleteAnimationGroup,
+    HierarchyAnimation,
+};
+pub use lens::{Lens, LensContext};
+#[cfg(feature = "bevy_ui")]
+pub use opacity::UiOpacity;
 #[cfg(feature = "bevy_asset")]
-pub use plugin::asset_animator_system;
-pub use plugin::{component_animator_system, AnimationSystem, TweeningPlugin};
+pub use plugin::{asset_animator_system, synced_animator_system};
+pub use plugin::{
+    component_animator_system, resource_animator_system, AnimationBudget, AnimationOrder,
+    AnimationSystem, CleanupPolicy, IdleAnimatorCleanup, TweeningAppExt, TweeningPlugin,
+    WriteCoalescing,
+};
+pub use prototype::{TweenOverrides, TweenPrototype};
+#[cfg(feature = "recording")]
+pub use recording::{RecordedSample, TweenRecorder, TweenRecording};
+pub use registry::{DynLens, LensParam, LensParams, LensRegistry, LensRegistryError};
+#[cfg(feature = "bevy_scene")]
+pub use scene::{animator_def_system, AnimatorDef, TweenDef, TweenDefError};
+pub use snapshot::TweenableState;
+pub use state_binding::OnStateExit;
+use state_binding::StateBinding;
+pub use state_machine::{TweenStateMachine, TweenStateTransition};
+pub use stats::{StatsEntry, TweenStats};
+pub use tuning::{TuningKey, TweenTuning, TweenTuningEntry};
 pub use tweenable::{
-    BoxedTweenable, Delay, Sequence, Targetable, TotalDuration, Tracks, Tween, TweenCompleted,
-    TweenState, Tweenable,
+    BoxedTweenable, CancelToken, Delay, DelayHandle, Eased, EmptyTweenableError, Sequence, Slice,
+    Targetable, TotalDuration, Tracks, Tween, TweenCancelled, TweenCompleted, TweenId, TweenState,
+    TweenValidationError, Tweenable, WaitFor, WaitHandle,
 };
-
+use tweenable::{CompletedTweenable, UpdateCallback};
+
+mod actions;
+#[cfg(feature = "bevy_animation")]
+mod animation_clip;
+mod builder;
+mod chain;
+mod commands;
+mod conditions;
+#[cfg(feature = "debug_gizmos")]
+pub mod debug;
+#[cfg(feature = "diagnostics")]
+pub mod diagnostics;
+mod ease;
+#[cfg(feature = "ease_preview")]
+pub mod ease_preview;
+mod group;
 pub mod lens;
+#[cfg(feature = "bevy_ui")]
+mod opacity;
 mod plugin;
+mod prototype;
+pub mod recipes;
+#[cfg(feature = "recording")]
+mod recording;
+mod registry;
+#[cfg(feature = "bevy_scene")]
+mod scene;
+mod snapshot;
+mod state_binding;
+mod state_machine;
+mod stats;
+#[cfg(feature = "bevy_ui")]
+pub mod transitions;
+mod tuning;
 mod tweenable;
 
 #[cfg(test)]
 mod test_utils;
 
+/// Conversion into the duration of a tweenable.
+///
+/// This is accepted by [`Tween::new()`] and [`Delay::new()`] to reduce the
+/// boilerplate of building a [`Duration`] at each call site. It's implemented
+/// for:
+/// - [`Duration`], used as is;
+/// - `f32`, a number of seconds.
+///
+/// Integers are deliberately not accepted, as a bare `2` could be read as
+/// either seconds or milliseconds; use [`Tween::new_millis()`] or
+/// [`Delay::millis()`] for a number of milliseconds.
+///
+/// A negative or non-finite number of seconds is invalid, and converted into
+/// a zero duration with a debug message.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_tweening::*;
+/// # use std::time::Duration;
+/// assert_eq!(0.5.into_duration(), Duration::from_millis(500));
+/// assert_eq!((-1.).into_duration(), Duration::ZERO);
+/// ```
+pub trait IntoDuration {
+    /// Convert into a [`Duration`].
+    fn into_duration(self) -> Duration;
+}
+
+impl IntoDuration for Duration {
+    fn into_duration(self) -> Duration {
+        self
+    }
+}
+
+impl IntoDuration for f32 {
+    fn into_duration(self) -> Duration {
+        Duration::try_from_secs_f32(self).unwrap_or_else(|_| {
+            debug!("Invalid duration of {self} seconds, using zero instead.");
+            Duration::ZERO
+        })
+    }
+}
+
 /// How many times to repeat a tween animation. See also: [`RepeatStrategy`].
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum RepeatCount {
     /// Run the animation N times.
     Finite(u32),
@@ -259,9 +435,10 @@ impl From<Duration> for RepeatCount {
 /// [`RepeatCount`].
 ///
 /// Only applicable when [`RepeatCount`] is greater than the animation duration.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
 pub enum RepeatStrategy {
     /// Reset the animation back to its starting position.
+    #[default]
     Repeat,
     /// Follow a ping-pong pattern, changing the direction each time an endpoint
     /// is reached.
@@ -270,30 +447,143 @@ pub enum RepeatStrategy {
     /// iterations for the various operations where looping matters. That
     /// is, a 1 second animation will take 2 seconds to end up back where it
     /// started.
+    ///
+    /// On the tick an endpoint is reached, the lens is applied with that exact
+    /// endpoint value, even if the tick overshoots it. This guarantees the
+    /// apex of each leg is always visible for one frame.
     MirroredRepeat,
 }
 
-impl Default for RepeatStrategy {
+/// Repeat count and strategy of a tween animation, set together with
+/// [`Tween::with_repeat()`].
+///
+/// This converts from a [`RepeatCount`] alone, which uses the default
+/// [`RepeatStrategy::Repeat`], from a `(RepeatCount, RepeatStrategy)` pair,
+/// and from the deprecated [`TweeningType`], to help migrating code written
+/// for older versions of this crate.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RepeatSpec {
+    /// How many times to repeat the animation.
+    pub count: RepeatCount,
+    /// What to do when the animation needs to be repeated.
+    pub strategy: RepeatStrategy,
+}
+
+impl From<RepeatCount> for RepeatSpec {
+    fn from(count: RepeatCount) -> Self {
+        Self {
+            count,
+            strategy: RepeatStrategy::default(),
+        }
+    }
+}
+
+impl From<(RepeatCount, RepeatStrategy)> for RepeatSpec {
+    fn from((count, strategy): (RepeatCount, RepeatStrategy)) -> Self {
+        Self { count, strategy }
+    }
+}
+
+#[allow(deprecated)]
+impl From<TweeningType> for RepeatSpec {
+    fn from(tweening_type: TweeningType) -> Self {
+        let (count, strategy) = tweening_type.into();
+        Self { count, strategy }
+    }
+}
+
+/// Type of looping of a tween animation, as used before version 0.6.
+///
+/// This was split into [`RepeatCount`] and [`RepeatStrategy`], which allow
+/// repeating an animation a finite number of times. This enum is only kept to
+/// ease migrating older code, and converts into the equivalent pair, or into a
+/// [`RepeatSpec`] for [`Tween::with_repeat()`]:
+///
+/// | `TweeningType` | [`RepeatCount`]   | [`RepeatStrategy`] |
+/// |----------------|-------------------|--------------------|
+/// | `Once`         | `Finite(1)`       | `Repeat`           |
+/// | `Loop`         | `Infinite`        | `Repeat`           |
+/// | `PingPong`     | `Infinite`        | `MirroredRepeat`   |
+#[deprecated(
+    since = "0.11.0",
+    note = "Use `Tween::with_repeat_count()` and `Tween::with_repeat_strategy()` instead"
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TweeningType {
+    /// Run the animation once from start to end only.
+    Once,
+    /// Loop the animation indefinitely, restarting from the start each time
+    /// the end is reached.
+    Loop,
+    /// Loop the animation back and forth, changing direction each time an
+    /// endpoint is reached.
+    PingPong,
+}
+
+// Deriving would use the deprecated variant outside of an `allow(deprecated)`
+#[allow(deprecated, clippy::derivable_impls)]
+impl Default for TweeningType {
     fn default() -> Self {
-        Self::Repeat
+        Self::Once
     }
 }
 
+#[allow(deprecated)]
+impl From<TweeningType> for (RepeatCount, RepeatStrategy) {
+    fn from(tweening_type: TweeningType) -> Self {
+        match tweening_type {
+            TweeningType::Once => (RepeatCount::Finite(1), RepeatStrategy::Repeat),
+            TweeningType::Loop => (RepeatCount::Infinite, RepeatStrategy::Repeat),
+            TweeningType::PingPong => (RepeatCount::Infinite, RepeatStrategy::MirroredRepeat),
+        }
+    }
+}
+
+/// What a tweenable leaves its target at once it completes.
+///
+/// Looping tweenables never complete, so this only applies to tweenables with
+/// a finite [`RepeatCount`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum CompletedBehavior {
+    /// Hold the target at the end value of the tweenable.
+    #[default]
+    HoldEnd,
+    /// Restore the target to the start value of the tweenable, on the same
+    /// tick it completes.
+    ///
+    /// The end value is still applied first, and the completion callbacks and
+    /// events are raised with the target at that value, so that observers see
+    /// the tweenable complete; the start value is applied right after them.
+    /// For example, a "press flash" on a button can't leave the button tinted
+    /// if the animation meant to follow it fails to run.
+    SnapToStart,
+}
+
+/// What a tweenable leaves its target at once it observes its [`CancelToken`]
+/// cancelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum CancelBehavior {
+    /// Leave the target where the last tick applied it.
+    #[default]
+    Freeze,
+    /// Restore the target to the start value of the tweenable.
+    SnapToStart,
+    /// Jump the target to the end value of the tweenable, without raising
+    /// its completion events. For a tweenable repeating forever, this is the
+    /// end of its first iteration.
+    SnapToEnd,
+}
+
 /// Playback state of an animator.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum AnimatorState {
     /// The animation is playing. This is the default state.
+    #[default]
     Playing,
     /// The animation is paused in its current state.
     Paused,
 }
 
-impl Default for AnimatorState {
-    fn default() -> Self {
-        Self::Playing
-    }
-}
-
 impl std::ops::Not for AnimatorState {
     type Output = Self;
 
@@ -305,21 +595,403 @@ impl std::ops::Not for AnimatorState {
     }
 }
 
+/// Reporting of the completions skipped by [`Animator::start_at()`] and
+/// [`Animator::skip_to_end()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SkippedCompletions {
+    /// Don't raise any completion event, nor invoke any completion callback,
+    /// for the completions which happened before the start time. This is the
+    /// default.
+    #[default]
+    Suppress,
+    /// Raise the completion events and invoke the completion callbacks of the
+    /// skipped completions on the first tick, as if that tick lasted for the
+    /// whole skipped time. Like for any single tick, a [`Tween`] looping
+    /// several times raises a single event.
+    Notify,
+}
+
+/// Event raised when the playback state of an animator changes.
+///
+/// This event is raised by the animator systems for any change of the
+/// [`state`] of an [`Animator`], [`AssetAnimator`], or [`ResourceAnimator`],
+/// whatever the code which changed it, for example by assigning the field
+/// directly or by calling [`Animator::stop()`]. The initial state of an
+/// animator, including one set with [`Animator::with_state()`], doesn't raise
+/// any event.
+///
+/// The change is detected the next time the animator system of the animator
+/// runs, so a change made before [`AnimationSystem::AnimationUpdate`] is
+/// reported in the same frame, and can be read by any system running after it.
+/// Only the net change since the last report is raised, so ticking a paused
+/// animator doesn't raise any event, and pausing then resuming an animator
+/// between two runs of the animator system doesn't raise any event either.
+///
+/// The completion of a tweenable doesn't change the state of its animator; use
+/// [`TweenCompleted`] to be notified of it.
+///
+/// [`state`]: Animator::state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct AnimatorStateChanged {
+    /// The entity the animator is attached to.
+    pub entity: Entity,
+    /// The previous state of the animator.
+    pub old: AnimatorState,
+    /// The new state of the animator.
+    pub new: AnimatorState,
+}
+
 /// Describe how eased value should be computed.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Default)]
 pub enum EaseMethod {
     /// Follow `EaseFunction`.
     EaseFunction(EaseFunction),
     /// Linear interpolation, with no function.
+    #[default]
     Linear,
     /// Discrete interpolation, eased value will jump from start to end when
     /// stepping over the discrete limit.
     Discrete(f32),
     /// Use a custom function to interpolate the value.
     CustomFunction(fn(f32) -> f32),
+    /// Use an easing function with parameters, created by one of the
+    /// parameterized constructors like [`EaseMethod::elastic_out()`].
+    Parametric(ParametricEase),
 }
 
+/// Named easing presets, in the order returned by [`EaseMethod::presets()`].
+const EASE_PRESETS: [(&str, EaseMethod); 31] = [
+    ("linear", EaseMethod::Linear),
+    (
+        "quadraticIn",
+        EaseMethod::EaseFunction(EaseFunction::QuadraticIn),
+    ),
+    (
+        "quadraticOut",
+        EaseMethod::EaseFunction(EaseFunction::QuadraticOut),
+    ),
+    (
+        "quadraticInOut",
+        EaseMethod::EaseFunction(EaseFunction::QuadraticInOut),
+    ),
+    ("cubicIn", EaseMethod::EaseFunction(EaseFunction::CubicIn)),
+    ("cubicOut", EaseMethod::EaseFunction(EaseFunction::CubicOut)),
+    (
+        "cubicInOut",
+        EaseMethod::EaseFunction(EaseFunction::CubicInOut),
+    ),
+    (
+        "quarticIn",
+        EaseMethod::EaseFunction(EaseFunction::QuarticIn),
+    ),
+    (
+        "quarticOut",
+        EaseMethod::EaseFunction(EaseFunction::QuarticOut),
+    ),
+    (
+        "quarticInOut",
+        EaseMethod::EaseFunction(EaseFunction::QuarticInOut),
+    ),
+    (
+        "quinticIn",
+        EaseMethod::EaseFunction(EaseFunction::QuinticIn),
+    ),
+    (
+        "quinticOut",
+        EaseMethod::EaseFunction(EaseFunction::QuinticOut),
+    ),
+    (
+        "quinticInOut",
+        EaseMethod::EaseFunction(EaseFunction::QuinticInOut),
+    ),
+    ("sineIn", EaseMethod::EaseFunction(EaseFunction::SineIn)),
+    ("sineOut", EaseMethod::EaseFunction(EaseFunction::SineOut)),
+    (
+        "sineInOut",
+        EaseMethod::EaseFunction(EaseFunction::SineInOut),
+    ),
+    (
+        "circularIn",
+        EaseMethod::EaseFunction(EaseFunction::CircularIn),
+    ),
+    (
+        "circularOut",
+        EaseMethod::EaseFunction(EaseFunction::CircularOut),
+    ),
+    (
+        "circularInOut",
+        EaseMethod::EaseFunction(EaseFunction::CircularInOut),
+    ),
+    (
+        "exponentialIn",
+        EaseMethod::EaseFunction(EaseFunction::ExponentialIn),
+    ),
+    (
+        "exponentialOut",
+        EaseMethod::EaseFunction(EaseFunction::ExponentialOut),
+    ),
+    (
+        "exponentialInOut",
+        EaseMethod::EaseFunction(EaseFunction::ExponentialInOut),
+    ),
+    (
+        "elasticIn",
+        EaseMethod::EaseFunction(EaseFunction::ElasticIn),
+    ),
+    (
+        "elasticOut",
+        EaseMethod::EaseFunction(EaseFunction::ElasticOut),
+    ),
+    (
+        "elasticInOut",
+        EaseMethod::EaseFunction(EaseFunction::ElasticInOut),
+    ),
+    ("backIn", EaseMethod::EaseFunction(EaseFunction::BackIn)),
+    ("backOut", EaseMethod::EaseFunction(EaseFunction::BackOut)),
+    (
+        "backInOut",
+        EaseMethod::EaseFunction(EaseFunction::BackInOut),
+    ),
+    ("bounceIn", EaseMethod::EaseFunction(EaseFunction::BounceIn)),
+    (
+        "bounceOut",
+        EaseMethod::EaseFunction(EaseFunction::BounceOut),
+    ),
+    (
+        "bounceInOut",
+        EaseMethod::EaseFunction(EaseFunction::BounceInOut),
+    ),
+];
+
+/// Abbreviations accepted by [`EaseMethod::from_name()`], and the family name
+/// they expand to.
+const EASE_ABBREVIATIONS: [(&str, &str); 5] = [
+    ("quad", "quadratic"),
+    ("quart", "quartic"),
+    ("quint", "quintic"),
+    ("circ", "circular"),
+    ("expo", "exponential"),
+];
+
 impl EaseMethod {
+    /// Get the easing method with the given name.
+    ///
+    /// The names of the presets are the lower camel case names of the
+    /// [`EaseFunction`] variants, like `"quadraticInOut"` or `"backOut"`, and
+    /// `"linear"` for [`EaseMethod::Linear`]; see [`presets()`]. The lookup is
+    /// case-insensitive, and ignores the `-` and `_` separators, so
+    /// `"Quadratic-In-Out"` and `"quadratic_in_out"` are accepted too. The
+    /// common abbreviations `quad`, `quart`, `quint`, `circ`, and `expo` are
+    /// expanded, so `"quadInOut"` also names
+    /// [`EaseFunction::QuadraticInOut`].
+    ///
+    /// [`EaseMethod::Discrete`] is named with its limit as a parameter, like
+    /// `"discrete(0.5)"`. [`EaseMethod::CustomFunction`] has no name.
+    ///
+    /// Returns `None` if the name is unknown, or the parameter is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_tweening::*;
+    /// let ease = EaseMethod::from_name("quadInOut").unwrap();
+    /// assert_eq!(ease.name(), Some("quadraticInOut"));
+    /// assert!(EaseMethod::from_name("discrete(0.3)").is_some());
+    /// assert!(EaseMethod::from_name("wobbly").is_none());
+    /// ```
+    ///
+    /// [`presets()`]: EaseMethod::presets
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_ascii_lowercase();
+        if let Some(limit) = name
+            .strip_prefix("discrete(")
+            .and_then(|param| param.strip_suffix(')'))
+        {
+            let limit = limit.trim().parse::<f32>().ok()?;
+            return limit.is_finite().then_some(Self::Discrete(limit));
+        }
+        let mut name: String = name.chars().filter(|c| !matches!(c, '-' | '_')).collect();
+        for (abbreviation, family) in EASE_ABBREVIATIONS {
+            if name.starts_with(abbreviation) && !name.starts_with(family) {
+                name.replace_range(..abbreviation.len(), family);
+                break;
+            }
+        }
+        EASE_PRESETS
+            .iter()
+            .find(|(preset, _)| preset.eq_ignore_ascii_case(&name))
+            .map(|(_, ease)| *ease)
+    }
+
+    /// Get the name of a preset easing method.
+    ///
+    /// Returns `None` for [`EaseMethod::Discrete`],
+    /// [`EaseMethod::CustomFunction`], and [`EaseMethod::Parametric`], which
+    /// are not presets. The name is the
+    /// canonical one accepted by [`from_name()`].
+    ///
+    /// [`from_name()`]: EaseMethod::from_name
+    #[must_use]
+    pub fn name(&self) -> Option<&'static str> {
+        let function = match self {
+            Self::Linear => return Some("linear"),
+            Self::EaseFunction(function) => function,
+            Self::Discrete(_) | Self::CustomFunction(_) | Self::Parametric(_) => return None,
+        };
+        EASE_PRESETS.iter().find_map(|(name, ease)| match ease {
+            Self::EaseFunction(preset) if preset == function => Some(*name),
+            _ => None,
+        })
+    }
+
+    /// Iterate over the preset easing methods and their names.
+    ///
+    /// This includes [`EaseMethod::Linear`] and all the [`EaseFunction`]
+    /// variants, for example to build the list of easings of an editor.
+    pub fn presets() -> impl Iterator<Item = (&'static str, EaseMethod)> {
+        EASE_PRESETS.into_iter()
+    }
+
+    /// Default amplitude of the elastic easings.
+    pub const ELASTIC_AMPLITUDE: f32 = 1.;
+
+    /// Default period of the elastic easings.
+    pub const ELASTIC_PERIOD: f32 = 4. / 13.;
+
+    /// Default number of bounces of the bounce easings.
+    pub const BOUNCE_COUNT: u32 = 3;
+
+    /// Default restitution of the bounce easings.
+    pub const BOUNCE_RESTITUTION: f32 = 0.5;
+
+    /// Elastic easing with the given amplitude and period, oscillating with a
+    /// growing amplitude before snapping to the end value.
+    ///
+    /// The `amplitude` scales the oscillation, and is clamped to \[1:10\].
+    /// The `period` is the duration of an oscillation as a fraction of the
+    /// whole easing, clamped to \[0.01:2\]; a longer period oscillates less.
+    /// Out of range parameters are clamped with a warning. With the default
+    /// [`ELASTIC_AMPLITUDE`] and [`ELASTIC_PERIOD`], this is the standard
+    /// elastic easing. The easing is exactly `0.` at `0.` and `1.` at `1.`.
+    ///
+    /// Note that the [`EaseFunction::ElasticIn`] function of the
+    /// `interpolation` crate uses a 4 times shorter period, and doesn't end on
+    /// `1.`.
+    ///
+    /// [`ELASTIC_AMPLITUDE`]: Self::ELASTIC_AMPLITUDE
+    /// [`ELASTIC_PERIOD`]: Self::ELASTIC_PERIOD
+    #[must_use]
+    pub fn elastic_in(amplitude: f32, period: f32) -> Self {
+        let (amplitude, period) = Self::elastic_params(amplitude, period);
+        Self::Parametric(ParametricEase::ElasticIn { amplitude, period })
+    }
+
+    /// Elastic easing with the given amplitude and period, overshooting the
+    /// end value and oscillating around it with a decaying amplitude.
+    ///
+    /// This is the reverse of [`elastic_in()`]; see it for the parameters.
+    /// For example, a period 30% longer than [`ELASTIC_PERIOD`] gives the
+    /// same easing, oscillating 30% less often.
+    ///
+    /// [`elastic_in()`]: Self::elastic_in
+    /// [`ELASTIC_PERIOD`]: Self::ELASTIC_PERIOD
+    #[must_use]
+    pub fn elastic_out(amplitude: f32, period: f32) -> Self {
+        let (amplitude, period) = Self::elastic_params(amplitude, period);
+        Self::Parametric(ParametricEase::ElasticOut { amplitude, period })
+    }
+
+    /// Elastic easing with the given amplitude and period, made of
+    /// [`elastic_in()`] on the first half and [`elastic_out()`] on the second
+    /// one.
+    ///
+    /// The period is a fraction of each half. See [`elastic_in()`] for the
+    /// parameters.
+    ///
+    /// [`elastic_in()`]: Self::elastic_in
+    /// [`elastic_out()`]: Self::elastic_out
+    #[must_use]
+    pub fn elastic_in_out(amplitude: f32, period: f32) -> Self {
+        let (amplitude, period) = Self::elastic_params(amplitude, period);
+        Self::Parametric(ParametricEase::ElasticInOut { amplitude, period })
+    }
+
+    /// Bounce easing, the reverse of [`bounce_out()`].
+    ///
+    /// See [`bounce_out()`] for the parameters.
+    ///
+    /// [`bounce_out()`]: Self::bounce_out
+    #[must_use]
+    pub fn bounce_in(bounces: u32, restitution: f32) -> Self {
+        let (bounces, restitution) = Self::bounce_params(bounces, restitution);
+        Self::Parametric(ParametricEase::BounceIn {
+            bounces,
+            restitution,
+        })
+    }
+
+    /// Bounce easing with the given number of bounces and restitution.
+    ///
+    /// The easing follows a ball falling from the start value onto the end
+    /// value, then bouncing `bounces` times, clamped to at most 16. Each
+    /// bounce keeps a `restitution` fraction of the impact velocity, clamped
+    /// to \[0:1\], so a lower restitution gives smaller bounces. Out of range
+    /// parameters are clamped with a warning. The motion is scaled so the
+    /// last bounce lands exactly at the end of the easing, and the easing is
+    /// exactly `0.` at `0.` and `1.` at `1.`.
+    ///
+    /// With the default [`BOUNCE_COUNT`] and [`BOUNCE_RESTITUTION`], this is
+    /// the standard bounce easing. The [`EaseFunction::BounceOut`] function of
+    /// the `interpolation` crate matches it up to the first impact, then has
+    /// slightly higher bounces.
+    ///
+    /// [`BOUNCE_COUNT`]: Self::BOUNCE_COUNT
+    /// [`BOUNCE_RESTITUTION`]: Self::BOUNCE_RESTITUTION
+    #[must_use]
+    pub fn bounce_out(bounces: u32, restitution: f32) -> Self {
+        let (bounces, restitution) = Self::bounce_params(bounces, restitution);
+        Self::Parametric(ParametricEase::BounceOut {
+            bounces,
+            restitution,
+        })
+    }
+
+    /// Bounce easing made of [`bounce_in()`] on the first half and
+    /// [`bounce_out()`] on the second one.
+    ///
+    /// See [`bounce_out()`] for the parameters.
+    ///
+    /// [`bounce_in()`]: Self::bounce_in
+    /// [`bounce_out()`]: Self::bounce_out
+    #[must_use]
+    pub fn bounce_in_out(bounces: u32, restitution: f32) -> Self {
+        let (bounces, restitution) = Self::bounce_params(bounces, restitution);
+        Self::Parametric(ParametricEase::BounceInOut {
+            bounces,
+            restitution,
+        })
+    }
+
+    fn elastic_params(amplitude: f32, period: f32) -> (f32, f32) {
+        let amplitude = ease::clamp_param("amplitude", amplitude, 1., 10., Self::ELASTIC_AMPLITUDE);
+        let period = ease::clamp_param("period", period, 0.01, 2., Self::ELASTIC_PERIOD);
+        (amplitude, period)
+    }
+
+    fn bounce_params(bounces: u32, restitution: f32) -> (u32, f32) {
+        if bounces > ease::MAX_BOUNCES {
+            warn!(
+                "Easing parameter bounces={bounces} out of range [0:{}], clamped to {}.",
+                ease::MAX_BOUNCES,
+                ease::MAX_BOUNCES
+            );
+        }
+        let restitution =
+            ease::clamp_param("restitution", restitution, 0., 1., Self::BOUNCE_RESTITUTION);
+        (bounces.min(ease::MAX_BOUNCES), restitution)
+    }
+
     #[must_use]
     fn sample(self, x: f32) -> f32 {
         match self {
@@ -333,16 +1005,11 @@ impl EaseMethod {
                 }
             }
             Self::CustomFunction(function) => function(x),
+            Self::Parametric(ease) => ease.sample(x),
         }
     }
 }
 
-impl Default for EaseMethod {
-    fn default() -> Self {
-        Self::Linear
-    }
-}
-
 impl From<EaseFunction> for EaseMethod {
     fn from(ease_function: EaseFunction) -> Self {
         Self::EaseFunction(ease_function)
@@ -365,9 +1032,11 @@ impl From<EaseFunction> for EaseMethod {
 /// value set. When using [`RepeatStrategy::MirroredRepeat`], this is either
 /// forward (from start to end; ping) or backward (from end to start; pong),
 /// depending on the current iteration of the loop.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TweeningDirection {
     /// Animation playing from start to end.
+    #[default]
     Forward,
     /// Animation playing from end to start, in reverse.
     Backward,
@@ -387,12 +1056,6 @@ impl TweeningDirection {
     }
 }
 
-impl Default for TweeningDirection {
-    fn default() -> Self {
-        Self::Forward
-    }
-}
-
 impl std::ops::Not for TweeningDirection {
     type Output = Self;
 
@@ -406,10 +1069,14 @@ impl std::ops::Not for TweeningDirection {
 
 macro_rules! animator_impl {
     () => {
+        animator_impl!(T);
+    };
+    ($t:ty) => {
         /// Set the initial playback state of the animator.
         #[must_use]
         pub fn with_state(mut self, state: AnimatorState) -> Self {
             self.state = state;
+            self.reported_state = state;
             self
         }
 
@@ -439,29 +1106,671 @@ macro_rules! animator_impl {
         }
 
         /// Set the top-level tweenable item this animator controls.
-        pub fn set_tweenable(&mut self, tween: impl Tweenable<T> + 'static) {
-            self.tweenable = Box::new(tween);
+        ///
+        /// This is the only way to play again an animator which was shrunk; see
+        /// [`with_shrink_on_complete()`].
+        ///
+        /// [`with_shrink_on_complete()`]: Self::with_shrink_on_complete
+        ///
+        /// If the current tweenable didn't complete, a [`TweenCancelled`] event
+        /// is raised for each of its pending [`TweenCompleted`] events.
+        pub fn set_tweenable(&mut self, tween: impl Tweenable<$t> + 'static) {
+            self.set_boxed_tweenable(Box::new(tween));
+        }
+
+        /// Set an already boxed tweenable; see [`set_tweenable()`].
+        ///
+        /// [`set_tweenable()`]: Self::set_tweenable
+        pub(crate) fn set_boxed_tweenable(&mut self, tween: BoxedTweenable<$t>) {
+            self.cancel_tweenable();
+            self.cancel_latched = false;
+            self.tweenable = tween;
+            self.shrunk = false;
+            self.completed_at = None;
+            self.last_progress = 0.;
+            self.last_times_completed = 0;
+            self.started = false;
+        }
+
+        /// Enable shrinking the animator once its tweenable completes.
+        ///
+        /// When enabled, the animator system drops the tweenable on the tick it
+        /// completes, and replaces it with a small placeholder. This reclaims the
+        /// memory of the boxed tweenables and lenses of animations which will
+        /// never play again, without removing the animator component. The
+        /// placeholder still reports the duration, the number of times completed,
+        /// and the final progress of the dropped tweenable.
+        ///
+        /// A shrunk animator cannot be replayed: [`stop()`] and [`Tweenable::rewind()`]
+        /// have no effect on the placeholder. Call [`set_tweenable()`] with a new
+        /// tweenable to play another animation.
+        ///
+        /// Only non-looping tweenables complete, so looping animations are never
+        /// shrunk. Defaults to `false`.
+        ///
+        /// [`stop()`]: Self::stop
+        /// [`set_tweenable()`]: Self::set_tweenable
+        #[must_use]
+        pub fn with_shrink_on_complete(mut self, shrink_on_complete: bool) -> Self {
+            self.shrink_on_complete = shrink_on_complete;
+            self
+        }
+
+        /// Enable shrinking the animator once its tweenable completes.
+        ///
+        /// See [`with_shrink_on_complete()`] for details.
+        ///
+        /// [`with_shrink_on_complete()`]: Self::with_shrink_on_complete
+        pub fn set_shrink_on_complete(&mut self, shrink_on_complete: bool) {
+            self.shrink_on_complete = shrink_on_complete;
+        }
+
+        /// Is shrinking the animator once its tweenable completes enabled?
+        #[must_use]
+        pub fn shrink_on_complete(&self) -> bool {
+            self.shrink_on_complete
+        }
+
+        /// Despawn the entity of this animator, and its descendants, once its
+        /// tweenable has been completed for `delay`.
+        ///
+        /// The despawn is applied by the [`TweeningPlugin`] after the animator
+        /// system ticks, on the first frame where the [`Time::elapsed()`] time
+        /// is at least `delay` past the [`completed_at()`] time, so a zero
+        /// delay despawns the entity on the frame the tweenable completes.
+        /// Replacing the tweenable with [`set_tweenable()`], stopping the
+        /// animator with [`stop()`], rewinding it, or removing it before then
+        /// aborts the despawn. A tweenable replayed after it completed
+        /// schedules it again.
+        ///
+        /// [`TweeningPlugin`]: crate::TweeningPlugin
+        /// [`completed_at()`]: Self::completed_at
+        /// [`set_tweenable()`]: Self::set_tweenable
+        /// [`stop()`]: Self::stop
+        #[must_use]
+        pub fn with_completed_despawn_after(mut self, delay: Duration) -> Self {
+            self.completed_actions.push(CompletedAction::despawn(delay));
+            self
+        }
+
+        /// Insert a bundle produced by `factory` on the entity of this
+        /// animator once its tweenable has been completed for `delay`.
+        ///
+        /// The factory is called each time the insertion is applied, which
+        /// follows the same rules as [`with_completed_despawn_after()`]. The
+        /// insertion is skipped if the entity was despawned in the meantime.
+        ///
+        /// [`with_completed_despawn_after()`]: Self::with_completed_despawn_after
+        #[must_use]
+        pub fn with_completed_insert_after<B: Bundle>(
+            mut self,
+            delay: Duration,
+            factory: impl Fn() -> B + Send + Sync + 'static,
+        ) -> Self {
+            self.completed_actions
+                .push(CompletedAction::insert(delay, factory));
+            self
+        }
+
+        /// Bind this animator to the value `state` of the [`States`] type `S`.
+        ///
+        /// When the app exits `state`, the `on_exit` policy is applied to the
+        /// animator, and an animator paused that way resumes playing once the
+        /// app enters `state` again. The transitions are applied before the
+        /// animators tick, on the frame they happen, and only if the state type
+        /// was registered with [`TweeningPlugin::register_state()`] or
+        /// [`TweeningAppExt::add_tweening_state()`]. This replaces any previous
+        /// binding of the animator.
+        ///
+        /// [`States`]: bevy::ecs::schedule::States
+        /// [`TweeningPlugin::register_state()`]: crate::TweeningPlugin::register_state
+        /// [`TweeningAppExt::add_tweening_state()`]: crate::TweeningAppExt::add_tweening_state
+        #[must_use]
+        pub fn bound_to_state<S: States>(mut self, state: S, on_exit: OnStateExit) -> Self {
+            self.set_bound_state(state, on_exit);
+            self
+        }
+
+        /// Bind this animator to the value `state` of the [`States`] type `S`.
+        ///
+        /// See [`bound_to_state()`] for details.
+        ///
+        /// [`States`]: bevy::ecs::schedule::States
+        /// [`bound_to_state()`]: Self::bound_to_state
+        pub fn set_bound_state<S: States>(&mut self, state: S, on_exit: OnStateExit) {
+            self.state_binding = Some(StateBinding::new(state, on_exit));
+        }
+
+        /// Remove the binding of this animator to a state, if any.
+        ///
+        /// An animator paused by exiting its state stays paused.
+        pub fn unbind_state(&mut self) {
+            self.state_binding = None;
+        }
+
+        /// Apply the transition of a state from `exited` to `entered` to this
+        /// animator, according to its binding if any.
+        pub(crate) fn apply_state_transition(
+            &mut self,
+            exited: &dyn std::any::Any,
+            entered: &dyn std::any::Any,
+        ) {
+            let Some(binding) = self.state_binding.as_mut() else {
+                return;
+            };
+            if binding.is_state(exited) {
+                match binding.on_exit() {
+                    OnStateExit::Pause => {
+                        let playing = self.state == AnimatorState::Playing;
+                        binding.replace_paused(playing);
+                        self.state = AnimatorState::Paused;
+                    }
+                    OnStateExit::Cancel => self.stop(),
+                    OnStateExit::Complete => {
+                        if !self.skip_to_end(SkippedCompletions::Notify) {
+                            self.stop();
+                        }
+                    }
+                }
+            } else if binding.is_state(entered) && binding.replace_paused(false) {
+                self.state = AnimatorState::Playing;
+            }
+        }
+
+        /// Does this animator have any action to apply after it completed?
+        pub(crate) fn has_completed_actions(&self) -> bool {
+            !self.completed_actions.is_empty()
+        }
+
+        /// Apply the actions due at time `now` to the entity of this animator.
+        pub(crate) fn apply_completed_actions(
+            &mut self,
+            now: Duration,
+            commands: &mut Commands,
+            entity: Entity,
+        ) {
+            if let Some(completed_at) = self.completed_at {
+                for action in &mut self.completed_actions {
+                    action.apply(completed_at, now, commands, entity);
+                }
+            }
+        }
+
+        /// Was the tweenable of this animator dropped after it completed?
+        ///
+        /// See [`with_shrink_on_complete()`] for details.
+        ///
+        /// [`with_shrink_on_complete()`]: Self::with_shrink_on_complete
+        #[must_use]
+        pub fn is_shrunk(&self) -> bool {
+            self.shrunk
+        }
+
+        /// Replace the tweenable with a placeholder, if not already done.
+        pub(crate) fn shrink(&mut self) {
+            if !self.shrunk {
+                // Keep the cancellations raised by a token on the last tick
+                self.tweenable.take_token_cancelled(&mut self.cancelled);
+                self.tweenable = Box::new(CompletedTweenable::new(self.tweenable.as_ref()));
+                self.shrunk = true;
+            }
+        }
+
+        /// Consume the animator, and return its tweenable in its current state.
+        ///
+        /// The tweenable keeps its elapsed time, direction, and the number of
+        /// times it completed, so it can be inserted into another animator to
+        /// continue playing, or its state saved with [`Tweenable::save_state()`].
+        /// No [`TweenCancelled`] event is raised. If the animator was shrunk,
+        /// this returns the placeholder of the completed tweenable.
+        #[must_use]
+        pub fn into_tweenable(self) -> BoxedTweenable<$t> {
+            self.tweenable
+        }
+
+        /// Take the tweenable of the animator in its current state, leaving a
+        /// completed placeholder in its place.
+        ///
+        /// See [`into_tweenable()`] for details. The animator is then shrunk,
+        /// like after completing with [`with_shrink_on_complete()`]: it reports
+        /// the duration, elapsed time, and number of times completed of the
+        /// tweenable taken, but doesn't animate anything until a new tweenable
+        /// is set with [`set_tweenable()`].
+        ///
+        /// [`into_tweenable()`]: Self::into_tweenable
+        /// [`with_shrink_on_complete()`]: Self::with_shrink_on_complete
+        /// [`set_tweenable()`]: Self::set_tweenable
+        #[must_use]
+        pub fn take_tweenable(&mut self) -> BoxedTweenable<$t> {
+            let placeholder = Box::new(CompletedTweenable::new(self.tweenable.as_ref()));
+            self.shrunk = true;
+            std::mem::replace(&mut self.tweenable, placeholder)
         }
 
         /// Get the top-level tweenable this animator is currently controlling.
         #[must_use]
-        pub fn tweenable(&self) -> &dyn Tweenable<T> {
+        pub fn tweenable(&self) -> &dyn Tweenable<$t> {
             self.tweenable.as_ref()
         }
 
         /// Get the top-level mutable tweenable this animator is currently controlling.
         #[must_use]
-        pub fn tweenable_mut(&mut self) -> &mut dyn Tweenable<T> {
+        pub fn tweenable_mut(&mut self) -> &mut dyn Tweenable<$t> {
             self.tweenable.as_mut()
         }
 
+        /// Get the current state of the tweenable, without ticking it.
+        ///
+        /// This is the [`Tweenable::state()`] of the tweenable of the animator,
+        /// which is independent of the [`AnimatorState`]: a paused animator
+        /// can hold a completed tweenable. A shrunk animator reports
+        /// [`TweenState::Completed`].
+        #[must_use]
+        pub fn tween_state(&self) -> TweenState {
+            self.tweenable.state()
+        }
+
+        /// Get the time remaining until the animation completes.
+        ///
+        /// This is the [`total_duration()`] of the tweenable minus its
+        /// [`elapsed()`] time, so it accounts for the children of a [`Sequence`]
+        /// already played and for the loops of a repeating [`Tween`]. The value
+        /// is in tweenable time, and doesn't account for the [`speed()`] of the
+        /// animator, nor for it being paused. Returns `None` if the animation
+        /// loops forever.
+        ///
+        /// [`total_duration()`]: Tweenable::total_duration
+        /// [`elapsed()`]: Tweenable::elapsed
+        /// [`speed()`]: Self::speed
+        #[must_use]
+        pub fn time_remaining(&self) -> Option<Duration> {
+            match self.tweenable.total_duration() {
+                TotalDuration::Finite(total_duration) => {
+                    Some(total_duration.saturating_sub(self.tweenable.elapsed()))
+                }
+                TotalDuration::Infinite => None,
+            }
+        }
+
+        /// Get the fraction in \[0:1\] of the total duration of the animation
+        /// remaining until it completes.
+        ///
+        /// This is [`time_remaining()`] divided by the total duration of the
+        /// tweenable. Returns `None` if the animation loops forever.
+        ///
+        /// [`time_remaining()`]: Self::time_remaining
+        #[must_use]
+        pub fn fraction_remaining(&self) -> Option<f32> {
+            match self.tweenable.total_duration() {
+                TotalDuration::Finite(total_duration) if total_duration.is_zero() => Some(0.),
+                TotalDuration::Finite(total_duration) => {
+                    let remaining = total_duration.saturating_sub(self.tweenable.elapsed());
+                    Some((remaining.as_secs_f64() / total_duration.as_secs_f64()) as f32)
+                }
+                TotalDuration::Infinite => None,
+            }
+        }
+
+        /// Take the state transition not reported yet by an
+        /// [`AnimatorStateChanged`] event, if any, as an `(old, new)` pair.
+        pub(crate) fn take_state_change(&mut self) -> Option<(AnimatorState, AnimatorState)> {
+            if self.state == self.reported_state {
+                return None;
+            }
+            let old = self.reported_state;
+            self.reported_state = self.state;
+            Some((old, self.state))
+        }
+
+        /// Start the animation already advanced by `elapsed`.
+        ///
+        /// This fast-forwards the tweenable as if it had already been playing
+        /// for `elapsed`, for example to compensate for the network latency of an
+        /// animation started on a server. The tweenable time is used, so the
+        /// [`speed()`] of the animator doesn't apply to `elapsed`. Sequences
+        /// advance through their children, loops accrue their completions, and
+        /// ping-pong tweens take the direction they would have at that time. The
+        /// first tick of the animator applies the state at that time, even if the
+        /// animation already completed.
+        ///
+        /// The completions happening before `elapsed` are reported according to
+        /// `skipped`. With [`SkippedCompletions::Suppress`], the tweenable is
+        /// seeked with [`Tweenable::set_elapsed()`], so only the tweenables active
+        /// at `elapsed` are applied to the target. Notably, the children of a
+        /// [`Sequence`] preceding the active one are not applied.
+        ///
+        /// [`speed()`]: Self::speed
+        #[must_use]
+        pub fn start_at(mut self, elapsed: Duration, skipped: SkippedCompletions) -> Self {
+            match skipped {
+                SkippedCompletions::Suppress => self.tweenable.set_elapsed(elapsed),
+                SkippedCompletions::Notify => self.start_delta = elapsed,
+            }
+            self
+        }
+
+        /// Skip the animation to its end.
+        ///
+        /// The tweenable completes on the next tick of the animator, which
+        /// applies its end state to the target, and reports the completion of
+        /// the animator itself like any other completion: [`just_completed()`]
+        /// returns `true` and [`completed_at()`] is set on that tick.
+        ///
+        /// The completions skipped along the way are reported according to
+        /// `skipped`. With [`SkippedCompletions::Notify`], the next tick
+        /// fast-forwards the tweenable by the [`time_remaining()`], so every
+        /// nested tweenable which didn't complete yet raises its events and
+        /// invokes its callbacks exactly once, and applies its end state to
+        /// the target, in the order of the timeline. Like for any single tick,
+        /// the events of the children of a [`Tracks`] are grouped by child.
+        /// With [`SkippedCompletions::Suppress`], the tweenable is seeked to its
+        /// end with [`Tweenable::set_elapsed()`] instead, so no event is raised
+        /// and no callback is invoked, and the end state of each child of a
+        /// [`Sequence`] is applied in order by sampling it.
+        ///
+        /// Like with [`start_at()`], a paused animator is skipped once it plays
+        /// again. Returns `false` if the animation loops forever, in which case
+        /// it's left unchanged.
+        ///
+        /// [`just_completed()`]: Self::just_completed
+        /// [`completed_at()`]: Self::completed_at
+        /// [`time_remaining()`]: Self::time_remaining
+        /// [`start_at()`]: Self::start_at
+        pub fn skip_to_end(&mut self, skipped: SkippedCompletions) -> bool {
+            let Some(remaining) = self.time_remaining() else {
+                return false;
+            };
+            match skipped {
+                SkippedCompletions::Suppress => {
+                    let elapsed = self.tweenable.elapsed();
+                    self.tweenable.set_elapsed(elapsed + remaining);
+                }
+                SkippedCompletions::Notify => {
+                    self.start_delta = self.start_delta.max(remaining);
+                }
+            }
+            true
+        }
+
+        /// Take the time to fast-forward the tweenable by on the next tick, in
+        /// addition of the frame time.
+        pub(crate) fn take_start_delta(&mut self) -> Duration {
+            std::mem::take(&mut self.start_delta)
+        }
+
+        /// Defer to the next tick the `delta` of a frame skipped because of the
+        /// [`AnimationBudget`], with the speed of the animator applied.
+        pub(crate) fn defer_tick(&mut self, delta: Duration) {
+            if self.state != AnimatorState::Paused {
+                self.start_delta += delta.mul_f64(f64::from(self.speed));
+            }
+            self.clear_frame_flags();
+        }
+
         /// Stop animation playback and rewind the animation.
         ///
         /// This changes the animator state to [`AnimatorState::Paused`] and rewind its
-        /// tweenable.
+        /// tweenable. If the tweenable didn't complete, a [`TweenCancelled`] event is
+        /// raised for each of its pending [`TweenCompleted`] events.
         pub fn stop(&mut self) {
+            self.cancel_tweenable();
             self.state = AnimatorState::Paused;
             self.tweenable_mut().rewind();
+            self.completed_at = None;
+            self.started = false;
+        }
+
+        /// Reverse the playback direction of the animation, preserving its
+        /// current value.
+        ///
+        /// The tweenable direction is flipped with [`Tweenable::set_direction()`],
+        /// and its elapsed time is mirrored within the current iteration, so
+        /// that the animation retraces the values it went through since the
+        /// start of that iteration, for example to close a menu halfway through
+        /// its opening animation. A completed tweenable plays again its last
+        /// iteration in the opposite direction, and completes again once back
+        /// to its starting value.
+        ///
+        /// Returns `false` and logs a warning if the tweenable doesn't support
+        /// directions, in which case it's left unchanged. Among the built-in
+        /// tweenables, only [`Tween`] supports directions. A shrunk animator
+        /// doesn't support them either.
+        pub fn reverse(&mut self) -> bool {
+            let Some(direction) = self.tweenable.direction() else {
+                warn!("Cannot reverse animator: its tweenable doesn't support directions.");
+                return false;
+            };
+            let duration = self.tweenable.duration();
+            let elapsed = self.tweenable.elapsed();
+            let completed = matches!(
+                self.tweenable.total_duration(),
+                TotalDuration::Finite(total_duration) if elapsed >= total_duration
+            );
+            let within = if completed || duration.is_zero() {
+                duration.min(elapsed)
+            } else {
+                Duration::from_nanos((elapsed.as_nanos() % duration.as_nanos()) as u64)
+            };
+            let start = elapsed - within;
+            self.tweenable.set_direction(!direction);
+            self.tweenable.set_elapsed(start + (duration - within));
+            true
+        }
+
+        /// Get the current playback direction of the animation.
+        ///
+        /// Returns `None` if the tweenable doesn't support directions; see
+        /// [`reverse()`].
+        ///
+        /// [`reverse()`]: Self::reverse
+        #[must_use]
+        pub fn direction(&self) -> Option<TweeningDirection> {
+            self.tweenable.direction()
+        }
+
+        /// Is the animation currently playing in the [`TweeningDirection::Backward`]
+        /// direction?
+        ///
+        /// Returns `false` if the tweenable doesn't support directions.
+        #[must_use]
+        pub fn is_reversed(&self) -> bool {
+            self.direction()
+                .is_some_and(|direction| direction.is_backward())
+        }
+
+        /// Get the progress of the tweenable, as of the last tick of the
+        /// animator system.
+        ///
+        /// This is the value [`Tweenable::progress()`] returned at the end of
+        /// the last tick, cached in the animator so that it can be polled every
+        /// frame without accessing the tweenable, for example to drive a
+        /// progress bar. It keeps its final value once the animator is shrunk.
+        /// Changes made to the tweenable since the last tick, like seeking it
+        /// or rewinding it, are only reflected after the next tick; paused
+        /// animators are not ticked. This is zero until the first tick after
+        /// the animator is created, or after the tweenable is replaced with
+        /// [`set_tweenable()`].
+        ///
+        /// [`set_tweenable()`]: Self::set_tweenable
+        #[must_use]
+        pub fn last_progress(&self) -> f32 {
+            self.last_progress
+        }
+
+        /// Get the number of times the tweenable completed, as of the last
+        /// tick of the animator system.
+        ///
+        /// This is the value [`Tweenable::times_completed()`] returned at the
+        /// end of the last tick. See [`last_progress()`] for details.
+        ///
+        /// [`last_progress()`]: Self::last_progress
+        #[must_use]
+        pub fn last_times_completed(&self) -> u32 {
+            self.last_times_completed
+        }
+
+        /// Did the tweenable of this animator start playing during the last
+        /// run of the animator system?
+        ///
+        /// This is `true` for exactly one frame: the first tick after the
+        /// animator is created, after the tweenable is replaced with
+        /// [`set_tweenable()`] or the animator is [`stop()`]ped, or after the
+        /// tweenable is rewound or seeked back once it completed. The flags
+        /// [`just_started()`], [`just_completed()`], and [`just_looped()`] are
+        /// set by the animator system, and cleared on its next run, so systems
+        /// ordered after [`AnimationSystem::AnimationUpdate`] can react to
+        /// the animator they're iterating without reading the
+        /// [`TweenCompleted`] events. Elapsed triggers don't set any of those
+        /// flags.
+        ///
+        /// [`set_tweenable()`]: Self::set_tweenable
+        /// [`stop()`]: Self::stop
+        /// [`just_started()`]: Self::just_started
+        /// [`just_completed()`]: Self::just_completed
+        /// [`just_looped()`]: Self::just_looped
+        #[must_use]
+        pub fn just_started(&self) -> bool {
+            self.just_started
+        }
+
+        /// Did the tweenable of this animator complete during the last run of
+        /// the animator system?
+        ///
+        /// This is `true` for exactly one frame, the one where
+        /// [`completed_at()`] becomes set. Looping tweenables never complete.
+        /// See [`just_started()`] for details.
+        ///
+        /// [`completed_at()`]: Self::completed_at
+        /// [`just_started()`]: Self::just_started
+        #[must_use]
+        pub fn just_completed(&self) -> bool {
+            self.just_completed
+        }
+
+        /// Get the number of iterations the tweenable of this animator
+        /// completed during the last run of the animator system.
+        ///
+        /// This is the increase of [`last_times_completed()`] over that tick,
+        /// so it includes the last iteration of a non-looping tweenable, and
+        /// can be more than one if the tick was longer than an iteration. This
+        /// is zero on frames where the animator was not ticked. See
+        /// [`just_started()`] for details.
+        ///
+        /// [`last_times_completed()`]: Self::last_times_completed
+        /// [`just_started()`]: Self::just_started
+        #[must_use]
+        pub fn just_looped(&self) -> u32 {
+            self.just_looped
+        }
+
+        /// Clear the flags set by the previous run of the animator system.
+        pub(crate) fn clear_frame_flags(&mut self) {
+            self.just_started = false;
+            self.just_completed = false;
+            self.just_looped = 0;
+        }
+
+        /// Cache the progress of the tweenable after it ticked, with
+        /// `times_completed` its number of completed iterations before the
+        /// tick.
+        pub(crate) fn cache_progress(&mut self, times_completed: u32) {
+            self.last_progress = self.tweenable.progress();
+            self.last_times_completed = self.tweenable.times_completed();
+            self.just_looped = self.last_times_completed.saturating_sub(times_completed);
+        }
+
+        /// Get the time the tweenable of this animator completed at, if it's
+        /// currently completed.
+        ///
+        /// The time is the [`Time::elapsed()`] time since startup of the
+        /// animator system tick during which the tweenable completed. This is
+        /// reset to `None` when the tweenable is replaced with
+        /// [`set_tweenable()`], when the animator is [`stop()`]ped, and on the
+        /// next tick after the tweenable is rewound or seeked back before its
+        /// end. Looping tweenables never complete, so never record a time.
+        ///
+        /// [`set_tweenable()`]: Self::set_tweenable
+        /// [`stop()`]: Self::stop
+        #[must_use]
+        pub fn completed_at(&self) -> Option<Duration> {
+            self.completed_at
+        }
+
+        /// Get for how long the tweenable of this animator has been completed,
+        /// at the time `now`.
+        ///
+        /// The time `now` is a time since startup, like [`Time::elapsed()`].
+        /// Returns `None` if the tweenable is not completed; see
+        /// [`completed_at()`] for details.
+        ///
+        /// [`completed_at()`]: Self::completed_at
+        #[must_use]
+        pub fn completed_since(&self, now: Duration) -> Option<Duration> {
+            self.completed_at
+                .map(|completed_at| now.saturating_sub(completed_at))
+        }
+
+        /// Update the completion time after the animator system ticked the
+        /// tweenable at time `now`, with `state` the result of that tick if
+        /// the animator was not paused.
+        ///
+        /// This also sets the flags returned by [`just_started()`] and
+        /// [`just_completed()`], and queues the [`TweenCancelled`] events
+        /// raised by the [`CancelToken`] of the tweenable during the tick.
+        ///
+        /// [`just_started()`]: Self::just_started
+        /// [`just_completed()`]: Self::just_completed
+        pub(crate) fn track_completion(&mut self, state: Option<TweenState>, now: Duration) {
+            if state.is_some() {
+                // Playing again after a cancellation allows another one
+                self.cancel_latched = false;
+                self.just_started = !self.started;
+                self.started = true;
+                self.tweenable.take_token_cancelled(&mut self.cancelled);
+            }
+            if self.completed_at.is_some() {
+                // Rewound or seeked back since it completed. A tweenable
+                // cancelled through its token stays completed wherever it
+                // stopped.
+                if self.time_remaining() != Some(Duration::ZERO)
+                    && state != Some(TweenState::Completed)
+                {
+                    self.completed_at = None;
+                    self.just_started = state.is_some();
+                }
+            } else if state == Some(TweenState::Completed) {
+                self.completed_at = Some(now);
+                self.just_completed = true;
+            }
+        }
+
+        /// Append the [`TweenCancelled`] events the tweenable would raise if
+        /// it was discarded now.
+        pub(crate) fn collect_cancelled(&self, entity: Entity, cancelled: &mut Vec<TweenCancelled>) {
+            if self.completed_at.is_none() && !self.cancel_latched {
+                self.tweenable.collect_cancelled(entity, cancelled);
+            }
+        }
+
+        /// Queue the [`TweenCancelled`] events of the tweenable, which is
+        /// about to be discarded or rewound, unless already done.
+        pub(crate) fn cancel_tweenable(&mut self) {
+            let mut cancelled = std::mem::take(&mut self.cancelled);
+            self.collect_cancelled(Entity::PLACEHOLDER, &mut cancelled);
+            self.cancelled = cancelled;
+            self.cancel_latched = true;
+        }
+
+        /// Take the queued [`TweenCancelled`] events, raised from the entity
+        /// of the animator.
+        pub(crate) fn take_cancelled(
+            &mut self,
+            entity: Entity,
+        ) -> impl Iterator<Item = TweenCancelled> + '_ {
+            self.cancelled.drain(..).map(move |mut cancelled| {
+                cancelled.entity = entity;
+                cancelled
+            })
         }
     };
 }
@@ -476,6 +1785,23 @@ pub struct Animator<T: Component> {
     pub state: AnimatorState,
     tweenable: BoxedTweenable<T>,
     speed: f32,
+    shrink_on_complete: bool,
+    shrunk: bool,
+    reported_state: AnimatorState,
+    start_delta: Duration,
+    completed_at: Option<Duration>,
+    last_progress: f32,
+    last_times_completed: u32,
+    cancelled: Vec<TweenCancelled>,
+    cancel_latched: bool,
+    state_binding: Option<StateBinding>,
+    completed_actions: Vec<CompletedAction>,
+    started: bool,
+    just_started: bool,
+    just_completed: bool,
+    just_looped: u32,
+    on_update: Option<Box<UpdateCallback<T>>>,
+    lens_context: LensContext,
 }
 
 impl<T: Component + std::fmt::Debug> std::fmt::Debug for Animator<T> {
@@ -490,27 +1816,194 @@ impl<T: Component> Animator<T> {
     /// Create a new animator component from a single tweenable.
     #[must_use]
     pub fn new(tween: impl Tweenable<T> + 'static) -> Self {
+        Self::from_boxed(Box::new(tween))
+    }
+
+    /// Create a new animator component from an already boxed tweenable.
+    pub(crate) fn from_boxed(tweenable: BoxedTweenable<T>) -> Self {
         Self {
             state: default(),
-            tweenable: Box::new(tween),
+            tweenable,
             speed: 1.,
+            shrink_on_complete: false,
+            shrunk: false,
+            reported_state: default(),
+            start_delta: Duration::ZERO,
+            completed_at: None,
+            last_progress: 0.,
+            last_times_completed: 0,
+            cancelled: vec![],
+            cancel_latched: false,
+            state_binding: None,
+            completed_actions: vec![],
+            started: false,
+            just_started: false,
+            just_completed: false,
+            just_looped: 0,
+            on_update: None,
+            lens_context: default(),
+        }
+    }
+
+    /// Set a callback invoked each time the animator ticks its tweenable.
+    ///
+    /// The callback when invoked receives as parameters the [`Entity`] of the
+    /// animator, and a reference to the animated component, after the
+    /// tweenable wrote its new value to it. Unlike [`Tween::set_on_update()`],
+    /// the callback is invoked exactly once per tick of the animator, whatever
+    /// the structure of its tweenable, even on ticks which leave the component
+    /// untouched. It's not invoked while the animator is paused.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::prelude::*;
+    /// # let tween = Tween::new(EaseFunction::QuadraticInOut, 1., TransformPositionLens { start: Vec3::ZERO, end: Vec3::ONE });
+    /// let animator = Animator::new(tween).with_on_update(|entity, transform: &Transform| {
+    ///     println!("Entity {entity:?} moved to {}", transform.translation);
+    /// });
+    /// ```
+    pub fn set_on_update<C>(&mut self, callback: C)
+    where
+        C: Fn(Entity, &T) + Send + Sync + 'static,
+    {
+        self.on_update = Some(Box::new(callback));
+    }
+
+    /// Set a callback invoked each time the animator ticks its tweenable.
+    ///
+    /// See [`set_on_update()`] for details.
+    ///
+    /// [`set_on_update()`]: Animator::set_on_update
+    #[must_use]
+    pub fn with_on_update<C>(mut self, callback: C) -> Self
+    where
+        C: Fn(Entity, &T) + Send + Sync + 'static,
+    {
+        self.set_on_update(callback);
+        self
+    }
+
+    /// Clear the callback invoked each time the animator ticks its tweenable.
+    pub fn clear_on_update(&mut self) {
+        self.on_update = None;
+    }
+
+    /// Invoke the callback set with [`set_on_update()`], if any.
+    ///
+    /// [`set_on_update()`]: Animator::set_on_update
+    pub(crate) fn notify_update(&self, entity: Entity, target: &T) {
+        if let Some(cb) = &self.on_update {
+            cb(entity, target);
         }
     }
 
+    /// Get the context passed to the lenses when ticking the tweenable.
+    pub(crate) fn lens_context(&self) -> LensContext {
+        self.lens_context
+    }
+
+    /// Set the context passed to the lenses when ticking the tweenable.
+    pub(crate) fn set_lens_context(&mut self, context: LensContext) {
+        self.lens_context = context;
+    }
+
     animator_impl!();
 }
 
+/// Asset animated by an [`AssetAnimator`].
+#[cfg(feature = "bevy_asset")]
+#[derive(Default)]
+pub enum AssetAnimatorTarget<T: Asset> {
+    /// Animate the asset referenced by the [`Handle<T>`] component located on
+    /// the same entity as the animator.
+    ///
+    /// The handle is read again on each tick, so if the entity swaps its
+    /// handle mid-animation, the animation continues on the new asset from
+    /// its current progress, and the old asset is left untouched. This is the
+    /// default.
+    #[default]
+    FollowComponentHandle,
+    /// Animate the asset referenced by the given handle, whichever handle the
+    /// entity holds, if any.
+    FixedHandle(Handle<T>),
+}
+
+#[cfg(feature = "bevy_asset")]
+impl<T: Asset> Clone for AssetAnimatorTarget<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::FollowComponentHandle => Self::FollowComponentHandle,
+            Self::FixedHandle(handle) => Self::FixedHandle(handle.clone()),
+        }
+    }
+}
+
+#[cfg(feature = "bevy_asset")]
+impl<T: Asset> std::fmt::Debug for AssetAnimatorTarget<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FollowComponentHandle => write!(f, "FollowComponentHandle"),
+            Self::FixedHandle(handle) => f.debug_tuple("FixedHandle").field(handle).finish(),
+        }
+    }
+}
+
+#[cfg(feature = "bevy_asset")]
+impl<T: Asset> PartialEq for AssetAnimatorTarget<T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::FollowComponentHandle, Self::FollowComponentHandle) => true,
+            (Self::FixedHandle(a), Self::FixedHandle(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(feature = "bevy_asset")]
+impl<T: Asset> AssetAnimatorTarget<T> {
+    /// Resolve the handle of the animated asset, given the handle component
+    /// of the entity, if any.
+    pub(crate) fn resolve<'a>(&'a self, component: Option<&'a Handle<T>>) -> Option<&'a Handle<T>> {
+        match self {
+            Self::FollowComponentHandle => component,
+            Self::FixedHandle(handle) => Some(handle),
+        }
+    }
+}
+
 /// Component to control the animation of an asset.
 ///
-/// The animated asset is the asset referenced by a [`Handle<T>`] component
-/// located on the same entity as the [`AssetAnimator<T>`] itself.
+/// By default, the animated asset is the asset referenced by a [`Handle<T>`]
+/// component located on the same entity as the [`AssetAnimator<T>`] itself,
+/// read again on each tick. Use [`with_target()`] to animate a fixed asset
+/// instead; see [`AssetAnimatorTarget`].
+///
+/// [`with_target()`]: AssetAnimator::with_target
 #[cfg(feature = "bevy_asset")]
 #[derive(Component)]
 pub struct AssetAnimator<T: Asset> {
     /// Control if this animation is played or not.
     pub state: AnimatorState,
     tweenable: BoxedTweenable<T>,
+    target: AssetAnimatorTarget<T>,
     speed: f32,
+    shrink_on_complete: bool,
+    shrunk: bool,
+    reported_state: AnimatorState,
+    start_delta: Duration,
+    completed_at: Option<Duration>,
+    last_progress: f32,
+    last_times_completed: u32,
+    cancelled: Vec<TweenCancelled>,
+    cancel_latched: bool,
+    state_binding: Option<StateBinding>,
+    completed_actions: Vec<CompletedAction>,
+    started: bool,
+    just_started: bool,
+    just_completed: bool,
+    just_looped: u32,
 }
 
 #[cfg(feature = "bevy_asset")]
@@ -530,31 +2023,285 @@ impl<T: Asset> AssetAnimator<T> {
         Self {
             state: default(),
             tweenable: Box::new(tween),
+            target: default(),
             speed: 1.,
+            shrink_on_complete: false,
+            shrunk: false,
+            reported_state: default(),
+            start_delta: Duration::ZERO,
+            completed_at: None,
+            last_progress: 0.,
+            last_times_completed: 0,
+            cancelled: vec![],
+            cancel_latched: false,
+            state_binding: None,
+            completed_actions: vec![],
+            started: false,
+            just_started: false,
+            just_completed: false,
+            just_looped: 0,
         }
     }
 
+    /// Set the asset animated by the animator. Defaults to
+    /// [`AssetAnimatorTarget::FollowComponentHandle`].
+    #[must_use]
+    pub fn with_target(mut self, target: AssetAnimatorTarget<T>) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Set the asset animated by the animator.
+    ///
+    /// The animation continues from its current progress on the new target.
+    pub fn set_target(&mut self, target: AssetAnimatorTarget<T>) {
+        self.target = target;
+    }
+
+    /// Get the asset animated by the animator.
+    pub fn target(&self) -> &AssetAnimatorTarget<T> {
+        &self.target
+    }
+
     animator_impl!();
 }
 
-/// Trait to interpolate between two values.
-/// Needed for color.
-#[allow(dead_code)]
-trait ColorLerper {
-    fn lerp(&self, target: &Self, ratio: f32) -> Self;
+/// Animation factor shared by the two lenses of a [`SyncedAnimator`].
+///
+/// This is the target of the tweenable of a [`SyncedAnimator`]. The tweenable
+/// animates the factor with a [`SyncFactorLens`], and the animator then
+/// forwards it as the `ratio` of both its component and asset lenses.
+///
+/// [`SyncFactorLens`]: lens::SyncFactorLens
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct SyncFactor(pub f32);
+
+/// Component to animate a component and an asset from a single timeline.
+///
+/// The animated component is the component `C` located on the same entity as
+/// the [`SyncedAnimator`], and the animated asset is the asset referenced by
+/// the [`Handle<A>`] component on that same entity. A single tweenable drives
+/// a [`SyncFactor`], and on each tick where that factor changes, both lenses
+/// are invoked with the same factor as their `ratio`. Compared to an
+/// [`Animator<C>`] and an [`AssetAnimator<A>`] on the same entity, this
+/// guarantees both lenses always sample the same eased value, and raises a
+/// single [`TweenCompleted`] event.
+///
+/// The animator is ticked by the [`synced_animator_system::<C, A>`] system,
+/// which needs to be registered with [`TweeningPlugin::register_synced()`] or
+/// added manually. If the asset doesn't exist, the component is still
+/// animated. This requires the `bevy_asset` feature (enabled by default).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # #[derive(Asset, TypePath)]
+/// # struct Glow { intensity: f32 }
+/// struct GlowLens;
+///
+/// impl Lens<Glow> for GlowLens {
+///     fn lerp(&mut self, target: &mut Glow, ratio: f32) {
+///         target.intensity = ratio * 5.;
+///     }
+/// }
+///
+/// fn hit(mut commands: Commands, entity: Entity) {
+///     // Flash up and back, in sync with a scale punch
+///     let tween = Tween::new_millis(
+///         EaseFunction::QuadraticOut,
+///         100,
+///         SyncFactorLens { start: 0., end: 1. },
+///     )
+///     .with_repeat_count(2)
+///     .with_repeat_strategy(RepeatStrategy::MirroredRepeat)
+///     .with_completed_event(0);
+///     commands.entity(entity).insert(SyncedAnimator::new(
+///         tween,
+///         TransformScaleLens {
+///             start: Vec3::ONE,
+///             end: Vec3::splat(1.2),
+///         },
+///         GlowLens,
+///     ));
+/// }
+/// ```
+///
+/// [`synced_animator_system::<C, A>`]: synced_animator_system
+#[cfg(feature = "bevy_asset")]
+#[derive(Component)]
+pub struct SyncedAnimator<C: Component, A: Asset> {
+    /// Control if this animation is played or not.
+    pub state: AnimatorState,
+    tweenable: BoxedTweenable<SyncFactor>,
+    component_lens: Box<dyn Lens<C> + Send + Sync + 'static>,
+    asset_lens: Box<dyn Lens<A> + Send + Sync + 'static>,
+    factor: SyncFactor,
+    speed: f32,
+    shrink_on_complete: bool,
+    shrunk: bool,
+    reported_state: AnimatorState,
+    start_delta: Duration,
+    completed_at: Option<Duration>,
+    last_progress: f32,
+    last_times_completed: u32,
+    cancelled: Vec<TweenCancelled>,
+    cancel_latched: bool,
+    state_binding: Option<StateBinding>,
+    completed_actions: Vec<CompletedAction>,
+    started: bool,
+    just_started: bool,
+    just_completed: bool,
+    just_looped: u32,
+}
+
+#[cfg(feature = "bevy_asset")]
+impl<C: Component, A: Asset> std::fmt::Debug for SyncedAnimator<C, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SyncedAnimator")
+            .field("state", &self.state)
+            .field("factor", &self.factor)
+            .finish()
+    }
+}
+
+#[cfg(feature = "bevy_asset")]
+impl<C: Component, A: Asset> SyncedAnimator<C, A> {
+    /// Create a new synced animator component from a tweenable driving the
+    /// shared factor, and the lenses of the component and the asset.
+    #[must_use]
+    pub fn new(
+        tween: impl Tweenable<SyncFactor> + 'static,
+        component_lens: impl Lens<C> + Send + Sync + 'static,
+        asset_lens: impl Lens<A> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            state: default(),
+            tweenable: Box::new(tween),
+            component_lens: Box::new(component_lens),
+            asset_lens: Box::new(asset_lens),
+            factor: default(),
+            speed: 1.,
+            shrink_on_complete: false,
+            shrunk: false,
+            reported_state: default(),
+            start_delta: Duration::ZERO,
+            completed_at: None,
+            last_progress: 0.,
+            last_times_completed: 0,
+            cancelled: vec![],
+            cancel_latched: false,
+            state_binding: None,
+            completed_actions: vec![],
+            started: false,
+            just_started: false,
+            just_completed: false,
+            just_looped: 0,
+        }
+    }
+
+    /// Get the factor last applied to both lenses.
+    #[must_use]
+    pub fn factor(&self) -> f32 {
+        self.factor.0
+    }
+
+    /// Tick the tweenable, and apply the shared factor to the component and
+    /// the asset if it changed.
+    pub(crate) fn tick(
+        &mut self,
+        delta: Duration,
+        component: &mut Mut<C>,
+        assets: &mut Assets<A>,
+        handle: &Handle<A>,
+        entity: Entity,
+        events: &mut Vec<TweenCompleted>,
+    ) -> TweenState {
+        let mut target = tweenable::SyncFactorTarget::new(&mut self.factor);
+        let state = self.tweenable.tick(delta, &mut target, entity, events);
+        if target.changed() {
+            let ratio = self.factor.0;
+            self.component_lens.lerp(component, ratio);
+            if let Some(asset) = assets.get_mut(handle) {
+                self.asset_lens.lerp(asset, ratio);
+            }
+        }
+        state
+    }
+
+    animator_impl!(SyncFactor);
+}
+
+/// Component to control the animation of a resource.
+///
+/// The animated resource is the resource of type `T` in the world. Unlike an
+/// [`Animator<T>`], a [`ResourceAnimator<T>`] can be spawned on any entity,
+/// which is only used to report that entity in the [`TweenCompleted`] events
+/// and the completion callbacks. Spawning more than one [`ResourceAnimator<T>`]
+/// for the same resource type makes them override each other.
+///
+/// The animation is not ticked while the resource doesn't exist.
+#[derive(Component)]
+pub struct ResourceAnimator<T: Resource> {
+    /// Control if this animation is played or not.
+    pub state: AnimatorState,
+    tweenable: BoxedTweenable<T>,
+    speed: f32,
+    shrink_on_complete: bool,
+    shrunk: bool,
+    reported_state: AnimatorState,
+    start_delta: Duration,
+    completed_at: Option<Duration>,
+    last_progress: f32,
+    last_times_completed: u32,
+    cancelled: Vec<TweenCancelled>,
+    cancel_latched: bool,
+    state_binding: Option<StateBinding>,
+    completed_actions: Vec<CompletedAction>,
+    started: bool,
+    just_started: bool,
+    just_completed: bool,
+    just_looped: u32,
 }
 
-#[allow(dead_code)]
-impl ColorLerper for Color {
-    fn lerp(&self, target: &Color, ratio: f32) -> Color {
-        let r = self.r().lerp(target.r(), ratio);
-        let g = self.g().lerp(target.g(), ratio);
-        let b = self.b().lerp(target.b(), ratio);
-        let a = self.a().lerp(target.a(), ratio);
-        Color::rgba(r, g, b, a)
+impl<T: Resource + std::fmt::Debug> std::fmt::Debug for ResourceAnimator<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResourceAnimator")
+            .field("state", &self.state)
+            .finish()
     }
 }
 
+impl<T: Resource> ResourceAnimator<T> {
+    /// Create a new resource animator component from a single tweenable.
+    #[must_use]
+    pub fn new(tween: impl Tweenable<T> + 'static) -> Self {
+        Self {
+            state: default(),
+            tweenable: Box::new(tween),
+            speed: 1.,
+            shrink_on_complete: false,
+            shrunk: false,
+            reported_state: default(),
+            start_delta: Duration::ZERO,
+            completed_at: None,
+            last_progress: 0.,
+            last_times_completed: 0,
+            cancelled: vec![],
+            cancel_latched: false,
+            state_binding: None,
+            completed_actions: vec![],
+            started: false,
+            just_started: false,
+            just_completed: false,
+            just_looped: 0,
+        }
+    }
+
+    animator_impl!();
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -610,6 +2357,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn into_duration() {
+        let duration = Duration::from_nanos(123_456_789);
+        assert_eq!(duration.into_duration(), duration);
+        assert_eq!(0.25.into_duration(), Duration::from_millis(250));
+        for invalid in [-0.5, f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            assert_eq!(invalid.into_duration(), Duration::ZERO);
+        }
+
+        let tween: Tween<DummyComponent> =
+            Tween::new_secs(EaseMethod::Linear, 0.5, DummyLens { start: 0., end: 1. });
+        assert_eq!(tween.duration(), Duration::from_millis(500));
+        let tween: Tween<DummyComponent> =
+            Tween::new_millis(EaseMethod::Linear, 40, DummyLens { start: 0., end: 1. });
+        assert_eq!(tween.duration(), Duration::from_millis(40));
+        let tween: Tween<DummyComponent> =
+            Tween::new_secs(EaseMethod::Linear, -1., DummyLens { start: 0., end: 1. });
+        assert_eq!(tween.duration(), Duration::ZERO);
+
+        let delay: Delay<DummyComponent> = Delay::secs(2.);
+        assert_eq!(delay.duration(), Duration::from_secs(2));
+        let delay: Delay<DummyComponent> = Delay::millis(150);
+        assert_eq!(delay.duration(), Duration::from_millis(150));
+    }
+
     #[test]
     fn repeat_count() {
         let count = RepeatCount::default();
@@ -622,6 +2394,59 @@ mod tests {
         assert_eq!(strategy, RepeatStrategy::Repeat);
     }
 
+    #[test]
+    #[allow(deprecated)]
+    fn legacy_tweening_type() {
+        assert_eq!(TweeningType::default(), TweeningType::Once);
+        for (tweening_type, count, strategy) in [
+            (
+                TweeningType::Once,
+                RepeatCount::Finite(1),
+                RepeatStrategy::Repeat,
+            ),
+            (
+                TweeningType::Loop,
+                RepeatCount::Infinite,
+                RepeatStrategy::Repeat,
+            ),
+            (
+                TweeningType::PingPong,
+                RepeatCount::Infinite,
+                RepeatStrategy::MirroredRepeat,
+            ),
+        ] {
+            let pair: (RepeatCount, RepeatStrategy) = tweening_type.into();
+            assert_eq!(pair, (count, strategy));
+
+            // Same behavior as the equivalent repeat count and strategy
+            let make_tween = || {
+                Tween::new(
+                    EaseFunction::QuadraticIn,
+                    Duration::from_secs(1),
+                    DummyLens { start: 0., end: 1. },
+                )
+            };
+            let legacy = make_tween().with_repeat(tweening_type);
+            let tween = make_tween()
+                .with_repeat_count(count)
+                .with_repeat_strategy(strategy);
+            assert_eq!(legacy.total_duration(), tween.total_duration());
+            for millis in [0, 300, 1000, 1300, 2000, 2700] {
+                let elapsed = Duration::from_millis(millis);
+                let mut a = DummyComponent::default();
+                let mut b = DummyComponent::default();
+                assert_eq!(
+                    legacy.sample(elapsed, &mut a),
+                    tween.sample(elapsed, &mut b)
+                );
+                assert_eq!(a.value, b.value);
+            }
+        }
+
+        let spec: RepeatSpec = RepeatCount::Finite(3).into();
+        assert_eq!(spec.strategy, RepeatStrategy::Repeat);
+    }
+
     #[test]
     fn tweening_direction() {
         let tweening_direction = TweeningDirection::default();
@@ -638,6 +2463,53 @@ mod tests {
         assert_eq!(state, AnimatorState::Playing);
     }
 
+    #[test]
+    fn ease_method_names() {
+        // Round trip over all the presets
+        assert_eq!(EaseMethod::presets().count(), 31);
+        for (name, ease) in EaseMethod::presets() {
+            assert_eq!(ease.name(), Some(name));
+            let parsed = EaseMethod::from_name(name).unwrap();
+            assert_eq!(parsed.name(), Some(name));
+            assert_eq!(
+                EaseMethod::from_name(&name.to_uppercase()).unwrap().name(),
+                Some(name)
+            );
+        }
+
+        for (alias, name) in [
+            ("quadInOut", "quadraticInOut"),
+            ("QUAD_IN", "quadraticIn"),
+            ("cubic-in-out", "cubicInOut"),
+            ("expoOut", "exponentialOut"),
+            ("circIn", "circularIn"),
+            ("  Linear ", "linear"),
+            ("back_out", "backOut"),
+        ] {
+            assert_eq!(EaseMethod::from_name(alias).unwrap().name(), Some(name));
+        }
+
+        let Some(EaseMethod::Discrete(limit)) = EaseMethod::from_name("Discrete( 0.25 )") else {
+            panic!("expected a discrete easing");
+        };
+        assert_eq!(limit, 0.25);
+        assert!(EaseMethod::Discrete(0.25).name().is_none());
+        assert!(EaseMethod::CustomFunction(|x| x).name().is_none());
+
+        for unknown in [
+            "",
+            "quad",
+            "wobbly",
+            "quadraticInOutOut",
+            "discrete()",
+            "discrete(nan)",
+            "discrete(0.5",
+            "cubic-bezier(0.4,0,0.2,1)",
+        ] {
+            assert!(EaseMethod::from_name(unknown).is_none(), "{unknown}");
+        }
+    }
+
     #[test]
     fn ease_method() {
         let ease = EaseMethod::default();
@@ -733,6 +2605,49 @@ mod tests {
         assert_approx_eq!(animator.tweenable().progress(), 0.);
     }
 
+    #[test]
+    fn animator_time_remaining() {
+        let make_tween = || {
+            Tween::<DummyComponent>::new(
+                EaseFunction::QuadraticInOut,
+                Duration::from_secs(1),
+                DummyLens { start: 0., end: 1. },
+            )
+        };
+
+        // Sequence paused mid-way through its second child
+        let mut animator = Animator::new(make_tween().then(make_tween()));
+        assert_eq!(animator.time_remaining(), Some(Duration::from_secs(2)));
+        assert_eq!(animator.fraction_remaining(), Some(1.));
+        animator
+            .tweenable_mut()
+            .set_elapsed(Duration::from_millis(1400));
+        animator.state = AnimatorState::Paused;
+        assert_eq!(animator.time_remaining(), Some(Duration::from_millis(600)));
+        assert_approx_eq!(animator.fraction_remaining().unwrap(), 0.3);
+
+        // Finite ping-pong in its final, backward, leg
+        let tween = make_tween()
+            .with_repeat_count(RepeatCount::Finite(4))
+            .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
+        let mut animator = Animator::new(tween);
+        animator
+            .tweenable_mut()
+            .set_elapsed(Duration::from_millis(3250));
+        assert_eq!(animator.time_remaining(), Some(Duration::from_millis(750)));
+        assert_approx_eq!(animator.fraction_remaining().unwrap(), 0.1875);
+        animator
+            .tweenable_mut()
+            .set_elapsed(Duration::from_secs(10));
+        assert_eq!(animator.time_remaining(), Some(Duration::ZERO));
+        assert_eq!(animator.fraction_remaining(), Some(0.));
+
+        // Infinite loop
+        let animator = Animator::new(make_tween().with_repeat_count(RepeatCount::Infinite));
+        assert_eq!(animator.time_remaining(), None);
+        assert_eq!(animator.fraction_remaining(), None);
+    }
+
     #[test]
     fn animator_speed() {
         let tween = Tween::<DummyComponent>::new(
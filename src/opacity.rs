@@ -0,0 +1,284 @@
+//! Opacity of a whole UI subtree.
+
+use bevy::{prelude::*, utils::HashSet};
+
+/// Opacity of a UI node and all its descendants.
+///
+/// Bevy UI has no notion of the opacity of a subtree, so fading a panel means
+/// animating the color of each of its nodes. Instead, a single animator can
+/// animate this component on the root of the panel with a [`UiOpacityLens`].
+/// Each frame the opacity isn't `1.`, a system added by the
+/// [`TweeningPlugin`] multiplies it into the alpha of the [`BackgroundColor`],
+/// [`BorderColor`], and [`Text`] sections of the node and its descendants,
+/// including the ones spawned in the middle of a fade. Nested opacities
+/// combine by multiplication. Images are tinted by their [`BackgroundColor`],
+/// so are faded too.
+///
+/// The original alpha of each node is recorded the first time it's faded,
+/// and restored exactly once the opacity reaches `1.` again. A color changed
+/// by something else during the fade, for example by another animator,
+/// becomes the new original alpha of that color.
+///
+/// [`UiOpacityLens`]: crate::lens::UiOpacityLens
+/// [`TweeningPlugin`]: crate::TweeningPlugin
+/// [`Text`]: bevy::text::Text
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct UiOpacity(pub f32);
+
+impl Default for UiOpacity {
+    fn default() -> Self {
+        Self(1.)
+    }
+}
+
+/// Alpha of a color faded by a [`UiOpacity`].
+#[derive(Debug, Clone, Copy)]
+struct FadedAlpha {
+    /// Alpha of the color before fading.
+    base: f32,
+    /// Alpha last written by the fade.
+    written: f32,
+}
+
+impl FadedAlpha {
+    /// Fade `color` by `factor`, recording its original alpha in `slot`.
+    fn fade(slot: Option<Self>, color: &Color, factor: f32) -> (Self, Option<f32>) {
+        let current = color.a();
+        let base = match slot {
+            Some(faded) if faded.written == current => faded.base,
+            _ => current,
+        };
+        let alpha = base * factor;
+        let faded = Self {
+            base,
+            written: alpha,
+        };
+        (faded, (alpha != current).then_some(alpha))
+    }
+
+    /// Get the original alpha to restore `color` to, if it wasn't changed by
+    /// something else since it was faded.
+    fn restore(slot: Option<Self>, color: &Color) -> Option<f32> {
+        slot.filter(|faded| faded.written == color.a() && faded.base != faded.written)
+            .map(|faded| faded.base)
+    }
+}
+
+/// Original alphas of a UI node faded by a [`UiOpacity`].
+#[derive(Debug, Default, Component)]
+pub(crate) struct UiOpacityFaded {
+    background: Option<FadedAlpha>,
+    border: Option<FadedAlpha>,
+    #[cfg(feature = "bevy_text")]
+    text: Vec<FadedAlpha>,
+}
+
+/// System applying the [`UiOpacity`] of UI nodes to their subtree.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn ui_opacity_system(
+    mut commands: Commands,
+    opacities: Query<(Entity, Ref<UiOpacity>)>,
+    parents: Query<&Parent>,
+    children: Query<&Children>,
+    mut faded: Query<&mut UiOpacityFaded>,
+    mut backgrounds: Query<&mut BackgroundColor>,
+    mut borders: Query<&mut BorderColor>,
+    #[cfg(feature = "bevy_text")] mut texts: Query<&mut Text>,
+    mut stack: Local<Vec<(Entity, f32)>>,
+) {
+    // Find the topmost opacity of each subtree needing an update: either
+    // faded, or just restored to an opacity of 1.
+    let mut roots = HashSet::new();
+    for (entity, opacity) in &opacities {
+        if opacity.0 == 1. && !opacity.is_changed() {
+            continue;
+        }
+        let root = parents
+            .iter_ancestors(entity)
+            .filter(|ancestor| opacities.contains(*ancestor))
+            .last()
+            .unwrap_or(entity);
+        roots.insert(root);
+    }
+
+    for root in roots {
+        stack.push((root, 1.));
+        while let Some((entity, parent_factor)) = stack.pop() {
+            let factor = opacities.get(entity).map_or(parent_factor, |(_, opacity)| {
+                parent_factor * opacity.0.clamp(0., 1.)
+            });
+            if let Ok(children) = children.get(entity) {
+                stack.extend(children.iter().map(|child| (*child, factor)));
+            }
+
+            let mut record = faded.get_mut(entity).ok();
+            let tracked = record.is_some();
+            if factor == 1. {
+                // Restore the original alphas, and stop tracking the node.
+                let Some(record) = record else {
+                    continue;
+                };
+                if let Ok(mut color) = backgrounds.get_mut(entity) {
+                    if let Some(alpha) = FadedAlpha::restore(record.background, &color.0) {
+                        color.0.set_a(alpha);
+                    }
+                }
+                if let Ok(mut color) = borders.get_mut(entity) {
+                    if let Some(alpha) = FadedAlpha::restore(record.border, &color.0) {
+                        color.0.set_a(alpha);
+                    }
+                }
+                #[cfg(feature = "bevy_text")]
+                if let Ok(mut text) = texts.get_mut(entity) {
+                    for (index, slot) in record.text.iter().enumerate() {
+                        let restored = text.sections.get(index).and_then(|section| {
+                            FadedAlpha::restore(Some(*slot), &section.style.color)
+                        });
+                        if let Some(alpha) = restored {
+                            text.sections[index].style.color.set_a(alpha);
+                        }
+                    }
+                }
+                commands.entity(entity).remove::<UiOpacityFaded>();
+                continue;
+            }
+
+            let mut new_record = UiOpacityFaded::default();
+            let record = match record.as_deref_mut() {
+                Some(record) => record,
+                None => &mut new_record,
+            };
+            if let Ok(mut color) = backgrounds.get_mut(entity) {
+                let (slot, alpha) = FadedAlpha::fade(record.background, &color.0, factor);
+                record.background = Some(slot);
+                if let Some(alpha) = alpha {
+                    color.0.set_a(alpha);
+                }
+            }
+            if let Ok(mut color) = borders.get_mut(entity) {
+                let (slot, alpha) = FadedAlpha::fade(record.border, &color.0, factor);
+                record.border = Some(slot);
+                if let Some(alpha) = alpha {
+                    color.0.set_a(alpha);
+                }
+            }
+            #[cfg(feature = "bevy_text")]
+            if let Ok(mut text) = texts.get_mut(entity) {
+                record.text.truncate(text.sections.len());
+                for index in 0..text.sections.len() {
+                    let color = text.sections[index].style.color;
+                    let (slot, alpha) =
+                        FadedAlpha::fade(record.text.get(index).copied(), &color, factor);
+                    match record.text.get_mut(index) {
+                        Some(recorded) => *recorded = slot,
+                        None => record.text.push(slot),
+                    }
+                    if let Some(alpha) = alpha {
+                        text.sections[index].style.color.set_a(alpha);
+                    }
+                }
+            }
+            if !tracked {
+                commands.entity(entity).insert(new_record);
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "bevy_text"))]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{lens::UiOpacityLens, Animator, EaseMethod, Tween, TweeningPlugin};
+
+    #[test]
+    fn fade_subtree() {
+        let mut app = App::new();
+        app.add_plugins(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register::<UiOpacity>(),
+        )
+        .init_resource::<Time>();
+
+        let fade = |start, end| {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                UiOpacityLens { start, end },
+            )
+        };
+        let background = Color::rgba(1., 0., 0., 0.8);
+        let border = Color::rgba(0., 1., 0., 0.6);
+        let text_colors = [Color::WHITE, Color::rgba(0.2, 0.3, 0.4, 0.5)];
+        let child = app
+            .world
+            .spawn((
+                BackgroundColor(Color::NONE),
+                BorderColor(border),
+                Text::from_sections(
+                    text_colors
+                        .map(|color| TextSection::new("a", TextStyle { color, ..default() })),
+                ),
+            ))
+            .id();
+        let root = app
+            .world
+            .spawn((
+                BackgroundColor(background),
+                UiOpacity::default(),
+                Animator::new(fade(1., 0.).then(fade(0., 1.))),
+            ))
+            .push_children(&[child])
+            .id();
+
+        let step = |app: &mut App, millis| {
+            app.world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(millis));
+            app.update();
+        };
+        let alpha = |app: &App, entity| app.world.get::<BackgroundColor>(entity).unwrap().0.a();
+
+        step(&mut app, 500);
+        assert!((alpha(&app, root) - 0.4).abs() < 1e-5);
+        let border_alpha = app.world.get::<BorderColor>(child).unwrap().0.a();
+        assert!((border_alpha - 0.3).abs() < 1e-5);
+        let text = app.world.get::<Text>(child).unwrap();
+        assert!((text.sections[0].style.color.a() - 0.5).abs() < 1e-5);
+        assert!((text.sections[1].style.color.a() - 0.25).abs() < 1e-5);
+
+        // A node added in the middle of the fade is faded too
+        let late = app.world.spawn(BackgroundColor(Color::BLUE)).id();
+        app.world.entity_mut(child).push_children(&[late]);
+        step(&mut app, 250);
+        assert!((alpha(&app, late) - 0.25).abs() < 1e-5);
+
+        // Fully transparent, then back to the exact original colors
+        step(&mut app, 250);
+        assert_eq!(alpha(&app, root), 0.);
+        assert_eq!(alpha(&app, late), 0.);
+        step(&mut app, 1000);
+        assert_eq!(app.world.get::<UiOpacity>(root).unwrap().0, 1.);
+        assert_eq!(
+            app.world.get::<BackgroundColor>(root).unwrap().0,
+            background
+        );
+        assert_eq!(
+            app.world.get::<BackgroundColor>(child).unwrap().0,
+            Color::NONE
+        );
+        assert_eq!(app.world.get::<BorderColor>(child).unwrap().0, border);
+        assert_eq!(
+            app.world.get::<BackgroundColor>(late).unwrap().0,
+            Color::BLUE
+        );
+        let text = app.world.get::<Text>(child).unwrap();
+        assert_eq!(text.sections[0].style.color, text_colors[0]);
+        assert_eq!(text.sections[1].style.color, text_colors[1]);
+        for entity in [root, child, late] {
+            assert!(app.world.get::<UiOpacityFaded>(entity).is_none());
+        }
+    }
+}
@@ -1,271 +1,3895 @@
-use bevy::prelude::*;
+use std::{
+    any::{type_name, Any, TypeId},
+    time::Duration,
+};
 
+use bevy::{
+    ecs::{
+        entity::EntityHashMap,
+        schedule::{InternedScheduleLabel, ScheduleLabel},
+    },
+    prelude::*,
+    utils::{HashSet, Instant},
+};
+
+#[cfg(feature = "diagnostics")]
+use crate::diagnostics::{AnimatorDiagnosticPaths, AnimatorSystemDiagnostics};
+use crate::{
+    actions::completed_actions_system,
+    animation_chain_system,
+    lens::AnimatedDuration,
+    recipes::{GridPosition, ShakeOffset},
+    state_binding::{apply_state_transition, state_binding_system},
+    state_machine::state_machine_system,
+    stats::tween_stats_system,
+    tuning::{tuning_system, TunableAnimator},
+    tweenable::{CoalescingTarget, ComponentTarget, ResourceTarget},
+    AnimationGroups, Animator, AnimatorState, AnimatorStateChanged, LensContext, LensRegistry,
+    ResourceAnimator, TweenCancelled, TweenCompleted, TweenState, TweenStateTransition, TweenStats,
+};
 #[cfg(feature = "bevy_asset")]
-use crate::{tweenable::AssetTarget, AssetAnimator};
-use crate::{tweenable::ComponentTarget, Animator, AnimatorState, TweenCompleted};
+use crate::{tweenable::AssetTarget, AssetAnimator, SyncedAnimator};
 
 /// Plugin to add systems related to tweening of common components and assets.
 ///
-/// This plugin adds systems for a predefined set of components and assets, to
-/// allow their respective animators to be updated each frame:
+/// By default, this plugin adds systems for a predefined set of components and
+/// assets, to allow their respective animators to be updated each frame:
 /// - [`Transform`]
+/// - [`AnimatedDuration`]
+/// - [`GridPosition`]
+/// - [`ShakeOffset`]
 /// - [`Text`]
 /// - [`Style`]
+/// - [`ZIndex`]
+/// - [`UiOpacity`]
+/// - [`Window`]
 /// - [`Sprite`]
 /// - [`ColorMaterial`]
+/// - [`StandardMaterial`]
+/// - [`AmbientLight`] (resource)
+/// - [`FogSettings`]
+/// - [`Exposure`]
+/// - [`DirectionalLight`]
+///
+/// Each of those except [`Transform`], [`AnimatedDuration`], [`GridPosition`],
+/// and [`ShakeOffset`] is only registered if the cargo feature of the Bevy
+/// crate it belongs to is enabled (`bevy_text`, `bevy_ui`, `bevy_window`,
+/// `bevy_sprite`, `bevy_pbr`).
 ///
 /// This ensures that all predefined lenses work as intended, as well as any
 /// custom lens animating the same component or asset type.
 ///
 /// For other components and assets, including custom ones, the relevant system
-/// needs to be added manually by the application:
+/// needs to be registered, either with [`register()`], [`register_asset()`],
+/// and [`register_resource()`], or manually by the application:
 /// - For components, add [`component_animator_system::<T>`] where `T:
 ///   Component`
 /// - For assets, add [`asset_animator_system::<T>`] where `T: Asset`
+/// - For resources, add [`resource_animator_system::<T>`] where `T: Resource`
+/// - For a [`SyncedAnimator`], use [`register_synced()`] or add
+///   [`synced_animator_system::<C, A>`] where `C: Component` and `A: Asset`
+///
+/// # Configuration
+///
+/// The plugin exposes a builder-style configuration. The animator systems run
+/// in the [`Update`] schedule unless configured otherwise with
+/// [`with_schedule()`]. Calling [`without_defaults()`] opts out of the
+/// predefined set of target types above, to avoid the cost of systems for types
+/// never animated by the application; the target types actually animated then
+/// need to be registered explicitly.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::*;
+/// App::new().add_plugins(
+///     TweeningPlugin::default()
+///         .with_schedule(PostUpdate)
+///         .without_defaults()
+///         .register::<Transform>(),
+/// );
+/// ```
+///
+/// Registering a target type more than once, or registering a type already
+/// part of the predefined set, is allowed and only adds a single system for
+/// that type. The plugin can also be added more than once to the same app,
+/// for example by several plugins depending on this crate. The events,
+/// resources, and shared systems are added by the first instance, and each
+/// instance only adds the animator systems of the target types not registered
+/// yet, in its own schedule. The [`with_deterministic_order()`],
+/// [`with_idle_cleanup()`], and [`with_budget()`] options of any instance
+/// apply to the whole app.
+/// Plugins depending on this crate can also register their target types with
+/// [`TweeningAppExt`].
 ///
 /// This plugin is entirely optional. If you want more control, you can instead
 /// add manually the relevant systems for the exact set of components and assets
 /// actually animated.
 ///
+/// [`register()`]: TweeningPlugin::register
+/// [`register_asset()`]: TweeningPlugin::register_asset
+/// [`register_resource()`]: TweeningPlugin::register_resource
+/// [`register_synced()`]: TweeningPlugin::register_synced
+/// [`SyncedAnimator`]: crate::SyncedAnimator
+/// [`synced_animator_system::<C, A>`]: synced_animator_system
+/// [`with_schedule()`]: TweeningPlugin::with_schedule
+/// [`without_defaults()`]: TweeningPlugin::without_defaults
+/// [`with_deterministic_order()`]: TweeningPlugin::with_deterministic_order
+/// [`with_idle_cleanup()`]: TweeningPlugin::with_idle_cleanup
+/// [`with_budget()`]: TweeningPlugin::with_budget
 /// [`Transform`]: https://docs.rs/bevy/0.12.0/bevy/transform/components/struct.Transform.html
 /// [`Text`]: https://docs.rs/bevy/0.12.0/bevy/text/struct.Text.html
 /// [`Style`]: https://docs.rs/bevy/0.12.0/bevy/ui/struct.Style.html
+/// [`ZIndex`]: https://docs.rs/bevy/0.13.0/bevy/ui/enum.ZIndex.html
+/// [`UiOpacity`]: crate::UiOpacity
+/// [`Window`]: https://docs.rs/bevy/0.13.0/bevy/window/struct.Window.html
 /// [`Sprite`]: https://docs.rs/bevy/0.12.0/bevy/sprite/struct.Sprite.html
 /// [`ColorMaterial`]: https://docs.rs/bevy/0.12.0/bevy/sprite/struct.ColorMaterial.html
-#[derive(Debug, Clone, Copy)]
-pub struct TweeningPlugin;
-
-impl Plugin for TweeningPlugin {
-    fn build(&self, app: &mut App) {
-        app.add_event::<TweenCompleted>().add_systems(
-            Update,
-            component_animator_system::<Transform>.in_set(AnimationSystem::AnimationUpdate),
-        );
+/// [`StandardMaterial`]: https://docs.rs/bevy/0.13.0/bevy/pbr/struct.StandardMaterial.html
+/// [`AmbientLight`]: https://docs.rs/bevy/0.13.0/bevy/pbr/struct.AmbientLight.html
+/// [`FogSettings`]: https://docs.rs/bevy/0.13.0/bevy/pbr/struct.FogSettings.html
+/// [`Exposure`]: https://docs.rs/bevy/0.13.0/bevy/render/camera/struct.Exposure.html
+/// [`DirectionalLight`]: https://docs.rs/bevy/0.13.0/bevy/pbr/struct.DirectionalLight.html
+#[derive(Debug, Clone)]
+pub struct TweeningPlugin {
+    schedule: InternedScheduleLabel,
+    defaults: bool,
+    order: AnimationOrder,
+    idle_cleanup: Option<IdleAnimatorCleanup>,
+    budget: Option<AnimationBudget>,
+    coalesce_writes: bool,
+    stats: bool,
+    targets: Vec<Target>,
+    states: Vec<BoundStateType>,
+}
 
-        #[cfg(feature = "bevy_ui")]
-        app.add_systems(
-            Update,
-            component_animator_system::<Style>.in_set(AnimationSystem::AnimationUpdate),
-        );
-        #[cfg(feature = "bevy_ui")]
-        app.add_systems(
-            Update,
-            component_animator_system::<BackgroundColor>.in_set(AnimationSystem::AnimationUpdate),
-        );
+/// A target type registered with the [`TweeningPlugin`].
+///
+/// Targets are identified by the type of their animator component, so that the
+/// same type can be registered as, for example, both a component and a
+/// resource.
+#[derive(Debug, Clone, Copy)]
+struct Target {
+    animator: TypeId,
+    /// Type name of the animator, identifying the target in logs and
+    /// diagnostics.
+    name: &'static str,
+    add_system: fn(&mut App, InternedScheduleLabel),
+    add_cleanup_system: fn(&mut App, InternedScheduleLabel),
+    apply_state_transition: StateTransitionFn,
+}
 
-        #[cfg(feature = "bevy_sprite")]
-        app.add_systems(
-            Update,
-            component_animator_system::<Sprite>.in_set(AnimationSystem::AnimationUpdate),
-        );
+/// Apply the transition of a state from the exited to the entered value to
+/// the animators of a target type.
+pub(crate) type StateTransitionFn = fn(&mut World, &dyn Any, &dyn Any);
 
-        #[cfg(all(feature = "bevy_sprite", feature = "bevy_asset"))]
-        app.add_systems(
-            Update,
-            asset_animator_system::<ColorMaterial>.in_set(AnimationSystem::AnimationUpdate),
-        );
+/// A [`States`] type registered with the [`TweeningPlugin`], whose
+/// transitions are applied to the animators bound to one of its values.
+#[derive(Debug, Clone, Copy)]
+struct BoundStateType {
+    state: TypeId,
+    add_system: fn(&mut App, InternedScheduleLabel),
+}
 
-        #[cfg(feature = "bevy_text")]
-        app.add_systems(
-            Update,
-            component_animator_system::<Text>.in_set(AnimationSystem::AnimationUpdate),
-        );
+impl BoundStateType {
+    fn new<S: States>() -> Self {
+        Self {
+            state: TypeId::of::<S>(),
+            add_system: |app, schedule| {
+                app.add_systems(
+                    schedule,
+                    state_binding_system::<S>.before(AnimationSystem::AnimationUpdate),
+                );
+            },
+        }
     }
 }
 
-/// Label enum for the systems relating to animations
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, SystemSet)]
-pub enum AnimationSystem {
-    /// Ticks animations
-    AnimationUpdate,
+/// Update the [`LensContext`] of the [`Transform`] animators with the
+/// [`GlobalTransform`] of the parent of their entity, if any.
+fn transform_lens_context_system(
+    mut animators: Query<(&mut Animator<Transform>, Option<&Parent>)>,
+    parents: Query<&GlobalTransform>,
+) {
+    for (mut animator, parent) in &mut animators {
+        let context = LensContext {
+            parent: parent.and_then(|parent| parents.get(parent.get()).ok().copied()),
+        };
+        if animator.lens_context() != context {
+            animator.bypass_change_detection().set_lens_context(context);
+        }
+    }
 }
 
-/// Animator system for components.
+/// Add the [`tuning_system()`] of the animator `A`, applying the
+/// [`TweenTuning`] before the animators tick.
 ///
-/// This system extracts all components of type `T` with an [`Animator<T>`]
-/// attached to the same entity, and tick the animator to animate the component.
-pub fn component_animator_system<T: Component>(
-    time: Res<Time>,
-    mut query: Query<(Entity, &mut T, &mut Animator<T>)>,
-    events: ResMut<Events<TweenCompleted>>,
+/// [`TweenTuning`]: crate::TweenTuning
+fn add_tuning_system<A: TunableAnimator>(app: &mut App, schedule: InternedScheduleLabel) {
+    app.add_systems(
+        schedule,
+        tuning_system::<A>.before(AnimationSystem::AnimationUpdate),
+    );
+}
+
+/// Add the [`cancellation_system()`] of the animator `A`.
+fn add_cancellation_system<A: CompletionTracking>(app: &mut App, schedule: InternedScheduleLabel) {
+    app.add_systems(
+        schedule,
+        cancellation_system::<A>.after(AnimationSystem::AnimationUpdate),
+    );
+}
+
+/// Add the [`completed_actions_system()`] of the animator `A`.
+fn add_completed_actions_system<A: CompletionTracking>(
+    app: &mut App,
+    schedule: InternedScheduleLabel,
 ) {
-    let mut events: Mut<Events<TweenCompleted>> = events.into();
-    for (entity, target, mut animator) in query.iter_mut() {
-        if animator.state != AnimatorState::Paused {
-            let speed = animator.speed();
-            let mut target = ComponentTarget::new(target);
-            animator.tweenable_mut().tick(
-                time.delta().mul_f32(speed),
-                &mut target,
-                entity,
-                &mut events,
-            );
+    app.add_systems(
+        schedule,
+        completed_actions_system::<A>.after(AnimationSystem::AnimationUpdate),
+    );
+}
+
+/// Add the [`idle_cleanup_system()`] of the animator `A`.
+fn add_cleanup_system<A: CompletionTracking>(app: &mut App, schedule: InternedScheduleLabel) {
+    app.add_systems(
+        schedule,
+        idle_cleanup_system::<A>.after(AnimationSystem::AnimationUpdate),
+    );
+}
+
+impl Target {
+    fn component<T: Component>() -> Self {
+        Self {
+            animator: TypeId::of::<Animator<T>>(),
+            name: type_name::<Animator<T>>(),
+            add_system: |app, schedule| {
+                app.add_systems(
+                    schedule,
+                    component_animator_system::<T>.in_set(AnimationSystem::AnimationUpdate),
+                );
+                app.add_systems(
+                    schedule,
+                    state_machine_system::<T>.before(AnimationSystem::AnimationUpdate),
+                );
+                if TypeId::of::<T>() == TypeId::of::<Transform>() {
+                    app.add_systems(
+                        schedule,
+                        transform_lens_context_system.before(AnimationSystem::AnimationUpdate),
+                    );
+                }
+                add_tuning_system::<Animator<T>>(app, schedule);
+                add_cancellation_system::<Animator<T>>(app, schedule);
+                add_completed_actions_system::<Animator<T>>(app, schedule);
+            },
+            add_cleanup_system: add_cleanup_system::<Animator<T>>,
+            apply_state_transition: apply_state_transition::<Animator<T>>,
+        }
+    }
+
+    #[cfg(feature = "bevy_asset")]
+    fn asset<T: Asset>() -> Self {
+        Self {
+            animator: TypeId::of::<AssetAnimator<T>>(),
+            name: type_name::<AssetAnimator<T>>(),
+            add_system: |app, schedule| {
+                app.add_systems(
+                    schedule,
+                    asset_animator_system::<T>.in_set(AnimationSystem::AnimationUpdate),
+                );
+                add_tuning_system::<AssetAnimator<T>>(app, schedule);
+                add_cancellation_system::<AssetAnimator<T>>(app, schedule);
+                add_completed_actions_system::<AssetAnimator<T>>(app, schedule);
+            },
+            add_cleanup_system: add_cleanup_system::<AssetAnimator<T>>,
+            apply_state_transition: apply_state_transition::<AssetAnimator<T>>,
+        }
+    }
+
+    #[cfg(feature = "bevy_asset")]
+    fn synced<C: Component, A: Asset>() -> Self {
+        Self {
+            animator: TypeId::of::<SyncedAnimator<C, A>>(),
+            name: type_name::<SyncedAnimator<C, A>>(),
+            add_system: |app, schedule| {
+                app.add_systems(
+                    schedule,
+                    synced_animator_system::<C, A>.in_set(AnimationSystem::AnimationUpdate),
+                );
+                add_tuning_system::<SyncedAnimator<C, A>>(app, schedule);
+                add_cancellation_system::<SyncedAnimator<C, A>>(app, schedule);
+                add_completed_actions_system::<SyncedAnimator<C, A>>(app, schedule);
+            },
+            add_cleanup_system: add_cleanup_system::<SyncedAnimator<C, A>>,
+            apply_state_transition: apply_state_transition::<SyncedAnimator<C, A>>,
+        }
+    }
+
+    fn resource<T: Resource>() -> Self {
+        Self {
+            animator: TypeId::of::<ResourceAnimator<T>>(),
+            name: type_name::<ResourceAnimator<T>>(),
+            add_system: |app, schedule| {
+                app.add_systems(
+                    schedule,
+                    resource_animator_system::<T>.in_set(AnimationSystem::AnimationUpdate),
+                );
+                add_tuning_system::<ResourceAnimator<T>>(app, schedule);
+                add_cancellation_system::<ResourceAnimator<T>>(app, schedule);
+                add_completed_actions_system::<ResourceAnimator<T>>(app, schedule);
+            },
+            add_cleanup_system: add_cleanup_system::<ResourceAnimator<T>>,
+            apply_state_transition: apply_state_transition::<ResourceAnimator<T>>,
         }
     }
 }
 
-/// Animator system for assets.
-///
-/// This system ticks all [`AssetAnimator<T>`] components to animate their
-/// associated asset.
-///
-/// This requires the `bevy_asset` feature (enabled by default).
-#[cfg(feature = "bevy_asset")]
-pub fn asset_animator_system<T: Asset>(
-    time: Res<Time>,
-    assets: ResMut<Assets<T>>,
-    mut query: Query<(Entity, &Handle<T>, &mut AssetAnimator<T>)>,
-    events: ResMut<Events<TweenCompleted>>,
-) {
-    let mut events: Mut<Events<TweenCompleted>> = events.into();
-    let mut target = AssetTarget::new(assets);
-    for (entity, handle, mut animator) in query.iter_mut() {
-        if animator.state != AnimatorState::Paused {
-            target.handle = handle.clone();
-            if !target.is_valid() {
-                continue;
-            }
-            let speed = animator.speed();
-            animator.tweenable_mut().tick(
-                time.delta().mul_f32(speed),
-                &mut target,
-                entity,
-                &mut events,
-            );
+impl Default for TweeningPlugin {
+    fn default() -> Self {
+        Self {
+            schedule: Update.intern(),
+            defaults: true,
+            order: AnimationOrder::Unspecified,
+            idle_cleanup: None,
+            budget: None,
+            coalesce_writes: false,
+            stats: false,
+            targets: vec![],
+            states: vec![],
         }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::{lens::TransformPositionLens, *};
+impl TweeningPlugin {
+    /// Set the schedule the animator systems run in. Defaults to [`Update`].
+    #[must_use]
+    pub fn with_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = schedule.intern();
+        self
+    }
 
-    /// A simple isolated test environment with a [`World`] and a single
-    /// [`Entity`] in it.
-    struct TestEnv {
-        world: World,
-        entity: Entity,
+    /// Don't register the animator systems for the predefined set of target
+    /// types.
+    ///
+    /// Only the target types explicitly registered are animated.
+    #[must_use]
+    pub fn without_defaults(mut self) -> Self {
+        self.defaults = false;
+        self
     }
 
-    impl TestEnv {
-        /// Create a new test environment containing a single entity with a
-        /// [`Transform`], and add the given animator on that same entity.
-        pub fn new<T: Component>(animator: T) -> Self {
-            let mut world = World::new();
-            world.init_resource::<Events<TweenCompleted>>();
-            world.init_resource::<Time>();
+    /// Tick the animators in a deterministic order.
+    ///
+    /// By default the animators are ticked in query iteration order, which
+    /// can change across runs. With this option, each animator system ticks
+    /// its animators sorted by entity, so the events and callbacks they
+    /// produce are deterministic. This has a small per-frame cost. See
+    /// [`AnimationOrder::SortedByEntity`] for the exact guarantee.
+    #[must_use]
+    pub fn with_deterministic_order(mut self) -> Self {
+        self.order = AnimationOrder::SortedByEntity;
+        self
+    }
 
-            let entity = world.spawn((Transform::default(), animator)).id();
+    /// Clean up the animators whose tweenable has been completed for longer
+    /// than `threshold`, according to `policy`.
+    ///
+    /// This inserts an [`IdleAnimatorCleanup`] resource, and adds a
+    /// maintenance system for each registered target type, running after the
+    /// animator systems. See [`IdleAnimatorCleanup`] for details. Disabled by
+    /// default.
+    #[must_use]
+    pub fn with_idle_cleanup(mut self, threshold: Duration, policy: CleanupPolicy) -> Self {
+        self.idle_cleanup = Some(IdleAnimatorCleanup { threshold, policy });
+        self
+    }
 
-            Self { world, entity }
-        }
+    /// Cap the number of animators ticked each frame by each animator system.
+    ///
+    /// This inserts an [`AnimationBudget`] resource; see its documentation for
+    /// details. Disabled by default, in which case all animators are ticked
+    /// each frame.
+    #[must_use]
+    pub fn with_budget(mut self, budget: AnimationBudget) -> Self {
+        self.budget = Some(budget);
+        self
+    }
 
-        /// Get the test world.
-        pub fn world_mut(&mut self) -> &mut World {
-            &mut self.world
-        }
+    /// Coalesce the writes of the animators of [`Transform`] and of the
+    /// default color targets.
+    ///
+    /// This inserts a [`WriteCoalescing`] resource for [`Transform`], and with
+    /// their respective features for [`BackgroundColor`], [`Sprite`], and
+    /// [`Text`]; see its documentation for details. Disabled by default, in
+    /// which case the animators write their component each time a lens
+    /// accesses it.
+    ///
+    /// [`BackgroundColor`]: https://docs.rs/bevy/0.13.0/bevy/ui/struct.BackgroundColor.html
+    #[must_use]
+    pub fn with_write_coalescing(mut self) -> Self {
+        self.coalesce_writes = true;
+        self
+    }
 
-        /// Tick the test environment, updating the simulation time and ticking
-        /// the given system.
-        pub fn tick(&mut self, duration: Duration, system: &mut dyn System<In = (), Out = ()>) {
-            // Simulate time passing by updating the simulation time resource
-            {
-                let mut time = self.world.resource_mut::<Time>();
-                time.advance_by(duration);
-            }
+    /// Aggregate the completed and cancelled events per user data into the
+    /// [`TweenStats`] resource.
+    ///
+    /// Disabled by default, in which case the resource isn't inserted and no
+    /// system reads the events for it.
+    #[must_use]
+    pub fn with_stats(mut self) -> Self {
+        self.stats = true;
+        self
+    }
 
-            // Reset world-related change detection
-            self.world.clear_trackers();
-            assert!(!self.transform().is_changed());
+    /// Register the animator system for the component `T`.
+    #[must_use]
+    pub fn register<T: Component>(mut self) -> Self {
+        self.targets.push(Target::component::<T>());
+        self
+    }
 
-            // Tick system
-            system.run((), &mut self.world);
+    /// Register the animator system for the asset `T`.
+    ///
+    /// This requires the `bevy_asset` feature (enabled by default).
+    #[cfg(feature = "bevy_asset")]
+    #[must_use]
+    pub fn register_asset<T: Asset>(mut self) -> Self {
+        self.targets.push(Target::asset::<T>());
+        self
+    }
 
-            // Update events after system ticked, in case system emitted some events
-            let mut events = self.world.resource_mut::<Events<TweenCompleted>>();
-            events.update();
-        }
+    /// Register the animator system for a [`SyncedAnimator`] animating the
+    /// component `C` and the asset `A`.
+    ///
+    /// This requires the `bevy_asset` feature (enabled by default).
+    #[cfg(feature = "bevy_asset")]
+    #[must_use]
+    pub fn register_synced<C: Component, A: Asset>(mut self) -> Self {
+        self.targets.push(Target::synced::<C, A>());
+        self
+    }
 
-        /// Get the animator for the transform.
-        pub fn animator(&self) -> &Animator<Transform> {
-            self.world
-                .entity(self.entity)
-                .get::<Animator<Transform>>()
-                .unwrap()
-        }
+    /// Register the animator system for the resource `T`.
+    #[must_use]
+    pub fn register_resource<T: Resource>(mut self) -> Self {
+        self.targets.push(Target::resource::<T>());
+        self
+    }
 
-        /// Get the transform component.
-        pub fn transform(&mut self) -> Mut<Transform> {
-            self.world.get_mut::<Transform>(self.entity).unwrap()
+    /// Apply the transitions of the [`States`] type `S` to the animators
+    /// bound to one of its values.
+    ///
+    /// The animators of all the registered target types are bound with
+    /// [`Animator::bound_to_state()`] and its equivalent on the other animator
+    /// types. The state itself is not added to the app; see
+    /// [`App::init_state()`].
+    #[must_use]
+    pub fn register_state<S: States>(mut self) -> Self {
+        self.states.push(BoundStateType::new::<S>());
+        self
+    }
+
+    /// The predefined set of target types registered by default.
+    fn default_targets() -> Vec<Target> {
+        #[allow(unused_mut)]
+        let mut targets = vec![
+            Target::component::<Transform>(),
+            Target::component::<AnimatedDuration>(),
+            Target::component::<GridPosition>(),
+            Target::component::<ShakeOffset>(),
+        ];
+
+        #[cfg(feature = "bevy_ui")]
+        targets.extend([
+            Target::component::<Style>(),
+            Target::component::<BackgroundColor>(),
+            Target::component::<ZIndex>(),
+            Target::component::<crate::UiOpacity>(),
+        ]);
+
+        #[cfg(feature = "bevy_window")]
+        targets.push(Target::component::<Window>());
+
+        #[cfg(feature = "bevy_sprite")]
+        targets.push(Target::component::<Sprite>());
+
+        #[cfg(all(feature = "bevy_sprite", feature = "bevy_asset"))]
+        targets.push(Target::asset::<ColorMaterial>());
+
+        #[cfg(feature = "bevy_text")]
+        targets.push(Target::component::<Text>());
+
+        #[cfg(feature = "bevy_pbr")]
+        targets.extend([
+            Target::asset::<StandardMaterial>(),
+            Target::resource::<AmbientLight>(),
+            Target::component::<FogSettings>(),
+            Target::component::<bevy::render::camera::Exposure>(),
+            Target::component::<DirectionalLight>(),
+        ]);
+
+        targets
+    }
+}
+
+impl Plugin for TweeningPlugin {
+    fn build(&self, app: &mut App) {
+        build_core(app, self.schedule);
+
+        // Only override the defaults set by another instance of the plugin
+        if self.order != AnimationOrder::Unspecified {
+            app.insert_resource(self.order);
+        }
+        if let Some(budget) = self.budget {
+            app.insert_resource(budget);
+        }
+        if self.coalesce_writes {
+            app.insert_resource(WriteCoalescing::<Transform>::new());
+            #[cfg(feature = "bevy_ui")]
+            app.insert_resource(WriteCoalescing::<BackgroundColor>::without_comparison());
+            #[cfg(feature = "bevy_sprite")]
+            app.insert_resource(WriteCoalescing::<Sprite>::without_comparison());
+            #[cfg(feature = "bevy_text")]
+            app.insert_resource(WriteCoalescing::<Text>::without_comparison());
+        }
+        if self.stats && !app.world.contains_resource::<TweenStats>() {
+            app.init_resource::<TweenStats>()
+                .add_systems(Last, tween_stats_system);
+        }
+        if let Some(idle_cleanup) = self.idle_cleanup {
+            app.insert_resource(idle_cleanup);
+            let targets = app.world.resource::<RegisteredTargets>().targets.clone();
+            for target in &targets {
+                register_cleanup(app, target);
+            }
         }
 
-        /// Get the emitted event count since last tick.
-        pub fn event_count(&self) -> usize {
-            let events = self.world.resource::<Events<TweenCompleted>>();
-            events.get_reader().len(events)
+        let defaults = if self.defaults {
+            Self::default_targets()
+        } else {
+            vec![]
+        };
+        for target in defaults.iter().chain(self.targets.iter()) {
+            register_target(app, target, self.schedule);
+        }
+        for state in &self.states {
+            register_state(app, state);
+        }
+        #[cfg(feature = "bevy_scene")]
+        if self.defaults {
+            register_default_animator_defs(app, self.schedule);
         }
     }
 
-    #[test]
-    fn change_detect_component() {
-        let tween = Tween::new(
-            EaseMethod::Linear,
-            Duration::from_secs(1),
-            TransformPositionLens {
-                start: Vec3::ZERO,
-                end: Vec3::ONE,
-            },
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
+
+/// Target types whose animator system was added to an app.
+///
+/// This is shared by all the instances of the [`TweeningPlugin`] added to the
+/// app and by the [`TweeningAppExt`] methods, so that each animator system is
+/// only added once.
+#[derive(Resource)]
+struct RegisteredTargets {
+    /// Schedule of the first instance of the plugin.
+    schedule: InternedScheduleLabel,
+    targets: Vec<Target>,
+    /// Animators whose idle cleanup system was added.
+    cleanup: HashSet<TypeId>,
+    /// State types whose binding system was added.
+    states: HashSet<TypeId>,
+    /// Animator definitions whose hydration system was added.
+    #[cfg(feature = "bevy_scene")]
+    scene_defs: HashSet<TypeId>,
+}
+
+/// Add the events, resources, and systems shared by all target types, unless
+/// already added.
+fn build_core(app: &mut App, schedule: InternedScheduleLabel) {
+    if app.world.contains_resource::<RegisteredTargets>() {
+        return;
+    }
+    app.add_event::<TweenCompleted>()
+        .add_event::<TweenCancelled>()
+        .add_event::<AnimatorStateChanged>()
+        .add_event::<TweenStateTransition>()
+        .init_resource::<AnimationGroups>()
+        .init_resource::<AnimationOrder>()
+        .insert_resource(RegisteredTargets {
+            schedule,
+            targets: vec![],
+            cleanup: HashSet::new(),
+            states: HashSet::new(),
+            #[cfg(feature = "bevy_scene")]
+            scene_defs: HashSet::new(),
+        });
+    if !app.world.contains_resource::<LensRegistry>() {
+        app.insert_resource(LensRegistry::with_builtins());
+    }
+    app.add_systems(
+        schedule,
+        animation_chain_system.after(AnimationSystem::AnimationUpdate),
+    );
+    #[cfg(feature = "bevy_ui")]
+    app.add_systems(
+        schedule,
+        (
+            crate::transitions::screen_fade_system,
+            crate::opacity::ui_opacity_system,
         )
-        .with_completed_event(0);
+            .after(AnimationSystem::AnimationUpdate),
+    );
+}
 
-        let mut env = TestEnv::new(Animator::new(tween));
+/// Add the animator system of `target` to `schedule`, unless already added.
+fn register_target(app: &mut App, target: &Target, schedule: InternedScheduleLabel) {
+    let mut registry = app.world.resource_mut::<RegisteredTargets>();
+    if registry
+        .targets
+        .iter()
+        .any(|registered| registered.animator == target.animator)
+    {
+        debug!(
+            "Animator system for {} already registered, skipping.",
+            target.name
+        );
+        return;
+    }
+    registry.targets.push(*target);
+    (target.add_system)(app, schedule);
+    #[cfg(feature = "diagnostics")]
+    AnimatorDiagnosticPaths::from_type_name(target.name).register(app);
+    if app.world.contains_resource::<IdleAnimatorCleanup>() {
+        register_cleanup(app, target);
+    }
+}
 
-        // After being inserted, components are always considered changed
-        let transform = env.transform();
-        assert!(transform.is_changed());
+/// Register the [`AnimatorDef<T>`] type and add its hydration system, unless
+/// already added.
+///
+/// [`AnimatorDef<T>`]: crate::AnimatorDef
+#[cfg(feature = "bevy_scene")]
+fn register_animator_def<T: Component + TypePath>(app: &mut App, schedule: InternedScheduleLabel) {
+    let mut registry = app.world.resource_mut::<RegisteredTargets>();
+    if !registry
+        .scene_defs
+        .insert(TypeId::of::<crate::AnimatorDef<T>>())
+    {
+        return;
+    }
+    // Types of the fields, not registered automatically with the component
+    app.register_type::<crate::AnimatorDef<T>>()
+        .register_type::<crate::TweenDef>()
+        .register_type::<crate::RepeatCount>()
+        .register_type::<crate::RepeatStrategy>()
+        .register_type::<Option<crate::RepeatStrategy>>()
+        .register_type::<Option<u64>>()
+        .register_type::<Option<Entity>>()
+        .register_type::<Duration>()
+        .add_systems(
+            schedule,
+            crate::animator_def_system::<T>.before(AnimationSystem::AnimationUpdate),
+        );
+}
 
-        // fn nit() {}
-        // let mut system = IntoSystem::into_system(nit);
-        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
-        system.initialize(env.world_mut());
+/// Register the [`AnimatorDef<T>`] of the Bevy component types animated by
+/// default.
+///
+/// [`AnimatorDef<T>`]: crate::AnimatorDef
+#[cfg(feature = "bevy_scene")]
+fn register_default_animator_defs(app: &mut App, schedule: InternedScheduleLabel) {
+    register_animator_def::<Transform>(app, schedule);
 
-        env.tick(Duration::ZERO, &mut system);
+    #[cfg(feature = "bevy_ui")]
+    {
+        register_animator_def::<Style>(app, schedule);
+        register_animator_def::<BackgroundColor>(app, schedule);
+        register_animator_def::<ZIndex>(app, schedule);
+    }
 
-        let animator = env.animator();
-        assert_eq!(animator.state, AnimatorState::Playing);
-        assert_eq!(animator.tweenable().times_completed(), 0);
-        let transform = env.transform();
-        assert!(transform.is_changed());
-        assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
+    #[cfg(feature = "bevy_sprite")]
+    register_animator_def::<Sprite>(app, schedule);
 
-        env.tick(Duration::from_millis(500), &mut system);
+    #[cfg(feature = "bevy_text")]
+    register_animator_def::<Text>(app, schedule);
+}
 
-        assert_eq!(env.event_count(), 0);
-        let animator = env.animator();
-        assert_eq!(animator.state, AnimatorState::Playing);
-        assert_eq!(animator.tweenable().times_completed(), 0);
-        let transform = env.transform();
-        assert!(transform.is_changed());
-        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.5), 1e-5));
+/// Add the binding system of `state`, unless already added.
+fn register_state(app: &mut App, state: &BoundStateType) {
+    let mut registry = app.world.resource_mut::<RegisteredTargets>();
+    if registry.states.insert(state.state) {
+        let schedule = registry.schedule;
+        (state.add_system)(app, schedule);
+    }
+}
 
-        env.tick(Duration::from_millis(500), &mut system);
+/// The functions applying a state transition to the animators of each
+/// registered target type.
+pub(crate) fn registered_state_transitions(world: &World) -> Vec<StateTransitionFn> {
+    world
+        .get_resource::<RegisteredTargets>()
+        .map(|registry| {
+            registry
+                .targets
+                .iter()
+                .map(|target| target.apply_state_transition)
+                .collect()
+        })
+        .unwrap_or_default()
+}
 
-        assert_eq!(env.event_count(), 1);
-        let animator = env.animator();
-        assert_eq!(animator.state, AnimatorState::Playing);
-        assert_eq!(animator.tweenable().times_completed(), 1);
-        let transform = env.transform();
-        assert!(transform.is_changed());
-        assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+/// Add the idle cleanup system of `target`, unless already added.
+fn register_cleanup(app: &mut App, target: &Target) {
+    let mut registry = app.world.resource_mut::<RegisteredTargets>();
+    if registry.cleanup.insert(target.animator) {
+        let schedule = registry.schedule;
+        (target.add_cleanup_system)(app, schedule);
+    }
+}
 
-        env.tick(Duration::from_millis(100), &mut system);
+/// Extension trait for [`App`] to register target types to animate.
+///
+/// This registers the animator system of a target type, like the registration
+/// methods of the [`TweeningPlugin`], but can be called by plugins depending
+/// on this crate, after the [`TweeningPlugin`] was added by the application or
+/// by another plugin. The animator systems run in the schedule of the first
+/// [`TweeningPlugin`] added to the app. If none was added yet, the events and
+/// resources of the crate are set up with the animator systems running in
+/// [`Update`].
+///
+/// Each animator system is only added once to an app, however many times its
+/// target type is registered by the plugins or by this trait; extra
+/// registrations are ignored, and logged at the debug level.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::*;
+/// #[derive(Component)]
+/// struct Health(f32);
+///
+/// struct HealthBarPlugin;
+///
+/// impl Plugin for HealthBarPlugin {
+///     fn build(&self, app: &mut App) {
+///         app.add_tweenable_component::<Health>();
+///     }
+/// }
+/// ```
+pub trait TweeningAppExt {
+    /// Register the animator system for the component `T`.
+    fn add_tweenable_component<T: Component>(&mut self) -> &mut Self;
 
-        assert_eq!(env.event_count(), 0);
-        let animator = env.animator();
-        assert_eq!(animator.state, AnimatorState::Playing);
-        assert_eq!(animator.tweenable().times_completed(), 1);
-        let transform = env.transform();
-        assert!(!transform.is_changed());
-        assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+    /// Register the animator system for the resource `T`.
+    fn add_tweenable_resource<T: Resource>(&mut self) -> &mut Self;
+
+    /// Register the animator system for the asset `T`.
+    ///
+    /// This requires the `bevy_asset` feature (enabled by default).
+    #[cfg(feature = "bevy_asset")]
+    fn add_tweenable_asset<T: Asset>(&mut self) -> &mut Self;
+
+    /// Register the animator system for a [`SyncedAnimator`] animating the
+    /// component `C` and the asset `A`.
+    ///
+    /// This requires the `bevy_asset` feature (enabled by default).
+    #[cfg(feature = "bevy_asset")]
+    fn add_tweenable_synced<C: Component, A: Asset>(&mut self) -> &mut Self;
+
+    /// Register the animator system for the component `T`, and the
+    /// [`AnimatorDef<T>`] component and its system to spawn animators
+    /// authored in scenes.
+    ///
+    /// This requires the `bevy_scene` feature.
+    ///
+    /// [`AnimatorDef<T>`]: crate::AnimatorDef
+    #[cfg(feature = "bevy_scene")]
+    fn add_tweenable_scene_component<T: Component + TypePath>(&mut self) -> &mut Self;
+
+    /// Apply the transitions of the [`States`] type `S` to the animators
+    /// bound to one of its values.
+    ///
+    /// See [`TweeningPlugin::register_state()`].
+    fn add_tweening_state<S: States>(&mut self) -> &mut Self;
+}
+
+impl TweeningAppExt for App {
+    fn add_tweenable_component<T: Component>(&mut self) -> &mut Self {
+        register_with_core(self, &Target::component::<T>());
+        self
+    }
+
+    fn add_tweenable_resource<T: Resource>(&mut self) -> &mut Self {
+        register_with_core(self, &Target::resource::<T>());
+        self
+    }
+
+    #[cfg(feature = "bevy_asset")]
+    fn add_tweenable_asset<T: Asset>(&mut self) -> &mut Self {
+        register_with_core(self, &Target::asset::<T>());
+        self
+    }
+
+    #[cfg(feature = "bevy_asset")]
+    fn add_tweenable_synced<C: Component, A: Asset>(&mut self) -> &mut Self {
+        register_with_core(self, &Target::synced::<C, A>());
+        self
+    }
+
+    #[cfg(feature = "bevy_scene")]
+    fn add_tweenable_scene_component<T: Component + TypePath>(&mut self) -> &mut Self {
+        register_with_core(self, &Target::component::<T>());
+        let schedule = self.world.resource::<RegisteredTargets>().schedule;
+        register_animator_def::<T>(self, schedule);
+        self
+    }
+
+    fn add_tweening_state<S: States>(&mut self) -> &mut Self {
+        build_core(self, Update.intern());
+        register_state(self, &BoundStateType::new::<S>());
+        self
+    }
+}
+
+/// Register `target` in the schedule of the first instance of the plugin,
+/// setting up the core of the crate if no instance was added yet.
+fn register_with_core(app: &mut App, target: &Target) {
+    build_core(app, Update.intern());
+    let schedule = app.world.resource::<RegisteredTargets>().schedule;
+    register_target(app, target, schedule);
+}
+
+/// Label enum for the systems relating to animations
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, SystemSet)]
+pub enum AnimationSystem {
+    /// Ticks animations
+    AnimationUpdate,
+}
+
+/// Order in which the animator systems tick the animators.
+///
+/// This resource is inserted by [`TweeningPlugin`], and configured with
+/// [`TweeningPlugin::with_deterministic_order()`]. Applications adding the
+/// animator systems manually can insert it themselves; when it's missing, the
+/// animators are ticked in [`AnimationOrder::Unspecified`] order.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Resource)]
+pub enum AnimationOrder {
+    /// Tick the animators in query iteration order. This order depends on the
+    /// archetypes and tables the entities are stored in, so can change when
+    /// components are added or removed, including on unrelated entities, and
+    /// is not guaranteed to be the same across runs.
+    #[default]
+    Unspecified,
+    /// Tick the animators by increasing [`Entity::index()`], then increasing
+    /// [`Entity::generation()`].
+    ///
+    /// Within a single run of an animator system, this guarantees that the
+    /// [`TweenCompleted`] and [`AnimatorStateChanged`] events are written, and
+    /// the completion callbacks are invoked, in that entity order; this holds
+    /// whatever order the entities were spawned in, and whatever archetype
+    /// they belong to. Events from separate animator systems, that is from
+    /// different target types, are not interleaved but follow the order the
+    /// systems run in, which is only deterministic if the application orders
+    /// those systems explicitly. The order of events raised by a single
+    /// animator during a single tick, like a [`Sequence`] completing several
+    /// children, is unchanged.
+    ///
+    /// This requires collecting and sorting the entities on each run of each
+    /// animator system.
+    ///
+    /// [`Sequence`]: crate::Sequence
+    SortedByEntity,
+}
+
+/// Per-frame budget of each animator system.
+///
+/// When this resource exists, each animator system ticks at most a budgeted
+/// number of its animators each frame, and skips the others. A skipped
+/// animator doesn't lose time: the time of the frames it skipped is added to
+/// its next tick, with its current [`speed()`] applied, so the animation
+/// plays for the correct total time, and loops and completes at the correct
+/// time, only updating its target less often. The completion of a skipped
+/// animator is delayed until its next tick, that is by at most the number of
+/// frames needed to tick all the animators of its system once.
+///
+/// The animators are ticked round-robin: each animator system keeps a cursor
+/// in its list of animators, and the animators skipped on a frame are the
+/// first ticked on the next frame. The list follows the [`AnimationOrder`]
+/// configured. With [`AnimationOrder::Unspecified`] the query iteration order
+/// is used, so the fairness is only approximate when animators are added or
+/// removed. At least one animator is ticked each frame, whatever the budget.
+/// Paused animators are visited like any other, and count toward the budget.
+///
+/// This resource is inserted by [`TweeningPlugin::with_budget()`]. Modifying
+/// the resource changes the budget from the next frame; removing it ticks all
+/// the animators again each frame, including the time they skipped. Without
+/// it, the animator systems don't have any overhead.
+///
+/// [`speed()`]: crate::Animator::speed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Resource)]
+pub enum AnimationBudget {
+    /// Tick at most this many animators per frame.
+    Animators(usize),
+    /// Tick animators until this much time was spent in the animator system
+    /// this frame. This is only checked between two animators.
+    Time(Duration),
+}
+
+impl AnimationBudget {
+    /// Visit the `entities` of an animator system, starting at `cursor`, and
+    /// calling `visit` with whether the animator of each entity is ticked this
+    /// frame. The cursor is moved after the last animator ticked.
+    fn visit(&self, entities: &[Entity], cursor: &mut usize, mut visit: impl FnMut(Entity, bool)) {
+        if entities.is_empty() {
+            return;
+        }
+        let start = Instant::now();
+        let first = *cursor % entities.len();
+        let mut ticked = 0;
+        for index in 0..entities.len() {
+            let due = ticked == 0
+                || match *self {
+                    AnimationBudget::Animators(max) => ticked < max,
+                    AnimationBudget::Time(max) => start.elapsed() < max,
+                };
+            if due {
+                ticked += 1;
+            }
+            visit(entities[(first + index) % entities.len()], due);
+        }
+        *cursor = (first + ticked) % entities.len();
+    }
+}
+
+/// What to do with an animator to clean it up.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CleanupPolicy {
+    /// Remove the animator component from its entity, leaving the entity and
+    /// the animated target untouched.
+    #[default]
+    Remove,
+    /// Despawn the entity of the animator. The children of the entity, if
+    /// any, are not despawned.
+    Despawn,
+}
+
+/// Configuration of the cleanup of idle animators.
+///
+/// An animator is idle once its tweenable completed, for as long as it stays
+/// completed; see [`Animator::completed_since()`]. When this resource exists,
+/// the maintenance systems added by [`TweeningPlugin::with_idle_cleanup()`]
+/// apply the [`CleanupPolicy`] to each animator idle for longer than the
+/// threshold. Modifying the resource changes the configuration from the next
+/// frame; removing it suspends the cleanup.
+///
+/// Animators shrunk with [`Animator::with_shrink_on_complete()`] keep the time
+/// they completed at, so are cleaned up like any other completed animator.
+/// Looping animators never complete, so are never cleaned up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Resource)]
+pub struct IdleAnimatorCleanup {
+    /// Time an animator needs to stay completed before it's cleaned up.
+    pub threshold: Duration,
+    /// What to do with the idle animators.
+    pub policy: CleanupPolicy,
+}
+
+/// Write coalescing of the animators of the component `T`.
+///
+/// A single tick of an animator can write its component several times, for
+/// example once per track of a [`Tracks`], or every frame with the same value
+/// while a [`Tween`] holds on a [`EaseMethod::Discrete`] step. When this
+/// resource exists, the [`component_animator_system::<T>`] ticks each
+/// animator against a scratch copy of its component instead, and writes the
+/// copy back with a single mutable dereference of the component per entity
+/// at the end of the tick. With a resource created by [`new()`], the copy is
+/// only written back if its value changed, so [`Changed<T>`] and other change detection
+/// consumers are only triggered by actual changes. With a resource created by
+/// [`without_comparison()`], the copy is written back whenever a lens
+/// accessed it.
+///
+/// This costs a clone of the component per animator ticked, so is best
+/// suited to small components like [`Transform`] or colors. This resource is
+/// inserted for the default targets by
+/// [`TweeningPlugin::with_write_coalescing()`], and can be inserted for any
+/// other component. Removing it disables the coalescing from the next frame.
+///
+/// [`Tracks`]: crate::Tracks
+/// [`Tween`]: crate::Tween
+/// [`EaseMethod::Discrete`]: crate::EaseMethod::Discrete
+/// [`new()`]: WriteCoalescing::new
+/// [`without_comparison()`]: WriteCoalescing::without_comparison
+#[derive(Resource)]
+pub struct WriteCoalescing<T> {
+    clone: fn(&T) -> T,
+    eq: Option<fn(&T, &T) -> bool>,
+}
+
+impl<T: Clone + PartialEq> WriteCoalescing<T> {
+    /// Coalesce the writes, and skip writing back unchanged values.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            clone: T::clone,
+            eq: Some(T::eq),
+        }
+    }
+}
+
+impl<T: Clone + PartialEq> Default for WriteCoalescing<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> WriteCoalescing<T> {
+    /// Coalesce the writes of a component which can't be compared, writing
+    /// the value back whenever a lens accessed it.
+    #[must_use]
+    pub fn without_comparison() -> Self {
+        Self {
+            clone: T::clone,
+            eq: None,
+        }
+    }
+}
+
+impl<T> WriteCoalescing<T> {
+    pub(crate) fn clone(&self, value: &T) -> T {
+        (self.clone)(value)
+    }
+
+    pub(crate) fn eq(&self, a: &T, b: &T) -> bool {
+        self.eq.is_some_and(|eq| eq(a, b))
+    }
+}
+
+impl<T> std::fmt::Debug for WriteCoalescing<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WriteCoalescing")
+            .field("compare", &self.eq.is_some())
+            .finish()
+    }
+}
+
+/// An animator component recording the time its tweenable completed, and
+/// the cancellation of its tweenable before it completed.
+pub(crate) trait CompletionTracking: Component {
+    /// See [`Animator::completed_since()`].
+    fn completed_since(&self, now: Duration) -> Option<Duration>;
+
+    /// Append the [`TweenCancelled`] events raised if the animator was
+    /// discarded now.
+    fn collect_cancelled(&self, entity: Entity, cancelled: &mut Vec<TweenCancelled>);
+
+    /// Take the [`TweenCancelled`] events queued by the animator.
+    fn take_cancelled(&mut self, entity: Entity) -> Vec<TweenCancelled>;
+
+    /// Does the animator have any action to apply after it completed?
+    fn has_completed_actions(&self) -> bool;
+
+    /// Apply the actions due at time `now` to the entity of the animator.
+    fn apply_completed_actions(&mut self, now: Duration, commands: &mut Commands, entity: Entity);
+}
+
+impl<T: Component> CompletionTracking for Animator<T> {
+    fn completed_since(&self, now: Duration) -> Option<Duration> {
+        Animator::completed_since(self, now)
+    }
+
+    fn collect_cancelled(&self, entity: Entity, cancelled: &mut Vec<TweenCancelled>) {
+        Animator::collect_cancelled(self, entity, cancelled);
+    }
+
+    fn take_cancelled(&mut self, entity: Entity) -> Vec<TweenCancelled> {
+        Animator::take_cancelled(self, entity).collect()
+    }
+
+    fn has_completed_actions(&self) -> bool {
+        Animator::has_completed_actions(self)
+    }
+
+    fn apply_completed_actions(&mut self, now: Duration, commands: &mut Commands, entity: Entity) {
+        Animator::apply_completed_actions(self, now, commands, entity);
+    }
+}
+
+#[cfg(feature = "bevy_asset")]
+impl<T: Asset> CompletionTracking for AssetAnimator<T> {
+    fn completed_since(&self, now: Duration) -> Option<Duration> {
+        AssetAnimator::completed_since(self, now)
+    }
+
+    fn collect_cancelled(&self, entity: Entity, cancelled: &mut Vec<TweenCancelled>) {
+        AssetAnimator::collect_cancelled(self, entity, cancelled);
+    }
+
+    fn take_cancelled(&mut self, entity: Entity) -> Vec<TweenCancelled> {
+        AssetAnimator::take_cancelled(self, entity).collect()
+    }
+
+    fn has_completed_actions(&self) -> bool {
+        AssetAnimator::has_completed_actions(self)
+    }
+
+    fn apply_completed_actions(&mut self, now: Duration, commands: &mut Commands, entity: Entity) {
+        AssetAnimator::apply_completed_actions(self, now, commands, entity);
+    }
+}
+
+#[cfg(feature = "bevy_asset")]
+impl<C: Component, A: Asset> CompletionTracking for SyncedAnimator<C, A> {
+    fn completed_since(&self, now: Duration) -> Option<Duration> {
+        SyncedAnimator::completed_since(self, now)
+    }
+
+    fn collect_cancelled(&self, entity: Entity, cancelled: &mut Vec<TweenCancelled>) {
+        SyncedAnimator::collect_cancelled(self, entity, cancelled);
+    }
+
+    fn take_cancelled(&mut self, entity: Entity) -> Vec<TweenCancelled> {
+        SyncedAnimator::take_cancelled(self, entity).collect()
+    }
+
+    fn has_completed_actions(&self) -> bool {
+        SyncedAnimator::has_completed_actions(self)
+    }
+
+    fn apply_completed_actions(&mut self, now: Duration, commands: &mut Commands, entity: Entity) {
+        SyncedAnimator::apply_completed_actions(self, now, commands, entity);
+    }
+}
+
+impl<T: Resource> CompletionTracking for ResourceAnimator<T> {
+    fn completed_since(&self, now: Duration) -> Option<Duration> {
+        ResourceAnimator::completed_since(self, now)
+    }
+
+    fn collect_cancelled(&self, entity: Entity, cancelled: &mut Vec<TweenCancelled>) {
+        ResourceAnimator::collect_cancelled(self, entity, cancelled);
+    }
+
+    fn take_cancelled(&mut self, entity: Entity) -> Vec<TweenCancelled> {
+        ResourceAnimator::take_cancelled(self, entity).collect()
+    }
+
+    fn has_completed_actions(&self) -> bool {
+        ResourceAnimator::has_completed_actions(self)
+    }
+
+    fn apply_completed_actions(&mut self, now: Duration, commands: &mut Commands, entity: Entity) {
+        ResourceAnimator::apply_completed_actions(self, now, commands, entity);
+    }
+}
+
+/// System raising the [`TweenCancelled`] events of the animators of type `A`.
+///
+/// The events queued by the animators, when their tweenable is replaced or
+/// they're stopped, are sent as is. Removed animators can't be read anymore,
+/// so the events they would raise are recorded each time they change, and
+/// sent once they're removed.
+fn cancellation_system<A: CompletionTracking>(
+    mut query: Query<(Entity, &mut A), Changed<A>>,
+    mut removed: RemovedComponents<A>,
+    mut pending: Local<EntityHashMap<Vec<TweenCancelled>>>,
+    mut events: EventWriter<TweenCancelled>,
+) {
+    for entity in removed.read() {
+        if let Some(cancelled) = pending.remove(&entity) {
+            events.send_batch(cancelled);
+        }
+    }
+    for (entity, mut animator) in &mut query {
+        let animator = animator.bypass_change_detection();
+        let queued = animator.take_cancelled(entity);
+        if !queued.is_empty() {
+            events.send_batch(queued);
+        }
+        let mut cancelled = pending.remove(&entity).unwrap_or_default();
+        cancelled.clear();
+        animator.collect_cancelled(entity, &mut cancelled);
+        if !cancelled.is_empty() {
+            pending.insert(entity, cancelled);
+        }
+    }
+}
+
+/// Maintenance system applying the [`IdleAnimatorCleanup`] to the animators
+/// of type `A`.
+fn idle_cleanup_system<A: CompletionTracking>(
+    time: Res<Time>,
+    cleanup: Option<Res<IdleAnimatorCleanup>>,
+    query: Query<(Entity, &A)>,
+    mut commands: Commands,
+) {
+    let Some(cleanup) = cleanup else {
+        return;
+    };
+    let now = time.elapsed();
+    for (entity, animator) in query.iter() {
+        let Some(idle) = animator.completed_since(now) else {
+            continue;
+        };
+        if idle <= cleanup.threshold {
+            continue;
+        }
+        match cleanup.policy {
+            CleanupPolicy::Remove => {
+                commands.entity(entity).remove::<A>();
+            }
+            CleanupPolicy::Despawn => {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
+
+/// Check whether the animators need to be ticked in sorted order.
+fn is_sorted(order: Option<Res<AnimationOrder>>) -> bool {
+    order.is_some_and(|order| *order == AnimationOrder::SortedByEntity)
+}
+
+/// Collect and sort the animated entities, for
+/// [`AnimationOrder::SortedByEntity`].
+fn sorted_entities(entities: impl Iterator<Item = Entity>) -> Vec<Entity> {
+    let mut entities: Vec<Entity> = entities.collect();
+    entities.sort_unstable_by_key(|entity| (entity.index(), entity.generation()));
+    entities
+}
+
+/// Collect the animated entities in the [`AnimationOrder`] configured, for
+/// the [`AnimationBudget`].
+fn ordered_entities(sorted: bool, entities: impl Iterator<Item = Entity>) -> Vec<Entity> {
+    if sorted {
+        sorted_entities(entities)
+    } else {
+        entities.collect()
+    }
+}
+
+/// Send the completion events collected while ticking the animators, as a
+/// single batch. The buffer is drained but keeps its capacity for the next
+/// frame.
+fn send_completed(events: &mut EventWriter<TweenCompleted>, completed: &mut Vec<TweenCompleted>) {
+    if !completed.is_empty() {
+        events.send_batch(completed.drain(..));
+    }
+}
+
+/// Animator system for components.
+///
+/// This system extracts all components of type `T` with an [`Animator<T>`]
+/// attached to the same entity, and tick the animator to animate the component.
+/// The animators are ticked in the [`AnimationOrder`] configured, within the
+/// [`AnimationBudget`] if any.
+#[allow(clippy::too_many_arguments)]
+pub fn component_animator_system<T: Component>(
+    time: Res<Time>,
+    order: Option<Res<AnimationOrder>>,
+    budget: Option<Res<AnimationBudget>>,
+    mut cursor: Local<usize>,
+    coalescing: Option<Res<WriteCoalescing<T>>>,
+    mut query: Query<(Entity, &mut T, &mut Animator<T>)>,
+    mut completed: Local<Vec<TweenCompleted>>,
+    mut events: EventWriter<TweenCompleted>,
+    mut state_events: EventWriter<AnimatorStateChanged>,
+    #[cfg(feature = "diagnostics")] mut diagnostics: AnimatorSystemDiagnostics<Animator<T>>,
+) {
+    let _span = info_span!("component_animator_system", target_type = type_name::<T>()).entered();
+    #[cfg(feature = "diagnostics")]
+    let start = Instant::now();
+    let mut tick = |entity: Entity, mut target: Mut<T>, mut animator: Mut<Animator<T>>| {
+        animator.bypass_change_detection().clear_frame_flags();
+        if let Some((old, new)) = animator.take_state_change() {
+            state_events.send(AnimatorStateChanged { entity, old, new });
+        }
+        let mut tick_state = None;
+        if animator.state != AnimatorState::Paused {
+            let delta =
+                time.delta().mul_f64(f64::from(animator.speed())) + animator.take_start_delta();
+            let times_completed = animator.tweenable().times_completed();
+            let context = animator.lens_context();
+            let tweenable = animator.tweenable_mut();
+            let state = if let Some(coalescing) = coalescing.as_deref() {
+                let mut target =
+                    CoalescingTarget::new(target.reborrow(), coalescing).with_context(context);
+                let state = tweenable.tick(delta, &mut target, entity, &mut completed);
+                target.flush();
+                state
+            } else {
+                let mut target = ComponentTarget::new(target.reborrow()).with_context(context);
+                tweenable.tick(delta, &mut target, entity, &mut completed)
+            };
+            animator.notify_update(entity, &target);
+            animator.cache_progress(times_completed);
+            if state == TweenState::Completed && animator.shrink_on_complete() {
+                animator.shrink();
+            }
+            tick_state = Some(state);
+        }
+        animator
+            .bypass_change_detection()
+            .track_completion(tick_state, time.elapsed());
+    };
+    let sorted = is_sorted(order);
+    if let Some(budget) = budget {
+        let entities = ordered_entities(sorted, query.iter().map(|(entity, ..)| entity));
+        budget.visit(&entities, &mut cursor, |entity, due| {
+            let (entity, target, mut animator) = query.get_mut(entity).unwrap();
+            if due {
+                tick(entity, target, animator);
+            } else {
+                animator.bypass_change_detection().defer_tick(time.delta());
+            }
+        });
+    } else if sorted {
+        for entity in sorted_entities(query.iter().map(|(entity, ..)| entity)) {
+            let (entity, target, animator) = query.get_mut(entity).unwrap();
+            tick(entity, target, animator);
+        }
+    } else {
+        for (entity, target, animator) in query.iter_mut() {
+            tick(entity, target, animator);
+        }
+    }
+    send_completed(&mut events, &mut completed);
+    #[cfg(feature = "diagnostics")]
+    diagnostics.publish(start, query.iter().len());
+}
+
+/// Animator system for resources.
+///
+/// This system ticks all [`ResourceAnimator<T>`] components to animate the
+/// resource of type `T`. The animators are not ticked while the resource
+/// doesn't exist. The animators are ticked in the [`AnimationOrder`]
+/// configured, within the [`AnimationBudget`] if any.
+#[allow(clippy::too_many_arguments)]
+pub fn resource_animator_system<T: Resource>(
+    time: Res<Time>,
+    order: Option<Res<AnimationOrder>>,
+    budget: Option<Res<AnimationBudget>>,
+    mut cursor: Local<usize>,
+    resource: Option<ResMut<T>>,
+    mut query: Query<(Entity, &mut ResourceAnimator<T>)>,
+    mut completed: Local<Vec<TweenCompleted>>,
+    mut events: EventWriter<TweenCompleted>,
+    mut state_events: EventWriter<AnimatorStateChanged>,
+    #[cfg(feature = "diagnostics")] mut diagnostics: AnimatorSystemDiagnostics<ResourceAnimator<T>>,
+) {
+    let _span = info_span!("resource_animator_system", target_type = type_name::<T>()).entered();
+    #[cfg(feature = "diagnostics")]
+    let start = Instant::now();
+    let mut target = resource.map(ResourceTarget::new);
+    let has_resource = target.is_some();
+    let mut tick = |entity: Entity, mut animator: Mut<ResourceAnimator<T>>| {
+        animator.bypass_change_detection().clear_frame_flags();
+        if let Some((old, new)) = animator.take_state_change() {
+            state_events.send(AnimatorStateChanged { entity, old, new });
+        }
+        let mut tick_state = None;
+        let Some(target) = target.as_mut() else {
+            return;
+        };
+        if animator.state != AnimatorState::Paused {
+            let delta =
+                time.delta().mul_f64(f64::from(animator.speed())) + animator.take_start_delta();
+            let times_completed = animator.tweenable().times_completed();
+            let state = animator
+                .tweenable_mut()
+                .tick(delta, target, entity, &mut completed);
+            animator.cache_progress(times_completed);
+            if state == TweenState::Completed && animator.shrink_on_complete() {
+                animator.shrink();
+            }
+            tick_state = Some(state);
+        }
+        animator
+            .bypass_change_detection()
+            .track_completion(tick_state, time.elapsed());
+    };
+    let sorted = is_sorted(order);
+    if let Some(budget) = budget {
+        let entities = ordered_entities(sorted, query.iter().map(|(entity, _)| entity));
+        budget.visit(&entities, &mut cursor, |entity, due| {
+            let (entity, mut animator) = query.get_mut(entity).unwrap();
+            if due {
+                tick(entity, animator);
+            } else if has_resource {
+                animator.bypass_change_detection().defer_tick(time.delta());
+            }
+        });
+    } else if sorted {
+        for entity in sorted_entities(query.iter().map(|(entity, _)| entity)) {
+            let (entity, animator) = query.get_mut(entity).unwrap();
+            tick(entity, animator);
+        }
+    } else {
+        for (entity, animator) in query.iter_mut() {
+            tick(entity, animator);
+        }
+    }
+    send_completed(&mut events, &mut completed);
+    #[cfg(feature = "diagnostics")]
+    diagnostics.publish(start, query.iter().len());
+}
+
+/// Query of the [`asset_animator_system()`].
+#[cfg(feature = "bevy_asset")]
+type AssetAnimatorQuery<'w, 's, T> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Option<&'static Handle<T>>,
+        &'static mut AssetAnimator<T>,
+    ),
+>;
+
+/// Animator system for assets.
+///
+/// This system ticks all [`AssetAnimator<T>`] components to animate their
+/// associated asset, as selected by their [`AssetAnimatorTarget`]. Animators
+/// following the handle of their entity are skipped while the entity has no
+/// [`Handle<T>`]. The animators are ticked in the [`AnimationOrder`]
+/// configured, within the [`AnimationBudget`] if any.
+///
+/// [`AssetAnimatorTarget`]: crate::AssetAnimatorTarget
+///
+/// This requires the `bevy_asset` feature (enabled by default).
+#[cfg(feature = "bevy_asset")]
+#[allow(clippy::too_many_arguments)]
+pub fn asset_animator_system<T: Asset>(
+    time: Res<Time>,
+    order: Option<Res<AnimationOrder>>,
+    budget: Option<Res<AnimationBudget>>,
+    mut cursor: Local<usize>,
+    assets: ResMut<Assets<T>>,
+    mut query: AssetAnimatorQuery<T>,
+    mut completed: Local<Vec<TweenCompleted>>,
+    mut events: EventWriter<TweenCompleted>,
+    mut state_events: EventWriter<AnimatorStateChanged>,
+    #[cfg(feature = "diagnostics")] mut diagnostics: AnimatorSystemDiagnostics<AssetAnimator<T>>,
+) {
+    let _span = info_span!("asset_animator_system", target_type = type_name::<T>()).entered();
+    #[cfg(feature = "diagnostics")]
+    let start = Instant::now();
+    let mut target = AssetTarget::new(assets);
+    let mut tick = |entity: Entity,
+                    handle: Option<&Handle<T>>,
+                    mut animator: Mut<AssetAnimator<T>>| {
+        animator.bypass_change_detection().clear_frame_flags();
+        if let Some((old, new)) = animator.take_state_change() {
+            state_events.send(AnimatorStateChanged { entity, old, new });
+        }
+        let Some(handle) = animator.target().resolve(handle).cloned() else {
+            return;
+        };
+        let mut tick_state = None;
+        if animator.state != AnimatorState::Paused {
+            target.handle = handle;
+            if !target.is_valid() {
+                return;
+            }
+            let delta =
+                time.delta().mul_f64(f64::from(animator.speed())) + animator.take_start_delta();
+            let times_completed = animator.tweenable().times_completed();
+            let state = animator
+                .tweenable_mut()
+                .tick(delta, &mut target, entity, &mut completed);
+            animator.cache_progress(times_completed);
+            if state == TweenState::Completed && animator.shrink_on_complete() {
+                animator.shrink();
+            }
+            tick_state = Some(state);
+        }
+        animator
+            .bypass_change_detection()
+            .track_completion(tick_state, time.elapsed());
+    };
+    let sorted = is_sorted(order);
+    if let Some(budget) = budget {
+        let entities = ordered_entities(sorted, query.iter().map(|(entity, ..)| entity));
+        budget.visit(&entities, &mut cursor, |entity, due| {
+            let (entity, handle, mut animator) = query.get_mut(entity).unwrap();
+            if due {
+                tick(entity, handle, animator);
+            } else if animator.target().resolve(handle).is_some() {
+                animator.bypass_change_detection().defer_tick(time.delta());
+            }
+        });
+    } else if sorted {
+        for entity in sorted_entities(query.iter().map(|(entity, ..)| entity)) {
+            let (entity, handle, animator) = query.get_mut(entity).unwrap();
+            tick(entity, handle, animator);
+        }
+    } else {
+        for (entity, handle, animator) in query.iter_mut() {
+            tick(entity, handle, animator);
+        }
+    }
+    send_completed(&mut events, &mut completed);
+    #[cfg(feature = "diagnostics")]
+    diagnostics.publish(start, query.iter().len());
+}
+
+/// Query of the [`synced_animator_system()`].
+#[cfg(feature = "bevy_asset")]
+type SyncedAnimatorQuery<'w, 's, C, A> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static mut C,
+        &'static Handle<A>,
+        &'static mut SyncedAnimator<C, A>,
+    ),
+>;
+
+/// Animator system for synced component and asset animations.
+///
+/// This system ticks all [`SyncedAnimator<C, A>`] components, to animate the
+/// component `C` on the same entity and the asset `A` referenced by the
+/// [`Handle<A>`] on that entity with a single timeline. The animators are
+/// ticked in the [`AnimationOrder`] configured, within the [`AnimationBudget`]
+/// if any.
+///
+/// This requires the `bevy_asset` feature (enabled by default).
+#[cfg(feature = "bevy_asset")]
+#[allow(clippy::too_many_arguments)]
+pub fn synced_animator_system<C: Component, A: Asset>(
+    time: Res<Time>,
+    order: Option<Res<AnimationOrder>>,
+    budget: Option<Res<AnimationBudget>>,
+    mut cursor: Local<usize>,
+    mut assets: ResMut<Assets<A>>,
+    mut query: SyncedAnimatorQuery<C, A>,
+    mut completed: Local<Vec<TweenCompleted>>,
+    mut events: EventWriter<TweenCompleted>,
+    mut state_events: EventWriter<AnimatorStateChanged>,
+    #[cfg(feature = "diagnostics")] mut diagnostics: AnimatorSystemDiagnostics<
+        SyncedAnimator<C, A>,
+    >,
+) {
+    let _span = info_span!(
+        "synced_animator_system",
+        component_type = type_name::<C>(),
+        asset_type = type_name::<A>()
+    )
+    .entered();
+    #[cfg(feature = "diagnostics")]
+    let start = Instant::now();
+    let mut tick = |entity: Entity,
+                    mut target: Mut<C>,
+                    handle: &Handle<A>,
+                    mut animator: Mut<SyncedAnimator<C, A>>| {
+        animator.bypass_change_detection().clear_frame_flags();
+        if let Some((old, new)) = animator.take_state_change() {
+            state_events.send(AnimatorStateChanged { entity, old, new });
+        }
+        let mut tick_state = None;
+        if animator.state != AnimatorState::Paused {
+            let delta =
+                time.delta().mul_f64(f64::from(animator.speed())) + animator.take_start_delta();
+            let times_completed = animator.tweenable().times_completed();
+            let state = animator.tick(
+                delta,
+                &mut target,
+                &mut assets,
+                handle,
+                entity,
+                &mut completed,
+            );
+            animator.cache_progress(times_completed);
+            if state == TweenState::Completed && animator.shrink_on_complete() {
+                animator.shrink();
+            }
+            tick_state = Some(state);
+        }
+        animator
+            .bypass_change_detection()
+            .track_completion(tick_state, time.elapsed());
+    };
+    let sorted = is_sorted(order);
+    if let Some(budget) = budget {
+        let entities = ordered_entities(sorted, query.iter().map(|(entity, ..)| entity));
+        budget.visit(&entities, &mut cursor, |entity, due| {
+            let (entity, target, handle, mut animator) = query.get_mut(entity).unwrap();
+            if due {
+                tick(entity, target, handle, animator);
+            } else {
+                animator.bypass_change_detection().defer_tick(time.delta());
+            }
+        });
+    } else if sorted {
+        for entity in sorted_entities(query.iter().map(|(entity, ..)| entity)) {
+            let (entity, target, handle, animator) = query.get_mut(entity).unwrap();
+            tick(entity, target, handle, animator);
+        }
+    } else {
+        for (entity, target, handle, animator) in query.iter_mut() {
+            tick(entity, target, handle, animator);
+        }
+    }
+    send_completed(&mut events, &mut completed);
+    #[cfg(feature = "diagnostics")]
+    diagnostics.publish(start, query.iter().len());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[cfg(feature = "bevy_asset")]
+    use crate::lens::SyncFactorLens;
+    use crate::{
+        lens::{TransformPositionLens, TransformScaleLens, WorldPositionLens},
+        *,
+    };
+
+    /// A simple isolated test environment with a [`World`] and a single
+    /// [`Entity`] in it.
+    struct TestEnv {
+        world: World,
+        entity: Entity,
+    }
+
+    impl TestEnv {
+        /// Create a new test environment containing a single entity with a
+        /// [`Transform`], and add the given animator on that same entity.
+        pub fn new<T: Component>(animator: T) -> Self {
+            let mut world = World::new();
+            world.init_resource::<Events<TweenCompleted>>();
+            world.init_resource::<Events<AnimatorStateChanged>>();
+            world.init_resource::<Time>();
+
+            let entity = world.spawn((Transform::default(), animator)).id();
+
+            Self { world, entity }
+        }
+
+        /// Get the test world.
+        pub fn world_mut(&mut self) -> &mut World {
+            &mut self.world
+        }
+
+        /// Tick the test environment, updating the simulation time and ticking
+        /// the given system.
+        pub fn tick(&mut self, duration: Duration, system: &mut dyn System<In = (), Out = ()>) {
+            // Simulate time passing by updating the simulation time resource
+            {
+                let mut time = self.world.resource_mut::<Time>();
+                time.advance_by(duration);
+            }
+
+            // Reset world-related change detection
+            self.world.clear_trackers();
+            assert!(!self.transform().is_changed());
+
+            // Tick system
+            system.run((), &mut self.world);
+
+            // Update events after system ticked, in case system emitted some events
+            let mut events = self.world.resource_mut::<Events<TweenCompleted>>();
+            events.update();
+            let mut events = self.world.resource_mut::<Events<AnimatorStateChanged>>();
+            events.update();
+        }
+
+        /// Get the animator for the transform.
+        pub fn animator(&self) -> &Animator<Transform> {
+            self.world
+                .entity(self.entity)
+                .get::<Animator<Transform>>()
+                .unwrap()
+        }
+
+        /// Get the mutable animator for the transform.
+        pub fn animator_mut(&mut self) -> Mut<'_, Animator<Transform>> {
+            self.world
+                .get_mut::<Animator<Transform>>(self.entity)
+                .unwrap()
+        }
+
+        /// Get the transform component.
+        pub fn transform(&mut self) -> Mut<'_, Transform> {
+            self.world.get_mut::<Transform>(self.entity).unwrap()
+        }
+
+        /// Get the emitted event count since last tick.
+        pub fn event_count(&self) -> usize {
+            let events = self.world.resource::<Events<TweenCompleted>>();
+            events.get_reader().len(events)
+        }
+
+        /// Get the animator state changes emitted since last tick.
+        pub fn state_events(&self) -> Vec<AnimatorStateChanged> {
+            let events = self.world.resource::<Events<AnimatorStateChanged>>();
+            events.get_reader().read(events).copied().collect()
+        }
+    }
+
+    #[test]
+    fn change_detect_component() {
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+        .with_completed_event(0);
+
+        let mut env = TestEnv::new(Animator::new(tween));
+
+        // After being inserted, components are always considered changed
+        let transform = env.transform();
+        assert!(transform.is_changed());
+
+        // fn nit() {}
+        // let mut system = IntoSystem::into_system(nit);
+        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+        system.initialize(env.world_mut());
+
+        env.tick(Duration::ZERO, &mut system);
+
+        let animator = env.animator();
+        assert_eq!(animator.state, AnimatorState::Playing);
+        assert_eq!(animator.tweenable().times_completed(), 0);
+        let transform = env.transform();
+        assert!(transform.is_changed());
+        assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
+
+        env.tick(Duration::from_millis(500), &mut system);
+
+        assert_eq!(env.event_count(), 0);
+        let animator = env.animator();
+        assert_eq!(animator.state, AnimatorState::Playing);
+        assert_eq!(animator.tweenable().times_completed(), 0);
+        let transform = env.transform();
+        assert!(transform.is_changed());
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.5), 1e-5));
+
+        env.tick(Duration::from_millis(500), &mut system);
+
+        assert_eq!(env.event_count(), 1);
+        let animator = env.animator();
+        assert_eq!(animator.state, AnimatorState::Playing);
+        assert_eq!(animator.tweenable().times_completed(), 1);
+        let transform = env.transform();
+        assert!(transform.is_changed());
+        assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+
+        env.tick(Duration::from_millis(100), &mut system);
+
+        assert_eq!(env.event_count(), 0);
+        let animator = env.animator();
+        assert_eq!(animator.state, AnimatorState::Playing);
+        assert_eq!(animator.tweenable().times_completed(), 1);
+        let transform = env.transform();
+        assert!(!transform.is_changed());
+        assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+    }
+
+    #[test]
+    fn world_position_lens() {
+        let start = Vec3::new(1., 2., 3.);
+        let end = Vec3::new(-4., 0., 6.);
+        let make_animator = || {
+            Animator::new(Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                WorldPositionLens::new(start, end),
+            ))
+        };
+        let mut env = TestEnv::new(make_animator());
+        let parent_transform = Transform::from_xyz(10., -3., 5.)
+            .with_rotation(Quat::from_rotation_y(0.7) * Quat::from_rotation_x(-0.4));
+        let parent = env
+            .world_mut()
+            .spawn((parent_transform, GlobalTransform::from(parent_transform)))
+            .id();
+        let child = env.entity;
+        env.world_mut().entity_mut(parent).add_child(child);
+        let orphan = env
+            .world_mut()
+            .spawn((Transform::default(), make_animator()))
+            .id();
+        env.world_mut()
+            .run_system_once(super::transform_lens_context_system);
+        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+        system.initialize(env.world_mut());
+
+        // The child reaches the world endpoints through its rotated parent,
+        // while the entity without a parent animates its local position
+        for (millis, ratio) in [(0, 0.), (500, 0.5), (500, 1.)] {
+            env.tick(Duration::from_millis(millis), &mut system);
+            let expected = start.lerp(end, ratio);
+            let global = GlobalTransform::from(parent_transform) * *env.transform();
+            assert!(
+                global.translation().abs_diff_eq(expected, 1e-4),
+                "{ratio}: {} != {expected}",
+                global.translation()
+            );
+            let local = env
+                .world_mut()
+                .get::<Transform>(orphan)
+                .unwrap()
+                .translation;
+            assert!(local.abs_diff_eq(expected, 1e-5));
+        }
+    }
+
+    #[test]
+    fn animator_on_update() {
+        let make_tween = |start: f32, end: f32| {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::X * start,
+                    end: Vec3::X * end,
+                },
+            )
+        };
+        let child_values = Arc::new(Mutex::new(vec![]));
+        let values = Arc::clone(&child_values);
+        let second = make_tween(1., 2.).with_on_update(move |_, transform: &Transform| {
+            values.lock().unwrap().push(transform.translation.x);
+        });
+        let animator_values = Arc::new(Mutex::new(vec![]));
+        let values = Arc::clone(&animator_values);
+        let animator = Animator::new(make_tween(0., 1.).then(second)).with_on_update(
+            move |_, transform: &Transform| {
+                values.lock().unwrap().push(transform.translation.x);
+            },
+        );
+        let mut env = TestEnv::new(animator);
+        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+        system.initialize(env.world_mut());
+
+        // The animator callback observes the value written on each tick, even
+        // once completed, while the callback of the second child only observes
+        // the ticks of that child
+        for millis in [0, 500, 500, 500, 500, 500] {
+            env.tick(Duration::from_millis(millis), &mut system);
+            let x = env.transform().translation.x;
+            assert_eq!(*animator_values.lock().unwrap().last().unwrap(), x);
+        }
+        let expected = [0., 0.5, 1., 1.5, 2., 2.];
+        let observed = animator_values.lock().unwrap().clone();
+        assert_eq!(observed.len(), expected.len());
+        for (observed, expected) in observed.iter().zip(expected) {
+            assert!(
+                (observed - expected).abs() < 1e-5,
+                "{observed} != {expected}"
+            );
+        }
+        assert_eq!(*child_values.lock().unwrap(), [1., 1.5, 2.]);
+
+        // Not invoked while paused
+        env.animator_mut().state = AnimatorState::Paused;
+        env.tick(Duration::from_millis(500), &mut system);
+        assert_eq!(animator_values.lock().unwrap().len(), 6);
+    }
+
+    #[test]
+    fn animator_state_changed() {
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+        let mut env = TestEnv::new(Animator::new(tween).with_state(AnimatorState::Paused));
+        let entity = env.entity;
+        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+        system.initialize(env.world_mut());
+
+        // The initial state is not a transition
+        env.tick(Duration::from_millis(100), &mut system);
+        assert!(env.state_events().is_empty());
+
+        // Assigning the state
+        env.world
+            .get_mut::<Animator<Transform>>(entity)
+            .unwrap()
+            .state = AnimatorState::Playing;
+        env.tick(Duration::from_millis(100), &mut system);
+        assert_eq!(
+            env.state_events(),
+            vec![AnimatorStateChanged {
+                entity,
+                old: AnimatorState::Paused,
+                new: AnimatorState::Playing
+            }]
+        );
+        env.tick(Duration::from_millis(100), &mut system);
+        assert!(env.state_events().is_empty());
+
+        // Stopping the animator
+        env.world
+            .get_mut::<Animator<Transform>>(entity)
+            .unwrap()
+            .stop();
+        env.tick(Duration::from_millis(100), &mut system);
+        assert_eq!(
+            env.state_events(),
+            vec![AnimatorStateChanged {
+                entity,
+                old: AnimatorState::Playing,
+                new: AnimatorState::Paused
+            }]
+        );
+
+        // Ticking a paused animator doesn't spam events
+        env.tick(Duration::from_millis(100), &mut system);
+        assert!(env.state_events().is_empty());
+
+        // Toggling back and forth between two ticks is not a transition
+        {
+            let mut animator = env.world.get_mut::<Animator<Transform>>(entity).unwrap();
+            animator.state = !animator.state;
+            animator.state = !animator.state;
+        }
+        env.tick(Duration::from_millis(100), &mut system);
+        assert!(env.state_events().is_empty());
+
+        // Completion doesn't change the state
+        env.world
+            .get_mut::<Animator<Transform>>(entity)
+            .unwrap()
+            .state = AnimatorState::Playing;
+        env.tick(Duration::from_secs(2), &mut system);
+        assert_eq!(env.state_events().len(), 1);
+        env.tick(Duration::from_secs(1), &mut system);
+        assert!(env.state_events().is_empty());
+    }
+
+    #[test]
+    fn start_at() {
+        let make_tween = |start: f32, end: f32| {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::splat(start),
+                    end: Vec3::splat(end),
+                },
+            )
+            .with_completed_event(0)
+        };
+
+        // Start halfway through the second tween of a sequence; the completion
+        // of the first tween is skipped silently.
+        let animator = Animator::new(make_tween(0., 1.).then(make_tween(1., 2.)))
+            .start_at(Duration::from_millis(1500), SkippedCompletions::Suppress);
+        let mut env = TestEnv::new(animator);
+        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+        system.initialize(env.world_mut());
+
+        env.tick(Duration::ZERO, &mut system);
+        assert_eq!(env.event_count(), 0);
+        assert!(env
+            .transform()
+            .translation
+            .abs_diff_eq(Vec3::splat(1.5), 1e-5));
+        env.tick(Duration::from_millis(500), &mut system);
+        assert_eq!(env.event_count(), 1);
+        assert!(env
+            .transform()
+            .translation
+            .abs_diff_eq(Vec3::splat(2.), 1e-5));
+
+        // Start past the end of a tween; it's applied completed, without any event
+        let animator = Animator::new(make_tween(0., 1.))
+            .start_at(Duration::from_secs(5), SkippedCompletions::Suppress);
+        let mut env = TestEnv::new(animator);
+        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+        system.initialize(env.world_mut());
+
+        env.tick(Duration::ZERO, &mut system);
+        assert_eq!(env.event_count(), 0);
+        assert_eq!(env.animator().tweenable().times_completed(), 1);
+        assert_eq!(env.animator().tweenable().progress(), 1.);
+        assert!(env.transform().translation.abs_diff_eq(Vec3::ONE, 1e-5));
+        env.tick(Duration::from_secs(1), &mut system);
+        assert_eq!(env.event_count(), 0);
+
+        // Same, but reporting the skipped completion on the first tick
+        let animator = Animator::new(make_tween(0., 1.))
+            .start_at(Duration::from_secs(5), SkippedCompletions::Notify);
+        let mut env = TestEnv::new(animator);
+        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+        system.initialize(env.world_mut());
+
+        env.tick(Duration::ZERO, &mut system);
+        assert_eq!(env.event_count(), 1);
+        assert!(env.transform().translation.abs_diff_eq(Vec3::ONE, 1e-5));
+        env.tick(Duration::from_secs(1), &mut system);
+        assert_eq!(env.event_count(), 0);
+    }
+
+    #[test]
+    fn skip_to_end_nested() {
+        fn make_tween<L: Lens<Transform> + Send + Sync + 'static>(
+            millis: u64,
+            lens: L,
+            user_data: u64,
+        ) -> Tween<Transform> {
+            Tween::new(EaseMethod::Linear, Duration::from_millis(millis), lens)
+                .with_completed_event(user_data)
+        }
+
+        // Sequence > Tracks > Sequence, each level raising events
+        let make_sequence = || {
+            let position = |start: f32, end: f32| TransformPositionLens {
+                start: Vec3::splat(start),
+                end: Vec3::splat(end),
+            };
+            let scale = |start: f32, end: f32| TransformScaleLens {
+                start: Vec3::splat(start),
+                end: Vec3::splat(end),
+            };
+            let inner = make_tween(100, scale(1., 2.), 3).then(make_tween(100, scale(2., 3.), 4));
+            let tracks = Tracks::new([
+                BoxedTweenable::from(inner),
+                Box::new(make_tween(300, position(1., 2.), 5)),
+            ]);
+            Sequence::new([
+                BoxedTweenable::from(make_tween(1000, position(0., 1.), 1)),
+                Box::new(Delay::new(Duration::from_millis(200)).with_completed_event(2)),
+                Box::new(tracks),
+                Box::new(make_tween(100, scale(3., 4.), 6)),
+            ])
+        };
+
+        for skipped in [SkippedCompletions::Notify, SkippedCompletions::Suppress] {
+            let mut app = App::new();
+            app.add_plugins(
+                TweeningPlugin::default()
+                    .without_defaults()
+                    .register::<Transform>(),
+            )
+            .init_resource::<Time>();
+            let entity = app
+                .world
+                .spawn((Transform::default(), Animator::new(make_sequence())))
+                .id();
+            let mut reader = app.world.resource::<Events<TweenCompleted>>().get_reader();
+
+            app.world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(500));
+            app.update();
+            let mut animator = app.world.get_mut::<Animator<Transform>>(entity).unwrap();
+            assert!(animator.skip_to_end(skipped));
+            app.update();
+
+            let events = app.world.resource::<Events<TweenCompleted>>();
+            let user_data: Vec<u64> = reader.read(events).map(|event| event.user_data).collect();
+            match skipped {
+                SkippedCompletions::Notify => assert_eq!(user_data, [1, 2, 3, 4, 5, 6]),
+                SkippedCompletions::Suppress => assert!(user_data.is_empty()),
+            }
+            let animator = app.world.get::<Animator<Transform>>(entity).unwrap();
+            assert!(animator.just_completed());
+            assert_eq!(animator.completed_at(), Some(Duration::from_millis(500)));
+            assert_eq!(animator.tweenable().progress(), 1.);
+            let transform = app.world.get::<Transform>(entity).unwrap();
+            assert!(transform.translation.abs_diff_eq(Vec3::splat(2.), 1e-5));
+            assert!(transform.scale.abs_diff_eq(Vec3::splat(4.), 1e-5));
+
+            // Nothing is raised again on the next frames
+            app.update();
+            let events = app.world.resource::<Events<TweenCompleted>>();
+            assert_eq!(reader.read(events).count(), 0);
+        }
+
+        // An infinite animation can't be skipped
+        let mut animator = Animator::new(
+            make_tween(
+                100,
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+                0,
+            )
+            .with_repeat_count(RepeatCount::Infinite),
+        );
+        assert!(!animator.skip_to_end(SkippedCompletions::Notify));
+        assert_eq!(animator.tweenable().elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn resource_animator_state_changed() {
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            DummyLens { start: 0., end: 1. },
+        );
+        let mut env = TestEnv::new(ResourceAnimator::new(tween));
+        let entity = env.entity;
+        let mut system = IntoSystem::into_system(resource_animator_system::<DummyResource>);
+        system.initialize(env.world_mut());
+
+        // Transitions are reported even while the resource doesn't exist
+        env.world
+            .get_mut::<ResourceAnimator<DummyResource>>(entity)
+            .unwrap()
+            .state = AnimatorState::Paused;
+        env.tick(Duration::from_millis(100), &mut system);
+        assert_eq!(
+            env.state_events(),
+            vec![AnimatorStateChanged {
+                entity,
+                old: AnimatorState::Playing,
+                new: AnimatorState::Paused
+            }]
+        );
+    }
+
+    #[derive(Resource, Default)]
+    struct DummyResource {
+        value: f32,
+    }
+
+    struct DummyLens {
+        start: f32,
+        end: f32,
+    }
+
+    impl Lens<DummyResource> for DummyLens {
+        fn lerp(&mut self, target: &mut DummyResource, ratio: f32) {
+            target.value = self.start + (self.end - self.start) * ratio;
+        }
+    }
+
+    #[test]
+    fn resource_animator() {
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            DummyLens { start: 0., end: 1. },
+        )
+        .with_completed_event(0);
+
+        let mut env = TestEnv::new(ResourceAnimator::new(tween));
+        let mut system = IntoSystem::into_system(resource_animator_system::<DummyResource>);
+        system.initialize(env.world_mut());
+
+        // Missing resource; animator not ticked
+        env.tick(Duration::from_millis(500), &mut system);
+        let animator = env
+            .world
+            .get::<ResourceAnimator<DummyResource>>(env.entity)
+            .unwrap();
+        assert_eq!(animator.tweenable().elapsed(), Duration::ZERO);
+
+        env.world_mut().init_resource::<DummyResource>();
+        env.tick(Duration::from_millis(500), &mut system);
+        assert!((env.world.resource::<DummyResource>().value - 0.5).abs() < 1e-5);
+        assert_eq!(env.event_count(), 0);
+
+        env.tick(Duration::from_millis(500), &mut system);
+        assert!((env.world.resource::<DummyResource>().value - 1.).abs() < 1e-5);
+        assert_eq!(env.event_count(), 1);
+    }
+
+    #[cfg(feature = "bevy_pbr")]
+    #[test]
+    fn pbr_environment() {
+        use crate::lens::{AmbientLightLens, FogLens};
+
+        let mut app = App::new();
+        app.add_event::<TweenCompleted>()
+            .add_event::<AnimatorStateChanged>()
+            .init_resource::<Time>()
+            .add_systems(
+                Update,
+                (
+                    resource_animator_system::<AmbientLight>,
+                    component_animator_system::<FogSettings>,
+                ),
+            );
+        app.insert_resource(AmbientLight {
+            color: Color::WHITE,
+            brightness: 0.,
+        });
+        app.world.spawn(ResourceAnimator::new(Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(10),
+            AmbientLightLens {
+                start_color: Color::WHITE,
+                end_color: Color::BLACK,
+                start_brightness: 500.,
+                end_brightness: 100.,
+            },
+        )));
+        let camera = app
+            .world
+            .spawn((
+                FogSettings::default(),
+                Animator::new(Tween::new(
+                    EaseMethod::Linear,
+                    Duration::from_secs(10),
+                    FogLens {
+                        start_color: Color::WHITE,
+                        end_color: Color::BLACK,
+                        start_falloff: FogFalloff::Linear {
+                            start: 10.,
+                            end: 100.,
+                        },
+                        end_falloff: FogFalloff::Linear {
+                            start: 0.,
+                            end: 20.,
+                        },
+                    },
+                )),
+            ))
+            .id();
+
+        app.world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(5));
+        app.update();
+
+        let light = app.world.resource::<AmbientLight>();
+        assert_eq!(light.color, Color::rgba(0.5, 0.5, 0.5, 1.));
+        assert!((light.brightness - 300.).abs() < 1e-3);
+        let fog = app.world.get::<FogSettings>(camera).unwrap();
+        assert_eq!(fog.color, Color::rgba(0.5, 0.5, 0.5, 1.));
+        let FogFalloff::Linear { start, end } = fog.falloff else {
+            panic!("unexpected falloff {:?}", fog.falloff);
+        };
+        assert!((start - 5.).abs() < 1e-5);
+        assert!((end - 60.).abs() < 1e-5);
+    }
+
+    #[cfg(feature = "bevy_pbr")]
+    #[test]
+    fn pbr_camera_and_shadows() {
+        use bevy::render::camera::Exposure;
+
+        use crate::lens::{
+            DirectionalLightShadowDepthBiasLens, DirectionalLightShadowNormalBiasLens, ExposureLens,
+        };
+
+        let mut app = App::new();
+        app.add_event::<TweenCompleted>()
+            .add_event::<AnimatorStateChanged>()
+            .init_resource::<Time>()
+            .add_systems(
+                Update,
+                (
+                    component_animator_system::<Exposure>,
+                    component_animator_system::<DirectionalLight>,
+                ),
+            );
+        let camera = app
+            .world
+            .spawn((
+                Exposure::SUNLIGHT,
+                Animator::new(Tween::new(
+                    EaseMethod::Linear,
+                    Duration::from_secs(10),
+                    ExposureLens {
+                        start_ev100: Exposure::EV100_SUNLIGHT,
+                        end_ev100: Exposure::EV100_INDOOR,
+                    },
+                )),
+            ))
+            .id();
+        let depth_bias = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(10),
+            DirectionalLightShadowDepthBiasLens {
+                start: 0.02,
+                end: -0.02,
+            },
+        );
+        let normal_bias = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(10),
+            DirectionalLightShadowNormalBiasLens {
+                start: 1.8,
+                end: 0.6,
+            },
+        );
+        let sun = app
+            .world
+            .spawn((
+                DirectionalLight::default(),
+                Animator::new(Tracks::new([depth_bias, normal_bias])),
+            ))
+            .id();
+
+        app.world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(5));
+        app.update();
+
+        let exposure = app.world.get::<Exposure>(camera).unwrap();
+        assert!((exposure.ev100 - 11.).abs() < 1e-5);
+        let light = app.world.get::<DirectionalLight>(sun).unwrap();
+        assert!(light.shadow_depth_bias.abs() < 1e-5);
+        assert!((light.shadow_normal_bias - 1.2).abs() < 1e-5);
+    }
+
+    /// Create an app with the given plugin, and an entity with an animated
+    /// [`Transform`] and an animated [`DummyResource`].
+    fn make_plugin_app(plugin: TweeningPlugin) -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins(plugin)
+            .init_resource::<Time>()
+            .init_resource::<DummyResource>();
+        // Assets animated by the default systems
+        #[cfg(all(feature = "bevy_sprite", feature = "bevy_asset"))]
+        app.init_resource::<Assets<ColorMaterial>>();
+        #[cfg(feature = "bevy_pbr")]
+        app.init_resource::<Assets<StandardMaterial>>();
+        let entity = app
+            .world
+            .spawn((
+                Transform::default(),
+                Animator::new(Tween::new(
+                    EaseMethod::Linear,
+                    Duration::from_secs(1),
+                    TransformPositionLens {
+                        start: Vec3::ZERO,
+                        end: Vec3::ONE,
+                    },
+                )),
+                ResourceAnimator::new(Tween::new(
+                    EaseMethod::Linear,
+                    Duration::from_secs(1),
+                    DummyLens { start: 0., end: 1. },
+                )),
+            ))
+            .id();
+        (app, entity)
+    }
+
+    /// Tick the app by the given duration, and return the animated values.
+    fn tick_plugin_app(app: &mut App, entity: Entity, duration: Duration) -> (Vec3, f32) {
+        app.world.resource_mut::<Time>().advance_by(duration);
+        app.update();
+        (
+            app.world.get::<Transform>(entity).unwrap().translation,
+            app.world.resource::<DummyResource>().value,
+        )
+    }
+
+    #[test]
+    fn plugin_without_defaults() {
+        let (mut app, entity) = make_plugin_app(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register_resource::<DummyResource>(),
+        );
+        let (translation, value) = tick_plugin_app(&mut app, entity, Duration::from_millis(500));
+
+        // Transform was opted out; its animator does nothing
+        assert_eq!(translation, Vec3::ZERO);
+        let animator = app.world.get::<Animator<Transform>>(entity).unwrap();
+        assert_eq!(animator.tweenable().elapsed(), Duration::ZERO);
+
+        assert!((value - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn plugin_register_twice() {
+        // Transform is part of the defaults; registering it again, even twice,
+        // must not tick its animators more than once per frame.
+        let (mut app, entity) = make_plugin_app(
+            TweeningPlugin::default()
+                .register::<Transform>()
+                .register::<Transform>(),
+        );
+        let (translation, value) = tick_plugin_app(&mut app, entity, Duration::from_millis(500));
+        assert!(translation.abs_diff_eq(Vec3::splat(0.5), 1e-5));
+        // Not registered
+        assert_eq!(value, 0.);
+    }
+
+    #[test]
+    fn plugin_lens_registry() {
+        let (app, _) = make_plugin_app(TweeningPlugin::default());
+        let registry = app.world.resource::<LensRegistry>();
+        assert!(registry.contains::<Transform>("TransformPositionLens"));
+
+        // A registry inserted before the plugin is kept as is
+        let mut app = App::new();
+        app.insert_resource(LensRegistry::new())
+            .add_plugins(TweeningPlugin::default());
+        let registry = app.world.resource::<LensRegistry>();
+        assert_eq!(registry.names::<Transform>().count(), 0);
+    }
+
+    #[test]
+    fn plugin_schedule() {
+        let (mut app, entity) = make_plugin_app(
+            TweeningPlugin::default()
+                .with_schedule(PostUpdate)
+                .register_resource::<DummyResource>(),
+        );
+        // Reading in Update sees the state before the animators ticked this frame
+        app.add_systems(Update, |query: Query<&Transform>| {
+            for transform in &query {
+                assert_eq!(transform.translation, Vec3::ZERO);
+            }
+        });
+        let (translation, value) = tick_plugin_app(&mut app, entity, Duration::from_millis(500));
+        assert!(translation.abs_diff_eq(Vec3::splat(0.5), 1e-5));
+        assert!((value - 0.5).abs() < 1e-5);
+    }
+
+    #[cfg(feature = "bevy_asset")]
+    #[derive(Asset, Default, Reflect)]
+    struct DummyAsset {
+        value: f32,
+    }
+
+    #[cfg(feature = "bevy_asset")]
+    struct DummyAssetLens {
+        start: f32,
+        end: f32,
+    }
+
+    #[cfg(feature = "bevy_asset")]
+    impl Lens<DummyAsset> for DummyAssetLens {
+        fn lerp(&mut self, target: &mut DummyAsset, ratio: f32) {
+            target.value = self.start + (self.end - self.start) * ratio;
+        }
+    }
+
+    /// A synced animator applies the same eased factor to its component and
+    /// its asset each frame, and raises a single completion event.
+    #[cfg(feature = "bevy_asset")]
+    #[test]
+    fn synced_animator() {
+        let mut app = App::new();
+        app.add_plugins(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register_synced::<Transform, DummyAsset>(),
+        )
+        .init_resource::<Time>()
+        .init_resource::<Assets<DummyAsset>>();
+
+        let handle = app
+            .world
+            .resource_mut::<Assets<DummyAsset>>()
+            .add(DummyAsset::default());
+        let tween = Tween::new(
+            EaseFunction::QuadraticInOut,
+            Duration::from_secs(1),
+            SyncFactorLens { start: 0., end: 1. },
+        )
+        .with_completed_event(42);
+        let entity = app
+            .world
+            .spawn((
+                Transform::default(),
+                handle.clone(),
+                SyncedAnimator::new(
+                    tween,
+                    TransformScaleLens {
+                        start: Vec3::ZERO,
+                        end: Vec3::ONE,
+                    },
+                    DummyAssetLens { start: 0., end: 1. },
+                ),
+            ))
+            .id();
+
+        let ease = EaseMethod::from(EaseFunction::QuadraticInOut);
+        let mut completed = 0;
+        for i in 1..=12 {
+            app.world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(100));
+            app.update();
+
+            let scale = app.world.get::<Transform>(entity).unwrap().scale.x;
+            let value = app
+                .world
+                .resource::<Assets<DummyAsset>>()
+                .get(&handle)
+                .unwrap()
+                .value;
+            assert_eq!(scale, value);
+            let expected = ease.sample((i as f32 / 10.).min(1.));
+            assert!(
+                (scale - expected).abs() < 1e-5,
+                "{i}: {scale} != {expected}"
+            );
+            let animator = app
+                .world
+                .get::<SyncedAnimator<Transform, DummyAsset>>(entity)
+                .unwrap();
+            assert_eq!(animator.factor(), scale);
+
+            let events = app.world.resource::<Events<TweenCompleted>>();
+            for ev in events.get_reader().read(events) {
+                assert_eq!(ev.entity, entity);
+                assert_eq!(ev.user_data, 42);
+                completed += 1;
+            }
+            app.world.resource_mut::<Events<TweenCompleted>>().update();
+        }
+        assert_eq!(completed, 1);
+    }
+
+    /// An asset animator following the handle of its entity continues on the
+    /// new asset when the handle is swapped, while a fixed one doesn't.
+    #[cfg(feature = "bevy_asset")]
+    #[test]
+    fn asset_animator_handle_swap() {
+        let mut app = App::new();
+        app.add_plugins(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register_asset::<DummyAsset>(),
+        )
+        .init_resource::<Time>()
+        .init_resource::<Assets<DummyAsset>>();
+
+        let mut assets = app.world.resource_mut::<Assets<DummyAsset>>();
+        let old = assets.add(DummyAsset::default());
+        let new = assets.add(DummyAsset::default());
+        let fixed = assets.add(DummyAsset::default());
+        let make_animator = || {
+            AssetAnimator::new(Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                DummyAssetLens { start: 0., end: 1. },
+            ))
+        };
+        let following = app.world.spawn((old.clone(), make_animator())).id();
+        let pinned = app
+            .world
+            .spawn((
+                old.clone(),
+                make_animator().with_target(AssetAnimatorTarget::FixedHandle(fixed.clone())),
+            ))
+            .id();
+        assert_eq!(
+            *app.world
+                .get::<AssetAnimator<DummyAsset>>(following)
+                .unwrap()
+                .target(),
+            AssetAnimatorTarget::FollowComponentHandle
+        );
+
+        let tick = |app: &mut App| {
+            app.world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(250));
+            app.update();
+        };
+        let value = |app: &App, handle: &Handle<DummyAsset>| {
+            app.world
+                .resource::<Assets<DummyAsset>>()
+                .get(handle)
+                .unwrap()
+                .value
+        };
+
+        tick(&mut app);
+        tick(&mut app);
+        assert!((value(&app, &old) - 0.5).abs() < 1e-5);
+        assert!((value(&app, &fixed) - 0.5).abs() < 1e-5);
+        assert_eq!(value(&app, &new), 0.);
+
+        // Swap the handles halfway
+        app.world.entity_mut(following).insert(new.clone());
+        app.world.entity_mut(pinned).insert(new.clone());
+        tick(&mut app);
+        assert!((value(&app, &old) - 0.5).abs() < 1e-5);
+        assert!((value(&app, &new) - 0.75).abs() < 1e-5);
+        assert!((value(&app, &fixed) - 0.75).abs() < 1e-5);
+
+        tick(&mut app);
+        assert!((value(&app, &old) - 0.5).abs() < 1e-5);
+        assert!((value(&app, &new) - 1.).abs() < 1e-5);
+        assert!((value(&app, &fixed) - 1.).abs() < 1e-5);
+
+        // A fixed handle doesn't need a handle component
+        app.world.entity_mut(pinned).remove::<Handle<DummyAsset>>();
+        let mut animator = app
+            .world
+            .get_mut::<AssetAnimator<DummyAsset>>(pinned)
+            .unwrap();
+        animator.tweenable_mut().rewind();
+        tick(&mut app);
+        assert!((value(&app, &fixed) - 0.25).abs() < 1e-5);
+    }
+
+    /// State transitions of an asset animator are reported even while its
+    /// handle doesn't resolve.
+    #[cfg(feature = "bevy_asset")]
+    #[test]
+    fn asset_animator_state_changed_without_handle() {
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            DummyAssetLens { start: 0., end: 1. },
+        );
+        let mut env = TestEnv::new(AssetAnimator::<DummyAsset>::new(tween));
+        env.world.init_resource::<Assets<DummyAsset>>();
+        let entity = env.entity;
+        let mut system = IntoSystem::into_system(asset_animator_system::<DummyAsset>);
+        system.initialize(env.world_mut());
+
+        env.world
+            .get_mut::<AssetAnimator<DummyAsset>>(entity)
+            .unwrap()
+            .stop();
+        env.tick(Duration::from_millis(100), &mut system);
+        assert_eq!(
+            env.state_events(),
+            vec![AnimatorStateChanged {
+                entity,
+                old: AnimatorState::Playing,
+                new: AnimatorState::Paused
+            }]
+        );
+    }
+
+    #[derive(Component)]
+    struct Marker;
+
+    #[test]
+    fn deterministic_order() {
+        let mut app = App::new();
+        app.add_plugins(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register::<Transform>()
+                .with_deterministic_order(),
+        )
+        .init_resource::<Time>();
+        assert_eq!(
+            *app.world.resource::<AnimationOrder>(),
+            AnimationOrder::SortedByEntity
+        );
+
+        // Despawned entities are reused in reverse order, and every third
+        // entity lives in another archetype, so the query iteration order is
+        // unrelated to the entity order.
+        let entities: Vec<Entity> = (0..12).map(|_| app.world.spawn_empty().id()).collect();
+        for entity in entities {
+            app.world.despawn(entity);
+        }
+        for i in 0..12 {
+            let tween = Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+            )
+            .with_completed_event(i);
+            let mut entity = app
+                .world
+                .spawn((Transform::default(), Animator::new(tween)));
+            if i % 3 == 0 {
+                entity.insert(Marker);
+            }
+        }
+
+        app.world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        app.update();
+
+        let events = app.world.resource::<Events<TweenCompleted>>();
+        let order: Vec<(u32, u32)> = events
+            .get_reader()
+            .read(events)
+            .map(|ev| (ev.entity.index(), ev.entity.generation()))
+            .collect();
+        assert_eq!(order.len(), 12);
+        let mut sorted = order.clone();
+        sorted.sort_unstable();
+        assert_eq!(order, sorted);
+    }
+
+    /// Under a tight budget, the animators are updated less often but still
+    /// complete and loop at the correct total elapsed time.
+    #[test]
+    fn budget_stress() {
+        let mut app = App::new();
+        app.add_plugins(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register::<Transform>()
+                .with_budget(AnimationBudget::Animators(10)),
+        )
+        .init_resource::<Time>();
+
+        // Exactly representable as f32, so the time doesn't drift
+        let frame = Duration::from_nanos(15_625_000);
+        let entities: Vec<Entity> = (0..100)
+            .map(|i| {
+                let tween = Tween::new(
+                    EaseMethod::Linear,
+                    Duration::from_secs(1),
+                    TransformPositionLens {
+                        start: Vec3::ZERO,
+                        end: Vec3::ONE,
+                    },
+                )
+                .with_completed_event(i);
+                app.world
+                    .spawn((Transform::default(), Animator::new(tween)))
+                    .id()
+            })
+            .collect();
+
+        // Each animator is ticked once every 10 frames, so completes at most
+        // 9 frames late.
+        let mut completed_at = vec![None; 100];
+        for _ in 0..100 {
+            app.world.resource_mut::<Time>().advance_by(frame);
+            app.update();
+            let now = app.world.resource::<Time>().elapsed();
+            for ev in app.world.resource_mut::<Events<TweenCompleted>>().drain() {
+                assert!(completed_at[ev.user_data as usize].replace(now).is_none());
+            }
+        }
+        let mut frames = vec![];
+        for (entity, completed_at) in entities.iter().zip(completed_at) {
+            let completed_at = completed_at.unwrap();
+            assert!(completed_at >= Duration::from_secs(1));
+            assert!(completed_at < Duration::from_secs(1) + frame * 10);
+            frames.push(completed_at);
+            let transform = app.world.get::<Transform>(*entity).unwrap();
+            assert_eq!(transform.translation, Vec3::ONE);
+        }
+        frames.sort();
+        frames.dedup();
+        assert_eq!(frames.len(), 10);
+
+        // Looping animators don't drift, with a single animator ticked per
+        // frame.
+        let mut app = App::new();
+        app.add_plugins(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register::<Transform>()
+                .with_budget(AnimationBudget::Time(Duration::ZERO)),
+        )
+        .init_resource::<Time>();
+        let entities: Vec<Entity> = (0..8)
+            .map(|_| {
+                let tween = Tween::new(
+                    EaseMethod::Linear,
+                    frame * 16,
+                    TransformPositionLens {
+                        start: Vec3::ZERO,
+                        end: Vec3::ONE,
+                    },
+                )
+                .with_repeat_count(RepeatCount::Infinite);
+                let animator = Animator::new(tween).with_speed(0.5);
+                app.world.spawn((Transform::default(), animator)).id()
+            })
+            .collect();
+        for _ in 0..200 {
+            app.world.resource_mut::<Time>().advance_by(frame);
+            app.update();
+        }
+        let ticked = entities
+            .iter()
+            .filter(|entity| {
+                let animator = app.world.get::<Animator<Transform>>(**entity).unwrap();
+                animator.tweenable().elapsed() == frame * 100
+            })
+            .count();
+        assert_eq!(ticked, 1);
+
+        // Removing the budget catches up with the time skipped
+        app.world.remove_resource::<AnimationBudget>();
+        app.world.resource_mut::<Time>().advance_by(Duration::ZERO);
+        app.update();
+        for entity in entities {
+            let animator = app.world.get::<Animator<Transform>>(entity).unwrap();
+            assert_eq!(animator.tweenable().elapsed(), frame * 100);
+            assert_eq!(animator.tweenable().times_completed(), 6);
+            assert!((animator.tweenable().progress() - 0.25).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn plugin_added_twice() {
+        // Several dependencies adding the plugin and registering the same
+        // types must not tick the animators more than once per frame.
+        let (mut app, entity) = make_plugin_app(TweeningPlugin::default());
+        app.add_plugins(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register::<Transform>()
+                .register_resource::<DummyResource>(),
+        )
+        .add_tweenable_component::<Transform>()
+        .add_tweenable_component::<Transform>()
+        .add_tweenable_resource::<DummyResource>()
+        .add_tweenable_resource::<DummyResource>();
+        let registry = app.world.resource::<super::RegisteredTargets>();
+        assert_eq!(
+            registry.targets.len(),
+            TweeningPlugin::default_targets().len() + 1
+        );
+
+        let (translation, value) = tick_plugin_app(&mut app, entity, Duration::from_millis(500));
+        assert!(translation.abs_diff_eq(Vec3::splat(0.5), 1e-5));
+        assert!((value - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn app_ext_without_plugin() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_tweenable_component::<Transform>()
+            .add_tweenable_component::<Transform>();
+        assert!(app.world.contains_resource::<Events<TweenCompleted>>());
+        let entity = app
+            .world
+            .spawn((
+                Transform::default(),
+                Animator::new(
+                    Tween::new(
+                        EaseMethod::Linear,
+                        Duration::from_secs(1),
+                        TransformPositionLens {
+                            start: Vec3::ZERO,
+                            end: Vec3::ONE,
+                        },
+                    )
+                    .with_completed_event(7),
+                ),
+            ))
+            .id();
+
+        app.world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(500));
+        app.update();
+        let transform = app.world.get::<Transform>(entity).unwrap();
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.5), 1e-5));
+
+        // The plugin added afterwards reuses the registrations
+        app.add_plugins(TweeningPlugin::default().without_defaults());
+        app.world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(500));
+        app.update();
+        let transform = app.world.get::<Transform>(entity).unwrap();
+        assert_eq!(transform.translation, Vec3::ONE);
+        let events = app.world.resource::<Events<TweenCompleted>>();
+        assert_eq!(events.get_reader().read(events).count(), 1);
+    }
+
+    /// Shrinking animators on completion replaces their tweenables, without
+    /// removing the animator components. The memory reclaimed is measured by
+    /// the `shrink_allocations` integration test.
+    #[test]
+    fn shrink_on_complete() {
+        const COUNT: usize = 1000;
+
+        let mut world = World::new();
+        world.init_resource::<Events<TweenCompleted>>();
+        world.init_resource::<Events<AnimatorStateChanged>>();
+        world.init_resource::<Time>();
+        let make_tween = || {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+            )
+        };
+        let entities: Vec<Entity> = (0..COUNT)
+            .map(|_| {
+                let animator = Animator::new(make_tween().then(make_tween()).then(make_tween()))
+                    .with_shrink_on_complete(true);
+                world.spawn((Transform::default(), animator)).id()
+            })
+            .collect();
+
+        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+        system.initialize(&mut world);
+        let mut tick = |world: &mut World, duration: Duration| {
+            world.resource_mut::<Time>().advance_by(duration);
+            system.run((), world);
+        };
+
+        // Not completed yet; nothing shrunk
+        tick(&mut world, Duration::from_secs(2));
+        for &entity in &entities {
+            assert!(!world
+                .get::<Animator<Transform>>(entity)
+                .unwrap()
+                .is_shrunk());
+        }
+
+        tick(&mut world, Duration::from_secs(1));
+        for &entity in &entities {
+            let animator = world.get::<Animator<Transform>>(entity).unwrap();
+            assert!(animator.is_shrunk());
+            assert_eq!(animator.tweenable().progress(), 1.);
+            assert_eq!(animator.tweenable().times_completed(), 1);
+            assert_eq!(animator.tweenable().duration(), Duration::from_secs(3));
+            let transform = world.get::<Transform>(entity).unwrap();
+            assert_eq!(transform.translation, Vec3::ONE);
+        }
+
+        // Ticking again keeps the placeholder
+        tick(&mut world, Duration::from_secs(1));
+        assert!(world
+            .get::<Animator<Transform>>(entities[1])
+            .unwrap()
+            .is_shrunk());
+
+        // Setting a new tweenable plays again
+        let mut animator = world.get_mut::<Animator<Transform>>(entities[0]).unwrap();
+        animator.stop();
+        assert!(animator.is_shrunk());
+        animator.state = AnimatorState::Playing;
+        animator.set_tweenable(make_tween());
+        assert!(!animator.is_shrunk());
+        tick(&mut world, Duration::from_millis(500));
+        let transform = world.get::<Transform>(entities[0]).unwrap();
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.5), 1e-5));
+    }
+
+    #[test]
+    fn completed_since() {
+        let make_tween = || {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+            )
+        };
+        let mut env = TestEnv::new(Animator::new(make_tween()));
+        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+        system.initialize(env.world_mut());
+
+        env.tick(Duration::from_millis(500), &mut system);
+        assert_eq!(env.animator().completed_at(), None);
+        assert_eq!(
+            env.animator().completed_since(Duration::from_secs(10)),
+            None
+        );
+
+        // Completes at 1.5s since startup
+        env.tick(Duration::from_secs(1), &mut system);
+        assert_eq!(
+            env.animator().completed_at(),
+            Some(Duration::from_millis(1500))
+        );
+        env.tick(Duration::from_secs(1), &mut system);
+        assert_eq!(
+            env.animator().completed_at(),
+            Some(Duration::from_millis(1500))
+        );
+        assert_eq!(
+            env.animator().completed_since(Duration::from_secs(4)),
+            Some(Duration::from_millis(2500))
+        );
+
+        // Rewinding a paused animator resets the time on next tick
+        {
+            let mut animator = env.animator_mut();
+            animator.state = AnimatorState::Paused;
+            animator.tweenable_mut().rewind();
+        }
+        env.tick(Duration::from_secs(1), &mut system);
+        assert_eq!(env.animator().completed_at(), None);
+
+        // Completes again at 4.5s since startup
+        env.animator_mut().state = AnimatorState::Playing;
+        env.tick(Duration::from_secs(1), &mut system);
+        assert_eq!(
+            env.animator().completed_at(),
+            Some(Duration::from_millis(4500))
+        );
+
+        // Replacing the tweenable resets the time
+        env.animator_mut().set_tweenable(make_tween());
+        assert_eq!(env.animator().completed_at(), None);
+
+        // Looping tweenables never complete
+        env.animator_mut()
+            .set_tweenable(make_tween().with_repeat_count(RepeatCount::Infinite));
+        env.tick(Duration::from_secs(3), &mut system);
+        assert_eq!(env.animator().completed_at(), None);
+    }
+
+    #[test]
+    fn idle_cleanup() {
+        for policy in [CleanupPolicy::Remove, CleanupPolicy::Despawn] {
+            let (mut app, entity) = make_plugin_app(
+                TweeningPlugin::default()
+                    .register_resource::<DummyResource>()
+                    .with_idle_cleanup(Duration::from_secs(2), policy),
+            );
+            app.world
+                .get_mut::<Animator<Transform>>(entity)
+                .unwrap()
+                .set_shrink_on_complete(true);
+
+            // Completes at 1s, idle for 2s at 3s
+            tick_plugin_app(&mut app, entity, Duration::from_secs(1));
+            tick_plugin_app(&mut app, entity, Duration::from_secs(2));
+            assert!(app
+                .world
+                .get::<Animator<Transform>>(entity)
+                .unwrap()
+                .is_shrunk());
+            assert!(app
+                .world
+                .get::<ResourceAnimator<DummyResource>>(entity)
+                .is_some());
+
+            // Idle for longer than the threshold
+            app.world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(1));
+            app.update();
+            match policy {
+                CleanupPolicy::Remove => {
+                    let entity = app.world.entity(entity);
+                    assert!(!entity.contains::<Animator<Transform>>());
+                    assert!(!entity.contains::<ResourceAnimator<DummyResource>>());
+                    assert_eq!(entity.get::<Transform>().unwrap().translation, Vec3::ONE);
+                }
+                CleanupPolicy::Despawn => assert!(app.world.get_entity(entity).is_none()),
+            }
+        }
+    }
+
+    #[test]
+    fn frame_flags() {
+        fn tick(app: &mut App, entity: Entity, millis: u64) -> (bool, u32, bool) {
+            tick_plugin_app(app, entity, Duration::from_millis(millis));
+            let animator = app.world.get::<Animator<Transform>>(entity).unwrap();
+            (
+                animator.just_started(),
+                animator.just_looped(),
+                animator.just_completed(),
+            )
+        }
+
+        let (mut app, entity) = make_plugin_app(TweeningPlugin::default());
+        app.world
+            .get_mut::<Animator<Transform>>(entity)
+            .unwrap()
+            .set_tweenable(
+                Tween::new(
+                    EaseMethod::Linear,
+                    Duration::from_secs(1),
+                    TransformPositionLens {
+                        start: Vec3::ZERO,
+                        end: Vec3::ONE,
+                    },
+                )
+                .with_repeat_count(RepeatCount::Finite(2)),
+            );
+
+        // Each flag is set for exactly one frame
+        assert_eq!(tick(&mut app, entity, 0), (true, 0, false));
+        assert_eq!(tick(&mut app, entity, 500), (false, 0, false));
+        assert_eq!(tick(&mut app, entity, 500), (false, 1, false));
+        assert_eq!(tick(&mut app, entity, 250), (false, 0, false));
+        assert_eq!(tick(&mut app, entity, 750), (false, 1, true));
+        assert_eq!(tick(&mut app, entity, 100), (false, 0, false));
+
+        // Playing again after a stop starts again
+        let mut animator = app.world.get_mut::<Animator<Transform>>(entity).unwrap();
+        animator.stop();
+        animator.state = AnimatorState::Playing;
+        assert_eq!(tick(&mut app, entity, 2500), (true, 2, true));
+        assert_eq!(tick(&mut app, entity, 100), (false, 0, false));
+
+        // Paused animators have no flag set
+        app.world
+            .get_mut::<Animator<Transform>>(entity)
+            .unwrap()
+            .stop();
+        assert_eq!(tick(&mut app, entity, 100), (false, 0, false));
+    }
+
+    #[test]
+    fn cancelled_events() {
+        let mut app = App::new();
+        app.add_plugins(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register::<Transform>(),
+        )
+        .init_resource::<Time>();
+        let make_tween = |user_data| {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+            )
+            .with_completed_event(user_data)
+        };
+        let tick = |app: &mut App, millis| {
+            app.world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(millis));
+            app.update();
+            app.world
+                .resource_mut::<Events<TweenCancelled>>()
+                .drain()
+                .map(|ev| (ev.entity, ev.user_data, ev.progress_at_cancel))
+                .collect::<Vec<_>>()
+        };
+
+        // Looping animation, never completing
+        let entity = app
+            .world
+            .spawn((
+                Transform::default(),
+                Animator::new(make_tween(1).with_repeat_count(RepeatCount::Infinite)),
+            ))
+            .id();
+        assert!(tick(&mut app, 1250).is_empty());
+
+        // Replacing the tweenable cancels the previous one
+        fn animator(app: &mut App, entity: Entity) -> Mut<'_, Animator<Transform>> {
+            app.world.get_mut::<Animator<Transform>>(entity).unwrap()
+        }
+        animator(&mut app, entity).set_tweenable(make_tween(2));
+        assert_eq!(tick(&mut app, 0), [(entity, 1, 0.25)]);
+
+        // Stopping twice, then removing the animator, cancels once
+        assert!(tick(&mut app, 500).is_empty());
+        animator(&mut app, entity).stop();
+        animator(&mut app, entity).stop();
+        assert_eq!(tick(&mut app, 0), [(entity, 2, 0.5)]);
+        app.world.entity_mut(entity).remove::<Animator<Transform>>();
+        assert!(tick(&mut app, 0).is_empty());
+
+        // Removing a playing animator cancels it
+        app.world
+            .entity_mut(entity)
+            .insert(Animator::new(make_tween(3)));
+        assert!(tick(&mut app, 100).is_empty());
+        app.world.entity_mut(entity).remove::<Animator<Transform>>();
+        assert_eq!(tick(&mut app, 0), [(entity, 3, 0.1)]);
+
+        // Removing a completed animator doesn't
+        app.world
+            .entity_mut(entity)
+            .insert(Animator::new(make_tween(4)));
+        assert!(tick(&mut app, 1000).is_empty());
+        app.world.entity_mut(entity).remove::<Animator<Transform>>();
+        assert!(tick(&mut app, 0).is_empty());
+
+        // Neither does playing again a stopped animator, until cancelled
+        // again by despawning its entity
+        app.world
+            .entity_mut(entity)
+            .insert(Animator::new(make_tween(5)));
+        animator(&mut app, entity).stop();
+        assert_eq!(tick(&mut app, 0), [(entity, 5, 0.)]);
+        animator(&mut app, entity).state = AnimatorState::Playing;
+        assert!(tick(&mut app, 200).is_empty());
+        app.world.despawn(entity);
+        assert_eq!(tick(&mut app, 0), [(entity, 5, 0.2)]);
+    }
+
+    #[test]
+    fn animator_reverse() {
+        // Hover animation, reversed after 40% when the cursor leaves
+        let tween = Tween::new(
+            EaseFunction::QuadraticInOut,
+            Duration::from_secs(1),
+            TransformScaleLens {
+                start: Vec3::ONE,
+                end: Vec3::splat(1.5),
+            },
+        )
+        .with_completed_event(0);
+        let mut env = TestEnv::new(Animator::new(tween));
+        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+        system.initialize(env.world_mut());
+        assert_eq!(env.animator().direction(), Some(TweeningDirection::Forward));
+        assert!(!env.animator().is_reversed());
+
+        let mut forward = vec![env.transform().scale];
+        for _ in 0..4 {
+            env.tick(Duration::from_millis(100), &mut system);
+            forward.push(env.transform().scale);
+        }
+
+        assert!(env.animator_mut().reverse());
+        assert_eq!(
+            env.animator().direction(),
+            Some(TweeningDirection::Backward)
+        );
+        assert!(env.animator().is_reversed());
+
+        // The current value is preserved, then the same values are retraced
+        env.tick(Duration::ZERO, &mut system);
+        let mut backward = vec![env.transform().scale];
+        for _ in 0..4 {
+            env.tick(Duration::from_millis(100), &mut system);
+            backward.push(env.transform().scale);
+        }
+        backward.reverse();
+        for (forward, backward) in forward.iter().zip(backward.iter()) {
+            assert!(forward.abs_diff_eq(*backward, 1e-5));
+        }
+
+        // Back to the start value, the reversed animation completes
+        assert_eq!(env.event_count(), 1);
+        assert_eq!(env.animator().time_remaining(), Some(Duration::ZERO));
+
+        // Reversing again plays the last iteration forward from the start
+        assert!(env.animator_mut().reverse());
+        assert!(!env.animator().is_reversed());
+        env.tick(Duration::from_millis(100), &mut system);
+        assert!(env.transform().scale.abs_diff_eq(forward[1], 1e-5));
+
+        // Tweenables without directions are left untouched
+        env.animator_mut()
+            .set_tweenable(Delay::new(Duration::from_secs(1)));
+        env.tick(Duration::from_millis(300), &mut system);
+        let elapsed = env.animator().tweenable().elapsed();
+        assert!(!env.animator_mut().reverse());
+        assert_eq!(env.animator().direction(), None);
+        assert!(!env.animator().is_reversed());
+        assert_eq!(env.animator().tweenable().elapsed(), elapsed);
+    }
+
+    #[test]
+    fn animator_system_span() {
+        use std::sync::{Arc, Mutex};
+
+        use bevy::utils::tracing::{
+            field::{Field, Visit},
+            span, subscriber, Event, Metadata, Subscriber,
+        };
+
+        /// Subscriber recording the name and fields of the spans created.
+        #[derive(Default, Clone)]
+        struct SpanRecorder(Arc<Mutex<Vec<String>>>);
+
+        /// Visitor appending the fields of a span to its description.
+        struct SpanDesc(String);
+
+        impl Visit for SpanDesc {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0.push_str(&format!(" {}={:?}", field.name(), value));
+            }
+        }
+
+        impl Subscriber for SpanRecorder {
+            fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+                let mut spans = self.0.lock().unwrap();
+                let mut desc = SpanDesc(span.metadata().name().to_string());
+                span.record(&mut desc);
+                spans.push(desc.0);
+                span::Id::from_u64(spans.len() as u64)
+            }
+
+            fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+            fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+            fn event(&self, _event: &Event<'_>) {}
+            fn enter(&self, _span: &span::Id) {}
+            fn exit(&self, _span: &span::Id) {}
+        }
+
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+        let mut env = TestEnv::new(Animator::new(tween));
+        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+        system.initialize(env.world_mut());
+
+        let recorder = SpanRecorder::default();
+        subscriber::with_default(recorder.clone(), || {
+            env.tick(Duration::from_millis(100), &mut system);
+        });
+        let spans = recorder.0.lock().unwrap();
+        assert!(
+            spans.contains(&format!(
+                "component_animator_system target_type={:?}",
+                std::any::type_name::<Transform>()
+            )),
+            "{spans:?}"
+        );
+    }
+
+    #[cfg(feature = "diagnostics")]
+    #[test]
+    fn animator_diagnostics() {
+        use bevy::diagnostic::DiagnosticsStore;
+
+        use crate::diagnostics::AnimatorDiagnosticPaths;
+
+        let (mut app, entity) = make_plugin_app(TweeningPlugin::default());
+        app.world.spawn((
+            Transform::default(),
+            Animator::new(Delay::<Transform>::new(Duration::from_secs(1))),
+        ));
+        tick_plugin_app(&mut app, entity, Duration::from_millis(100));
+
+        let store = app.world.resource::<DiagnosticsStore>();
+        let paths = AnimatorDiagnosticPaths::of::<Animator<Transform>>();
+        assert_eq!(
+            store.get_measurement(&paths.entity_count).unwrap().value,
+            2.
+        );
+        assert!(store.get_measurement(&paths.tick_time).unwrap().value >= 0.);
+
+        // Targets not registered have no diagnostics
+        let paths = AnimatorDiagnosticPaths::of::<ResourceAnimator<DummyResource>>();
+        assert!(store.get(&paths.entity_count).is_none());
+    }
+
+    #[test]
+    fn write_coalescing() {
+        #[derive(Default, Resource)]
+        struct ChangedCount(Vec<usize>);
+
+        fn count_changed(query: Query<(), Changed<Transform>>, mut count: ResMut<ChangedCount>) {
+            count.0.push(query.iter().count());
+        }
+
+        fn run(plugin: TweeningPlugin) -> Vec<usize> {
+            let mut app = App::new();
+            app.add_plugins(plugin.without_defaults().register::<Transform>())
+                .init_resource::<Time>()
+                .init_resource::<ChangedCount>()
+                .add_systems(PostUpdate, count_changed);
+            // Three tracks writing the same component, all holding their
+            // value for the first half.
+            let tween = |ease| {
+                Tween::new(
+                    ease,
+                    Duration::from_secs(1),
+                    TransformPositionLens {
+                        start: Vec3::ZERO,
+                        end: Vec3::ONE,
+                    },
+                )
+            };
+            let scale = Tween::new(
+                EaseMethod::Discrete(0.5),
+                Duration::from_secs(1),
+                TransformScaleLens {
+                    start: Vec3::ONE,
+                    end: Vec3::splat(2.),
+                },
+            );
+            let tracks = Tracks::new([
+                tween(EaseMethod::Discrete(0.5)),
+                tween(EaseMethod::Discrete(0.5)),
+                scale,
+            ]);
+            let entity = app
+                .world
+                .spawn((Transform::default(), Animator::new(tracks)))
+                .id();
+            for _ in 0..6 {
+                app.world
+                    .resource_mut::<Time>()
+                    .advance_by(Duration::from_millis(200));
+                app.update();
+            }
+            let transform = app.world.get::<Transform>(entity).unwrap();
+            assert_eq!(transform.translation, Vec3::ONE);
+            assert_eq!(transform.scale, Vec3::splat(2.));
+            std::mem::take(&mut app.world.resource_mut::<ChangedCount>().0)
+        }
+
+        // The first frame sees the spawn, and the animator completes on the
+        // fifth. Without coalescing, each tick triggers change detection even
+        // when nothing changed.
+        assert_eq!(run(TweeningPlugin::default()), [1, 1, 1, 1, 1, 0]);
+        // With coalescing, only the tick where the values change does.
+        assert_eq!(
+            run(TweeningPlugin::default().with_write_coalescing()),
+            [1, 0, 1, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn last_progress() {
+        let make_tween = || {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+            )
+        };
+        let animator = Animator::new(make_tween().then(make_tween().with_repeat_count(2)))
+            .with_shrink_on_complete(true);
+        let mut env = TestEnv::new(animator);
+        let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+        system.initialize(env.world_mut());
+        assert_eq!(env.animator().last_progress(), 0.);
+        assert_eq!(env.animator().last_times_completed(), 0);
+
+        for _ in 0..5 {
+            env.tick(Duration::from_millis(700), &mut system);
+            let animator = env.animator();
+            assert_eq!(animator.last_progress(), animator.tweenable().progress());
+            assert_eq!(
+                animator.last_times_completed(),
+                animator.tweenable().times_completed()
+            );
+        }
+
+        // Completed and shrunk, the final values are retained
+        assert!(env.animator().is_shrunk());
+        assert_eq!(env.animator().last_progress(), 1.);
+        assert_eq!(env.animator().last_times_completed(), 1);
+
+        // Not updated until the next tick
+        env.animator_mut().state = AnimatorState::Paused;
+        env.animator_mut().set_tweenable(make_tween());
+        assert_eq!(env.animator().last_progress(), 0.);
+        env.animator_mut().tweenable_mut().set_progress(0.5);
+        env.tick(Duration::from_millis(100), &mut system);
+        assert_eq!(env.animator().last_progress(), 0.);
+        env.animator_mut().state = AnimatorState::Playing;
+        env.tick(Duration::from_millis(100), &mut system);
+        assert!((env.animator().last_progress() - 0.6).abs() < 1e-5);
+    }
+
+    #[test]
+    fn small_deltas_precision() {
+        // A long tween ticked at 480 Hz advances by tiny fractions per tick,
+        // with a frame delta which is not a round number of nanoseconds in
+        // seconds
+        const DELTA: Duration = Duration::from_nanos(2_083_333);
+
+        fn run(speed: f32) -> (Vec<f32>, Vec<Duration>) {
+            let mut app = App::new();
+            app.add_plugins(
+                TweeningPlugin::default()
+                    .without_defaults()
+                    .register::<Transform>(),
+            )
+            .init_resource::<Time>();
+            let tween = Tween::new(
+                EaseFunction::QuadraticInOut,
+                Duration::from_secs(60),
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+            );
+            let entity = app
+                .world
+                .spawn((Transform::default(), Animator::new(tween).with_speed(speed)))
+                .id();
+            let mut values = vec![];
+            let mut elapsed = vec![];
+            for _ in 0..30_000 {
+                app.world.resource_mut::<Time>().advance_by(DELTA);
+                app.update();
+                values.push(app.world.get::<Transform>(entity).unwrap().translation.x);
+                let animator = app.world.get::<Animator<Transform>>(entity).unwrap();
+                elapsed.push(animator.tweenable().elapsed());
+            }
+            (values, elapsed)
+        }
+
+        for speed in [1., 0.5] {
+            let (values, elapsed) = run(speed);
+            let step = DELTA.mul_f64(f64::from(speed));
+
+            // The elapsed time accumulates exactly, without drifting, until
+            // the end is reached
+            let duration = Duration::from_secs(60);
+            for (index, elapsed) in elapsed.iter().enumerate() {
+                assert_eq!(*elapsed, (step * (index as u32 + 1)).min(duration));
+            }
+            let ticks = elapsed.iter().take_while(|e| **e < duration).count();
+
+            // Every tick moves forward, and every window of ticks visibly so
+            assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+            for window in values[..ticks].chunks(100) {
+                assert!(window.last().unwrap() > window.first().unwrap());
+            }
+            if ticks < values.len() {
+                assert_eq!(values[ticks], 1.);
+            }
+        }
+    }
+
+    #[test]
+    fn animator_auto_rewind_on_completion() {
+        let mut app = App::new();
+        app.add_plugins(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register::<Transform>(),
+        )
+        .init_resource::<Time>();
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+        .with_completed_event(5)
+        .with_auto_rewind_on_completion(true);
+        let entity = app
+            .world
+            .spawn((Transform::default(), Animator::new(tween)))
+            .id();
+        let mut reader = app.world.resource::<Events<TweenCompleted>>().get_reader();
+        let tick = |app: &mut App, millis| {
+            app.world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(millis));
+            app.update();
+        };
+        tick(&mut app, 0);
+        tick(&mut app, 1500);
+
+        // The animator observes the completion on that tick, with the end
+        // value applied, while the tween is already rewound
+        let events = app.world.resource::<Events<TweenCompleted>>();
+        assert_eq!(reader.read(events).count(), 1);
+        let transform = app.world.get::<Transform>(entity).unwrap();
+        assert_eq!(transform.translation, Vec3::ONE);
+        let mut animator = app.world.get_mut::<Animator<Transform>>(entity).unwrap();
+        assert!(animator.just_completed());
+        assert_eq!(animator.completed_at(), Some(Duration::from_millis(1500)));
+        assert_eq!(animator.tweenable().elapsed(), Duration::ZERO);
+
+        // Parked in a pool, then recycled: it plays again from the start
+        animator.state = AnimatorState::Paused;
+        tick(&mut app, 0);
+        let mut animator = app.world.get_mut::<Animator<Transform>>(entity).unwrap();
+        assert_eq!(animator.completed_at(), None);
+        animator.state = AnimatorState::Playing;
+        tick(&mut app, 250);
+        let transform = app.world.get::<Transform>(entity).unwrap();
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.25), 1e-5));
+
+        // A tweenable taken out after its completion is ready for reuse too
+        tick(&mut app, 1000);
+        let mut animator = app.world.get_mut::<Animator<Transform>>(entity).unwrap();
+        assert!(animator.just_completed());
+        let tweenable = animator.take_tweenable();
+        assert_eq!(tweenable.progress(), 0.);
+        assert_eq!(tweenable.times_completed(), 0);
+    }
+
+    /// Tween moving along X from `start` to `end` in one second, raising
+    /// completion events with `user_data`.
+    fn make_cancel_tween(start: f32, end: f32, user_data: u64) -> Tween<Transform> {
+        Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::X * start,
+                end: Vec3::X * end,
+            },
+        )
+        .with_completed_event(user_data)
+    }
+
+    /// Tick `app` by `millis`, and return the user data of the completion and
+    /// cancellation events raised.
+    fn tick_events(
+        app: &mut App,
+        millis: u64,
+        completed: &mut bevy::ecs::event::ManualEventReader<TweenCompleted>,
+        cancelled: &mut bevy::ecs::event::ManualEventReader<TweenCancelled>,
+    ) -> (Vec<u64>, Vec<(u64, f32)>) {
+        app.world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(millis));
+        app.update();
+        let events = app.world.resource::<Events<TweenCompleted>>();
+        let completed = completed.read(events).map(|e| e.user_data).collect();
+        let events = app.world.resource::<Events<TweenCancelled>>();
+        let cancelled = cancelled
+            .read(events)
+            .map(|e| (e.user_data, e.progress_at_cancel))
+            .collect();
+        (completed, cancelled)
+    }
+
+    #[test]
+    fn cancel_token_before_first_tick() {
+        let mut app = App::new();
+        app.add_plugins(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register::<Transform>()
+                .with_idle_cleanup(Duration::from_millis(100), CleanupPolicy::Remove),
+        )
+        .init_resource::<Time>();
+        let token = CancelToken::default();
+        let tween = make_cancel_tween(0., 1., 1)
+            .with_cancel_token(token.clone())
+            .with_cancel_behavior(CancelBehavior::SnapToEnd);
+        let entity = app
+            .world
+            .spawn((Transform::default(), Animator::new(tween)))
+            .id();
+        let mut completed = app.world.resource::<Events<TweenCompleted>>().get_reader();
+        let mut cancelled = app.world.resource::<Events<TweenCancelled>>().get_reader();
+
+        // Cancelled before ever playing: the end value is applied, and only
+        // the cancellation is raised
+        token.cancel();
+        let (done, cancels) = tick_events(&mut app, 0, &mut completed, &mut cancelled);
+        assert!(done.is_empty());
+        assert_eq!(cancels, [(1, 0.)]);
+        let transform = app.world.get::<Transform>(entity).unwrap();
+        assert_eq!(transform.translation, Vec3::X);
+        let animator = app.world.get::<Animator<Transform>>(entity).unwrap();
+        assert!(animator.just_completed());
+
+        // It stays stopped, and the cleanup policy of completed animators
+        // applies to it
+        let (done, cancels) = tick_events(&mut app, 50, &mut completed, &mut cancelled);
+        assert!(done.is_empty() && cancels.is_empty());
+        assert!(app.world.get::<Animator<Transform>>(entity).is_some());
+        let (done, cancels) = tick_events(&mut app, 100, &mut completed, &mut cancelled);
+        assert!(done.is_empty() && cancels.is_empty());
+        assert!(app.world.get::<Animator<Transform>>(entity).is_none());
+    }
+
+    #[test]
+    fn cancel_token_mid_sequence() {
+        let mut app = App::new();
+        app.add_plugins(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register::<Transform>(),
+        )
+        .init_resource::<Time>();
+        let root = CancelToken::default();
+        let child = CancelToken::default();
+        let sequence = Sequence::new([
+            make_cancel_tween(0., 1., 1),
+            make_cancel_tween(1., 2., 2).with_cancel_token(child.clone()),
+            make_cancel_tween(5., 6., 3),
+        ])
+        .with_cancel_token(root.clone())
+        .with_cancel_behavior(CancelBehavior::SnapToStart);
+        let entity = app
+            .world
+            .spawn((Transform::default(), Animator::new(sequence)))
+            .id();
+        let mut completed = app.world.resource::<Events<TweenCompleted>>().get_reader();
+        let mut cancelled = app.world.resource::<Events<TweenCancelled>>().get_reader();
+        let translation = |app: &App| app.world.get::<Transform>(entity).unwrap().translation;
+
+        let (done, cancels) = tick_events(&mut app, 0, &mut completed, &mut cancelled);
+        assert!(done.is_empty() && cancels.is_empty());
+        let (done, _) = tick_events(&mut app, 1500, &mut completed, &mut cancelled);
+        assert_eq!(done, [1]);
+        assert!(translation(&app).abs_diff_eq(Vec3::X * 1.5, 1e-5));
+
+        // Cancelling the token of the second child skips it, and the third
+        // one starts on the same tick
+        child.cancel();
+        let (done, cancels) = tick_events(&mut app, 0, &mut completed, &mut cancelled);
+        assert!(done.is_empty());
+        assert_eq!(cancels, [(2, 0.5)]);
+        assert!(translation(&app).abs_diff_eq(Vec3::X * 5., 1e-5));
+        let (done, cancels) = tick_events(&mut app, 250, &mut completed, &mut cancelled);
+        assert!(done.is_empty() && cancels.is_empty());
+        assert!(translation(&app).abs_diff_eq(Vec3::X * 5.25, 1e-5));
+        let animator = app.world.get::<Animator<Transform>>(entity).unwrap();
+        assert_eq!(animator.tweenable().elapsed(), Duration::from_millis(2250));
+
+        // Cancelling the token of the sequence stops it all, and restores its
+        // start value
+        root.cancel();
+        let (done, cancels) = tick_events(&mut app, 100, &mut completed, &mut cancelled);
+        assert!(done.is_empty());
+        assert_eq!(cancels, [(3, 0.25)]);
+        assert_eq!(translation(&app), Vec3::ZERO);
+        let animator = app.world.get::<Animator<Transform>>(entity).unwrap();
+        assert!(animator.just_completed());
+        let (done, cancels) = tick_events(&mut app, 5000, &mut completed, &mut cancelled);
+        assert!(done.is_empty() && cancels.is_empty());
+        assert_eq!(translation(&app), Vec3::ZERO);
+    }
+
+    #[test]
+    fn cancel_token_single_track() {
+        let mut app = App::new();
+        app.add_plugins(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register::<Transform>(),
+        )
+        .init_resource::<Time>();
+        let token = CancelToken::default();
+        let scale = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformScaleLens {
+                start: Vec3::ONE,
+                end: Vec3::splat(2.),
+            },
+        )
+        .with_completed_event(2)
+        .with_cancel_token(token.clone());
+        let tracks = Tracks::new([make_cancel_tween(0., 1., 1), scale]);
+        let entity = app
+            .world
+            .spawn((Transform::default(), Animator::new(tracks)))
+            .id();
+        let mut completed = app.world.resource::<Events<TweenCompleted>>().get_reader();
+        let mut cancelled = app.world.resource::<Events<TweenCancelled>>().get_reader();
+
+        tick_events(&mut app, 0, &mut completed, &mut cancelled);
+        tick_events(&mut app, 500, &mut completed, &mut cancelled);
+
+        // Only the track holding the token stops, frozen where it was
+        token.cancel();
+        let (done, cancels) = tick_events(&mut app, 250, &mut completed, &mut cancelled);
+        assert!(done.is_empty());
+        assert_eq!(cancels, [(2, 0.5)]);
+        let (done, cancels) = tick_events(&mut app, 250, &mut completed, &mut cancelled);
+        assert_eq!(done, [1]);
+        assert!(cancels.is_empty());
+        let transform = app.world.get::<Transform>(entity).unwrap();
+        assert_eq!(transform.translation, Vec3::X);
+        assert!(transform.scale.abs_diff_eq(Vec3::splat(1.5), 1e-5));
+        let animator = app.world.get::<Animator<Transform>>(entity).unwrap();
+        assert!(animator.just_completed());
+    }
+
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, States)]
+    enum GameState {
+        #[default]
+        Playing,
+        Paused,
+        MainMenu,
+    }
+
+    #[test]
+    fn animator_bound_to_state() {
+        let mut app = App::new();
+        app.add_plugins(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register::<Transform>()
+                .register_state::<GameState>(),
+        )
+        .init_resource::<Time>()
+        .init_state::<GameState>();
+        let mut spawn = |on_exit: Option<OnStateExit>, user_data| {
+            let tween = make_cancel_tween(0., 1., user_data);
+            let mut animator = Animator::new(tween);
+            if let Some(on_exit) = on_exit {
+                animator = animator.bound_to_state(GameState::Playing, on_exit);
+            }
+            app.world.spawn((Transform::default(), animator)).id()
+        };
+        let paused = spawn(Some(OnStateExit::Pause), 1);
+        let cancelled = spawn(Some(OnStateExit::Cancel), 2);
+        let completed = spawn(Some(OnStateExit::Complete), 3);
+        let unbound = spawn(None, 4);
+        let paused_by_user = spawn(Some(OnStateExit::Pause), 5);
+        let mut completed_reader = app.world.resource::<Events<TweenCompleted>>().get_reader();
+        let mut cancelled_reader = app.world.resource::<Events<TweenCancelled>>().get_reader();
+        let tick = |app: &mut App, millis| {
+            app.world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(millis));
+            app.update();
+        };
+        let x = |app: &App, entity| app.world.get::<Transform>(entity).unwrap().translation.x;
+        let state = |app: &App, entity| app.world.get::<Animator<Transform>>(entity).unwrap().state;
+        tick(&mut app, 0);
+        tick(&mut app, 250);
+        app.world
+            .get_mut::<Animator<Transform>>(paused_by_user)
+            .unwrap()
+            .state = AnimatorState::Paused;
+
+        // Exiting the state applies each policy before the animators tick
+        app.world
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Paused);
+        tick(&mut app, 250);
+        assert!((x(&app, paused) - 0.25).abs() < 1e-5);
+        assert_eq!(state(&app, paused), AnimatorState::Paused);
+        assert!((x(&app, cancelled) - 0.25).abs() < 1e-5);
+        assert_eq!(state(&app, cancelled), AnimatorState::Paused);
+        assert_eq!(x(&app, completed), 1.);
+        assert!((x(&app, unbound) - 0.5).abs() < 1e-5);
+        let events = app.world.resource::<Events<TweenCompleted>>();
+        let user_data: Vec<_> = completed_reader.read(events).map(|e| e.user_data).collect();
+        assert_eq!(user_data, [3]);
+        let events = app.world.resource::<Events<TweenCancelled>>();
+        let user_data: Vec<_> = cancelled_reader.read(events).map(|e| e.user_data).collect();
+        assert_eq!(user_data, [2]);
+
+        // Re-entering the state only resumes the animators it paused
+        tick(&mut app, 250);
+        assert!((x(&app, paused) - 0.25).abs() < 1e-5);
+        app.world
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        tick(&mut app, 250);
+        assert!((x(&app, paused) - 0.5).abs() < 1e-5);
+        assert_eq!(state(&app, paused), AnimatorState::Playing);
+        assert_eq!(state(&app, cancelled), AnimatorState::Paused);
+        assert_eq!(state(&app, paused_by_user), AnimatorState::Paused);
+        assert!((x(&app, paused_by_user) - 0.25).abs() < 1e-5);
+
+        // Transitions between other states don't affect the bound animators
+        app.world
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::MainMenu);
+        tick(&mut app, 0);
+        assert_eq!(state(&app, paused), AnimatorState::Paused);
+        app.world
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Paused);
+        tick(&mut app, 250);
+        assert_eq!(state(&app, paused), AnimatorState::Paused);
+        assert!((x(&app, paused) - 0.5).abs() < 1e-5);
+
+        // An unbound animator is no longer affected
+        app.world
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        tick(&mut app, 0);
+        let mut animator = app.world.get_mut::<Animator<Transform>>(paused).unwrap();
+        assert_eq!(animator.state, AnimatorState::Playing);
+        animator.unbind_state();
+        app.world
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::MainMenu);
+        tick(&mut app, 250);
+        assert_eq!(state(&app, paused), AnimatorState::Playing);
+        assert!((x(&app, paused) - 0.75).abs() < 1e-5);
     }
 }
@@ -0,0 +1,293 @@
+//! Prototypes stamping out variations of the same animation.
+
+use std::{sync::Arc, time::Duration};
+
+use crate::Tweenable;
+
+/// Overrides applied to a tweenable instantiated from a [`TweenPrototype`].
+///
+/// The overrides are applied recursively by [`Tweenable::apply_overrides()`]
+/// to all the tweenables of the instance, like the children of a
+/// [`Sequence`] or a [`Tracks`]. Scaling the duration of a [`Sequence`]
+/// scales the duration of each of its children, so the sequence keeps its
+/// rhythm.
+///
+/// [`Sequence`]: crate::Sequence
+/// [`Tracks`]: crate::Tracks
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct TweenOverrides {
+    duration_scale: Option<f32>,
+    lens_range_scale: Option<f32>,
+    user_data: Option<u64>,
+}
+
+impl TweenOverrides {
+    /// Scale the duration of all the tweenables by `factor`.
+    ///
+    /// For example a factor of `1.2` makes the animation 20% longer. Calling
+    /// this method again replaces the previous factor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is not strictly positive.
+    pub fn scale_duration(&mut self, factor: f32) -> &mut Self {
+        assert!(
+            factor > 0.,
+            "Invalid duration scale {factor}, expected a strictly positive value"
+        );
+        self.duration_scale = Some(factor);
+        self
+    }
+
+    /// Scale the range of the lenses of all the tweens by `factor`, moving
+    /// their end value around their start value.
+    ///
+    /// For example a factor of `0.5` halves the amplitude of the animation.
+    /// This uses [`Lens::scale_range()`]; the lenses which don't support it
+    /// are left untouched, and a warning is logged. Calling this method again
+    /// replaces the previous factor.
+    ///
+    /// [`Lens::scale_range()`]: crate::Lens::scale_range
+    pub fn scale_lens_range(&mut self, factor: f32) -> &mut Self {
+        self.lens_range_scale = Some(factor);
+        self
+    }
+
+    /// Replace the user data of the [`TweenCompleted`] events raised by the
+    /// tweenables.
+    ///
+    /// Only the tweenables already raising a completion event are affected,
+    /// for example a [`Tween`] with [`Tween::with_completed_event()`]; this
+    /// doesn't enable the event on the other ones.
+    ///
+    /// [`TweenCompleted`]: crate::TweenCompleted
+    /// [`Tween`]: crate::Tween
+    /// [`Tween::with_completed_event()`]: crate::Tween::with_completed_event
+    pub fn set_user_data(&mut self, user_data: u64) -> &mut Self {
+        self.user_data = Some(user_data);
+        self
+    }
+
+    /// Get the factor to scale the duration of the tweenables by, if any.
+    #[must_use]
+    pub fn duration_scale(&self) -> Option<f32> {
+        self.duration_scale
+    }
+
+    /// Get the factor to scale the range of the lenses by, if any.
+    #[must_use]
+    pub fn lens_range_scale(&self) -> Option<f32> {
+        self.lens_range_scale
+    }
+
+    /// Get the user data replacing the one of the completion events, if any.
+    #[must_use]
+    pub fn user_data(&self) -> Option<u64> {
+        self.user_data
+    }
+
+    /// Scale a duration by the duration factor, if any.
+    pub(crate) fn scaled(&self, duration: Duration) -> Duration {
+        match self.duration_scale {
+            Some(factor) => duration.mul_f64(f64::from(factor)),
+            None => duration,
+        }
+    }
+}
+
+/// Prototype of an animation, to instantiate many times.
+///
+/// Tweenables own boxed lenses and callbacks, so can't be cloned. A prototype
+/// instead stores a factory constructing the tweenable, and is cheap to clone
+/// and share, for example in a resource. Each call to [`instantiate()`]
+/// constructs a new identical tweenable, while [`instantiate_with()`] applies
+/// some [`TweenOverrides`] to vary it, like a longer fade or a smaller bob for
+/// a given entity.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// let bob = TweenPrototype::new(|| {
+///     Tween::new(
+///         EaseFunction::SineInOut,
+///         1.,
+///         TransformPositionLens {
+///             start: Vec3::ZERO,
+///             end: Vec3::Y,
+///         },
+///     )
+///     .with_repeat_count(RepeatCount::Infinite)
+///     .with_repeat_strategy(RepeatStrategy::MirroredRepeat)
+/// });
+///
+/// fn spawn_pickup(mut commands: Commands, bob: &TweenPrototype<Tween<Transform>>) {
+///     // Half the amplitude, 20% slower
+///     let tween = bob.instantiate_with(|cfg| {
+///         cfg.scale_duration(1.2).scale_lens_range(0.5);
+///     });
+///     commands.spawn((Transform::default(), Animator::new(tween)));
+/// }
+/// ```
+///
+/// [`instantiate()`]: Self::instantiate
+/// [`instantiate_with()`]: Self::instantiate_with
+pub struct TweenPrototype<W> {
+    factory: Arc<dyn Fn() -> W + Send + Sync + 'static>,
+}
+
+impl<W> Clone for TweenPrototype<W> {
+    fn clone(&self) -> Self {
+        Self {
+            factory: Arc::clone(&self.factory),
+        }
+    }
+}
+
+impl<W> std::fmt::Debug for TweenPrototype<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TweenPrototype").finish_non_exhaustive()
+    }
+}
+
+impl<W> TweenPrototype<W> {
+    /// Create a prototype constructing its instances with `factory`.
+    #[must_use]
+    pub fn new(factory: impl Fn() -> W + Send + Sync + 'static) -> Self {
+        Self {
+            factory: Arc::new(factory),
+        }
+    }
+
+    /// Construct a new instance of the prototype.
+    #[must_use]
+    pub fn instantiate(&self) -> W {
+        (self.factory)()
+    }
+
+    /// Construct a new instance of the prototype, with the overrides set by
+    /// `configure`.
+    ///
+    /// See [`TweenOverrides`] for the available overrides.
+    #[must_use]
+    pub fn instantiate_with<T>(&self, configure: impl FnOnce(&mut TweenOverrides)) -> W
+    where
+        W: Tweenable<T>,
+    {
+        let mut overrides = TweenOverrides::default();
+        configure(&mut overrides);
+        let mut instance = self.instantiate();
+        instance.apply_overrides(&overrides);
+        instance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use super::*;
+    use crate::{
+        lens::{TransformPositionLens, TransformScaleLens},
+        Animator, Delay, EaseMethod, Lens, Sequence, TotalDuration, Tracks, Tween, TweenCompleted,
+        TweeningPlugin,
+    };
+
+    /// Lens not supporting [`Lens::scale_range()`].
+    struct RotationLens;
+
+    impl Lens<Transform> for RotationLens {
+        fn lerp(&mut self, target: &mut Transform, ratio: f32) {
+            target.rotation = Quat::from_rotation_z(ratio);
+        }
+    }
+
+    fn make_prototype() -> TweenPrototype<Sequence<Transform>> {
+        TweenPrototype::new(|| {
+            let position = Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::splat(2.),
+                },
+            );
+            let scale = Tween::new(
+                EaseMethod::Linear,
+                Duration::from_millis(500),
+                TransformScaleLens {
+                    start: Vec3::ONE,
+                    end: Vec3::splat(3.),
+                },
+            )
+            .with_completed_event(7);
+            let rotation = Tween::new(EaseMethod::Linear, Duration::from_secs(1), RotationLens);
+            position.then(Tracks::new([
+                scale.then(rotation),
+                Sequence::from_single(Delay::new(Duration::from_secs(1))),
+            ]))
+        })
+    }
+
+    #[test]
+    fn instantiate_with_overrides() {
+        let prototype = make_prototype();
+        let instance = prototype.instantiate();
+        assert_eq!(
+            instance.total_duration(),
+            TotalDuration::Finite(Duration::from_millis(2500))
+        );
+
+        let instance = prototype.clone().instantiate_with(|cfg| {
+            cfg.scale_duration(2.)
+                .scale_lens_range(0.5)
+                .set_user_data(42);
+        });
+        assert_eq!(
+            instance.total_duration(),
+            TotalDuration::Finite(Duration::from_secs(5))
+        );
+
+        let mut app = App::new();
+        app.add_plugins(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register::<Transform>(),
+        )
+        .init_resource::<Time>();
+        let entity = app
+            .world
+            .spawn((Transform::default(), Animator::new(instance)))
+            .id();
+        let mut reader = app.world.resource::<Events<TweenCompleted>>().get_reader();
+        let mut user_data = vec![];
+        for _ in 0..5 {
+            app.world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(1));
+            app.update();
+            let events = app.world.resource::<Events<TweenCompleted>>();
+            user_data.extend(reader.read(events).map(|event| event.user_data));
+        }
+        assert_eq!(user_data, [42]);
+
+        // The scaled endpoints are reached, and the unsupported lens is untouched
+        let transform = app.world.get::<Transform>(entity).unwrap();
+        assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+        assert!(transform.scale.abs_diff_eq(Vec3::splat(2.), 1e-5));
+        assert!(transform
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_z(1.), 1e-5));
+        let animator = app.world.get::<Animator<Transform>>(entity).unwrap();
+        assert_eq!(animator.tweenable().progress(), 1.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn scale_duration_zero() {
+        let _ = make_prototype().instantiate_with(|cfg| {
+            cfg.scale_duration(0.);
+        });
+    }
+}
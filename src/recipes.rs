@@ -0,0 +1,28 @@
+//! Ready-made animations for common use cases.
+//!
+//! The recipes in this module combine existing lenses, tweenables, and
+//! animators into higher-level helpers, for common one-shot effects like
+//! [`punch()`] and [`anticipate()`], or for animations which need to drive
+//! several components of the same entity together.
+//!
+//! # Feature-gated recipes
+//!
+//! | Recipe | Feature |
+//! |---|---|
+//! | `CardRaise` | `bevy_ui` |
+
+mod anticipate;
+#[cfg(feature = "bevy_ui")]
+mod card;
+mod grid;
+mod noise;
+mod punch;
+mod shake;
+
+pub use anticipate::*;
+#[cfg(feature = "bevy_ui")]
+pub use card::*;
+pub use grid::*;
+pub use noise::*;
+pub use punch::*;
+pub use shake::*;
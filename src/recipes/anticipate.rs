@@ -0,0 +1,142 @@
+//! Two-stage anticipation animation.
+
+use std::{
+    ops::{Add, Mul, Sub},
+    time::Duration,
+};
+
+use crate::{lens::RangedLens, EaseFunction, EaseMethod, Lens, Sequence, Tween};
+
+/// Create a sequence moving slightly backward before playing a lens.
+///
+/// This is the "anticipation" animation principle: before a punchy motion
+/// from the start to the end value of `lens`, the target first winds up by
+/// moving away from the end value, to an anticipation value extrapolated
+/// backward from the start value by `anticipation_overshoot` times the range
+/// of the lens. For example an overshoot of `0.05` on a scale lens from `1.`
+/// to `1.2` winds up to `0.99`.
+///
+/// The anticipation takes the `anticipation_fraction` of `duration`, with a
+/// [`EaseFunction::QuadraticOut`] easing, and the remaining time plays from
+/// the anticipation value to the end value with `ease_function`. The total
+/// duration of the sequence is exactly `duration`.
+///
+/// # Panics
+///
+/// Panics if `anticipation_fraction` is not in \]0:1\[, or if
+/// `anticipation_overshoot` is negative.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, recipes::*, *};
+/// # use std::time::Duration;
+/// fn on_jump(mut commands: Commands, entity: Entity) {
+///     // Squash by 5% for 80 ms, then stretch to 120% in 320 ms
+///     let sequence = anticipate(
+///         EaseFunction::BackOut,
+///         Duration::from_millis(400),
+///         TransformScaleLens {
+///             start: Vec3::ONE,
+///             end: Vec3::splat(1.2),
+///         },
+///         0.2,
+///         0.25,
+///     );
+///     commands.entity(entity).insert(Animator::new(sequence));
+/// }
+/// ```
+pub fn anticipate<T, L, V>(
+    ease_function: impl Into<EaseMethod>,
+    duration: Duration,
+    lens: L,
+    anticipation_fraction: f32,
+    anticipation_overshoot: f32,
+) -> Sequence<T>
+where
+    T: 'static,
+    L: Lens<T> + RangedLens<Value = V> + Clone + Send + Sync + 'static,
+    V: Copy + Add<Output = V> + Sub<Output = V> + Mul<f32, Output = V>,
+{
+    assert!(
+        anticipation_fraction > 0. && anticipation_fraction < 1.,
+        "Invalid anticipation fraction {anticipation_fraction}, expected a value in ]0:1["
+    );
+    assert!(
+        anticipation_overshoot >= 0.,
+        "Invalid anticipation overshoot {anticipation_overshoot}, expected a positive value"
+    );
+
+    let start = lens.start();
+    let anticipation = start - (lens.end() - start) * anticipation_overshoot;
+    let mut wind_up = lens.clone();
+    wind_up.set_end(anticipation);
+    let mut act = lens;
+    act.set_start(anticipation);
+
+    let wind_up_duration = duration.mul_f32(anticipation_fraction);
+    Sequence::new([
+        Tween::new(EaseFunction::QuadraticOut, wind_up_duration, wind_up),
+        Tween::new(ease_function, duration - wind_up_duration, act),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use super::*;
+    use crate::{lens::TransformScaleLens, TotalDuration, Tweenable};
+
+    fn make_sequence() -> Sequence<Transform> {
+        anticipate(
+            EaseFunction::BackOut,
+            Duration::from_millis(400),
+            TransformScaleLens {
+                start: Vec3::ONE,
+                end: Vec3::new(1.2, 1.4, 1.),
+            },
+            0.2,
+            0.25,
+        )
+    }
+
+    #[test]
+    fn anticipate_keyframes() {
+        let sequence = make_sequence();
+        assert_eq!(sequence.duration(), Duration::from_millis(400));
+        assert_eq!(
+            sequence.total_duration(),
+            TotalDuration::Finite(Duration::from_millis(400))
+        );
+        let wind_up = sequence.current().duration();
+        assert!(wind_up.abs_diff(Duration::from_millis(80)) < Duration::from_micros(1));
+        assert_eq!(sequence.locate(0.5).0, 1);
+
+        // The anticipation extrapolates backward from the start value
+        let mut transform = Transform::default();
+        sequence.sample(Duration::from_millis(80), &mut transform);
+        assert!(transform.scale.abs_diff_eq(Vec3::new(0.95, 0.9, 1.), 1e-5));
+
+        sequence.sample(Duration::ZERO, &mut transform);
+        assert!(transform.scale.abs_diff_eq(Vec3::ONE, 1e-5));
+        sequence.sample(Duration::from_millis(400), &mut transform);
+        assert!(transform.scale.abs_diff_eq(Vec3::new(1.2, 1.4, 1.), 1e-5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn anticipate_invalid_fraction() {
+        let _: Sequence<Transform> = anticipate(
+            EaseFunction::BackOut,
+            Duration::from_millis(400),
+            TransformScaleLens {
+                start: Vec3::ONE,
+                end: Vec3::splat(1.2),
+            },
+            1.,
+            0.25,
+        );
+    }
+}
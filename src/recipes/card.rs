@@ -0,0 +1,322 @@
+//! Card raise animation for `bevy_ui` nodes.
+
+use std::time::Duration;
+
+use bevy::{ecs::system::EntityCommands, prelude::*};
+
+use crate::{lens::UiZIndexLens, Animator, EaseFunction, EaseMethod, Lens, Tween};
+
+/// Bundle of the animators driving a [`CardRaise`] animation.
+///
+/// The two animators are spawned and removed together, so that the transform
+/// and the z-index of the card always stay in sync. Inserting a new bundle on
+/// an entity replaces any animation in progress.
+#[derive(Bundle)]
+pub struct CardRaiseBundle {
+    /// Animator for the [`Transform`] of the card.
+    pub transform: Animator<Transform>,
+    /// Animator for the [`ZIndex`] of the card.
+    pub z_index: Animator<ZIndex>,
+}
+
+/// Helper to raise a card above its siblings, and lower it back.
+///
+/// This animates the [`Transform`] of the card between a `rest` and a `raised`
+/// state, and bumps its [`ZIndex`] above its siblings while the card is not at
+/// rest. The z-index is raised as soon as the raise animation starts, and only
+/// restored once the lower animation completes, so the card never slides below
+/// its siblings mid-animation.
+///
+/// Both [`raise()`] and [`lower()`] start from the _current_ transform of the
+/// card, and scale the animation duration by the remaining distance. This
+/// handles interruptions, like the pointer leaving a card mid-raise: simply
+/// insert the bundle returned by [`lower()`] to replace the raise in progress,
+/// and the card smoothly returns to rest from wherever it was.
+///
+/// Note that the layout of `bevy_ui` overwrites the translation of UI nodes
+/// each frame, so on UI nodes only the rotation and scale of the transforms
+/// are visible. Use a lens on [`Style`] to move a UI node.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{recipes::*, *};
+/// # use std::time::Duration;
+/// fn on_hover(mut commands: Commands, query: Query<(Entity, &Transform, &Interaction)>) {
+///     let card_raise = CardRaise::new(
+///         Transform::IDENTITY,
+///         Transform::from_scale(Vec3::splat(1.2)),
+///         Duration::from_millis(200),
+///     );
+///     for (entity, transform, interaction) in &query {
+///         let bundle = match interaction {
+///             Interaction::Hovered => card_raise.raise(transform),
+///             _ => card_raise.lower(transform),
+///         };
+///         commands.entity(entity).insert(bundle);
+///     }
+/// }
+/// ```
+///
+/// [`raise()`]: CardRaise::raise
+/// [`lower()`]: CardRaise::lower
+#[derive(Clone, Copy)]
+pub struct CardRaise {
+    /// Transform of the card at rest.
+    pub rest: Transform,
+    /// Transform of the card once fully raised.
+    pub raised: Transform,
+    /// Z-index of the card at rest.
+    pub rest_z_index: ZIndex,
+    /// Z-index of the card while not at rest.
+    pub raised_z_index: ZIndex,
+    /// Duration of a full raise or lower animation.
+    pub duration: Duration,
+    /// Easing method of the transform animation.
+    pub ease: EaseMethod,
+}
+
+impl CardRaise {
+    /// Create a new card raise animation between two transforms.
+    ///
+    /// The z-index defaults to `ZIndex::Local(0)` at rest and
+    /// `ZIndex::Local(1)` when raised, and the animation uses a quadratic
+    /// ease-out.
+    pub fn new(rest: Transform, raised: Transform, duration: Duration) -> Self {
+        Self {
+            rest,
+            raised,
+            rest_z_index: ZIndex::Local(0),
+            raised_z_index: ZIndex::Local(1),
+            duration,
+            ease: EaseFunction::QuadraticOut.into(),
+        }
+    }
+
+    /// Set the z-index of the card at rest and while raised.
+    #[must_use]
+    pub fn with_z_index(mut self, rest: ZIndex, raised: ZIndex) -> Self {
+        self.rest_z_index = rest;
+        self.raised_z_index = raised;
+        self
+    }
+
+    /// Set the easing method of the transform animation.
+    #[must_use]
+    pub fn with_ease(mut self, ease: impl Into<EaseMethod>) -> Self {
+        self.ease = ease.into();
+        self
+    }
+
+    /// Build the animators raising the card from its current transform.
+    ///
+    /// The z-index is raised immediately.
+    pub fn raise(&self, current: &Transform) -> CardRaiseBundle {
+        let fraction = 1. - self.raised_fraction(current);
+        self.make_bundle(
+            *current,
+            self.raised,
+            fraction,
+            UiZIndexLens {
+                start: self.rest_z_index,
+                end: self.raised_z_index,
+                threshold: 0.,
+            },
+        )
+    }
+
+    /// Build the animators lowering the card from its current transform.
+    ///
+    /// The z-index is restored once the card is back at rest.
+    pub fn lower(&self, current: &Transform) -> CardRaiseBundle {
+        let fraction = self.raised_fraction(current);
+        self.make_bundle(
+            *current,
+            self.rest,
+            fraction,
+            UiZIndexLens {
+                start: self.raised_z_index,
+                end: self.rest_z_index,
+                threshold: 1.,
+            },
+        )
+    }
+
+    /// Cancel any animation in progress and snap the card back to rest.
+    ///
+    /// This does nothing if the entity is despawned before the commands are
+    /// applied.
+    pub fn cancel(&self, entity: &mut EntityCommands) {
+        entity
+            .remove::<CardRaiseBundle>()
+            .try_insert((self.rest, self.rest_z_index));
+    }
+
+    /// Fraction of the distance from rest to raised already covered by the
+    /// given transform, in `[0:1]`.
+    fn raised_fraction(&self, current: &Transform) -> f32 {
+        let total = distance(&self.rest, &self.raised);
+        if total <= f32::EPSILON {
+            return 1.;
+        }
+        (distance(&self.rest, current) / total).clamp(0., 1.)
+    }
+
+    fn make_bundle(
+        &self,
+        start: Transform,
+        end: Transform,
+        fraction: f32,
+        z_index_lens: UiZIndexLens,
+    ) -> CardRaiseBundle {
+        // Tweens cannot have a zero duration; a card already at its target
+        // still needs a (very short) animation to update its z-index.
+        let duration = self
+            .duration
+            .mul_f32(fraction)
+            .max(Duration::from_millis(1));
+        CardRaiseBundle {
+            transform: Animator::new(Tween::new(
+                self.ease,
+                duration,
+                CardTransformLens { start, end },
+            )),
+            z_index: Animator::new(Tween::new(EaseMethod::Linear, duration, z_index_lens)),
+        }
+    }
+}
+
+fn distance(a: &Transform, b: &Transform) -> f32 {
+    a.translation.distance(b.translation)
+        + a.scale.distance(b.scale)
+        + a.rotation.angle_between(b.rotation)
+}
+
+/// Lens interpolating all fields of a [`Transform`].
+struct CardTransformLens {
+    start: Transform,
+    end: Transform,
+}
+
+impl Lens<Transform> for CardTransformLens {
+    fn lerp(&mut self, target: &mut Transform, ratio: f32) {
+        target.translation = self.start.translation.lerp(self.end.translation, ratio);
+        target.rotation = self.start.rotation.slerp(self.end.rotation, ratio);
+        target.scale = self.start.scale.lerp(self.end.scale, ratio);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::SystemState;
+
+    use super::*;
+    use crate::{component_animator_system, AnimatorStateChanged, TweenCompleted};
+
+    struct TestEnv {
+        world: World,
+        entity: Entity,
+    }
+
+    impl TestEnv {
+        fn new(card: &CardRaise) -> Self {
+            let mut world = World::new();
+            world.init_resource::<Events<TweenCompleted>>();
+            world.init_resource::<Events<AnimatorStateChanged>>();
+            world.init_resource::<Time>();
+            let entity = world.spawn((card.rest, card.rest_z_index)).id();
+            Self { world, entity }
+        }
+
+        fn insert(&mut self, bundle: CardRaiseBundle) {
+            self.world.entity_mut(self.entity).insert(bundle);
+        }
+
+        fn transform(&self) -> Transform {
+            *self.world.get::<Transform>(self.entity).unwrap()
+        }
+
+        fn z_index(&self) -> ZIndex {
+            *self.world.get::<ZIndex>(self.entity).unwrap()
+        }
+
+        fn step(&mut self, duration: Duration) {
+            self.world.resource_mut::<Time>().advance_by(duration);
+            let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+            system.initialize(&mut self.world);
+            system.run((), &mut self.world);
+            let mut system = IntoSystem::into_system(component_animator_system::<ZIndex>);
+            system.initialize(&mut self.world);
+            system.run((), &mut self.world);
+            let mut state: SystemState<Commands> = SystemState::new(&mut self.world);
+            state.apply(&mut self.world);
+        }
+    }
+
+    #[test]
+    fn card_raise_interrupted() {
+        let card = CardRaise::new(
+            Transform::IDENTITY,
+            Transform::from_xyz(0., 10., 0.).with_scale(Vec3::splat(2.)),
+            Duration::from_millis(200),
+        )
+        .with_ease(EaseMethod::Linear)
+        .with_z_index(ZIndex::Local(0), ZIndex::Global(5));
+        let mut env = TestEnv::new(&card);
+
+        // Raise; the z-index is bumped on the first tick.
+        env.insert(card.raise(&env.transform()));
+        env.step(Duration::from_millis(100));
+        let transform = env.transform();
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(0., 5., 0.), 1e-5));
+        assert!(transform.scale.abs_diff_eq(Vec3::splat(1.5), 1e-5));
+        assert!(matches!(env.z_index(), ZIndex::Global(5)));
+
+        // Interrupt mid-raise; lowering from halfway takes half the duration,
+        // and the card stays above its siblings until back at rest.
+        env.insert(card.lower(&transform));
+        env.step(Duration::from_millis(50));
+        let transform = env.transform();
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(0., 2.5, 0.), 1e-5));
+        assert!(transform.scale.abs_diff_eq(Vec3::splat(1.25), 1e-5));
+        assert!(matches!(env.z_index(), ZIndex::Global(5)));
+
+        env.step(Duration::from_millis(50));
+        assert_eq!(env.transform(), card.rest);
+        assert!(matches!(env.z_index(), ZIndex::Local(0)));
+
+        // Lowering a card already at rest still restores the z-index.
+        env.world.entity_mut(env.entity).insert(ZIndex::Global(5));
+        env.insert(card.lower(&card.rest));
+        env.step(Duration::from_millis(1));
+        assert_eq!(env.transform(), card.rest);
+        assert!(matches!(env.z_index(), ZIndex::Local(0)));
+    }
+
+    #[test]
+    fn card_raise_cancel() {
+        let card = CardRaise::new(
+            Transform::IDENTITY,
+            Transform::from_scale(Vec3::splat(2.)),
+            Duration::from_millis(200),
+        );
+        let mut env = TestEnv::new(&card);
+        env.insert(card.raise(&env.transform()));
+        env.step(Duration::from_millis(100));
+
+        let mut state: SystemState<Commands> = SystemState::new(&mut env.world);
+        let mut commands = state.get_mut(&mut env.world);
+        card.cancel(&mut commands.entity(env.entity));
+        state.apply(&mut env.world);
+
+        assert!(env.world.get::<Animator<Transform>>(env.entity).is_none());
+        assert!(env.world.get::<Animator<ZIndex>>(env.entity).is_none());
+        assert_eq!(env.transform(), card.rest);
+        assert!(matches!(env.z_index(), ZIndex::Local(0)));
+    }
+}
@@ -0,0 +1,352 @@
+//! Cell-to-cell movement for tile-based games.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{lens::RangedLens, Animator, EaseFunction, EaseMethod, Lens, Tween};
+
+/// Cell of a grid an entity occupies.
+///
+/// Gameplay logic like collision or occupancy should read this component,
+/// which always holds a single cell, rather than the [`Transform`] of the
+/// entity, which moves smoothly between cells. It's animated by the
+/// [`GridCommitLens`] of a [`GridMoveBundle`], and its animator system is
+/// registered by default by the [`TweeningPlugin`].
+///
+/// [`TweeningPlugin`]: crate::TweeningPlugin
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Component)]
+pub struct GridPosition(pub IVec2);
+
+/// A lens moving a [`Transform`] from the center of a grid cell to another.
+///
+/// The cell `(x, y)` is centered at `(x * cell_size, y * cell_size)` on the XY
+/// plane. The Z coordinate of the translation is left untouched, so the entity
+/// keeps its draw order.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridMoveLens {
+    /// Cell the movement starts from.
+    pub from: IVec2,
+    /// Cell the movement ends at.
+    pub to: IVec2,
+    /// Size of a grid cell, in world units.
+    pub cell_size: f32,
+}
+
+impl Lens<Transform> for GridMoveLens {
+    fn lerp(&mut self, target: &mut Transform, ratio: f32) {
+        let from = self.from.as_vec2() * self.cell_size;
+        let to = self.to.as_vec2() * self.cell_size;
+        let position = from.lerp(to, ratio);
+        target.translation.x = position.x;
+        target.translation.y = position.y;
+    }
+
+    fn sample(&self, target: &mut Transform, ratio: f32) -> bool {
+        let mut lens = *self;
+        lens.lerp(target, ratio);
+        true
+    }
+}
+
+impl RangedLens for GridMoveLens {
+    type Value = IVec2;
+
+    fn start(&self) -> IVec2 {
+        self.from
+    }
+
+    fn set_start(&mut self, start: IVec2) {
+        self.from = start;
+    }
+
+    fn end(&self) -> IVec2 {
+        self.to
+    }
+
+    fn set_end(&mut self, end: IVec2) {
+        self.to = end;
+    }
+}
+
+/// A lens switching a [`GridPosition`] from a cell to another at a commit
+/// point.
+///
+/// The grid position is `from` while the ratio is below `commit`, and `to`
+/// from then on. The lens holds no state, so the position flips exactly once
+/// per pass over the commit point: an animation interrupted before that point
+/// leaves the entity in its `from` cell, and a rewound animation moves it back
+/// to that cell on its next tick.
+///
+/// The ratio is the eased progress of the tween, so the lens is normally used
+/// with [`EaseMethod::Linear`] to commit at a given fraction of the duration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridCommitLens {
+    /// Cell occupied before the commit point.
+    pub from: IVec2,
+    /// Cell occupied from the commit point.
+    pub to: IVec2,
+    /// Ratio at which the entity moves to the `to` cell.
+    pub commit: f32,
+}
+
+impl Lens<GridPosition> for GridCommitLens {
+    fn lerp(&mut self, target: &mut GridPosition, ratio: f32) {
+        target.0 = if ratio >= self.commit {
+            self.to
+        } else {
+            self.from
+        };
+    }
+}
+
+impl RangedLens for GridCommitLens {
+    type Value = IVec2;
+
+    fn start(&self) -> IVec2 {
+        self.from
+    }
+
+    fn set_start(&mut self, start: IVec2) {
+        self.from = start;
+    }
+
+    fn end(&self) -> IVec2 {
+        self.to
+    }
+
+    fn set_end(&mut self, end: IVec2) {
+        self.to = end;
+    }
+}
+
+/// Bundle of the animators driving a [`GridMove`] animation.
+///
+/// The two animators share the same duration, and are spawned and removed
+/// together, so the [`GridPosition`] of the entity commits to the next cell
+/// at a fixed point of the visual movement. Inserting a new bundle on an
+/// entity replaces any movement in progress.
+#[derive(Bundle)]
+pub struct GridMoveBundle {
+    /// Animator for the [`Transform`] of the entity.
+    pub transform: Animator<Transform>,
+    /// Animator for the [`GridPosition`] of the entity.
+    pub grid_position: Animator<GridPosition>,
+}
+
+/// Helper to move an entity from a grid cell to an adjacent one.
+///
+/// The [`Transform`] of the entity is interpolated smoothly with a
+/// [`GridMoveLens`], while its [`GridPosition`] switches from one cell to the
+/// other once the movement reaches the `commit` point, with a
+/// [`GridCommitLens`]. The commit point defaults to `0.5`, so the entity
+/// occupies the cell it's visually closest to.
+///
+/// To interrupt a movement, for example when the entity gets stunned, remove
+/// its [`GridMoveBundle`]. The [`GridPosition`] keeps the last cell it
+/// committed to, which is the cell the next movement should start from. The
+/// [`Transform`] of the entity jumps to that cell when the next movement
+/// starts.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{recipes::*, *};
+/// # use std::time::Duration;
+/// fn move_right(mut commands: Commands, query: Query<(Entity, &GridPosition)>) {
+///     let grid_move = GridMove::new(32., Duration::from_millis(150));
+///     for (entity, position) in &query {
+///         let bundle = grid_move.step(position.0, position.0 + IVec2::X);
+///         commands.entity(entity).insert(bundle);
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub struct GridMove {
+    /// Size of a grid cell, in world units.
+    pub cell_size: f32,
+    /// Duration of a movement from a cell to the next one.
+    pub duration: Duration,
+    /// Easing method of the [`Transform`] movement.
+    pub ease: EaseMethod,
+    /// Fraction of the duration at which the [`GridPosition`] commits to the
+    /// next cell, in \[0:1\].
+    pub commit: f32,
+}
+
+impl GridMove {
+    /// Create a new grid movement for cells of the given size.
+    ///
+    /// The movement uses a quadratic ease-in-out, and commits to the next cell
+    /// halfway through.
+    pub fn new(cell_size: f32, duration: Duration) -> Self {
+        Self {
+            cell_size,
+            duration,
+            ease: EaseFunction::QuadraticInOut.into(),
+            commit: 0.5,
+        }
+    }
+
+    /// Set the easing method of the [`Transform`] movement.
+    #[must_use]
+    pub fn with_ease(mut self, ease: impl Into<EaseMethod>) -> Self {
+        self.ease = ease.into();
+        self
+    }
+
+    /// Set the fraction of the duration at which the [`GridPosition`]
+    /// commits to the next cell.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `commit` is not in \[0:1\].
+    #[must_use]
+    pub fn with_commit(mut self, commit: f32) -> Self {
+        assert!((0. ..=1.).contains(&commit));
+        self.commit = commit;
+        self
+    }
+
+    /// Build the animators moving an entity from the `from` cell to the `to`
+    /// cell.
+    pub fn step(&self, from: IVec2, to: IVec2) -> GridMoveBundle {
+        GridMoveBundle {
+            transform: Animator::new(Tween::new(
+                self.ease,
+                self.duration,
+                GridMoveLens {
+                    from,
+                    to,
+                    cell_size: self.cell_size,
+                },
+            )),
+            grid_position: Animator::new(Tween::new(
+                EaseMethod::Linear,
+                self.duration,
+                GridCommitLens {
+                    from,
+                    to,
+                    commit: self.commit,
+                },
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{component_animator_system, AnimatorStateChanged, TweenCompleted};
+
+    struct TestEnv {
+        world: World,
+        entity: Entity,
+    }
+
+    impl TestEnv {
+        fn new(position: IVec2) -> Self {
+            let mut world = World::new();
+            world.init_resource::<Events<TweenCompleted>>();
+            world.init_resource::<Events<AnimatorStateChanged>>();
+            world.init_resource::<Time>();
+            let entity = world
+                .spawn((Transform::from_xyz(0., 0., 3.), GridPosition(position)))
+                .id();
+            Self { world, entity }
+        }
+
+        fn insert(&mut self, bundle: GridMoveBundle) {
+            self.world.entity_mut(self.entity).insert(bundle);
+        }
+
+        fn translation(&self) -> Vec3 {
+            self.world
+                .get::<Transform>(self.entity)
+                .unwrap()
+                .translation
+        }
+
+        fn position(&self) -> IVec2 {
+            self.world.get::<GridPosition>(self.entity).unwrap().0
+        }
+
+        fn step(&mut self, duration: Duration) {
+            self.world.resource_mut::<Time>().advance_by(duration);
+            let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+            system.initialize(&mut self.world);
+            system.run((), &mut self.world);
+            let mut system = IntoSystem::into_system(component_animator_system::<GridPosition>);
+            system.initialize(&mut self.world);
+            system.run((), &mut self.world);
+        }
+    }
+
+    #[test]
+    fn grid_move_commit() {
+        let grid_move = GridMove::new(10., Duration::from_millis(100))
+            .with_ease(EaseMethod::Linear)
+            .with_commit(0.6);
+        let mut env = TestEnv::new(IVec2::ZERO);
+        env.insert(grid_move.step(IVec2::ZERO, IVec2::X));
+
+        // The position commits exactly once, when crossing the commit point
+        let mut commits = 0;
+        let mut position = env.position();
+        for _ in 0..10 {
+            env.step(Duration::from_millis(20));
+            if env.position() != position {
+                position = env.position();
+                commits += 1;
+            }
+            let x = env.translation().x;
+            assert_eq!(position, if x < 6. { IVec2::ZERO } else { IVec2::X });
+        }
+        assert_eq!(commits, 1);
+        assert_eq!(env.position(), IVec2::X);
+        assert_eq!(env.translation(), Vec3::new(10., 0., 3.));
+
+        // Rewinding re-arms the commit
+        env.world
+            .get_mut::<Animator<GridPosition>>(env.entity)
+            .unwrap()
+            .tweenable_mut()
+            .rewind();
+        env.world
+            .get_mut::<Animator<Transform>>(env.entity)
+            .unwrap()
+            .tweenable_mut()
+            .rewind();
+        env.step(Duration::from_millis(20));
+        assert_eq!(env.position(), IVec2::ZERO);
+        env.step(Duration::from_millis(60));
+        assert_eq!(env.position(), IVec2::X);
+    }
+
+    #[test]
+    fn grid_move_interrupted() {
+        let grid_move =
+            GridMove::new(10., Duration::from_millis(100)).with_ease(EaseMethod::Linear);
+        let mut env = TestEnv::new(IVec2::new(2, 3));
+        env.insert(grid_move.step(IVec2::new(2, 3), IVec2::new(2, 4)));
+
+        // Interrupted before the commit point; the entity still occupies its
+        // initial cell.
+        env.step(Duration::from_millis(40));
+        assert_eq!(env.position(), IVec2::new(2, 3));
+        assert!(env.translation().abs_diff_eq(Vec3::new(20., 34., 3.), 1e-4));
+        env.world.entity_mut(env.entity).remove::<GridMoveBundle>();
+        env.step(Duration::from_millis(100));
+        assert_eq!(env.position(), IVec2::new(2, 3));
+
+        // The next movement starts from that cell
+        let position = env.position();
+        env.insert(grid_move.step(position, position + IVec2::X));
+        env.step(Duration::ZERO);
+        assert!(env.translation().abs_diff_eq(Vec3::new(20., 30., 3.), 1e-4));
+        env.step(Duration::from_millis(100));
+        assert_eq!(env.position(), IVec2::new(3, 3));
+        assert!(env.translation().abs_diff_eq(Vec3::new(30., 30., 3.), 1e-4));
+    }
+}
@@ -0,0 +1,297 @@
+//! Smooth pseudo-random drift, for organic idle motion.
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::Lens;
+
+/// A lens moving the translation of a [`Transform`] around a base position
+/// with a smooth pseudo-random drift.
+///
+/// The offset from `base` follows a smooth noise on each axis, scaled by the
+/// `amplitude` of that axis. The noise is a function of the ratio of the
+/// animation only, so the motion is fully determined by the `seed` and the
+/// progress of the tween, whatever its duration and the deltas it's ticked
+/// with. A zero amplitude on an axis writes exactly the base value on that
+/// axis.
+///
+/// The noise is sampled along a circle, so its end joins its start, and a
+/// looping tween drifts continuously without any jump when it repeats. The
+/// `frequency` is roughly the number of changes of direction of each axis
+/// over a single iteration of the animation.
+///
+/// Use [`NoiseRotationLens`] to drift the rotation instead.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{recipes::*, *};
+/// # use std::time::Duration;
+/// fn float_idle(mut commands: Commands, buoy: Entity) {
+///     let lens = NoiseLens::new(Vec3::new(0., 1., 0.), Vec3::new(0.2, 0.1, 0.2)).with_seed(3);
+///     let tween = Tween::new(EaseMethod::Linear, Duration::from_secs(8), lens)
+///         .with_repeat_count(RepeatCount::Infinite);
+///     commands.entity(buoy).insert(Animator::new(tween));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoiseLens {
+    /// Position the drift is centered on.
+    pub base: Vec3,
+    /// Maximum offset from the base position on each axis.
+    pub amplitude: Vec3,
+    /// Roughly the number of changes of direction per iteration.
+    pub frequency: f32,
+    /// Seed of the noise.
+    pub seed: u64,
+}
+
+impl NoiseLens {
+    /// Create a drift around `base` with the given amplitude, at a frequency
+    /// of 4 with a seed of zero.
+    #[must_use]
+    pub fn new(base: Vec3, amplitude: Vec3) -> Self {
+        Self {
+            base,
+            amplitude,
+            frequency: 4.,
+            seed: 0,
+        }
+    }
+
+    /// Set the number of changes of direction per iteration.
+    #[must_use]
+    pub fn with_frequency(mut self, frequency: f32) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    /// Set the seed of the noise.
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Get the offset from the base position at the given ratio.
+    ///
+    /// The offset at a ratio of `1.` is exactly the one at `0.`. This can be
+    /// used to drive a custom lens, like one drifting a scalar value.
+    #[must_use]
+    pub fn offset(&self, ratio: f32) -> Vec3 {
+        self.amplitude * loop_noise(self.seed, self.frequency, ratio)
+    }
+}
+
+impl Lens<Transform> for NoiseLens {
+    fn lerp(&mut self, target: &mut Transform, ratio: f32) {
+        target.translation = self.base + self.offset(ratio);
+    }
+
+    fn sample(&self, target: &mut Transform, ratio: f32) -> bool {
+        target.translation = self.base + self.offset(ratio);
+        true
+    }
+}
+
+/// A lens rotating a [`Transform`] around a base rotation with a smooth
+/// pseudo-random drift.
+///
+/// The drift is applied on top of `base` as a rotation by the Euler angles of
+/// the noise, in radians, in the XYZ order. The `amplitude` is the maximum
+/// angle on each axis. See [`NoiseLens`] for details on the noise itself.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoiseRotationLens {
+    /// Rotation the drift is centered on.
+    pub base: Quat,
+    /// Maximum angle in radians around each axis.
+    pub amplitude: Vec3,
+    /// Roughly the number of changes of direction per iteration.
+    pub frequency: f32,
+    /// Seed of the noise.
+    pub seed: u64,
+}
+
+impl NoiseRotationLens {
+    /// Create a drift around `base` with the given amplitude in radians, at a
+    /// frequency of 4 with a seed of zero.
+    #[must_use]
+    pub fn new(base: Quat, amplitude: Vec3) -> Self {
+        Self {
+            base,
+            amplitude,
+            frequency: 4.,
+            seed: 0,
+        }
+    }
+
+    /// Set the number of changes of direction per iteration.
+    #[must_use]
+    pub fn with_frequency(mut self, frequency: f32) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    /// Set the seed of the noise.
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Get the rotation at the given ratio.
+    #[must_use]
+    pub fn rotation(&self, ratio: f32) -> Quat {
+        if self.amplitude == Vec3::ZERO {
+            return self.base;
+        }
+        let angles = self.amplitude * loop_noise(self.seed, self.frequency, ratio);
+        self.base * Quat::from_euler(EulerRot::XYZ, angles.x, angles.y, angles.z)
+    }
+}
+
+impl Lens<Transform> for NoiseRotationLens {
+    fn lerp(&mut self, target: &mut Transform, ratio: f32) {
+        target.rotation = self.rotation(ratio);
+    }
+
+    fn sample(&self, target: &mut Transform, ratio: f32) -> bool {
+        target.rotation = self.rotation(ratio);
+        true
+    }
+}
+
+/// Sample the smooth noise of each axis at a ratio, in \[-1:1\].
+///
+/// The ratio is mapped to an angle around a circle in a 2D value noise, whose
+/// circumference spans `frequency` cells of the noise, so a ratio of `1.`
+/// samples exactly the same point as `0.`.
+fn loop_noise(seed: u64, frequency: f32, ratio: f32) -> Vec3 {
+    let angle = ratio.rem_euclid(1.) * TAU;
+    let radius = frequency / TAU;
+    let point = Vec2::new(angle.cos(), angle.sin()) * radius;
+    Vec3::new(
+        value_noise(seed, 0, point),
+        value_noise(seed, 1, point),
+        value_noise(seed, 2, point),
+    )
+}
+
+/// Sample the 2D value noise of an axis, in \[-1:1\].
+fn value_noise(seed: u64, axis: u64, point: Vec2) -> f32 {
+    let cell = point.floor();
+    let fract = point - cell;
+    let (x, y) = (cell.x as i64, cell.y as i64);
+    let s = fract * fract * (Vec2::splat(3.) - 2. * fract);
+    let top = lerp(knot(seed, axis, x, y), knot(seed, axis, x + 1, y), s.x);
+    let bottom = lerp(
+        knot(seed, axis, x, y + 1),
+        knot(seed, axis, x + 1, y + 1),
+        s.x,
+    );
+    lerp(top, bottom, s.y)
+}
+
+fn lerp(a: f32, b: f32, ratio: f32) -> f32 {
+    a + (b - a) * ratio
+}
+
+/// Get the pseudo-random value of an axis at a point of the noise lattice, in
+/// \[-1:1\].
+fn knot(seed: u64, axis: u64, x: i64, y: i64) -> f32 {
+    // SplitMix64 finalizer
+    let mut h = seed
+        ^ axis.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ (x as u64).wrapping_mul(0xBF58_476D_1CE4_E5B9)
+        ^ (y as u64).wrapping_mul(0x94D0_49BB_1331_11EB);
+    h = (h ^ (h >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    h = (h ^ (h >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    h ^= h >> 31;
+    (h >> 40) as f32 / (1_u64 << 24) as f32 * 2. - 1.
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{tweenable::ComponentTarget, EaseMethod, RepeatCount, Tween, Tweenable};
+
+    /// Tick `tween` `ticks` times in steps of 50 ms, and collect the values of
+    /// the target after each tick.
+    fn run(mut tween: Tween<Transform>, ticks: usize) -> Vec<Transform> {
+        let mut world = World::new();
+        let entity = world.spawn(Transform::default()).id();
+        (0..ticks)
+            .map(|_| {
+                let target = world.get_mut::<Transform>(entity).unwrap();
+                tween.tick(
+                    Duration::from_millis(50),
+                    &mut ComponentTarget::new(target),
+                    entity,
+                    &mut vec![],
+                );
+                *world.get::<Transform>(entity).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn noise_golden_values() {
+        let lens = NoiseLens::new(Vec3::new(1., 2., 3.), Vec3::new(1., 0.5, 2.)).with_seed(42);
+        let golden = [
+            (0., Vec3::new(-0.289_455_35, 0.385_589_84, 0.349_317_2)),
+            (0.25, Vec3::new(-0.587_192_06, 0.188_218_53, -0.268_165_23)),
+            (0.6, Vec3::new(-0.188_263_9, 0.068_988_204, 1.112_121_1)),
+        ];
+        for (ratio, offset) in golden {
+            assert!(
+                lens.offset(ratio).abs_diff_eq(offset, 1e-5),
+                "{ratio}: {} != {offset}",
+                lens.offset(ratio)
+            );
+        }
+        assert_ne!(lens.offset(0.25), lens.with_seed(43).offset(0.25));
+    }
+
+    #[test]
+    fn noise_loops_seamlessly() {
+        let lens = NoiseLens::new(Vec3::ZERO, Vec3::ONE)
+            .with_seed(9)
+            .with_frequency(6.);
+        assert_eq!(lens.offset(0.), lens.offset(1.));
+        assert!(lens.offset(0.999).abs_diff_eq(lens.offset(0.001), 0.05));
+
+        // A looping tween repeats exactly the same motion
+        let tween = Tween::new(EaseMethod::Linear, Duration::from_secs(1), lens)
+            .with_repeat_count(RepeatCount::Infinite);
+        let values = run(tween, 60);
+        assert!(values.iter().any(|t| t.translation.length() > 0.3));
+        for (first, second) in values[..20].iter().zip(&values[20..40]) {
+            assert!(first.translation.abs_diff_eq(second.translation, 1e-5));
+        }
+        for pair in values.windows(2) {
+            assert!(pair[0].translation.distance(pair[1].translation) < 1.);
+        }
+    }
+
+    #[test]
+    fn noise_zero_amplitude() {
+        let base = Vec3::new(0.3, -7.1, 2.9);
+        let lens = NoiseLens::new(base, Vec3::ZERO).with_seed(5);
+        let tween = Tween::new(EaseMethod::Linear, Duration::from_secs(1), lens);
+        assert!(run(tween, 25).iter().all(|t| t.translation == base));
+
+        let base = Quat::from_rotation_y(1.2);
+        let lens = NoiseRotationLens::new(base, Vec3::ZERO).with_seed(5);
+        let tween = Tween::new(EaseMethod::Linear, Duration::from_secs(1), lens);
+        assert!(run(tween, 25).iter().all(|t| t.rotation == base));
+
+        // Zero on a single axis keeps that axis exactly at its base value
+        let lens = NoiseLens::new(Vec3::ONE, Vec3::new(1., 0., 1.));
+        let tween = Tween::new(EaseMethod::Linear, Duration::from_secs(1), lens);
+        assert!(run(tween, 25).iter().all(|t| t.translation.y == 1.));
+    }
+}
@@ -0,0 +1,84 @@
+//! One-shot punch animation.
+
+use std::time::Duration;
+
+use crate::{EaseMethod, Lens, Tween};
+
+/// Create a tween going to the end state of a lens and back once.
+///
+/// The tween plays the `lens` from its start to its end state in `duration`,
+/// then back to its start state in the same duration, and completes. This is
+/// the common "punch" effect, like a scale bump on a hit or a flash of color.
+/// The completion event and callback are raised once, when the target is back
+/// at its exact start value. See [`Tween::ping_pong_once()`] for details.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, recipes::*, *};
+/// # use std::time::Duration;
+/// fn on_hit(mut commands: Commands, entity: Entity) {
+///     let tween = punch(
+///         EaseFunction::QuadraticOut,
+///         Duration::from_millis(80),
+///         TransformScaleLens {
+///             start: Vec3::ONE,
+///             end: Vec3::splat(1.3),
+///         },
+///     );
+///     commands.entity(entity).insert(Animator::new(tween));
+/// }
+/// ```
+pub fn punch<T, L>(ease_function: impl Into<EaseMethod>, duration: Duration, lens: L) -> Tween<T>
+where
+    L: Lens<T> + Send + Sync + 'static,
+{
+    Tween::new(ease_function, duration, lens).ping_pong_once()
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use super::*;
+    use crate::{
+        lens::TransformScaleLens, tweenable::ComponentTarget, EaseFunction, TotalDuration,
+        TweenState, Tweenable,
+    };
+
+    #[test]
+    fn punch_returns_to_start() {
+        let start = Vec3::new(0.7, 1.1, 1.3);
+        let mut tween = punch(
+            EaseFunction::BackOut,
+            Duration::from_millis(100),
+            TransformScaleLens {
+                start,
+                end: start * 1.5,
+            },
+        )
+        .with_completed_event(0);
+        assert_eq!(
+            tween.total_duration(),
+            TotalDuration::Finite(Duration::from_millis(200))
+        );
+
+        let mut world = World::new();
+        let entity = world.spawn(Transform::from_scale(start)).id();
+        let mut max_scale = 0_f32;
+        let mut state = TweenState::Active;
+        let mut events = Vec::new();
+        for _ in 0..13 {
+            let transform = world.get_mut::<Transform>(entity).unwrap();
+            let mut target = ComponentTarget::new(transform);
+            state = tween.tick(Duration::from_millis(17), &mut target, entity, &mut events);
+            max_scale = max_scale.max(world.get::<Transform>(entity).unwrap().scale.x);
+        }
+
+        assert_eq!(state, TweenState::Completed);
+        assert!(max_scale > start.x * 1.5 - 1e-5);
+        assert_eq!(world.get::<Transform>(entity).unwrap().scale, start);
+        assert_eq!(events.len(), 1);
+    }
+}
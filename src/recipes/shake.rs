@@ -0,0 +1,295 @@
+//! Decaying pseudo-random shake, for camera or trauma shake.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{EaseMethod, Lens, Tween};
+
+/// Translation offset of an entity shaken by a [`ShakeLens`].
+///
+/// Shaking the [`Transform`] of an entity directly fights with any other
+/// animation or system moving it. Instead, a shake can animate this offset,
+/// which a system of the application adds to the position of the entity after
+/// it was moved by everything else. Its animator system is registered by
+/// default by the [`TweeningPlugin`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::recipes::ShakeOffset;
+/// /// Position of the camera, before shaking.
+/// #[derive(Component)]
+/// struct CameraRig(Vec3);
+///
+/// fn apply_shake(mut query: Query<(&CameraRig, &ShakeOffset, &mut Transform)>) {
+///     for (rig, offset, mut transform) in &mut query {
+///         transform.translation = rig.0 + offset.0;
+///     }
+/// }
+/// ```
+///
+/// [`TweeningPlugin`]: crate::TweeningPlugin
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component)]
+pub struct ShakeOffset(pub Vec3);
+
+/// A lens shaking a [`ShakeOffset`] with a decaying pseudo-random motion.
+///
+/// The offset follows a smooth noise on each axis, scaled by the `amplitude`
+/// of that axis, and sampled `frequency` times per second of animation. The
+/// noise is deterministic: the same `seed` always produces the same motion.
+/// The amplitude decays over the animation following the `decay` easing
+/// method, from its full value at the start to zero at the end, where the
+/// offset is exactly zero.
+///
+/// Because the lens converts the ratio of the animation into a time to sample
+/// the noise, the tween playing it must have the same `duration` as the lens.
+/// Use [`shake()`] or [`shake_transform()`] to create such a tween.
+#[derive(Clone, Copy)]
+pub struct ShakeLens {
+    /// Duration of the shake.
+    pub duration: Duration,
+    /// Maximum offset on each axis, at the start of the shake.
+    pub amplitude: Vec3,
+    /// Number of noise samples per second, controlling how fast the offset
+    /// changes direction.
+    pub frequency: f32,
+    /// Seed of the noise.
+    pub seed: u64,
+    /// Easing method of the decay of the amplitude, from full to zero.
+    pub decay: EaseMethod,
+}
+
+impl ShakeLens {
+    /// Create a shake of the given duration and amplitude, at 20 noise
+    /// samples per second, with a seed of zero and a linear decay.
+    #[must_use]
+    pub fn new(duration: Duration, amplitude: impl Into<Vec3>) -> Self {
+        Self {
+            duration,
+            amplitude: amplitude.into(),
+            frequency: 20.,
+            seed: 0,
+            decay: EaseMethod::Linear,
+        }
+    }
+
+    /// Set the number of noise samples per second.
+    #[must_use]
+    pub fn with_frequency(mut self, frequency: f32) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    /// Set the seed of the noise.
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Set the easing method of the decay of the amplitude.
+    #[must_use]
+    pub fn with_decay(mut self, decay: impl Into<EaseMethod>) -> Self {
+        self.decay = decay.into();
+        self
+    }
+
+    /// Get the offset of the shake at the given ratio of its duration.
+    ///
+    /// The offset is exactly zero from a ratio of `1.` onward.
+    #[must_use]
+    pub fn offset(&self, ratio: f32) -> Vec3 {
+        if ratio >= 1. {
+            return Vec3::ZERO;
+        }
+        let envelope = 1. - self.decay.sample(ratio);
+        let time = ratio * self.duration.as_secs_f32() * self.frequency;
+        let noise = Vec3::new(
+            self.noise(0, time),
+            self.noise(1, time),
+            self.noise(2, time),
+        );
+        self.amplitude * noise * envelope
+    }
+
+    /// Sample the smooth noise of an axis, in \[-1:1\].
+    fn noise(&self, axis: u64, time: f32) -> f32 {
+        let index = time.floor();
+        let fract = time - index;
+        let index = index as i64;
+        let a = self.knot(axis, index);
+        let b = self.knot(axis, index.wrapping_add(1));
+        let s = fract * fract * (3. - 2. * fract);
+        a + (b - a) * s
+    }
+
+    /// Get the pseudo-random noise value of an axis at an integer time, in
+    /// \[-1:1\].
+    fn knot(&self, axis: u64, index: i64) -> f32 {
+        // SplitMix64 finalizer
+        let mut x = self.seed
+            ^ axis.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+            ^ (index as u64).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        x ^= x >> 31;
+        (x >> 40) as f32 / (1_u64 << 24) as f32 * 2. - 1.
+    }
+}
+
+impl Lens<ShakeOffset> for ShakeLens {
+    fn lerp(&mut self, target: &mut ShakeOffset, ratio: f32) {
+        target.0 = self.offset(ratio);
+    }
+
+    fn sample(&self, target: &mut ShakeOffset, ratio: f32) -> bool {
+        target.0 = self.offset(ratio);
+        true
+    }
+}
+
+/// A lens shaking the translation of a [`Transform`] around a base position.
+///
+/// The base position is captured from the target the first time the lens is
+/// applied, and the offset of the [`ShakeLens`] is added to it on each tick.
+/// The translation returns exactly to the base position at the end of the
+/// shake. The lens overwrites the translation, so it can't be combined with
+/// another animation of the same [`Transform`]; animate a [`ShakeOffset`]
+/// instead in that case.
+#[derive(Clone, Copy)]
+pub struct ShakeTransformLens {
+    /// The shake added to the base position.
+    pub shake: ShakeLens,
+    /// Base position, captured on the first application if `None`.
+    pub base: Option<Vec3>,
+}
+
+impl Lens<Transform> for ShakeTransformLens {
+    fn lerp(&mut self, target: &mut Transform, ratio: f32) {
+        let base = *self.base.get_or_insert(target.translation);
+        target.translation = base + self.shake.offset(ratio);
+    }
+
+    fn sample(&self, target: &mut Transform, ratio: f32) -> bool {
+        let Some(base) = self.base else {
+            return false;
+        };
+        target.translation = base + self.shake.offset(ratio);
+        true
+    }
+}
+
+/// Create a tween shaking a [`ShakeOffset`].
+///
+/// The tween has the duration of the shake, and a linear easing, the decay
+/// being eased by the lens itself.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{recipes::*, *};
+/// # use std::time::Duration;
+/// fn on_explosion(mut commands: Commands, camera: Entity) {
+///     let tween = shake(
+///         ShakeLens::new(Duration::from_millis(400), Vec3::new(12., 8., 0.))
+///             .with_seed(7)
+///             .with_decay(EaseFunction::QuadraticOut),
+///     );
+///     commands.entity(camera).insert(Animator::new(tween));
+/// }
+/// ```
+pub fn shake(lens: ShakeLens) -> Tween<ShakeOffset> {
+    Tween::new(EaseMethod::Linear, lens.duration, lens)
+}
+
+/// Create a tween shaking the translation of a [`Transform`] around its
+/// current position.
+///
+/// See [`ShakeTransformLens`] for the limitations of shaking the [`Transform`]
+/// directly, and [`shake()`] to shake a [`ShakeOffset`] instead.
+pub fn shake_transform(lens: ShakeLens) -> Tween<Transform> {
+    Tween::new(
+        EaseMethod::Linear,
+        lens.duration,
+        ShakeTransformLens {
+            shake: lens,
+            base: None,
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tweenable::ComponentTarget, EaseFunction, TweenState, Tweenable};
+
+    fn make_lens(seed: u64) -> ShakeLens {
+        ShakeLens::new(Duration::from_secs(1), Vec3::new(3., 2., 1.))
+            .with_seed(seed)
+            .with_decay(EaseFunction::QuadraticOut)
+    }
+
+    /// Tick a shake of a `T` to completion in steps of 16 ms, and collect the
+    /// values of the target after each tick.
+    fn run<T: Component + Clone>(mut tween: Tween<T>, start: T) -> (Vec<T>, TweenState) {
+        let mut world = World::new();
+        let entity = world.spawn(start).id();
+        let mut values = vec![];
+        let mut state = TweenState::Active;
+        while state == TweenState::Active {
+            let target = world.get_mut::<T>(entity).unwrap();
+            state = tween.tick(
+                Duration::from_millis(16),
+                &mut ComponentTarget::new(target),
+                entity,
+                &mut vec![],
+            );
+            values.push(world.get::<T>(entity).unwrap().clone());
+        }
+        (values, state)
+    }
+
+    #[test]
+    fn shake_deterministic() {
+        let (values, _) = run(shake(make_lens(42)), ShakeOffset::default());
+        let (again, _) = run(shake(make_lens(42)), ShakeOffset::default());
+        assert_eq!(values, again);
+        assert!(values.iter().any(|offset| offset.0.length() > 0.5));
+        for offset in &values {
+            assert!(offset.0.abs().cmple(Vec3::new(3., 2., 1.)).all());
+        }
+
+        let (other, _) = run(shake(make_lens(43)), ShakeOffset::default());
+        assert_ne!(values, other);
+    }
+
+    #[test]
+    fn shake_returns_to_base() {
+        let (values, state) = run(shake(make_lens(1)), ShakeOffset::default());
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(values.last().unwrap().0, Vec3::ZERO);
+
+        let base = Vec3::new(0.3, -7.1, 2.9);
+        let (values, state) = run(
+            shake_transform(make_lens(1)),
+            Transform::from_translation(base),
+        );
+        assert_eq!(state, TweenState::Completed);
+        assert!(values.iter().any(|t| t.translation != base));
+        assert_eq!(values.last().unwrap().translation, base);
+    }
+
+    #[test]
+    fn shake_zero_amplitude() {
+        let lens = make_lens(5).with_frequency(60.);
+        let lens = ShakeLens {
+            amplitude: Vec3::ZERO,
+            ..lens
+        };
+        let (values, _) = run(shake(lens), ShakeOffset::default());
+        assert!(values.iter().all(|offset| offset.0 == Vec3::ZERO));
+    }
+}
@@ -0,0 +1,427 @@
+//! Recording of the values produced by a tweenable.
+//!
+//! This module is only available with the `recording` feature.
+
+use std::{
+    any::Any,
+    collections::VecDeque,
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    BoxedTweenable, Targetable, TotalDuration, TweenCancelled, TweenCompleted, TweenId,
+    TweenOverrides, TweenState, TweenTuning, TweenValidationError, Tweenable, TweenableState,
+    TweeningDirection,
+};
+
+/// A single value recorded by a [`TweenRecorder`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedSample<T> {
+    /// Elapsed time of the recorded tweenable when the value was sampled.
+    pub elapsed: Duration,
+    /// Value of the animation target after the tweenable was ticked.
+    pub value: T,
+}
+
+/// Shared handle to the samples recorded by a [`TweenRecorder`].
+///
+/// The handle remains valid after the recorder itself was moved into an
+/// animator, or dropped, so the samples can be retrieved once the animation
+/// ran. The buffer is bounded: once it contains `capacity` samples, recording a
+/// new sample discards the oldest one.
+///
+/// The samples can be serialized with any [`serde`] format to produce golden
+/// files, which can later be deserialized and compared to a new recording
+/// with [`mismatch()`].
+///
+/// [`serde`]: https://docs.rs/serde
+/// [`mismatch()`]: TweenRecording::mismatch
+#[derive(Debug)]
+pub struct TweenRecording<T> {
+    samples: Arc<Mutex<VecDeque<RecordedSample<T>>>>,
+    capacity: usize,
+}
+
+impl<T> Clone for TweenRecording<T> {
+    fn clone(&self) -> Self {
+        Self {
+            samples: Arc::clone(&self.samples),
+            capacity: self.capacity,
+        }
+    }
+}
+
+impl<T: Clone> TweenRecording<T> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            samples: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+            capacity,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, VecDeque<RecordedSample<T>>> {
+        // A panic while holding the lock can't leave the buffer in an
+        // inconsistent state, so ignore poisoning.
+        self.samples
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn push(&self, sample: RecordedSample<T>) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut samples = self.lock();
+        if samples.len() == self.capacity {
+            samples.pop_front();
+        }
+        samples.push_back(sample);
+    }
+
+    /// Maximum number of samples retained.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of samples currently retained.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Check if no sample is currently retained.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Get a copy of the retained samples, from oldest to newest.
+    pub fn samples(&self) -> Vec<RecordedSample<T>> {
+        self.lock().iter().cloned().collect()
+    }
+
+    /// Discard all retained samples.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Compare the retained samples against a golden recording.
+    ///
+    /// Two samples match if their elapsed times are equal, and the `distance`
+    /// between their values is at most `tolerance`. Returns the index of the
+    /// first sample which doesn't match, or `None` if all samples match. If
+    /// the two recordings have a different length, and all common samples
+    /// match, the index returned is the length of the shortest one.
+    pub fn mismatch(
+        &self,
+        golden: &[RecordedSample<T>],
+        tolerance: f32,
+        distance: impl Fn(&T, &T) -> f32,
+    ) -> Option<usize> {
+        let samples = self.lock();
+        let index = samples.iter().zip(golden).position(|(sample, golden)| {
+            sample.elapsed != golden.elapsed || distance(&sample.value, &golden.value) > tolerance
+        });
+        if index.is_some() {
+            index
+        } else if samples.len() != golden.len() {
+            Some(samples.len().min(golden.len()))
+        } else {
+            None
+        }
+    }
+}
+
+/// Tweenable recording the values produced by another tweenable.
+///
+/// This wraps an existing tweenable, and after each tick records the elapsed
+/// time of the child and a copy of the animation target into a bounded
+/// [`TweenRecording`]. This allows capturing the curve a tweenable actually
+/// produced, either to debug it or to compare it against a golden recording
+/// in a regression test.
+///
+/// The target is read through [`Targetable::target_mut()`], so recording
+/// triggers change detection even on ticks where the child doesn't modify the
+/// target. Once the child completed, ticking it again doesn't record any new
+/// sample.
+///
+/// This type is only available with the `recording` feature.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// #[derive(Resource)]
+/// struct Recording(TweenRecording<Transform>);
+///
+/// fn setup(mut commands: Commands) {
+///     let tween = Tween::new(
+///         EaseFunction::QuadraticInOut,
+///         1.,
+///         TransformPositionLens {
+///             start: Vec3::ZERO,
+///             end: Vec3::X,
+///         },
+///     );
+///     let recorder = TweenRecorder::new(tween, 256);
+///     commands.insert_resource(Recording(recorder.recording()));
+///     commands.spawn((Transform::default(), Animator::new(recorder)));
+/// }
+///
+/// // Later, once the animation ran
+/// fn dump(recording: Res<Recording>) {
+///     for sample in recording.0.samples() {
+///         println!("{:?}: {:?}", sample.elapsed, sample.value.translation);
+///     }
+/// }
+/// ```
+pub struct TweenRecorder<T> {
+    child: BoxedTweenable<T>,
+    recording: TweenRecording<T>,
+}
+
+impl<T: Clone + Send + 'static> TweenRecorder<T> {
+    /// Create a new recorder for `child`, retaining at most `capacity`
+    /// samples.
+    #[must_use]
+    pub fn new(child: impl Into<BoxedTweenable<T>>, capacity: usize) -> Self {
+        Self {
+            child: child.into(),
+            recording: TweenRecording::new(capacity),
+        }
+    }
+
+    /// Get the wrapped tweenable.
+    #[must_use]
+    pub fn child(&self) -> &dyn Tweenable<T> {
+        self.child.as_ref()
+    }
+
+    /// Get a handle to the recorded samples.
+    #[must_use]
+    pub fn recording(&self) -> TweenRecording<T> {
+        self.recording.clone()
+    }
+}
+
+impl<T: Clone + Send + 'static> Tweenable<T> for TweenRecorder<T> {
+    fn id(&self) -> Option<TweenId> {
+        self.child.id()
+    }
+
+    fn duration(&self) -> Duration {
+        self.child.duration()
+    }
+
+    fn total_duration(&self) -> TotalDuration {
+        self.child.total_duration()
+    }
+
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        self.child.set_elapsed(elapsed);
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.child.elapsed()
+    }
+
+    fn state(&self) -> TweenState {
+        self.child.state()
+    }
+
+    fn tick(
+        &mut self,
+        delta: Duration,
+        target: &mut dyn Targetable<T>,
+        entity: Entity,
+        events: &mut Vec<TweenCompleted>,
+    ) -> TweenState {
+        let was_completed = match self.child.total_duration() {
+            TotalDuration::Finite(total_duration) => self.child.elapsed() >= total_duration,
+            TotalDuration::Infinite => false,
+        };
+        let state = self.child.tick(delta, target, entity, events);
+        if !was_completed {
+            self.recording.push(RecordedSample {
+                elapsed: self.child.elapsed(),
+                value: target.target_mut().clone(),
+            });
+        }
+        state
+    }
+
+    fn sample(&self, elapsed: Duration, target: &mut T) -> bool {
+        self.child.sample(elapsed, target)
+    }
+
+    fn direction(&self) -> Option<TweeningDirection> {
+        self.child.direction()
+    }
+
+    fn set_direction(&mut self, direction: TweeningDirection) -> bool {
+        self.child.set_direction(direction)
+    }
+
+    fn apply_tuning(&mut self, tuning: &TweenTuning) {
+        self.child.apply_tuning(tuning);
+    }
+
+    fn apply_overrides(&mut self, overrides: &TweenOverrides) {
+        self.child.apply_overrides(overrides);
+    }
+
+    fn boundary_value(&self) -> Option<Box<dyn Any + Send + Sync>> {
+        self.child.boundary_value()
+    }
+
+    fn current_value(&self) -> Option<Box<dyn Any + Send + Sync>> {
+        self.child.current_value()
+    }
+
+    fn blend_start(&mut self, from: Box<dyn Any + Send + Sync>, window: Duration) -> bool {
+        self.child.blend_start(from, window)
+    }
+
+    fn collect_cancelled(&self, entity: Entity, cancelled: &mut Vec<TweenCancelled>) {
+        self.child.collect_cancelled(entity, cancelled);
+    }
+
+    fn take_token_cancelled(&mut self, cancelled: &mut Vec<TweenCancelled>) {
+        self.child.take_token_cancelled(cancelled);
+    }
+
+    fn rewind(&mut self) {
+        self.child.rewind();
+    }
+
+    fn times_completed(&self) -> u32 {
+        self.child.times_completed()
+    }
+
+    fn validate(&self) -> Result<(), TweenValidationError> {
+        self.child.validate()
+    }
+
+    fn save_state(&self) -> TweenableState {
+        self.child.save_state()
+    }
+
+    fn restore_state(&mut self, state: &TweenableState) -> bool {
+        self.child.restore_state(state)
+    }
+}
+
+impl<T: Clone + Send + 'static> From<TweenRecorder<T>> for BoxedTweenable<T> {
+    fn from(t: TweenRecorder<T>) -> Self {
+        Box::new(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        lens::TransformPositionLens, test_utils::*, tweenable::ComponentTarget, EaseFunction,
+        EaseMethod, Tween,
+    };
+
+    fn make_recorder(capacity: usize) -> TweenRecorder<Transform> {
+        let tween = Tween::new(
+            EaseFunction::QuadraticInOut,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::X,
+            },
+        );
+        TweenRecorder::new(tween, capacity)
+    }
+
+    fn run(recorder: &mut TweenRecorder<Transform>, ticks: u32) {
+        let mut world = World::new();
+        let entity = world.spawn(Transform::default()).id();
+        let mut events = Vec::new();
+        for _ in 0..ticks {
+            let transform = world.get_mut::<Transform>(entity).unwrap();
+            let mut target = ComponentTarget::new(transform);
+            recorder.tick(Duration::from_millis(250), &mut target, entity, &mut events);
+        }
+    }
+
+    #[test]
+    fn record() {
+        let mut recorder = make_recorder(16);
+        let recording = recorder.recording();
+        assert!(recording.is_empty());
+
+        // The two ticks after completion are not recorded.
+        run(&mut recorder, 6);
+        let samples = recording.samples();
+        assert_eq!(samples.len(), 4);
+        for (index, sample) in samples.iter().enumerate() {
+            let progress = (index + 1) as f32 / 4.;
+            assert_eq!(sample.elapsed, Duration::from_secs_f32(progress));
+            let expected = EaseMethod::from(EaseFunction::QuadraticInOut).sample(progress);
+            assert!(abs_diff_eq(sample.value.translation.x, expected, 1e-5));
+        }
+
+        recording.clear();
+        assert!(recording.is_empty());
+    }
+
+    #[test]
+    fn record_bounded() {
+        let mut recorder = make_recorder(3);
+        let recording = recorder.recording();
+        run(&mut recorder, 4);
+        assert_eq!(recording.capacity(), 3);
+        let samples = recording.samples();
+        assert_eq!(samples.len(), 3);
+        assert_eq!(samples[0].elapsed, Duration::from_millis(500));
+        assert_eq!(samples[2].elapsed, Duration::from_secs(1));
+
+        let mut recorder = make_recorder(0);
+        let recording = recorder.recording();
+        run(&mut recorder, 4);
+        assert!(recording.is_empty());
+    }
+
+    #[test]
+    fn golden() {
+        let mut recorder = make_recorder(16);
+        let recording = recorder.recording();
+        run(&mut recorder, 4);
+
+        let golden = ron::to_string(
+            &recording
+                .samples()
+                .into_iter()
+                .map(|s| RecordedSample {
+                    elapsed: s.elapsed,
+                    value: s.value.translation.to_array(),
+                })
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+        let golden: Vec<RecordedSample<[f32; 3]>> = ron::from_str(&golden).unwrap();
+        let golden: Vec<_> = golden
+            .into_iter()
+            .map(|s| RecordedSample {
+                elapsed: s.elapsed,
+                value: Transform::from_translation(Vec3::from_array(s.value)),
+            })
+            .collect();
+
+        let distance = |a: &Transform, b: &Transform| a.translation.distance(b.translation);
+        assert_eq!(recording.mismatch(&golden, 1e-5, distance), None);
+
+        let mut altered = golden.clone();
+        altered[2].value.translation.x += 0.1;
+        assert_eq!(recording.mismatch(&altered, 1e-5, distance), Some(2));
+        assert_eq!(recording.mismatch(&altered, 0.2, distance), None);
+        assert_eq!(recording.mismatch(&golden[..3], 1e-5, distance), Some(3));
+    }
+}
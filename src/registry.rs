@@ -0,0 +1,684 @@
+//! Registry of lens factories, to construct lenses by name at runtime.
+
+use std::any::{Any, TypeId};
+
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::{lens::*, Lens, TweeningDirection};
+
+/// A lens of a type only known at runtime, constructed by a [`LensRegistry`].
+///
+/// This is a [`Lens`] itself, so can be animated by a [`Tween`] like any
+/// other lens.
+///
+/// [`Tween`]: crate::Tween
+pub type DynLens<T> = Box<dyn Lens<T> + Send + Sync + 'static>;
+
+impl<T> Lens<T> for DynLens<T> {
+    fn lerp(&mut self, target: &mut T, ratio: f32) {
+        self.as_mut().lerp(target, ratio);
+    }
+
+    fn sample(&self, target: &mut T, ratio: f32) -> bool {
+        self.as_ref().sample(target, ratio)
+    }
+
+    fn lerp_directed(&mut self, target: &mut T, ratio: f32, direction: TweeningDirection) {
+        self.as_mut().lerp_directed(target, ratio, direction);
+    }
+
+    fn lerp_ex(&mut self, target: &mut T, eased: f32, raw: f32, direction: TweeningDirection) {
+        self.as_mut().lerp_ex(target, eased, raw, direction);
+    }
+
+    fn sample_directed(&self, target: &mut T, ratio: f32, direction: TweeningDirection) -> bool {
+        self.as_ref().sample_directed(target, ratio, direction)
+    }
+
+    fn set_context(&mut self, context: &LensContext) {
+        self.as_mut().set_context(context);
+    }
+
+    fn scale_range(&mut self, factor: f32) -> bool {
+        self.as_mut().scale_range(factor)
+    }
+
+    fn boundary_value(&self, direction: TweeningDirection) -> Option<Box<dyn Any + Send + Sync>> {
+        self.as_ref().boundary_value(direction)
+    }
+
+    fn lerp_from(&mut self, target: &mut T, ratio: f32, from: &dyn Any, weight: f32) -> bool {
+        self.as_mut().lerp_from(target, ratio, from, weight)
+    }
+}
+
+/// Error returned when constructing a lens with a [`LensRegistry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LensRegistryError {
+    /// No lens factory is registered under this name for the target type.
+    UnknownLens(String),
+    /// The parameter of this name required by the lens is missing.
+    MissingParam(String),
+    /// The parameter of this name has the wrong type or an invalid value.
+    InvalidParam(String),
+    /// The parameters string parsed by [`LensParams::parse()`] is malformed
+    /// at this entry.
+    MalformedParams(String),
+}
+
+impl std::fmt::Display for LensRegistryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownLens(name) => {
+                write!(f, "no lens named '{name}' is registered for this target")
+            }
+            Self::MissingParam(name) => write!(f, "the lens parameter '{name}' is missing"),
+            Self::InvalidParam(name) => {
+                write!(f, "the lens parameter '{name}' has an invalid value")
+            }
+            Self::MalformedParams(entry) => {
+                write!(
+                    f,
+                    "malformed lens parameter '{entry}', expected 'name=x[,y[,z[,w]]]'"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for LensRegistryError {}
+
+/// Value of a parameter of [`LensParams`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LensParam {
+    /// A scalar value.
+    Float(f32),
+    /// A 2D vector.
+    Vec2(Vec2),
+    /// A 3D vector, or an RGB color.
+    Vec3(Vec3),
+    /// A 4D vector, an RGBA color, or a quaternion.
+    Vec4(Vec4),
+}
+
+impl From<f32> for LensParam {
+    fn from(value: f32) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<Vec2> for LensParam {
+    fn from(value: Vec2) -> Self {
+        Self::Vec2(value)
+    }
+}
+
+impl From<Vec3> for LensParam {
+    fn from(value: Vec3) -> Self {
+        Self::Vec3(value)
+    }
+}
+
+impl From<Vec4> for LensParam {
+    fn from(value: Vec4) -> Self {
+        Self::Vec4(value)
+    }
+}
+
+impl From<Quat> for LensParam {
+    fn from(value: Quat) -> Self {
+        Self::Vec4(value.into())
+    }
+}
+
+#[cfg(any(
+    feature = "bevy_sprite",
+    feature = "bevy_ui",
+    feature = "bevy_text",
+    feature = "bevy_pbr"
+))]
+impl From<Color> for LensParam {
+    fn from(value: Color) -> Self {
+        Self::Vec4(value.as_rgba_f32().into())
+    }
+}
+
+/// Named parameters of a lens constructed by a [`LensRegistry`].
+///
+/// The parameters are either inserted in code, or parsed from a string with
+/// [`parse()`]. The lens factories read them with the typed getters, which
+/// return a [`LensRegistryError`] if the parameter is missing or has the
+/// wrong type.
+///
+/// [`parse()`]: LensParams::parse
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct LensParams(HashMap<String, LensParam>);
+
+impl LensParams {
+    /// Create an empty set of parameters.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse parameters from a string.
+    ///
+    /// The string is a list of `name=value` entries separated by `;`, where
+    /// the value is a list of one to four numbers separated by `,`, for a
+    /// [`LensParam::Float`] up to a [`LensParam::Vec4`]. Whitespace is
+    /// ignored, as well as empty entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_tweening::*;
+    /// let params = LensParams::parse("start = 0, 0, 0; end = 1, 2, 3").unwrap();
+    /// assert_eq!(params.vec3("end"), Ok(Vec3::new(1., 2., 3.)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LensRegistryError::MalformedParams`] with the first invalid
+    /// entry, if any.
+    pub fn parse(params: &str) -> Result<Self, LensRegistryError> {
+        let mut parsed = Self::new();
+        for entry in params.split(';').map(str::trim).filter(|e| !e.is_empty()) {
+            let malformed = || LensRegistryError::MalformedParams(entry.to_string());
+            let (name, value) = entry.split_once('=').ok_or_else(malformed)?;
+            let name = name.trim();
+            if name.is_empty() {
+                return Err(malformed());
+            }
+            let values = value
+                .split(',')
+                .map(|v| v.trim().parse::<f32>().ok().filter(|v| v.is_finite()))
+                .collect::<Option<Vec<f32>>>()
+                .ok_or_else(malformed)?;
+            let param = match values[..] {
+                [x] => LensParam::Float(x),
+                [x, y] => LensParam::Vec2(Vec2::new(x, y)),
+                [x, y, z] => LensParam::Vec3(Vec3::new(x, y, z)),
+                [x, y, z, w] => LensParam::Vec4(Vec4::new(x, y, z, w)),
+                _ => return Err(malformed()),
+            };
+            parsed.insert(name, param);
+        }
+        Ok(parsed)
+    }
+
+    /// Set a parameter, replacing any previous value of the same name.
+    #[must_use]
+    pub fn with(mut self, name: impl Into<String>, value: impl Into<LensParam>) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    /// Set a parameter, replacing any previous value of the same name.
+    pub fn insert(&mut self, name: impl Into<String>, value: impl Into<LensParam>) {
+        self.0.insert(name.into(), value.into());
+    }
+
+    /// Get a parameter, if it exists.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<LensParam> {
+        self.0.get(name).copied()
+    }
+
+    /// Get a required parameter.
+    fn required(&self, name: &str) -> Result<LensParam, LensRegistryError> {
+        self.get(name)
+            .ok_or_else(|| LensRegistryError::MissingParam(name.to_string()))
+    }
+
+    /// Get a [`LensParam::Float`] parameter.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parameter is missing or of another type.
+    pub fn f32(&self, name: &str) -> Result<f32, LensRegistryError> {
+        match self.required(name)? {
+            LensParam::Float(value) => Ok(value),
+            _ => Err(LensRegistryError::InvalidParam(name.to_string())),
+        }
+    }
+
+    /// Get a [`LensParam::Vec2`] parameter.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parameter is missing or of another type.
+    pub fn vec2(&self, name: &str) -> Result<Vec2, LensRegistryError> {
+        match self.required(name)? {
+            LensParam::Vec2(value) => Ok(value),
+            _ => Err(LensRegistryError::InvalidParam(name.to_string())),
+        }
+    }
+
+    /// Get a [`LensParam::Vec3`] parameter.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parameter is missing or of another type.
+    pub fn vec3(&self, name: &str) -> Result<Vec3, LensRegistryError> {
+        match self.required(name)? {
+            LensParam::Vec3(value) => Ok(value),
+            _ => Err(LensRegistryError::InvalidParam(name.to_string())),
+        }
+    }
+
+    /// Get a [`LensParam::Vec4`] parameter as a quaternion, in `x, y, z, w`
+    /// order.
+    ///
+    /// The quaternion is normalized.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parameter is missing, of another type, or
+    /// can't be normalized.
+    pub fn quat(&self, name: &str) -> Result<Quat, LensRegistryError> {
+        match self.required(name)? {
+            LensParam::Vec4(value) if value.length_squared() > 0. => {
+                Ok(Quat::from_vec4(value).normalize())
+            }
+            _ => Err(LensRegistryError::InvalidParam(name.to_string())),
+        }
+    }
+
+    #[cfg(any(
+        feature = "bevy_sprite",
+        feature = "bevy_ui",
+        feature = "bevy_text",
+        feature = "bevy_pbr"
+    ))]
+    /// Get a [`LensParam::Vec3`] or [`LensParam::Vec4`] parameter as an sRGB
+    /// color, respectively opaque or with an alpha.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parameter is missing or of another type.
+    pub fn color(&self, name: &str) -> Result<Color, LensRegistryError> {
+        match self.required(name)? {
+            LensParam::Vec3(value) => Ok(Color::rgb(value.x, value.y, value.z)),
+            LensParam::Vec4(value) => Ok(Color::rgba(value.x, value.y, value.z, value.w)),
+            _ => Err(LensRegistryError::InvalidParam(name.to_string())),
+        }
+    }
+}
+
+/// Boxed factory of lenses animating a `T`.
+type LensFactory<T> =
+    Box<dyn Fn(&LensParams) -> Result<DynLens<T>, LensRegistryError> + Send + Sync + 'static>;
+
+/// Registry of lens factories, to construct lenses by name at runtime.
+///
+/// Each lens factory is registered under a name and for a target type, and
+/// constructs a lens from a set of [`LensParams`]. This allows a scripting
+/// layer or a data file to build a tween entirely from strings: the name of
+/// the lens and its parameters, and the name of the easing method for
+/// [`EaseMethod::from_name()`].
+///
+/// The [`TweeningPlugin`] inserts this resource with the built-in lenses of
+/// this crate registered, under the name of their type; see
+/// [`with_builtins()`]. The [`Transform`] lenses and the color lenses take a
+/// `start` and an `end` parameter, and additionally an `axis` for the
+/// [`TransformRotateAxisLens`] and an optional `section` index for the
+/// [`TextColorLens`]. The colors are interpolated in the default
+/// [`ColorSpace`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::*;
+/// fn tween_from_script(
+///     registry: &LensRegistry,
+///     lens: &str,
+///     params: &str,
+///     ease: &str,
+///     millis: u64,
+/// ) -> Option<Tween<Transform>> {
+///     let params = LensParams::parse(params).ok()?;
+///     let lens = registry.create::<Transform>(lens, &params).ok()?;
+///     let ease = EaseMethod::from_name(ease)?;
+///     Some(Tween::new_millis(ease, millis, lens))
+/// }
+///
+/// let registry = LensRegistry::with_builtins();
+/// let tween = tween_from_script(
+///     &registry,
+///     "TransformScaleLens",
+///     "start=1,1,1; end=2,2,2",
+///     "backOut",
+///     300,
+/// );
+/// assert!(tween.is_some());
+/// ```
+///
+/// [`EaseMethod::from_name()`]: crate::EaseMethod::from_name
+/// [`TweeningPlugin`]: crate::TweeningPlugin
+/// [`with_builtins()`]: LensRegistry::with_builtins
+#[derive(Default, Resource)]
+pub struct LensRegistry {
+    /// The [`LensFactory<T>`] of each name and target type, as [`Any`].
+    factories: HashMap<(TypeId, String), Box<dyn Any + Send + Sync>>,
+}
+
+impl LensRegistry {
+    /// Create an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a registry with the built-in lenses of this crate registered.
+    ///
+    /// The lenses of the optional Bevy features are only registered if the
+    /// feature is enabled.
+    #[must_use]
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::new();
+        registry
+            .register("TransformPositionLens", |p| {
+                Ok(TransformPositionLens {
+                    start: p.vec3("start")?,
+                    end: p.vec3("end")?,
+                })
+            })
+            .register("TransformRotationLens", |p| {
+                Ok(TransformRotationLens {
+                    start: p.quat("start")?,
+                    end: p.quat("end")?,
+                })
+            })
+            .register("TransformRotateXLens", |p| {
+                Ok(TransformRotateXLens {
+                    start: p.f32("start")?,
+                    end: p.f32("end")?,
+                })
+            })
+            .register("TransformRotateYLens", |p| {
+                Ok(TransformRotateYLens {
+                    start: p.f32("start")?,
+                    end: p.f32("end")?,
+                })
+            })
+            .register("TransformRotateZLens", |p| {
+                Ok(TransformRotateZLens {
+                    start: p.f32("start")?,
+                    end: p.f32("end")?,
+                })
+            })
+            .register("TransformRotateAxisLens", |p| {
+                let axis = p.vec3("axis")?.try_normalize();
+                Ok(TransformRotateAxisLens {
+                    axis: axis.ok_or_else(|| LensRegistryError::InvalidParam("axis".into()))?,
+                    start: p.f32("start")?,
+                    end: p.f32("end")?,
+                })
+            })
+            .register("TransformScaleLens", |p| {
+                Ok(TransformScaleLens {
+                    start: p.vec3("start")?,
+                    end: p.vec3("end")?,
+                })
+            });
+
+        #[cfg(feature = "bevy_sprite")]
+        registry.register("SpriteColorLens", |p| {
+            Ok(SpriteColorLens::new(p.color("start")?, p.color("end")?))
+        });
+
+        #[cfg(all(feature = "bevy_sprite", feature = "bevy_asset"))]
+        registry.register("ColorMaterialColorLens", |p| {
+            Ok(ColorMaterialColorLens::new(
+                p.color("start")?,
+                p.color("end")?,
+            ))
+        });
+
+        #[cfg(feature = "bevy_ui")]
+        registry.register("UiBackgroundColorLens", |p| {
+            Ok(UiBackgroundColorLens::new(
+                p.color("start")?,
+                p.color("end")?,
+            ))
+        });
+
+        #[cfg(feature = "bevy_ui")]
+        registry.register("UiOpacityLens", |p| {
+            Ok(UiOpacityLens {
+                start: p.f32("start")?,
+                end: p.f32("end")?,
+            })
+        });
+
+        #[cfg(feature = "bevy_window")]
+        registry.register("WindowResolutionLens", |p| {
+            Ok(WindowResolutionLens {
+                start: p.vec2("start")?,
+                end: p.vec2("end")?,
+            })
+        });
+
+        #[cfg(feature = "bevy_window")]
+        registry.register("WindowPositionLens", |p| {
+            Ok(WindowPositionLens {
+                start: p.vec2("start")?.round().as_ivec2(),
+                end: p.vec2("end")?.round().as_ivec2(),
+            })
+        });
+
+        #[cfg(feature = "bevy_text")]
+        registry.register("TextColorLens", |p| {
+            let section = match p.get("section") {
+                None => 0,
+                Some(LensParam::Float(section)) if section >= 0. && section.fract() == 0. => {
+                    section as usize
+                }
+                Some(_) => return Err(LensRegistryError::InvalidParam("section".into())),
+            };
+            Ok(TextColorLens::new(
+                p.color("start")?,
+                p.color("end")?,
+                section,
+            ))
+        });
+
+        #[cfg(feature = "bevy_pbr")]
+        registry.register("StandardMaterialBaseColorLens", |p| {
+            Ok(StandardMaterialBaseColorLens::new(
+                p.color("start")?,
+                p.color("end")?,
+            ))
+        });
+
+        registry
+    }
+
+    /// Register a factory of lenses animating a `T` under the given name.
+    ///
+    /// This replaces any factory already registered under the same name for
+    /// the same target type.
+    pub fn register<T, L>(
+        &mut self,
+        name: impl Into<String>,
+        factory: impl Fn(&LensParams) -> Result<L, LensRegistryError> + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        T: 'static,
+        L: Lens<T> + Send + Sync + 'static,
+    {
+        let factory: LensFactory<T> = Box::new(move |params: &LensParams| {
+            let lens: DynLens<T> = Box::new(factory(params)?);
+            Ok(lens)
+        });
+        self.factories
+            .insert((TypeId::of::<T>(), name.into()), Box::new(factory));
+        self
+    }
+
+    /// Check whether a lens factory is registered under the given name for
+    /// the target type `T`.
+    #[must_use]
+    pub fn contains<T: 'static>(&self, name: &str) -> bool {
+        self.factories
+            .contains_key(&(TypeId::of::<T>(), name.to_string()))
+    }
+
+    /// Iterate over the names of the lens factories registered for the target
+    /// type `T`, in no particular order.
+    pub fn names<T: 'static>(&self) -> impl Iterator<Item = &str> {
+        self.factories
+            .keys()
+            .filter(|(type_id, _)| *type_id == TypeId::of::<T>())
+            .map(|(_, name)| name.as_str())
+    }
+
+    /// Construct a lens animating a `T` with the factory registered under the
+    /// given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LensRegistryError::UnknownLens`] if no factory is registered
+    /// under that name for `T`, or the error of the factory if the parameters
+    /// are invalid.
+    pub fn create<T: 'static>(
+        &self,
+        name: &str,
+        params: &LensParams,
+    ) -> Result<DynLens<T>, LensRegistryError> {
+        let factory = self
+            .factories
+            .get(&(TypeId::of::<T>(), name.to_string()))
+            .and_then(|factory| factory.downcast_ref::<LensFactory<T>>())
+            .ok_or_else(|| LensRegistryError::UnknownLens(name.to_string()))?;
+        factory(params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{tweenable::ComponentTarget, EaseMethod, Tween, TweenState, Tweenable};
+
+    #[test]
+    fn tween_from_strings() {
+        let registry = LensRegistry::with_builtins();
+        assert!(registry.contains::<Transform>("TransformPositionLens"));
+        assert!(registry
+            .names::<Transform>()
+            .any(|name| name == "TransformScaleLens"));
+
+        let params = LensParams::parse(" start = 0, 0, 0 ; end = 1, 2, 3; ").unwrap();
+        let lens = registry
+            .create::<Transform>("TransformPositionLens", &params)
+            .unwrap();
+        let ease = EaseMethod::from_name("quadInOut").unwrap();
+        let mut tween = Tween::new(ease, Duration::from_secs(1), lens);
+
+        let mut world = World::new();
+        let entity = world.spawn(Transform::default()).id();
+        let mut tick = |tween: &mut Tween<Transform>, millis| {
+            let target = world.get_mut::<Transform>(entity).unwrap();
+            let state = tween.tick(
+                Duration::from_millis(millis),
+                &mut ComponentTarget::new(target),
+                entity,
+                &mut vec![],
+            );
+            (state, world.get::<Transform>(entity).unwrap().translation)
+        };
+        let (state, translation) = tick(&mut tween, 500);
+        assert_eq!(state, TweenState::Active);
+        assert!(translation.abs_diff_eq(Vec3::new(0.5, 1., 1.5), 1e-5));
+        let (state, translation) = tick(&mut tween, 500);
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(translation, Vec3::new(1., 2., 3.));
+
+        // Sampling is forwarded to the lens constructed
+        let mut transform = Transform::default();
+        assert!(tween.sample(Duration::from_millis(250), &mut transform));
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(0.125, 0.25, 0.375), 1e-5));
+    }
+
+    #[test]
+    fn registry_errors() {
+        let mut registry = LensRegistry::with_builtins();
+        let params = LensParams::parse("start=0; end=1").unwrap();
+
+        // Unknown name, or known name for another target type
+        assert_eq!(
+            registry.create::<Transform>("Wobble", &params).err(),
+            Some(LensRegistryError::UnknownLens("Wobble".into()))
+        );
+        assert_eq!(
+            registry
+                .create::<GlobalTransform>("TransformRotateZLens", &params)
+                .err(),
+            Some(LensRegistryError::UnknownLens(
+                "TransformRotateZLens".into()
+            ))
+        );
+        assert!(registry
+            .create::<Transform>("TransformRotateZLens", &params)
+            .is_ok());
+
+        // Missing and mistyped parameters
+        assert_eq!(
+            registry
+                .create::<Transform>("TransformRotateAxisLens", &params)
+                .err(),
+            Some(LensRegistryError::MissingParam("axis".into()))
+        );
+        assert_eq!(
+            registry
+                .create::<Transform>("TransformScaleLens", &params)
+                .err(),
+            Some(LensRegistryError::InvalidParam("start".into()))
+        );
+        let params = params.with("axis", Vec3::ZERO);
+        assert_eq!(
+            registry
+                .create::<Transform>("TransformRotateAxisLens", &params)
+                .err(),
+            Some(LensRegistryError::InvalidParam("axis".into()))
+        );
+
+        // Malformed strings
+        for malformed in [
+            "start",
+            "start=",
+            "=1",
+            "start=1,2,3,4,5",
+            "start=x",
+            "a=inf",
+        ] {
+            assert_eq!(
+                LensParams::parse(malformed),
+                Err(LensRegistryError::MalformedParams(malformed.into())),
+            );
+        }
+        assert_eq!(
+            LensParams::parse("a=1;b=2,,3"),
+            Err(LensRegistryError::MalformedParams("b=2,,3".into())),
+        );
+
+        // Custom factories, replacing a built-in one
+        registry.register("TransformPositionLens", |p| {
+            let end = p.vec3("end")?;
+            Ok(TransformPositionLens { start: end, end })
+        });
+        let lens = registry.create::<Transform>(
+            "TransformPositionLens",
+            &LensParams::new().with("end", Vec3::X),
+        );
+        let mut transform = Transform::default();
+        assert!(lens.unwrap().sample(&mut transform, 0.));
+        assert_eq!(transform.translation, Vec3::X);
+    }
+}
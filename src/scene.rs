@@ -0,0 +1,388 @@
+//! Animators authored in Bevy scenes.
+//!
+//! This module is only available with the `bevy_scene` feature.
+
+use std::{marker::PhantomData, time::Duration};
+
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
+    prelude::*,
+    reflect::TypePath,
+};
+
+use crate::{
+    Animator, EaseMethod, LensParams, LensRegistry, LensRegistryError, RepeatCount, RepeatStrategy,
+    Tween, TweenBuildError,
+};
+
+/// Error returned by [`TweenDef::build()`] when the descriptor is invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TweenDefError {
+    /// The name of the easing method is not recognized by
+    /// [`EaseMethod::from_name()`].
+    UnknownEase(String),
+    /// The lens couldn't be constructed by the [`LensRegistry`].
+    Lens(LensRegistryError),
+    /// The options of the tween are invalid.
+    Build(TweenBuildError),
+}
+
+impl std::fmt::Display for TweenDefError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownEase(name) => write!(f, "unknown easing method '{name}'"),
+            Self::Lens(err) => write!(f, "invalid lens: {err}"),
+            Self::Build(err) => write!(f, "invalid tween: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for TweenDefError {}
+
+impl From<LensRegistryError> for TweenDefError {
+    fn from(err: LensRegistryError) -> Self {
+        Self::Lens(err)
+    }
+}
+
+impl From<TweenBuildError> for TweenDefError {
+    fn from(err: TweenBuildError) -> Self {
+        Self::Build(err)
+    }
+}
+
+/// Serializable descriptor of a [`Tween`].
+///
+/// A tween holds its lens as a boxed trait object, which can't be serialized.
+/// This descriptor instead stores the lens by name, along with its parameters
+/// in the string format of [`LensParams::parse()`], and the easing method by
+/// name, to be constructed with a [`LensRegistry`] by [`build()`]. It derives
+/// [`Reflect`], so can be stored in a Bevy scene as part of an
+/// [`AnimatorDef`].
+///
+/// [`build()`]: TweenDef::build
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[reflect(Default)]
+#[type_path = "bevy_tweening"]
+pub struct TweenDef {
+    /// Name of the lens in the [`LensRegistry`].
+    pub lens: String,
+    /// Parameters of the lens, parsed with [`LensParams::parse()`].
+    pub params: String,
+    /// Name of the easing method, parsed with [`EaseMethod::from_name()`].
+    /// Defaults to `"linear"`.
+    pub ease: String,
+    /// Duration of a single iteration of the tween.
+    pub duration: Duration,
+    /// Number of times to repeat the tween. Defaults to once.
+    pub repeat_count: RepeatCount,
+    /// Strategy to repeat the tween, if it repeats.
+    pub repeat_strategy: Option<RepeatStrategy>,
+    /// User data of the [`TweenCompleted`] event raised when the tween
+    /// completes, if any.
+    ///
+    /// [`TweenCompleted`]: crate::TweenCompleted
+    pub completed_event: Option<u64>,
+}
+
+impl Default for TweenDef {
+    fn default() -> Self {
+        Self {
+            lens: String::new(),
+            params: String::new(),
+            ease: "linear".to_string(),
+            duration: Duration::ZERO,
+            repeat_count: RepeatCount::default(),
+            repeat_strategy: None,
+            completed_event: None,
+        }
+    }
+}
+
+impl TweenDef {
+    /// Create a descriptor for a tween with the given lens and parameters,
+    /// easing method, and duration.
+    #[must_use]
+    pub fn new(
+        lens: impl Into<String>,
+        params: impl Into<String>,
+        ease: impl Into<String>,
+        duration: Duration,
+    ) -> Self {
+        Self {
+            lens: lens.into(),
+            params: params.into(),
+            ease: ease.into(),
+            duration,
+            ..default()
+        }
+    }
+
+    /// Construct the tween described, with the lenses of the given registry.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TweenDefError`] if the easing method is unknown, the lens
+    /// is unknown or its parameters are invalid, or the options of the tween
+    /// are rejected by the [`TweenBuilder`].
+    ///
+    /// [`TweenBuilder`]: crate::TweenBuilder
+    pub fn build<T: 'static>(&self, registry: &LensRegistry) -> Result<Tween<T>, TweenDefError> {
+        let ease = EaseMethod::from_name(&self.ease)
+            .ok_or_else(|| TweenDefError::UnknownEase(self.ease.clone()))?;
+        let params = LensParams::parse(&self.params)?;
+        let lens = registry.create::<T>(&self.lens, &params)?;
+        let mut builder = Tween::builder()
+            .with_ease(ease)
+            .with_duration(self.duration)
+            .with_lens(lens)
+            .with_repeat_count(self.repeat_count);
+        if let Some(strategy) = self.repeat_strategy {
+            builder = builder.with_repeat_strategy(strategy);
+        }
+        if let Some(user_data) = self.completed_event {
+            builder = builder.with_completed_event(user_data);
+        }
+        Ok(builder.build()?)
+    }
+}
+
+/// Serializable companion of an [`Animator<T>`], to author animators in Bevy
+/// scenes.
+///
+/// An [`Animator`] can't be stored in a scene, because its tweenable is a
+/// boxed trait object. This component stores a [`TweenDef`] instead, and is
+/// replaced by the [`animator_def_system::<T>`] with an [`Animator<T>`]
+/// playing the tween described, as soon as it's added to an entity, for
+/// example when a [`DynamicScene`] is spawned. The tween is constructed with
+/// the [`LensRegistry`] resource, and the definition is removed whether it
+/// succeeds or not; errors are logged.
+///
+/// By default the animator is inserted on the entity of the definition. It
+/// can instead be inserted on another [`target`] entity of the same scene,
+/// for example to define on the root of a scene the animations of its
+/// children; that entity is remapped when the scene is spawned.
+///
+/// The [`TweeningPlugin`] registers this component and its system for the
+/// Bevy component types animated by default. Other types are registered with
+/// [`TweeningAppExt::add_tweenable_scene_component()`].
+///
+/// In a `.scn.ron` scene file, the definition of an entity animating its own
+/// [`Transform`] looks like:
+///
+/// ```ron
+/// "bevy_tweening::AnimatorDef<bevy_transform::components::transform::Transform>": (
+///     tween: (
+///         lens: "TransformScaleLens",
+///         params: "start=1,1,1; end=2,2,2",
+///         ease: "quadInOut",
+///         duration: (secs: 1, nanos: 0),
+///         repeat_count: Infinite,
+///         repeat_strategy: Some(MirroredRepeat),
+///         completed_event: None,
+///     ),
+///     target: None,
+/// ),
+/// ```
+///
+/// [`animator_def_system::<T>`]: animator_def_system
+/// [`DynamicScene`]: bevy::scene::DynamicScene
+/// [`target`]: AnimatorDef::target
+/// [`TweeningPlugin`]: crate::TweeningPlugin
+/// [`TweeningAppExt::add_tweenable_scene_component()`]: crate::TweeningAppExt::add_tweenable_scene_component
+#[derive(Component, Reflect)]
+#[reflect(Component, MapEntities)]
+#[type_path = "bevy_tweening"]
+pub struct AnimatorDef<T: Component> {
+    /// Descriptor of the tween played by the animator.
+    pub tween: TweenDef,
+    /// Entity to insert the animator on, if not the entity of the definition.
+    pub target: Option<Entity>,
+    #[reflect(ignore)]
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Component> Default for AnimatorDef<T> {
+    fn default() -> Self {
+        Self::new(TweenDef::default())
+    }
+}
+
+impl<T: Component> Clone for AnimatorDef<T> {
+    fn clone(&self) -> Self {
+        Self {
+            tween: self.tween.clone(),
+            target: self.target,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Component> std::fmt::Debug for AnimatorDef<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AnimatorDef")
+            .field("tween", &self.tween)
+            .field("target", &self.target)
+            .finish()
+    }
+}
+
+impl<T: Component> AnimatorDef<T> {
+    /// Create a definition of an animator playing the described tween on the
+    /// entity of the definition.
+    #[must_use]
+    pub fn new(tween: TweenDef) -> Self {
+        Self {
+            tween,
+            target: None,
+            marker: PhantomData,
+        }
+    }
+
+    /// Insert the animator on another entity than the one of the definition.
+    #[must_use]
+    pub fn with_target(mut self, target: Entity) -> Self {
+        self.target = Some(target);
+        self
+    }
+}
+
+impl<T: Component> MapEntities for AnimatorDef<T> {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        if let Some(target) = &mut self.target {
+            *target = entity_mapper.map_entity(*target);
+        }
+    }
+}
+
+/// Replace each [`AnimatorDef<T>`] newly added with the [`Animator<T>`] it
+/// describes.
+///
+/// This system is added by the [`TweeningPlugin`] before the animator systems,
+/// for each target type registered for scenes.
+///
+/// [`TweeningPlugin`]: crate::TweeningPlugin
+pub fn animator_def_system<T: Component + TypePath>(
+    mut commands: Commands,
+    registry: Option<Res<LensRegistry>>,
+    query: Query<(Entity, &AnimatorDef<T>), Added<AnimatorDef<T>>>,
+) {
+    for (entity, def) in &query {
+        commands.entity(entity).remove::<AnimatorDef<T>>();
+        let Some(registry) = registry.as_deref() else {
+            error!("Cannot spawn the animator of {entity:?}: no LensRegistry resource.");
+            continue;
+        };
+        let tween = match def.tween.build::<T>(registry) {
+            Ok(tween) => tween,
+            Err(err) => {
+                error!("Cannot spawn the animator of {entity:?}: {err}");
+                continue;
+            }
+        };
+        let target = def.target.unwrap_or(entity);
+        match commands.get_entity(target) {
+            Some(mut target) => {
+                target.insert(Animator::new(tween));
+            }
+            None => warn!("Cannot spawn the animator of {entity:?}: target {target:?} not found."),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{ecs::entity::EntityHashMap, scene::DynamicSceneBuilder};
+
+    use super::*;
+    use crate::{Tweenable, TweeningAppExt, TweeningPlugin};
+
+    fn make_def() -> TweenDef {
+        TweenDef::new(
+            "TransformPositionLens",
+            "start=0,0,0; end=1,2,3",
+            "linear",
+            Duration::from_secs(1),
+        )
+    }
+
+    #[test]
+    fn tween_def_build() {
+        let registry = LensRegistry::with_builtins();
+        let mut def = make_def();
+        let tween = def.build::<Transform>(&registry).unwrap();
+        assert_eq!(tween.duration(), Duration::from_secs(1));
+
+        def.ease = "wobbly".into();
+        assert_eq!(
+            def.build::<Transform>(&registry).err(),
+            Some(TweenDefError::UnknownEase("wobbly".into()))
+        );
+        def.ease = "quadInOut".into();
+        def.params = "start=0".into();
+        assert_eq!(
+            def.build::<Transform>(&registry).err(),
+            Some(TweenDefError::Lens(LensRegistryError::InvalidParam(
+                "start".into()
+            )))
+        );
+        def.params = make_def().params;
+        def.repeat_strategy = Some(RepeatStrategy::MirroredRepeat);
+        assert_eq!(
+            def.build::<Transform>(&registry).err(),
+            Some(TweenDefError::Build(
+                TweenBuildError::RepeatStrategyWithoutRepeat
+            ))
+        );
+        def.repeat_count = RepeatCount::Infinite;
+        assert!(def.build::<Transform>(&registry).is_ok());
+    }
+
+    #[test]
+    fn spawn_scene_with_animator_def() {
+        let mut app = App::new();
+        app.add_plugins(TweeningPlugin::default().without_defaults())
+            .add_tweenable_scene_component::<Transform>()
+            .init_resource::<Time>()
+            .register_type::<Transform>();
+
+        // Author the scene in a separate world, with the animator defined on
+        // another entity than the animated one
+        let mut source = World::new();
+        source.insert_resource(app.world.resource::<AppTypeRegistry>().clone());
+        let animated = source.spawn(Transform::default()).id();
+        let holder = source
+            .spawn(AnimatorDef::<Transform>::new(make_def()).with_target(animated))
+            .id();
+        let scene = DynamicSceneBuilder::from_world(&source)
+            .extract_entities([animated, holder].into_iter())
+            .build();
+
+        // Offset the entities of the app, so the scene entities are remapped
+        app.world.spawn_batch([(), (), ()]);
+        let mut entity_map = EntityHashMap::default();
+        scene
+            .write_to_world(&mut app.world, &mut entity_map)
+            .unwrap();
+        let (source_animated, animated) = (animated, entity_map[&animated]);
+        let holder = entity_map[&holder];
+        assert_ne!(animated, source_animated);
+
+        app.world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(500));
+        app.update();
+
+        assert!(app.world.get::<AnimatorDef<Transform>>(holder).is_none());
+        assert!(app.world.get::<Animator<Transform>>(holder).is_none());
+        let animator = app.world.get::<Animator<Transform>>(animated).unwrap();
+        assert_eq!(animator.tweenable().elapsed(), Duration::from_millis(500));
+        let transform = app.world.get::<Transform>(animated).unwrap();
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(0.5, 1., 1.5), 1e-5));
+    }
+}
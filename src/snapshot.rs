@@ -0,0 +1,112 @@
+//! Snapshots of the playback state of tweenables, for persistence.
+
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::TweeningDirection;
+
+/// Snapshot of the playback state of a tweenable and its children.
+///
+/// This is returned by [`Tweenable::save_state()`], and captures only what
+/// changes while the tweenable plays: elapsed times, directions, the active
+/// child of a [`Sequence`], and the like. The structure of the tweenable
+/// itself, like its lenses, easing functions, and callbacks, is not part of
+/// the snapshot. To persist an animation in flight, for example in a save
+/// game, store the snapshot next to whatever description the tweenable was
+/// built from, then on load build the tweenable again from that description
+/// and pass the snapshot to [`Tweenable::restore_state()`].
+///
+/// The number of times a tweenable completed is derived from its elapsed
+/// time, so is restored too. With the `serde` feature, snapshots can be
+/// serialized with any [`serde`] format.
+///
+/// [`Tweenable::save_state()`]: crate::Tweenable::save_state
+/// [`Tweenable::restore_state()`]: crate::Tweenable::restore_state
+/// [`Sequence`]: crate::Sequence
+/// [`serde`]: https://docs.rs/serde
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TweenableState {
+    /// The tweenable doesn't support snapshots.
+    Unsupported,
+    /// State of a [`Tween`].
+    ///
+    /// [`Tween`]: crate::Tween
+    Tween {
+        /// Elapsed time, including the completed iterations.
+        elapsed: Duration,
+        /// Duration of a single iteration, which differs from the one the
+        /// tween was built with for tweens built from a speed.
+        duration: Duration,
+        /// Current playback direction.
+        direction: TweeningDirection,
+        /// Time played since the tween started, for the elapsed triggers.
+        total_elapsed: Duration,
+        /// Time accumulated since the lens was last applied, or `None` if the
+        /// tween was never ticked.
+        pending_step: Option<Duration>,
+        /// Whether each elapsed trigger already fired.
+        fired_triggers: Vec<bool>,
+    },
+    /// State of a [`Sequence`].
+    ///
+    /// [`Sequence`]: crate::Sequence
+    Sequence {
+        /// Elapsed time of the whole sequence.
+        elapsed: Duration,
+        /// Index of the active child.
+        index: usize,
+        /// State of each child.
+        children: Vec<TweenableState>,
+    },
+    /// State of a [`Tracks`].
+    ///
+    /// [`Tracks`]: crate::Tracks
+    Tracks {
+        /// Elapsed time of the tracks.
+        elapsed: Duration,
+        /// State of each track.
+        children: Vec<TweenableState>,
+    },
+    /// State of a [`Delay`].
+    ///
+    /// [`Delay`]: crate::Delay
+    Delay {
+        /// Elapsed time of the delay.
+        elapsed: Duration,
+        /// Duration of the delay, which may have been changed while it was
+        /// pending.
+        duration: Duration,
+    },
+    /// State of a [`WaitFor`].
+    ///
+    /// [`WaitFor`]: crate::WaitFor
+    WaitFor {
+        /// Elapsed time of the wait.
+        elapsed: Duration,
+        /// Whether the wait completed, by timeout or by being released.
+        completed: bool,
+    },
+    /// State of an [`Eased`].
+    ///
+    /// [`Eased`]: crate::Eased
+    Eased {
+        /// Elapsed time before warping.
+        elapsed: Duration,
+        /// State of the warped child.
+        child: Box<TweenableState>,
+    },
+    /// State of a [`Slice`].
+    ///
+    /// [`Slice`]: crate::Slice
+    Slice {
+        /// Elapsed time within the slice.
+        elapsed: Duration,
+        /// Whether the slice completed.
+        completed: bool,
+        /// State of the sliced child.
+        child: Box<TweenableState>,
+    },
+}
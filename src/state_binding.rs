@@ -0,0 +1,170 @@
+//! Animators bound to a value of a Bevy [`States`] type.
+
+use std::any::Any;
+
+use bevy::{
+    ecs::{event::ManualEventReader, schedule::StateTransitionEvent},
+    prelude::*,
+};
+
+use crate::{Animator, ResourceAnimator};
+#[cfg(feature = "bevy_asset")]
+use crate::{AssetAnimator, SyncedAnimator};
+
+/// Policy applied to an animator bound to a state when that state is exited.
+///
+/// See [`Animator::bound_to_state()`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OnStateExit {
+    /// Pause the animator, and resume it once the state is entered again.
+    ///
+    /// An animator already paused when the state is exited is left paused
+    /// when it's entered again.
+    #[default]
+    Pause,
+    /// Stop the animator with [`Animator::stop()`], raising a
+    /// [`TweenCancelled`] event for each of its pending [`TweenCompleted`]
+    /// events. The animator is not resumed when the state is entered again.
+    ///
+    /// [`TweenCancelled`]: crate::TweenCancelled
+    /// [`TweenCompleted`]: crate::TweenCompleted
+    Cancel,
+    /// Skip the animator to its end with [`Animator::skip_to_end()`],
+    /// notifying the skipped completions, and applying its end state on its
+    /// next tick. An animator looping forever has no end, and is stopped like
+    /// with [`OnStateExit::Cancel`] instead.
+    Complete,
+}
+
+/// Binding of an animator to a value of a [`States`] type, stored type-erased
+/// in the animator.
+pub(crate) struct StateBinding {
+    state: Box<dyn Any + Send + Sync>,
+    is_state: fn(&dyn Any, &dyn Any) -> bool,
+    on_exit: OnStateExit,
+    /// Was the animator paused by exiting the state?
+    paused: bool,
+}
+
+impl StateBinding {
+    pub(crate) fn new<S: States>(state: S, on_exit: OnStateExit) -> Self {
+        Self {
+            state: Box::new(state),
+            is_state: is_state::<S>,
+            on_exit,
+            paused: false,
+        }
+    }
+
+    /// Is `state` the state of the binding? A state of another type never is.
+    pub(crate) fn is_state(&self, state: &dyn Any) -> bool {
+        (self.is_state)(self.state.as_ref(), state)
+    }
+
+    pub(crate) fn on_exit(&self) -> OnStateExit {
+        self.on_exit
+    }
+
+    /// Record whether the animator was paused by exiting the state, and
+    /// return the previous value.
+    pub(crate) fn replace_paused(&mut self, paused: bool) -> bool {
+        std::mem::replace(&mut self.paused, paused)
+    }
+}
+
+fn is_state<S: States>(bound: &dyn Any, state: &dyn Any) -> bool {
+    match (bound.downcast_ref::<S>(), state.downcast_ref::<S>()) {
+        (Some(bound), Some(state)) => bound == state,
+        _ => false,
+    }
+}
+
+/// An animator which can be bound to a state.
+pub(crate) trait StateBoundAnimator: Component {
+    /// Is the animator bound to a state?
+    fn is_state_bound(&self) -> bool;
+
+    /// Apply the transition of a state from `exited` to `entered` to the
+    /// animator, according to its binding if any.
+    fn apply_state_transition(&mut self, exited: &dyn Any, entered: &dyn Any);
+}
+
+impl<T: Component> StateBoundAnimator for Animator<T> {
+    fn is_state_bound(&self) -> bool {
+        self.state_binding.is_some()
+    }
+
+    fn apply_state_transition(&mut self, exited: &dyn Any, entered: &dyn Any) {
+        Animator::apply_state_transition(self, exited, entered);
+    }
+}
+
+#[cfg(feature = "bevy_asset")]
+impl<T: Asset> StateBoundAnimator for AssetAnimator<T> {
+    fn is_state_bound(&self) -> bool {
+        self.state_binding.is_some()
+    }
+
+    fn apply_state_transition(&mut self, exited: &dyn Any, entered: &dyn Any) {
+        AssetAnimator::apply_state_transition(self, exited, entered);
+    }
+}
+
+#[cfg(feature = "bevy_asset")]
+impl<C: Component, A: Asset> StateBoundAnimator for SyncedAnimator<C, A> {
+    fn is_state_bound(&self) -> bool {
+        self.state_binding.is_some()
+    }
+
+    fn apply_state_transition(&mut self, exited: &dyn Any, entered: &dyn Any) {
+        SyncedAnimator::apply_state_transition(self, exited, entered);
+    }
+}
+
+impl<T: Resource> StateBoundAnimator for ResourceAnimator<T> {
+    fn is_state_bound(&self) -> bool {
+        self.state_binding.is_some()
+    }
+
+    fn apply_state_transition(&mut self, exited: &dyn Any, entered: &dyn Any) {
+        ResourceAnimator::apply_state_transition(self, exited, entered);
+    }
+}
+
+/// Apply the transition of a state to all the animators of type `A`.
+pub(crate) fn apply_state_transition<A: StateBoundAnimator>(
+    world: &mut World,
+    exited: &dyn Any,
+    entered: &dyn Any,
+) {
+    let mut query = world.query::<&mut A>();
+    for mut animator in query.iter_mut(world) {
+        // Only flag the bound animators as changed
+        if animator.is_state_bound() {
+            animator.apply_state_transition(exited, entered);
+        }
+    }
+}
+
+/// Apply the transitions of the state `S` to the animators bound to it, for
+/// all the target types registered with the [`TweeningPlugin`].
+///
+/// [`TweeningPlugin`]: crate::TweeningPlugin
+pub(crate) fn state_binding_system<S: States>(
+    world: &mut World,
+    mut reader: Local<ManualEventReader<StateTransitionEvent<S>>>,
+) {
+    let Some(events) = world.get_resource::<Events<StateTransitionEvent<S>>>() else {
+        return;
+    };
+    let transitions: Vec<_> = reader.read(events).cloned().collect();
+    if transitions.is_empty() {
+        return;
+    }
+    let targets = crate::plugin::registered_state_transitions(world);
+    for transition in &transitions {
+        for apply in &targets {
+            apply(world, &transition.before, &transition.after);
+        }
+    }
+}
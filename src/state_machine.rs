@@ -0,0 +1,531 @@
+//! Per-entity animation state machines built on tweenables.
+
+use std::{borrow::Cow, time::Duration};
+
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::{Animator, BoxedTweenable, TotalDuration, TweenPrototype, Tweenable};
+
+/// Event raised when a [`TweenStateMachine`] exits a state and enters another
+/// one.
+///
+/// The event is raised on the frame the transition starts, before the
+/// animator ticks the animation of the new state.
+#[derive(Debug, Clone, PartialEq, Eq, Event)]
+pub struct TweenStateTransition {
+    /// The [`Entity`] the state machine is attached to.
+    pub entity: Entity,
+    /// The state exited, or `None` when entering the initial state.
+    pub exited: Option<Cow<'static, str>>,
+    /// The state entered.
+    pub entered: Cow<'static, str>,
+}
+
+/// A state of a [`TweenStateMachine`].
+struct MachineState<T> {
+    /// Construct the tweenable played when entering the state.
+    factory: Box<dyn Fn() -> BoxedTweenable<T> + Send + Sync + 'static>,
+    /// State to transition to once the tweenable of this one completes.
+    return_to: Option<Cow<'static, str>>,
+}
+
+/// Component cycling an entity through a set of animation states, like the
+/// idle, hovered, and pressed states of a button.
+///
+/// Each state is registered by key with a [`TweenPrototype`], instantiated
+/// each time the state is entered. The state machine owns the tweenable of
+/// the [`Animator<T>`] on the same entity, and inserts that animator if it's
+/// missing. Request a new state with [`transition_to()`]; the
+/// [`TweeningPlugin`] applies the request before the animators tick, and
+/// raises a [`TweenStateTransition`] event.
+///
+/// A state entered while the animation of the current state is still in
+/// flight doesn't make the target jump:
+/// - Going back to the state the current one was entered from reverses the
+///   current animation with [`Animator::reverse()`], so it retraces its
+///   values back to where it started, then plays the animation of that state
+///   once the reverse completes. This requires the tweenable of the current
+///   state to support directions, like a [`Tween`].
+/// - Otherwise, the animation of the new state blends its start from the
+///   current value of the animation it replaces over the crossfade window set
+///   with [`with_crossfade()`], using [`Tweenable::current_value()`] and
+///   [`Tweenable::blend_start()`]. Without a window, or if the lenses don't
+///   support blending, the new animation starts with a hard cut.
+///
+/// A state registered with [`with_return()`] automatically transitions to
+/// another state once its animation completes, for example to go back to the
+/// hovered state after the press animation of a button.
+///
+/// Replacing the tweenable of the animator raises a [`TweenCancelled`] event
+/// for each pending completion event of the interrupted animation. A reversed
+/// animation raises its completion events again when reaching its start.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// let scale_to = |scale: f32| {
+///     TweenPrototype::new(move || {
+///         Tween::new_millis(
+///             EaseFunction::QuadraticOut,
+///             150,
+///             TransformScaleLens {
+///                 start: Vec3::ONE,
+///                 end: Vec3::splat(scale),
+///             },
+///         )
+///     })
+/// };
+/// let machine = TweenStateMachine::<Transform>::new("idle")
+///     .with_state("idle", scale_to(1.))
+///     .with_state("hovered", scale_to(1.1))
+///     .with_state("pressed", scale_to(0.9))
+///     .with_return("pressed", "hovered")
+///     .with_crossfade(Duration::from_millis(100));
+/// ```
+///
+/// [`transition_to()`]: Self::transition_to
+/// [`with_crossfade()`]: Self::with_crossfade
+/// [`with_return()`]: Self::with_return
+/// [`TweeningPlugin`]: crate::TweeningPlugin
+/// [`Tween`]: crate::Tween
+/// [`TweenCancelled`]: crate::TweenCancelled
+#[derive(Component)]
+pub struct TweenStateMachine<T: Component> {
+    states: HashMap<Cow<'static, str>, MachineState<T>>,
+    /// Current state, once the initial one was entered.
+    current: Option<Cow<'static, str>>,
+    /// State the current one was entered from.
+    previous: Option<Cow<'static, str>>,
+    /// State requested with `transition_to()`, not entered yet.
+    requested: Option<Cow<'static, str>>,
+    /// Whether the animator plays backward the animation of the previous
+    /// state, before playing the one of the current state.
+    reversing: bool,
+    crossfade: Duration,
+}
+
+impl<T: Component> std::fmt::Debug for TweenStateMachine<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TweenStateMachine")
+            .field("current", &self.current)
+            .field("requested", &self.requested)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Component> TweenStateMachine<T> {
+    /// Create a state machine entering the `initial` state on the first
+    /// update.
+    ///
+    /// The initial state must be registered with [`with_state()`].
+    ///
+    /// [`with_state()`]: Self::with_state
+    #[must_use]
+    pub fn new(initial: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            states: HashMap::default(),
+            current: None,
+            previous: None,
+            requested: Some(initial.into()),
+            reversing: false,
+            crossfade: Duration::ZERO,
+        }
+    }
+
+    /// Register a state, playing an instance of `prototype` when entered.
+    ///
+    /// Registering a state again replaces its prototype, and keeps its
+    /// return transition.
+    #[must_use]
+    pub fn with_state<W>(
+        mut self,
+        key: impl Into<Cow<'static, str>>,
+        prototype: TweenPrototype<W>,
+    ) -> Self
+    where
+        W: Tweenable<T> + 'static,
+    {
+        let key = key.into();
+        let return_to = self.states.remove(&key).and_then(|state| state.return_to);
+        let state = MachineState {
+            factory: Box::new(move || Box::new(prototype.instantiate())),
+            return_to,
+        };
+        self.states.insert(key, state);
+        self
+    }
+
+    /// Automatically transition from the state `key` to the state `to` once
+    /// the animation of `key` completes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the state `key` was not registered with [`with_state()`].
+    ///
+    /// [`with_state()`]: Self::with_state
+    #[must_use]
+    pub fn with_return(
+        mut self,
+        key: impl Into<Cow<'static, str>>,
+        to: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        let key = key.into();
+        let Some(state) = self.states.get_mut(&key) else {
+            panic!("Unknown state '{key}', register it with with_state() first.");
+        };
+        state.return_to = Some(to.into());
+        self
+    }
+
+    /// Set the window over which the animation of a state entered while
+    /// another animation is in flight blends from the current value.
+    ///
+    /// Defaults to zero, which starts the new animation with a hard cut.
+    #[must_use]
+    pub fn with_crossfade(mut self, window: Duration) -> Self {
+        self.crossfade = window;
+        self
+    }
+
+    /// Request a transition to the state `key`, applied on the next update
+    /// of the animators.
+    ///
+    /// A later request made before that update replaces this one, and
+    /// requesting the current state cancels any pending request. A request
+    /// for a state which was not registered is ignored with a warning when
+    /// applied.
+    pub fn transition_to(&mut self, key: impl Into<Cow<'static, str>>) {
+        let key = key.into();
+        if self.current.as_ref() == Some(&key) {
+            self.requested = None;
+        } else {
+            self.requested = Some(key);
+        }
+    }
+
+    /// Get the current state, or `None` before the initial state is entered.
+    #[must_use]
+    pub fn current(&self) -> Option<&str> {
+        self.current.as_deref()
+    }
+
+    /// Get the state requested with [`transition_to()`] and not entered yet,
+    /// if any.
+    ///
+    /// [`transition_to()`]: Self::transition_to
+    #[must_use]
+    pub fn requested(&self) -> Option<&str> {
+        self.requested.as_deref()
+    }
+
+    /// Is the state machine reversing the animation of the previous state,
+    /// before playing the one of the current state?
+    #[must_use]
+    pub fn is_reversing(&self) -> bool {
+        self.reversing
+    }
+
+    /// Enter the requested state with a new animator, for an entity which
+    /// doesn't have one.
+    fn spawn_animator(&mut self, entity: Entity) -> Option<(Animator<T>, TweenStateTransition)> {
+        let key = self.requested.take()?;
+        let Some(state) = self.states.get(&key) else {
+            warn!("Ignoring transition to unknown state '{key}' of entity {entity:?}.");
+            return None;
+        };
+        let animator = Animator::from_boxed((state.factory)());
+        let exited = self.current.replace(key.clone());
+        self.previous = exited.clone();
+        self.reversing = false;
+        let event = TweenStateTransition {
+            entity,
+            exited,
+            entered: key,
+        };
+        Some((animator, event))
+    }
+
+    /// Instantiate the animation of the current state.
+    fn instantiate_current(&self) -> Option<BoxedTweenable<T>> {
+        let state = self.states.get(self.current.as_ref()?)?;
+        Some((state.factory)())
+    }
+
+    /// Update the state machine, before its animator ticks.
+    ///
+    /// Returns the transition to raise an event for, if any.
+    fn update(
+        &mut self,
+        entity: Entity,
+        animator: &mut Animator<T>,
+    ) -> Option<TweenStateTransition> {
+        let completed = is_completed(animator.tweenable());
+
+        // Once the reverse completes, play the animation of the state itself
+        if self.reversing && completed {
+            self.reversing = false;
+            if let Some(tweenable) = self.instantiate_current() {
+                self.play(animator, tweenable);
+            }
+            return None;
+        }
+
+        if self.requested.is_none() && completed && !self.reversing {
+            self.requested = self
+                .current
+                .as_ref()
+                .and_then(|current| self.states.get(current))
+                .and_then(|state| state.return_to.clone());
+        }
+        let key = self.requested.take()?;
+        if self.current.as_ref() == Some(&key) {
+            return None;
+        }
+        let Some(state) = self.states.get(&key) else {
+            warn!("Ignoring transition to unknown state '{key}' of entity {entity:?}.");
+            return None;
+        };
+
+        let back = self.previous.as_ref() == Some(&key);
+        if back && !completed && animator.direction().is_some() {
+            // Retrace the animation in flight, or resume it if it was itself
+            // reversing toward the state being left
+            animator.reverse();
+            self.reversing = !self.reversing;
+        } else {
+            let tweenable = (state.factory)();
+            self.reversing = false;
+            self.play(animator, tweenable);
+        }
+
+        let exited = self.current.replace(key.clone());
+        self.previous = exited.clone();
+        Some(TweenStateTransition {
+            entity,
+            exited,
+            entered: key,
+        })
+    }
+
+    /// Replace the tweenable of the animator, blending from the current value
+    /// of the animation it replaces.
+    fn play(&self, animator: &mut Animator<T>, mut tweenable: BoxedTweenable<T>) {
+        if !self.crossfade.is_zero() {
+            if let Some(from) = animator.tweenable().current_value() {
+                tweenable.blend_start(from, self.crossfade);
+            }
+        }
+        animator.set_boxed_tweenable(tweenable);
+    }
+}
+
+/// Did the tweenable play until its end?
+fn is_completed<T>(tweenable: &dyn Tweenable<T>) -> bool {
+    matches!(
+        tweenable.total_duration(),
+        TotalDuration::Finite(total_duration) if tweenable.elapsed() >= total_duration
+    )
+}
+
+/// Query data of the [`state_machine_system()`].
+type MachineItem<T> = (
+    Entity,
+    &'static mut TweenStateMachine<T>,
+    Option<&'static mut Animator<T>>,
+);
+
+/// Apply the transitions of the [`TweenStateMachine<T>`] components to their
+/// [`Animator<T>`], inserting the animator if missing.
+pub(crate) fn state_machine_system<T: Component>(
+    mut commands: Commands,
+    mut machines: Query<MachineItem<T>>,
+    mut events: EventWriter<TweenStateTransition>,
+) {
+    for (entity, mut machine, animator) in &mut machines {
+        let Some(mut animator) = animator else {
+            if let Some((animator, event)) = machine.spawn_animator(entity) {
+                commands.entity(entity).insert(animator);
+                events.send(event);
+            }
+            continue;
+        };
+        if machine.requested.is_none() && !machine.reversing && !is_completed(animator.tweenable())
+        {
+            continue;
+        }
+        if let Some(event) = machine.update(entity, &mut animator) {
+            events.send(event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lens::TransformPositionLens, EaseMethod, Tween, TweeningPlugin};
+
+    fn move_x(start: f32, end: f32, millis: u64) -> TweenPrototype<Tween<Transform>> {
+        TweenPrototype::new(move || {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_millis(millis),
+                TransformPositionLens {
+                    start: Vec3::X * start,
+                    end: Vec3::X * end,
+                },
+            )
+        })
+    }
+
+    fn make_machine() -> TweenStateMachine<Transform> {
+        TweenStateMachine::new("idle")
+            .with_state("idle", move_x(0., 0., 1000))
+            .with_state("hovered", move_x(0., 1., 1000))
+            .with_state("pressed", move_x(1., 2., 500))
+            .with_return("pressed", "hovered")
+    }
+
+    fn make_app(machine: TweenStateMachine<Transform>) -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register::<Transform>(),
+        )
+        .init_resource::<Time>();
+        let entity = app.world.spawn((Transform::default(), machine)).id();
+        (app, entity)
+    }
+
+    fn tick(app: &mut App, millis: u64) {
+        app.world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(millis));
+        app.update();
+    }
+
+    fn x(app: &App, entity: Entity) -> f32 {
+        app.world.get::<Transform>(entity).unwrap().translation.x
+    }
+
+    fn transition_to(app: &mut App, entity: Entity, key: &'static str) {
+        app.world
+            .get_mut::<TweenStateMachine<Transform>>(entity)
+            .unwrap()
+            .transition_to(key);
+    }
+
+    fn machine(app: &App, entity: Entity) -> &TweenStateMachine<Transform> {
+        app.world
+            .get::<TweenStateMachine<Transform>>(entity)
+            .unwrap()
+    }
+
+    fn transitions(app: &App) -> Vec<(Option<String>, String)> {
+        let events = app.world.resource::<Events<TweenStateTransition>>();
+        events
+            .get_reader()
+            .read(events)
+            .map(|ev| {
+                let exited = ev.exited.as_ref().map(ToString::to_string);
+                (exited, ev.entered.to_string())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn state_machine_enter_and_return() {
+        let (mut app, entity) = make_app(make_machine());
+        tick(&mut app, 0);
+        assert!(app.world.get::<Animator<Transform>>(entity).is_some());
+        assert_eq!(machine(&app, entity).current(), Some("idle"));
+        assert_eq!(transitions(&app), [(None, "idle".to_string())]);
+
+        tick(&mut app, 1000);
+        transition_to(&mut app, entity, "pressed");
+        assert_eq!(machine(&app, entity).requested(), Some("pressed"));
+        tick(&mut app, 250);
+        assert_eq!(machine(&app, entity).current(), Some("pressed"));
+        assert!((x(&app, entity) - 1.5).abs() < 1e-5);
+
+        // Once pressed completes, the machine returns to hovered
+        tick(&mut app, 250);
+        assert!((x(&app, entity) - 2.).abs() < 1e-5);
+        tick(&mut app, 0);
+        assert_eq!(machine(&app, entity).current(), Some("hovered"));
+        assert_eq!(
+            transitions(&app),
+            [(Some("pressed".to_string()), "hovered".to_string())]
+        );
+
+        // Requesting the current state does nothing
+        transition_to(&mut app, entity, "hovered");
+        assert_eq!(machine(&app, entity).requested(), None);
+    }
+
+    #[test]
+    fn state_machine_reverse_mid_way() {
+        let (mut app, entity) = make_app(make_machine());
+        tick(&mut app, 0);
+        transition_to(&mut app, entity, "hovered");
+        tick(&mut app, 400);
+        assert!((x(&app, entity) - 0.4).abs() < 1e-5);
+
+        // Going back to idle retraces the hover animation from where it is
+        transition_to(&mut app, entity, "idle");
+        tick(&mut app, 0);
+        assert_eq!(machine(&app, entity).current(), Some("idle"));
+        assert!(machine(&app, entity).is_reversing());
+        assert!((x(&app, entity) - 0.4).abs() < 1e-5);
+        tick(&mut app, 100);
+        assert!((x(&app, entity) - 0.3).abs() < 1e-5);
+
+        // Interrupting the reverse resumes the hover animation
+        transition_to(&mut app, entity, "hovered");
+        tick(&mut app, 100);
+        assert!(!machine(&app, entity).is_reversing());
+        assert!((x(&app, entity) - 0.4).abs() < 1e-5);
+        transition_to(&mut app, entity, "idle");
+        tick(&mut app, 400);
+        assert!(x(&app, entity).abs() < 1e-5);
+
+        // Once back to the start, the idle animation plays
+        tick(&mut app, 0);
+        assert!(!machine(&app, entity).is_reversing());
+        let animator = app.world.get::<Animator<Transform>>(entity).unwrap();
+        assert_eq!(animator.tweenable().duration(), Duration::from_secs(1));
+        assert!(!animator.is_reversed());
+    }
+
+    #[test]
+    fn state_machine_crossfade_mid_way() {
+        let (mut app, entity) = make_app(make_machine().with_crossfade(Duration::from_millis(200)));
+        tick(&mut app, 0);
+        transition_to(&mut app, entity, "hovered");
+        tick(&mut app, 500);
+        assert!((x(&app, entity) - 0.5).abs() < 1e-5);
+
+        // Pressing mid-way blends from the current value instead of jumping
+        transition_to(&mut app, entity, "pressed");
+        tick(&mut app, 0);
+        assert_eq!(machine(&app, entity).current(), Some("pressed"));
+        assert!((x(&app, entity) - 0.5).abs() < 1e-5);
+        tick(&mut app, 100);
+        // Start blended halfway from 0.5 to 1, then 20% of the way to 2
+        assert!((x(&app, entity) - 1.).abs() < 1e-5);
+        tick(&mut app, 400);
+        assert!((x(&app, entity) - 2.).abs() < 1e-5);
+
+        // Without a crossfade, the new state starts with a hard cut
+        let (mut app, entity) = make_app(make_machine());
+        tick(&mut app, 0);
+        transition_to(&mut app, entity, "hovered");
+        tick(&mut app, 500);
+        transition_to(&mut app, entity, "pressed");
+        tick(&mut app, 0);
+        assert!((x(&app, entity) - 1.).abs() < 1e-5);
+    }
+}
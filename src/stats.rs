@@ -0,0 +1,231 @@
+//! Aggregate completion statistics of the tweens, per user data.
+
+use std::time::Duration;
+
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::{TweenCancelled, TweenCompleted, CHAIN_USER_DATA_BIT};
+
+/// Statistics of the tweens raising events with a given user data.
+///
+/// This is an entry of the [`TweenStats`] resource.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct StatsEntry {
+    /// Number of [`TweenCompleted`] events raised, excluding the events of
+    /// elapsed triggers.
+    pub completed: u64,
+    /// Number of [`TweenCancelled`] events raised.
+    pub cancelled: u64,
+    /// Total animation time played, as reported by the [`played`] field of
+    /// the completed and cancelled events.
+    ///
+    /// [`played`]: TweenCompleted::played
+    pub total_play_time: Duration,
+}
+
+/// Resource aggregating the [`TweenCompleted`] and [`TweenCancelled`] events
+/// per user data, for analytics.
+///
+/// This resource is only inserted and updated when the [`TweeningPlugin`] is
+/// built with [`with_stats()`], so that it costs nothing otherwise. It's
+/// updated in the [`Last`] schedule from the events themselves, so its counts
+/// always match what any reader of those events sees in the same frame,
+/// whichever animator, command, or chain raised them.
+///
+/// The internal user data of the animation chains, which have the
+/// [`CHAIN_USER_DATA_BIT`] set, are not recorded, and neither are the
+/// [`TweenCompleted`] events of elapsed triggers, which don't mark a
+/// completion.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::*;
+/// fn report(mut stats: ResMut<TweenStats>) {
+///     for (user_data, entry) in stats.iter() {
+///         info!(
+///             "{user_data}: {} completed, {} cancelled",
+///             entry.completed, entry.cancelled
+///         );
+///     }
+///     stats.reset();
+/// }
+/// ```
+///
+/// [`TweeningPlugin`]: crate::TweeningPlugin
+/// [`with_stats()`]: crate::TweeningPlugin::with_stats
+#[derive(Debug, Default, Resource)]
+pub struct TweenStats {
+    entries: HashMap<u64, StatsEntry>,
+}
+
+impl TweenStats {
+    /// Get the statistics of a user data, if any event was raised with it
+    /// since the last reset.
+    #[must_use]
+    pub fn get(&self, user_data: u64) -> Option<&StatsEntry> {
+        self.entries.get(&user_data)
+    }
+
+    /// Iterate over the statistics of all user data, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (u64, &StatsEntry)> {
+        self.entries
+            .iter()
+            .map(|(user_data, entry)| (*user_data, entry))
+    }
+
+    /// Clear all the statistics.
+    pub fn reset(&mut self) {
+        self.entries.clear();
+    }
+
+    fn entry(&mut self, user_data: u64) -> Option<&mut StatsEntry> {
+        if user_data & CHAIN_USER_DATA_BIT != 0 {
+            return None;
+        }
+        Some(self.entries.entry(user_data).or_default())
+    }
+}
+
+/// Record the [`TweenCompleted`] and [`TweenCancelled`] events into the
+/// [`TweenStats`] resource.
+pub(crate) fn tween_stats_system(
+    mut stats: ResMut<TweenStats>,
+    mut completed: EventReader<TweenCompleted>,
+    mut cancelled: EventReader<TweenCancelled>,
+) {
+    for event in completed.read().filter(|event| !event.is_elapsed_trigger) {
+        if let Some(entry) = stats.entry(event.user_data) {
+            entry.completed += 1;
+            entry.total_play_time += event.played;
+        }
+    }
+    for event in cancelled.read() {
+        if let Some(entry) = stats.entry(event.user_data) {
+            entry.cancelled += 1;
+            entry.total_play_time += event.played;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lens::TransformPositionLens, Animator, EaseMethod, Tween, TweeningPlugin};
+
+    fn make_tween(user_data: u64) -> Tween<Transform> {
+        Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+        .with_completed_event(user_data)
+    }
+
+    fn tick(app: &mut App, millis: u64) {
+        app.world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(millis));
+        app.update();
+    }
+
+    #[test]
+    fn stats_completed_and_cancelled() {
+        let mut app = App::new();
+        app.add_plugins(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register::<Transform>()
+                .with_stats(),
+        )
+        .init_resource::<Time>();
+        for user_data in [1, 1, 2] {
+            app.world
+                .spawn((Transform::default(), Animator::new(make_tween(user_data))));
+        }
+        let cancelled = app
+            .world
+            .spawn((Transform::default(), Animator::new(make_tween(2))))
+            .id();
+        tick(&mut app, 0);
+        tick(&mut app, 250);
+
+        // Replacing a tween in flight cancels it
+        app.world
+            .get_mut::<Animator<Transform>>(cancelled)
+            .unwrap()
+            .set_tweenable(make_tween(3));
+        tick(&mut app, 1000);
+        tick(&mut app, 0);
+
+        let stats = app.world.resource::<TweenStats>();
+        assert_eq!(
+            stats.get(1),
+            Some(&StatsEntry {
+                completed: 2,
+                cancelled: 0,
+                total_play_time: Duration::from_secs(2),
+            })
+        );
+        assert_eq!(
+            stats.get(2),
+            Some(&StatsEntry {
+                completed: 1,
+                cancelled: 1,
+                total_play_time: Duration::from_millis(1250),
+            })
+        );
+        assert_eq!(stats.get(3).unwrap().completed, 1);
+        assert_eq!(stats.iter().count(), 3);
+
+        app.world.resource_mut::<TweenStats>().reset();
+        assert!(app.world.resource::<TweenStats>().get(1).is_none());
+    }
+
+    #[test]
+    fn stats_elapsed_trigger() {
+        let mut app = App::new();
+        app.add_plugins(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register::<Transform>()
+                .with_stats(),
+        )
+        .init_resource::<Time>();
+        let tween = make_tween(1).with_elapsed_trigger(Duration::from_millis(500), 1);
+        app.world
+            .spawn((Transform::default(), Animator::new(tween)));
+        tick(&mut app, 0);
+        tick(&mut app, 500);
+        assert!(app.world.resource::<TweenStats>().get(1).is_none());
+        tick(&mut app, 500);
+
+        assert_eq!(
+            app.world.resource::<TweenStats>().get(1),
+            Some(&StatsEntry {
+                completed: 1,
+                cancelled: 0,
+                total_play_time: Duration::from_secs(1),
+            })
+        );
+    }
+
+    #[test]
+    fn stats_disabled() {
+        let mut app = App::new();
+        app.add_plugins(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register::<Transform>(),
+        )
+        .init_resource::<Time>();
+        app.world
+            .spawn((Transform::default(), Animator::new(make_tween(1))));
+        tick(&mut app, 2000);
+        assert!(!app.world.contains_resource::<TweenStats>());
+    }
+}
@@ -0,0 +1,352 @@
+//! Screen transitions driven by a full-screen UI overlay.
+//!
+//! This module is only available with the `bevy_ui` feature.
+
+use std::time::Duration;
+
+use bevy::{ecs::system::Command, prelude::*};
+
+use crate::{
+    commands::resolve_overwrite, lens::UiBackgroundColorLens, Animator, AnimatorOverwritePolicy,
+    EaseFunction, EaseMethod, Tween,
+};
+
+/// Marker component of the full-screen overlay of a [`ScreenFade`].
+///
+/// There's at most one overlay, spawned by the first transition and reused by
+/// the following ones while it exists.
+#[derive(Debug, Default, Clone, Copy, Component)]
+pub struct ScreenFadeOverlay;
+
+/// Fade-to-color screen transition.
+///
+/// A screen fade animates the [`BackgroundColor`] of a full-screen UI node
+/// covering the whole window, the [`ScreenFadeOverlay`]. The transition fades
+/// the overlay out to a covering color, then optionally fades it back in to
+/// reveal the screen again, at which point the overlay is despawned.
+///
+/// Once the screen is fully covered, the transition raises a
+/// [`TweenCompleted`] event for the overlay entity, with the user data given
+/// to [`then_run()`]. The background color of the overlay is exactly the
+/// covering color on the tick the event is raised, so the application can
+/// swap scenes while the screen is hidden.
+///
+/// Starting a transition while another one is in flight reuses the overlay,
+/// and resolves the animation in flight according to an
+/// [`AnimatorOverwritePolicy`]. The new transition fades out from the current
+/// color of the overlay, so it doesn't flash. The default policy
+/// [`AnimatorOverwritePolicy::ReplaceAndComplete`] completes the transition in
+/// flight first, raising its event if the screen wasn't covered yet.
+///
+/// The overlay is animated by the `component_animator_system::<BackgroundColor>`,
+/// and despawned by the [`screen_fade_system`], both added by the
+/// [`TweeningPlugin`]; the former is a default target of the plugin, so it must
+/// be registered explicitly if the defaults are disabled. The overlay is a
+/// regular UI node, so it blocks the interactions with the UI nodes below it
+/// while it exists.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{transitions::*, *};
+/// # use std::time::Duration;
+/// const SWAP_LEVEL: u64 = 42;
+///
+/// fn exit_level(mut commands: Commands) {
+///     ScreenFade::out(Duration::from_millis(300))
+///         .then_run(SWAP_LEVEL)
+///         .then_in(Duration::from_millis(300))
+///         .start(&mut commands);
+/// }
+///
+/// fn swap_level(mut reader: EventReader<TweenCompleted>) {
+///     for ev in reader.read() {
+///         if ev.user_data == SWAP_LEVEL {
+///             // The screen is fully covered; despawn the old level and spawn
+///             // the new one.
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`then_run()`]: ScreenFade::then_run
+/// [`TweenCompleted`]: crate::TweenCompleted
+/// [`TweeningPlugin`]: crate::TweeningPlugin
+#[derive(Clone, Copy)]
+pub struct ScreenFade {
+    /// Color covering the screen at the middle of the transition.
+    pub color: Color,
+    /// Duration of the fade out, covering the screen.
+    pub fade_out: Duration,
+    /// Duration of the fade in, revealing the screen, if any.
+    pub fade_in: Option<Duration>,
+    /// User data of the event raised once the screen is covered, if any.
+    pub user_data: Option<u64>,
+    /// Easing method of both fades.
+    pub ease: EaseMethod,
+    /// Resolution of a transition in flight.
+    pub policy: AnimatorOverwritePolicy,
+}
+
+impl ScreenFade {
+    /// Create a transition fading the screen out to black.
+    ///
+    /// The screen stays covered once faded out, unless a fade in is added with
+    /// [`then_in()`]. The fades use a quadratic ease-in-out.
+    ///
+    /// [`then_in()`]: ScreenFade::then_in
+    pub fn out(duration: Duration) -> Self {
+        Self {
+            color: Color::BLACK,
+            fade_out: duration,
+            fade_in: None,
+            user_data: None,
+            ease: EaseFunction::QuadraticInOut.into(),
+            policy: AnimatorOverwritePolicy::ReplaceAndComplete,
+        }
+    }
+
+    /// Raise a [`TweenCompleted`] event with the given user data once the
+    /// screen is fully covered.
+    ///
+    /// [`TweenCompleted`]: crate::TweenCompleted
+    #[must_use]
+    pub fn then_run(mut self, user_data: u64) -> Self {
+        self.user_data = Some(user_data);
+        self
+    }
+
+    /// Fade the screen back in after it was covered, then despawn the
+    /// overlay.
+    #[must_use]
+    pub fn then_in(mut self, duration: Duration) -> Self {
+        self.fade_in = Some(duration);
+        self
+    }
+
+    /// Set the color covering the screen. Defaults to opaque black.
+    #[must_use]
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set the easing method of both fades.
+    #[must_use]
+    pub fn with_ease(mut self, ease: impl Into<EaseMethod>) -> Self {
+        self.ease = ease.into();
+        self
+    }
+
+    /// Set the resolution of a transition in flight when this one starts.
+    /// Defaults to [`AnimatorOverwritePolicy::ReplaceAndComplete`].
+    #[must_use]
+    pub fn with_policy(mut self, policy: AnimatorOverwritePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Start the transition when the commands are applied.
+    pub fn start(self, commands: &mut Commands) {
+        commands.add(self);
+    }
+
+    /// Spawn a transparent overlay.
+    fn spawn_overlay(&self, world: &mut World) -> Entity {
+        world
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        width: Val::Percent(100.),
+                        height: Val::Percent(100.),
+                        ..default()
+                    },
+                    background_color: self.color.with_a(0.).into(),
+                    z_index: ZIndex::Global(i32::MAX),
+                    ..default()
+                },
+                ScreenFadeOverlay,
+                Name::new("ScreenFadeOverlay"),
+            ))
+            .id()
+    }
+}
+
+impl Command for ScreenFade {
+    fn apply(self, world: &mut World) {
+        let existing = world
+            .query_filtered::<Entity, With<ScreenFadeOverlay>>()
+            .iter(world)
+            .next();
+        let overlay = match existing {
+            Some(overlay) => {
+                if !resolve_overwrite::<BackgroundColor>(world, overlay, self.policy) {
+                    return;
+                }
+                overlay
+            }
+            None => self.spawn_overlay(world),
+        };
+
+        let current = world
+            .get::<BackgroundColor>(overlay)
+            .map_or(self.color.with_a(0.), |color| color.0);
+        let mut fade_out = Tween::new(
+            self.ease,
+            self.fade_out,
+            UiBackgroundColorLens::new(current, self.color),
+        );
+        if let Some(user_data) = self.user_data {
+            fade_out = fade_out.with_completed_event(user_data);
+        }
+        let mut overlay = world.entity_mut(overlay);
+        match self.fade_in {
+            Some(fade_in) => {
+                let fade_in = Tween::new(
+                    self.ease,
+                    fade_in,
+                    UiBackgroundColorLens::new(self.color, self.color.with_a(0.)),
+                );
+                overlay.insert(Animator::new(fade_out.then(fade_in)))
+            }
+            None => overlay.insert(Animator::new(fade_out)),
+        };
+    }
+}
+
+/// System despawning the [`ScreenFadeOverlay`] once a [`ScreenFade`] revealed
+/// the screen again.
+///
+/// The overlay is despawned once its animator completed with a fully
+/// transparent color. An overlay left covering the screen by a transition
+/// without fade in is kept. This system is added by the [`TweeningPlugin`].
+///
+/// [`TweeningPlugin`]: crate::TweeningPlugin
+pub fn screen_fade_system(
+    mut commands: Commands,
+    query: Query<(Entity, &BackgroundColor, &Animator<BackgroundColor>), With<ScreenFadeOverlay>>,
+) {
+    for (entity, color, animator) in &query {
+        if animator.time_remaining() == Some(Duration::ZERO) && color.0.a() == 0. {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::CommandQueue;
+
+    use super::*;
+    use crate::{TweenCompleted, TweeningPlugin};
+
+    fn make_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register::<BackgroundColor>(),
+        )
+        .init_resource::<Time>();
+        app
+    }
+
+    fn start(app: &mut App, fade: ScreenFade) {
+        let mut queue = CommandQueue::default();
+        fade.start(&mut Commands::new(&mut queue, &app.world));
+        queue.apply(&mut app.world);
+    }
+
+    fn overlay(app: &mut App) -> Option<(Entity, Color)> {
+        app.world
+            .query_filtered::<(Entity, &BackgroundColor), With<ScreenFadeOverlay>>()
+            .iter(&app.world)
+            .next()
+            .map(|(entity, color)| (entity, color.0))
+    }
+
+    /// Tick the app, and return the user data of the completion events.
+    fn tick(app: &mut App, duration: Duration) -> Vec<u64> {
+        app.world.resource_mut::<Time>().advance_by(duration);
+        app.update();
+        app.world
+            .resource_mut::<Events<TweenCompleted>>()
+            .drain()
+            .map(|ev| ev.user_data)
+            .collect()
+    }
+
+    #[test]
+    fn screen_fade() {
+        let mut app = make_app();
+        start(
+            &mut app,
+            ScreenFade::out(Duration::from_secs(1))
+                .then_run(42)
+                .then_in(Duration::from_secs(1)),
+        );
+        let (overlay_entity, color) = overlay(&mut app).unwrap();
+        assert_eq!(color.a(), 0.);
+
+        assert!(tick(&mut app, Duration::from_millis(600)).is_empty());
+        let (_, color) = overlay(&mut app).unwrap();
+        assert!(color.a() > 0. && color.a() < 1.);
+
+        // The event fires on the tick the screen is fully covered
+        assert_eq!(tick(&mut app, Duration::from_millis(400)), vec![42]);
+        let (_, color) = overlay(&mut app).unwrap();
+        assert_eq!(color, Color::BLACK);
+        assert_eq!(color.a(), 1.);
+
+        assert!(tick(&mut app, Duration::from_millis(500)).is_empty());
+        let (_, color) = overlay(&mut app).unwrap();
+        assert!(color.a() > 0. && color.a() < 1.);
+
+        // Revealed again, the overlay is despawned
+        tick(&mut app, Duration::from_millis(500));
+        assert!(overlay(&mut app).is_none());
+        assert!(app.world.get_entity(overlay_entity).is_none());
+    }
+
+    #[test]
+    fn screen_fade_in_flight() {
+        let mut app = make_app();
+        start(
+            &mut app,
+            ScreenFade::out(Duration::from_secs(1)).then_run(1),
+        );
+        tick(&mut app, Duration::from_millis(500));
+        let (overlay_entity, color) = overlay(&mut app).unwrap();
+        assert!(color.a() < 1.);
+
+        // Rejected while in flight
+        start(
+            &mut app,
+            ScreenFade::out(Duration::from_secs(1))
+                .then_run(2)
+                .with_policy(AnimatorOverwritePolicy::Reject),
+        );
+        assert_eq!(overlay(&mut app).unwrap().1, color);
+
+        // The transition in flight completes, covering the screen and raising
+        // its event, and the new one reuses the overlay.
+        start(
+            &mut app,
+            ScreenFade::out(Duration::from_secs(1))
+                .then_run(3)
+                .then_in(Duration::from_secs(1))
+                .with_color(Color::WHITE),
+        );
+        let (entity, color) = overlay(&mut app).unwrap();
+        assert_eq!(entity, overlay_entity);
+        assert_eq!(color, Color::BLACK);
+        assert_eq!(tick(&mut app, Duration::ZERO), vec![1]);
+
+        assert_eq!(tick(&mut app, Duration::from_secs(1)), vec![3]);
+        assert_eq!(overlay(&mut app).unwrap().1, Color::WHITE);
+        tick(&mut app, Duration::from_secs(1));
+        assert!(overlay(&mut app).is_none());
+    }
+}
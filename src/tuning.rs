@@ -0,0 +1,403 @@
+//! Live tuning of the duration and easing of tweens.
+
+use std::{borrow::Cow, time::Duration};
+
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::{Animator, EaseMethod, ResourceAnimator};
+#[cfg(feature = "bevy_asset")]
+use crate::{AssetAnimator, SyncedAnimator};
+
+/// Key identifying the tweens tuned by an entry of the [`TweenTuning`]
+/// resource.
+///
+/// A key is set on a tween with [`Tween::with_tuning_key()`], and can be
+/// shared by any number of tweens.
+///
+/// [`Tween::with_tuning_key()`]: crate::Tween::with_tuning_key
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TuningKey {
+    /// Key with a name, for example `"card_hover"`.
+    Name(Cow<'static, str>),
+    /// Key with a numeric tag.
+    Id(u64),
+}
+
+impl From<&'static str> for TuningKey {
+    fn from(name: &'static str) -> Self {
+        Self::Name(Cow::Borrowed(name))
+    }
+}
+
+impl From<String> for TuningKey {
+    fn from(name: String) -> Self {
+        Self::Name(Cow::Owned(name))
+    }
+}
+
+impl From<u64> for TuningKey {
+    fn from(id: u64) -> Self {
+        Self::Id(id)
+    }
+}
+
+/// Overrides applied to the tweens of a [`TuningKey`].
+///
+/// Each field left to `None` keeps the value the tween was created with, or
+/// last tuned to.
+#[derive(Default, Clone, Copy)]
+pub struct TweenTuningEntry {
+    /// Duration of a single iteration of the tweens. A zero duration is
+    /// ignored.
+    pub duration: Option<Duration>,
+    /// Easing method of the tweens.
+    pub ease: Option<EaseMethod>,
+}
+
+impl TweenTuningEntry {
+    /// Create an entry overriding the duration of the tweens.
+    #[must_use]
+    pub fn duration(duration: Duration) -> Self {
+        Self {
+            duration: Some(duration),
+            ease: None,
+        }
+    }
+
+    /// Create an entry overriding the easing method of the tweens.
+    #[must_use]
+    pub fn ease(ease: impl Into<EaseMethod>) -> Self {
+        Self {
+            duration: None,
+            ease: Some(ease.into()),
+        }
+    }
+
+    /// Also override the duration of the tweens.
+    #[must_use]
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Also override the easing method of the tweens.
+    #[must_use]
+    pub fn with_ease(mut self, ease: impl Into<EaseMethod>) -> Self {
+        self.ease = Some(ease.into());
+        self
+    }
+}
+
+/// Resource overriding the duration and easing of the tweens with a
+/// [`TuningKey`], to tune the feel of animations without rebuilding them.
+///
+/// When this resource is inserted or changes, the [`TweeningPlugin`] applies
+/// its entries to all the [`Tween`]s with a matching key, including the ones
+/// nested in a [`Sequence`] or [`Tracks`], before the animators tick. Newly
+/// spawned animators are tuned on their first tick. Changing the duration of
+/// a tween preserves its progress fraction, so the animated value doesn't
+/// jump. Tweens without a key, and all tweens while the resource doesn't
+/// exist, are never visited.
+///
+/// The entries are only applied when the resource changes, so a duration or
+/// easing changed at runtime with [`Tween::set_duration()`] or
+/// [`Tween::set_ease_method()`] is kept until the resource is next modified.
+/// Tweens nested in a [`Sequence`], [`Tracks`], [`Eased`], or [`Slice`] are
+/// tuned too. A custom tweenable wrapping another one needs to forward
+/// [`Tweenable::apply_tuning()`] for the tweens it wraps to be tuned.
+///
+/// This is typically filled from a file reloaded while the application runs;
+/// see the `tuning` example.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// let tween = Tween::new_millis(
+///     EaseFunction::QuadraticOut,
+///     200,
+///     TransformScaleLens {
+///         start: Vec3::ONE,
+///         end: Vec3::splat(1.1),
+///     },
+/// )
+/// .with_tuning_key("card_hover");
+///
+/// let mut tuning = TweenTuning::default();
+/// tuning.insert(
+///     "card_hover",
+///     TweenTuningEntry::duration(Duration::from_millis(120))
+///         .with_ease(EaseFunction::BackOut),
+/// );
+/// ```
+///
+/// [`TweeningPlugin`]: crate::TweeningPlugin
+/// [`Tween`]: crate::Tween
+/// [`Sequence`]: crate::Sequence
+/// [`Tracks`]: crate::Tracks
+/// [`Eased`]: crate::Eased
+/// [`Slice`]: crate::Slice
+/// [`Tweenable::apply_tuning()`]: crate::Tweenable::apply_tuning
+/// [`Tween::set_duration()`]: crate::Tween::set_duration
+/// [`Tween::set_ease_method()`]: crate::Tween::set_ease_method
+#[derive(Default, Resource)]
+pub struct TweenTuning {
+    entries: HashMap<TuningKey, TweenTuningEntry>,
+}
+
+impl TweenTuning {
+    /// Create an empty set of tuning entries.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the entry of a key, replacing any previous one.
+    pub fn insert(&mut self, key: impl Into<TuningKey>, entry: TweenTuningEntry) {
+        self.entries.insert(key.into(), entry);
+    }
+
+    /// Get the entry of a key, if any.
+    #[must_use]
+    pub fn get(&self, key: &TuningKey) -> Option<&TweenTuningEntry> {
+        self.entries.get(key)
+    }
+
+    /// Remove the entry of a key, returning it if it existed.
+    ///
+    /// The tweens previously tuned by that entry keep their tuned values.
+    pub fn remove(&mut self, key: &TuningKey) -> Option<TweenTuningEntry> {
+        self.entries.remove(key)
+    }
+
+    /// Remove all the entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Iterate over all the entries, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&TuningKey, &TweenTuningEntry)> {
+        self.entries.iter()
+    }
+}
+
+/// An animator component whose tweenable can be tuned.
+pub(crate) trait TunableAnimator: Component {
+    /// Apply the [`TweenTuning`] to the tweenable of the animator.
+    fn apply_tuning(&mut self, tuning: &TweenTuning);
+}
+
+impl<T: Component> TunableAnimator for Animator<T> {
+    fn apply_tuning(&mut self, tuning: &TweenTuning) {
+        self.tweenable_mut().apply_tuning(tuning);
+    }
+}
+
+#[cfg(feature = "bevy_asset")]
+impl<T: Asset> TunableAnimator for AssetAnimator<T> {
+    fn apply_tuning(&mut self, tuning: &TweenTuning) {
+        self.tweenable_mut().apply_tuning(tuning);
+    }
+}
+
+#[cfg(feature = "bevy_asset")]
+impl<C: Component, A: Asset> TunableAnimator for SyncedAnimator<C, A> {
+    fn apply_tuning(&mut self, tuning: &TweenTuning) {
+        self.tweenable_mut().apply_tuning(tuning);
+    }
+}
+
+impl<T: Resource> TunableAnimator for ResourceAnimator<T> {
+    fn apply_tuning(&mut self, tuning: &TweenTuning) {
+        self.tweenable_mut().apply_tuning(tuning);
+    }
+}
+
+/// Apply the [`TweenTuning`] to the animators of type `A`: all of them when
+/// the resource changed, or else only the ones added since the last run.
+pub(crate) fn tuning_system<A: TunableAnimator>(
+    tuning: Option<Res<TweenTuning>>,
+    mut animators: Query<&mut A>,
+) {
+    let Some(tuning) = tuning else {
+        return;
+    };
+    let all = tuning.is_changed();
+    for mut animator in &mut animators {
+        if all || animator.is_added() {
+            animator.apply_tuning(&tuning);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        lens::TransformPositionLens, EaseFunction, Eased, Sequence, Slice, Tween, Tweenable,
+        TweeningPlugin,
+    };
+
+    fn make_tween(key: Option<&'static str>) -> Tween<Transform> {
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::new(4., 0., 0.),
+            },
+        );
+        match key {
+            Some(key) => tween.with_tuning_key(key),
+            None => tween,
+        }
+    }
+
+    fn make_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(
+            TweeningPlugin::default()
+                .without_defaults()
+                .register::<Transform>(),
+        )
+        .init_resource::<Time>();
+        app
+    }
+
+    fn tick(app: &mut App, millis: u64) {
+        app.world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(millis));
+        app.update();
+    }
+
+    fn tweenable(app: &App, entity: Entity) -> &dyn Tweenable<Transform> {
+        app.world
+            .get::<Animator<Transform>>(entity)
+            .unwrap()
+            .tweenable()
+    }
+
+    fn x(app: &App, entity: Entity) -> f32 {
+        app.world.get::<Transform>(entity).unwrap().translation.x
+    }
+
+    #[test]
+    fn tuning_preserves_progress() {
+        let mut app = make_app();
+        let keyed = app
+            .world
+            .spawn((Transform::default(), Animator::new(make_tween(Some("a")))))
+            .id();
+        let unkeyed = app
+            .world
+            .spawn((Transform::default(), Animator::new(make_tween(None))))
+            .id();
+        tick(&mut app, 250);
+        assert!((x(&app, keyed) - 1.).abs() < 1e-5);
+
+        let mut tuning = TweenTuning::new();
+        tuning.insert(
+            "a",
+            TweenTuningEntry::duration(Duration::from_secs(2)).with_ease(EaseFunction::QuadraticIn),
+        );
+        app.insert_resource(tuning);
+        tick(&mut app, 0);
+        let tween = tweenable(&app, keyed);
+        assert_eq!(tween.duration(), Duration::from_secs(2));
+        assert_eq!(tween.elapsed(), Duration::from_millis(500));
+        assert!((tween.progress() - 0.25).abs() < 1e-5);
+        assert_eq!(tweenable(&app, unkeyed).duration(), Duration::from_secs(1));
+
+        // The new duration and easing apply from there
+        tick(&mut app, 500);
+        assert!((x(&app, keyed) - 1.).abs() < 1e-5);
+        assert!((x(&app, unkeyed) - 3.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn tuning_new_animators() {
+        let mut app = make_app();
+        let mut tuning = TweenTuning::new();
+        tuning.insert("a", TweenTuningEntry::duration(Duration::from_secs(2)));
+        app.insert_resource(tuning);
+        tick(&mut app, 0);
+
+        // Animators added after the resource are tuned, including nested tweens
+        let entity = app
+            .world
+            .spawn((
+                Transform::default(),
+                Animator::new(Sequence::new([make_tween(None), make_tween(Some("a"))])),
+            ))
+            .id();
+        tick(&mut app, 0);
+        let animator = app.world.get::<Animator<Transform>>(entity).unwrap();
+        assert_eq!(animator.tweenable().duration(), Duration::from_secs(3));
+    }
+
+    #[test]
+    fn tuning_wrapped_tweens() {
+        let mut app = make_app();
+        let eased = app
+            .world
+            .spawn((
+                Transform::default(),
+                Animator::new(Eased::new(make_tween(Some("a")), EaseFunction::QuadraticIn)),
+            ))
+            .id();
+        let slice = app
+            .world
+            .spawn((
+                Transform::default(),
+                Animator::new(Slice::new(make_tween(Some("a")), 0.5, 1.)),
+            ))
+            .id();
+        let mut tuning = TweenTuning::new();
+        tuning.insert("a", TweenTuningEntry::duration(Duration::from_secs(2)));
+        app.insert_resource(tuning);
+        tick(&mut app, 0);
+
+        // The wrappers forward the tuning to their child, and a slice keeps
+        // covering the same fraction of it
+        assert_eq!(tweenable(&app, eased).duration(), Duration::from_secs(2));
+        assert_eq!(tweenable(&app, slice).duration(), Duration::from_secs(1));
+        tick(&mut app, 500);
+        assert!((x(&app, eased) - 0.25).abs() < 1e-5);
+        assert!((x(&app, slice) - 3.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn tuning_keeps_runtime_changes() {
+        let mut app = make_app();
+        let mut tuning = TweenTuning::new();
+        tuning.insert("a", TweenTuningEntry::duration(Duration::from_secs(2)));
+        app.insert_resource(tuning);
+        let entity = app
+            .world
+            .spawn((Transform::default(), Animator::new(make_tween(Some("a")))))
+            .id();
+        tick(&mut app, 0);
+        assert_eq!(tweenable(&app, entity).duration(), Duration::from_secs(2));
+
+        // A tween changed at runtime is kept while the resource doesn't change
+        app.world
+            .get_mut::<Animator<Transform>>(entity)
+            .unwrap()
+            .set_tweenable(make_tween(Some("a")));
+        tick(&mut app, 100);
+        assert_eq!(tweenable(&app, entity).duration(), Duration::from_secs(1));
+
+        // Changing the resource re-applies its entries
+        app.world
+            .resource_mut::<TweenTuning>()
+            .insert("a", TweenTuningEntry::duration(Duration::from_millis(500)));
+        tick(&mut app, 0);
+        assert_eq!(
+            tweenable(&app, entity).duration(),
+            Duration::from_millis(500)
+        );
+    }
+}
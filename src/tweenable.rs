@@ -1,8 +1,22 @@
-use std::{ops::DerefMut, time::Duration};
+use std::{
+    any::Any,
+    marker::PhantomData,
+    ops::DerefMut,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
 use bevy::prelude::*;
 
-use crate::{EaseMethod, Lens, RepeatCount, RepeatStrategy, TweeningDirection};
+use crate::{
+    lens::{PathLens, RangedLens},
+    CancelBehavior, CompletedBehavior, EaseMethod, IntoDuration, Lens, LensContext, RepeatCount,
+    RepeatSpec, RepeatStrategy, TuningKey, TweenBuilder, TweenOverrides, TweenTuning,
+    TweenableState, TweeningDirection, WriteCoalescing,
+};
 
 /// The dynamic tweenable type.
 ///
@@ -22,7 +36,7 @@ use crate::{EaseMethod, Lens, RepeatCount, RepeatStrategy, TweeningDirection};
 /// implement [`From`]:
 /// ```no_run
 /// # use std::time::Duration;
-/// # use bevy::prelude::{Entity, Events, Mut, Transform};
+/// # use bevy::prelude::{Entity, Transform};
 /// # use bevy_tweening::{BoxedTweenable, Sequence, Tweenable, TweenCompleted, TweenState, Targetable, TotalDuration};
 /// #
 /// # struct MyTweenable;
@@ -31,7 +45,7 @@ use crate::{EaseMethod, Lens, RepeatCount, RepeatStrategy, TweeningDirection};
 /// #     fn total_duration(&self) -> TotalDuration  { unimplemented!() }
 /// #     fn set_elapsed(&mut self, elapsed: Duration)  { unimplemented!() }
 /// #     fn elapsed(&self) -> Duration  { unimplemented!() }
-/// #     fn tick<'a>(&mut self, delta: Duration, target: &'a mut dyn Targetable<Transform>, entity: Entity, events: &mut Mut<Events<TweenCompleted>>) -> TweenState  { unimplemented!() }
+/// #     fn tick<'a>(&mut self, delta: Duration, target: &'a mut dyn Targetable<Transform>, entity: Entity, events: &mut Vec<TweenCompleted>) -> TweenState  { unimplemented!() }
 /// #     fn rewind(&mut self) { unimplemented!() }
 /// # }
 ///
@@ -65,6 +79,73 @@ pub enum TweenState {
     Completed,
 }
 
+/// Unique identifier of a tweenable.
+///
+/// Each [`Tween`], [`Sequence`], [`Tracks`], [`Delay`], [`WaitFor`], [`Eased`],
+/// and [`Slice`] is assigned a new identifier when created, from a counter
+/// increasing for the lifetime of the process, so no two tweenables share the
+/// same identifier. The identifier is available with [`Tweenable::id()`] and
+/// doesn't change for the lifetime of the tweenable.
+///
+/// The [`TweenCompleted`] and [`TweenCancelled`] events hold the identifier of
+/// the tweenable which raised them, so an event can be matched to the exact
+/// tweenable which was created, even with several animators per entity.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// #[derive(Resource)]
+/// struct DoorOpening(TweenId);
+///
+/// fn on_completed(door: Res<DoorOpening>, mut reader: EventReader<TweenCompleted>) {
+///     for ev in reader.read() {
+///         if ev.tween_id == door.0 {
+///             println!("Door opened");
+///         }
+///     }
+/// }
+///
+/// let tween = Tween::new(
+///     EaseFunction::QuadraticInOut,
+///     1.,
+///     TransformRotateYLens {
+///         start: 0.,
+///         end: 1.5,
+///     },
+/// )
+/// .with_completed_event(0);
+/// let door = DoorOpening(tween.id().unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TweenId(u64);
+
+impl TweenId {
+    /// Allocate a new identifier, different from all the ones allocated
+    /// before.
+    ///
+    /// The built-in tweenables allocate their own identifier. Custom
+    /// tweenables raising events can use this to allocate theirs.
+    #[must_use]
+    pub fn allocate() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// Get the raw value of the identifier.
+    #[must_use]
+    pub fn get(&self) -> u64 {
+        self.0
+    }
+}
+
+impl std::fmt::Display for TweenId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "tween#{}", self.0)
+    }
+}
+
 /// Event raised when a tween completed.
 ///
 /// This event is raised when a tween completed. When looping, this is raised
@@ -81,10 +162,44 @@ pub enum TweenState {
 /// updated anymore, a state which is never reached for looping animation. Here
 /// the [`TweenCompleted`] event instead marks the end of a single loop
 /// iteration.
-#[derive(Copy, Clone, Event)]
+///
+/// # Elapsed triggers
+///
+/// A [`Tween`] also raises this event for each of its [elapsed triggers],
+/// once it played for the time of the trigger, whether or not it completed.
+/// Those events have [`is_elapsed_trigger`] set, and don't mark the end of
+/// anything; readers reacting to completions should skip them.
+///
+/// # Despawned entities
+///
+/// The [`entity`] of the event may have been despawned by the time the event
+/// is read, for example if gameplay code despawned it in the same frame the
+/// tween completed. Readers should not assume the entity still exists; use
+/// [`Query::get()`] instead of indexing, and [`Commands::get_entity()`] or
+/// [`EntityCommands::try_insert()`] instead of [`Commands::entity()`], which
+/// would panic when the commands are applied.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::*;
+/// # #[derive(Component)] struct Done;
+/// fn on_completed(mut commands: Commands, mut reader: EventReader<TweenCompleted>) {
+///     for ev in reader.read() {
+///         if let Some(mut entity) = commands.get_entity(ev.entity) {
+///             entity.try_insert(Done);
+///         }
+///     }
+/// }
+/// ```
+///
+/// [elapsed triggers]: Tween::with_elapsed_trigger
+/// [`is_elapsed_trigger`]: TweenCompleted::is_elapsed_trigger
+/// [`entity`]: TweenCompleted::entity
+/// [`EntityCommands::try_insert()`]: bevy::ecs::system::EntityCommands::try_insert
+#[derive(Debug, Copy, Clone, Event)]
 pub struct TweenCompleted {
     /// The [`Entity`] the tween which completed and its animator are attached
-    /// to.
+    /// to. This entity may have been despawned since the event was sent.
     pub entity: Entity,
     /// An opaque value set by the user when activating event raising, used to
     /// identify the particular tween which raised this event. The value is
@@ -94,11 +209,259 @@ pub struct TweenCompleted {
     /// [`with_completed_event()`]: Tween::with_completed_event
     /// [`set_completed_event()`]: Tween::set_completed_event
     pub user_data: u64,
+    /// The identifier of the tweenable which raised this event.
+    pub tween_id: TweenId,
+    /// The animation time of the playback this event completes: the duration
+    /// of the iterations of a [`Tween`] completed on the tick raising the
+    /// event, the elapsed time of an [`elapsed trigger`], or the duration of
+    /// a [`Delay`] or a [`Slice`].
+    ///
+    /// [`elapsed trigger`]: Tween::with_elapsed_trigger
+    pub played: Duration,
+    /// The number of iterations left to play by the tweenable which raised
+    /// this event, as returned by [`Tween::remaining_repeats()`] after the
+    /// tick raising it. The event of the penultimate iteration of a tween
+    /// reports `Some(1)`, and the one of its final iteration `Some(0)`, which
+    /// is also what a [`Delay`] or a [`Slice`] report. This is `None` for a
+    /// tween repeating forever.
+    pub remaining_repeats: Option<u32>,
+    /// Whether this event was raised by an [`elapsed trigger`] of a
+    /// [`Tween`], instead of marking a completion.
+    ///
+    /// [`elapsed trigger`]: Tween::with_elapsed_trigger
+    pub is_elapsed_trigger: bool,
+}
+
+/// Event raised when a tweenable raising [`TweenCompleted`] events is
+/// discarded before it completed.
+///
+/// This is the counterpart of [`TweenCompleted`] for cleanup code which needs
+/// to know when an animation will never complete, for example a looping
+/// animation interrupted by gameplay. One event is raised for each
+/// [`with_completed_event()`] user data of the tweens, delays, and slices of
+/// the tweenable which didn't complete yet, when:
+/// - the tweenable of an animator is replaced with [`set_tweenable()`];
+/// - the animator is [`stop()`]ped;
+/// - the animator component is removed, including when its
+///   [`AnimationGroupId`] is cancelled, or when its entity is despawned;
+/// - the animator is overwritten with [`animate_with_policy()`]. Overwriting
+///   it with a plain [`EntityCommands::insert()`] raises no event;
+/// - the [`CancelToken`] of the tweenable, or of one of its parents, is
+///   cancelled.
+///
+/// The events of an animator are raised by the animator systems of the
+/// [`TweeningPlugin`], after the animators ticked. A tweenable raises at most
+/// one cancellation until it plays again, so stopping an animator twice, or
+/// stopping then removing it, raises a single event.
+///
+/// The events of a looping tween raising a completion on each loop are
+/// cancelled once, as the loop in progress never completes.
+///
+/// [`with_completed_event()`]: Tween::with_completed_event
+/// [`set_tweenable()`]: crate::Animator::set_tweenable
+/// [`stop()`]: crate::Animator::stop
+/// [`AnimationGroupId`]: crate::AnimationGroupId
+/// [`animate_with_policy()`]: crate::AnimatorCommandsExt::animate_with_policy
+/// [`EntityCommands::insert()`]: bevy::ecs::system::EntityCommands::insert
+/// [`TweeningPlugin`]: crate::TweeningPlugin
+#[derive(Debug, Copy, Clone, PartialEq, Event)]
+pub struct TweenCancelled {
+    /// The [`Entity`] the cancelled tweenable and its animator are attached
+    /// to. This entity may have been despawned since the event was sent.
+    pub entity: Entity,
+    /// The user data of the [`TweenCompleted`] event the tweenable would have
+    /// raised.
+    pub user_data: u64,
+    /// The identifier of the tween, delay, or slice raising the event.
+    pub tween_id: TweenId,
+    /// The progress in \[0:1\] of the tween, delay, or slice raising the
+    /// event when it was cancelled, as of the last tick of its animator.
+    pub progress_at_cancel: f32,
+    /// The animation time played before the cancellation and not already
+    /// reported by a [`TweenCompleted`] event: the elapsed time of the current
+    /// iteration of a [`Tween`], or the elapsed time of a [`Delay`] or a
+    /// [`Slice`].
+    pub played: Duration,
+}
+
+/// Token to cancel a tweenable from outside the animator playing it.
+///
+/// The token is cheap to clone, and can be stored anywhere, including in an
+/// async task. Attach a clone to a [`Tween`], a [`Sequence`], or a [`Tracks`]
+/// with their `with_cancel_token()` method, then call [`cancel()`] from
+/// anywhere, without knowing which animator ended up playing it. On its next
+/// tick, the tweenable observes the cancellation:
+/// - it applies its [`CancelBehavior`] to the target;
+/// - it raises a [`TweenCancelled`] event for each [`TweenCompleted`] event it
+///   would still have raised, including the ones of its children;
+/// - it stops playing, and reports itself as [`TweenState::Completed`] to
+///   its parent or its animator.
+///
+/// A cancelled child of a [`Tracks`] stops while the other tracks continue,
+/// and a cancelled child of a [`Sequence`] is skipped, the next child starting
+/// on the same tick. A cancelled root tweenable completes its animator, which
+/// then applies its completion policies like [`with_shrink_on_complete()`] or
+/// the [`IdleAnimatorCleanup`]. The completion events and callbacks of a
+/// cancelled tweenable are never raised.
+///
+/// Rewinding the tweenable lets it play again, and observe the token again;
+/// use [`reset()`] to reuse a token after a cancellation.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// let token = CancelToken::default();
+/// let tween = Tween::new(
+///     EaseFunction::QuadraticOut,
+///     2.,
+///     TransformScaleLens {
+///         start: Vec3::ONE,
+///         end: Vec3::splat(2.),
+///     },
+/// )
+/// .with_cancel_token(token.clone())
+/// .with_cancel_behavior(CancelBehavior::SnapToStart);
+///
+/// // Later, from any system or task
+/// token.cancel();
+/// ```
+///
+/// [`cancel()`]: CancelToken::cancel
+/// [`reset()`]: CancelToken::reset
+/// [`CancelBehavior`]: crate::CancelBehavior
+/// [`with_shrink_on_complete()`]: crate::Animator::with_shrink_on_complete
+/// [`IdleAnimatorCleanup`]: crate::IdleAnimatorCleanup
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    /// Cancel the tweenables holding this token, on their next tick.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    /// Check if the token was cancelled since it was created or last reset.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+
+    /// Clear the cancellation, so that the tweenables holding this token play
+    /// again once rewound.
+    pub fn reset(&self) {
+        self.0.store(false, Ordering::Release);
+    }
+}
+
+/// Cancellation of a tweenable through its [`CancelToken`].
+#[derive(Default)]
+struct Cancellation {
+    token: Option<CancelToken>,
+    behavior: CancelBehavior,
+    /// Whether the tweenable observed its token cancelled since it was last
+    /// rewound.
+    observed: bool,
+    /// The events raised when observing the cancellation, not taken yet.
+    events: Vec<TweenCancelled>,
+}
+
+impl Cancellation {
+    /// Check if the token was cancelled and the cancellation not observed yet.
+    fn is_due(&self) -> bool {
+        !self.observed && self.token.as_ref().is_some_and(CancelToken::is_cancelled)
+    }
+
+    /// Mark the cancellation as observed, with the events it raises.
+    fn observe(&mut self, mut events: Vec<TweenCancelled>) {
+        self.observed = true;
+        self.events.append(&mut events);
+    }
+}
+
+/// Apply a [`CancelBehavior`] to the target of a tweenable cancelled through
+/// its [`CancelToken`].
+fn apply_cancel_behavior<T>(
+    tweenable: &dyn Tweenable<T>,
+    behavior: CancelBehavior,
+    target: &mut T,
+) {
+    match behavior {
+        CancelBehavior::Freeze => {}
+        CancelBehavior::SnapToStart => {
+            tweenable.sample(Duration::ZERO, target);
+        }
+        CancelBehavior::SnapToEnd => {
+            tweenable.sample(end_elapsed(tweenable), target);
+        }
+    }
+}
+
+/// Error returned by [`Tweenable::validate()`] when a tweenable breaks an
+/// invariant its playback relies on.
+///
+/// Each variant holds the identifier of the offending tweenable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TweenValidationError {
+    /// A [`Sequence`] or [`Tracks`] has no child.
+    EmptyComposite(TweenId),
+    /// A child of a [`Sequence`] other than the last one repeats infinitely,
+    /// so the children after it would never play. The identifier is the one
+    /// of the sequence.
+    InfiniteChild(TweenId),
+    /// The lens of a [`Tween`] has invalid parameters, like a NaN start or
+    /// end value; see [`Lens::is_valid()`].
+    InvalidLens(TweenId),
+}
+
+impl std::fmt::Display for TweenValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyComposite(id) => write!(f, "{id} has no child"),
+            Self::InfiniteChild(id) => write!(
+                f,
+                "{id} has a child repeating infinitely before its last one, so its next children never play"
+            ),
+            Self::InvalidLens(id) => write!(f, "{id} has a lens with invalid parameters"),
+        }
+    }
+}
+
+impl std::error::Error for TweenValidationError {}
+
+/// Error returned by [`Sequence::try_new()`] and [`Tracks::try_new()`] when
+/// given no child.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmptyTweenableError {
+    type_name: &'static str,
+}
+
+impl EmptyTweenableError {
+    /// Name of the type which couldn't be created, like `"Sequence"`.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
+
+impl std::fmt::Display for EmptyTweenableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "cannot create a {} from an empty collection, it needs at least one child",
+            self.type_name
+        )
+    }
 }
 
+impl std::error::Error for EmptyTweenableError {}
+
 /// Calculate the progress fraction in \[0:1\] of the ratio between two
 /// [`Duration`]s.
 fn fraction_progress(n: Duration, d: Duration) -> f32 {
+    // Elapsed times are kept as integer nanoseconds, and the fraction is
+    // computed in f64, so that tiny per-tick advances on long tweens are not
+    // lost. Only the result is narrowed, for the easing function and lens.
     // TODO - Replace with div_duration_f32() once it's stable
     (n.as_secs_f64() / d.as_secs_f64()).fract() as f32
 }
@@ -108,6 +471,7 @@ struct AnimClock {
     elapsed: Duration,
     duration: Duration,
     total_duration: TotalDuration,
+    repeat_count: RepeatCount,
     strategy: RepeatStrategy,
 }
 
@@ -117,15 +481,39 @@ impl AnimClock {
             elapsed: Duration::ZERO,
             duration,
             total_duration: compute_total_duration(duration, RepeatCount::default()),
+            repeat_count: RepeatCount::default(),
             strategy: RepeatStrategy::default(),
         }
     }
 
+    fn set_repeat_count(&mut self, count: RepeatCount) {
+        self.repeat_count = count;
+        self.total_duration = compute_total_duration(self.duration, count);
+    }
+
+    /// Change the duration of a single iteration, scaling the elapsed time so
+    /// that the number of iterations completed and the progress within the
+    /// current one are preserved.
+    fn set_duration(&mut self, duration: Duration) {
+        if !self.duration.is_zero() {
+            let scale = duration.as_secs_f64() / self.duration.as_secs_f64();
+            self.elapsed = Duration::from_secs_f64(self.elapsed.as_secs_f64() * scale);
+        }
+        self.duration = duration;
+        self.total_duration = compute_total_duration(duration, self.repeat_count);
+        if let TotalDuration::Finite(total_duration) = self.total_duration {
+            self.elapsed = self.elapsed.min(total_duration);
+        }
+    }
+
     fn tick(&mut self, tick: Duration) -> (TweenState, i32) {
         self.set_elapsed(self.elapsed.saturating_add(tick))
     }
 
     fn times_completed(&self) -> u32 {
+        if self.duration.is_zero() {
+            return 0;
+        }
         (self.elapsed.as_nanos() / self.duration.as_nanos()) as u32
     }
 
@@ -157,23 +545,41 @@ impl AnimClock {
     }
 
     fn state(&self) -> TweenState {
+        elapsed_state(self.elapsed, self.total_duration)
+    }
+
+    /// Does another iteration start after the current one?
+    fn is_looping(&self) -> bool {
         match self.total_duration {
             TotalDuration::Finite(total_duration) => {
-                if self.elapsed >= total_duration {
-                    TweenState::Completed
-                } else {
-                    TweenState::Active
-                }
+                let next = self.duration.saturating_mul(self.times_completed() + 1);
+                !self.duration.is_zero() && next < total_duration
             }
-            TotalDuration::Infinite => TweenState::Active,
+            TotalDuration::Infinite => true,
         }
     }
 
+    /// Number of times a mirrored animation changed direction, that is the
+    /// number of completions excluding the final one.
+    fn direction_changes(&self) -> u32 {
+        self.times_completed()
+            .saturating_sub(u32::from(self.state() == TweenState::Completed))
+    }
+
     fn reset(&mut self) {
         self.elapsed = Duration::ZERO;
     }
 }
 
+/// State of an animation which completes once `elapsed` reaches
+/// `total_duration`.
+fn elapsed_state(elapsed: Duration, total_duration: TotalDuration) -> TweenState {
+    match total_duration {
+        TotalDuration::Finite(total_duration) if elapsed >= total_duration => TweenState::Completed,
+        _ => TweenState::Active,
+    }
+}
+
 /// Possibly infinite duration of an animation.
 ///
 /// Used to measure the total duration of an animation including any looping.
@@ -186,6 +592,10 @@ pub enum TotalDuration {
 }
 
 fn compute_total_duration(duration: Duration, count: RepeatCount) -> TotalDuration {
+    // An instant animation completes at once, whatever its repeat count.
+    if duration.is_zero() {
+        return TotalDuration::Finite(Duration::ZERO);
+    }
     match count {
         RepeatCount::Finite(times) => TotalDuration::Finite(duration.saturating_mul(times)),
         RepeatCount::For(duration) => TotalDuration::Finite(duration),
@@ -202,15 +612,31 @@ pub trait Targetable<T> {
     /// Dereference the target, triggering any change detection, and return a
     /// mutable reference.
     fn target_mut(&mut self) -> &mut T;
+
+    /// Get the context of the target, passed to the lenses animating it.
+    ///
+    /// The default implementation returns an empty context.
+    fn context(&self) -> LensContext {
+        LensContext::default()
+    }
 }
 
 pub struct ComponentTarget<'a, T: Component> {
     target: Mut<'a, T>,
+    context: LensContext,
 }
 
 impl<'a, T: Component> ComponentTarget<'a, T> {
     pub fn new(target: Mut<'a, T>) -> Self {
-        Self { target }
+        Self {
+            target,
+            context: default(),
+        }
+    }
+
+    pub fn with_context(mut self, context: LensContext) -> Self {
+        self.context = context;
+        self
     }
 }
 
@@ -218,6 +644,111 @@ impl<'a, T: Component> Targetable<T> for ComponentTarget<'a, T> {
     fn target_mut(&mut self) -> &mut T {
         self.target.deref_mut()
     }
+
+    fn context(&self) -> LensContext {
+        self.context
+    }
+}
+
+/// Target of a component animator coalescing its writes, ticking against a
+/// scratch copy of the component written back once by [`flush()`].
+///
+/// [`flush()`]: CoalescingTarget::flush
+pub(crate) struct CoalescingTarget<'a, T: Component> {
+    target: Mut<'a, T>,
+    scratch: Option<T>,
+    coalescing: &'a WriteCoalescing<T>,
+    context: LensContext,
+}
+
+impl<'a, T: Component> CoalescingTarget<'a, T> {
+    pub fn new(target: Mut<'a, T>, coalescing: &'a WriteCoalescing<T>) -> Self {
+        Self {
+            target,
+            scratch: None,
+            coalescing,
+            context: default(),
+        }
+    }
+
+    pub fn with_context(mut self, context: LensContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Write the scratch copy back to the component if it was accessed, and
+    /// its value changed.
+    pub fn flush(mut self) {
+        if let Some(value) = self.scratch {
+            if !self.coalescing.eq(&self.target, &value) {
+                *self.target = value;
+            }
+        }
+    }
+}
+
+impl<'a, T: Component> Targetable<T> for CoalescingTarget<'a, T> {
+    fn target_mut(&mut self) -> &mut T {
+        let Self {
+            target,
+            scratch,
+            coalescing,
+            ..
+        } = self;
+        scratch.get_or_insert_with(|| coalescing.clone(target))
+    }
+
+    fn context(&self) -> LensContext {
+        self.context
+    }
+}
+
+pub struct ResourceTarget<'a, T: Resource> {
+    target: ResMut<'a, T>,
+}
+
+impl<'a, T: Resource> ResourceTarget<'a, T> {
+    pub fn new(target: ResMut<'a, T>) -> Self {
+        Self { target }
+    }
+}
+
+impl<'a, T: Resource> Targetable<T> for ResourceTarget<'a, T> {
+    fn target_mut(&mut self) -> &mut T {
+        self.target.deref_mut()
+    }
+}
+
+/// Target of the tweenable of a [`SyncedAnimator`], recording whether the
+/// tweenable changed the factor.
+///
+/// [`SyncedAnimator`]: crate::SyncedAnimator
+#[cfg(feature = "bevy_asset")]
+pub(crate) struct SyncFactorTarget<'a> {
+    factor: &'a mut crate::SyncFactor,
+    changed: bool,
+}
+
+#[cfg(feature = "bevy_asset")]
+impl<'a> SyncFactorTarget<'a> {
+    pub fn new(factor: &'a mut crate::SyncFactor) -> Self {
+        Self {
+            factor,
+            changed: false,
+        }
+    }
+
+    pub fn changed(&self) -> bool {
+        self.changed
+    }
+}
+
+#[cfg(feature = "bevy_asset")]
+impl<'a> Targetable<crate::SyncFactor> for SyncFactorTarget<'a> {
+    fn target_mut(&mut self) -> &mut crate::SyncFactor {
+        self.changed = true;
+        self.factor
+    }
 }
 
 #[cfg(feature = "bevy_asset")]
@@ -249,26 +780,70 @@ impl<'a, T: Asset> Targetable<T> for AssetTarget<'a, T> {
 
 /// An animatable entity, either a single [`Tween`] or a collection of them.
 pub trait Tweenable<T>: Send + Sync {
+    /// Get the unique identifier of the tweenable, if any.
+    ///
+    /// All the built-in tweenables have an identifier, allocated when they're
+    /// created; see [`TweenId`] for details. Wrappers like the ones recording
+    /// a tweenable report the identifier of the tweenable they wrap. The
+    /// default implementation returns `None`.
+    fn id(&self) -> Option<TweenId> {
+        None
+    }
+
     /// Get the duration of a single iteration of the animation.
     ///
     /// Note that for [`RepeatStrategy::MirroredRepeat`], this is the duration
     /// of a single way, either from start to end or back from end to start.
     /// The total "loop" duration start -> end -> start to reach back the
     /// same state in this case is the double of the returned value.
+    ///
+    /// A [`Sequence`] or [`Tracks`] doesn't repeat, so its single iteration
+    /// includes all the repeats of its children. A child repeating forever
+    /// counts for a single iteration.
     fn duration(&self) -> Duration;
 
     /// Get the total duration of the entire animation, including looping.
     ///
     /// For [`TotalDuration::Finite`], this is the number of repeats times the
-    /// duration of a single iteration ([`duration()`]).
+    /// duration of a single iteration ([`duration()`]). This is the time
+    /// after which the animation completes, to schedule something right when
+    /// it ends. It's computed recursively: the total duration of a
+    /// [`Sequence`] is the sum of the total durations of its children, and
+    /// the one of [`Tracks`] the longest of its tracks. Either is
+    /// [`TotalDuration::Infinite`] if any child repeats forever.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use std::time::Duration;
+    /// let lens = TransformScaleLens {
+    ///     start: Vec3::ONE,
+    ///     end: Vec3::splat(2.),
+    /// };
+    /// // Two ping-pong loops of 2 x 300 ms each, then a delay of 400 ms
+    /// let pulse = Tween::new(EaseFunction::QuadraticInOut, Duration::from_millis(300), lens)
+    ///     .with_repeat_count(RepeatCount::Finite(4))
+    ///     .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
+    /// let seq = pulse.then(Delay::millis(400));
+    /// assert_eq!(
+    ///     seq.total_duration(),
+    ///     TotalDuration::Finite(Duration::from_millis(1600))
+    /// );
+    /// ```
     ///
     /// [`duration()`]: Tweenable::duration
     fn total_duration(&self) -> TotalDuration;
 
     /// Set the current animation playback elapsed time.
     ///
-    /// See [`elapsed()`] for details on the meaning. If `elapsed` is greater
-    /// than or equal to [`duration()`], then the animation completes.
+    /// See [`elapsed()`] for details on the meaning. This seeks to an
+    /// absolute time, like a timestamp of a soundtrack the animation is synced
+    /// to. If `elapsed` is greater than or equal to [`total_duration()`], then
+    /// the animation completes, and its elapsed time is clamped to that
+    /// total duration. An animation repeating forever never completes, and
+    /// seeks into the iteration containing `elapsed` instead.
     ///
     /// Setting the elapsed time seeks the animation to a new position, but does
     /// not apply that change to the underlying component being animated. To
@@ -276,19 +851,71 @@ pub trait Tweenable<T>: Send + Sync {
     /// `Duration::ZERO`.
     ///
     /// [`elapsed()`]: Tweenable::elapsed
-    /// [`duration()`]: Tweenable::duration
+    /// [`total_duration()`]: Tweenable::total_duration
     /// [`tick()`]: Tweenable::tick
     fn set_elapsed(&mut self, elapsed: Duration);
 
     /// Get the current elapsed duration.
     ///
+    /// For a tweenable repeating its animation, this is the time elapsed
+    /// across all its iterations, up to its [`total_duration()`], matching
+    /// [`set_elapsed()`]. Use [`iteration_elapsed()`] to get the time elapsed
+    /// within the current iteration instead.
+    ///
+    /// [`total_duration()`]: Tweenable::total_duration
+    /// [`set_elapsed()`]: Tweenable::set_elapsed
+    /// [`iteration_elapsed()`]: Tweenable::iteration_elapsed
+    fn elapsed(&self) -> Duration;
+
+    /// Get the time elapsed within the current iteration of the animation.
+    ///
     /// While looping, the exact value returned by [`duration()`] is never
     /// reached, since the tweenable loops over to zero immediately when it
-    /// changes direction at either endpoint. Upon completion, the tweenable
-    /// always reports the same value as [`duration()`].
+    /// starts a new iteration. Upon completion, the tweenable always reports
+    /// the same value as [`duration()`]. Unlike the product of [`progress()`]
+    /// and [`duration()`], this is exact, without any float rounding.
+    ///
+    /// For a tweenable which doesn't repeat, this is the same as
+    /// [`elapsed()`].
     ///
     /// [`duration()`]: Tweenable::duration
-    fn elapsed(&self) -> Duration;
+    /// [`progress()`]: Tweenable::progress
+    /// [`elapsed()`]: Tweenable::elapsed
+    fn iteration_elapsed(&self) -> Duration {
+        let duration = self.duration();
+        let elapsed = self.elapsed();
+        if let TotalDuration::Finite(total_duration) = self.total_duration() {
+            if elapsed >= total_duration {
+                return duration;
+            }
+        }
+        if duration.is_zero() {
+            return Duration::ZERO;
+        }
+        let nanos = elapsed.as_nanos() % duration.as_nanos();
+        Duration::from_nanos(nanos as u64)
+    }
+
+    /// Get the current state of the animation, without ticking it.
+    ///
+    /// This returns [`TweenState::Completed`] if the tweenable reached its
+    /// final state, or was cancelled through its [`CancelToken`], and
+    /// [`TweenState::Active`] otherwise. The state reflects the last tick or
+    /// seek of the tweenable: for example, a [`WaitFor`] released since its
+    /// last tick is still active until ticked again. This never mutates the
+    /// tweenable nor its target, so it can be used on a tweenable sitting in a
+    /// queue or a pool, which [`tick()`] with a zero delta would apply to the
+    /// target.
+    ///
+    /// The default implementation compares the [`elapsed()`] time with the
+    /// [`total_duration()`].
+    ///
+    /// [`tick()`]: Tweenable::tick
+    /// [`elapsed()`]: Tweenable::elapsed
+    /// [`total_duration()`]: Tweenable::total_duration
+    fn state(&self) -> TweenState {
+        elapsed_state(self.elapsed(), self.total_duration())
+    }
 
     /// Tick the animation, advancing it by the given delta time and mutating
     /// the given target component or asset.
@@ -298,6 +925,19 @@ pub trait Tweenable<T>: Send + Sync {
     /// the tweenable completed this tick. Only non-looping tweenables return
     /// a completed state, since looping ones continue forever.
     ///
+    /// The [`TweenCompleted`] events raised during the tick are appended to
+    /// `events`, in the order they occurred. The animator systems collect the
+    /// events of all their animators into a single buffer reused across
+    /// frames, and send them all at once at the end of the system.
+    ///
+    /// Completion notifications, whether callbacks or events, always observe
+    /// the final state of the tweenable raising them: a tweenable applies its
+    /// end state to the target before notifying its completion. When a single
+    /// tick completes several tweenables, for example when a large delta makes
+    /// a [`Sequence`] skip over some of its children, each child is completed
+    /// in order, so the end state of a child is applied and notified before
+    /// the next child is ticked. Implementations must preserve this order.
+    ///
     /// Calling this method with a duration of [`Duration::ZERO`] is valid, and
     /// updates the target to the current state of the tweenable without
     /// actually modifying the tweenable state. This is useful after certain
@@ -311,58 +951,275 @@ pub trait Tweenable<T>: Send + Sync {
         delta: Duration,
         target: &mut dyn Targetable<T>,
         entity: Entity,
-        events: &mut Mut<Events<TweenCompleted>>,
+        events: &mut Vec<TweenCompleted>,
     ) -> TweenState;
 
-    /// Rewind the animation to its starting state.
+    /// Apply the state of the animation at the given `elapsed` time to
+    /// `target`, without modifying the tweenable.
     ///
-    /// Note that the starting state depends on the current direction. For
-    /// [`TweeningDirection::Forward`] this is the start point of the lens,
-    /// whereas for [`TweeningDirection::Backward`] this is the end one.
-    fn rewind(&mut self);
+    /// This previews the animation, for example to draw the path of an entity
+    /// before it moves, without changing the live animation: its elapsed
+    /// time, its direction, its lenses, and its completion events are left
+    /// untouched. The `elapsed` time is on the same timeline as [`elapsed()`],
+    /// and is clamped to [`total_duration()`].
+    ///
+    /// Only the fields the animation animates are written, so `target` should
+    /// be initialized with the current value of the animated component or
+    /// asset. A [`Sequence`] applies the end state of its children preceding
+    /// the one active at `elapsed`, so that children animating different
+    /// fields combine as they would when ticked.
+    ///
+    /// Returns `false` if the tweenable or any of its lenses doesn't support
+    /// sampling; see [`Lens::sample()`]. The default implementation doesn't
+    /// support sampling.
+    ///
+    /// [`elapsed()`]: Tweenable::elapsed
+    /// [`total_duration()`]: Tweenable::total_duration
+    fn sample(&self, elapsed: Duration, target: &mut T) -> bool {
+        let _ = (elapsed, target);
+        false
+    }
 
-    /// Set the current animation playback progress.
+    /// Get the current playback direction of the animation.
     ///
-    /// See [`progress()`] for details on the meaning.
+    /// Returns `None` if the tweenable doesn't support changing its direction;
+    /// see [`set_direction()`]. The default implementation doesn't support
+    /// directions.
     ///
-    /// Setting the progress seeks the animation to a new position, but does not
-    /// apply that change to the underlying component being animated. To
-    /// force the change to apply, call [`tick()`] with a `delta` of
-    /// `Duration::ZERO`.
+    /// [`set_direction()`]: Tweenable::set_direction
+    fn direction(&self) -> Option<TweeningDirection> {
+        None
+    }
+
+    /// Set the playback direction of the animation.
     ///
-    /// [`progress()`]: Tweenable::progress
-    /// [`tick()`]: Tweenable::tick
-    fn set_progress(&mut self, progress: f32) {
-        self.set_elapsed(self.duration().mul_f32(progress.max(0.)));
+    /// Like [`Tween::set_direction()`], this only changes the mapping of the
+    /// progress to the animated value, and doesn't change the progress itself
+    /// nor the target. Use [`Animator::reverse()`] to reverse an animation
+    /// while preserving its current value.
+    ///
+    /// Returns `false` if the tweenable doesn't support changing its
+    /// direction, in which case it's left unchanged. The default
+    /// implementation doesn't support directions. Only [`Tween`] supports
+    /// them among the built-in tweenables.
+    ///
+    /// [`Animator::reverse()`]: crate::Animator::reverse
+    fn set_direction(&mut self, direction: TweeningDirection) -> bool {
+        let _ = direction;
+        false
     }
 
-    /// Get the current progress in \[0:1\] of the animation.
+    /// Apply the entries of a [`TweenTuning`] resource to the tweens with a
+    /// tuning key.
     ///
-    /// While looping, the exact value `1.0` is never reached, since the
-    /// tweenable loops over to `0.0` immediately when it changes direction at
-    /// either endpoint. Upon completion, the tweenable always reports exactly
-    /// `1.0`.
-    fn progress(&self) -> f32 {
-        let elapsed = self.elapsed();
-        if let TotalDuration::Finite(total_duration) = self.total_duration() {
-            if elapsed >= total_duration {
-                return 1.;
-            }
-        }
-        fraction_progress(elapsed, self.duration())
+    /// This is called by the animator systems when the resource changes. The
+    /// default implementation does nothing. [`Tween`] applies the entry of
+    /// its key, if any, while [`Sequence`], [`Tracks`], [`Eased`], and
+    /// [`Slice`] forward it to their children. Custom tweenables wrapping
+    /// another tweenable should forward it too.
+    fn apply_tuning(&mut self, tuning: &TweenTuning) {
+        let _ = tuning;
     }
 
-    /// Get the number of times this tweenable completed.
+    /// Apply the [`TweenOverrides`] of an instance of a [`TweenPrototype`].
     ///
-    /// For looping animations, this returns the number of times a single
-    /// playback was completed. In the case of
-    /// [`RepeatStrategy::MirroredRepeat`] this corresponds to a playback in
-    /// a single direction, so tweening from start to end and back to start
-    /// counts as two completed times (one forward, one backward).
-    fn times_completed(&self) -> u32 {
-        (self.elapsed().as_nanos() / self.duration().as_nanos()) as u32
+    /// This is called by [`TweenPrototype::instantiate_with()`] on the newly
+    /// constructed tweenable. The default implementation does nothing.
+    /// [`Tween`], [`Delay`], and [`Slice`] apply the overrides to themselves,
+    /// while [`Sequence`], [`Tracks`], [`Eased`], and [`Slice`] forward them
+    /// to their children.
+    ///
+    /// [`TweenPrototype`]: crate::TweenPrototype
+    /// [`TweenPrototype::instantiate_with()`]: crate::TweenPrototype::instantiate_with
+    fn apply_overrides(&mut self, overrides: &TweenOverrides) {
+        let _ = overrides;
     }
-}
+
+    /// Get the value this tweenable leaves its target at once it completes,
+    /// for a [boundary blend] with the next child of a [`Sequence`].
+    ///
+    /// The value is type-erased, and only meaningful to a lens animating a
+    /// value of the same type; see [`Lens::boundary_value()`]. The default
+    /// implementation returns `None`, which disables the blend. [`Tween`]
+    /// returns the endpoint of its lens, while [`Sequence`] and [`Eased`]
+    /// forward to their last child.
+    ///
+    /// [boundary blend]: Sequence::with_boundary_blend
+    fn boundary_value(&self) -> Option<Box<dyn Any + Send + Sync>> {
+        None
+    }
+
+    /// Get the value this tweenable currently applies to its target, to blend
+    /// it into the start of another tweenable with [`blend_start()`].
+    ///
+    /// This is like [`boundary_value()`] at the current elapsed time, and
+    /// allows blending from an animation interrupted before it completed; see
+    /// [`Lens::value_at()`]. The default implementation returns `None`, which
+    /// disables the blend. [`Tween`] returns the value of its lens at its
+    /// current progress, while [`Sequence`] and [`Eased`] forward to their
+    /// active child.
+    ///
+    /// [`blend_start()`]: Tweenable::blend_start
+    /// [`boundary_value()`]: Tweenable::boundary_value
+    fn current_value(&self) -> Option<Box<dyn Any + Send + Sync>> {
+        None
+    }
+
+    /// Blend the start of this tweenable from the value `from` returned by
+    /// the [`boundary_value()`] of the previous child of a [`Sequence`], over
+    /// the first `window` of its playback.
+    ///
+    /// The blend lasts until the tweenable is rewound or seeked. Returns
+    /// `false` if the tweenable doesn't support blending, in which case it
+    /// starts with a hard cut. The default implementation doesn't support
+    /// blending. [`Tween`] blends its lens with [`Lens::lerp_from()`], while
+    /// [`Sequence`] and [`Eased`] forward to their first child.
+    ///
+    /// [`boundary_value()`]: Tweenable::boundary_value
+    fn blend_start(&mut self, from: Box<dyn Any + Send + Sync>, window: Duration) -> bool {
+        let _ = (from, window);
+        false
+    }
+
+    /// Append a [`TweenCancelled`] event for each [`TweenCompleted`] event
+    /// this tweenable would still raise if it played until its end.
+    ///
+    /// This is called by the animators discarding the tweenable before it
+    /// completed. The default implementation appends nothing. [`Tween`],
+    /// [`Delay`], and [`Slice`] append an event for their own user data if
+    /// they didn't complete yet, while [`Sequence`], [`Tracks`], and [`Eased`]
+    /// forward to the children which didn't complete yet.
+    fn collect_cancelled(&self, entity: Entity, cancelled: &mut Vec<TweenCancelled>) {
+        let _ = (entity, cancelled);
+    }
+
+    /// Move the [`TweenCancelled`] events raised by this tweenable and its
+    /// children when observing their [`CancelToken`] cancelled into
+    /// `cancelled`.
+    ///
+    /// This is called by the animators after ticking the tweenable. The
+    /// default implementation moves nothing. [`Tween`], [`Sequence`], and
+    /// [`Tracks`] move their own events, and tweenables wrapping other
+    /// tweenables should forward it to them.
+    fn take_token_cancelled(&mut self, cancelled: &mut Vec<TweenCancelled>) {
+        let _ = cancelled;
+    }
+
+    /// Check the invariants the playback of this tweenable relies on.
+    ///
+    /// This walks the tree of tweenables, and returns the first error found,
+    /// if any: a [`Sequence`] or [`Tracks`] without child, a child repeating
+    /// infinitely before the last child of a [`Sequence`], or a [`Tween`]
+    /// whose lens is not [valid], like a built-in lens with a NaN endpoint.
+    /// This doesn't need a [`World`], so a tweenable built on another thread,
+    /// for example in an async task, can be validated there before it's sent
+    /// to the main thread and inserted into an animator.
+    ///
+    /// The default implementation accepts any tweenable. Tweenables wrapping
+    /// other tweenables should forward it to them.
+    ///
+    /// [valid]: Lens::is_valid
+    fn validate(&self) -> Result<(), TweenValidationError> {
+        Ok(())
+    }
+
+    /// Save a snapshot of the playback state of this tweenable and its
+    /// children.
+    ///
+    /// See [`TweenableState`] for details. The default implementation returns
+    /// [`TweenableState::Unsupported`]. All the built-in tweenables support
+    /// snapshots, and tweenables wrapping other tweenables should forward it
+    /// to them.
+    fn save_state(&self) -> TweenableState {
+        TweenableState::Unsupported
+    }
+
+    /// Restore a snapshot of the playback state saved by [`save_state()`].
+    ///
+    /// The snapshot is usually restored onto a tweenable built again the same
+    /// way as the one it was saved from, in which case the next tick resumes
+    /// the animation exactly where it was saved, and applies the current
+    /// state to the target like after a seek. A boundary blend in progress
+    /// when the snapshot was saved is not restored; see
+    /// [`Sequence::with_boundary_blend()`].
+    ///
+    /// Returns `false` if the snapshot doesn't match the structure of this
+    /// tweenable, for example a [`Sequence`] with a different number of
+    /// children, or if any tweenable of the tree doesn't support snapshots.
+    /// In that case the tweenable may be partially restored, and should be
+    /// rewound or discarded. The default implementation doesn't support
+    /// snapshots.
+    ///
+    /// [`save_state()`]: Tweenable::save_state
+    fn restore_state(&mut self, state: &TweenableState) -> bool {
+        let _ = state;
+        false
+    }
+
+    /// Rewind the animation to its starting state.
+    ///
+    /// Note that the starting state depends on the current direction. For
+    /// [`TweeningDirection::Forward`] this is the start point of the lens,
+    /// whereas for [`TweeningDirection::Backward`] this is the end one.
+    fn rewind(&mut self);
+
+    /// Set the current animation playback progress.
+    ///
+    /// See [`progress()`] for details on the meaning.
+    ///
+    /// Setting the progress seeks the animation to a new position, but does not
+    /// apply that change to the underlying component being animated. To
+    /// force the change to apply, call [`tick()`] with a `delta` of
+    /// `Duration::ZERO`.
+    ///
+    /// [`progress()`]: Tweenable::progress
+    /// [`tick()`]: Tweenable::tick
+    fn set_progress(&mut self, progress: f32) {
+        self.set_elapsed(self.duration().mul_f64(f64::from(progress.max(0.))));
+    }
+
+    /// Get the current progress in \[0:1\] of the animation.
+    ///
+    /// While looping, the exact value `1.0` is never reached, since the
+    /// tweenable loops over to `0.0` immediately when it changes direction at
+    /// either endpoint. Upon completion, the tweenable always reports exactly
+    /// `1.0`, whatever the deltas it was ticked with, and after [`rewind()`]
+    /// it reports exactly `0.0`.
+    ///
+    /// [`rewind()`]: Tweenable::rewind
+    fn progress(&self) -> f32 {
+        let elapsed = self.elapsed();
+        if let TotalDuration::Finite(total_duration) = self.total_duration() {
+            if elapsed >= total_duration {
+                return 1.;
+            }
+        }
+        fraction_progress(elapsed, self.duration())
+    }
+
+    /// Get the number of times this tweenable completed.
+    ///
+    /// For looping animations, this returns the number of times a single
+    /// playback was completed. In the case of
+    /// [`RepeatStrategy::MirroredRepeat`] this corresponds to a playback in
+    /// a single direction, so tweening from start to end and back to start
+    /// counts as two completed times (one forward, one backward).
+    ///
+    /// For composite tweenables like [`Sequence`], [`Tracks`], and [`Eased`],
+    /// this is the number of full passes of the composite itself, independently
+    /// of how many times its children completed. Like for a [`Tween`], the
+    /// value is derived from the elapsed time, so seeking with
+    /// [`set_elapsed()`] or [`set_progress()`] updates it accordingly, and
+    /// [`rewind()`] resets it to zero. For example, seeking a completed
+    /// sequence back to a progress of `0.7` resets this to zero.
+    ///
+    /// [`set_elapsed()`]: Tweenable::set_elapsed
+    /// [`set_progress()`]: Tweenable::set_progress
+    /// [`rewind()`]: Tweenable::rewind
+    fn times_completed(&self) -> u32 {
+        (self.elapsed().as_nanos() / self.duration().as_nanos()) as u32
+    }
+}
 
 macro_rules! impl_boxed {
     ($tweenable:ty) => {
@@ -378,20 +1235,145 @@ impl_boxed!(Tween<T>);
 impl_boxed!(Sequence<T>);
 impl_boxed!(Tracks<T>);
 impl_boxed!(Delay<T>);
+impl_boxed!(Eased<T>);
+impl_boxed!(Slice<T>);
+impl_boxed!(WaitFor<T>);
+
+/// Implement the conversion of a tuple of tweenables of different types into
+/// a [`Sequence`] and a [`Tracks`]. Tuples always have at least one element,
+/// so these conversions never fail.
+macro_rules! impl_from_tuple {
+    ($($item:ident $var:ident),+) => {
+        impl<T: 'static, $($item: Into<BoxedTweenable<T>>),+> From<($($item,)+)> for Sequence<T> {
+            fn from(($($var,)+): ($($item,)+)) -> Self {
+                Self::new([$($var.into()),+])
+            }
+        }
+
+        impl<T: 'static, $($item: Into<BoxedTweenable<T>>),+> From<($($item,)+)> for Tracks<T> {
+            fn from(($($var,)+): ($($item,)+)) -> Self {
+                Self::new([$($var.into()),+])
+            }
+        }
+    };
+}
+
+impl_from_tuple!(A a);
+impl_from_tuple!(A a, B b);
+impl_from_tuple!(A a, B b, C c);
+impl_from_tuple!(A a, B b, C c, D d);
 
 /// Type of a callback invoked when a [`Tween`] or [`Delay`] has completed.
 ///
 /// See [`Tween::set_completed()`] or [`Delay::set_completed()`] for usage.
 pub type CompletedCallback<T> = dyn Fn(Entity, &T) + Send + Sync + 'static;
 
+/// Type of a callback invoked when a [`Tween`] has completed, receiving the
+/// final value of its target.
+///
+/// See [`Tween::set_completed_with_target()`] for usage.
+pub type CompletedWithTargetCallback<T> = dyn Fn(Entity, &Tween<T>, &T) + Send + Sync + 'static;
+
+/// Type of a callback invoked each time a [`Tween`] or an [`Animator`] writes
+/// to its target, receiving the new value of the target.
+///
+/// See [`Tween::set_on_update()`] or [`Animator::set_on_update()`] for usage.
+///
+/// [`Animator`]: crate::Animator
+/// [`Animator::set_on_update()`]: crate::Animator::set_on_update
+pub type UpdateCallback<T> = dyn Fn(Entity, &T) + Send + Sync + 'static;
+
+/// A [`Lens`] owned by a [`Tween`], which can be downcast to its concrete type.
+pub(crate) trait AnyLens<T>: Lens<T> + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T, L: Lens<T> + Send + Sync + 'static> AnyLens<T> for L {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// The boxed lens of a [`Tween`].
+pub(crate) type BoxedLens<T> = Box<dyn AnyLens<T> + 'static>;
+
+/// Blend of the start of a tween from the value the previous child of a
+/// [`Sequence`] left the target at.
+struct StartBlend {
+    from: Box<dyn Any + Send + Sync>,
+    window: Duration,
+}
+
 /// Single tweening animation instance.
 pub struct Tween<T> {
+    id: TweenId,
     ease_function: EaseMethod,
     clock: AnimClock,
     direction: TweeningDirection,
-    lens: Box<dyn Lens<T> + Send + Sync + 'static>,
+    lens: BoxedLens<T>,
     on_completed: Option<Box<CompletedCallback<Tween<T>>>>,
+    on_completed_with_target: Option<Box<CompletedWithTargetCallback<T>>>,
+    on_update: Option<Box<UpdateCallback<T>>>,
     event_data: Option<u64>,
+    notify_final_only: bool,
+    needs_apply: bool,
+    min_step: Duration,
+    pending_step: Option<Duration>,
+    total_elapsed: Duration,
+    elapsed_triggers: Vec<ElapsedTrigger>,
+    completed_behavior: CompletedBehavior,
+    auto_rewind: bool,
+    cancellation: Cancellation,
+    tuning_key: Option<TuningKey>,
+    speed: Option<TweenSpeed<T>>,
+    start_blend: Option<StartBlend>,
+}
+
+impl<T> std::fmt::Debug for Tween<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tween")
+            .field("id", &self.id)
+            .field("duration", &self.clock.duration)
+            .field("elapsed", &self.clock.elapsed)
+            .field("direction", &self.direction)
+            .field("event_data", &self.event_data)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Speed of a [`Tween`] created with [`Tween::new_with_speed()`].
+struct TweenSpeed<T> {
+    /// Speed in units of the lens path per second.
+    speed: f32,
+    /// Get the arc length of the lens of the tween.
+    arc_length: fn(&dyn AnyLens<T>) -> f32,
+    /// Whether the duration was measured again on the first tick.
+    measured: bool,
+}
+
+/// Get the arc length of a lens of type `L`.
+fn lens_arc_length<T, L: PathLens + 'static>(lens: &dyn AnyLens<T>) -> f32 {
+    lens.as_any().downcast_ref::<L>().map_or(0., L::arc_length)
+}
+
+/// Duration to travel a distance at a given speed.
+fn duration_at_speed(distance: f32, speed: f32) -> Duration {
+    Duration::try_from_secs_f32(distance / speed).unwrap_or(Duration::ZERO)
+}
+
+/// An event raised by a [`Tween`] once it played for some time.
+struct ElapsedTrigger {
+    /// Total elapsed time the event is raised at.
+    elapsed: Duration,
+    /// User data of the event.
+    user_data: u64,
+    /// Whether the event was raised since the tween was last rewound.
+    fired: bool,
 }
 
 impl<T: 'static> Tween<T> {
@@ -402,10 +1384,9 @@ impl<T: 'static> Tween<T> {
     /// ```
     /// # use bevy_tweening::{lens::*, *};
     /// # use bevy::math::*;
-    /// # use std::time::Duration;
     /// let tween1 = Tween::new(
     ///     EaseFunction::QuadraticInOut,
-    ///     Duration::from_secs(1),
+    ///     1.,
     ///     TransformPositionLens {
     ///         start: Vec3::ZERO,
     ///         end: Vec3::new(3.5, 0., 0.),
@@ -413,7 +1394,7 @@ impl<T: 'static> Tween<T> {
     /// );
     /// let tween2 = Tween::new(
     ///     EaseFunction::QuadraticInOut,
-    ///     Duration::from_secs(1),
+    ///     1.,
     ///     TransformRotationLens {
     ///         start: Quat::IDENTITY,
     ///         end: Quat::from_rotation_x(90.0_f32.to_radians()),
@@ -425,19 +1406,58 @@ impl<T: 'static> Tween<T> {
     pub fn then(self, tween: impl Tweenable<T> + 'static) -> Sequence<T> {
         Sequence::with_capacity(2).then(self).then(tween)
     }
+
+    /// Chain another [`Tweenable`] before this tween, making a [`Sequence`]
+    /// which plays `tween` first, then this tween.
+    ///
+    /// This is the reverse of [`then()`], to build a sequence starting from
+    /// its last step. See also [`Sequence::prepend()`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::math::Vec3;
+    /// let fade = Tween::new(
+    ///     EaseFunction::QuadraticIn,
+    ///     1.,
+    ///     TransformScaleLens {
+    ///         start: Vec3::ONE,
+    ///         end: Vec3::ZERO,
+    ///     },
+    /// );
+    /// let slide = Tween::new(
+    ///     EaseFunction::QuadraticOut,
+    ///     1.,
+    ///     TransformPositionLens {
+    ///         start: Vec3::ZERO,
+    ///         end: Vec3::X,
+    ///     },
+    /// );
+    /// // Slide, then fade
+    /// let seq = fade.before(slide);
+    /// ```
+    ///
+    /// [`then()`]: Tween::then
+    #[must_use]
+    pub fn before(self, tween: impl Tweenable<T> + 'static) -> Sequence<T> {
+        Sequence::with_capacity(2).then(tween).then(self)
+    }
 }
 
 impl<T> Tween<T> {
     /// Create a new tween animation.
     ///
+    /// The duration is anything convertible with [`IntoDuration`]: a
+    /// [`Duration`], a number of seconds as `f32`, or a number of
+    /// milliseconds as `u64`.
+    ///
     /// # Example
     /// ```
     /// # use bevy_tweening::{lens::*, *};
     /// # use bevy::math::Vec3;
-    /// # use std::time::Duration;
     /// let tween = Tween::new(
     ///     EaseFunction::QuadraticInOut,
-    ///     Duration::from_secs(1),
+    ///     1.,
     ///     TransformPositionLens {
     ///         start: Vec3::ZERO,
     ///         end: Vec3::new(3.5, 0., 0.),
@@ -445,17 +1465,231 @@ impl<T> Tween<T> {
     /// );
     /// ```
     #[must_use]
-    pub fn new<L>(ease_function: impl Into<EaseMethod>, duration: Duration, lens: L) -> Self
+    pub fn new<L>(
+        ease_function: impl Into<EaseMethod>,
+        duration: impl IntoDuration,
+        lens: L,
+    ) -> Self
+    where
+        L: Lens<T> + Send + Sync + 'static,
+    {
+        Self::from_boxed_lens(
+            ease_function.into(),
+            duration.into_duration(),
+            Box::new(lens),
+        )
+    }
+
+    /// Create a new tween animation lasting `secs` seconds.
+    ///
+    /// A negative or non-finite duration is replaced by zero, with a
+    /// warning. See [`new()`] for details.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::math::Vec3;
+    /// # use std::time::Duration;
+    /// let tween = Tween::new_secs(
+    ///     EaseFunction::QuadraticOut,
+    ///     0.25,
+    ///     TransformPositionLens {
+    ///         start: Vec3::ZERO,
+    ///         end: Vec3::X,
+    ///     },
+    /// );
+    /// assert_eq!(tween.duration(), Duration::from_millis(250));
+    /// ```
+    ///
+    /// [`new()`]: Tween::new
+    #[must_use]
+    pub fn new_secs<L>(ease_function: impl Into<EaseMethod>, secs: f32, lens: L) -> Self
+    where
+        L: Lens<T> + Send + Sync + 'static,
+    {
+        Self::new(ease_function, secs, lens)
+    }
+
+    /// Create a new tween animation lasting `millis` milliseconds.
+    ///
+    /// See [`new()`] for details.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::math::Vec3;
+    /// # use std::time::Duration;
+    /// let tween = Tween::new_millis(
+    ///     EaseFunction::QuadraticOut,
+    ///     150,
+    ///     TransformPositionLens {
+    ///         start: Vec3::ZERO,
+    ///         end: Vec3::X,
+    ///     },
+    /// );
+    /// assert_eq!(tween.duration(), Duration::from_millis(150));
+    /// ```
+    ///
+    /// [`new()`]: Tween::new
+    #[must_use]
+    pub fn new_millis<L>(ease_function: impl Into<EaseMethod>, millis: u64, lens: L) -> Self
     where
         L: Lens<T> + Send + Sync + 'static,
     {
+        Self::new(ease_function, Duration::from_millis(millis), lens)
+    }
+
+    /// Create a new tween animation from an already boxed lens.
+    pub(crate) fn from_boxed_lens(
+        ease_function: EaseMethod,
+        duration: Duration,
+        lens: BoxedLens<T>,
+    ) -> Self {
         Self {
-            ease_function: ease_function.into(),
+            id: TweenId::allocate(),
+            ease_function,
             clock: AnimClock::new(duration),
             direction: TweeningDirection::Forward,
-            lens: Box::new(lens),
+            lens,
             on_completed: None,
+            on_completed_with_target: None,
+            on_update: None,
             event_data: None,
+            notify_final_only: false,
+            needs_apply: false,
+            min_step: Duration::ZERO,
+            pending_step: None,
+            total_elapsed: Duration::ZERO,
+            elapsed_triggers: vec![],
+            completed_behavior: CompletedBehavior::HoldEnd,
+            auto_rewind: false,
+            cancellation: Cancellation::default(),
+            tuning_key: None,
+            speed: None,
+            start_blend: None,
+        }
+    }
+
+    /// Create a new tween animation moving at a constant speed along the
+    /// path of its lens, instead of for a fixed duration.
+    ///
+    /// The duration of the tween is the [arc length] of the lens divided by
+    /// `speed`, in units of the lens path per second, so the same tween
+    /// definition moves at the same speed whatever the distance to travel.
+    /// The easing function still applies within that duration. A lens with a
+    /// zero length produces a zero-duration tween, which jumps to its end on
+    /// its first tick and completes.
+    ///
+    /// The duration is measured again on the first tick of the tween, after
+    /// the lens was applied once at its start. This accounts for any change
+    /// made to the lens with [`lens_mut()`] since the tween was created, and
+    /// for lenses capturing their start value from the target on first use,
+    /// like an [`OptionLens`] with [`MissingValuePolicy::TakeCurrentAsStart`].
+    /// Setting an explicit duration with [`set_duration()`] cancels that.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `speed` is not strictly positive and finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::math::Vec3;
+    /// # use std::time::Duration;
+    /// let tween = Tween::new_with_speed(
+    ///     EaseFunction::QuadraticInOut,
+    ///     2.,
+    ///     TransformPositionLens {
+    ///         start: Vec3::ZERO,
+    ///         end: Vec3::new(3., 0., 0.),
+    ///     },
+    /// );
+    /// assert_eq!(tween.duration(), Duration::from_millis(1500));
+    /// ```
+    ///
+    /// [arc length]: PathLens::arc_length
+    /// [`lens_mut()`]: Tween::lens_mut
+    /// [`OptionLens`]: crate::lens::OptionLens
+    /// [`MissingValuePolicy::TakeCurrentAsStart`]: crate::lens::MissingValuePolicy::TakeCurrentAsStart
+    /// [`set_duration()`]: Tween::set_duration
+    #[must_use]
+    pub fn new_with_speed<L>(ease_function: impl Into<EaseMethod>, speed: f32, lens: L) -> Self
+    where
+        L: Lens<T> + PathLens + Send + Sync + 'static,
+    {
+        assert!(
+            speed > 0. && speed.is_finite(),
+            "invalid tween speed {speed}"
+        );
+        let duration = duration_at_speed(lens.arc_length(), speed);
+        let mut tween = Self::new(ease_function, duration, lens);
+        tween.speed = Some(TweenSpeed {
+            speed,
+            arc_length: lens_arc_length::<T, L>,
+            measured: false,
+        });
+        tween
+    }
+
+    /// Create a new [`TweenBuilder`] to build a tween with validation.
+    ///
+    /// This is the recommended way to construct a tween from data or scripts,
+    /// where the various options are not known at compile time. See
+    /// [`TweenBuilder`] for details.
+    ///
+    /// [`TweenBuilder`]: crate::TweenBuilder
+    #[must_use]
+    pub fn builder() -> TweenBuilder<T> {
+        TweenBuilder::new()
+    }
+
+    /// Get the lens of the tween, if it's of type `L`.
+    ///
+    /// Returns `None` if the tween animates its target with a lens of another
+    /// type.
+    #[must_use]
+    pub fn lens<L: 'static>(&self) -> Option<&L> {
+        self.lens.as_any().downcast_ref()
+    }
+
+    /// Get mutable access to the lens of the tween, if it's of type `L`.
+    ///
+    /// Returns `None` if the tween animates its target with a lens of another
+    /// type. Modifying the lens takes effect on the next tick.
+    pub fn lens_mut<L: 'static>(&mut self) -> Option<&mut L> {
+        self.lens.as_any_mut().downcast_mut()
+    }
+
+    /// Apply a closure to the lens of the tween, if it's of type `L`.
+    ///
+    /// This is typically used to retarget a running tween by changing its
+    /// endpoints through [`RangedLens`]. Returns `true` if the lens is of type
+    /// `L` and the closure was invoked, or `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_tweening::{lens::*, *};
+    /// let mut tween = Tween::new(
+    ///     EaseFunction::QuadraticInOut,
+    ///     1.,
+    ///     TransformScaleLens {
+    ///         start: Vec3::ONE,
+    ///         end: Vec3::splat(2.),
+    ///     },
+    /// );
+    /// assert!(tween.map_lens(|lens: &mut TransformScaleLens| lens.set_end(Vec3::splat(3.))));
+    /// assert!(!tween.map_lens(|lens: &mut TransformPositionLens| lens.set_end(Vec3::X)));
+    /// ```
+    pub fn map_lens<L: RangedLens + 'static>(&mut self, f: impl FnOnce(&mut L)) -> bool {
+        match self.lens_mut::<L>() {
+            Some(lens) => {
+                f(lens);
+                true
+            }
+            None => false,
         }
     }
 
@@ -470,11 +1704,10 @@ impl<T> Tween<T> {
     /// ```
     /// # use bevy_tweening::{lens::*, *};
     /// # use bevy::{ecs::event::EventReader, math::Vec3};
-    /// # use std::time::Duration;
     /// let tween = Tween::new(
     ///     // [...]
     /// #    EaseFunction::QuadraticInOut,
-    /// #    Duration::from_secs(1),
+    /// #    1.,
     /// #    TransformPositionLens {
     /// #        start: Vec3::ZERO,
     /// #        end: Vec3::new(3.5, 0., 0.),
@@ -504,18 +1737,19 @@ impl<T> Tween<T> {
     /// current [`Tween`]. This is similar to [`with_completed_event()`], but
     /// with a callback instead.
     ///
-    /// Only non-looping tweenables can complete.
+    /// Only non-looping tweenables can complete. The entity may be despawned
+    /// before any command queued by the callback is applied; see
+    /// [despawned entities](TweenCompleted#despawned-entities).
     ///
     /// # Example
     ///
     /// ```
     /// # use bevy_tweening::{lens::*, *};
     /// # use bevy::{ecs::event::EventReader, math::Vec3};
-    /// # use std::time::Duration;
     /// let tween = Tween::new(
     ///     // [...]
     /// #    EaseFunction::QuadraticInOut,
-    /// #    Duration::from_secs(1),
+    /// #    1.,
     /// #    TransformPositionLens {
     /// #        start: Vec3::ZERO,
     /// #        end: Vec3::new(3.5, 0., 0.),
@@ -569,10 +1803,41 @@ impl<T> Tween<T> {
         self.direction
     }
 
+    /// Reverse the playback direction of the tween, without changing the
+    /// value it currently applies to its target.
+    ///
+    /// Unlike [`Tween::set_direction()`], this also mirrors the position
+    /// within the current iteration, so toggling the direction mid-animation
+    /// plays the tween back from where it is instead of jumping to the
+    /// mirrored value. For example flipping a tween at 30% of its progress
+    /// continues at 70% of its progress in the other direction. A completed
+    /// tween is moved back to the start of its last iteration, so flipping
+    /// it plays that iteration back from end to start.
+    ///
+    /// Like [`Tweenable::set_elapsed()`], the change is applied to the target
+    /// on the next tick.
+    pub fn flip_direction(&mut self) {
+        self.direction = !self.direction;
+        let duration = self.clock.duration;
+        if duration.is_zero() {
+            self.needs_apply = true;
+            return;
+        }
+        let elapsed = self.clock.elapsed();
+        let mut iteration_start = duration.saturating_mul(self.clock.times_completed());
+        if iteration_start == elapsed && !elapsed.is_zero() {
+            // At the end of an iteration, which is also the end of the tween
+            // once it completed
+            iteration_start -= duration;
+        }
+        let within = elapsed - iteration_start;
+        self.set_elapsed(iteration_start + (duration - within));
+    }
+
     /// Set the number of times to repeat the animation.
     #[must_use]
     pub fn with_repeat_count(mut self, count: impl Into<RepeatCount>) -> Self {
-        self.clock.total_duration = compute_total_duration(self.clock.duration, count.into());
+        self.clock.set_repeat_count(count.into());
         self
     }
 
@@ -583,499 +1848,599 @@ impl<T> Tween<T> {
         self
     }
 
-    /// Set a callback invoked when the animation completes.
+    /// Set both the repeat count and the repeat strategy of the animation.
     ///
-    /// The callback when invoked receives as parameters the [`Entity`] on which
-    /// the target and the animator are, as well as a reference to the
-    /// current [`Tween`].
+    /// This accepts a [`RepeatCount`] alone, a `(RepeatCount, RepeatStrategy)`
+    /// pair, or a deprecated [`TweeningType`]; see [`RepeatSpec`].
     ///
-    /// Only non-looping tweenables can complete.
-    pub fn set_completed<C>(&mut self, callback: C)
-    where
-        C: Fn(Entity, &Self) + Send + Sync + 'static,
-    {
-        self.on_completed = Some(Box::new(callback));
+    /// [`TweeningType`]: crate::TweeningType
+    #[must_use]
+    pub fn with_repeat(self, repeat: impl Into<RepeatSpec>) -> Self {
+        let repeat = repeat.into();
+        self.with_repeat_count(repeat.count)
+            .with_repeat_strategy(repeat.strategy)
     }
 
-    /// Clear the callback invoked when the animation completes.
-    ///
-    /// See also [`set_completed()`].
-    ///
-    /// [`set_completed()`]: Tween::set_completed
-    pub fn clear_completed(&mut self) {
-        self.on_completed = None;
+    /// The number of times the animation plays.
+    #[must_use]
+    pub fn repeat_count(&self) -> RepeatCount {
+        self.clock.repeat_count
     }
 
-    /// Enable or disable raising a completed event.
-    ///
-    /// If enabled, the tween will raise a [`TweenCompleted`] event when the
-    /// animation completed. This is similar to the [`set_completed()`]
-    /// callback, but uses Bevy events instead.
+    /// The number of iterations left to play, including the current one.
     ///
-    /// See [`with_completed_event()`] for details.
+    /// For a tween playing 3 times, this is `Some(3)` until the first
+    /// iteration completes, then decreases on each completed iteration, down
+    /// to `Some(1)` while playing the final iteration, and `Some(0)` once the
+    /// tween completed. A tween repeating for a [`RepeatCount::For`] duration
+    /// counts its last partial iteration as a full one. A tween repeating
+    /// forever returns `None`.
     ///
-    /// [`set_completed()`]: Tween::set_completed
-    /// [`with_completed_event()`]: Tween::with_completed_event
-    pub fn set_completed_event(&mut self, user_data: u64) {
-        self.event_data = Some(user_data);
+    /// The value is updated on the tick an iteration completes, before the
+    /// completion callbacks are invoked, and is also reported by the
+    /// [`TweenCompleted`] event of that tick, including when a large delta
+    /// completed several iterations at once.
+    #[must_use]
+    pub fn remaining_repeats(&self) -> Option<u32> {
+        let TotalDuration::Finite(total_duration) = self.clock.total_duration else {
+            return None;
+        };
+        if self.state() == TweenState::Completed {
+            return Some(0);
+        }
+        let duration = self.clock.duration.as_nanos();
+        let iterations = if duration == 0 {
+            1
+        } else {
+            total_duration.as_nanos().div_ceil(duration) as u32
+        };
+        Some(iterations.saturating_sub(self.times_completed()))
     }
 
-    /// Clear the event sent when the animation completes.
+    /// Check if the tween is playing the final iteration of its animation.
     ///
-    /// See also [`set_completed_event()`].
+    /// This is `true` while [`remaining_repeats()`] is `Some(1)`, and `false`
+    /// before it, after the tween completed, and for a tween repeating
+    /// forever.
     ///
-    /// [`set_completed_event()`]: Tween::set_completed_event
-    pub fn clear_completed_event(&mut self) {
-        self.event_data = None;
+    /// [`remaining_repeats()`]: Tween::remaining_repeats
+    #[must_use]
+    pub fn is_final_cycle(&self) -> bool {
+        self.remaining_repeats() == Some(1)
     }
-}
 
-impl<T> Tweenable<T> for Tween<T> {
-    fn duration(&self) -> Duration {
-        self.clock.duration
+    /// Check if the animation repeats again after its current iteration.
+    ///
+    /// This is `true` for a tween repeating forever, and for a tween with a
+    /// finite [`RepeatCount`] while repeats remain. It becomes `false` once
+    /// the last iteration started, and stays so after the tween completed,
+    /// until it's rewound or seeked back.
+    #[must_use]
+    pub fn is_looping(&self) -> bool {
+        self.clock.is_looping()
     }
 
-    fn total_duration(&self) -> TotalDuration {
-        self.clock.total_duration
+    /// Play the tween to its end state and back to its start state once, then
+    /// complete.
+    ///
+    /// This is a shortcut for a [`RepeatCount::Finite(2)`] repeat count with
+    /// the [`RepeatStrategy::MirroredRepeat`] strategy, except that the
+    /// completion event and callback are only raised once, when the tween
+    /// returns to its start state and completes. The [`total_duration()`] is
+    /// twice the duration of a single leg. On completion, the lens is applied
+    /// with the exact ratio of the endpoint the tween started from (`0.`, or
+    /// `1.` when playing [`TweeningDirection::Backward`]), bypassing the
+    /// easing function, so the target returns exactly to its original value. This is well suited for one-shot effects like a punch
+    /// scale, a flash, or a nudge; see [`recipes::punch()`].
+    ///
+    /// Setting another repeat count or strategy afterwards keeps raising the
+    /// completion event only once, on the final completion.
+    ///
+    /// [`RepeatCount::Finite(2)`]: RepeatCount::Finite
+    /// [`total_duration()`]: Tweenable::total_duration
+    /// [`recipes::punch()`]: crate::recipes::punch
+    #[must_use]
+    pub fn ping_pong_once(mut self) -> Self {
+        self.notify_final_only = true;
+        self.with_repeat_count(2)
+            .with_repeat_strategy(RepeatStrategy::MirroredRepeat)
     }
 
-    fn set_elapsed(&mut self, elapsed: Duration) {
-        self.clock.set_elapsed(elapsed);
+    /// Set the minimum amount of animation time between two applications of
+    /// the lens to the target.
+    ///
+    /// By default the lens is applied on each tick, which for very long tweens
+    /// mutates the target every frame by a change too small to be visible,
+    /// triggering change detection and the systems depending on it for
+    /// nothing. With a minimum step, ticks are accumulated and the lens is
+    /// only applied once at least `min_step` of animation time elapsed since
+    /// it was last applied; the target is left untouched by the other ticks.
+    ///
+    /// The tween clock still advances precisely on each tick, so the
+    /// [`progress()`] and [`elapsed()`] of the tween reflect the actual time,
+    /// and its completion isn't delayed. The lens is always applied on the
+    /// first tick, on the tick the tween completes an iteration, and after it
+    /// was seeked, so the target starts from and ends on the exact values of
+    /// the animation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::math::Vec3;
+    /// # use std::time::Duration;
+    /// // Raise the water level over 5 minutes, 10 times per second
+    /// let tween = Tween::new(
+    ///     EaseMethod::Linear,
+    ///     300.,
+    ///     TransformPositionLens {
+    ///         start: Vec3::ZERO,
+    ///         end: Vec3::new(0., 2., 0.),
+    ///     },
+    /// )
+    /// .with_min_step(Duration::from_millis(100));
+    /// ```
+    ///
+    /// [`progress()`]: Tweenable::progress
+    /// [`elapsed()`]: Tweenable::elapsed
+    #[must_use]
+    pub fn with_min_step(mut self, min_step: Duration) -> Self {
+        self.min_step = min_step;
+        self
     }
 
-    fn elapsed(&self) -> Duration {
-        self.clock.elapsed()
+    /// Set the minimum amount of animation time between two applications of
+    /// the lens to the target.
+    ///
+    /// See [`with_min_step()`] for details.
+    ///
+    /// [`with_min_step()`]: Tween::with_min_step
+    pub fn set_min_step(&mut self, min_step: Duration) {
+        self.min_step = min_step;
     }
 
-    fn tick(
-        &mut self,
-        delta: Duration,
-        target: &mut dyn Targetable<T>,
-        entity: Entity,
-        events: &mut Mut<Events<TweenCompleted>>,
-    ) -> TweenState {
-        if self.clock.state() == TweenState::Completed {
-            return TweenState::Completed;
-        }
-
-        // Tick the animation clock
-        let (state, times_completed) = self.clock.tick(delta);
-        let (progress, times_completed_for_direction) = match state {
-            TweenState::Active => (self.progress(), times_completed),
-            TweenState::Completed => (1., times_completed.max(1) - 1), // ignore last
-        };
-        if self.clock.strategy == RepeatStrategy::MirroredRepeat
-            && times_completed_for_direction & 1 != 0
-        {
-            self.direction = !self.direction;
-        }
-
-        // Apply the lens, even if the animation finished, to ensure the state is
-        // consistent
-        let mut factor = progress;
-        if self.direction.is_backward() {
-            factor = 1. - factor;
-        }
-        let factor = self.ease_function.sample(factor);
-        let target = target.target_mut();
-        self.lens.lerp(target, factor);
-
-        // If completed at least once this frame, notify the user
-        if times_completed > 0 {
-            if let Some(user_data) = &self.event_data {
-                events.send(TweenCompleted {
-                    entity,
-                    user_data: *user_data,
-                });
-            }
-            if let Some(cb) = &self.on_completed {
-                cb(entity, self);
-            }
-        }
+    /// The minimum amount of animation time between two applications of the
+    /// lens to the target, or [`Duration::ZERO`] if the lens is applied on
+    /// each tick.
+    #[must_use]
+    pub fn min_step(&self) -> Duration {
+        self.min_step
+    }
 
-        state
+    /// Set what the tween leaves its target at once it completes.
+    ///
+    /// By default the target holds the end value. With
+    /// [`CompletedBehavior::SnapToStart`], the start value is applied again
+    /// on the tick the tween completes, right after raising its completion
+    /// event and invoking its completion callbacks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::prelude::*;
+    /// let press = Tween::new_millis(
+    ///     EaseFunction::QuadraticOut,
+    ///     150,
+    ///     TransformScaleLens {
+    ///         start: Vec3::ONE,
+    ///         end: Vec3::splat(0.9),
+    ///     },
+    /// )
+    /// .with_completed_behavior(CompletedBehavior::SnapToStart);
+    /// ```
+    #[must_use]
+    pub fn with_completed_behavior(mut self, behavior: CompletedBehavior) -> Self {
+        self.completed_behavior = behavior;
+        self
     }
 
-    fn rewind(&mut self) {
-        if self.clock.strategy == RepeatStrategy::MirroredRepeat {
-            // In mirrored mode, direction alternates each loop. To reset to the original
-            // direction on Tween creation, we count the number of completions, ignoring the
-            // last one if the Tween is currently in TweenState::Completed because that one
-            // freezes all parameters.
-            let mut times_completed = self.clock.times_completed();
-            if self.clock.state() == TweenState::Completed {
-                debug_assert!(times_completed > 0);
-                times_completed -= 1;
-            }
-            if times_completed & 1 != 0 {
-                self.direction = !self.direction;
-            }
-        }
-        self.clock.reset();
+    /// Set what the tween leaves its target at once it completes.
+    ///
+    /// See [`with_completed_behavior()`] for details.
+    ///
+    /// [`with_completed_behavior()`]: Tween::with_completed_behavior
+    pub fn set_completed_behavior(&mut self, behavior: CompletedBehavior) {
+        self.completed_behavior = behavior;
     }
-}
 
-/// A sequence of tweens played back in order one after the other.
-pub struct Sequence<T> {
-    tweens: Vec<BoxedTweenable<T>>,
-    index: usize,
-    duration: Duration,
-    elapsed: Duration,
-}
+    /// What the tween leaves its target at once it completes.
+    #[must_use]
+    pub fn completed_behavior(&self) -> CompletedBehavior {
+        self.completed_behavior
+    }
 
-impl<T> Sequence<T> {
-    /// Create a new sequence of tweens.
+    /// Enable or disable rewinding the tween as soon as it completes.
+    ///
+    /// When enabled, the tick the tween completes still applies its end value
+    /// (or its start value with [`CompletedBehavior::SnapToStart`]), raises
+    /// its completion event, invokes its completion callbacks, and returns
+    /// [`TweenState::Completed`], so the [`Animator`] playing it applies its
+    /// completion policies as usual. The tween is then immediately
+    /// [rewound], ready to be played again from the start, without a separate
+    /// call to [`rewind()`]. This is useful to keep a tween for later reuse,
+    /// for example when recycling pooled entities with
+    /// [`Animator::take_tweenable()`].
+    ///
+    /// Like [`rewind()`], this resets the number of times the tween completed
+    /// returned by [`times_completed()`]; read it from the completion
+    /// callbacks if needed, which are invoked before the rewind. An animator
+    /// still playing an auto-rewound tween plays it again from the start on
+    /// its next tick. A tween repeating forever never completes, so this has
+    /// no effect on it.
     ///
-    /// This method panics if the input collection is empty.
+    /// This is meant for a tween played on its own by an animator. Inside a
+    /// [`Sequence`] or [`Tracks`], the parent doesn't expect its completed
+    /// children to restart, so leave this disabled there.
+    ///
+    /// [`Animator`]: crate::Animator
+    /// [`Animator::take_tweenable()`]: crate::Animator::take_tweenable
+    /// [rewound]: Tweenable::rewind
+    /// [`rewind()`]: Tweenable::rewind
+    /// [`times_completed()`]: Tweenable::times_completed
     #[must_use]
-    pub fn new(items: impl IntoIterator<Item = impl Into<BoxedTweenable<T>>>) -> Self {
-        let tweens: Vec<_> = items.into_iter().map(Into::into).collect();
-        assert!(!tweens.is_empty());
-        let duration = tweens
-            .iter()
-            .map(AsRef::as_ref)
-            .map(Tweenable::duration)
-            .sum();
-        Self {
-            tweens,
-            index: 0,
-            duration,
-            elapsed: Duration::ZERO,
-        }
+    pub fn with_auto_rewind_on_completion(mut self, auto_rewind: bool) -> Self {
+        self.auto_rewind = auto_rewind;
+        self
     }
 
-    /// Create a new sequence containing a single tween.
-    #[must_use]
-    pub fn from_single(tween: impl Tweenable<T> + 'static) -> Self {
-        let duration = tween.duration();
-        let boxed: BoxedTweenable<T> = Box::new(tween);
-        Self {
-            tweens: vec![boxed],
-            index: 0,
-            duration,
-            elapsed: Duration::ZERO,
-        }
+    /// Enable or disable rewinding the tween as soon as it completes.
+    ///
+    /// See [`with_auto_rewind_on_completion()`] for details.
+    ///
+    /// [`with_auto_rewind_on_completion()`]: Tween::with_auto_rewind_on_completion
+    pub fn set_auto_rewind_on_completion(&mut self, auto_rewind: bool) {
+        self.auto_rewind = auto_rewind;
     }
 
-    /// Create a new sequence with the specified capacity.
+    /// Whether the tween is rewound as soon as it completes.
     #[must_use]
-    pub fn with_capacity(capacity: usize) -> Self {
-        Self {
-            tweens: Vec::with_capacity(capacity),
-            index: 0,
-            duration: Duration::ZERO,
-            elapsed: Duration::ZERO,
-        }
+    pub fn auto_rewind_on_completion(&self) -> bool {
+        self.auto_rewind
     }
 
-    /// Append a [`Tweenable`] to this sequence.
+    /// Set a token to cancel the tween from outside its animator.
+    ///
+    /// On its next tick after [`CancelToken::cancel()`] is called, the tween
+    /// applies its [`CancelBehavior`] to the target, raises a
+    /// [`TweenCancelled`] event if it raises completion events, and stops
+    /// playing. See [`CancelToken`] for details.
     #[must_use]
-    pub fn then(mut self, tween: impl Tweenable<T> + 'static) -> Self {
-        self.duration += tween.duration();
-        self.tweens.push(Box::new(tween));
+    pub fn with_cancel_token(mut self, token: CancelToken) -> Self {
+        self.cancellation.token = Some(token);
         self
     }
 
-    /// Index of the current active tween in the sequence.
-    #[must_use]
-    pub fn index(&self) -> usize {
-        self.index.min(self.tweens.len() - 1)
+    /// Set or clear the token to cancel the tween from outside its animator.
+    ///
+    /// See [`with_cancel_token()`] for details.
+    ///
+    /// [`with_cancel_token()`]: Tween::with_cancel_token
+    pub fn set_cancel_token(&mut self, token: Option<CancelToken>) {
+        self.cancellation.token = token;
     }
 
-    /// Get the current active tween in the sequence.
+    /// Set what the tween leaves its target at once cancelled through its
+    /// [`CancelToken`].
+    ///
+    /// By default the target is left where the last tick applied it.
     #[must_use]
-    pub fn current(&self) -> &dyn Tweenable<T> {
-        self.tweens[self.index()].as_ref()
+    pub fn with_cancel_behavior(mut self, behavior: CancelBehavior) -> Self {
+        self.cancellation.behavior = behavior;
+        self
     }
-}
 
-impl<T> Tweenable<T> for Sequence<T> {
-    fn duration(&self) -> Duration {
-        self.duration
+    /// Set what the tween leaves its target at once cancelled through its
+    /// [`CancelToken`].
+    ///
+    /// See [`with_cancel_behavior()`] for details.
+    ///
+    /// [`with_cancel_behavior()`]: Tween::with_cancel_behavior
+    pub fn set_cancel_behavior(&mut self, behavior: CancelBehavior) {
+        self.cancellation.behavior = behavior;
     }
 
-    fn total_duration(&self) -> TotalDuration {
-        TotalDuration::Finite(self.duration)
+    /// What the tween leaves its target at once cancelled through its
+    /// [`CancelToken`].
+    #[must_use]
+    pub fn cancel_behavior(&self) -> CancelBehavior {
+        self.cancellation.behavior
     }
 
-    fn set_elapsed(&mut self, elapsed: Duration) {
-        // Set the total sequence progress
-        self.elapsed = elapsed;
-
-        // Find which tween is active in the sequence
-        let mut accum_duration = Duration::ZERO;
-        for index in 0..self.tweens.len() {
-            let tween = &mut self.tweens[index];
-            let tween_duration = tween.duration();
-            if elapsed < accum_duration + tween_duration {
-                self.index = index;
-                let local_duration = elapsed - accum_duration;
-                tween.set_elapsed(local_duration);
-                // TODO?? set progress of other tweens after that one to 0. ??
-                return;
-            }
-            tween.set_elapsed(tween.duration()); // ?? to prepare for next loop/rewind?
-            accum_duration += tween_duration;
-        }
+    /// Change the duration of a single iteration of the tween.
+    ///
+    /// The progress of the tween is preserved, as well as the number of
+    /// iterations already completed, so the animated value doesn't jump; the
+    /// elapsed time is scaled accordingly. A [`RepeatCount::Finite`] repeat
+    /// count is preserved, while a [`RepeatCount::For`] total duration is
+    /// kept as is. The duration must not be zero.
+    ///
+    /// For a tween created with [`new_with_speed()`], this replaces the
+    /// duration derived from the speed, which is not measured again.
+    ///
+    /// [`new_with_speed()`]: Tween::new_with_speed
+    pub fn set_duration(&mut self, duration: Duration) {
+        self.clock.set_duration(duration);
+        self.speed = None;
+    }
 
-        // None found; sequence ended
-        self.index = self.tweens.len();
+    /// The speed of the tween, in units of the lens path per second, if it
+    /// was created with [`new_with_speed()`].
+    ///
+    /// [`new_with_speed()`]: Tween::new_with_speed
+    #[must_use]
+    pub fn speed(&self) -> Option<f32> {
+        self.speed.as_ref().map(|speed| speed.speed)
     }
 
-    fn elapsed(&self) -> Duration {
-        self.elapsed
+    /// The easing method of the tween.
+    #[must_use]
+    pub fn ease_method(&self) -> EaseMethod {
+        self.ease_function
     }
 
-    fn tick(
-        &mut self,
-        mut delta: Duration,
-        target: &mut dyn Targetable<T>,
-        entity: Entity,
-        events: &mut Mut<Events<TweenCompleted>>,
-    ) -> TweenState {
-        self.elapsed = self.elapsed.saturating_add(delta).min(self.duration);
-        while self.index < self.tweens.len() {
-            let tween = &mut self.tweens[self.index];
-            let tween_remaining = tween.duration() - tween.elapsed();
-            if let TweenState::Active = tween.tick(delta, target, entity, events) {
-                return TweenState::Active;
-            }
+    /// Change the easing method of the tween.
+    ///
+    /// The new easing applies from the next tick the tween is active.
+    pub fn set_ease_method(&mut self, ease_method: impl Into<EaseMethod>) {
+        self.ease_function = ease_method.into();
+    }
 
-            tween.rewind();
-            delta -= tween_remaining;
-            self.index += 1;
-        }
+    /// Set the key of the [`TweenTuning`] entry overriding the duration and
+    /// the easing of this tween.
+    ///
+    /// See [`TweenTuning`] for details.
+    #[must_use]
+    pub fn with_tuning_key(mut self, key: impl Into<TuningKey>) -> Self {
+        self.tuning_key = Some(key.into());
+        self
+    }
 
-        TweenState::Completed
+    /// Set or clear the key of the [`TweenTuning`] entry overriding the
+    /// duration and the easing of this tween.
+    pub fn set_tuning_key(&mut self, key: Option<TuningKey>) {
+        self.tuning_key = key;
     }
 
-    fn rewind(&mut self) {
-        self.elapsed = Duration::ZERO;
-        self.index = 0;
-        for tween in &mut self.tweens {
-            // or only first?
-            tween.rewind();
-        }
+    /// The key of the [`TweenTuning`] entry overriding the duration and the
+    /// easing of this tween, if any.
+    #[must_use]
+    pub fn tuning_key(&self) -> Option<&TuningKey> {
+        self.tuning_key.as_ref()
     }
-}
 
-/// A collection of [`Tweenable`] executing in parallel.
-pub struct Tracks<T> {
-    tracks: Vec<BoxedTweenable<T>>,
-    duration: Duration,
-    elapsed: Duration,
-}
-
-impl<T> Tracks<T> {
-    /// Create a new [`Tracks`] from an iterator over a collection of
-    /// [`Tweenable`].
+    /// Raise an event once the tween played for a total of `elapsed`.
+    ///
+    /// The tween raises a [`TweenCompleted`] event with the given user data
+    /// and [`is_elapsed_trigger`] set on the tick its [`total_elapsed()`]
+    /// reaches `elapsed`, or on the first tick
+    /// past it if a large delta skipped that exact time. Unlike the
+    /// completion event, this is independent of the progress of the tween, so
+    /// the event is raised once even for a looping tween, for example to
+    /// escalate an effect the longer it runs. The event is raised again after
+    /// the tween is rewound. Several events can be added, and those raised on
+    /// the same tick are raised in order of their `elapsed` time, before the
+    /// completion event.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::math::Vec3;
+    /// # use std::time::Duration;
+    /// const ESCALATE: u64 = 7;
+    /// let pulse = Tween::new_millis(
+    ///     // [...]
+    /// #    EaseFunction::SineInOut,
+    /// #    800,
+    /// #    TransformScaleLens {
+    /// #        start: Vec3::ONE,
+    /// #        end: Vec3::splat(1.2),
+    /// #    },
+    /// )
+    /// .with_repeat_count(RepeatCount::Infinite)
+    /// .with_elapsed_trigger(Duration::from_secs(10), ESCALATE);
+    /// ```
+    ///
+    /// [`is_elapsed_trigger`]: TweenCompleted::is_elapsed_trigger
+    /// [`total_elapsed()`]: Tween::total_elapsed
     #[must_use]
-    pub fn new(items: impl IntoIterator<Item = impl Into<BoxedTweenable<T>>>) -> Self {
-        let tracks: Vec<_> = items.into_iter().map(Into::into).collect();
-        let duration = tracks
-            .iter()
-            .map(AsRef::as_ref)
-            .map(Tweenable::duration)
-            .max()
-            .unwrap();
-        Self {
-            tracks,
-            duration,
-            elapsed: Duration::ZERO,
-        }
-    }
-}
-
-impl<T> Tweenable<T> for Tracks<T> {
-    fn duration(&self) -> Duration {
-        self.duration
-    }
-
-    fn total_duration(&self) -> TotalDuration {
-        TotalDuration::Finite(self.duration)
-    }
-
-    fn set_elapsed(&mut self, elapsed: Duration) {
-        self.elapsed = elapsed;
-
-        for tweenable in &mut self.tracks {
-            tweenable.set_elapsed(elapsed);
-        }
-    }
-
-    fn elapsed(&self) -> Duration {
-        self.elapsed
+    pub fn with_elapsed_trigger(mut self, elapsed: Duration, user_data: u64) -> Self {
+        self.add_elapsed_trigger(elapsed, user_data);
+        self
     }
 
-    fn tick(
-        &mut self,
-        delta: Duration,
-        target: &mut dyn Targetable<T>,
-        entity: Entity,
-        events: &mut Mut<Events<TweenCompleted>>,
-    ) -> TweenState {
-        self.elapsed = self.elapsed.saturating_add(delta).min(self.duration);
-        let mut any_active = false;
-        for tweenable in &mut self.tracks {
-            let state = tweenable.tick(delta, target, entity, events);
-            any_active = any_active || (state == TweenState::Active);
-        }
-        if any_active {
-            TweenState::Active
-        } else {
-            TweenState::Completed
-        }
+    /// Raise an event once the tween played for a total of `elapsed`.
+    ///
+    /// If the tween already played for that long, the event is raised on the
+    /// next tick. See [`with_elapsed_trigger()`] for details.
+    ///
+    /// [`with_elapsed_trigger()`]: Tween::with_elapsed_trigger
+    pub fn add_elapsed_trigger(&mut self, elapsed: Duration, user_data: u64) {
+        let index = self
+            .elapsed_triggers
+            .partition_point(|trigger| trigger.elapsed <= elapsed);
+        self.elapsed_triggers.insert(
+            index,
+            ElapsedTrigger {
+                elapsed,
+                user_data,
+                fired: false,
+            },
+        );
     }
 
-    fn rewind(&mut self) {
-        self.elapsed = Duration::ZERO;
-        for tween in &mut self.tracks {
-            tween.rewind();
-        }
+    /// Remove all the events added with [`with_elapsed_trigger()`] or
+    /// [`add_elapsed_trigger()`].
+    ///
+    /// [`with_elapsed_trigger()`]: Tween::with_elapsed_trigger
+    /// [`add_elapsed_trigger()`]: Tween::add_elapsed_trigger
+    pub fn clear_elapsed_triggers(&mut self) {
+        self.elapsed_triggers.clear();
     }
-}
-
-/// A time delay that doesn't animate anything.
-///
-/// This is generally useful for combining with other tweenables into sequences
-/// and tracks, for example to delay the start of a tween in a track relative to
-/// another track. The `menu` example (`examples/menu.rs`) uses this technique
-/// to delay the animation of its buttons.
-pub struct Delay<T> {
-    timer: Timer,
-    on_completed: Option<Box<CompletedCallback<Delay<T>>>>,
-    event_data: Option<u64>,
-}
 
-impl<T: 'static> Delay<T> {
-    /// Chain another [`Tweenable`] after this tween, making a [`Sequence`] with
-    /// the two.
+    /// Total time the tween played for since it was created or last rewound,
+    /// across all its loops.
+    ///
+    /// This accumulates the time the tween was ticked by until it completed.
+    /// Unlike [`elapsed()`], it's not changed by seeking the tween with
+    /// [`set_elapsed()`] or [`set_progress()`], only reset by [`rewind()`].
+    ///
+    /// [`elapsed()`]: Tweenable::elapsed
+    /// [`set_elapsed()`]: Tweenable::set_elapsed
+    /// [`set_progress()`]: Tweenable::set_progress
+    /// [`rewind()`]: Tweenable::rewind
     #[must_use]
-    pub fn then(self, tween: impl Tweenable<T> + 'static) -> Sequence<T> {
-        Sequence::with_capacity(2).then(self).then(tween)
+    pub fn total_elapsed(&self) -> Duration {
+        self.total_elapsed
     }
-}
 
-impl<T> Delay<T> {
-    /// Create a new [`Delay`] with a given duration.
+    /// Set a callback invoked when the animation completes.
     ///
-    /// # Panics
+    /// The callback when invoked receives as parameters the [`Entity`] on which
+    /// the target and the animator are, as well as a reference to the
+    /// current [`Tween`].
     ///
-    /// Panics if the duration is zero.
-    #[must_use]
-    pub fn new(duration: Duration) -> Self {
-        assert!(!duration.is_zero());
-        Self {
-            timer: Timer::new(duration, TimerMode::Once),
-            on_completed: None,
-            event_data: None,
-        }
+    /// Only non-looping tweenables can complete. The entity may be despawned
+    /// before any command queued by the callback is applied; see
+    /// [despawned entities](TweenCompleted#despawned-entities).
+    pub fn set_completed<C>(&mut self, callback: C)
+    where
+        C: Fn(Entity, &Self) + Send + Sync + 'static,
+    {
+        self.on_completed = Some(Box::new(callback));
     }
 
-    /// Enable raising a completed event.
+    /// Set a callback invoked when the animation completes, receiving the
+    /// final value of the target.
     ///
-    /// If enabled, the tweenable will raise a [`TweenCompleted`] event when it
-    /// completed. This is similar to the [`set_completed()`] callback, but
-    /// uses Bevy events instead.
+    /// The callback when invoked receives as parameters the [`Entity`] on which
+    /// the target and the animator are, a reference to the current [`Tween`],
+    /// and a reference to the target, after the final state of the animation
+    /// was applied to it. This allows handing over the end value of an
+    /// animation to another system, like a physics engine. This callback is
+    /// independent from the one set with [`set_completed()`]; if both are set,
+    /// that other callback is invoked first.
     ///
     /// # Example
     ///
     /// ```
     /// # use bevy_tweening::{lens::*, *};
-    /// # use bevy::{ecs::event::EventReader, math::Vec3, transform::components::Transform};
-    /// # use std::time::Duration;
-    /// let delay: Delay<Transform> = Delay::new(Duration::from_secs(5))
-    ///   .with_completed_event(42);
-    ///
-    /// fn my_system(mut reader: EventReader<TweenCompleted>) {
-    ///   for ev in reader.read() {
-    ///     assert_eq!(ev.user_data, 42);
-    ///     println!("Entity {:?} raised TweenCompleted!", ev.entity);
-    ///   }
-    /// }
+    /// # use bevy::prelude::*;
+    /// let mut tween = Tween::new(
+    ///     // [...]
+    /// #    EaseFunction::QuadraticInOut,
+    /// #    1.,
+    /// #    TransformPositionLens {
+    /// #        start: Vec3::ZERO,
+    /// #        end: Vec3::new(3.5, 0., 0.),
+    /// #    },
+    /// );
+    /// tween.set_completed_with_target(|entity, _tween, transform: &Transform| {
+    ///     println!("Entity {entity:?} arrived at {}", transform.translation);
+    /// });
     /// ```
     ///
-    /// [`set_completed()`]: Delay::set_completed
+    /// [`set_completed()`]: Tween::set_completed
+    pub fn set_completed_with_target<C>(&mut self, callback: C)
+    where
+        C: Fn(Entity, &Self, &T) + Send + Sync + 'static,
+    {
+        self.on_completed_with_target = Some(Box::new(callback));
+    }
+
+    /// Set a callback invoked when the animation completes, receiving the
+    /// final value of the target.
+    ///
+    /// See [`set_completed_with_target()`] for details.
+    ///
+    /// [`set_completed_with_target()`]: Tween::set_completed_with_target
     #[must_use]
-    pub fn with_completed_event(mut self, user_data: u64) -> Self {
-        self.event_data = Some(user_data);
+    pub fn with_completed_with_target<C>(mut self, callback: C) -> Self
+    where
+        C: Fn(Entity, &Self, &T) + Send + Sync + 'static,
+    {
+        self.set_completed_with_target(callback);
         self
     }
 
-    /// Set a callback invoked when the delay completes.
+    /// Set an already boxed callback invoked when the animation completes.
+    pub(crate) fn set_completed_boxed(&mut self, callback: Box<CompletedCallback<Self>>) {
+        self.on_completed = Some(callback);
+    }
+
+    /// Clear the callbacks invoked when the animation completes.
+    ///
+    /// This clears both the callbacks set with [`set_completed()`] and
+    /// [`set_completed_with_target()`].
+    ///
+    /// [`set_completed()`]: Tween::set_completed
+    /// [`set_completed_with_target()`]: Tween::set_completed_with_target
+    pub fn clear_completed(&mut self) {
+        self.on_completed = None;
+        self.on_completed_with_target = None;
+    }
+
+    /// Set a callback invoked each time the tween writes to its target.
     ///
     /// The callback when invoked receives as parameters the [`Entity`] on which
-    /// the target and the animator are, as well as a reference to the
-    /// current [`Delay`]. This is similar to [`with_completed_event()`], but
-    /// with a callback instead.
+    /// the target and the animator are, and a reference to the target, after
+    /// the tween wrote its new value. This allows mirroring the animated value
+    /// into some state outside of the ECS, like the parameter of an audio
+    /// engine, without a system polling the target for changes.
     ///
-    /// Only non-looping tweenables can complete.
+    /// The callback is invoked at most once per tick, after the completion
+    /// callbacks if the tween completed during that tick. It's not invoked
+    /// on the ticks the tween leaves its target untouched, like once it
+    /// completed, or while a minimum step is pending. A tween nested in a
+    /// [`Sequence`] or [`Tracks`] only invokes its own callback while it's
+    /// ticked; to observe every tick of a whole tweenable, use
+    /// [`Animator::set_on_update()`] instead.
     ///
     /// # Example
     ///
     /// ```
     /// # use bevy_tweening::{lens::*, *};
-    /// # use bevy::{ecs::event::EventReader, math::Vec3};
-    /// # use std::time::Duration;
-    /// let tween = Tween::new(
+    /// # use bevy::prelude::*;
+    /// let mut tween = Tween::new(
     ///     // [...]
     /// #    EaseFunction::QuadraticInOut,
-    /// #    Duration::from_secs(1),
+    /// #    1.,
     /// #    TransformPositionLens {
     /// #        start: Vec3::ZERO,
     /// #        end: Vec3::new(3.5, 0., 0.),
     /// #    },
-    /// )
-    /// .with_completed(|entity, delay| {
-    ///   println!("Delay of {} seconds elapsed on entity {:?}",
-    ///     delay.duration().as_secs(), entity);
+    /// );
+    /// tween.set_on_update(|entity, transform: &Transform| {
+    ///     println!("Entity {entity:?} moved to {}", transform.translation);
     /// });
     /// ```
     ///
-    /// [`with_completed_event()`]: Tween::with_completed_event
-    pub fn with_completed<C>(mut self, callback: C) -> Self
+    /// [`Animator::set_on_update()`]: crate::Animator::set_on_update
+    pub fn set_on_update<C>(&mut self, callback: C)
     where
-        C: Fn(Entity, &Self) + Send + Sync + 'static,
+        C: Fn(Entity, &T) + Send + Sync + 'static,
     {
-        self.on_completed = Some(Box::new(callback));
-        self
-    }
-
-    /// Check if the delay completed.
-    pub fn is_completed(&self) -> bool {
-        self.timer.finished()
-    }
-
-    /// Get the current tweenable state.
-    pub fn state(&self) -> TweenState {
-        if self.is_completed() {
-            TweenState::Completed
-        } else {
-            TweenState::Active
-        }
+        self.on_update = Some(Box::new(callback));
     }
 
-    /// Set a callback invoked when the animation completes.
+    /// Set a callback invoked each time the tween writes to its target.
     ///
-    /// The callback when invoked receives as parameters the [`Entity`] on which
-    /// the target and the animator are, as well as a reference to the
-    /// current [`Tween`].
+    /// See [`set_on_update()`] for details.
     ///
-    /// Only non-looping tweenables can complete.
-    pub fn set_completed<C>(&mut self, callback: C)
+    /// [`set_on_update()`]: Tween::set_on_update
+    #[must_use]
+    pub fn with_on_update<C>(mut self, callback: C) -> Self
     where
-        C: Fn(Entity, &Self) + Send + Sync + 'static,
+        C: Fn(Entity, &T) + Send + Sync + 'static,
     {
-        self.on_completed = Some(Box::new(callback));
+        self.set_on_update(callback);
+        self
     }
 
-    /// Clear the callback invoked when the animation completes.
-    ///
-    /// See also [`set_completed()`].
-    ///
-    /// [`set_completed()`]: Tween::set_completed
-    pub fn clear_completed(&mut self) {
-        self.on_completed = None;
+    /// Clear the callback invoked each time the tween writes to its target.
+    pub fn clear_on_update(&mut self) {
+        self.on_update = None;
     }
 
     /// Enable or disable raising a completed event.
@@ -1102,230 +2467,2931 @@ impl<T> Delay<T> {
     }
 }
 
-impl<T> Tweenable<T> for Delay<T> {
+impl<T> Tweenable<T> for Tween<T> {
+    fn id(&self) -> Option<TweenId> {
+        Some(self.id)
+    }
+
     fn duration(&self) -> Duration {
-        self.timer.duration()
+        self.clock.duration
+    }
+
+    fn times_completed(&self) -> u32 {
+        if self.clock.duration.is_zero() {
+            // An instant tween completes once, on its first tick
+            u32::from(self.pending_step.is_some())
+        } else {
+            self.clock.times_completed()
+        }
     }
 
     fn total_duration(&self) -> TotalDuration {
-        TotalDuration::Finite(self.duration())
+        self.clock.total_duration
     }
 
     fn set_elapsed(&mut self, elapsed: Duration) {
-        // need to reset() to clear finished() unfortunately
-        self.timer.reset();
-        self.timer.set_elapsed(elapsed);
-        // set_elapsed() does not update finished() etc. which we rely on
-        self.timer.tick(Duration::ZERO);
+        // Keep the direction consistent with the new elapsed time, as if the
+        // tween had been ticked until it.
+        let direction_changes = self.clock.direction_changes();
+        self.clock.set_elapsed(elapsed);
+        if self.clock.strategy == RepeatStrategy::MirroredRepeat
+            && (direction_changes ^ self.clock.direction_changes()) & 1 != 0
+        {
+            self.direction = !self.direction;
+        }
+        self.needs_apply = true;
+        self.start_blend = None;
     }
 
     fn elapsed(&self) -> Duration {
-        self.timer.elapsed()
+        self.clock.elapsed()
+    }
+
+    fn state(&self) -> TweenState {
+        if self.cancellation.observed {
+            TweenState::Completed
+        } else {
+            self.clock.state()
+        }
     }
 
     fn tick(
         &mut self,
         delta: Duration,
-        _target: &mut dyn Targetable<T>,
+        target: &mut dyn Targetable<T>,
         entity: Entity,
-        events: &mut Mut<Events<TweenCompleted>>,
+        events: &mut Vec<TweenCompleted>,
     ) -> TweenState {
-        let was_completed = self.is_completed();
-
-        self.timer.tick(delta);
+        // A cancelled tween stops playing until rewound
+        if self.cancellation.is_due() {
+            let mut cancelled = vec![];
+            self.collect_cancelled(entity, &mut cancelled);
+            self.cancellation.observe(cancelled);
+            apply_cancel_behavior(&*self, self.cancellation.behavior, target.target_mut());
+        }
+        if self.cancellation.observed {
+            return TweenState::Completed;
+        }
+        self.lens.set_context(&target.context());
+
+        // Measure the duration of a speed-based tween again, once the lens
+        // had a chance to capture its start from the target.
+        if let Some(speed) = self.speed.as_mut().filter(|speed| !speed.measured) {
+            speed.measured = true;
+            let raw = if self.direction.is_backward() { 1. } else { 0. };
+            let factor = self.ease_function.sample(raw);
+            self.lens
+                .lerp_ex(target.target_mut(), factor, raw, self.direction);
+            let duration = duration_at_speed((speed.arc_length)(&*self.lens), speed.speed);
+            self.clock.set_duration(duration);
+        }
 
-        let state = self.state();
+        // A zero-duration tween completes on its first tick, jumping to its
+        // end. Once completed, the target is left untouched, unless the tween
+        // was seeked since it was last applied.
+        let instant = self.clock.duration.is_zero() && self.pending_step.is_none();
+        if self.clock.state() == TweenState::Completed && !self.needs_apply && !instant {
+            return TweenState::Completed;
+        }
+        let seeked = std::mem::take(&mut self.needs_apply);
 
-        // If completed this frame, notify the user
-        if (state == TweenState::Completed) && !was_completed {
-            if let Some(user_data) = &self.event_data {
-                events.send(TweenCompleted {
+        // Tick the animation clock
+        let previous = self.clock.elapsed();
+        let (state, times_completed) = self.clock.tick(delta);
+        let times_completed = if instant { 1 } else { times_completed };
+        let played = self.clock.elapsed().saturating_sub(previous);
+        self.total_elapsed = self.total_elapsed.saturating_add(played);
+        let remaining_repeats = self.remaining_repeats();
+        for trigger in &mut self.elapsed_triggers {
+            if !trigger.fired && trigger.elapsed <= self.total_elapsed {
+                trigger.fired = true;
+                events.push(TweenCompleted {
+                    entity,
+                    user_data: trigger.user_data,
+                    tween_id: self.id,
+                    played: trigger.elapsed,
+                    remaining_repeats,
+                    is_elapsed_trigger: true,
+                });
+            }
+        }
+        let (progress, times_completed_for_direction) = match state {
+            TweenState::Active => (self.progress(), times_completed),
+            TweenState::Completed => (1., times_completed.max(1) - 1), // ignore last
+        };
+        if self.clock.strategy == RepeatStrategy::MirroredRepeat
+            && times_completed_for_direction & 1 != 0
+        {
+            self.direction = !self.direction;
+        }
+
+        // On the tick a ping-pong leg completes, the clock has usually already
+        // wrapped past the endpoint. Apply the exact endpoint where the new leg
+        // starts instead, so the apex of the motion is always reached once per
+        // leg. The progress reported by the tween still reflects the actual
+        // elapsed time; the remaining overshoot is applied on the next tick.
+        let progress = if self.clock.strategy == RepeatStrategy::MirroredRepeat
+            && state == TweenState::Active
+            && times_completed > 0
+        {
+            0.
+        } else {
+            progress
+        };
+
+        // With a minimum step, skip applying the lens until enough animation
+        // time accumulated, unless the target must reach an exact value.
+        let pending_step = self
+            .pending_step
+            .map_or(Duration::ZERO, |pending| pending.saturating_add(delta));
+        if pending_step < self.min_step
+            && self.pending_step.is_some()
+            && times_completed == 0
+            && !seeked
+        {
+            self.pending_step = Some(pending_step);
+            return state;
+        }
+        self.pending_step = Some(Duration::ZERO);
+
+        // Apply the lens, even if the animation finished, to ensure the state is
+        // consistent
+        let mut raw = progress;
+        if self.direction.is_backward() {
+            raw = 1. - raw;
+        }
+        // A ping-pong-once tween returns exactly to where it started, even if
+        // the easing function doesn't exactly map the endpoints onto themselves.
+        let factor = if self.notify_final_only && state == TweenState::Completed {
+            raw
+        } else {
+            self.ease_function.sample(raw)
+        };
+        let target = target.target_mut();
+        let blended = match &self.start_blend {
+            Some(blend) if !self.direction.is_backward() && self.clock.elapsed() < blend.window => {
+                let weight = fraction_progress(self.clock.elapsed(), blend.window);
+                self.lens
+                    .lerp_from(target, factor, blend.from.as_ref(), weight)
+            }
+            _ => false,
+        };
+        if !blended {
+            self.lens.lerp_ex(target, factor, raw, self.direction);
+        }
+
+        // If completed at least once this frame, notify the user
+        if times_completed > 0 && (!self.notify_final_only || state == TweenState::Completed) {
+            if let Some(user_data) = &self.event_data {
+                // A ping-pong-once tween notifies once for both legs
+                let played = if self.notify_final_only {
+                    self.clock.elapsed()
+                } else {
+                    self.clock.duration * times_completed as u32
+                };
+                events.push(TweenCompleted {
                     entity,
                     user_data: *user_data,
+                    tween_id: self.id,
+                    played,
+                    remaining_repeats,
+                    is_elapsed_trigger: false,
                 });
             }
             if let Some(cb) = &self.on_completed {
                 cb(entity, self);
             }
+            if let Some(cb) = &self.on_completed_with_target {
+                cb(entity, self, target);
+            }
+        }
+
+        if state == TweenState::Completed
+            && self.completed_behavior == CompletedBehavior::SnapToStart
+        {
+            self.sample(Duration::ZERO, target);
+        }
+
+        if let Some(cb) = &self.on_update {
+            cb(entity, target);
+        }
+
+        // Ready for reuse, once everything observed the completion
+        if state == TweenState::Completed && self.auto_rewind {
+            self.rewind();
         }
 
         state
     }
 
-    fn rewind(&mut self) {
-        self.timer.reset();
+    fn direction(&self) -> Option<TweeningDirection> {
+        Some(self.direction)
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use std::sync::{Arc, Mutex};
-
-    use bevy::ecs::system::SystemState;
 
-    use super::*;
-    use crate::{lens::*, test_utils::*};
+    fn save_state(&self) -> TweenableState {
+        TweenableState::Tween {
+            elapsed: self.clock.elapsed,
+            duration: self.clock.duration,
+            direction: self.direction,
+            total_elapsed: self.total_elapsed,
+            pending_step: self.pending_step,
+            fired_triggers: self.elapsed_triggers.iter().map(|t| t.fired).collect(),
+        }
+    }
 
-    #[derive(Default, Copy, Clone)]
-    struct CallbackMonitor {
-        invoke_count: u64,
-        last_reported_count: u32,
+    fn restore_state(&mut self, state: &TweenableState) -> bool {
+        let TweenableState::Tween {
+            elapsed,
+            duration,
+            direction,
+            total_elapsed,
+            pending_step,
+            fired_triggers,
+        } = state
+        else {
+            return false;
+        };
+        if fired_triggers.len() != self.elapsed_triggers.len() {
+            return false;
+        }
+        if *duration != self.clock.duration {
+            self.clock.set_duration(*duration);
+        }
+        // A speed-based tween measures its duration on its first tick
+        if let Some(speed) = self.speed.as_mut() {
+            speed.measured = pending_step.is_some();
+        }
+        self.clock.elapsed = *elapsed;
+        self.direction = *direction;
+        self.total_elapsed = *total_elapsed;
+        self.pending_step = *pending_step;
+        for (trigger, fired) in self.elapsed_triggers.iter_mut().zip(fired_triggers) {
+            trigger.fired = *fired;
+        }
+        self.needs_apply = true;
+        self.start_blend = None;
+        true
     }
 
-    /// Utility to create a tween for testing.
-    fn make_test_tween() -> Tween<Transform> {
-        Tween::new(
-            EaseMethod::Linear,
-            Duration::from_secs(1),
-            TransformPositionLens {
-                start: Vec3::ZERO,
-                end: Vec3::ONE,
-            },
-        )
+    fn set_direction(&mut self, direction: TweeningDirection) -> bool {
+        self.direction = direction;
+        true
     }
 
-    /// Utility to create a test environment to tick a tween.
-    fn make_test_env() -> (World, Entity) {
-        let mut world = World::new();
-        world.init_resource::<Events<TweenCompleted>>();
-        let entity = world.spawn(Transform::default()).id();
-        (world, entity)
+    fn apply_tuning(&mut self, tuning: &TweenTuning) {
+        let Some(entry) = self.tuning_key.as_ref().and_then(|key| tuning.get(key)) else {
+            return;
+        };
+        if let Some(duration) = entry.duration {
+            if !duration.is_zero() && duration != self.clock.duration {
+                self.set_duration(duration);
+            }
+        }
+        if let Some(ease) = entry.ease {
+            self.set_ease_method(ease);
+        }
     }
 
-    /// Manually tick a test tweenable targeting a component.
-    fn manual_tick_component<T: Component>(
-        duration: Duration,
-        tween: &mut dyn Tweenable<T>,
-        world: &mut World,
-        entity: Entity,
-    ) -> TweenState {
-        world.resource_scope(
-            |world: &mut World, mut events: Mut<Events<TweenCompleted>>| {
-                let transform = world.get_mut::<T>(entity).unwrap();
-                let mut target = ComponentTarget::new(transform);
-                tween.tick(duration, &mut target, entity, &mut events)
-            },
-        )
+    fn apply_overrides(&mut self, overrides: &TweenOverrides) {
+        if let Some(factor) = overrides.duration_scale() {
+            // A speed-based tween not measured yet measures its scaled duration
+            if let Some(speed) = self.speed.as_mut() {
+                speed.speed /= factor;
+            }
+            self.clock
+                .set_duration(overrides.scaled(self.clock.duration));
+        }
+        if let Some(factor) = overrides.lens_range_scale() {
+            if !self.lens.scale_range(factor) {
+                warn!(
+                    "Cannot scale the lens range of {}: its lens doesn't support scaling.",
+                    self.id
+                );
+            }
+        }
+        if let (Some(user_data), Some(_)) = (overrides.user_data(), self.event_data) {
+            self.event_data = Some(user_data);
+        }
     }
 
-    #[test]
-    fn anim_clock_precision() {
-        let duration = Duration::from_millis(1);
-        let mut clock = AnimClock::new(duration);
-        clock.total_duration = TotalDuration::Infinite;
+    fn boundary_value(&self) -> Option<Box<dyn Any + Send + Sync>> {
+        if self.completed_behavior == CompletedBehavior::SnapToStart {
+            return None;
+        }
+        self.lens.boundary_value(self.direction)
+    }
 
-        let test_ticks = [
-            Duration::from_micros(123),
-            Duration::from_millis(1),
-            Duration::from_secs_f32(1. / 24.),
-            Duration::from_secs_f32(1. / 30.),
-            Duration::from_secs_f32(1. / 60.),
-            Duration::from_secs_f32(1. / 120.),
-            Duration::from_secs_f32(1. / 144.),
-            Duration::from_secs_f32(1. / 240.),
-        ];
+    fn current_value(&self) -> Option<Box<dyn Any + Send + Sync>> {
+        let completed = self.clock.state() == TweenState::Completed;
+        if completed && self.completed_behavior == CompletedBehavior::SnapToStart {
+            return None;
+        }
+        // Same factor as the last tick applied, see tick()
+        let mut factor = self.progress();
+        if self.direction.is_backward() {
+            factor = 1. - factor;
+        }
+        if !(self.notify_final_only && completed) {
+            factor = self.ease_function.sample(factor);
+        }
+        self.lens.value_at(factor)
+    }
 
-        let mut times_completed = 0;
-        let mut total_duration = Duration::ZERO;
-        for i in 0..10_000_000 {
-            let tick = test_ticks[i % test_ticks.len()];
-            times_completed += clock.tick(tick).1;
-            total_duration += tick;
+    fn blend_start(&mut self, from: Box<dyn Any + Send + Sync>, window: Duration) -> bool {
+        // Only blend the first iteration, so a looping tween doesn't pop
+        let window = window.min(self.clock.duration);
+        if window.is_zero() {
+            return false;
         }
+        self.start_blend = Some(StartBlend { from, window });
+        true
+    }
 
-        assert_eq!(
-            (total_duration.as_secs_f64() / duration.as_secs_f64()) as i32,
-            times_completed
-        );
+    fn collect_cancelled(&self, entity: Entity, cancelled: &mut Vec<TweenCancelled>) {
+        // An instant tween completes on its first tick
+        let completed = self.cancellation.observed
+            || (self.clock.state() == TweenState::Completed
+                && !(self.clock.duration.is_zero() && self.pending_step.is_none()));
+        if let (Some(user_data), false) = (self.event_data, completed) {
+            // Only the iteration in progress is lost, the completed ones
+            // already raised their event
+            let played = if self.notify_final_only {
+                self.clock.elapsed()
+            } else {
+                let completed = self.clock.duration * self.clock.times_completed();
+                self.clock.elapsed().saturating_sub(completed)
+            };
+            cancelled.push(TweenCancelled {
+                entity,
+                user_data,
+                tween_id: self.id,
+                progress_at_cancel: self.progress(),
+                played,
+            });
+        }
     }
 
-    #[test]
-    fn into_repeat_count() {
-        let tween = Tween::new(
-            EaseMethod::Linear,
-            Duration::from_secs(1),
-            TransformPositionLens {
-                start: Vec3::ZERO,
-                end: Vec3::ONE,
-            },
-        )
-        .with_repeat_count(5);
-        assert_eq!(
-            tween.total_duration(),
-            TotalDuration::Finite(Duration::from_secs(5))
-        );
+    fn take_token_cancelled(&mut self, cancelled: &mut Vec<TweenCancelled>) {
+        cancelled.append(&mut self.cancellation.events);
+    }
 
-        let tween = Tween::new(
-            EaseMethod::Linear,
-            Duration::from_secs(1),
-            TransformPositionLens {
-                start: Vec3::ZERO,
-                end: Vec3::ONE,
-            },
-        )
-        .with_repeat_count(Duration::from_secs(3));
-        assert_eq!(
-            tween.total_duration(),
-            TotalDuration::Finite(Duration::from_secs(3))
-        );
+    fn rewind(&mut self) {
+        if self.clock.strategy == RepeatStrategy::MirroredRepeat {
+            // In mirrored mode, direction alternates each loop. To reset to the original
+            // direction on Tween creation, we count the number of completions, ignoring the
+            // last one if the Tween is currently in TweenState::Completed because that one
+            // freezes all parameters.
+            if self.clock.direction_changes() & 1 != 0 {
+                self.direction = !self.direction;
+            }
+        }
+        self.clock.reset();
+        self.pending_step = None;
+        self.total_elapsed = Duration::ZERO;
+        for trigger in &mut self.elapsed_triggers {
+            trigger.fired = false;
+        }
+        self.start_blend = None;
+        self.cancellation.observed = false;
     }
 
-    /// Test ticking of a single tween in isolation.
-    #[test]
-    fn tween_tick() {
-        for tweening_direction in &[TweeningDirection::Forward, TweeningDirection::Backward] {
-            for (count, strategy) in &[
-                (RepeatCount::Finite(1), RepeatStrategy::default()),
-                (RepeatCount::Infinite, RepeatStrategy::Repeat),
-                (RepeatCount::Finite(2), RepeatStrategy::Repeat),
-                (RepeatCount::Infinite, RepeatStrategy::MirroredRepeat),
-                (RepeatCount::Finite(2), RepeatStrategy::MirroredRepeat),
-            ] {
-                println!(
-                    "TweeningType: count={count:?} strategy={strategy:?} dir={tweening_direction:?}",
-                );
+    fn sample(&self, elapsed: Duration, target: &mut T) -> bool {
+        let duration = self.clock.duration;
+        let (elapsed, completed) = match self.clock.total_duration {
+            TotalDuration::Finite(total_duration) if elapsed >= total_duration => {
+                (total_duration, true)
+            }
+            _ => (elapsed, false),
+        };
+        let (progress, times_completed) = if duration.is_zero() {
+            (1., 0)
+        } else {
+            let times_completed = (elapsed.as_nanos() / duration.as_nanos()) as u32;
+            if completed {
+                (1., times_completed)
+            } else {
+                (fraction_progress(elapsed, duration), times_completed)
+            }
+        };
 
-                // Create a linear tween over 1 second
-                let mut tween = make_test_tween()
-                    .with_direction(*tweening_direction)
-                    .with_repeat_count(*count)
-                    .with_repeat_strategy(*strategy);
-                assert_eq!(tween.direction(), *tweening_direction);
-                assert!(tween.on_completed.is_none());
-                assert!(tween.event_data.is_none());
+        // Like tick(), ignore the last completion for the direction. Undo the
+        // direction changes the live tween went through, then apply the ones
+        // happening until the sampled time.
+        let mut direction = self.direction;
+        if self.clock.strategy == RepeatStrategy::MirroredRepeat {
+            let current_flips = self.clock.direction_changes();
+            let flips = times_completed.saturating_sub(u32::from(completed));
+            if (current_flips ^ flips) & 1 != 0 {
+                direction = !direction;
+            }
+        }
 
-                let (mut world, entity) = make_test_env();
-                let mut event_reader_system_state: SystemState<EventReader<TweenCompleted>> =
-                    SystemState::new(&mut world);
+        let mut factor = progress;
+        if direction.is_backward() {
+            factor = 1. - factor;
+        }
+        let factor = if self.notify_final_only && completed {
+            factor
+        } else {
+            self.ease_function.sample(factor)
+        };
+        self.lens.sample_directed(target, factor, direction)
+    }
 
-                // Register callbacks to count started/ended events
-                let callback_monitor = Arc::new(Mutex::new(CallbackMonitor::default()));
-                let cb_mon_ptr = Arc::clone(&callback_monitor);
-                let reference_entity = entity;
-                tween.set_completed(move |completed_entity, tween| {
-                    assert_eq!(completed_entity, reference_entity);
-                    let mut cb_mon = cb_mon_ptr.lock().unwrap();
-                    cb_mon.invoke_count += 1;
-                    cb_mon.last_reported_count = tween.times_completed();
-                });
-                assert!(tween.on_completed.is_some());
-                assert!(tween.event_data.is_none());
-                assert_eq!(callback_monitor.lock().unwrap().invoke_count, 0);
+    fn validate(&self) -> Result<(), TweenValidationError> {
+        if self.lens.is_valid() {
+            Ok(())
+        } else {
+            Err(TweenValidationError::InvalidLens(self.id))
+        }
+    }
+}
 
-                // Activate event sending
-                const USER_DATA: u64 = 54789; // dummy
-                tween.set_completed_event(USER_DATA);
-                assert!(tween.event_data.is_some());
-                assert_eq!(tween.event_data.unwrap(), USER_DATA);
+/// Elapsed time at the end of a tweenable, or of its first loop if it loops
+/// forever.
+fn end_elapsed<T>(tweenable: &dyn Tweenable<T>) -> Duration {
+    match tweenable.total_duration() {
+        TotalDuration::Finite(total_duration) => total_duration,
+        TotalDuration::Infinite => tweenable.duration(),
+    }
+}
 
-                // Loop over 2.2 seconds, so greater than one ping-pong loop
-                let tick_duration = Duration::from_millis(200);
-                for i in 1..=11 {
-                    // Calculate expected values
-                    let (progress, times_completed, mut direction, expected_state, just_completed) =
-                        match count {
-                            RepeatCount::Finite(1) => {
-                                let progress = (i as f32 * 0.2).min(1.0);
+/// A sequence of tweens played back in order one after the other.
+///
+/// A sequence can be created from an iterator of tweenables of the same type
+/// with [`new()`], or from a tuple of two to four tweenables of different
+/// types, without boxing them:
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// let grow = Tween::new_millis(
+///     EaseFunction::QuadraticOut,
+///     300,
+///     TransformScaleLens {
+///         start: Vec3::ZERO,
+///         end: Vec3::ONE,
+///     },
+/// );
+/// let pause = Delay::millis(500);
+/// let sequence = Sequence::<Transform>::from((grow, pause));
+/// assert_eq!(sequence.duration(), Duration::from_millis(800));
+/// ```
+///
+/// [`new()`]: Sequence::new
+pub struct Sequence<T> {
+    id: TweenId,
+    tweens: Vec<BoxedTweenable<T>>,
+    index: usize,
+    duration: Duration,
+    elapsed: Duration,
+    /// Whether the sequence was seeked since it was last ticked, so the next
+    /// tick needs to apply the children other than the active one.
+    needs_apply: bool,
+    completed_behavior: CompletedBehavior,
+    boundary_blend: Duration,
+    cancellation: Cancellation,
+}
+
+impl<T> std::fmt::Debug for Sequence<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let tweens: Vec<_> = self.tweens.iter().map(|tween| tween.id()).collect();
+        f.debug_struct("Sequence")
+            .field("id", &self.id)
+            .field("tweens", &tweens)
+            .field("index", &self.index)
+            .field("duration", &self.duration)
+            .field("elapsed", &self.elapsed)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Sequence<T> {
+    /// Update the duration of the sequence after its children changed
+    /// duration, keeping its elapsed time consistent with the position of the
+    /// active child.
+    fn update_duration(&mut self) {
+        self.duration = self.tweens.iter().map(|t| end_elapsed(t.as_ref())).sum();
+        self.elapsed = match self.tweens.get(self.index) {
+            Some(tween) => {
+                let start: Duration = self.tweens[..self.index]
+                    .iter()
+                    .map(|t| end_elapsed(t.as_ref()))
+                    .sum();
+                start.saturating_add(tween.elapsed()).min(self.duration)
+            }
+            None => self.duration,
+        };
+    }
+
+    /// Create a new sequence of tweens.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input collection is empty. Use [`try_new()`] to handle
+    /// that case instead.
+    ///
+    /// [`try_new()`]: Sequence::try_new
+    #[must_use]
+    pub fn new(items: impl IntoIterator<Item = impl Into<BoxedTweenable<T>>>) -> Self {
+        Self::try_new(items).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Create a new sequence of tweens, or return an error if the input
+    /// collection is empty.
+    pub fn try_new(
+        items: impl IntoIterator<Item = impl Into<BoxedTweenable<T>>>,
+    ) -> Result<Self, EmptyTweenableError> {
+        let tweens: Vec<_> = items.into_iter().map(Into::into).collect();
+        if tweens.is_empty() {
+            return Err(EmptyTweenableError {
+                type_name: "Sequence",
+            });
+        }
+        let duration = tweens.iter().map(AsRef::as_ref).map(end_elapsed).sum();
+        Ok(Self {
+            id: TweenId::allocate(),
+            tweens,
+            index: 0,
+            duration,
+            elapsed: Duration::ZERO,
+            needs_apply: false,
+            completed_behavior: CompletedBehavior::HoldEnd,
+            boundary_blend: Duration::ZERO,
+            cancellation: Cancellation::default(),
+        })
+    }
+
+    /// Create a new sequence containing a single tween.
+    #[must_use]
+    pub fn from_single(tween: impl Tweenable<T> + 'static) -> Self {
+        let duration = end_elapsed(&tween);
+        let boxed: BoxedTweenable<T> = Box::new(tween);
+        Self {
+            id: TweenId::allocate(),
+            tweens: vec![boxed],
+            index: 0,
+            duration,
+            elapsed: Duration::ZERO,
+            needs_apply: false,
+            completed_behavior: CompletedBehavior::HoldEnd,
+            boundary_blend: Duration::ZERO,
+            cancellation: Cancellation::default(),
+        }
+    }
+
+    /// Create a new sequence with the specified capacity.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            id: TweenId::allocate(),
+            tweens: Vec::with_capacity(capacity),
+            index: 0,
+            duration: Duration::ZERO,
+            elapsed: Duration::ZERO,
+            needs_apply: false,
+            completed_behavior: CompletedBehavior::HoldEnd,
+            boundary_blend: Duration::ZERO,
+            cancellation: Cancellation::default(),
+        }
+    }
+
+    /// Append a [`Tweenable`] to this sequence.
+    #[must_use]
+    pub fn then(mut self, tween: impl Tweenable<T> + 'static) -> Self {
+        self.duration += end_elapsed(&tween);
+        self.tweens.push(Box::new(tween));
+        self
+    }
+
+    /// Insert a [`Tweenable`] at the start of this sequence.
+    ///
+    /// This allows building a sequence in reverse order, starting from its
+    /// last child. If the sequence didn't start playing yet, the new child is
+    /// the first one to play. Otherwise the playback already moved past the
+    /// start of the sequence, so the new child is considered already played:
+    /// it's set to its end, the active child stays the same, and the elapsed
+    /// time of the sequence grows by the duration of the new child, keeping
+    /// the same time remaining. The new child doesn't raise any event or
+    /// callback, and is only played again once the sequence is rewound or
+    /// seeked back.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::math::Vec3;
+    /// let tween = |start, end| {
+    ///     Tween::new(
+    ///         EaseFunction::QuadraticInOut,
+    ///         1.,
+    ///         TransformPositionLens { start, end },
+    ///     )
+    /// };
+    /// // Plays the second tween, then the first one
+    /// let seq = Sequence::from_single(tween(Vec3::X, Vec3::Y)).prepend(tween(Vec3::ZERO, Vec3::X));
+    /// ```
+    #[must_use]
+    pub fn prepend(mut self, mut tween: impl Tweenable<T> + 'static) -> Self {
+        let started = self.index > 0 || self.elapsed > Duration::ZERO;
+        if started {
+            tween.set_elapsed(end_elapsed(&tween));
+            self.index += 1;
+        }
+        self.tweens.insert(0, Box::new(tween));
+        self.update_duration();
+        self
+    }
+
+    /// Set what the sequence leaves its target at once it completes.
+    ///
+    /// By default the target holds the end value of the last child. With
+    /// [`CompletedBehavior::SnapToStart`], the start value of the sequence is
+    /// applied again on the tick its last child completes, after the events
+    /// and callbacks of that child. This is independent of the behavior of
+    /// each child [`Tween`], set with [`Tween::with_completed_behavior()`].
+    #[must_use]
+    pub fn with_completed_behavior(mut self, behavior: CompletedBehavior) -> Self {
+        self.completed_behavior = behavior;
+        self
+    }
+
+    /// Set what the sequence leaves its target at once it completes.
+    ///
+    /// See [`with_completed_behavior()`] for details.
+    ///
+    /// [`with_completed_behavior()`]: Sequence::with_completed_behavior
+    pub fn set_completed_behavior(&mut self, behavior: CompletedBehavior) {
+        self.completed_behavior = behavior;
+    }
+
+    /// What the sequence leaves its target at once it completes.
+    #[must_use]
+    pub fn completed_behavior(&self) -> CompletedBehavior {
+        self.completed_behavior
+    }
+
+    /// Set a token to cancel the whole sequence from outside its animator.
+    ///
+    /// On its next tick after [`CancelToken::cancel()`] is called, the
+    /// sequence applies its [`CancelBehavior`] to the target, raises a
+    /// [`TweenCancelled`] event for each of the pending completion events of
+    /// its children, and stops playing. A token attached to a child instead
+    /// only skips that child. See [`CancelToken`] for details.
+    #[must_use]
+    pub fn with_cancel_token(mut self, token: CancelToken) -> Self {
+        self.cancellation.token = Some(token);
+        self
+    }
+
+    /// Set or clear the token to cancel the sequence from outside its
+    /// animator.
+    ///
+    /// See [`with_cancel_token()`] for details.
+    ///
+    /// [`with_cancel_token()`]: Sequence::with_cancel_token
+    pub fn set_cancel_token(&mut self, token: Option<CancelToken>) {
+        self.cancellation.token = token;
+    }
+
+    /// Set what the sequence leaves its target at once cancelled through its
+    /// [`CancelToken`].
+    ///
+    /// By default the target is left where the last tick applied it. The
+    /// start and end values are the ones of the whole sequence, applying
+    /// all its children.
+    #[must_use]
+    pub fn with_cancel_behavior(mut self, behavior: CancelBehavior) -> Self {
+        self.cancellation.behavior = behavior;
+        self
+    }
+
+    /// Set what the sequence leaves its target at once cancelled through its
+    /// [`CancelToken`].
+    ///
+    /// See [`with_cancel_behavior()`] for details.
+    ///
+    /// [`with_cancel_behavior()`]: Sequence::with_cancel_behavior
+    pub fn set_cancel_behavior(&mut self, behavior: CancelBehavior) {
+        self.cancellation.behavior = behavior;
+    }
+
+    /// What the sequence leaves its target at once cancelled through its
+    /// [`CancelToken`].
+    #[must_use]
+    pub fn cancel_behavior(&self) -> CancelBehavior {
+        self.cancellation.behavior
+    }
+
+    /// Enable blending the start of each child into the end of the previous
+    /// one, over the given `window` of its playback.
+    ///
+    /// When the end value of a child doesn't match the start value of the
+    /// next one, the target jumps at the boundary between the two children.
+    /// With a blend window, the next child starts instead from where the
+    /// previous one left the target, and its start value is blended into its
+    /// own over the window, so the animation stays continuous without having
+    /// to match the endpoints exactly. When the endpoints already match, the
+    /// blend has no effect.
+    ///
+    /// The blend uses the [`Tweenable::boundary_value()`] of the previous
+    /// child and [`Tweenable::blend_start()`] of the next one, which for a
+    /// [`Tween`] require a lens supporting [`Lens::lerp_from()`], like the
+    /// [`Transform`] and color lenses. Other children keep a hard cut at
+    /// their boundary. The window is clamped to the duration of a single
+    /// iteration of the next child. Seeking the sequence cancels the blends
+    /// in progress, and [`sample()`] ignores them. A zero window, the
+    /// default, disables blending.
+    ///
+    /// [`Transform`]: https://docs.rs/bevy/0.12.0/bevy/transform/components/struct.Transform.html
+    /// [`sample()`]: Tweenable::sample
+    #[must_use]
+    pub fn with_boundary_blend(mut self, window: Duration) -> Self {
+        self.boundary_blend = window;
+        self
+    }
+
+    /// Set the window over which the start of each child is blended into the
+    /// end of the previous one.
+    ///
+    /// See [`with_boundary_blend()`] for details.
+    ///
+    /// [`with_boundary_blend()`]: Self::with_boundary_blend
+    pub fn set_boundary_blend(&mut self, window: Duration) {
+        self.boundary_blend = window;
+    }
+
+    /// Get the window over which the start of each child is blended into the
+    /// end of the previous one.
+    #[must_use]
+    pub fn boundary_blend(&self) -> Duration {
+        self.boundary_blend
+    }
+
+    /// Index of the current active tween in the sequence.
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.index.min(self.tweens.len() - 1)
+    }
+
+    /// Get the current active tween in the sequence.
+    #[must_use]
+    pub fn current(&self) -> &dyn Tweenable<T> {
+        self.tweens[self.index()].as_ref()
+    }
+
+    /// Find the child active at the given elapsed time of the sequence, and the
+    /// elapsed time local to that child. Returns `None` past the end of the
+    /// sequence.
+    fn find(&self, elapsed: Duration) -> Option<(usize, Duration)> {
+        let mut accum_duration = Duration::ZERO;
+        for (index, tween) in self.tweens.iter().enumerate() {
+            let tween_duration = end_elapsed(tween.as_ref());
+            if elapsed < accum_duration + tween_duration {
+                return Some((index, elapsed - accum_duration));
+            }
+            accum_duration += tween_duration;
+        }
+        None
+    }
+
+    /// Map a progress of the entire sequence to the child active at that
+    /// progress, and the progress local to that child.
+    ///
+    /// This doesn't modify the sequence. The input `progress` is clamped to
+    /// \[0:1\], and the returned local progress is in \[0:1\].
+    ///
+    /// A progress exactly on the border between two children returns the later
+    /// child at a local progress of `0.0`. Children with a zero duration are
+    /// never returned, since they're always on a border, except when the
+    /// sequence itself has a zero duration, in which case this returns the
+    /// first child at `0.0`. A progress of `1.0` returns the last child at
+    /// `1.0`.
+    ///
+    /// See [`global_progress()`] for the inverse mapping.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_tweening::*;
+    /// # use bevy::prelude::*;
+    /// let seq: Sequence<Transform> = Delay::secs(1.)
+    ///     .then(Delay::secs(3.));
+    /// assert_eq!(seq.locate(0.625), (1, 0.5));
+    /// assert_eq!(seq.locate(0.25), (1, 0.));
+    /// ```
+    ///
+    /// [`global_progress()`]: Sequence::global_progress
+    #[must_use]
+    pub fn locate(&self, progress: f32) -> (usize, f32) {
+        if self.duration.is_zero() {
+            return (0, 0.);
+        }
+        let elapsed = self.duration.mul_f64(progress.clamp(0., 1.) as f64);
+        match self.find(elapsed) {
+            Some((index, local_duration)) => (
+                index,
+                (local_duration.as_secs_f64()
+                    / end_elapsed(self.tweens[index].as_ref()).as_secs_f64())
+                    as f32,
+            ),
+            None => (self.tweens.len() - 1, 1.),
+        }
+    }
+
+    /// Map the progress local to a child of the sequence to the progress of the
+    /// entire sequence.
+    ///
+    /// This doesn't modify the sequence. The input `local` progress is clamped
+    /// to \[0:1\]. An `index` past the last child returns `1.0`, and a sequence
+    /// with a zero duration always returns `0.0`.
+    ///
+    /// This is the inverse of [`locate()`]. For example, seeking to the start of
+    /// the third child is `seq.set_progress(seq.global_progress(2, 0.))`.
+    ///
+    /// [`locate()`]: Sequence::locate
+    #[must_use]
+    pub fn global_progress(&self, index: usize, local: f32) -> f32 {
+        if self.duration.is_zero() {
+            return 0.;
+        }
+        if index >= self.tweens.len() {
+            return 1.;
+        }
+        let start: Duration = self.tweens[..index]
+            .iter()
+            .map(|t| end_elapsed(t.as_ref()))
+            .sum();
+        let elapsed =
+            start + end_elapsed(self.tweens[index].as_ref()).mul_f64(local.clamp(0., 1.) as f64);
+        (elapsed.as_secs_f64() / self.duration.as_secs_f64()) as f32
+    }
+}
+
+impl<T> Tweenable<T> for Sequence<T> {
+    fn id(&self) -> Option<TweenId> {
+        Some(self.id)
+    }
+
+    fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    fn total_duration(&self) -> TotalDuration {
+        if self
+            .tweens
+            .iter()
+            .any(|tween| tween.total_duration() == TotalDuration::Infinite)
+        {
+            TotalDuration::Infinite
+        } else {
+            TotalDuration::Finite(self.duration)
+        }
+    }
+
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        // Set the total sequence progress; a sequence doesn't loop, so clamp to
+        // a single pass to keep times_completed() consistent with tick().
+        self.elapsed = elapsed.min(self.duration);
+
+        // Find which tween is active in the sequence. Past the end, seek the
+        // last tween to its end, so the next tick applies its end state.
+        let (mut index, mut local_duration) = self.find(elapsed).unwrap_or_else(|| {
+            let index = self.tweens.len() - 1;
+            (index, end_elapsed(self.tweens[index].as_ref()))
+        });
+
+        // A child holding the sequence, like an unreleased WaitFor, is still
+        // active once seeked to its end, so the seek stops at that child.
+        let mut start = Duration::ZERO;
+        for (i, tween) in self.tweens[..index].iter_mut().enumerate() {
+            let end = end_elapsed(tween.as_ref());
+            tween.set_elapsed(end);
+            if tween.state() != TweenState::Completed {
+                index = i;
+                local_duration = end;
+                self.elapsed = start + end;
+                break;
+            }
+            start += end;
+        }
+
+        self.index = index;
+        for (i, tween) in self.tweens.iter_mut().enumerate().skip(index) {
+            if i == index {
+                tween.set_elapsed(local_duration);
+            } else {
+                // Not started yet, so play from their start once reached
+                tween.rewind();
+            }
+        }
+        self.needs_apply = true;
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    fn state(&self) -> TweenState {
+        // Past the end, or the remaining children have nothing left to play
+        let remaining = &self.tweens[self.index.min(self.tweens.len())..];
+        if self.cancellation.observed
+            || remaining
+                .iter()
+                .all(|tween| tween.state() == TweenState::Completed)
+        {
+            TweenState::Completed
+        } else {
+            TweenState::Active
+        }
+    }
+
+    fn tick(
+        &mut self,
+        mut delta: Duration,
+        target: &mut dyn Targetable<T>,
+        entity: Entity,
+        events: &mut Vec<TweenCompleted>,
+    ) -> TweenState {
+        // A cancelled sequence stops playing until rewound
+        if self.cancellation.is_due() {
+            let mut cancelled = vec![];
+            self.collect_cancelled(entity, &mut cancelled);
+            self.cancellation.observe(cancelled);
+            let target = target.target_mut();
+            match self.cancellation.behavior {
+                CancelBehavior::Freeze => {}
+                // Like a seek to the start, from the last child so the first wins
+                CancelBehavior::SnapToStart => {
+                    for tween in self.tweens.iter().rev() {
+                        tween.sample(Duration::ZERO, target);
+                    }
+                }
+                CancelBehavior::SnapToEnd => {
+                    self.sample(self.duration, target);
+                }
+            }
+        }
+        if self.cancellation.observed {
+            return TweenState::Completed;
+        }
+
+        // After a seek, the children other than the active one are not
+        // ticked, so apply their state directly: the start of the children not
+        // started yet, from the last one so the earliest wins, then the end of
+        // the children already completed.
+        if std::mem::take(&mut self.needs_apply) && self.index < self.tweens.len() {
+            let target = target.target_mut();
+            for tween in self.tweens[self.index + 1..].iter().rev() {
+                tween.sample(Duration::ZERO, target);
+            }
+            for tween in &self.tweens[..self.index] {
+                tween.sample(end_elapsed(tween.as_ref()), target);
+            }
+        }
+
+        // A child like a Delay controlled by a DelayHandle may have changed
+        // duration since the last tick.
+        if self
+            .tweens
+            .iter()
+            .map(|t| end_elapsed(t.as_ref()))
+            .sum::<Duration>()
+            != self.duration
+        {
+            self.update_duration();
+        }
+
+        let was_active = self.index < self.tweens.len();
+        let elapsed = self.elapsed.saturating_add(delta);
+        while self.index < self.tweens.len() {
+            let tween = &mut self.tweens[self.index];
+            let tween_remaining = match tween.total_duration() {
+                TotalDuration::Finite(total_duration) => {
+                    total_duration.saturating_sub(tween.elapsed())
+                }
+                TotalDuration::Infinite => Duration::MAX,
+            };
+            if let TweenState::Active = tween.tick(delta, target, entity, events) {
+                // Don't let the elapsed time of the sequence run ahead of the
+                // active child, which may hold the sequence in place (WaitFor).
+                // A child cancelled through its token may also have left the
+                // sequence behind the start of the active one.
+                let start: Duration = self.tweens[..self.index]
+                    .iter()
+                    .map(|t| end_elapsed(t.as_ref()))
+                    .sum();
+                let end = match self.tweens[self.index].total_duration() {
+                    TotalDuration::Finite(total_duration) => start.saturating_add(total_duration),
+                    TotalDuration::Infinite => Duration::MAX,
+                };
+                self.elapsed = elapsed.clamp(start, end).min(self.duration);
+                return TweenState::Active;
+            }
+
+            // The child applied its end state and notified its completion
+            // during its tick; only then move to the next one, so that the
+            // completion of each skipped child observes its own end state.
+            //
+            // Always tick the next child, even if no delta is left after the
+            // current one completed, so that it applies its start state on this
+            // same tick instead of leaving the target untouched until the next
+            // frame.
+            let from = if self.boundary_blend.is_zero() {
+                None
+            } else {
+                tween.boundary_value()
+            };
+            tween.rewind();
+            delta = delta.saturating_sub(tween_remaining);
+            self.index += 1;
+            if let (Some(from), Some(next)) = (from, self.tweens.get_mut(self.index)) {
+                next.blend_start(from, self.boundary_blend);
+            }
+        }
+
+        self.elapsed = self.duration;
+        if was_active && self.completed_behavior == CompletedBehavior::SnapToStart {
+            // Like a seek to the start, from the last child so the first wins
+            let target = target.target_mut();
+            for tween in self.tweens.iter().rev() {
+                tween.sample(Duration::ZERO, target);
+            }
+        }
+        TweenState::Completed
+    }
+
+    fn apply_tuning(&mut self, tuning: &TweenTuning) {
+        for tween in &mut self.tweens {
+            tween.apply_tuning(tuning);
+        }
+        self.update_duration();
+    }
+
+    fn apply_overrides(&mut self, overrides: &TweenOverrides) {
+        for tween in &mut self.tweens {
+            tween.apply_overrides(overrides);
+        }
+        self.update_duration();
+    }
+
+    fn boundary_value(&self) -> Option<Box<dyn Any + Send + Sync>> {
+        if self.completed_behavior == CompletedBehavior::SnapToStart {
+            return None;
+        }
+        self.tweens.last()?.boundary_value()
+    }
+
+    fn current_value(&self) -> Option<Box<dyn Any + Send + Sync>> {
+        if self.completed_behavior == CompletedBehavior::SnapToStart
+            && self.index >= self.tweens.len()
+        {
+            return None;
+        }
+        let index = self.index.min(self.tweens.len().checked_sub(1)?);
+        self.tweens[index].current_value()
+    }
+
+    fn blend_start(&mut self, from: Box<dyn Any + Send + Sync>, window: Duration) -> bool {
+        match self.tweens.first_mut() {
+            Some(tween) => tween.blend_start(from, window),
+            None => false,
+        }
+    }
+
+    fn collect_cancelled(&self, entity: Entity, cancelled: &mut Vec<TweenCancelled>) {
+        if self.cancellation.observed {
+            return;
+        }
+        for tween in self.tweens.iter().skip(self.index) {
+            tween.collect_cancelled(entity, cancelled);
+        }
+    }
+
+    fn take_token_cancelled(&mut self, cancelled: &mut Vec<TweenCancelled>) {
+        cancelled.append(&mut self.cancellation.events);
+        for tween in &mut self.tweens {
+            tween.take_token_cancelled(cancelled);
+        }
+    }
+
+    fn rewind(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.index = 0;
+        self.needs_apply = false;
+        self.cancellation.observed = false;
+        for tween in &mut self.tweens {
+            // or only first?
+            tween.rewind();
+        }
+    }
+
+    fn sample(&self, elapsed: Duration, target: &mut T) -> bool {
+        let (index, local_elapsed) = self
+            .find(elapsed)
+            .unwrap_or((self.tweens.len(), Duration::ZERO));
+        let mut supported = true;
+        for tween in &self.tweens[..index] {
+            supported &= tween.sample(end_elapsed(tween.as_ref()), target);
+        }
+        if let Some(tween) = self.tweens.get(index) {
+            supported &= tween.sample(local_elapsed, target);
+        }
+        supported
+    }
+
+    fn validate(&self) -> Result<(), TweenValidationError> {
+        let Some((last, others)) = self.tweens.split_last() else {
+            return Err(TweenValidationError::EmptyComposite(self.id));
+        };
+        for tween in others {
+            tween.validate()?;
+            if tween.total_duration() == TotalDuration::Infinite {
+                return Err(TweenValidationError::InfiniteChild(self.id));
+            }
+        }
+        last.validate()
+    }
+
+    fn save_state(&self) -> TweenableState {
+        TweenableState::Sequence {
+            elapsed: self.elapsed,
+            index: self.index,
+            children: self.tweens.iter().map(|t| t.save_state()).collect(),
+        }
+    }
+
+    fn restore_state(&mut self, state: &TweenableState) -> bool {
+        let TweenableState::Sequence {
+            elapsed,
+            index,
+            children,
+        } = state
+        else {
+            return false;
+        };
+        if children.len() != self.tweens.len() || *index > self.tweens.len() {
+            return false;
+        }
+        let mut restored = true;
+        for (tween, state) in self.tweens.iter_mut().zip(children) {
+            restored &= tween.restore_state(state);
+        }
+        self.index = *index;
+        self.duration = self.tweens.iter().map(|t| end_elapsed(t.as_ref())).sum();
+        self.elapsed = (*elapsed).min(self.duration);
+        self.needs_apply = true;
+        restored
+    }
+}
+
+/// A collection of [`Tweenable`] executing in parallel.
+///
+/// Tracks can be created from an iterator of tweenables of the same type with
+/// [`new()`], or from a tuple of two to four tweenables of different types,
+/// without boxing them:
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// let slide = Tween::new(
+///     EaseFunction::QuadraticInOut,
+///     1.,
+///     TransformPositionLens {
+///         start: Vec3::ZERO,
+///         end: Vec3::X * 100.,
+///     },
+/// );
+/// let spin = Tween::new_millis(
+///     EaseFunction::QuadraticInOut,
+///     500,
+///     TransformRotateZLens {
+///         start: 0.,
+///         end: std::f32::consts::TAU,
+///     },
+/// );
+/// // Start spinning halfway through the slide
+/// let tracks = Tracks::from((slide, Delay::millis(500).then(spin)));
+/// assert_eq!(tracks.duration(), Duration::from_secs(1));
+/// ```
+///
+/// [`new()`]: Tracks::new
+pub struct Tracks<T> {
+    id: TweenId,
+    tracks: Vec<BoxedTweenable<T>>,
+    duration: Duration,
+    elapsed: Duration,
+    cancellation: Cancellation,
+}
+
+impl<T> std::fmt::Debug for Tracks<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let tracks: Vec<_> = self.tracks.iter().map(|track| track.id()).collect();
+        f.debug_struct("Tracks")
+            .field("id", &self.id)
+            .field("tracks", &tracks)
+            .field("duration", &self.duration)
+            .field("elapsed", &self.elapsed)
+            .finish()
+    }
+}
+
+impl<T> Tracks<T> {
+    /// Update the duration of the tracks after their children changed
+    /// duration.
+    fn update_duration(&mut self) {
+        self.duration = self
+            .tracks
+            .iter()
+            .map(|t| end_elapsed(t.as_ref()))
+            .max()
+            .unwrap();
+        self.elapsed = self
+            .tracks
+            .iter()
+            .map(|t| t.elapsed())
+            .max()
+            .unwrap()
+            .min(self.duration);
+    }
+
+    /// Create a new [`Tracks`] from an iterator over a collection of
+    /// [`Tweenable`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input collection is empty. Use [`try_new()`] to handle
+    /// that case instead.
+    ///
+    /// [`try_new()`]: Tracks::try_new
+    #[must_use]
+    pub fn new(items: impl IntoIterator<Item = impl Into<BoxedTweenable<T>>>) -> Self {
+        Self::try_new(items).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Create a new [`Tracks`] from an iterator over a collection of
+    /// [`Tweenable`], or return an error if the input collection is empty.
+    pub fn try_new(
+        items: impl IntoIterator<Item = impl Into<BoxedTweenable<T>>>,
+    ) -> Result<Self, EmptyTweenableError> {
+        let tracks: Vec<_> = items.into_iter().map(Into::into).collect();
+        let Some(duration) = tracks.iter().map(AsRef::as_ref).map(end_elapsed).max() else {
+            return Err(EmptyTweenableError {
+                type_name: "Tracks",
+            });
+        };
+        Ok(Self {
+            id: TweenId::allocate(),
+            tracks,
+            duration,
+            elapsed: Duration::ZERO,
+            cancellation: Cancellation::default(),
+        })
+    }
+
+    /// Set a token to cancel all the tracks from outside their animator.
+    ///
+    /// On its next tick after [`CancelToken::cancel()`] is called, the
+    /// tracks apply their [`CancelBehavior`] to the target, raise a
+    /// [`TweenCancelled`] event for each of the pending completion events of
+    /// their children, and stop playing. A token attached to a single track
+    /// instead only stops that track, while the others continue. See
+    /// [`CancelToken`] for details.
+    #[must_use]
+    pub fn with_cancel_token(mut self, token: CancelToken) -> Self {
+        self.cancellation.token = Some(token);
+        self
+    }
+
+    /// Set or clear the token to cancel the tracks from outside their
+    /// animator.
+    ///
+    /// See [`with_cancel_token()`] for details.
+    ///
+    /// [`with_cancel_token()`]: Tracks::with_cancel_token
+    pub fn set_cancel_token(&mut self, token: Option<CancelToken>) {
+        self.cancellation.token = token;
+    }
+
+    /// Set what the tracks leave their target at once cancelled through their
+    /// [`CancelToken`].
+    ///
+    /// By default the target is left where the last tick applied it.
+    #[must_use]
+    pub fn with_cancel_behavior(mut self, behavior: CancelBehavior) -> Self {
+        self.cancellation.behavior = behavior;
+        self
+    }
+
+    /// Set what the tracks leave their target at once cancelled through their
+    /// [`CancelToken`].
+    ///
+    /// See [`with_cancel_behavior()`] for details.
+    ///
+    /// [`with_cancel_behavior()`]: Tracks::with_cancel_behavior
+    pub fn set_cancel_behavior(&mut self, behavior: CancelBehavior) {
+        self.cancellation.behavior = behavior;
+    }
+
+    /// What the tracks leave their target at once cancelled through their
+    /// [`CancelToken`].
+    #[must_use]
+    pub fn cancel_behavior(&self) -> CancelBehavior {
+        self.cancellation.behavior
+    }
+}
+
+impl<T> Tweenable<T> for Tracks<T> {
+    fn id(&self) -> Option<TweenId> {
+        Some(self.id)
+    }
+
+    fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    fn total_duration(&self) -> TotalDuration {
+        if self
+            .tracks
+            .iter()
+            .any(|track| track.total_duration() == TotalDuration::Infinite)
+        {
+            TotalDuration::Infinite
+        } else {
+            TotalDuration::Finite(self.duration)
+        }
+    }
+
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        self.elapsed = elapsed.min(self.duration);
+
+        for tweenable in &mut self.tracks {
+            tweenable.set_elapsed(elapsed);
+        }
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    fn state(&self) -> TweenState {
+        if self.cancellation.observed
+            || self
+                .tracks
+                .iter()
+                .all(|track| track.state() == TweenState::Completed)
+        {
+            TweenState::Completed
+        } else {
+            TweenState::Active
+        }
+    }
+
+    fn tick(
+        &mut self,
+        delta: Duration,
+        target: &mut dyn Targetable<T>,
+        entity: Entity,
+        events: &mut Vec<TweenCompleted>,
+    ) -> TweenState {
+        // Cancelled tracks stop playing until rewound
+        if self.cancellation.is_due() {
+            let mut cancelled = vec![];
+            self.collect_cancelled(entity, &mut cancelled);
+            self.cancellation.observe(cancelled);
+            apply_cancel_behavior(&*self, self.cancellation.behavior, target.target_mut());
+        }
+        if self.cancellation.observed {
+            return TweenState::Completed;
+        }
+
+        // A track may have changed duration since the last tick, like a Delay
+        // controlled by a DelayHandle.
+        let duration = self.tracks.iter().map(|t| end_elapsed(t.as_ref())).max();
+        if duration.unwrap_or_default() != self.duration {
+            self.update_duration();
+        }
+        self.elapsed = self.elapsed.saturating_add(delta).min(self.duration);
+        let mut any_active = false;
+        for tweenable in &mut self.tracks {
+            let state = tweenable.tick(delta, target, entity, events);
+            any_active = any_active || (state == TweenState::Active);
+        }
+        if any_active {
+            TweenState::Active
+        } else {
+            TweenState::Completed
+        }
+    }
+
+    fn apply_tuning(&mut self, tuning: &TweenTuning) {
+        for tweenable in &mut self.tracks {
+            tweenable.apply_tuning(tuning);
+        }
+        self.update_duration();
+    }
+
+    fn apply_overrides(&mut self, overrides: &TweenOverrides) {
+        for tweenable in &mut self.tracks {
+            tweenable.apply_overrides(overrides);
+        }
+        self.update_duration();
+    }
+
+    fn collect_cancelled(&self, entity: Entity, cancelled: &mut Vec<TweenCancelled>) {
+        if self.cancellation.observed {
+            return;
+        }
+        for tweenable in &self.tracks {
+            tweenable.collect_cancelled(entity, cancelled);
+        }
+    }
+
+    fn take_token_cancelled(&mut self, cancelled: &mut Vec<TweenCancelled>) {
+        cancelled.append(&mut self.cancellation.events);
+        for tweenable in &mut self.tracks {
+            tweenable.take_token_cancelled(cancelled);
+        }
+    }
+
+    fn rewind(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.cancellation.observed = false;
+        for tween in &mut self.tracks {
+            tween.rewind();
+        }
+    }
+
+    fn sample(&self, elapsed: Duration, target: &mut T) -> bool {
+        let elapsed = elapsed.min(self.duration);
+        let mut supported = true;
+        for track in &self.tracks {
+            supported &= track.sample(elapsed, target);
+        }
+        supported
+    }
+
+    fn validate(&self) -> Result<(), TweenValidationError> {
+        if self.tracks.is_empty() {
+            return Err(TweenValidationError::EmptyComposite(self.id));
+        }
+        self.tracks.iter().try_for_each(|track| track.validate())
+    }
+
+    fn save_state(&self) -> TweenableState {
+        TweenableState::Tracks {
+            elapsed: self.elapsed,
+            children: self.tracks.iter().map(|t| t.save_state()).collect(),
+        }
+    }
+
+    fn restore_state(&mut self, state: &TweenableState) -> bool {
+        let TweenableState::Tracks { elapsed, children } = state else {
+            return false;
+        };
+        if children.len() != self.tracks.len() {
+            return false;
+        }
+        let mut restored = true;
+        for (track, state) in self.tracks.iter_mut().zip(children) {
+            restored &= track.restore_state(state);
+        }
+        self.duration = self
+            .tracks
+            .iter()
+            .map(|t| end_elapsed(t.as_ref()))
+            .max()
+            .unwrap();
+        self.elapsed = (*elapsed).min(self.duration);
+        restored
+    }
+}
+
+/// A time delay that doesn't animate anything.
+///
+/// This is generally useful for combining with other tweenables into sequences
+/// and tracks, for example to delay the start of a tween in a track relative to
+/// another track. The `menu` example (`examples/menu.rs`) uses this technique
+/// to delay the animation of its buttons.
+///
+/// The duration of a pending delay can be changed with [`set_duration()`],
+/// keeping its elapsed time, or cut with [`finish_now()`]. Once the delay is
+/// moved into a [`Sequence`] or another tweenable, it can still be controlled
+/// through a [`DelayHandle`] obtained beforehand with [`handle()`]. The
+/// durations of the [`Sequence`] and [`Tracks`] containing the delay are
+/// updated on their next tick.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # let hint = Tween::new(EaseMethod::Linear, 1., TransformScaleLens { start: Vec3::ZERO, end: Vec3::ONE });
+/// let mut delay = Delay::secs(3.);
+/// let handle = delay.handle();
+/// let seq: Sequence<Transform> = delay.then(hint);
+///
+/// // Later, the player did something: show the hint now
+/// handle.finish_now();
+/// ```
+///
+/// [`set_duration()`]: Delay::set_duration
+/// [`finish_now()`]: Delay::finish_now
+/// [`handle()`]: Delay::handle
+pub struct Delay<T> {
+    id: TweenId,
+    timer: Timer,
+    handle: Option<DelayHandle>,
+    on_completed: Option<Box<CompletedCallback<Delay<T>>>>,
+    event_data: Option<u64>,
+}
+
+/// Handle to control a [`Delay`] from outside the tweenable owning it.
+///
+/// The handle is cheap to clone, and can be stored anywhere. The changes made
+/// through the handle are visible immediately in the duration of the delay,
+/// and are applied on its next tick. See [`Delay::handle()`].
+#[derive(Debug, Clone)]
+pub struct DelayHandle(Arc<DelayControl>);
+
+#[derive(Debug)]
+struct DelayControl {
+    /// Duration of the delay, in nanoseconds.
+    duration: AtomicU64,
+    /// Whether to finish the delay on its next tick.
+    finish: AtomicBool,
+}
+
+impl DelayHandle {
+    fn new(duration: Duration) -> Self {
+        Self(Arc::new(DelayControl {
+            duration: AtomicU64::new(duration_to_nanos(duration)),
+            finish: AtomicBool::new(false),
+        }))
+    }
+
+    /// Change the duration of the delay, keeping its elapsed time.
+    ///
+    /// See [`Delay::set_duration()`] for details.
+    pub fn set_duration(&self, duration: Duration) {
+        self.0
+            .duration
+            .store(duration_to_nanos(duration), Ordering::Release);
+        self.0.finish.store(false, Ordering::Release);
+    }
+
+    /// Finish the delay on its next tick.
+    ///
+    /// See [`Delay::finish_now()`] for details.
+    pub fn finish_now(&self) {
+        self.0.finish.store(true, Ordering::Release);
+    }
+
+    /// Duration of the delay, given its elapsed time.
+    fn duration(&self, elapsed: Duration) -> Duration {
+        let duration = Duration::from_nanos(self.0.duration.load(Ordering::Acquire));
+        if self.0.finish.load(Ordering::Acquire) {
+            duration.min(elapsed)
+        } else {
+            duration
+        }
+    }
+}
+
+fn duration_to_nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+impl<T> std::fmt::Debug for Delay<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Delay")
+            .field("id", &self.id)
+            .field("duration", &self.timer.duration())
+            .field("elapsed", &self.timer.elapsed())
+            .field("event_data", &self.event_data)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: 'static> Delay<T> {
+    /// Chain another [`Tweenable`] after this tween, making a [`Sequence`] with
+    /// the two.
+    #[must_use]
+    pub fn then(self, tween: impl Tweenable<T> + 'static) -> Sequence<T> {
+        Sequence::with_capacity(2).then(self).then(tween)
+    }
+}
+
+impl<T> Delay<T> {
+    /// Create a new [`Delay`] with a given duration.
+    ///
+    /// The duration is anything convertible with [`IntoDuration`]: a
+    /// [`Duration`], a number of seconds as `f32`, or a number of
+    /// milliseconds as `u64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the duration is zero, including after replacing an invalid
+    /// number of seconds by zero.
+    #[must_use]
+    pub fn new(duration: impl IntoDuration) -> Self {
+        let duration = duration.into_duration();
+        assert!(!duration.is_zero());
+        Self {
+            id: TweenId::allocate(),
+            timer: Timer::new(duration, TimerMode::Once),
+            handle: None,
+            on_completed: None,
+            event_data: None,
+        }
+    }
+
+    /// Create a new [`Delay`] lasting `secs` seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `secs` is zero, negative, or non-finite. See [`new()`].
+    ///
+    /// [`new()`]: Delay::new
+    #[must_use]
+    pub fn secs(secs: f32) -> Self {
+        Self::new(secs)
+    }
+
+    /// Create a new [`Delay`] lasting `millis` milliseconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `millis` is zero. See [`new()`].
+    ///
+    /// [`new()`]: Delay::new
+    #[must_use]
+    pub fn millis(millis: u64) -> Self {
+        Self::new(Duration::from_millis(millis))
+    }
+
+    /// Enable raising a completed event.
+    ///
+    /// If enabled, the tweenable will raise a [`TweenCompleted`] event when it
+    /// completed. This is similar to the [`set_completed()`] callback, but
+    /// uses Bevy events instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::{ecs::event::EventReader, math::Vec3, transform::components::Transform};
+    /// let delay: Delay<Transform> = Delay::secs(5.)
+    ///   .with_completed_event(42);
+    ///
+    /// fn my_system(mut reader: EventReader<TweenCompleted>) {
+    ///   for ev in reader.read() {
+    ///     assert_eq!(ev.user_data, 42);
+    ///     println!("Entity {:?} raised TweenCompleted!", ev.entity);
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// [`set_completed()`]: Delay::set_completed
+    #[must_use]
+    pub fn with_completed_event(mut self, user_data: u64) -> Self {
+        self.event_data = Some(user_data);
+        self
+    }
+
+    /// Set a callback invoked when the delay completes.
+    ///
+    /// The callback when invoked receives as parameters the [`Entity`] on which
+    /// the target and the animator are, as well as a reference to the
+    /// current [`Delay`]. This is similar to [`with_completed_event()`], but
+    /// with a callback instead.
+    ///
+    /// Only non-looping tweenables can complete. The entity may be despawned
+    /// before any command queued by the callback is applied; see
+    /// [despawned entities](TweenCompleted#despawned-entities).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::{ecs::event::EventReader, math::Vec3};
+    /// let tween = Tween::new(
+    ///     // [...]
+    /// #    EaseFunction::QuadraticInOut,
+    /// #    1.,
+    /// #    TransformPositionLens {
+    /// #        start: Vec3::ZERO,
+    /// #        end: Vec3::new(3.5, 0., 0.),
+    /// #    },
+    /// )
+    /// .with_completed(|entity, delay| {
+    ///   println!("Delay of {} seconds elapsed on entity {:?}",
+    ///     delay.duration().as_secs(), entity);
+    /// });
+    /// ```
+    ///
+    /// [`with_completed_event()`]: Tween::with_completed_event
+    pub fn with_completed<C>(mut self, callback: C) -> Self
+    where
+        C: Fn(Entity, &Self) + Send + Sync + 'static,
+    {
+        self.on_completed = Some(Box::new(callback));
+        self
+    }
+
+    /// Check if the delay completed.
+    pub fn is_completed(&self) -> bool {
+        self.timer.finished()
+    }
+
+    /// Get the time remaining before the delay completes.
+    #[must_use]
+    pub fn remaining(&self) -> Duration {
+        self.duration().saturating_sub(self.elapsed())
+    }
+
+    /// Change the duration of the delay, keeping its elapsed time.
+    ///
+    /// For example, extending a delay of 3 seconds to 5 seconds after 2
+    /// seconds leaves 3 seconds remaining. Shortening the delay below its
+    /// elapsed time completes it on its next tick. Extending a completed
+    /// delay makes it pending again. The new duration is kept when the delay
+    /// is rewound.
+    ///
+    /// A delay with a zero duration completes on its first tick.
+    pub fn set_duration(&mut self, duration: Duration) {
+        if let Some(handle) = &self.handle {
+            handle.set_duration(duration);
+        }
+        self.apply_duration(duration);
+    }
+
+    /// Finish the delay on its next tick.
+    ///
+    /// This sets the duration of the delay to its elapsed time, like
+    /// [`set_duration()`], so a pending delay inside a [`Sequence`] ends where
+    /// it is, and the following children play immediately. A delay not
+    /// started yet is skipped.
+    ///
+    /// [`set_duration()`]: Delay::set_duration
+    pub fn finish_now(&mut self) {
+        self.set_duration(self.elapsed());
+    }
+
+    /// Get a handle to control the delay after it's moved into another
+    /// tweenable, like a [`Sequence`].
+    ///
+    /// All the handles returned by this method control the same delay.
+    pub fn handle(&mut self) -> DelayHandle {
+        let duration = self.timer.duration();
+        self.handle
+            .get_or_insert_with(|| DelayHandle::new(duration))
+            .clone()
+    }
+
+    /// Apply the changes made through the handle, if any.
+    fn sync_handle(&mut self) {
+        let Some(handle) = &self.handle else {
+            return;
+        };
+        let duration = handle.duration(self.timer.elapsed());
+        handle.set_duration(duration);
+        if duration != self.timer.duration() {
+            self.apply_duration(duration);
+        }
+    }
+
+    fn apply_duration(&mut self, duration: Duration) {
+        let elapsed = self.timer.elapsed().min(duration);
+        let was_completed = self.is_completed();
+        self.timer.set_duration(duration);
+        if was_completed && elapsed < duration {
+            self.timer.reset();
+        }
+        self.timer.set_elapsed(elapsed);
+    }
+
+    /// Get the current tweenable state.
+    pub fn state(&self) -> TweenState {
+        if self.is_completed() {
+            TweenState::Completed
+        } else {
+            TweenState::Active
+        }
+    }
+
+    /// Set a callback invoked when the animation completes.
+    ///
+    /// The callback when invoked receives as parameters the [`Entity`] on which
+    /// the target and the animator are, as well as a reference to the
+    /// current [`Tween`].
+    ///
+    /// Only non-looping tweenables can complete. The entity may be despawned
+    /// before any command queued by the callback is applied; see
+    /// [despawned entities](TweenCompleted#despawned-entities).
+    pub fn set_completed<C>(&mut self, callback: C)
+    where
+        C: Fn(Entity, &Self) + Send + Sync + 'static,
+    {
+        self.on_completed = Some(Box::new(callback));
+    }
+
+    /// Clear the callback invoked when the animation completes.
+    ///
+    /// See also [`set_completed()`].
+    ///
+    /// [`set_completed()`]: Tween::set_completed
+    pub fn clear_completed(&mut self) {
+        self.on_completed = None;
+    }
+
+    /// Enable or disable raising a completed event.
+    ///
+    /// If enabled, the tween will raise a [`TweenCompleted`] event when the
+    /// animation completed. This is similar to the [`set_completed()`]
+    /// callback, but uses Bevy events instead.
+    ///
+    /// See [`with_completed_event()`] for details.
+    ///
+    /// [`set_completed()`]: Tween::set_completed
+    /// [`with_completed_event()`]: Tween::with_completed_event
+    pub fn set_completed_event(&mut self, user_data: u64) {
+        self.event_data = Some(user_data);
+    }
+
+    /// Clear the event sent when the animation completes.
+    ///
+    /// See also [`set_completed_event()`].
+    ///
+    /// [`set_completed_event()`]: Tween::set_completed_event
+    pub fn clear_completed_event(&mut self) {
+        self.event_data = None;
+    }
+}
+
+impl<T> Tweenable<T> for Delay<T> {
+    fn id(&self) -> Option<TweenId> {
+        Some(self.id)
+    }
+
+    fn duration(&self) -> Duration {
+        match &self.handle {
+            Some(handle) => handle.duration(self.timer.elapsed()),
+            None => self.timer.duration(),
+        }
+    }
+
+    fn total_duration(&self) -> TotalDuration {
+        TotalDuration::Finite(self.duration())
+    }
+
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        self.sync_handle();
+        // need to reset() to clear finished() unfortunately
+        self.timer.reset();
+        self.timer.set_elapsed(elapsed);
+        // set_elapsed() does not update finished() etc. which we rely on
+        self.timer.tick(Duration::ZERO);
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.timer.elapsed().min(self.duration())
+    }
+
+    fn state(&self) -> TweenState {
+        Delay::state(self)
+    }
+
+    fn tick(
+        &mut self,
+        delta: Duration,
+        _target: &mut dyn Targetable<T>,
+        entity: Entity,
+        events: &mut Vec<TweenCompleted>,
+    ) -> TweenState {
+        let was_completed = self.is_completed();
+
+        self.sync_handle();
+        self.timer.tick(delta);
+
+        let state = self.state();
+
+        // If completed this frame, notify the user
+        if (state == TweenState::Completed) && !was_completed {
+            if let Some(user_data) = &self.event_data {
+                events.push(TweenCompleted {
+                    entity,
+                    user_data: *user_data,
+                    tween_id: self.id,
+                    played: self.duration(),
+                    remaining_repeats: Some(0),
+                    is_elapsed_trigger: false,
+                });
+            }
+            if let Some(cb) = &self.on_completed {
+                cb(entity, self);
+            }
+        }
+
+        state
+    }
+
+    fn apply_overrides(&mut self, overrides: &TweenOverrides) {
+        self.set_duration(overrides.scaled(self.duration()));
+        if let (Some(user_data), Some(_)) = (overrides.user_data(), self.event_data) {
+            self.event_data = Some(user_data);
+        }
+    }
+
+    fn collect_cancelled(&self, entity: Entity, cancelled: &mut Vec<TweenCancelled>) {
+        if let (Some(user_data), false) = (self.event_data, self.is_completed()) {
+            cancelled.push(TweenCancelled {
+                entity,
+                user_data,
+                tween_id: self.id,
+                progress_at_cancel: self.progress(),
+                played: self.elapsed(),
+            });
+        }
+    }
+
+    fn rewind(&mut self) {
+        self.sync_handle();
+        self.timer.reset();
+    }
+
+    fn sample(&self, _elapsed: Duration, _target: &mut T) -> bool {
+        true
+    }
+
+    fn save_state(&self) -> TweenableState {
+        TweenableState::Delay {
+            elapsed: self.elapsed(),
+            duration: self.duration(),
+        }
+    }
+
+    fn restore_state(&mut self, state: &TweenableState) -> bool {
+        let TweenableState::Delay { elapsed, duration } = state else {
+            return false;
+        };
+        if *duration != self.duration() {
+            self.set_duration(*duration);
+        }
+        self.set_elapsed(*elapsed);
+        true
+    }
+}
+
+/// Handle to release a [`WaitFor`] tweenable.
+///
+/// The handle is cheap to clone, and can be stored anywhere, for example in a
+/// resource read by the system handling the player input.
+#[derive(Debug, Clone, Default)]
+pub struct WaitHandle(Arc<AtomicBool>);
+
+impl WaitHandle {
+    /// Release the [`WaitFor`] tweenable associated with this handle, which
+    /// completes on its next tick.
+    pub fn release(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    /// Check if the [`WaitFor`] tweenable was released since it was last
+    /// armed.
+    #[must_use]
+    pub fn is_released(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+}
+
+/// A tweenable waiting for an external signal before completing.
+///
+/// This tweenable doesn't animate anything. It stays active until released
+/// through its [`WaitHandle`], then completes on its next tick. This allows
+/// interleaving animations with waiting for an external event, like the
+/// player pressing a button in a tutorial.
+///
+/// By default the wait has no timeout, and reports a zero duration, so the
+/// waiting time doesn't count toward the duration or the progress of a
+/// [`Sequence`] containing it. Inside a [`Sequence`], the sequence is held in
+/// place without its elapsed time advancing while waiting. Once released, the
+/// whole delta time of the tick is forwarded to the next tweenable of the
+/// sequence. With a timeout, the wait also completes once the timeout
+/// elapsed, and reports the timeout as its duration.
+///
+/// Seeking with [`Tweenable::set_elapsed()`] doesn't release the wait: it only completes
+/// if it was already released, or if the seek reaches its timeout. A
+/// [`Sequence`] seeked past an unreleased wait stops at that wait.
+///
+/// Rewinding the tweenable re-arms it, resetting the released state of its
+/// handle. Note that a [`Sequence`] rewinds each child once completed, so a
+/// handle can be reused to release the same wait on the next pass.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # let tween1 = Tween::new(EaseMethod::Linear, 1., TransformScaleLens { start: Vec3::ZERO, end: Vec3::ONE });
+/// # let tween2 = Tween::new(EaseMethod::Linear, 1., TransformScaleLens { start: Vec3::ONE, end: Vec3::ZERO });
+/// let (wait, handle) = WaitFor::new();
+/// let seq: Sequence<Transform> = tween1.then(wait).then(tween2);
+///
+/// // Later, from a system reading the player input
+/// handle.release();
+/// ```
+pub struct WaitFor<T> {
+    id: TweenId,
+    handle: WaitHandle,
+    timeout: Option<Duration>,
+    elapsed: Duration,
+    completed: bool,
+    _phantom: PhantomData<fn(T)>,
+}
+
+impl<T: 'static> WaitFor<T> {
+    /// Chain another [`Tweenable`] after this wait, making a [`Sequence`] with
+    /// the two.
+    #[must_use]
+    pub fn then(self, tween: impl Tweenable<T> + 'static) -> Sequence<T> {
+        Sequence::with_capacity(2).then(self).then(tween)
+    }
+}
+
+impl<T> WaitFor<T> {
+    /// Create a new wait without timeout, and the handle to release it.
+    #[must_use]
+    pub fn new() -> (Self, WaitHandle) {
+        let handle = WaitHandle::default();
+        let wait = Self {
+            id: TweenId::allocate(),
+            handle: handle.clone(),
+            timeout: None,
+            elapsed: Duration::ZERO,
+            completed: false,
+            _phantom: PhantomData,
+        };
+        (wait, handle)
+    }
+
+    /// Set a timeout after which the wait completes even if not released.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Get the timeout of the wait, if any.
+    #[must_use]
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Get a new handle to release this wait.
+    #[must_use]
+    pub fn handle(&self) -> WaitHandle {
+        self.handle.clone()
+    }
+}
+
+impl<T> Tweenable<T> for WaitFor<T> {
+    fn id(&self) -> Option<TweenId> {
+        Some(self.id)
+    }
+
+    fn duration(&self) -> Duration {
+        self.timeout.unwrap_or(Duration::ZERO)
+    }
+
+    fn total_duration(&self) -> TotalDuration {
+        TotalDuration::Finite(self.duration())
+    }
+
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        self.elapsed = elapsed.min(self.duration());
+        // Seeking doesn't release the wait, so it only completes if it was
+        // already released, or if the seek reaches its timeout.
+        self.completed =
+            self.handle.is_released() || self.timeout.is_some_and(|timeout| elapsed >= timeout);
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    fn state(&self) -> TweenState {
+        if self.completed {
+            TweenState::Completed
+        } else {
+            TweenState::Active
+        }
+    }
+
+    fn tick(
+        &mut self,
+        delta: Duration,
+        _target: &mut dyn Targetable<T>,
+        _entity: Entity,
+        _events: &mut Vec<TweenCompleted>,
+    ) -> TweenState {
+        if !self.completed {
+            if self.handle.is_released() {
+                self.set_elapsed(self.duration());
+            } else if let Some(timeout) = self.timeout {
+                self.set_elapsed(self.elapsed.saturating_add(delta).min(timeout));
+            }
+        }
+        if self.completed {
+            TweenState::Completed
+        } else {
+            TweenState::Active
+        }
+    }
+
+    fn rewind(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.completed = false;
+        self.handle.0.store(false, Ordering::Release);
+    }
+
+    fn progress(&self) -> f32 {
+        match self.timeout {
+            _ if self.completed => 1.,
+            Some(timeout) => fraction_progress(self.elapsed, timeout),
+            None => 0.,
+        }
+    }
+
+    fn times_completed(&self) -> u32 {
+        u32::from(self.completed)
+    }
+
+    fn sample(&self, _elapsed: Duration, _target: &mut T) -> bool {
+        true
+    }
+
+    fn save_state(&self) -> TweenableState {
+        TweenableState::WaitFor {
+            elapsed: self.elapsed,
+            completed: self.completed,
+        }
+    }
+
+    fn restore_state(&mut self, state: &TweenableState) -> bool {
+        let TweenableState::WaitFor { elapsed, completed } = state else {
+            return false;
+        };
+        self.elapsed = *elapsed;
+        self.completed = *completed;
+        true
+    }
+}
+
+/// A tweenable warping the time of another tweenable with an easing function.
+///
+/// This applies an additional easing on top of an existing tweenable, without
+/// the need to access or reconstruct it. This is useful to soften the start or
+/// the end of a tweenable built elsewhere, like a [`Sequence`] child produced
+/// by a data file.
+///
+/// Each iteration of the child is warped independently: the elapsed time of the
+/// [`Eased`] tweenable is converted to a progress in \[0:1\] within the
+/// current iteration, sampled by the easing method, and the child is moved to
+/// that warped progress.
+///
+/// # Double easing
+///
+/// The easing is composed with, and does not replace, any easing the child
+/// already applies internally. Wrapping a [`Tween`] using `ease_child` into an
+/// [`Eased`] using `ease_wrapper` produces at progress `p` the lens ratio
+/// `ease_child(ease_wrapper(p))`. Only wrapping a tween with a linear easing
+/// produces the same animation as a tween directly using the wrapper easing.
+///
+/// The warped progress is clamped to \[0:1\], so easing functions
+/// overshooting that range (like [`EaseFunction::BackIn`]) hold the child at
+/// its endpoint instead.
+///
+/// # Events
+///
+/// As long as the warped time moves forward, the child is ticked by the warped
+/// delta time, so any completion event or callback of the child is raised as
+/// usual. If an easing function moves the warped time backward, the child is
+/// instead seeked to the warped time.
+///
+/// [`EaseFunction::BackIn`]: crate::EaseFunction::BackIn
+pub struct Eased<T> {
+    id: TweenId,
+    child: BoxedTweenable<T>,
+    ease_method: EaseMethod,
+    elapsed: Duration,
+}
+
+impl<T> Eased<T> {
+    /// Create a new tweenable warping the time of `child` with `ease_method`.
+    #[must_use]
+    pub fn new(child: impl Into<BoxedTweenable<T>>, ease_method: impl Into<EaseMethod>) -> Self {
+        Self {
+            id: TweenId::allocate(),
+            child: child.into(),
+            ease_method: ease_method.into(),
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Get the wrapped tweenable.
+    #[must_use]
+    pub fn child(&self) -> &dyn Tweenable<T> {
+        self.child.as_ref()
+    }
+
+    /// Get the easing method applied on top of the wrapped tweenable.
+    #[must_use]
+    pub fn ease_method(&self) -> EaseMethod {
+        self.ease_method
+    }
+
+    /// Convert an elapsed time of this tweenable into the warped elapsed time
+    /// of the child.
+    fn warp(&self, elapsed: Duration) -> Duration {
+        if let TotalDuration::Finite(total_duration) = self.child.total_duration() {
+            if elapsed >= total_duration {
+                return total_duration;
+            }
+        }
+        let duration = self.child.duration();
+        if duration.is_zero() {
+            // No iteration to warp, like a zero-length child looping forever
+            return elapsed;
+        }
+        let iterations = (elapsed.as_nanos() / duration.as_nanos()) as u32;
+        let start = duration * iterations;
+        let progress = fraction_progress(elapsed - start, duration);
+        let progress = self.ease_method.sample(progress).clamp(0., 1.);
+        start + duration.mul_f64(f64::from(progress))
+    }
+}
+
+impl<T> Tweenable<T> for Eased<T> {
+    fn id(&self) -> Option<TweenId> {
+        Some(self.id)
+    }
+
+    fn duration(&self) -> Duration {
+        self.child.duration()
+    }
+
+    fn total_duration(&self) -> TotalDuration {
+        self.child.total_duration()
+    }
+
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        self.elapsed = match self.child.total_duration() {
+            TotalDuration::Finite(total_duration) => elapsed.min(total_duration),
+            TotalDuration::Infinite => elapsed,
+        };
+        let warped = self.warp(self.elapsed);
+        self.child.set_elapsed(warped);
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    fn state(&self) -> TweenState {
+        self.child.state()
+    }
+
+    fn tick(
+        &mut self,
+        delta: Duration,
+        target: &mut dyn Targetable<T>,
+        entity: Entity,
+        events: &mut Vec<TweenCompleted>,
+    ) -> TweenState {
+        self.elapsed = self.elapsed.saturating_add(delta);
+        if let TotalDuration::Finite(total_duration) = self.child.total_duration() {
+            self.elapsed = self.elapsed.min(total_duration);
+        }
+
+        let warped = self.warp(self.elapsed);
+        let child_elapsed = self.child.elapsed();
+        if warped >= child_elapsed {
+            self.child
+                .tick(warped - child_elapsed, target, entity, events)
+        } else {
+            self.child.set_elapsed(warped);
+            self.child.tick(Duration::ZERO, target, entity, events)
+        }
+    }
+
+    fn apply_tuning(&mut self, tuning: &TweenTuning) {
+        self.child.apply_tuning(tuning);
+    }
+
+    fn apply_overrides(&mut self, overrides: &TweenOverrides) {
+        self.child.apply_overrides(overrides);
+    }
+
+    fn boundary_value(&self) -> Option<Box<dyn Any + Send + Sync>> {
+        self.child.boundary_value()
+    }
+
+    fn current_value(&self) -> Option<Box<dyn Any + Send + Sync>> {
+        self.child.current_value()
+    }
+
+    fn blend_start(&mut self, from: Box<dyn Any + Send + Sync>, window: Duration) -> bool {
+        self.child.blend_start(from, window)
+    }
+
+    fn collect_cancelled(&self, entity: Entity, cancelled: &mut Vec<TweenCancelled>) {
+        self.child.collect_cancelled(entity, cancelled);
+    }
+
+    fn take_token_cancelled(&mut self, cancelled: &mut Vec<TweenCancelled>) {
+        self.child.take_token_cancelled(cancelled);
+    }
+
+    fn rewind(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.child.rewind();
+    }
+
+    fn sample(&self, elapsed: Duration, target: &mut T) -> bool {
+        self.child.sample(self.warp(elapsed), target)
+    }
+
+    fn save_state(&self) -> TweenableState {
+        TweenableState::Eased {
+            elapsed: self.elapsed,
+            child: Box::new(self.child.save_state()),
+        }
+    }
+
+    fn restore_state(&mut self, state: &TweenableState) -> bool {
+        let TweenableState::Eased { elapsed, child } = state else {
+            return false;
+        };
+        self.elapsed = *elapsed;
+        self.child.restore_state(child)
+    }
+
+    fn validate(&self) -> Result<(), TweenValidationError> {
+        self.child.validate()
+    }
+}
+
+/// A tweenable playing only a sub-interval of another tweenable.
+///
+/// The slice plays its child from the `start` to the `end` fraction of the
+/// child's [`duration()`], for example the `[0.3:0.7]` slice to preview the
+/// middle of an animation or resume it from a checkpoint. The duration of the
+/// slice is the length of that sub-interval, and the slice doesn't loop.
+///
+/// On the first tick, the child is seeked to the start of the slice and
+/// applied. The child is then ticked forward with the slice, up to the end of
+/// the slice, so a child [`Sequence`] applies the end state of each of its own
+/// children crossed by the slice. Seeking the slice with [`set_elapsed()`]
+/// seeks the child within the slice.
+///
+/// # Events
+///
+/// The child never actually plays to its end, so the [`TweenCompleted`] events
+/// it raises are suppressed, even if the slice ends at the end of the child.
+/// The slice raises its own event when it completes, if enabled with
+/// [`with_completed_event()`]. Completion callbacks of the child can't be
+/// suppressed, and are still invoked if the slice crosses them.
+///
+/// [`duration()`]: Tweenable::duration
+/// [`set_elapsed()`]: Tweenable::set_elapsed
+/// [`with_completed_event()`]: Slice::with_completed_event
+pub struct Slice<T> {
+    id: TweenId,
+    child: BoxedTweenable<T>,
+    start: f32,
+    end: f32,
+    offset: Duration,
+    duration: Duration,
+    elapsed: Duration,
+    completed: bool,
+    needs_seek: bool,
+    event_data: Option<u64>,
+}
+
+impl<T> Slice<T> {
+    /// Update the bounds of the slice after its child changed duration.
+    fn update_duration(&mut self) {
+        self.offset = self.child.duration().mul_f64(f64::from(self.start));
+        self.duration = self
+            .child
+            .duration()
+            .mul_f64(f64::from(self.end))
+            .saturating_sub(self.offset);
+        self.elapsed = self.elapsed.min(self.duration);
+    }
+
+    /// Create a new tweenable playing `child` from the `start` to the `end`
+    /// fraction of its duration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` and `end` are not in \[0:1\], or if the slice is
+    /// empty (`start >= end`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::math::Vec3;
+    /// # use std::time::Duration;
+    /// let tween = Tween::new(
+    ///     EaseFunction::QuadraticInOut,
+    ///     2.,
+    ///     TransformPositionLens {
+    ///         start: Vec3::ZERO,
+    ///         end: Vec3::new(3.5, 0., 0.),
+    ///     },
+    /// );
+    /// let slice = Slice::new(tween, 0.25, 0.75).with_completed_event(42);
+    /// assert_eq!(slice.duration(), Duration::from_secs(1));
+    /// ```
+    #[must_use]
+    pub fn new(child: impl Into<BoxedTweenable<T>>, start: f32, end: f32) -> Self {
+        assert!((0. ..=1.).contains(&start) && (0. ..=1.).contains(&end));
+        assert!(start < end);
+        let child = child.into();
+        let offset = child.duration().mul_f64(f64::from(start));
+        let duration = child
+            .duration()
+            .mul_f64(f64::from(end))
+            .saturating_sub(offset);
+        Self {
+            id: TweenId::allocate(),
+            child,
+            start,
+            end,
+            offset,
+            duration,
+            elapsed: Duration::ZERO,
+            completed: false,
+            needs_seek: true,
+            event_data: None,
+        }
+    }
+
+    /// Get the wrapped tweenable.
+    #[must_use]
+    pub fn child(&self) -> &dyn Tweenable<T> {
+        self.child.as_ref()
+    }
+
+    /// Get the fraction of the duration of the child the slice starts at.
+    #[must_use]
+    pub fn start(&self) -> f32 {
+        self.start
+    }
+
+    /// Get the fraction of the duration of the child the slice ends at.
+    #[must_use]
+    pub fn end(&self) -> f32 {
+        self.end
+    }
+
+    /// Enable raising a completed event.
+    ///
+    /// If enabled, the slice will raise a [`TweenCompleted`] event when it
+    /// completed, that is when its child reaches the end fraction of the
+    /// slice.
+    #[must_use]
+    pub fn with_completed_event(mut self, user_data: u64) -> Self {
+        self.event_data = Some(user_data);
+        self
+    }
+
+    /// Enable or disable raising a completed event.
+    ///
+    /// See [`with_completed_event()`] for details.
+    ///
+    /// [`with_completed_event()`]: Slice::with_completed_event
+    pub fn set_completed_event(&mut self, user_data: u64) {
+        self.event_data = Some(user_data);
+    }
+
+    /// Clear the event sent when the slice completes.
+    pub fn clear_completed_event(&mut self) {
+        self.event_data = None;
+    }
+}
+
+impl<T> Tweenable<T> for Slice<T> {
+    fn id(&self) -> Option<TweenId> {
+        Some(self.id)
+    }
+
+    fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    fn total_duration(&self) -> TotalDuration {
+        TotalDuration::Finite(self.duration)
+    }
+
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        self.elapsed = elapsed.min(self.duration);
+        self.completed = self.elapsed >= self.duration;
+        self.needs_seek = true;
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    fn state(&self) -> TweenState {
+        if self.completed {
+            TweenState::Completed
+        } else {
+            TweenState::Active
+        }
+    }
+
+    fn tick(
+        &mut self,
+        delta: Duration,
+        target: &mut dyn Targetable<T>,
+        entity: Entity,
+        events: &mut Vec<TweenCompleted>,
+    ) -> TweenState {
+        // Once completed, the target is left untouched, unless the slice was
+        // seeked since it was last applied.
+        if self.completed && !self.needs_seek {
+            return TweenState::Completed;
+        }
+
+        // The events of the child are never forwarded
+        let mut child_events = Vec::new();
+        let was_completed = self.completed;
+        self.elapsed = self.elapsed.saturating_add(delta).min(self.duration);
+        if std::mem::take(&mut self.needs_seek) {
+            self.child.set_elapsed(self.offset + self.elapsed);
+            self.child
+                .tick(Duration::ZERO, target, entity, &mut child_events);
+        } else {
+            let child_elapsed = self.child.elapsed().saturating_sub(self.offset);
+            let child_delta = self.elapsed.saturating_sub(child_elapsed);
+            self.child
+                .tick(child_delta, target, entity, &mut child_events);
+        }
+
+        self.completed = self.elapsed >= self.duration;
+        if !self.completed {
+            return TweenState::Active;
+        }
+        if !was_completed {
+            if let Some(user_data) = self.event_data {
+                events.push(TweenCompleted {
+                    entity,
+                    user_data,
+                    tween_id: self.id,
+                    played: self.duration,
+                    remaining_repeats: Some(0),
+                    is_elapsed_trigger: false,
+                });
+            }
+        }
+        TweenState::Completed
+    }
+
+    fn apply_tuning(&mut self, tuning: &TweenTuning) {
+        self.child.apply_tuning(tuning);
+        self.update_duration();
+    }
+
+    fn apply_overrides(&mut self, overrides: &TweenOverrides) {
+        self.child.apply_overrides(overrides);
+        self.update_duration();
+        if let (Some(user_data), Some(_)) = (overrides.user_data(), self.event_data) {
+            self.event_data = Some(user_data);
+        }
+    }
+
+    fn collect_cancelled(&self, entity: Entity, cancelled: &mut Vec<TweenCancelled>) {
+        // Like completions, the cancellations of the child are not forwarded
+        if let (Some(user_data), false) = (self.event_data, self.completed) {
+            cancelled.push(TweenCancelled {
+                entity,
+                user_data,
+                tween_id: self.id,
+                progress_at_cancel: self.progress(),
+                played: self.elapsed(),
+            });
+        }
+    }
+
+    fn take_token_cancelled(&mut self, cancelled: &mut Vec<TweenCancelled>) {
+        // Like completions, the cancellations of the child are not forwarded
+        let _ = cancelled;
+        self.child.take_token_cancelled(&mut vec![]);
+    }
+
+    fn rewind(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.completed = false;
+        self.needs_seek = true;
+        self.child.rewind();
+    }
+
+    fn times_completed(&self) -> u32 {
+        u32::from(self.completed)
+    }
+
+    fn sample(&self, elapsed: Duration, target: &mut T) -> bool {
+        self.child
+            .sample(self.offset + elapsed.min(self.duration), target)
+    }
+
+    fn save_state(&self) -> TweenableState {
+        TweenableState::Slice {
+            elapsed: self.elapsed,
+            completed: self.completed,
+            child: Box::new(self.child.save_state()),
+        }
+    }
+
+    fn restore_state(&mut self, state: &TweenableState) -> bool {
+        let TweenableState::Slice {
+            elapsed,
+            completed,
+            child,
+        } = state
+        else {
+            return false;
+        };
+        self.elapsed = (*elapsed).min(self.duration);
+        self.completed = *completed;
+        // The child is seeked to the slice on the next tick anyway
+        self.needs_seek = true;
+        self.child.restore_state(child)
+    }
+
+    fn validate(&self) -> Result<(), TweenValidationError> {
+        self.child.validate()
+    }
+}
+
+/// Placeholder replacing a completed tweenable in a shrunk animator.
+///
+/// This retains the timing information of the dropped tweenable, so that
+/// queries on the animator keep returning the same values, but doesn't animate
+/// anything.
+pub(crate) struct CompletedTweenable {
+    id: Option<TweenId>,
+    duration: Duration,
+    total_duration: TotalDuration,
+    elapsed: Duration,
+    times_completed: u32,
+}
+
+impl CompletedTweenable {
+    pub(crate) fn new<T>(tweenable: &dyn Tweenable<T>) -> Self {
+        Self {
+            id: tweenable.id(),
+            duration: tweenable.duration(),
+            total_duration: tweenable.total_duration(),
+            elapsed: tweenable.elapsed(),
+            times_completed: tweenable.times_completed(),
+        }
+    }
+}
+
+impl<T> Tweenable<T> for CompletedTweenable {
+    fn id(&self) -> Option<TweenId> {
+        self.id
+    }
+
+    fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    fn total_duration(&self) -> TotalDuration {
+        self.total_duration
+    }
+
+    fn set_elapsed(&mut self, _elapsed: Duration) {}
+
+    fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    fn state(&self) -> TweenState {
+        TweenState::Completed
+    }
+
+    fn tick(
+        &mut self,
+        _delta: Duration,
+        _target: &mut dyn Targetable<T>,
+        _entity: Entity,
+        _events: &mut Vec<TweenCompleted>,
+    ) -> TweenState {
+        TweenState::Completed
+    }
+
+    fn rewind(&mut self) {}
+
+    fn progress(&self) -> f32 {
+        1.
+    }
+
+    fn times_completed(&self) -> u32 {
+        self.times_completed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use bevy::ecs::system::{CommandQueue, SystemState};
+
+    use super::*;
+    use crate::{lens::*, test_utils::*, Animator, EaseFunction};
+
+    #[derive(Default, Copy, Clone)]
+    struct CallbackMonitor {
+        invoke_count: u64,
+        last_reported_count: u32,
+    }
+
+    /// Utility to create a tween for testing.
+    fn make_test_tween() -> Tween<Transform> {
+        Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+    }
+
+    /// Utility to create a test environment to tick a tween.
+    fn make_test_env() -> (World, Entity) {
+        let mut world = World::new();
+        world.init_resource::<Events<TweenCompleted>>();
+        let entity = world.spawn(Transform::default()).id();
+        (world, entity)
+    }
+
+    /// Manually tick a test tweenable targeting a component.
+    fn manual_tick_component<T: Component>(
+        duration: Duration,
+        tween: &mut dyn Tweenable<T>,
+        world: &mut World,
+        entity: Entity,
+    ) -> TweenState {
+        world.resource_scope(
+            |world: &mut World, mut events: Mut<Events<TweenCompleted>>| {
+                let transform = world.get_mut::<T>(entity).unwrap();
+                let mut target = ComponentTarget::new(transform);
+                let mut completed = Vec::new();
+                let state = tween.tick(duration, &mut target, entity, &mut completed);
+                events.send_batch(completed);
+                state
+            },
+        )
+    }
+
+    #[test]
+    fn anim_clock_precision() {
+        let duration = Duration::from_millis(1);
+        let mut clock = AnimClock::new(duration);
+        clock.total_duration = TotalDuration::Infinite;
+
+        let test_ticks = [
+            Duration::from_micros(123),
+            Duration::from_millis(1),
+            Duration::from_secs_f32(1. / 24.),
+            Duration::from_secs_f32(1. / 30.),
+            Duration::from_secs_f32(1. / 60.),
+            Duration::from_secs_f32(1. / 120.),
+            Duration::from_secs_f32(1. / 144.),
+            Duration::from_secs_f32(1. / 240.),
+        ];
+
+        let mut times_completed = 0;
+        let mut total_duration = Duration::ZERO;
+        for i in 0..10_000_000 {
+            let tick = test_ticks[i % test_ticks.len()];
+            times_completed += clock.tick(tick).1;
+            total_duration += tick;
+        }
+
+        assert_eq!(
+            (total_duration.as_secs_f64() / duration.as_secs_f64()) as i32,
+            times_completed
+        );
+    }
+
+    #[test]
+    fn into_repeat_count() {
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+        .with_repeat_count(5);
+        assert_eq!(
+            tween.total_duration(),
+            TotalDuration::Finite(Duration::from_secs(5))
+        );
+
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+        .with_repeat_count(Duration::from_secs(3));
+        assert_eq!(
+            tween.total_duration(),
+            TotalDuration::Finite(Duration::from_secs(3))
+        );
+    }
+
+    /// Test ticking of a single tween in isolation.
+    #[test]
+    fn tween_tick() {
+        for tweening_direction in &[TweeningDirection::Forward, TweeningDirection::Backward] {
+            for (count, strategy) in &[
+                (RepeatCount::Finite(1), RepeatStrategy::default()),
+                (RepeatCount::Infinite, RepeatStrategy::Repeat),
+                (RepeatCount::Finite(2), RepeatStrategy::Repeat),
+                (RepeatCount::Infinite, RepeatStrategy::MirroredRepeat),
+                (RepeatCount::Finite(2), RepeatStrategy::MirroredRepeat),
+                (RepeatCount::Finite(3), RepeatStrategy::Repeat),
+                (RepeatCount::Finite(3), RepeatStrategy::MirroredRepeat),
+            ] {
+                println!(
+                    "TweeningType: count={count:?} strategy={strategy:?} dir={tweening_direction:?}",
+                );
+
+                // Create a linear tween over 1 second
+                let mut tween = make_test_tween()
+                    .with_direction(*tweening_direction)
+                    .with_repeat_count(*count)
+                    .with_repeat_strategy(*strategy);
+                assert_eq!(tween.direction(), *tweening_direction);
+                assert!(tween.on_completed.is_none());
+                assert!(tween.event_data.is_none());
+
+                let (mut world, entity) = make_test_env();
+                let mut event_reader_system_state: SystemState<EventReader<TweenCompleted>> =
+                    SystemState::new(&mut world);
+
+                // Register callbacks to count started/ended events
+                let callback_monitor = Arc::new(Mutex::new(CallbackMonitor::default()));
+                let cb_mon_ptr = Arc::clone(&callback_monitor);
+                let reference_entity = entity;
+                tween.set_completed(move |completed_entity, tween| {
+                    assert_eq!(completed_entity, reference_entity);
+                    let mut cb_mon = cb_mon_ptr.lock().unwrap();
+                    cb_mon.invoke_count += 1;
+                    cb_mon.last_reported_count = tween.times_completed();
+                });
+                assert!(tween.on_completed.is_some());
+                assert!(tween.event_data.is_none());
+                assert_eq!(callback_monitor.lock().unwrap().invoke_count, 0);
+
+                // Activate event sending
+                const USER_DATA: u64 = 54789; // dummy
+                tween.set_completed_event(USER_DATA);
+                assert!(tween.event_data.is_some());
+                assert_eq!(tween.event_data.unwrap(), USER_DATA);
+
+                // The far endpoint of the first leg, reached when changing direction
+                let apex = if tweening_direction.is_forward() {
+                    Vec3::ONE
+                } else {
+                    Vec3::ZERO
+                };
+                let mut apex_count = 0;
+
+                // Loop over 2.2 seconds, so greater than one ping-pong loop
+                let tick_duration = Duration::from_millis(200);
+                for i in 1..=11 {
+                    // Calculate expected values
+                    let (progress, times_completed, mut direction, expected_state, just_completed) =
+                        match count {
+                            RepeatCount::Finite(1) => {
+                                let progress = (i as f32 * 0.2).min(1.0);
                                 let times_completed = u32::from(i >= 5);
                                 let state = if i < 5 {
                                     TweenState::Active
@@ -1355,7 +5421,7 @@ mod tests {
                                         progress,
                                         times_completed,
                                         TweeningDirection::Forward,
-                                        if i < 10 {
+                                        if i < 5 * count {
                                             TweenState::Active
                                         } else {
                                             TweenState::Completed
@@ -1366,7 +5432,8 @@ mod tests {
                                     let i5 = i % 5;
                                     let times_completed = i / 5;
                                     // Once Completed, the direction doesn't change
-                                    let direction = if i >= 5 {
+                                    let direction_changes = times_completed.min(count - 1);
+                                    let direction = if direction_changes % 2 == 1 {
                                         TweeningDirection::Backward
                                     } else {
                                         TweeningDirection::Forward
@@ -1376,7 +5443,7 @@ mod tests {
                                         progress,
                                         times_completed,
                                         direction,
-                                        if i < 10 {
+                                        if i < 5 * count {
                                             TweenState::Active
                                         } else {
                                             TweenState::Completed
@@ -1418,6 +5485,10 @@ mod tests {
                             }
                             RepeatCount::For(_) => panic!("Untested"),
                         };
+                    let remaining_repeats = match count {
+                        RepeatCount::Finite(count) => Some(count - times_completed),
+                        _ => None,
+                    };
                     let factor = if tweening_direction.is_backward() {
                         direction = !direction;
                         1. - progress
@@ -1434,217 +5505,1470 @@ mod tests {
                         progress, factor, times_completed, direction, expected_state, just_completed, expected_translation
                     );
 
-                    // Tick the tween
-                    let actual_state =
-                        manual_tick_component(tick_duration, &mut tween, &mut world, entity);
+                    // Tick the tween
+                    let actual_state =
+                        manual_tick_component(tick_duration, &mut tween, &mut world, entity);
+
+                    // Propagate events
+                    {
+                        let mut events = world.resource_mut::<Events<TweenCompleted>>();
+                        events.update();
+                    }
+
+                    // Check actual values
+                    assert_eq!(tween.direction(), direction);
+                    assert_eq!(actual_state, expected_state);
+                    assert_approx_eq!(tween.progress(), progress);
+                    assert_eq!(tween.times_completed(), times_completed);
+                    assert_eq!(tween.repeat_count(), *count);
+                    assert_eq!(tween.remaining_repeats(), remaining_repeats);
+                    assert_eq!(tween.is_final_cycle(), remaining_repeats == Some(1));
+                    let transform = world.entity(entity).get::<Transform>().unwrap();
+                    assert!(transform
+                        .translation
+                        .abs_diff_eq(expected_translation, 1e-5));
+                    assert!(transform.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
+                    if transform.translation.abs_diff_eq(apex, 1e-5) {
+                        apex_count += 1;
+                    }
+                    let cb_mon = callback_monitor.lock().unwrap();
+                    assert_eq!(cb_mon.invoke_count, times_completed as u64);
+                    assert_eq!(cb_mon.last_reported_count, times_completed);
+                    {
+                        let mut event_reader = event_reader_system_state.get_mut(&mut world);
+                        let event = event_reader.read().next();
+                        if just_completed {
+                            assert!(event.is_some());
+                            if let Some(event) = event {
+                                assert_eq!(event.entity, entity);
+                                assert_eq!(event.user_data, USER_DATA);
+                                assert_eq!(event.remaining_repeats, remaining_repeats);
+                            }
+                        } else {
+                            assert!(event.is_none());
+                        }
+                    }
+                }
+
+                // Ping-pong reaches the far endpoint exactly once per cycle
+                if *strategy == RepeatStrategy::MirroredRepeat {
+                    assert_eq!(apex_count, 1);
+                }
+
+                // Rewind
+                tween.rewind();
+                assert_eq!(tween.direction(), *tweening_direction); // does not change
+                assert_approx_eq!(tween.progress(), 0.);
+                assert_eq!(tween.times_completed(), 0);
+
+                // Dummy tick to update target
+                let actual_state =
+                    manual_tick_component(Duration::ZERO, &mut tween, &mut world, entity);
+                assert_eq!(actual_state, TweenState::Active);
+                let expected_translation = if tweening_direction.is_backward() {
+                    Vec3::ONE
+                } else {
+                    Vec3::ZERO
+                };
+                let transform = world.entity(entity).get::<Transform>().unwrap();
+                assert!(transform
+                    .translation
+                    .abs_diff_eq(expected_translation, 1e-5));
+                assert!(transform.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
+
+                // Clear callback
+                tween.clear_completed();
+                assert!(tween.on_completed.is_none());
+
+                // Clear event sending
+                tween.clear_completed_event();
+                assert!(tween.event_data.is_none());
+            }
+        }
+    }
+
+    /// Ping-pong tweens apply the exact endpoint on the tick they turn around,
+    /// even if that tick overshoots the endpoint.
+    #[test]
+    fn tween_mirrored_apex() {
+        let mut tween = make_test_tween()
+            .with_repeat_count(RepeatCount::Infinite)
+            .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
+
+        let (mut world, entity) = make_test_env();
+
+        let mut values = vec![];
+        for _ in 0..7 {
+            manual_tick_component(Duration::from_millis(300), &mut tween, &mut world, entity);
+            let transform = world.entity(entity).get::<Transform>().unwrap();
+            values.push(transform.translation.x);
+        }
+
+        // 0.3, 0.6, 0.9, 1.2 (turnaround), 1.5, 1.8, 2.1 (turnaround)
+        for (actual, expected) in values.iter().zip([0.3, 0.6, 0.9, 1., 0.5, 0.2, 0.]) {
+            assert_approx_eq!(*actual, expected);
+        }
+
+        // The reported progress is not affected, and reflects the actual time
+        assert_eq!(tween.direction(), TweeningDirection::Forward);
+        assert_approx_eq!(tween.progress(), 0.1);
+        assert_eq!(tween.times_completed(), 2);
+    }
+
+    /// A ping-pong-once tween completes after a single round trip, back at
+    /// its exact start value, and notifies completion only once.
+    #[test]
+    fn tween_ping_pong_once() {
+        let count = Arc::new(Mutex::new(0));
+        let count_cb = Arc::clone(&count);
+        let mut tween = make_test_tween()
+            .ping_pong_once()
+            .with_completed_event(7)
+            .with_completed(move |_, _| {
+                *count_cb.lock().unwrap() += 1;
+            });
+        assert_eq!(
+            tween.total_duration(),
+            TotalDuration::Finite(Duration::from_secs(2))
+        );
+
+        let (mut world, entity) = make_test_env();
+
+        let mut values = vec![];
+        let mut states = vec![];
+        for _ in 0..7 {
+            states.push(manual_tick_component(
+                Duration::from_millis(300),
+                &mut tween,
+                &mut world,
+                entity,
+            ));
+            let transform = world.entity(entity).get::<Transform>().unwrap();
+            values.push(transform.translation.x);
+            let events = world.resource::<Events<TweenCompleted>>();
+            let expected_events = usize::from(states.len() == 7);
+            assert_eq!(events.get_reader().len(events), expected_events);
+        }
+
+        for (actual, expected) in values.iter().zip([0.3, 0.6, 0.9, 1., 0.5, 0.2]) {
+            assert_approx_eq!(*actual, expected);
+        }
+        // Exactly back to the start value
+        assert_eq!(values[6], 0.);
+        assert_eq!(states[5], TweenState::Active);
+        assert_eq!(states[6], TweenState::Completed);
+        assert_eq!(*count.lock().unwrap(), 1);
+        assert_eq!(tween.times_completed(), 2);
+    }
+
+    /// Sampling a tween previews any time of its timeline without changing
+    /// it, whatever its current direction.
+    #[test]
+    fn tween_sample() {
+        let mut tween = make_test_tween()
+            .with_repeat_count(4)
+            .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
+
+        let (mut world, entity) = make_test_env();
+        manual_tick_component(Duration::from_millis(1300), &mut tween, &mut world, entity);
+        assert_eq!(tween.direction(), TweeningDirection::Backward);
+
+        for (ms, expected) in [
+            (0, 0.),
+            (500, 0.5),
+            (1250, 0.75),
+            (2250, 0.25),
+            (3500, 0.5),
+            (4000, 0.),
+            (9000, 0.),
+        ] {
+            let mut transform = Transform::default();
+            assert!(tween.sample(Duration::from_millis(ms), &mut transform));
+            assert_approx_eq!(transform.translation.x, expected);
+        }
+
+        assert_eq!(tween.elapsed(), Duration::from_millis(1300));
+        assert_eq!(tween.direction(), TweeningDirection::Backward);
+        // Turnaround tick applies the apex
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert_approx_eq!(transform.translation.x, 1.);
+    }
+
+    #[test]
+    fn tween_dir() {
+        let mut tween = make_test_tween();
+
+        // Default
+        assert_eq!(tween.direction(), TweeningDirection::Forward);
+        assert_approx_eq!(tween.progress(), 0.0);
+
+        // no-op
+        tween.set_direction(TweeningDirection::Forward);
+        assert_eq!(tween.direction(), TweeningDirection::Forward);
+        assert_approx_eq!(tween.progress(), 0.0);
+
+        // Backward
+        tween.set_direction(TweeningDirection::Backward);
+        assert_eq!(tween.direction(), TweeningDirection::Backward);
+        // progress is independent of direction
+        assert_approx_eq!(tween.progress(), 0.0);
+
+        // Progress-invariant
+        tween.set_direction(TweeningDirection::Forward);
+        tween.set_progress(0.3);
+        assert_approx_eq!(tween.progress(), 0.3);
+        tween.set_direction(TweeningDirection::Backward);
+        // progress is independent of direction
+        assert_approx_eq!(tween.progress(), 0.3);
+
+        let (mut world, entity) = make_test_env();
+
+        // Progress always increases alongside the current direction
+        tween.set_direction(TweeningDirection::Backward);
+        assert_approx_eq!(tween.progress(), 0.3);
+        manual_tick_component(Duration::from_millis(100), &mut tween, &mut world, entity);
+        assert_approx_eq!(tween.progress(), 0.4);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.6), 1e-5));
+    }
+
+    #[test]
+    fn tween_elapsed() {
+        let mut tween = make_test_tween();
+
+        let duration = tween.duration();
+        let elapsed = tween.elapsed();
+
+        assert_eq!(elapsed, Duration::ZERO);
+        assert_eq!(duration, Duration::from_secs(1));
+
+        for ms in [0, 1, 500, 100, 300, 999, 847, 1000, 900] {
+            let elapsed = Duration::from_millis(ms);
+            tween.set_elapsed(elapsed);
+            assert_eq!(tween.elapsed(), elapsed);
+
+            let progress = (elapsed.as_secs_f64() / duration.as_secs_f64()) as f32;
+            assert_approx_eq!(tween.progress(), progress);
+
+            let times_completed = u32::from(ms == 1000);
+            assert_eq!(tween.times_completed(), times_completed);
+        }
+    }
+
+    /// Elapsed triggers raise their event once the tween played for long
+    /// enough, whatever its progress, marked as triggers.
+    #[test]
+    fn tween_elapsed_trigger() {
+        let mut tween = make_test_tween()
+            .with_repeat_count(RepeatCount::Infinite)
+            .with_elapsed_trigger(Duration::from_secs(10), 2)
+            .with_elapsed_trigger(Duration::from_millis(2500), 1);
+        let (mut world, entity) = make_test_env();
+        let mut tick = |tween: &mut Tween<Transform>, millis| {
+            manual_tick_component(Duration::from_millis(millis), tween, &mut world, entity);
+            world
+                .resource_mut::<Events<TweenCompleted>>()
+                .drain()
+                .map(|ev| {
+                    assert!(ev.is_elapsed_trigger);
+                    ev.user_data
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert!(tick(&mut tween, 2000).is_empty());
+        assert_eq!(tick(&mut tween, 600), vec![1]);
+        assert!(tick(&mut tween, 600).is_empty());
+        assert_eq!(tween.total_elapsed(), Duration::from_millis(3200));
+
+        // Seeking doesn't change the total elapsed time
+        tween.set_progress(0.5);
+        assert_eq!(tween.total_elapsed(), Duration::from_millis(3200));
+
+        // A large delta still raises the event, once
+        assert_eq!(tick(&mut tween, 20_000), vec![2]);
+        assert!(tick(&mut tween, 20_000).is_empty());
+        assert_eq!(tween.total_elapsed(), Duration::from_millis(43_200));
+
+        // Rewinding resets the triggers, raised in order on the same tick
+        tween.rewind();
+        assert_eq!(tween.total_elapsed(), Duration::ZERO);
+        assert_eq!(tick(&mut tween, 10_000), vec![1, 2]);
+
+        // A completed tween doesn't accumulate time
+        let mut tween = make_test_tween().with_elapsed_trigger(Duration::from_secs(2), 3);
+        assert!(tick(&mut tween, 5000).is_empty());
+        assert_eq!(tween.total_elapsed(), Duration::from_secs(1));
+        assert!(tick(&mut tween, 5000).is_empty());
+    }
+
+    #[test]
+    fn tween_completed_behavior() {
+        let seen = Arc::new(Mutex::new(vec![]));
+        let seen_cb = Arc::clone(&seen);
+        let mut tween = make_test_tween()
+            .with_repeat_count(2)
+            .with_completed_event(7)
+            .with_completed_with_target(move |_, _, transform: &Transform| {
+                seen_cb.lock().unwrap().push(transform.translation);
+            })
+            .with_completed_behavior(CompletedBehavior::SnapToStart);
+        assert_eq!(tween.completed_behavior(), CompletedBehavior::SnapToStart);
+        let (mut world, entity) = make_test_env();
+        let mut tick = |tween: &mut Tween<Transform>, millis| {
+            let state =
+                manual_tick_component(Duration::from_millis(millis), tween, &mut world, entity);
+            let events = world
+                .resource_mut::<Events<TweenCompleted>>()
+                .drain()
+                .count();
+            let translation = world.get::<Transform>(entity).unwrap().translation;
+            (state, events, translation)
+        };
+
+        // Completing a loop doesn't snap back
+        let (state, events, translation) = tick(&mut tween, 1200);
+        assert_eq!((state, events), (TweenState::Active, 1));
+        assert!(translation.abs_diff_eq(Vec3::splat(0.2), 1e-5));
+
+        // Completing the tween notifies with the end value, then restores the
+        // start value
+        let (state, events, translation) = tick(&mut tween, 1000);
+        assert_eq!((state, events), (TweenState::Completed, 1));
+        assert_eq!(translation, Vec3::ZERO);
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 2);
+        assert_eq!(seen[1], Vec3::ONE);
+
+        // And the target is left untouched afterwards
+        let (state, events, translation) = tick(&mut tween, 1000);
+        assert_eq!((state, events), (TweenState::Completed, 0));
+        assert_eq!(translation, Vec3::ZERO);
+
+        // The start of a backward tween is its end value
+        let mut tween = make_test_tween()
+            .with_direction(TweeningDirection::Backward)
+            .with_completed_behavior(CompletedBehavior::SnapToStart);
+        let (_, _, translation) = tick(&mut tween, 2000);
+        assert_eq!(translation, Vec3::ONE);
+
+        // Holding the end value is the default
+        let mut tween = make_test_tween();
+        assert_eq!(tween.completed_behavior(), CompletedBehavior::HoldEnd);
+        let (_, _, translation) = tick(&mut tween, 2000);
+        assert_eq!(translation, Vec3::ONE);
+    }
+
+    /// Lens recording the eased and raw ratios it's invoked with.
+    struct RatiosLens(Arc<Mutex<Vec<(f32, f32, TweeningDirection)>>>);
+
+    impl Lens<Transform> for RatiosLens {
+        fn lerp(&mut self, _target: &mut Transform, _ratio: f32) {
+            unreachable!()
+        }
+
+        fn lerp_ex(
+            &mut self,
+            _target: &mut Transform,
+            eased: f32,
+            raw: f32,
+            direction: TweeningDirection,
+        ) {
+            self.0.lock().unwrap().push((eased, raw, direction));
+        }
+    }
+
+    #[test]
+    fn tween_lens_eased_and_raw_ratios() {
+        let ratios = Arc::new(Mutex::new(vec![]));
+        let mut tween = Tween::new(
+            EaseFunction::QuadraticIn,
+            Duration::from_secs(1),
+            RatiosLens(Arc::clone(&ratios)),
+        )
+        .with_repeat_count(2)
+        .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
+        let (mut world, entity) = make_test_env();
+        for millis in [0, 250, 500, 500, 250, 250] {
+            manual_tick_component(
+                Duration::from_millis(millis),
+                &mut tween,
+                &mut world,
+                entity,
+            );
+        }
+
+        // The raw ratio follows the time, and the eased one is the easing
+        // function sampled at it, in both directions
+        let ratios = ratios.lock().unwrap();
+        let expected = [
+            (0., TweeningDirection::Forward),
+            (0.25, TweeningDirection::Forward),
+            (0.75, TweeningDirection::Forward),
+            // The apex is applied exactly when a new leg starts
+            (1., TweeningDirection::Backward),
+            (0.5, TweeningDirection::Backward),
+            (0.25, TweeningDirection::Backward),
+        ];
+        assert_eq!(ratios.len(), expected.len());
+        for (&(eased, raw, direction), (expected_raw, expected_direction)) in
+            ratios.iter().zip(expected)
+        {
+            assert_approx_eq!(raw, expected_raw);
+            assert_approx_eq!(eased, expected_raw * expected_raw);
+            assert_eq!(direction, expected_direction);
+        }
+    }
+
+    #[test]
+    fn tween_on_update() {
+        let (mut world, entity) = make_test_env();
+        let values = Arc::new(Mutex::new(vec![]));
+        let tween_values = Arc::clone(&values);
+        let mut tween = make_test_tween()
+            .with_on_update(move |_, transform: &Transform| {
+                tween_values.lock().unwrap().push(transform.translation.x);
+            })
+            .with_completed_behavior(CompletedBehavior::SnapToStart);
+
+        // Invoked once per tick with the value written, after the end of the
+        // animation snapped back to the start
+        for _ in 0..4 {
+            manual_tick_component(Duration::from_millis(250), &mut tween, &mut world, entity);
+        }
+        assert_eq!(*values.lock().unwrap(), [0.25, 0.5, 0.75, 0.]);
+
+        // Not invoked once completed, as the target is left untouched
+        manual_tick_component(Duration::from_millis(250), &mut tween, &mut world, entity);
+        assert_eq!(values.lock().unwrap().len(), 4);
+
+        tween.clear_on_update();
+        tween.rewind();
+        manual_tick_component(Duration::from_millis(250), &mut tween, &mut world, entity);
+        assert_eq!(values.lock().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn tween_flip_direction() {
+        let (mut world, entity) = make_test_env();
+        let mut tween = make_test_tween();
+        let translation = |world: &World| world.get::<Transform>(entity).unwrap().translation;
+
+        manual_tick_component(Duration::from_millis(300), &mut tween, &mut world, entity);
+        assert!(translation(&world).abs_diff_eq(Vec3::splat(0.3), 1e-5));
+
+        // Flipping mid-animation continues from the current value
+        tween.flip_direction();
+        assert_eq!(tween.direction(), TweeningDirection::Backward);
+        assert!((tween.progress() - 0.7).abs() < 1e-5);
+        manual_tick_component(Duration::ZERO, &mut tween, &mut world, entity);
+        assert!(translation(&world).abs_diff_eq(Vec3::splat(0.3), 1e-5));
+        manual_tick_component(Duration::from_millis(200), &mut tween, &mut world, entity);
+        assert!(translation(&world).abs_diff_eq(Vec3::splat(0.1), 1e-5));
+        let state = manual_tick_component(Duration::from_secs(1), &mut tween, &mut world, entity);
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(translation(&world), Vec3::ZERO);
+
+        // Flipping a completed tween plays it back from its end
+        tween.flip_direction();
+        assert_eq!(tween.direction(), TweeningDirection::Forward);
+        assert_eq!(tween.progress(), 0.);
+        let state = manual_tick_component(Duration::ZERO, &mut tween, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        assert_eq!(translation(&world), Vec3::ZERO);
+        manual_tick_component(Duration::from_millis(500), &mut tween, &mut world, entity);
+        assert!(translation(&world).abs_diff_eq(Vec3::splat(0.5), 1e-5));
+
+        // The start value of a tween is its end value in the other direction, so
+        // flipping a tween which didn't start completes it
+        let mut tween = make_test_tween().with_direction(TweeningDirection::Backward);
+        tween.flip_direction();
+        assert_eq!(tween.direction(), TweeningDirection::Forward);
+        assert_eq!(tween.state(), TweenState::Completed);
+    }
+
+    #[test]
+    fn tweenable_iteration_elapsed() {
+        let (mut world, entity) = make_test_env();
+        let mut tween = make_test_tween().with_repeat_count(3);
+        assert_eq!(tween.iteration_elapsed(), Duration::ZERO);
+        for (millis, elapsed, iteration_elapsed) in [
+            (1500, 1500, 500),
+            (500, 2000, 0),
+            (250, 2250, 250),
+            (5000, 3000, 1000),
+        ] {
+            let delta = Duration::from_millis(millis);
+            manual_tick_component(delta, &mut tween, &mut world, entity);
+            assert_eq!(tween.elapsed(), Duration::from_millis(elapsed));
+            assert_eq!(
+                tween.iteration_elapsed(),
+                Duration::from_millis(iteration_elapsed)
+            );
+        }
+
+        let mut tween = make_test_tween().with_repeat_count(RepeatCount::Infinite);
+        tween.set_elapsed(Duration::from_millis(10_250));
+        assert_eq!(tween.iteration_elapsed(), Duration::from_millis(250));
+
+        // Without repeat, this is the elapsed time
+        let mut sequence = make_test_tween().then(Delay::new(Duration::from_secs(1)));
+        for millis in [0, 700, 1300, 2000] {
+            sequence.set_elapsed(Duration::from_millis(millis));
+            assert_eq!(sequence.iteration_elapsed(), sequence.elapsed());
+        }
+        assert_eq!(sequence.iteration_elapsed(), Duration::from_secs(2));
+        let mut delay = Delay::<Transform>::new(Duration::from_secs(1));
+        delay.set_elapsed(Duration::from_millis(400));
+        assert_eq!(delay.iteration_elapsed(), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn tween_remaining_repeats() {
+        let (mut world, entity) = make_test_env();
+        let final_cycle = Arc::new(Mutex::new(vec![]));
+        let observed = Arc::clone(&final_cycle);
+        let mut tween = make_test_tween()
+            .with_repeat_count(3)
+            .with_completed_event(1)
+            .with_completed(move |_, tween| {
+                let mut observed = observed.lock().unwrap();
+                observed.push((tween.remaining_repeats(), tween.is_final_cycle()));
+            });
+        let mut reader = world.resource::<Events<TweenCompleted>>().get_reader();
+        let mut remaining_events = |world: &World| {
+            let events = world.resource::<Events<TweenCompleted>>();
+            reader
+                .read(events)
+                .map(|event| event.remaining_repeats)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(tween.repeat_count(), RepeatCount::Finite(3));
+        assert_eq!(tween.remaining_repeats(), Some(3));
+        assert!(!tween.is_final_cycle());
+
+        // A single tick completing two iterations reports the penultimate one
+        manual_tick_component(Duration::from_millis(2500), &mut tween, &mut world, entity);
+        assert_eq!(tween.remaining_repeats(), Some(1));
+        assert!(tween.is_final_cycle());
+        assert_eq!(remaining_events(&world), [Some(1)]);
+        assert_eq!(*final_cycle.lock().unwrap(), [(Some(1), true)]);
+
+        manual_tick_component(Duration::from_secs(5), &mut tween, &mut world, entity);
+        assert_eq!(tween.remaining_repeats(), Some(0));
+        assert!(!tween.is_final_cycle());
+        assert_eq!(remaining_events(&world), [Some(0)]);
+        assert_eq!(
+            *final_cycle.lock().unwrap(),
+            [(Some(1), true), (Some(0), false)]
+        );
+
+        // A partial last iteration counts as a full one
+        let mut tween = make_test_tween().with_repeat_count(Duration::from_millis(2500));
+        assert_eq!(tween.remaining_repeats(), Some(3));
+        tween.set_elapsed(Duration::from_millis(2200));
+        assert!(tween.is_final_cycle());
+        tween.set_elapsed(Duration::from_millis(2500));
+        assert_eq!(tween.remaining_repeats(), Some(0));
+
+        let tween = make_test_tween().with_repeat_count(RepeatCount::Infinite);
+        assert_eq!(tween.remaining_repeats(), None);
+        assert!(!tween.is_final_cycle());
+    }
+
+    #[test]
+    fn tween_finite_repeat_is_looping() {
+        let (mut world, entity) = make_test_env();
+        let completed = Arc::new(Mutex::new(0));
+        let tween_completed = Arc::clone(&completed);
+        let mut tween = make_test_tween()
+            .with_repeat_count(3)
+            .with_completed_event(7)
+            .with_completed(move |_, _| *tween_completed.lock().unwrap() += 1);
+        let mut reader = world.resource::<Events<TweenCompleted>>().get_reader();
+        let mut new_events = |world: &World| {
+            let events = world.resource::<Events<TweenCompleted>>();
+            reader.read(events).count()
+        };
+        assert!(tween.is_looping());
+
+        // Each completed iteration raises an event and invokes the callback
+        for (millis, looping) in [(500, true), (1000, true), (1000, false)] {
+            let delta = Duration::from_millis(millis);
+            let state = manual_tick_component(delta, &mut tween, &mut world, entity);
+            assert_eq!(state, TweenState::Active);
+            assert_eq!(tween.is_looping(), looping);
+        }
+        assert_eq!(new_events(&world), 2);
+        let state = manual_tick_component(Duration::from_secs(5), &mut tween, &mut world, entity);
+        assert_eq!(state, TweenState::Completed);
+        assert!(!tween.is_looping());
+        assert_eq!(tween.times_completed(), 3);
+        assert_eq!(new_events(&world), 1);
+        assert_eq!(*completed.lock().unwrap(), 3);
+
+        // Completed, the tween holds its end value
+        let state = manual_tick_component(Duration::from_secs(1), &mut tween, &mut world, entity);
+        assert_eq!(state, TweenState::Completed);
+        let transform = world.get::<Transform>(entity).unwrap();
+        assert_eq!(transform.translation, Vec3::ONE);
+        assert_eq!(new_events(&world), 0);
+        assert_eq!(*completed.lock().unwrap(), 3);
+
+        // Rewinding restores all the repeats
+        tween.rewind();
+        assert!(tween.is_looping());
+        assert_eq!(tween.times_completed(), 0);
+
+        assert!(!make_test_tween().is_looping());
+        assert!(make_test_tween()
+            .with_repeat_count(RepeatCount::Infinite)
+            .is_looping());
+        assert!(make_test_tween()
+            .with_repeat_count(Duration::from_millis(1500))
+            .is_looping());
+    }
+
+    #[test]
+    fn tweenable_state_without_ticking() {
+        /// Check the state of a tweenable, and that querying it doesn't modify
+        /// the tweenable nor its target.
+        fn check(
+            tweenable: &dyn Tweenable<Transform>,
+            world: &World,
+            entity: Entity,
+            expected: TweenState,
+        ) {
+            let elapsed = tweenable.elapsed();
+            let transform = *world.get::<Transform>(entity).unwrap();
+            assert_eq!(tweenable.state(), expected);
+            assert_eq!(tweenable.elapsed(), elapsed);
+            assert_eq!(*world.get::<Transform>(entity).unwrap(), transform);
+        }
+
+        let tween = make_test_tween().with_repeat_count(2);
+        let sequence = Sequence::new([make_test_tween(), make_test_tween()]);
+        let tracks = Tracks::new([make_test_tween(), make_test_tween().with_repeat_count(2)]);
+        let delay = Delay::<Transform>::new(Duration::from_secs(2));
+        let tweenables: [BoxedTweenable<Transform>; 4] = [
+            Box::new(tween),
+            Box::new(sequence),
+            Box::new(tracks),
+            Box::new(delay),
+        ];
+        for mut tweenable in tweenables {
+            // Before, during, and after playback, with the 2 seconds total
+            // duration of each tweenable
+            let (mut world, entity) = make_test_env();
+            check(&*tweenable, &world, entity, TweenState::Active);
+            for millis in [0, 500, 1000, 499] {
+                let delta = Duration::from_millis(millis);
+                manual_tick_component(delta, &mut *tweenable, &mut world, entity);
+                check(&*tweenable, &world, entity, TweenState::Active);
+            }
+            let state = manual_tick_component(
+                Duration::from_millis(1),
+                &mut *tweenable,
+                &mut world,
+                entity,
+            );
+            assert_eq!(state, TweenState::Completed);
+            check(&*tweenable, &world, entity, TweenState::Completed);
+
+            // Seeking back and rewinding makes it active again
+            tweenable.set_elapsed(Duration::from_millis(1500));
+            check(&*tweenable, &world, entity, TweenState::Active);
+            tweenable.set_elapsed(Duration::from_secs(2));
+            check(&*tweenable, &world, entity, TweenState::Completed);
+            tweenable.rewind();
+            check(&*tweenable, &world, entity, TweenState::Active);
+        }
+
+        // Looping forever, it never completes
+        let (mut world, entity) = make_test_env();
+        let mut tween = make_test_tween().with_repeat_count(RepeatCount::Infinite);
+        manual_tick_component(Duration::from_secs(10), &mut tween, &mut world, entity);
+        check(&tween, &world, entity, TweenState::Active);
+
+        // Cancelled through its token, it's completed once the cancellation
+        // is observed on its next tick
+        let token = CancelToken::default();
+        let mut tween = make_test_tween().with_cancel_token(token.clone());
+        manual_tick_component(Duration::from_millis(300), &mut tween, &mut world, entity);
+        token.cancel();
+        check(&tween, &world, entity, TweenState::Active);
+        manual_tick_component(Duration::ZERO, &mut tween, &mut world, entity);
+        check(&tween, &world, entity, TweenState::Completed);
+
+        // The animator reports the state of its tweenable
+        let mut animator = Animator::new(make_test_tween());
+        assert_eq!(animator.tween_state(), TweenState::Active);
+        animator.tweenable_mut().set_elapsed(Duration::from_secs(1));
+        assert_eq!(animator.tween_state(), TweenState::Completed);
+    }
+
+    #[test]
+    fn tween_auto_rewind_on_completion() {
+        let seen = Arc::new(Mutex::new(vec![]));
+        let seen_cb = Arc::clone(&seen);
+        let mut tween = make_test_tween()
+            .with_repeat_count(2)
+            .with_repeat_strategy(RepeatStrategy::MirroredRepeat)
+            .with_completed_event(3)
+            .with_completed(move |_, tween| {
+                seen_cb
+                    .lock()
+                    .unwrap()
+                    .push((tween.times_completed(), tween.progress()));
+            })
+            .with_auto_rewind_on_completion(true);
+        assert!(tween.auto_rewind_on_completion());
+        let (mut world, entity) = make_test_env();
+        let mut tick = |tween: &mut Tween<Transform>, millis| {
+            let state =
+                manual_tick_component(Duration::from_millis(millis), tween, &mut world, entity);
+            let events = world
+                .resource_mut::<Events<TweenCompleted>>()
+                .drain()
+                .count();
+            let translation = world.get::<Transform>(entity).unwrap().translation;
+            (state, events, translation)
+        };
+
+        // Completing a loop doesn't rewind
+        let (state, events, _) = tick(&mut tween, 1200);
+        assert_eq!((state, events), (TweenState::Active, 1));
+        assert_eq!(tween.direction(), TweeningDirection::Backward);
+
+        // The completion is reported and the end value applied, then the
+        // tween is rewound, including its direction and completion count
+        let (state, events, translation) = tick(&mut tween, 1000);
+        assert_eq!((state, events), (TweenState::Completed, 1));
+        assert_eq!(translation, Vec3::ZERO);
+        assert_eq!(*seen.lock().unwrap(), [(1, 0.2), (2, 1.)]);
+        assert_eq!(tween.elapsed(), Duration::ZERO);
+        assert_eq!(tween.times_completed(), 0);
+        assert_eq!(tween.direction(), TweeningDirection::Forward);
+
+        // And it plays again from the start when ticked again
+        let (state, events, translation) = tick(&mut tween, 500);
+        assert_eq!((state, events), (TweenState::Active, 0));
+        assert!(translation.abs_diff_eq(Vec3::splat(0.5), 1e-5));
+
+        // A tween repeating forever never completes, so is never rewound
+        let mut tween = make_test_tween()
+            .with_repeat_count(RepeatCount::Infinite)
+            .with_auto_rewind_on_completion(true);
+        let (state, events, translation) = tick(&mut tween, 2500);
+        assert_eq!((state, events), (TweenState::Active, 0));
+        assert!(translation.abs_diff_eq(Vec3::splat(0.5), 1e-5));
+        assert_eq!(tween.times_completed(), 2);
+
+        // Disabled by default
+        let mut tween = make_test_tween();
+        assert!(!tween.auto_rewind_on_completion());
+        let (state, _, _) = tick(&mut tween, 2000);
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(tween.progress(), 1.);
+    }
+
+    #[test]
+    fn seq_completed_behavior() {
+        let make_seq = || {
+            let move_x = Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::X,
+                },
+            );
+            let flash = Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformScaleLens {
+                    start: Vec3::ONE,
+                    end: Vec3::splat(2.),
+                },
+            );
+            (move_x, flash)
+        };
+        let (mut world, entity) = make_test_env();
+        let tick = |seq: &mut Sequence<Transform>, world: &mut World, millis| {
+            let state = manual_tick_component(Duration::from_millis(millis), seq, world, entity);
+            (state, *world.get::<Transform>(entity).unwrap())
+        };
+
+        // Per child: only the flash snaps back, on the tick it completes
+        let (move_x, flash) = make_seq();
+        let mut seq = move_x.then(
+            Tween::builder()
+                .with_duration(flash.duration())
+                .with_lens(TransformScaleLens {
+                    start: Vec3::ONE,
+                    end: Vec3::splat(2.),
+                })
+                .with_completed_behavior(CompletedBehavior::SnapToStart)
+                .build()
+                .unwrap(),
+        );
+        let (state, transform) = tick(&mut seq, &mut world, 1500);
+        assert_eq!(state, TweenState::Active);
+        assert_eq!(transform.translation, Vec3::X);
+        assert!(transform.scale.abs_diff_eq(Vec3::splat(1.5), 1e-5));
+        let (state, transform) = tick(&mut seq, &mut world, 500);
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(transform.translation, Vec3::X);
+        assert_eq!(transform.scale, Vec3::ONE);
+
+        // At the sequence level: all children snap back, once
+        world.entity_mut(entity).insert(Transform::default());
+        let (move_x, flash) = make_seq();
+        let mut seq = move_x
+            .then(flash)
+            .with_completed_behavior(CompletedBehavior::SnapToStart);
+        assert_eq!(seq.completed_behavior(), CompletedBehavior::SnapToStart);
+        let (state, transform) = tick(&mut seq, &mut world, 1999);
+        assert_eq!(state, TweenState::Active);
+        assert_eq!(transform.translation, Vec3::X);
+        let (state, transform) = tick(&mut seq, &mut world, 1);
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(transform, Transform::default());
+        world
+            .entity_mut(entity)
+            .insert(Transform::from_xyz(5., 0., 0.));
+        let (state, transform) = tick(&mut seq, &mut world, 100);
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(transform.translation, Vec3::new(5., 0., 0.));
+
+        // Seeking to the end applies the end value, then snaps back
+        seq.set_progress(1.);
+        let (state, transform) = tick(&mut seq, &mut world, 0);
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(transform, Transform::default());
+    }
+
+    /// A tween with a minimum step only applies its lens once enough time
+    /// accumulated, but always on the first and last ticks, and after a seek.
+    #[test]
+    fn tween_min_step() {
+        let mut tween = make_test_tween().with_min_step(Duration::from_millis(250));
+        assert_eq!(tween.min_step(), Duration::from_millis(250));
+        let (mut world, entity) = make_test_env();
+
+        let mut values = vec![];
+        let mut changed = vec![];
+        let mut states = vec![];
+        for i in 1..=10 {
+            states.push(manual_tick_component(
+                Duration::from_millis(100),
+                &mut tween,
+                &mut world,
+                entity,
+            ));
+            // Progress is unaffected by the step
+            assert_approx_eq!(tween.progress(), i as f32 / 10.);
+            let transform = world.entity(entity).get_ref::<Transform>().unwrap();
+            values.push(transform.translation.x);
+            changed.push(transform.is_changed());
+            world.clear_trackers();
+        }
+
+        let expected = [0.1, 0.1, 0.1, 0.4, 0.4, 0.4, 0.7, 0.7, 0.7];
+        for (actual, expected) in values.iter().zip(expected) {
+            assert_approx_eq!(*actual, expected);
+        }
+        // Exact end value on completion
+        assert_eq!(values[9], 1.);
+        assert_eq!(
+            changed,
+            [true, false, false, true, false, false, true, false, false, true]
+        );
+        assert_eq!(states[8], TweenState::Active);
+        assert_eq!(states[9], TweenState::Completed);
 
-                    // Propagate events
-                    {
-                        let mut events = world.resource_mut::<Events<TweenCompleted>>();
-                        events.update();
-                    }
+        // Seeking applies on the next tick, then accumulates again
+        tween.set_elapsed(Duration::from_millis(500));
+        manual_tick_component(Duration::ZERO, &mut tween, &mut world, entity);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert_approx_eq!(transform.translation.x, 0.5);
+        manual_tick_component(Duration::from_millis(100), &mut tween, &mut world, entity);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert_approx_eq!(transform.translation.x, 0.5);
 
-                    // Check actual values
-                    assert_eq!(tween.direction(), direction);
-                    assert_eq!(actual_state, expected_state);
-                    assert_approx_eq!(tween.progress(), progress);
-                    assert_eq!(tween.times_completed(), times_completed);
-                    let transform = world.entity(entity).get::<Transform>().unwrap();
-                    assert!(transform
-                        .translation
-                        .abs_diff_eq(expected_translation, 1e-5));
-                    assert!(transform.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
-                    let cb_mon = callback_monitor.lock().unwrap();
-                    assert_eq!(cb_mon.invoke_count, times_completed as u64);
-                    assert_eq!(cb_mon.last_reported_count, times_completed);
-                    {
-                        let mut event_reader = event_reader_system_state.get_mut(&mut world);
-                        let event = event_reader.read().next();
-                        if just_completed {
-                            assert!(event.is_some());
-                            if let Some(event) = event {
-                                assert_eq!(event.entity, entity);
-                                assert_eq!(event.user_data, USER_DATA);
-                            }
-                        } else {
-                            assert!(event.is_none());
-                        }
-                    }
-                }
+        // Rewinding applies the start value on the next tick
+        tween.rewind();
+        manual_tick_component(Duration::ZERO, &mut tween, &mut world, entity);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert_eq!(transform.translation.x, 0.);
+    }
 
-                // Rewind
-                tween.rewind();
-                assert_eq!(tween.direction(), *tweening_direction); // does not change
-                assert_approx_eq!(tween.progress(), 0.);
-                assert_eq!(tween.times_completed(), 0);
+    /// A slice across the boundary of two children of a sequence plays the
+    /// end of the first one and the start of the second one.
+    #[test]
+    fn slice_seq() {
+        let seq = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::X,
+            },
+        )
+        .with_completed_event(1)
+        .then(
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformScaleLens {
+                    start: Vec3::ONE,
+                    end: Vec3::splat(3.),
+                },
+            )
+            .with_completed_event(2),
+        );
+        let mut slice = Slice::new(seq, 0.25, 0.75).with_completed_event(3);
+        assert_eq!(slice.duration(), Duration::from_secs(1));
+        assert_eq!(
+            slice.total_duration(),
+            TotalDuration::Finite(Duration::from_secs(1))
+        );
 
-                // Dummy tick to update target
-                let actual_state =
-                    manual_tick_component(Duration::ZERO, &mut tween, &mut world, entity);
-                assert_eq!(actual_state, TweenState::Active);
-                let expected_translation = if tweening_direction.is_backward() {
-                    Vec3::ONE
-                } else {
-                    Vec3::ZERO
-                };
-                let transform = world.entity(entity).get::<Transform>().unwrap();
-                assert!(transform
-                    .translation
-                    .abs_diff_eq(expected_translation, 1e-5));
-                assert!(transform.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
+        let (mut world, entity) = make_test_env();
+        let transform = |world: &World| *world.get::<Transform>(entity).unwrap();
 
-                // Clear callback
-                tween.clear_completed();
-                assert!(tween.on_completed.is_none());
+        // Start of the slice
+        let state = manual_tick_component(Duration::ZERO, &mut slice, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        assert_approx_eq!(transform(&world).translation.x, 0.5);
+        assert_eq!(transform(&world).scale, Vec3::ONE);
 
-                // Clear event sending
-                tween.clear_completed_event();
-                assert!(tween.event_data.is_none());
-            }
-        }
+        // Across the boundary, the end state of the first child is applied
+        let state =
+            manual_tick_component(Duration::from_millis(700), &mut slice, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        assert_eq!(transform(&world).translation.x, 1.);
+        assert_approx_eq!(transform(&world).scale.x, 1.4);
+        assert_approx_eq!(slice.progress(), 0.7);
+
+        // End of the slice, raising only the event of the slice
+        let state =
+            manual_tick_component(Duration::from_millis(500), &mut slice, &mut world, entity);
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(transform(&world).scale.x, 2.);
+        assert_eq!(slice.elapsed(), Duration::from_secs(1));
+        assert_eq!(slice.times_completed(), 1);
+        let events = world.resource::<Events<TweenCompleted>>();
+        let user_data: Vec<_> = events
+            .get_reader()
+            .read(events)
+            .map(|ev| ev.user_data)
+            .collect();
+        assert_eq!(user_data, vec![3]);
+
+        // Scrub back within the slice
+        slice.set_elapsed(Duration::from_millis(200));
+        let state = manual_tick_component(Duration::ZERO, &mut slice, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        assert_approx_eq!(transform(&world).translation.x, 0.7);
+        let mut sampled = Transform::default();
+        assert!(slice.sample(Duration::from_secs(1), &mut sampled));
+        assert_eq!(sampled.scale.x, 2.);
+
+        // Rewinding replays the slice from its start
+        slice.rewind();
+        manual_tick_component(Duration::ZERO, &mut slice, &mut world, entity);
+        assert_approx_eq!(transform(&world).translation.x, 0.5);
     }
 
     #[test]
-    fn tween_dir() {
-        let mut tween = make_test_tween();
+    #[should_panic]
+    fn slice_empty_panics() {
+        let _ = Slice::new(make_test_tween(), 0.5, 0.5);
+    }
 
-        // Default
-        assert_eq!(tween.direction(), TweeningDirection::Forward);
-        assert_approx_eq!(tween.progress(), 0.0);
+    /// Test ticking a sequence of tweens.
+    #[test]
+    fn seq_tick() {
+        let tween1 = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+        let tween2 = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformRotationLens {
+                start: Quat::IDENTITY,
+                end: Quat::from_rotation_x(90_f32.to_radians()),
+            },
+        );
+        let mut seq = tween1.then(tween2);
 
-        // no-op
-        tween.set_direction(TweeningDirection::Forward);
-        assert_eq!(tween.direction(), TweeningDirection::Forward);
-        assert_approx_eq!(tween.progress(), 0.0);
+        let (mut world, entity) = make_test_env();
 
-        // Backward
-        tween.set_direction(TweeningDirection::Backward);
-        assert_eq!(tween.direction(), TweeningDirection::Backward);
-        // progress is independent of direction
-        assert_approx_eq!(tween.progress(), 0.0);
+        for i in 1..=16 {
+            let state =
+                manual_tick_component(Duration::from_millis(200), &mut seq, &mut world, entity);
+            let transform = world.entity(entity).get::<Transform>().unwrap();
+            if i < 5 {
+                assert_eq!(state, TweenState::Active);
+                let r = i as f32 * 0.2;
+                assert_eq!(*transform, Transform::from_translation(Vec3::splat(r)));
+            } else if i < 10 {
+                assert_eq!(state, TweenState::Active);
+                let alpha_deg = (18 * (i - 5)) as f32;
+                assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+                assert!(transform
+                    .rotation
+                    .abs_diff_eq(Quat::from_rotation_x(alpha_deg.to_radians()), 1e-5));
+            } else {
+                assert_eq!(state, TweenState::Completed);
+                assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
+                assert!(transform
+                    .rotation
+                    .abs_diff_eq(Quat::from_rotation_x(90_f32.to_radians()), 1e-5));
+            }
+        }
+    }
 
-        // Progress-invariant
-        tween.set_direction(TweeningDirection::Forward);
-        tween.set_progress(0.3);
-        assert_approx_eq!(tween.progress(), 0.3);
-        tween.set_direction(TweeningDirection::Backward);
-        // progress is independent of direction
-        assert_approx_eq!(tween.progress(), 0.3);
+    /// The completion callback with target sees the final value of the target,
+    /// including when a sequence catches up over several children in one tick.
+    #[test]
+    fn tween_completed_with_target() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let (mut world, entity) = make_test_env();
+
+        let seen_clone = Arc::clone(&seen);
+        let mut tween = Tween::new(
+            EaseFunction::QuadraticInOut,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::new(3., 2., 1.),
+            },
+        )
+        .with_completed_with_target(
+            move |completed_entity, _tween, transform: &Transform| {
+                assert_eq!(completed_entity, entity);
+                seen_clone.lock().unwrap().push(transform.translation);
+            },
+        );
+        manual_tick_component(Duration::from_millis(600), &mut tween, &mut world, entity);
+        assert!(seen.lock().unwrap().is_empty());
+        manual_tick_component(Duration::from_millis(600), &mut tween, &mut world, entity);
+        assert_eq!(*seen.lock().unwrap(), vec![Vec3::new(3., 2., 1.)]);
+
+        // Catch-up over a whole sequence
+        seen.lock().unwrap().clear();
+        let seen_clone = Arc::clone(&seen);
+        let last = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ONE,
+                end: Vec3::new(5., 1., 1.),
+            },
+        )
+        .with_completed_with_target(move |_entity, _tween, transform: &Transform| {
+            seen_clone.lock().unwrap().push(transform.translation);
+        });
+        let mut seq = make_test_tween().then(last);
+        let state = manual_tick_component(Duration::from_secs(5), &mut seq, &mut world, entity);
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(*seen.lock().unwrap(), vec![Vec3::new(5., 1., 1.)]);
+        let transform = world.get::<Transform>(entity).unwrap();
+        assert_eq!(transform.translation, Vec3::new(5., 1., 1.));
+
+        // Cleared along with the other callback
+        let mut tween = make_test_tween().with_completed_with_target(
+            |_entity, _tween, _transform: &Transform| {
+                panic!("cleared callback invoked");
+            },
+        );
+        tween.clear_completed();
+        manual_tick_component(Duration::from_secs(2), &mut tween, &mut world, entity);
+    }
+
+    /// Test crossing tween boundaries in one tick.
+    #[test]
+    fn seq_tick_boundaries() {
+        let mut seq = Sequence::new((0..3).map(|i| {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::splat(i as f32),
+                    end: Vec3::splat((i + 1) as f32),
+                },
+            )
+            .with_repeat_count(RepeatCount::Finite(1))
+        }));
 
         let (mut world, entity) = make_test_env();
 
-        // Progress always increases alongside the current direction
-        tween.set_direction(TweeningDirection::Backward);
-        assert_approx_eq!(tween.progress(), 0.3);
-        manual_tick_component(Duration::from_millis(100), &mut tween, &mut world, entity);
-        assert_approx_eq!(tween.progress(), 0.4);
+        // Tick halfway through the first tween, then in one tick:
+        // - Finish the first tween
+        // - Start and finish the second tween
+        // - Start the third tween
+        for delta_ms in [500, 2000] {
+            manual_tick_component(
+                Duration::from_millis(delta_ms),
+                &mut seq,
+                &mut world,
+                entity,
+            );
+        }
+        assert_eq!(seq.index(), 2);
         let transform = world.entity(entity).get::<Transform>().unwrap();
-        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.6), 1e-5));
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(2.5), 1e-5));
     }
 
+    /// Test a child completing exactly on a tick boundary, with zero leftover
+    /// delta; the next child must apply its start value on the same tick.
     #[test]
-    fn tween_elapsed() {
-        let mut tween = make_test_tween();
+    fn seq_tick_exact_boundary() {
+        let make_tween = |start: f32| {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::splat(start),
+                    end: Vec3::splat(start + 1.),
+                },
+            )
+        };
+        let mut seq = Delay::new(Duration::from_secs(1))
+            .then(make_tween(-10.))
+            .then(make_tween(10.));
 
-        let duration = tween.duration();
-        let elapsed = tween.elapsed();
+        let (mut world, entity) = make_test_env();
 
-        assert_eq!(elapsed, Duration::ZERO);
-        assert_eq!(duration, Duration::from_secs(1));
+        // Delay ends exactly on the boundary frame
+        for delta_ms in [500, 500] {
+            let state = manual_tick_component(
+                Duration::from_millis(delta_ms),
+                &mut seq,
+                &mut world,
+                entity,
+            );
+            assert_eq!(state, TweenState::Active);
+        }
+        assert_eq!(seq.index(), 1);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert_eq!(transform.translation, Vec3::splat(-10.));
 
-        for ms in [0, 1, 500, 100, 300, 999, 847, 1000, 900] {
-            let elapsed = Duration::from_millis(ms);
-            tween.set_elapsed(elapsed);
-            assert_eq!(tween.elapsed(), elapsed);
+        // First tween ends exactly on the boundary frame
+        let state = manual_tick_component(Duration::from_secs(1), &mut seq, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        assert_eq!(seq.index(), 2);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert_eq!(transform.translation, Vec3::splat(10.));
+    }
 
-            let progress = (elapsed.as_secs_f64() / duration.as_secs_f64()) as f32;
-            assert_approx_eq!(tween.progress(), progress);
+    #[test]
+    fn wait_for() {
+        let (mut wait, handle) = WaitFor::<Transform>::new();
+        assert_eq!(wait.duration(), Duration::ZERO);
+        assert_eq!(wait.timeout(), None);
 
-            let times_completed = u32::from(ms == 1000);
-            assert_eq!(tween.times_completed(), times_completed);
+        let (mut world, entity) = make_test_env();
+        for _ in 0..10 {
+            let state =
+                manual_tick_component(Duration::from_secs(3600), &mut wait, &mut world, entity);
+            assert_eq!(state, TweenState::Active);
+            assert_eq!(wait.elapsed(), Duration::ZERO);
+            assert_eq!(wait.progress(), 0.);
+            assert_eq!(wait.times_completed(), 0);
         }
+
+        handle.release();
+        let state = manual_tick_component(Duration::ZERO, &mut wait, &mut world, entity);
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(wait.progress(), 1.);
+        assert_eq!(wait.times_completed(), 1);
+
+        // Rewinding re-arms the wait
+        wait.rewind();
+        assert!(!handle.is_released());
+        let state = manual_tick_component(Duration::from_secs(1), &mut wait, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        assert_eq!(wait.times_completed(), 0);
+
+        // Timeout
+        let (wait, _handle) = WaitFor::<Transform>::new();
+        let mut wait = wait.with_timeout(Duration::from_secs(2));
+        assert_eq!(wait.duration(), Duration::from_secs(2));
+        let state = manual_tick_component(Duration::from_secs(1), &mut wait, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        assert_approx_eq!(wait.progress(), 0.5);
+        let state = manual_tick_component(Duration::from_secs(3), &mut wait, &mut world, entity);
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(wait.elapsed(), Duration::from_secs(2));
+        assert_eq!(wait.progress(), 1.);
     }
 
-    /// Test ticking a sequence of tweens.
     #[test]
-    fn seq_tick() {
-        let tween1 = Tween::new(
-            EaseMethod::Linear,
-            Duration::from_secs(1),
-            TransformPositionLens {
-                start: Vec3::ZERO,
-                end: Vec3::ONE,
-            },
-        );
-        let tween2 = Tween::new(
-            EaseMethod::Linear,
-            Duration::from_secs(1),
-            TransformRotationLens {
-                start: Quat::IDENTITY,
-                end: Quat::from_rotation_x(90_f32.to_radians()),
-            },
-        );
-        let mut seq = tween1.then(tween2);
+    fn seq_wait_for() {
+        let make_tween = |start: f32| {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::splat(start),
+                    end: Vec3::splat(start + 1.),
+                },
+            )
+        };
+        let (wait, handle) = WaitFor::new();
+        let mut seq = make_tween(0.).then(wait).then(make_tween(10.));
+        assert_eq!(seq.duration(), Duration::from_secs(2));
 
         let (mut world, entity) = make_test_env();
 
-        for i in 1..=16 {
+        // The sequence is held in place while waiting, whatever the time spent
+        let state =
+            manual_tick_component(Duration::from_millis(1500), &mut seq, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        assert_eq!(seq.index(), 1);
+        for _ in 0..1000 {
             let state =
-                manual_tick_component(Duration::from_millis(200), &mut seq, &mut world, entity);
-            let transform = world.entity(entity).get::<Transform>().unwrap();
-            if i < 5 {
-                assert_eq!(state, TweenState::Active);
-                let r = i as f32 * 0.2;
-                assert_eq!(*transform, Transform::from_translation(Vec3::splat(r)));
-            } else if i < 10 {
-                assert_eq!(state, TweenState::Active);
-                let alpha_deg = (18 * (i - 5)) as f32;
-                assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
-                assert!(transform
-                    .rotation
-                    .abs_diff_eq(Quat::from_rotation_x(alpha_deg.to_radians()), 1e-5));
-            } else {
-                assert_eq!(state, TweenState::Completed);
-                assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5));
-                assert!(transform
-                    .rotation
-                    .abs_diff_eq(Quat::from_rotation_x(90_f32.to_radians()), 1e-5));
-            }
+                manual_tick_component(Duration::from_secs(3600), &mut seq, &mut world, entity);
+            assert_eq!(state, TweenState::Active);
+            assert_eq!(seq.index(), 1);
+            assert_eq!(seq.elapsed(), Duration::from_secs(1));
+            assert_approx_eq!(seq.progress(), 0.5);
         }
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert_eq!(transform.translation, Vec3::ONE);
+
+        // Once released, the sequence continues with the next child
+        handle.release();
+        let state = manual_tick_component(Duration::from_millis(250), &mut seq, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        assert_eq!(seq.index(), 2);
+        assert_eq!(seq.elapsed(), Duration::from_millis(1250));
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(10.25), 1e-5));
+
+        let state = manual_tick_component(Duration::from_secs(1), &mut seq, &mut world, entity);
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(seq.elapsed(), Duration::from_secs(2));
+
+        // Rewinding the sequence re-arms the wait
+        seq.rewind();
+        let state = manual_tick_component(Duration::from_secs(2), &mut seq, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        assert_eq!(seq.index(), 1);
     }
 
-    /// Test crossing tween boundaries in one tick.
     #[test]
-    fn seq_tick_boundaries() {
-        let mut seq = Sequence::new((0..3).map(|i| {
+    fn seq_wait_for_seek() {
+        let make_tween = |start: f32| {
             Tween::new(
                 EaseMethod::Linear,
                 Duration::from_secs(1),
                 TransformPositionLens {
-                    start: Vec3::splat(i as f32),
-                    end: Vec3::splat((i + 1) as f32),
+                    start: Vec3::splat(start),
+                    end: Vec3::splat(start + 1.),
                 },
             )
-            .with_repeat_count(RepeatCount::Finite(1))
-        }));
+        };
+        let (mut world, entity) = make_test_env();
+        let x = |world: &World| world.get::<Transform>(entity).unwrap().translation.x;
+
+        // Seeking an unreleased wait doesn't complete it, unless it reaches
+        // the timeout
+        let (mut wait, _handle) = WaitFor::<Transform>::new();
+        wait.set_elapsed(Duration::ZERO);
+        assert_eq!(wait.state(), TweenState::Active);
+        wait.set_progress(1.);
+        assert_eq!(wait.state(), TweenState::Active);
+        let (wait, _handle) = WaitFor::<Transform>::new();
+        let mut wait = wait.with_timeout(Duration::from_secs(1));
+        wait.set_elapsed(Duration::from_secs(1));
+        assert_eq!(wait.state(), TweenState::Completed);
+        wait.set_elapsed(Duration::ZERO);
+        assert_eq!(wait.state(), TweenState::Active);
+
+        // Seeking past an unreleased wait, or exactly to the start of the
+        // next child, stops at the wait
+        let (wait, handle) = WaitFor::new();
+        let mut seq = make_tween(0.).then(wait).then(make_tween(10.));
+        for millis in [1000, 1500, 2000] {
+            seq.set_elapsed(Duration::from_millis(millis));
+            assert_eq!(seq.index(), 1);
+            assert_eq!(seq.elapsed(), Duration::from_secs(1));
+            let state = manual_tick_component(Duration::ZERO, &mut seq, &mut world, entity);
+            assert_eq!(state, TweenState::Active);
+            assert_eq!(x(&world), 1.);
+        }
+
+        // Once released, seeking goes through the wait
+        handle.release();
+        seq.set_elapsed(Duration::from_millis(1500));
+        assert_eq!(seq.index(), 2);
+        let state = manual_tick_component(Duration::ZERO, &mut seq, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        assert!((x(&world) - 10.5).abs() < 1e-5);
+
+        // Seeking back before the wait re-arms it, so playing waits again
+        seq.set_elapsed(Duration::from_millis(500));
+        assert_eq!(seq.index(), 0);
+        assert!(!handle.is_released());
+        let state = manual_tick_component(Duration::from_secs(2), &mut seq, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        assert_eq!(seq.index(), 1);
+        assert_eq!(x(&world), 1.);
+    }
+
+    /// Test completing a repeating child, whose elapsed time exceeds the
+    /// duration of a single iteration.
+    #[test]
+    fn seq_tick_repeating_child() {
+        let mut seq = make_test_tween()
+            .with_repeat_count(RepeatCount::Finite(2))
+            .then(Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::splat(10.),
+                    end: Vec3::splat(11.),
+                },
+            ));
 
         let (mut world, entity) = make_test_env();
 
-        // Tick halfway through the first tween, then in one tick:
-        // - Finish the first tween
-        // - Start and finish the second tween
-        // - Start the third tween
-        for delta_ms in [500, 2000] {
-            manual_tick_component(
+        for delta_ms in [1500, 1000] {
+            let state = manual_tick_component(
                 Duration::from_millis(delta_ms),
                 &mut seq,
                 &mut world,
                 entity,
             );
+            assert_eq!(state, TweenState::Active);
         }
-        assert_eq!(seq.index(), 2);
+        assert_eq!(seq.index(), 1);
         let transform = world.entity(entity).get::<Transform>().unwrap();
-        assert!(transform.translation.abs_diff_eq(Vec3::splat(2.5), 1e-5));
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(10.5), 1e-5));
+    }
+
+    /// Interleave ticking, seeking, and rewinding a sequence, and check
+    /// times_completed() against the expected number of full passes.
+    #[test]
+    fn seq_times_completed() {
+        let mut seq = make_test_tween().then(make_test_tween());
+        let (mut world, entity) = make_test_env();
+
+        enum Op {
+            Tick(u64),
+            Progress(f32),
+            Rewind,
+        }
+
+        // Operation, then expected (elapsed ms, times_completed, state)
+        let steps = [
+            (Op::Tick(500), 500, 0, TweenState::Active),
+            (Op::Tick(1600), 2000, 1, TweenState::Completed),
+            (Op::Tick(1000), 2000, 1, TweenState::Completed),
+            (Op::Progress(0.75), 1500, 0, TweenState::Active),
+            (Op::Tick(100), 1600, 0, TweenState::Active),
+            (Op::Progress(1.), 2000, 1, TweenState::Completed),
+            (Op::Progress(2.5), 2000, 1, TweenState::Completed),
+            (Op::Rewind, 0, 0, TweenState::Active),
+            (Op::Tick(2000), 2000, 1, TweenState::Completed),
+            (Op::Progress(0.), 0, 0, TweenState::Active),
+            (Op::Tick(1999), 1999, 0, TweenState::Active),
+            (Op::Tick(1), 2000, 1, TweenState::Completed),
+        ];
+        for (index, (op, elapsed_ms, times_completed, state)) in steps.into_iter().enumerate() {
+            let actual_state = match op {
+                Op::Tick(ms) => {
+                    manual_tick_component(Duration::from_millis(ms), &mut seq, &mut world, entity)
+                }
+                Op::Progress(progress) => {
+                    seq.set_progress(progress);
+                    manual_tick_component(Duration::ZERO, &mut seq, &mut world, entity)
+                }
+                Op::Rewind => {
+                    seq.rewind();
+                    manual_tick_component(Duration::ZERO, &mut seq, &mut world, entity)
+                }
+            };
+            assert_eq!(
+                seq.elapsed(),
+                Duration::from_millis(elapsed_ms),
+                "step #{index}"
+            );
+            assert_eq!(seq.times_completed(), times_completed, "step #{index}");
+            assert_eq!(actual_state, state, "step #{index}");
+        }
+    }
+
+    /// Seeking tracks past their end counts a single pass.
+    #[test]
+    fn tracks_times_completed() {
+        let mut tracks = Tracks::new([make_test_tween(), make_test_tween()]);
+        tracks.set_progress(2.5);
+        assert_eq!(tracks.elapsed(), Duration::from_secs(1));
+        assert_eq!(tracks.times_completed(), 1);
+        tracks.set_progress(0.5);
+        assert_eq!(tracks.times_completed(), 0);
+        tracks.rewind();
+        assert_eq!(tracks.elapsed(), Duration::ZERO);
+    }
+
+    /// Sequence::locate() and Sequence::global_progress()
+    #[test]
+    fn seq_locate() {
+        let make_zero_tween = || {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::ZERO,
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+            )
+        };
+        let seq: Sequence<Transform> = Delay::new(Duration::from_secs(1))
+            .then(Delay::new(Duration::from_secs(2)))
+            .then(make_zero_tween())
+            .then(Delay::new(Duration::from_secs(1)));
+
+        for (progress, index, local) in [
+            (0., 0, 0.),
+            (0.125, 0, 0.5),
+            // Border; later child at 0
+            (0.25, 1, 0.),
+            (0.5, 1, 0.5),
+            // Border with a zero-duration child; skipped
+            (0.75, 3, 0.),
+            (0.875, 3, 0.5),
+            (1., 3, 1.),
+        ] {
+            let (actual_index, actual_local) = seq.locate(progress);
+            assert_eq!(actual_index, index, "progress={progress}");
+            assert_approx_eq!(actual_local, local);
+            assert_approx_eq!(seq.global_progress(index, local), progress);
+        }
+
+        // Clamping
+        assert_eq!(seq.locate(-1.), (0, 0.));
+        assert_eq!(seq.locate(2.), (3, 1.));
+        assert_approx_eq!(seq.global_progress(1, 2.), 0.75);
+        assert_approx_eq!(seq.global_progress(4, 0.), 1.);
+
+        // Zero-duration child maps to its start
+        assert_approx_eq!(seq.global_progress(2, 0.5), 0.75);
+
+        // Zero-duration sequence
+        let seq = Sequence::from_single(make_zero_tween());
+        assert_eq!(seq.locate(0.5), (0, 0.));
+        assert_eq!(seq.global_progress(0, 0.5), 0.);
     }
 
     /// Sequence::new() and various Sequence-specific methods
@@ -1693,6 +7017,36 @@ mod tests {
         assert_eq!(seq.duration(), Duration::from_secs(1));
     }
 
+    /// Sequence and Tracks from tuples of tweenables of different types.
+    #[test]
+    fn from_tuple() {
+        let delayed = || Delay::new(Duration::from_millis(500)).then(make_test_tween());
+        let seq = Sequence::from((make_test_tween(), delayed()));
+        assert_eq!(seq.duration(), Duration::from_millis(2500));
+        let seq = Sequence::from((
+            make_test_tween(),
+            delayed(),
+            Delay::new(Duration::from_secs(1)),
+            Tracks::from((make_test_tween(), delayed())),
+        ));
+        assert_eq!(seq.duration(), Duration::from_millis(5000));
+        assert_eq!(seq.tweens.len(), 4);
+
+        let mut tracks = Tracks::from((
+            make_test_tween(),
+            delayed(),
+            Sequence::from_single(make_test_tween()),
+        ));
+        assert_eq!(tracks.duration(), Duration::from_millis(1500));
+        assert_eq!(tracks.tracks.len(), 3);
+        let (mut world, entity) = make_test_env();
+        let state =
+            manual_tick_component(Duration::from_millis(1500), &mut tracks, &mut world, entity);
+        assert_eq!(state, TweenState::Completed);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert_eq!(transform.translation, Vec3::ONE);
+    }
+
     #[test]
     fn seq_elapsed() {
         let mut seq = Sequence::new((1..5).map(|i| {
@@ -1718,6 +7072,306 @@ mod tests {
         }
     }
 
+    #[test]
+    fn seq_seek() {
+        let (mut world, entity) = make_test_env();
+        let tween = |start: f32, end: f32| {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::X * start,
+                    end: Vec3::X * end,
+                },
+            )
+        };
+        let mut seq = Sequence::from((tween(0., 1.), tween(1., 2.), tween(2., 3.)));
+        let x = |world: &World| world.get::<Transform>(entity).unwrap().translation.x;
+
+        // Seeking forward completes the earlier children
+        seq.set_elapsed(Duration::from_millis(2350));
+        assert_eq!(seq.index(), 2);
+        assert_eq!(seq.current().elapsed(), Duration::from_millis(350));
+        assert_eq!(seq.elapsed(), Duration::from_millis(2350));
+        manual_tick_component(Duration::ZERO, &mut seq, &mut world, entity);
+        assert!((x(&world) - 2.35).abs() < 1e-5);
+
+        // Seeking backward rewinds the later children, which play again
+        seq.set_elapsed(Duration::from_millis(500));
+        assert_eq!(seq.index(), 0);
+        manual_tick_component(Duration::ZERO, &mut seq, &mut world, entity);
+        assert!((x(&world) - 0.5).abs() < 1e-5);
+        let state = manual_tick_component(Duration::from_secs(2), &mut seq, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        assert!((x(&world) - 2.5).abs() < 1e-5);
+
+        // Beyond the end, the sequence clamps and completes
+        seq.set_elapsed(Duration::from_secs(10));
+        assert_eq!(seq.elapsed(), Duration::from_secs(3));
+        let state = manual_tick_component(Duration::ZERO, &mut seq, &mut world, entity);
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(x(&world), 3.);
+
+        // A tween repeating forever wraps into the matching iteration
+        let mut looping = tween(0., 1.).with_repeat_count(RepeatCount::Infinite);
+        looping.set_elapsed(Duration::from_millis(7250));
+        assert_eq!(looping.times_completed(), 7);
+        let state = manual_tick_component(Duration::ZERO, &mut looping, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        assert!((x(&world) - 0.25).abs() < 1e-5);
+    }
+
+    #[test]
+    fn nested_total_duration() {
+        let (mut world, entity) = make_test_env();
+        let tween = |start: f32, end: f32| {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::X * start,
+                    end: Vec3::X * end,
+                },
+            )
+        };
+        let ping_pong = || {
+            tween(0., 1.)
+                .with_repeat_count(RepeatCount::Finite(3))
+                .with_repeat_strategy(RepeatStrategy::MirroredRepeat)
+        };
+        let finite = |millis| TotalDuration::Finite(Duration::from_millis(millis));
+
+        // A sequence and tracks span all the iterations of their children
+        let seq = ping_pong().then(Delay::millis(500));
+        assert_eq!(seq.duration(), Duration::from_millis(3500));
+        assert_eq!(seq.total_duration(), finite(3500));
+        assert_eq!(seq.locate(0.5), (0, 1750. / 3000.));
+        let tracks = Tracks::new([seq.into(), BoxedTweenable::from(tween(0., 1.))]);
+        assert_eq!(tracks.total_duration(), finite(3500));
+        let mut nested = Sequence::from((tracks, ping_pong()));
+        assert_eq!(nested.total_duration(), finite(6500));
+
+        // The nested animation completes exactly at its total duration
+        let state =
+            manual_tick_component(Duration::from_millis(6499), &mut nested, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        assert_eq!(nested.index(), 1);
+        assert_eq!(nested.current().elapsed(), Duration::from_millis(2999));
+        let state =
+            manual_tick_component(Duration::from_millis(1), &mut nested, &mut world, entity);
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(nested.elapsed(), Duration::from_millis(6500));
+        assert_eq!(world.get::<Transform>(entity).unwrap().translation, Vec3::X);
+
+        // Any child repeating forever makes the whole animation infinite
+        let infinite = || tween(0., 1.).with_repeat_count(RepeatCount::Infinite);
+        let seq = ping_pong().then(infinite());
+        assert_eq!(seq.duration(), Duration::from_secs(4));
+        assert_eq!(seq.total_duration(), TotalDuration::Infinite);
+        let tracks = Tracks::new([ping_pong(), infinite()]);
+        assert_eq!(tracks.duration(), Duration::from_secs(3));
+        assert_eq!(tracks.total_duration(), TotalDuration::Infinite);
+    }
+
+    #[test]
+    fn save_restore_state() {
+        // Descriptor rebuilding the same tweenable, as loaded from a save game
+        let make = || {
+            let bounce = Tween::new(
+                EaseFunction::QuadraticInOut,
+                Duration::from_millis(400),
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::new(1., 2., 3.),
+                },
+            )
+            .with_repeat_count(3)
+            .with_repeat_strategy(RepeatStrategy::MirroredRepeat)
+            .with_completed_event(1)
+            .with_elapsed_trigger(Duration::from_millis(900), 2);
+            let scale = Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformScaleLens {
+                    start: Vec3::ONE,
+                    end: Vec3::splat(3.),
+                },
+            );
+            let rotation = Tween::new(
+                EaseMethod::Linear,
+                Duration::from_millis(700),
+                TransformRotationLens {
+                    start: Quat::IDENTITY,
+                    end: Quat::from_rotation_z(1.),
+                },
+            );
+            let slide = Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::X,
+                    end: Vec3::Y,
+                },
+            );
+            Sequence::from((
+                bounce,
+                Delay::new(Duration::from_millis(300)).with_completed_event(3),
+                Tracks::from((scale, Eased::new(rotation, EaseFunction::QuadraticIn))),
+                Slice::new(slide, 0.2, 0.8).with_completed_event(4),
+            ))
+        };
+        let deltas = [337, 491, 3, 650, 177, 0, 612, 199, 333, 245, 501, 1000];
+        let tick = |tweenable: &mut dyn Tweenable<Transform>, world: &mut World, entity, millis| {
+            let state =
+                manual_tick_component(Duration::from_millis(millis), tweenable, world, entity);
+            let mut events = world.resource_mut::<Events<TweenCompleted>>();
+            let events: Vec<_> = events.drain().map(|event| event.user_data).collect();
+            (state, *world.get::<Transform>(entity).unwrap(), events)
+        };
+
+        // Play mid-way through the tracks, then take the tweenable out of its
+        // animator to save it
+        let (mut world, entity) = make_test_env();
+        let mut animator = Animator::new(make());
+        for millis in &deltas[..5] {
+            tick(animator.tweenable_mut(), &mut world, entity, *millis);
+        }
+        let mut original = animator.take_tweenable();
+        assert!(animator.is_shrunk());
+        assert_eq!(animator.tweenable().elapsed(), original.elapsed());
+        let state = original.save_state();
+        let TweenableState::Sequence { index, .. } = &state else {
+            panic!("expected a sequence state");
+        };
+        assert_eq!(*index, 2);
+        #[cfg(feature = "serde")]
+        let state: TweenableState = ron::from_str(&ron::to_string(&state).unwrap()).unwrap();
+
+        // Rebuild and restore onto a fresh entity, which then plays exactly
+        // like the original
+        let (mut restored_world, restored_entity) = make_test_env();
+        let mut restored = make();
+        assert!(restored.restore_state(&state));
+        assert_eq!(restored.save_state(), state);
+        assert_eq!(restored.elapsed(), original.elapsed());
+        assert_eq!(restored.times_completed(), original.times_completed());
+        for millis in &deltas[5..] {
+            let expected = tick(original.as_mut(), &mut world, entity, *millis);
+            let actual = tick(&mut restored, &mut restored_world, restored_entity, *millis);
+            assert_eq!(actual, expected);
+        }
+        assert_eq!(restored.progress(), 1.);
+
+        // Mismatching shapes are rejected
+        let mut tween = make_test_tween();
+        assert!(!tween.restore_state(&state));
+        let mut tracks = Tracks::new([make_test_tween()]);
+        assert!(!tracks.restore_state(&make().save_state()));
+        assert!(!Sequence::new([make_test_tween()]).restore_state(&state));
+        let placeholder = Animator::new(make_test_tween()).into_tweenable();
+        assert_eq!(placeholder.save_state(), make_test_tween().save_state());
+    }
+
+    #[test]
+    fn seq_prepend() {
+        let tween = |start, end, user_data| {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens { start, end },
+            )
+            .with_completed_event(user_data)
+        };
+
+        // Idle sequence: the prepended children play first
+        let mut seq = Sequence::from_single(tween(Vec3::Y, Vec3::Z, 3))
+            .prepend(tween(Vec3::X, Vec3::Y, 2))
+            .prepend(tween(Vec3::ZERO, Vec3::X, 1));
+        assert_eq!(seq.duration(), Duration::from_secs(3));
+        assert_eq!(seq.index(), 0);
+        assert_eq!(seq.elapsed(), Duration::ZERO);
+        let (mut world, entity) = make_test_env();
+        manual_tick_component(Duration::from_millis(1500), &mut seq, &mut world, entity);
+        assert_eq!(seq.index(), 1);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(0.5, 0.5, 0.), 1e-5));
+
+        let mut seq = tween(Vec3::X, Vec3::Y, 2).before(tween(Vec3::ZERO, Vec3::X, 1));
+        manual_tick_component(Duration::from_millis(500), &mut seq, &mut world, entity);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(0.5, 0., 0.), 1e-5));
+
+        // Mid-playback: the prepended child is already played
+        let (mut world, entity) = make_test_env();
+        let mut reader = world.resource::<Events<TweenCompleted>>().get_reader();
+        let mut seq = Sequence::from_single(tween(Vec3::X, Vec3::Y, 2));
+        manual_tick_component(Duration::from_millis(500), &mut seq, &mut world, entity);
+        let mut seq = seq.prepend(tween(Vec3::ZERO, Vec3::X, 1));
+        assert_eq!(seq.duration(), Duration::from_secs(2));
+        assert_eq!(seq.index(), 1);
+        assert_eq!(seq.elapsed(), Duration::from_millis(1500));
+        assert_eq!(seq.tweens[0].progress(), 1.);
+        let state = manual_tick_component(Duration::from_millis(500), &mut seq, &mut world, entity);
+        assert_eq!(state, TweenState::Completed);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert_eq!(transform.translation, Vec3::Y);
+        let events = world.resource::<Events<TweenCompleted>>();
+        let user_data: Vec<_> = reader.read(events).map(|event| event.user_data).collect();
+        assert_eq!(user_data, [2]);
+
+        // Rewinding plays the prepended child again
+        seq.rewind();
+        manual_tick_component(Duration::from_millis(500), &mut seq, &mut world, entity);
+        assert_eq!(seq.index(), 0);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(0.5, 0., 0.), 1e-5));
+    }
+
+    /// Building a sequence or tracks from an empty collection with
+    /// `try_new()` returns an error naming the composite type.
+    #[test]
+    fn empty_composites() {
+        let empty = Vec::<Tween<Transform>>::new;
+        let err = Sequence::try_new(empty()).unwrap_err();
+        assert_eq!(err.type_name(), "Sequence");
+        let err = Tracks::try_new(empty()).unwrap_err();
+        assert_eq!(err.type_name(), "Tracks");
+        assert_eq!(
+            err.to_string(),
+            "cannot create a Tracks from an empty collection, it needs at least one child"
+        );
+
+        let seq = Sequence::try_new([make_test_tween()]).unwrap();
+        assert_eq!(seq.duration(), Duration::from_secs(1));
+        assert_eq!(seq.index(), 0);
+        let tracks = Tracks::try_new([make_test_tween()]).unwrap();
+        assert_eq!(tracks.duration(), Duration::from_secs(1));
+        assert_eq!(tracks.validate(), Ok(()));
+
+        let seq: Sequence<Transform> = (make_test_tween(),).into();
+        assert_eq!(seq.duration(), Duration::from_secs(1));
+        let tracks: Tracks<Transform> = (make_test_tween(),).into();
+        assert_eq!(tracks.duration(), Duration::from_secs(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot create a Sequence from an empty collection")]
+    fn seq_empty_panics() {
+        let _ = Sequence::new(Vec::<Tween<Transform>>::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot create a Tracks from an empty collection")]
+    fn tracks_empty_panics() {
+        let _ = Tracks::new(Vec::<Tween<Transform>>::new());
+    }
+
     /// Test ticking parallel tracks of tweens.
     #[test]
     fn tracks_tick() {
@@ -1920,9 +7574,92 @@ mod tests {
         delay.clear_completed();
         assert!(delay.on_completed.is_none());
 
-        // Clear event sending
-        delay.clear_completed_event();
-        assert!(delay.event_data.is_none());
+        // Clear event sending
+        delay.clear_completed_event();
+        assert!(delay.event_data.is_none());
+    }
+
+    #[test]
+    fn delay_set_duration() {
+        let (mut world, entity) = make_test_env();
+        let mut reader = world.resource::<Events<TweenCompleted>>().get_reader();
+
+        // Extending keeps the elapsed time
+        let mut delay = Delay::new(Duration::from_secs(3)).with_completed_event(1);
+        manual_tick_component(Duration::from_secs(2), &mut delay, &mut world, entity);
+        delay.set_duration(Duration::from_secs(5));
+        assert_eq!(
+            Tweenable::<Transform>::elapsed(&delay),
+            Duration::from_secs(2)
+        );
+        assert_eq!(delay.remaining(), Duration::from_secs(3));
+        let state = manual_tick_component(Duration::from_secs(2), &mut delay, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        let state = manual_tick_component(Duration::from_secs(1), &mut delay, &mut world, entity);
+        assert_eq!(state, TweenState::Completed);
+
+        // Extending a completed delay makes it pending again
+        delay.set_duration(Duration::from_secs(6));
+        assert!(!delay.is_completed());
+        assert_eq!(delay.remaining(), Duration::from_secs(1));
+
+        // Shortening below the elapsed time completes on the next tick
+        let mut delay = Delay::new(Duration::from_secs(3)).with_completed_event(2);
+        manual_tick_component(Duration::from_secs(2), &mut delay, &mut world, entity);
+        delay.set_duration(Duration::from_secs(1));
+        assert_eq!(delay.remaining(), Duration::ZERO);
+        let state = manual_tick_component(Duration::ZERO, &mut delay, &mut world, entity);
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(
+            Tweenable::<Transform>::elapsed(&delay),
+            Duration::from_secs(1)
+        );
+
+        let events = world.resource::<Events<TweenCompleted>>();
+        let user_data: Vec<_> = reader.read(events).map(|event| event.user_data).collect();
+        assert_eq!(user_data, [1, 2]);
+    }
+
+    #[test]
+    fn delay_handle_in_sequence() {
+        let (mut world, entity) = make_test_env();
+        let mut reader = world.resource::<Events<TweenCompleted>>().get_reader();
+        let mut delay = Delay::new(Duration::from_secs(3)).with_completed_event(1);
+        let handle = delay.handle();
+        let tween = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+        let mut seq = delay.then(tween);
+        manual_tick_component(Duration::from_secs(1), &mut seq, &mut world, entity);
+
+        // Extending the pending delay extends the sequence
+        handle.set_duration(Duration::from_secs(5));
+        manual_tick_component(Duration::from_secs(1), &mut seq, &mut world, entity);
+        assert_eq!(seq.duration(), Duration::from_secs(6));
+        assert_eq!(seq.elapsed(), Duration::from_secs(2));
+        assert_eq!(seq.index(), 0);
+
+        // Finishing the delay plays the next child immediately
+        handle.finish_now();
+        let state = manual_tick_component(Duration::from_millis(500), &mut seq, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        assert_eq!(seq.duration(), Duration::from_secs(3));
+        assert_eq!(seq.index(), 1);
+        assert_eq!(seq.elapsed(), Duration::from_millis(2500));
+        assert_approx_eq!(seq.progress(), 2.5 / 3.);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.5), 1e-5));
+        let events = world.resource::<Events<TweenCompleted>>();
+        let user_data: Vec<_> = reader.read(events).map(|event| event.user_data).collect();
+        assert_eq!(user_data, [1]);
+
+        let state = manual_tick_component(Duration::from_millis(500), &mut seq, &mut world, entity);
+        assert_eq!(state, TweenState::Completed);
     }
 
     #[test]
@@ -1952,6 +7689,364 @@ mod tests {
         }
     }
 
+    #[test]
+    fn tween_with_speed() {
+        let make_tween = |end: Vec3| {
+            Tween::new_with_speed(
+                EaseMethod::Linear,
+                2.,
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end,
+                },
+            )
+        };
+        let mut tween = make_tween(Vec3::new(3., 4., 0.)).with_repeat_count(2);
+        assert_eq!(tween.speed(), Some(2.));
+        assert_eq!(tween.duration(), Duration::from_millis(2500));
+        assert_eq!(
+            tween.total_duration(),
+            TotalDuration::Finite(Duration::from_secs(5))
+        );
+        let (mut world, entity) = make_test_env();
+        let state = manual_tick_component(Duration::from_secs(1), &mut tween, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        let translation = world.get::<Transform>(entity).unwrap().translation;
+        assert!(translation.abs_diff_eq(Vec3::new(1.2, 1.6, 0.), 1e-5));
+
+        // An explicit duration replaces the speed
+        tween.set_duration(Duration::from_secs(1));
+        assert_eq!(tween.speed(), None);
+
+        // A zero distance makes an instant tween, completing on its first tick
+        let mut tween = make_tween(Vec3::ZERO).with_completed_event(3);
+        assert_eq!(tween.duration(), Duration::ZERO);
+        assert_eq!(tween.times_completed(), 0);
+        world.get_mut::<Transform>(entity).unwrap().translation = Vec3::ONE;
+        for _ in 0..2 {
+            let state = manual_tick_component(Duration::ZERO, &mut tween, &mut world, entity);
+            assert_eq!(state, TweenState::Completed);
+            assert_eq!(tween.times_completed(), 1);
+            assert_eq!(tween.progress(), 1.);
+            assert_eq!(
+                world.get::<Transform>(entity).unwrap().translation,
+                Vec3::ZERO
+            );
+            let events = world
+                .resource_mut::<Events<TweenCompleted>>()
+                .drain()
+                .count();
+            assert_eq!(events, 1);
+
+            // Rewinding plays it again; otherwise it's left untouched
+            world.get_mut::<Transform>(entity).unwrap().translation = Vec3::ONE;
+            let state = manual_tick_component(Duration::ZERO, &mut tween, &mut world, entity);
+            assert_eq!(state, TweenState::Completed);
+            assert_eq!(
+                world.get::<Transform>(entity).unwrap().translation,
+                Vec3::ONE
+            );
+            tween.rewind();
+        }
+    }
+
+    #[test]
+    fn tween_with_speed_capture() {
+        #[derive(Component)]
+        struct Offset(Option<Vec2>);
+
+        struct OffsetLens(OptionLens<ValueLens<Vec2>, Vec2>);
+
+        impl Lens<Offset> for OffsetLens {
+            fn lerp(&mut self, target: &mut Offset, ratio: f32) {
+                self.0.lerp(&mut target.0, ratio);
+            }
+        }
+
+        impl PathLens for OffsetLens {
+            fn arc_length(&self) -> f32 {
+                self.0.arc_length()
+            }
+        }
+
+        let mut tween = Tween::new_with_speed(
+            EaseMethod::Linear,
+            10.,
+            OffsetLens(OptionLens::new(
+                ValueLens {
+                    start: Vec2::ZERO,
+                    end: Vec2::new(40., 0.),
+                },
+                MissingValuePolicy::TakeCurrentAsStart,
+            )),
+        );
+        assert_eq!(tween.duration(), Duration::from_secs(4));
+
+        // The duration is measured again from the captured start
+        let mut world = World::new();
+        world.init_resource::<Events<TweenCompleted>>();
+        let entity = world.spawn(Offset(Some(Vec2::new(30., 0.)))).id();
+        let state =
+            manual_tick_component(Duration::from_millis(500), &mut tween, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        assert_eq!(tween.duration(), Duration::from_secs(1));
+        let offset = world.get::<Offset>(entity).unwrap().0.unwrap();
+        assert!(offset.abs_diff_eq(Vec2::new(35., 0.), 1e-5));
+    }
+
+    #[test]
+    fn seq_boundary_blend() {
+        let make_sequence = |second_start: f32, blend: Duration| {
+            Sequence::new([
+                Tween::new(
+                    EaseFunction::BackOut,
+                    Duration::from_secs(1),
+                    TransformPositionLens {
+                        start: Vec3::ZERO,
+                        end: Vec3::X,
+                    },
+                ),
+                Tween::new(
+                    EaseMethod::Linear,
+                    Duration::from_secs(1),
+                    TransformPositionLens {
+                        start: Vec3::X * second_start,
+                        end: Vec3::X * 3.,
+                    },
+                ),
+            ])
+            .with_boundary_blend(blend)
+        };
+
+        // Play a sequence at 60 FPS, returning the largest jump of the target
+        // between two frames, and all the values.
+        let play = |mut sequence: Sequence<Transform>| {
+            let (mut world, entity) = make_test_env();
+            let delta = Duration::from_secs_f32(1. / 60.);
+            let mut values = vec![];
+            let mut state = TweenState::Active;
+            while state == TweenState::Active {
+                state = manual_tick_component(delta, &mut sequence, &mut world, entity);
+                values.push(world.get::<Transform>(entity).unwrap().translation.x);
+            }
+            let max_jump = values
+                .windows(2)
+                .map(|w| (w[1] - w[0]).abs())
+                .fold(0., f32::max);
+            (max_jump, values)
+        };
+
+        // Mismatched endpoints pop without blending, but not with blending
+        let (max_jump, values) = play(make_sequence(2., Duration::ZERO));
+        assert!(max_jump > 0.9);
+        assert_eq!(*values.last().unwrap(), 3.);
+        let (max_jump, values) = play(make_sequence(2., Duration::from_millis(250)));
+        assert!(max_jump < 0.1, "max jump {max_jump}");
+        assert_eq!(*values.last().unwrap(), 3.);
+
+        // Matching endpoints are not affected
+        let (_, hard_cut) = play(make_sequence(1., Duration::ZERO));
+        let (_, blended) = play(make_sequence(1., Duration::from_millis(250)));
+        assert_eq!(hard_cut, blended);
+
+        // The blend restarts after a rewind, and stops on a seek
+        let mut sequence = make_sequence(2., Duration::from_millis(250));
+        let (mut world, entity) = make_test_env();
+        manual_tick_component(Duration::from_secs(1), &mut sequence, &mut world, entity);
+        let translation = world.get::<Transform>(entity).unwrap().translation;
+        assert!(translation.abs_diff_eq(Vec3::X, 1e-5));
+        sequence.set_elapsed(Duration::from_millis(1100));
+        manual_tick_component(Duration::ZERO, &mut sequence, &mut world, entity);
+        let translation = world.get::<Transform>(entity).unwrap().translation;
+        assert!(translation.abs_diff_eq(Vec3::X * 2.1, 1e-5));
+        sequence.rewind();
+        manual_tick_component(
+            Duration::from_millis(1100),
+            &mut sequence,
+            &mut world,
+            entity,
+        );
+        // Start blended 40% from 1 to 2, then 10% of the way to 3
+        let translation = world.get::<Transform>(entity).unwrap().translation;
+        assert!(translation.abs_diff_eq(Vec3::X * 1.56, 1e-5));
+    }
+
+    #[test]
+    fn progress_exact_at_completion() {
+        let make_tween = |millis| {
+            Tween::new(
+                EaseMethod::Linear,
+                Duration::from_millis(millis),
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+            )
+        };
+        let tweenables: [BoxedTweenable<Transform>; 4] = [
+            Box::new(make_tween(1000)),
+            Box::new(Delay::new(Duration::from_millis(1000))),
+            Box::new(Sequence::new([
+                make_tween(333),
+                make_tween(334),
+                make_tween(333),
+            ])),
+            Box::new(Tracks::new([make_tween(1000), make_tween(700)])),
+        ];
+        for mut tweenable in tweenables {
+            let (mut world, entity) = make_test_env();
+            // Deltas which don't divide the duration, and lose precision
+            let delta = Duration::from_secs_f32(1. / 60.);
+            let mut state = TweenState::Active;
+            let mut ticks = 0;
+            while state == TweenState::Active {
+                state = manual_tick_component(delta, tweenable.as_mut(), &mut world, entity);
+                ticks += 1;
+                assert!(ticks < 100);
+                if state == TweenState::Active {
+                    assert!(tweenable.progress() < 1.);
+                }
+            }
+            assert_eq!(tweenable.progress(), 1.);
+
+            // Ticking again after completion keeps the exact value
+            manual_tick_component(delta, tweenable.as_mut(), &mut world, entity);
+            assert_eq!(tweenable.progress(), 1.);
+
+            tweenable.rewind();
+            assert_eq!(tweenable.progress(), 0.);
+        }
+    }
+
+    #[test]
+    fn validate() {
+        assert_eq!(make_test_tween().validate(), Ok(()));
+
+        let empty = Sequence::<Transform>::with_capacity(0);
+        let id = empty.id().unwrap();
+        assert_eq!(
+            Eased::new(empty, EaseFunction::QuadraticIn).validate(),
+            Err(TweenValidationError::EmptyComposite(id))
+        );
+
+        let nan = Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::new(1., f32::NAN, 0.),
+            },
+        );
+        let id = nan.id().unwrap();
+        let tracks = Tracks::new([
+            make_test_tween().then(nan),
+            make_test_tween().then(make_test_tween()),
+        ]);
+        assert_eq!(
+            tracks.validate(),
+            Err(TweenValidationError::InvalidLens(id))
+        );
+
+        // A looping child can only be the last one of a sequence
+        let looping = || make_test_tween().with_repeat_count(RepeatCount::Infinite);
+        assert_eq!(make_test_tween().then(looping()).validate(), Ok(()));
+        let seq = looping().then(make_test_tween());
+        let id = seq.id().unwrap();
+        assert_eq!(seq.validate(), Err(TweenValidationError::InfiniteChild(id)));
+    }
+
+    #[test]
+    fn build_on_thread() {
+        let tweenable = std::thread::spawn(|| {
+            let tracks = (0..16).map(|track| {
+                Sequence::new((0..64).map(|index| {
+                    Tween::new(
+                        EaseFunction::QuadraticInOut,
+                        Duration::from_millis(10 + index),
+                        TransformPositionLens {
+                            start: Vec3::splat(index as f32),
+                            end: Vec3::splat(track as f32),
+                        },
+                    )
+                }))
+            });
+            let tweenable = Delay::new(Duration::from_millis(100)).then(Tracks::new(tracks));
+            tweenable.validate().map(|()| tweenable)
+        })
+        .join()
+        .unwrap()
+        .unwrap();
+
+        let mut world = World::new();
+        let entity = world.spawn(Transform::default()).id();
+        let mut queue = CommandQueue::default();
+        Commands::new(&mut queue, &world)
+            .entity(entity)
+            .insert(Animator::new(tweenable));
+        queue.apply(&mut world);
+        let animator = world.get::<Animator<Transform>>(entity).unwrap();
+        assert_eq!(
+            animator.tweenable().duration(),
+            Duration::from_millis(100 + 64 * 10 + 63 * 64 / 2)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn tween_zero_speed_panics() {
+        let _: Tween<Transform> = Tween::new_with_speed(
+            EaseMethod::Linear,
+            0.,
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+    }
+
+    #[test]
+    fn tween_id() {
+        let tween = make_test_tween();
+        let delay: Delay<Transform> = Delay::new(Duration::from_secs(1));
+        let id = tween.id().unwrap();
+        let delay_id = delay.id().unwrap();
+        assert!(delay_id > id);
+        assert_eq!(format!("{}", id), format!("tween#{}", id.get()));
+        assert!(format!("{:?}", tween).contains(&format!("{:?}", id)));
+
+        // Children keep their identifier when nested
+        let seq = tween.then(delay);
+        let seq_id = seq.id().unwrap();
+        assert!(seq_id > delay_id);
+        assert_eq!(seq.tweens[0].id(), Some(id));
+        assert_eq!(seq.tweens[1].id(), Some(delay_id));
+        let debug = format!("{:?}", seq);
+        assert!(debug.contains(&format!("{:?}", seq_id)));
+        assert!(debug.contains(&format!("{:?}", delay_id)));
+        let tracks = Tracks::new([seq]);
+        assert!(tracks.id().unwrap() > seq_id);
+        assert_eq!(tracks.tracks[0].id(), Some(seq_id));
+    }
+
+    #[test]
+    fn tween_id_events() {
+        let mut tween = make_test_tween().with_completed_event(7);
+        let id = tween.id().unwrap();
+        let mut cancelled = Vec::new();
+        tween.collect_cancelled(Entity::PLACEHOLDER, &mut cancelled);
+        assert_eq!(cancelled.len(), 1);
+        assert_eq!(cancelled[0].tween_id, id);
+
+        let (mut world, entity) = make_test_env();
+        manual_tick_component(Duration::from_secs(1), &mut tween, &mut world, entity);
+        let events = world.resource::<Events<TweenCompleted>>();
+        let mut reader = events.get_reader();
+        let ev = reader.read(events).next().unwrap();
+        assert_eq!(ev.user_data, 7);
+        assert_eq!(ev.tween_id, id);
+        assert_eq!(tween.id(), Some(id));
+    }
+
     #[test]
     #[should_panic]
     fn delay_zero_duration_panics() {
@@ -2041,7 +8136,7 @@ mod tests {
         assert_eq!(1, tween.times_completed());
         assert_approx_eq!(tween.progress(), 0.2);
         let transform = world.entity(entity).get::<Transform>().unwrap();
-        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.8), 1e-5));
+        assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5)); // apex on turnaround tick
 
         // rewind
         tween.rewind();
@@ -2049,7 +8144,7 @@ mod tests {
         assert_eq!(0, tween.times_completed());
         assert_approx_eq!(tween.progress(), 0.);
         let transform = world.entity(entity).get::<Transform>().unwrap();
-        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.8), 1e-5)); // no-op, rewind doesn't apply Lens
+        assert!(transform.translation.abs_diff_eq(Vec3::ONE, 1e-5)); // no-op, rewind doesn't apply Lens
 
         // 400% - done mirror (because Completed freezes the state)
         let state =
@@ -2069,4 +8164,334 @@ mod tests {
         let transform = world.entity(entity).get::<Transform>().unwrap();
         assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5)); // no-op, rewind doesn't apply Lens
     }
+
+    #[test]
+    fn tween_mirrored_set_elapsed() {
+        let mut tween = make_test_tween()
+            .with_repeat_count(RepeatCount::Finite(4))
+            .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
+        let (mut world, entity) = make_test_env();
+
+        // 130% - second loop, backward
+        tween.set_elapsed(Duration::from_millis(1300));
+        assert_eq!(TweeningDirection::Backward, tween.direction());
+        assert_eq!(1, tween.times_completed());
+        let state = manual_tick_component(Duration::ZERO, &mut tween, &mut world, entity);
+        assert_eq!(TweenState::Active, state);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(0.7), 1e-5));
+
+        // 250% - third loop, forward again
+        tween.set_elapsed(Duration::from_millis(2500));
+        assert_eq!(TweeningDirection::Forward, tween.direction());
+        assert_eq!(2, tween.times_completed());
+
+        // 500% - completed on the last loop, backward; still applied once
+        tween.set_elapsed(Duration::from_secs(5));
+        assert_eq!(TweeningDirection::Backward, tween.direction());
+        assert_eq!(4, tween.times_completed());
+        let state = manual_tick_component(Duration::ZERO, &mut tween, &mut world, entity);
+        assert_eq!(TweenState::Completed, state);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform.translation.abs_diff_eq(Vec3::ZERO, 1e-5));
+    }
+
+    /// Test that wrapping a linear tween matches a tween directly using the
+    /// wrapper easing.
+    #[test]
+    fn eased_tick() {
+        let mut eased = Eased::new(make_test_tween(), EaseFunction::QuadraticInOut);
+        let mut direct = Tween::new(
+            EaseFunction::QuadraticInOut,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+        assert_eq!(eased.duration(), Duration::from_secs(1));
+        assert_eq!(
+            eased.total_duration(),
+            TotalDuration::Finite(Duration::from_secs(1))
+        );
+
+        let (mut world, entity) = make_test_env();
+        let direct_entity = world.spawn(Transform::default()).id();
+
+        for i in 1..=12 {
+            let delta = Duration::from_millis(100);
+            let state = manual_tick_component(delta, &mut eased, &mut world, entity);
+            let direct_state = manual_tick_component(delta, &mut direct, &mut world, direct_entity);
+            assert_eq!(state, direct_state);
+            assert_eq!(state == TweenState::Completed, i >= 10);
+            assert_eq!(eased.elapsed(), direct.elapsed());
+
+            let actual = world.get::<Transform>(entity).unwrap().translation;
+            let expected = world.get::<Transform>(direct_entity).unwrap().translation;
+            assert!(actual.abs_diff_eq(expected, 1e-5));
+        }
+
+        eased.rewind();
+        assert_eq!(eased.elapsed(), Duration::ZERO);
+        assert_eq!(eased.child().elapsed(), Duration::ZERO);
+    }
+
+    /// Test wrapping a child looping forever over a zero duration.
+    #[test]
+    fn eased_zero_duration_child() {
+        /// Tweenable with a zero duration, which never completes.
+        struct Forever(Duration);
+
+        impl Tweenable<Transform> for Forever {
+            fn duration(&self) -> Duration {
+                Duration::ZERO
+            }
+
+            fn total_duration(&self) -> TotalDuration {
+                TotalDuration::Infinite
+            }
+
+            fn set_elapsed(&mut self, elapsed: Duration) {
+                self.0 = elapsed;
+            }
+
+            fn elapsed(&self) -> Duration {
+                self.0
+            }
+
+            fn tick(
+                &mut self,
+                delta: Duration,
+                _target: &mut dyn Targetable<Transform>,
+                _entity: Entity,
+                _events: &mut Vec<TweenCompleted>,
+            ) -> TweenState {
+                self.0 += delta;
+                TweenState::Active
+            }
+
+            fn rewind(&mut self) {
+                self.0 = Duration::ZERO;
+            }
+        }
+
+        let child: BoxedTweenable<Transform> = Box::new(Forever(Duration::ZERO));
+        let mut eased = Eased::new(child, EaseFunction::QuadraticInOut);
+        let (mut world, entity) = make_test_env();
+        for _ in 0..3 {
+            let state =
+                manual_tick_component(Duration::from_millis(100), &mut eased, &mut world, entity);
+            assert_eq!(state, TweenState::Active);
+        }
+        assert_eq!(eased.elapsed(), Duration::from_millis(300));
+        eased.set_elapsed(Duration::from_millis(50));
+        assert_eq!(eased.child().elapsed(), Duration::from_millis(50));
+    }
+
+    /// Test that completion events of the wrapped tweenable are propagated.
+    #[test]
+    fn eased_completed_event() {
+        let mut eased = Eased::new(
+            make_test_tween().with_completed_event(42),
+            EaseFunction::QuadraticOut,
+        );
+
+        let (mut world, entity) = make_test_env();
+        let mut event_reader_system_state: SystemState<EventReader<TweenCompleted>> =
+            SystemState::new(&mut world);
+
+        let state =
+            manual_tick_component(Duration::from_millis(500), &mut eased, &mut world, entity);
+        assert_eq!(state, TweenState::Active);
+        // QuadraticOut(0.5) = 0.75
+        assert_approx_eq!(eased.child().progress(), 0.75);
+        let mut event_reader = event_reader_system_state.get_mut(&mut world);
+        assert!(event_reader.read().next().is_none());
+
+        let state =
+            manual_tick_component(Duration::from_millis(500), &mut eased, &mut world, entity);
+        assert_eq!(state, TweenState::Completed);
+        let transform = world.get::<Transform>(entity).unwrap();
+        assert_eq!(transform.translation, Vec3::ONE);
+        let mut event_reader = event_reader_system_state.get_mut(&mut world);
+        let event = event_reader.read().next().expect("Expected event");
+        assert_eq!(event.entity, entity);
+        assert_eq!(event.user_data, 42);
+    }
+}
+
+/// Round trip of [`Tweenable::set_progress()`], [`Tweenable::progress()`], and
+/// the value applied to the target, for each built-in tweenable.
+#[cfg(test)]
+mod progress_tests {
+    use super::*;
+    use crate::{lens::*, EaseFunction};
+
+    /// Seek `tweenable` to `progress` after ticking it by `warmup`, then check
+    /// the reported progress, and that the target equals sampling the
+    /// tweenable at its elapsed time after a zero-delta tick, and again after
+    /// playing on by `play_on`.
+    fn round_trip(
+        name: &str,
+        make: &dyn Fn() -> BoxedTweenable<Transform>,
+        looping: bool,
+        progress: f32,
+        play_on: Duration,
+    ) {
+        for warmup in [0, 300, 1300] {
+            let mut tweenable = make();
+            let mut world = World::new();
+            let entity = world.spawn(Transform::default()).id();
+            let mut events = vec![];
+            let mut tick = |tweenable: &mut BoxedTweenable<Transform>, world: &mut World, delta| {
+                let target = world.get_mut::<Transform>(entity).unwrap();
+                tweenable.tick(
+                    delta,
+                    &mut ComponentTarget::new(target),
+                    entity,
+                    &mut events,
+                )
+            };
+            tick(&mut tweenable, &mut world, Duration::from_millis(warmup));
+
+            tweenable.set_progress(progress);
+            let expected_progress = if looping {
+                progress.fract()
+            } else {
+                progress.min(1.)
+            };
+            assert!(
+                (tweenable.progress() - expected_progress).abs() < 1e-5,
+                "{name} @ {progress} (warmup {warmup}ms): progress {} != {expected_progress}",
+                tweenable.progress(),
+            );
+            // Applying the seeked time with a zero-delta tick, then playing on,
+            // follows the sampled values.
+            for step in [Duration::ZERO, play_on] {
+                tick(&mut tweenable, &mut world, step);
+                let mut expected = Transform::default();
+                assert!(tweenable.sample(tweenable.elapsed(), &mut expected));
+                let actual = *world.get::<Transform>(entity).unwrap();
+                assert!(
+                    actual.translation.abs_diff_eq(expected.translation, 1e-5)
+                        && actual.scale.abs_diff_eq(expected.scale, 1e-5),
+                    "{name} @ {progress} (warmup {warmup}ms, +{step:?}): {actual:?} != {expected:?}",
+                );
+            }
+        }
+    }
+
+    fn make_position(count: RepeatCount, strategy: RepeatStrategy) -> Tween<Transform> {
+        Tween::new(
+            EaseFunction::QuadraticInOut,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::new(1., 2., 3.),
+            },
+        )
+        .with_repeat_count(count)
+        .with_repeat_strategy(strategy)
+    }
+
+    fn make_scale(millis: u64) -> Tween<Transform> {
+        Tween::new(
+            EaseFunction::SineOut,
+            Duration::from_millis(millis),
+            TransformScaleLens {
+                start: Vec3::ONE,
+                end: Vec3::splat(3.),
+            },
+        )
+    }
+
+    const GRID: [f32; 9] = [0., 0.1, 0.25, 0.4, 0.5, 0.6, 0.75, 0.9, 1.];
+
+    #[test]
+    fn tween_once() {
+        let make = || -> BoxedTweenable<Transform> {
+            Box::new(make_position(1.into(), RepeatStrategy::Repeat))
+        };
+        for progress in GRID {
+            round_trip("once", &make, false, progress, Duration::from_millis(400));
+        }
+    }
+
+    #[test]
+    fn tween_loop() {
+        let make = || -> BoxedTweenable<Transform> {
+            Box::new(make_position(RepeatCount::Infinite, RepeatStrategy::Repeat))
+        };
+        for progress in GRID
+            .iter()
+            .chain(&GRID)
+            .enumerate()
+            .map(|(i, p)| p + (i / 9) as f32)
+        {
+            round_trip("loop", &make, true, progress, Duration::from_millis(400));
+        }
+    }
+
+    /// Play on without completing a leg, since the tick a leg completes on
+    /// applies the exact endpoint instead of the sampled value.
+    const LEG_STEP: Duration = Duration::from_millis(50);
+
+    #[test]
+    fn tween_ping_pong() {
+        let make = || -> BoxedTweenable<Transform> {
+            Box::new(make_position(
+                RepeatCount::Infinite,
+                RepeatStrategy::MirroredRepeat,
+            ))
+        };
+        for progress in GRID
+            .iter()
+            .chain(&GRID)
+            .enumerate()
+            .map(|(i, p)| p + (i / 9) as f32)
+        {
+            round_trip("ping-pong", &make, true, progress, LEG_STEP);
+        }
+    }
+
+    #[test]
+    fn sequence() {
+        let make = || -> BoxedTweenable<Transform> {
+            Box::new(
+                make_scale(500)
+                    .then(Delay::new(Duration::from_millis(250)))
+                    .then(make_position(1.into(), RepeatStrategy::Repeat)),
+            )
+        };
+        for progress in GRID {
+            round_trip(
+                "sequence",
+                &make,
+                false,
+                progress,
+                Duration::from_millis(400),
+            );
+        }
+    }
+
+    #[test]
+    fn tracks() {
+        let make = || -> BoxedTweenable<Transform> {
+            Box::new(Tracks::from((
+                make_position(1.into(), RepeatStrategy::Repeat),
+                Delay::new(Duration::from_millis(250)).then(make_scale(500)),
+            )))
+        };
+        for progress in GRID {
+            round_trip("tracks", &make, false, progress, Duration::from_millis(400));
+        }
+    }
+
+    #[test]
+    fn delay() {
+        let make = || -> BoxedTweenable<Transform> { Box::new(Delay::new(Duration::from_secs(1))) };
+        for progress in GRID {
+            round_trip("delay", &make, false, progress, Duration::from_millis(400));
+        }
+    }
 }
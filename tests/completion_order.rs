@@ -0,0 +1,126 @@
+//! Order of the lens applications and completion notifications.
+//!
+//! When a single tick skips over several children of a [`Sequence`], each
+//! child must have applied its end state to the target before its completion
+//! is notified, and after all the earlier children applied theirs. This runs
+//! a sequence of three children skipped in one frame, with a callback
+//! snapshotting the target on the completion of each of them.
+
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use bevy::prelude::*;
+use bevy_tweening::{lens::*, *};
+
+/// Values of the target seen by the completion callbacks, per child index.
+type Snapshots = Arc<Mutex<Vec<(u64, Vec3)>>>;
+
+/// Child `index` of the sequence, moving along X from `index` to `index + 1`,
+/// and along Y only for the first child, so later children overwriting the
+/// whole translation are detected.
+fn child(index: u64, snapshots: &Snapshots, min_step: Duration) -> Tween<Transform> {
+    let snapshots = Arc::clone(snapshots);
+    let start = index as f32;
+    let y = if index == 0 { 1. } else { 0. };
+    Tween::new(
+        EaseFunction::QuadraticInOut,
+        Duration::from_millis(300),
+        TransformPositionLens {
+            start: Vec3::new(start, y, 0.),
+            end: Vec3::new(start + 1., y, 0.),
+        },
+    )
+    .with_min_step(min_step)
+    .with_completed_event(index)
+    .with_completed_with_target(move |_entity, _tween, transform: &Transform| {
+        snapshots
+            .lock()
+            .unwrap()
+            .push((index, transform.translation));
+    })
+}
+
+fn run(min_step: Duration, nested: bool, deltas: &[u64]) -> (Vec<(u64, Vec3)>, Vec<u64>) {
+    let mut app = App::new();
+    app.add_plugins(
+        TweeningPlugin::default()
+            .without_defaults()
+            .register::<Transform>(),
+    )
+    .init_resource::<Time>();
+
+    let snapshots = Snapshots::default();
+    let children = (0..3).map(|index| child(index, &snapshots, min_step));
+    let animator = if nested {
+        // Warp each child, and wrap the sequence in a single track
+        let sequence = Sequence::new(children.map(|tween| Eased::new(tween, EaseFunction::SineIn)));
+        Animator::new(Tracks::new([sequence]))
+    } else {
+        Animator::new(Sequence::new(children))
+    };
+    app.world.spawn((Transform::default(), animator));
+
+    let mut reader = app.world.resource::<Events<TweenCompleted>>().get_reader();
+    let mut user_data = vec![];
+    for &millis in deltas {
+        app.world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(millis));
+        app.update();
+        let events = app.world.resource::<Events<TweenCompleted>>();
+        user_data.extend(reader.read(events).map(|event| event.user_data));
+    }
+
+    let snapshots = snapshots.lock().unwrap().clone();
+    (snapshots, user_data)
+}
+
+fn assert_end_states(snapshots: &[(u64, Vec3)]) {
+    let expected = [
+        (0, Vec3::new(1., 1., 0.)),
+        (1, Vec3::new(2., 0., 0.)),
+        (2, Vec3::new(3., 0., 0.)),
+    ];
+    assert_eq!(snapshots.len(), expected.len());
+    for ((index, value), (expected_index, expected_value)) in snapshots.iter().zip(expected) {
+        assert_eq!(*index, expected_index);
+        assert!(
+            value.abs_diff_eq(expected_value, 1e-5),
+            "child {index} completed with the target at {value}, expected {expected_value}"
+        );
+    }
+}
+
+#[test]
+fn skipped_children_apply_before_notifying() {
+    // The whole sequence is skipped in a single frame
+    let (snapshots, user_data) = run(Duration::ZERO, false, &[0, 5_000]);
+    assert_end_states(&snapshots);
+    assert_eq!(user_data, [0, 1, 2]);
+}
+
+#[test]
+fn skipped_children_apply_before_notifying_mid_child() {
+    // The first child is partially played, then the rest is skipped
+    let (snapshots, user_data) = run(Duration::ZERO, false, &[0, 100, 5_000]);
+    assert_end_states(&snapshots);
+    assert_eq!(user_data, [0, 1, 2]);
+}
+
+#[test]
+fn skipped_children_apply_before_notifying_with_min_step() {
+    // A minimum step larger than the children doesn't hold their end state
+    let (snapshots, user_data) = run(Duration::from_secs(1), false, &[0, 100, 5_000]);
+    assert_end_states(&snapshots);
+    assert_eq!(user_data, [0, 1, 2]);
+}
+
+#[test]
+fn skipped_children_apply_before_notifying_nested() {
+    // The same order holds through other combinators
+    let (snapshots, user_data) = run(Duration::ZERO, true, &[0, 100, 5_000]);
+    assert_end_states(&snapshots);
+    assert_eq!(user_data, [0, 1, 2]);
+}
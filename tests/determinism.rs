@@ -0,0 +1,127 @@
+//! Determinism of the animations, for lockstep simulations.
+//!
+//! This drives a nested tweenable with a fixed list of deltas, and hashes the
+//! animated values after each step. The hash is compared against a golden
+//! value, so any change to the tick semantics of the tweenables fails this
+//! test loudly. If such a change is intended, update [`GOLDEN_HASH`] with the
+//! value printed by the failing test, and mention it in the CHANGELOG.
+//!
+//! The `determinism` example runs the same animation, and prints the hash of
+//! the current platform.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_tweening::{lens::*, *};
+
+/// Hash of the animated values at all steps, on all platforms.
+const GOLDEN_HASH: u64 = 0xb647_2741_6d96_7b3d;
+
+/// Quantization step of the hashed values.
+///
+/// The easing functions use transcendental functions like `sin()` and
+/// `powf()`, which may differ in the last bits between platforms. The values
+/// are rounded to a multiple of this step before being hashed.
+const EPSILON: f32 = 1e-4;
+
+/// Deltas of the steps, in microseconds. Some are odd, some are zero, and some
+/// are longer than a tween, to cover all the paths of the tick.
+const DELTAS_US: [u64; 16] = [
+    16_667, 33_333, 1, 0, 7_919, 250_001, 16_666, 999_983, 3, 104_729, 16_667, 1_299_709, 49_999,
+    0, 611_953, 2_750_159,
+];
+
+/// Ping-pong tween of the position, scale, or rotation.
+fn ping_pong(index: u32) -> Tracks<Transform> {
+    let duration = |millis| Duration::from_millis(millis + 37 * u64::from(index));
+    let position = Tween::new(
+        EaseFunction::QuadraticInOut,
+        duration(300),
+        TransformPositionLens {
+            start: Vec3::ZERO,
+            end: Vec3::new(3., -2., 1.) * (index + 1) as f32,
+        },
+    )
+    .with_repeat_count(3)
+    .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
+    let scale = Tween::new(
+        EaseFunction::SineInOut,
+        duration(450),
+        TransformScaleLens {
+            start: Vec3::ONE,
+            end: Vec3::splat(0.25),
+        },
+    )
+    .with_repeat_count(2)
+    .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
+    let rotation = Tween::new(
+        EaseFunction::BackOut,
+        duration(700),
+        TransformRotationLens {
+            start: Quat::IDENTITY,
+            end: Quat::from_rotation_y(2.),
+        },
+    )
+    .with_repeat_count(2)
+    .with_repeat_strategy(RepeatStrategy::MirroredRepeat);
+    Tracks::new([
+        Sequence::from_single(position),
+        Sequence::from_single(scale),
+        Sequence::from_single(rotation),
+    ])
+}
+
+/// 64-bit FNV-1a hash, stable across platforms and Rust versions.
+struct Fnv(u64);
+
+impl Fnv {
+    fn write(&mut self, value: f32) {
+        let quantized = (value / EPSILON).round() as i64;
+        for byte in quantized.to_le_bytes() {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+#[test]
+fn golden_hash() {
+    let mut app = App::new();
+    app.add_plugins(
+        TweeningPlugin::default()
+            .without_defaults()
+            .register::<Transform>(),
+    )
+    .init_resource::<Time>();
+    let entity = app
+        .world
+        .spawn((
+            Transform::default(),
+            Animator::new(Sequence::new((0..3).map(ping_pong))),
+        ))
+        .id();
+
+    let mut hash = Fnv(0xcbf2_9ce4_8422_2325);
+    for delta in DELTAS_US {
+        app.world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_micros(delta));
+        app.update();
+        let transform = app.world.get::<Transform>(entity).unwrap();
+        for value in transform
+            .translation
+            .to_array()
+            .into_iter()
+            .chain(transform.scale.to_array())
+            .chain(transform.rotation.to_array())
+        {
+            hash.write(value);
+        }
+    }
+
+    assert_eq!(
+        hash.0, GOLDEN_HASH,
+        "Animation results changed, new hash: {:#018x}",
+        hash.0
+    );
+}
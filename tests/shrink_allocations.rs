@@ -0,0 +1,119 @@
+//! Memory reclaimed by animators shrinking once completed.
+//!
+//! This installs a global allocator counting the bytes currently allocated,
+//! to measure the memory freed when the animators shrink their tweenable. The
+//! counter is shared by the whole process, so this binary contains a single
+//! test, and the animator system runs on the test thread only, without any
+//! other test allocating concurrently.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicIsize, Ordering},
+    time::Duration,
+};
+
+use bevy::{ecs::system::System as _, prelude::*};
+use bevy_tweening::{lens::*, *};
+
+/// Global allocator counting the bytes currently allocated.
+struct CountingAllocator;
+
+static ALLOCATED: AtomicIsize = AtomicIsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            ALLOCATED.fetch_add(layout.size() as isize, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            ALLOCATED.fetch_add(layout.size() as isize, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        ALLOCATED.fetch_sub(layout.size() as isize, Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            ALLOCATED.fetch_add(
+                new_size as isize - layout.size() as isize,
+                Ordering::Relaxed,
+            );
+        }
+        new_ptr
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Get the number of bytes currently allocated by the process.
+fn allocated_bytes() -> isize {
+    ALLOCATED.load(Ordering::Relaxed)
+}
+
+#[test]
+fn shrink_on_complete_frees_tweenables() {
+    const COUNT: usize = 1000;
+
+    let mut world = World::new();
+    world.init_resource::<Events<TweenCompleted>>();
+    world.init_resource::<Events<AnimatorStateChanged>>();
+    world.init_resource::<Time>();
+    let make_tween = || {
+        Tween::new(
+            EaseMethod::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+    };
+    let entities: Vec<Entity> = (0..COUNT)
+        .map(|_| {
+            let animator = Animator::new(make_tween().then(make_tween()).then(make_tween()))
+                .with_shrink_on_complete(true);
+            world.spawn((Transform::default(), animator)).id()
+        })
+        .collect();
+
+    let mut system = IntoSystem::into_system(component_animator_system::<Transform>);
+    system.initialize(&mut world);
+    let mut tick = |world: &mut World, duration: Duration| {
+        world.resource_mut::<Time>().advance_by(duration);
+        system.run((), world);
+    };
+    tick(&mut world, Duration::from_secs(2));
+
+    // Each sequence of 3 boxed tweens with boxed lenses is replaced by a
+    // placeholder a fraction of that size.
+    let before = allocated_bytes();
+    tick(&mut world, Duration::from_secs(1));
+    let freed = before - allocated_bytes();
+    assert!(
+        freed > (COUNT * 256) as isize,
+        "freed only {freed} bytes for {COUNT} animators"
+    );
+    for &entity in &entities {
+        assert!(world
+            .get::<Animator<Transform>>(entity)
+            .unwrap()
+            .is_shrunk());
+    }
+
+    // Ticking again doesn't allocate a new placeholder
+    let before = allocated_bytes();
+    tick(&mut world, Duration::from_secs(1));
+    assert!(allocated_bytes() - before < (COUNT * 8) as isize);
+}